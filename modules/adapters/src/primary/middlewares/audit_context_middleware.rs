@@ -0,0 +1,40 @@
+use axum::extract::Request;
+use axum::http::{HeaderName, HeaderValue};
+use axum::middleware::Next;
+use axum::response::Response;
+use uuid::Uuid;
+
+// shared modules
+use domain::services::audit_service::AuditContext;
+
+// internal modules
+use crate::shared::utilities::request_extractor::{connect_info, extract_ip, extract_user_agent};
+
+static REQUEST_ID_HEADER: HeaderName = HeaderName::from_static("x-request-id");
+
+/// Attach the request id, IP and user agent to the audit events emitted while serving the request. A request
+/// id sent by the client or a proxy is kept, one is generated otherwise and echoed back in the response.
+pub async fn audit_context_middleware(req: Request, next: Next) -> Response {
+    let headers = req.headers();
+    let request_id = headers
+        .get(&REQUEST_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .filter(|value| !value.is_empty())
+        .map(|value| value.to_string())
+        .unwrap_or_else(|| Uuid::now_v7().to_string());
+
+    let context = AuditContext {
+        actor_id: None,
+        impersonator_id: None,
+        ip_address: Some(extract_ip(headers, connect_info(&req))),
+        user_agent: Some(extract_user_agent(headers)),
+        request_id: Some(request_id.clone()),
+    };
+
+    let mut response = context.scope(next.run(req)).await;
+    if let Ok(value) = HeaderValue::from_str(&request_id) {
+        response.headers_mut().insert(REQUEST_ID_HEADER.clone(), value);
+    }
+
+    response
+}
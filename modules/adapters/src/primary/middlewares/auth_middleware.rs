@@ -1,14 +1,21 @@
-use axum::extract::{FromRequestParts, Request};
-use axum::http::HeaderMap;
+use axum::Extension;
+use axum::extract::{FromRequestParts, Query, Request};
 use axum::http::request::Parts;
+use axum::http::{HeaderMap, Method};
 use axum::middleware::Next;
 use axum::response::Response;
+use serde::Deserialize;
+use std::sync::Arc;
 
 // shared modules
+use domain::services::audit_service::AuditContext;
+use domain::usecases::audit_usecases::RecordImpersonationUseCase;
+use domain::usecases::auth_usecases::ManageSessionAuthUseCase;
 use shared::jwt::service::JwtService;
 use shared::models::failure::Failure;
 
 // internal modules
+use crate::shared::di::state::AppState;
 use crate::shared::models::failure::HttpFailure;
 
 #[derive(Debug, Clone)]
@@ -16,6 +23,8 @@ pub struct AuthClaims {
     pub account_id: String,
     pub jti: String,
     pub role: Option<String>,
+    /// The admin acting as the account when the token was issued by an impersonation.
+    pub actor_id: Option<String>,
 }
 
 impl AuthClaims {
@@ -28,11 +37,16 @@ impl AuthClaims {
                 HttpFailure::new(Failure::Unauthorized("Missing or invalid Authorization header".to_string()))
             })?;
 
+        Self::from_token(token)
+    }
+
+    pub fn from_token(token: &str) -> Result<Self, HttpFailure> {
         let claims_wrapped = JwtService::verify_access_token(token).map_err(HttpFailure::new)?;
         Ok(AuthClaims {
             account_id: claims_wrapped.claims.sub,
             jti: claims_wrapped.claims.jti,
             role: claims_wrapped.claims.role,
+            actor_id: claims_wrapped.claims.act.map(|actor| actor.sub),
         })
     }
 }
@@ -51,11 +65,127 @@ where
     }
 }
 
-pub async fn auth_middleware(mut req: Request, next: Next) -> Result<Response, HttpFailure> {
+/// Access tokens outlive a sign-out, deactivation or suspension, so the session and the account are looked up on
+/// every request.
+async fn ensure_active(state: &AppState, claims: &AuthClaims) -> Result<(), HttpFailure> {
+    state.auth_app_service.ensure_session_active(&claims.account_id, &claims.jti).await.map_err(HttpFailure::new)
+}
+
+/// Requests made while impersonating an account are traced, the ones that can change something are also written
+/// to the audit log. Runs within the audit context of the request, which names the actor and the impersonator.
+async fn audit_impersonation(state: &AppState, claims: &AuthClaims, method: &Method, path: &str) {
+    let Some(actor_id) = &claims.actor_id else {
+        return;
+    };
+    tracing::info!(
+        target: "audit",
+        actor_id = %actor_id,
+        account_id = %claims.account_id,
+        jti = %claims.jti,
+        method = %method,
+        path = %path,
+        "Impersonated request"
+    );
+    if method.is_safe() {
+        return;
+    }
+
+    if let Err(failure) =
+        state.audit_app_service.record_impersonated_request(&claims.account_id, method.as_str(), path).await
+    {
+        tracing::warn!(account_id = %claims.account_id, "Failed to record an impersonated request: {}", failure.message());
+    }
+}
+
+/// The context set by `audit_context_middleware` with the signed-in account as the actor.
+fn actor_context(claims: &AuthClaims) -> AuditContext {
+    AuditContext {
+        actor_id: Some(claims.account_id.clone()),
+        impersonator_id: claims.actor_id.clone(),
+        ..AuditContext::current().unwrap_or_default()
+    }
+}
+
+/// Needs the `AppState` extension, which `initialize_app` layers over every route.
+pub async fn auth_middleware(
+    Extension(state): Extension<Arc<AppState>>,
+    mut req: Request,
+    next: Next,
+) -> Result<Response, HttpFailure> {
     let headers = req.headers();
     let claims = AuthClaims::from_headers(headers)?;
+    ensure_active(&state, &claims).await?;
+    let (method, path) = (req.method().clone(), req.uri().path().to_string());
 
     // Store claims in request extensions for later use
-    req.extensions_mut().insert(claims);
+    let context = actor_context(&claims);
+    req.extensions_mut().insert(claims.clone());
+    Ok(context
+        .scope(async {
+            audit_impersonation(&state, &claims, &method, &path).await;
+            next.run(req).await
+        })
+        .await)
+}
+
+/// Privacy, credential and destructive routes are only for the account itself, an impersonation token is turned
+/// away and the attempt recorded. Layered inside `auth_middleware`.
+pub async fn deny_impersonation(
+    Extension(state): Extension<Arc<AppState>>,
+    req: Request,
+    next: Next,
+) -> Result<Response, HttpFailure> {
+    let claims = req.extensions().get::<AuthClaims>().cloned().ok_or_else(|| {
+        HttpFailure::new(Failure::Unauthorized(
+            "Authentication required. Please ensure auth_middleware is applied first.".to_string(),
+        ))
+    })?;
+
+    if let Some(actor_id) = &claims.actor_id {
+        tracing::warn!(
+            target: "audit",
+            actor_id = %actor_id,
+            account_id = %claims.account_id,
+            method = %req.method(),
+            path = %req.uri().path(),
+            "Impersonated request denied"
+        );
+        if let Err(failure) = state.audit_app_service.record_denied_impersonation(&claims.account_id).await {
+            tracing::warn!(account_id = %claims.account_id, "Failed to record a denied impersonation: {}", failure.message());
+        }
+
+        return Err(HttpFailure::new(Failure::Forbidden("This action is not allowed while impersonating".to_string())));
+    }
+
     Ok(next.run(req).await)
 }
+
+#[derive(Deserialize)]
+struct StreamAuthQuery {
+    access_token: Option<String>,
+}
+
+/// Browsers can not set headers on WebSocket and EventSource requests, so streams also accept the
+/// access token as an `access_token` query parameter, the request trace masks its value.
+pub async fn stream_auth_middleware(
+    Extension(state): Extension<Arc<AppState>>,
+    mut req: Request,
+    next: Next,
+) -> Result<Response, HttpFailure> {
+    let query_token = Query::<StreamAuthQuery>::try_from_uri(req.uri()).ok().and_then(|query| query.0.access_token);
+    let claims = match query_token {
+        Some(token) => AuthClaims::from_token(&token)?,
+        None => AuthClaims::from_headers(req.headers())?,
+    };
+    ensure_active(&state, &claims).await?;
+    let (method, path) = (req.method().clone(), req.uri().path().to_string());
+
+    let context = actor_context(&claims);
+    req.extensions_mut().insert(claims.clone());
+    Ok(context
+        .scope(async {
+            audit_impersonation(&state, &claims, &method, &path).await;
+            next.run(req).await
+        })
+        .await)
+}
@@ -1,2 +1,3 @@
+pub mod audit_context_middleware;
 pub mod auth_middleware;
 pub mod role_middleware;
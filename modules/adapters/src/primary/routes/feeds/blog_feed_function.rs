@@ -0,0 +1,20 @@
+use axum::extract::{Path, State};
+use axum::http::HeaderMap;
+use axum::response::Response;
+use std::sync::Arc;
+
+// shared modules
+use domain::usecases::feed_usecases::FeedScope;
+
+// internal modules
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+
+pub async fn execute(
+    State(state): State<Arc<AppState>>,
+    Path(file): Path<String>,
+    headers: HeaderMap,
+) -> Result<Response, HttpFailure> {
+    let path = format!("/feeds/{}", file);
+    super::feed_response::execute(&state, &headers, FeedScope::All, &path, &file).await
+}
@@ -0,0 +1,20 @@
+use axum::extract::{Path, State};
+use axum::http::HeaderMap;
+use axum::response::Response;
+use std::sync::Arc;
+
+// shared modules
+use domain::usecases::feed_usecases::FeedScope;
+
+// internal modules
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+
+pub async fn execute(
+    State(state): State<Arc<AppState>>,
+    Path((category_id, file)): Path<(String, String)>,
+    headers: HeaderMap,
+) -> Result<Response, HttpFailure> {
+    let path = format!("/feeds/categories/{}/{}", category_id, file);
+    super::feed_response::execute(&state, &headers, FeedScope::Category(category_id), &path, &file).await
+}
@@ -0,0 +1,47 @@
+use axum::http::header::{CACHE_CONTROL, CONTENT_TYPE};
+use axum::http::{HeaderMap, HeaderValue, StatusCode};
+use axum::response::{IntoResponse, Response};
+use std::sync::Arc;
+
+// shared modules
+use domain::usecases::feed_usecases::{BlogFeedUseCase, FeedScope};
+use shared::models::failure::Failure;
+
+// internal modules
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::utilities::conditional_request::CacheValidators;
+use crate::shared::utilities::feed_writer::{self, FeedFormat};
+use crate::shared::utilities::site_links;
+
+/// Render the feed for `scope`, answering 304 when the reader already has the latest copy.
+pub async fn execute(
+    state: &Arc<AppState>,
+    headers: &HeaderMap,
+    scope: FeedScope,
+    path: &str,
+    file_name: &str,
+) -> Result<Response, HttpFailure> {
+    // 1. Resolve the format from the file name
+    let format = FeedFormat::from_file_name(file_name)
+        .ok_or_else(|| HttpFailure::new(Failure::NotFound(format!("Feed {} not found", file_name))))?;
+
+    // 2. Load the feed and answer conditional requests without rendering
+    let feed = state.feed_app_service.find_blog_feed(&scope).await.map_err(HttpFailure::new)?;
+    let item_ids: Vec<&str> = feed.items.iter().filter_map(|item| item.blog.base.id.as_deref()).collect();
+    let validators = CacheValidators::new((format, path, &item_ids), feed.updated_at);
+    if validators.is_not_modified(headers) {
+        return Ok(validators.not_modified());
+    }
+
+    // 3. Render the feed
+    let body = feed_writer::render(format, &feed, &site_links::page_url(path));
+
+    let mut response = (StatusCode::OK, body).into_response();
+    validators.apply(&mut response);
+    let response_headers = response.headers_mut();
+    response_headers.insert(CONTENT_TYPE, HeaderValue::from_static(format.content_type()));
+    response_headers.insert(CACHE_CONTROL, HeaderValue::from_static("public, max-age=300"));
+
+    Ok(response)
+}
@@ -0,0 +1,5 @@
+mod author_feed_function;
+mod blog_feed_function;
+mod category_feed_function;
+mod feed_response;
+pub mod routes;
@@ -0,0 +1,23 @@
+use axum::Router;
+use axum::routing::get;
+use std::sync::Arc;
+
+// internal modules
+use crate::shared::di::state::AppState;
+use crate::shared::utilities::route_logger;
+
+pub fn execute() -> Router<Arc<AppState>> {
+    register_routes_for_logging();
+    let public_routes = Router::new()
+        .route("/{file}", get(super::blog_feed_function::execute))
+        .route("/categories/{id}/{file}", get(super::category_feed_function::execute))
+        .route("/authors/{id}/{file}", get(super::author_feed_function::execute));
+
+    Router::new().merge(public_routes)
+}
+
+fn register_routes_for_logging() {
+    route_logger::track_route("GET", "/feeds/{blogs.rss|blogs.atom|blogs.json}", vec![]);
+    route_logger::track_route("GET", "/feeds/categories/{id}/{blogs.rss|blogs.atom|blogs.json}", vec![]);
+    route_logger::track_route("GET", "/feeds/authors/{id}/{blogs.rss|blogs.atom|blogs.json}", vec![]);
+}
@@ -4,16 +4,30 @@ use std::sync::Arc;
 // internal modules
 use crate::shared::di::state::AppState;
 
+mod feeds;
+mod seo;
 mod v1;
 
 pub fn execute() -> Router<Arc<AppState>> {
     let v1 = Router::new()
+        .nest("/audit-events", v1::audit_events::routes::execute())
         .nest("/auth", v1::auth::routes::execute())
         .nest("/accounts", v1::accounts::routes::execute())
         .nest("/sessions", v1::sessions::routes::execute())
         .nest("/blogs", v1::blogs::routes::execute())
+        .nest("/bookmarks", v1::bookmarks::routes::execute())
         .nest("/categories", v1::categories::routes::execute())
-        .nest("/globals", v1::globals::routes::execute());
+        .nest("/comments", v1::comments::routes::execute())
+        .nest("/courses", v1::courses::routes::execute())
+        .nest("/enrollments", v1::enrollments::routes::execute())
+        .nest("/globals", v1::globals::routes::execute())
+        .nest("/media", v1::media::routes::execute())
+        .nest("/notifications", v1::notifications::routes::execute())
+        .nest("/realtime", v1::realtime::routes::execute())
+        .nest("/reviews", v1::reviews::routes::execute())
+        .nest("/search", v1::search::routes::execute())
+        .nest("/uploads", v1::uploads::routes::execute())
+        .nest("/users", v1::users::routes::execute());
 
-    Router::new().nest("/api/v1", v1)
+    Router::new().nest("/api/v1", v1).nest("/feeds", feeds::routes::execute()).merge(seo::routes::execute())
 }
@@ -0,0 +1,5 @@
+mod robots_function;
+pub mod routes;
+mod sitemap_function;
+mod sitemap_page_function;
+mod sitemap_response;
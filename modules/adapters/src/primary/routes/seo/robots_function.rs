@@ -0,0 +1,23 @@
+use axum::http::StatusCode;
+use axum::http::header::CONTENT_TYPE;
+use axum::response::IntoResponse;
+
+// shared modules
+use shared::configs::APP_CONFIG;
+
+// internal modules
+use crate::shared::utilities::site_links;
+
+pub async fn execute() -> impl IntoResponse {
+    let site = &APP_CONFIG.site;
+
+    let mut lines = vec!["User-agent: *".to_string()];
+    match site.robots_allow_indexing {
+        true => lines.extend(site.robots_disallow.iter().map(|path| format!("Disallow: {}", path))),
+        false => lines.push("Disallow: /".to_string()),
+    }
+    lines.push(String::new());
+    lines.push(format!("Sitemap: {}", site_links::page_url("/sitemap.xml")));
+
+    (StatusCode::OK, [(CONTENT_TYPE, "text/plain; charset=utf-8")], lines.join("\n") + "\n")
+}
@@ -0,0 +1,23 @@
+use axum::Router;
+use axum::routing::get;
+use std::sync::Arc;
+
+// internal modules
+use crate::shared::di::state::AppState;
+use crate::shared::utilities::route_logger;
+
+pub fn execute() -> Router<Arc<AppState>> {
+    register_routes_for_logging();
+    let public_routes = Router::new()
+        .route("/robots.txt", get(super::robots_function::execute))
+        .route("/sitemap.xml", get(super::sitemap_function::execute))
+        .route("/sitemaps/{file}", get(super::sitemap_page_function::execute));
+
+    Router::new().merge(public_routes)
+}
+
+fn register_routes_for_logging() {
+    route_logger::track_route("GET", "/robots.txt", vec![]);
+    route_logger::track_route("GET", "/sitemap.xml", vec![]);
+    route_logger::track_route("GET", "/sitemaps/{sitemap-n.xml}", vec![]);
+}
@@ -0,0 +1,20 @@
+use axum::extract::State;
+use axum::http::HeaderMap;
+use axum::response::Response;
+use std::sync::Arc;
+
+// internal modules
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::utilities::sitemap_writer;
+
+pub async fn execute(State(state): State<Arc<AppState>>, headers: HeaderMap) -> Result<Response, HttpFailure> {
+    // Past the per-file limit the root becomes an index of numbered sitemaps
+    super::sitemap_response::execute(&state, &headers, "/sitemap.xml", |entries| {
+        match sitemap_writer::page_count(entries) {
+            1 => Some(sitemap_writer::render_urlset(entries)),
+            _ => Some(sitemap_writer::render_index(entries)),
+        }
+    })
+    .await
+}
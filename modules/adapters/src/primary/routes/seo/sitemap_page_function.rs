@@ -0,0 +1,27 @@
+use axum::extract::{Path, State};
+use axum::http::HeaderMap;
+use axum::response::Response;
+use std::sync::Arc;
+
+// shared modules
+use shared::models::failure::Failure;
+
+// internal modules
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::utilities::sitemap_writer;
+
+pub async fn execute(
+    State(state): State<Arc<AppState>>,
+    Path(file): Path<String>,
+    headers: HeaderMap,
+) -> Result<Response, HttpFailure> {
+    let page = sitemap_writer::page_from_file_name(&file)
+        .ok_or_else(|| HttpFailure::new(Failure::NotFound(format!("Sitemap {} not found", file))))?;
+
+    let path = sitemap_writer::page_path(page);
+    super::sitemap_response::execute(&state, &headers, &path, |entries| {
+        sitemap_writer::page(entries, page).map(sitemap_writer::render_urlset)
+    })
+    .await
+}
@@ -0,0 +1,43 @@
+use axum::http::header::{CACHE_CONTROL, CONTENT_TYPE};
+use axum::http::{HeaderMap, HeaderValue, StatusCode};
+use axum::response::{IntoResponse, Response};
+use std::sync::Arc;
+
+// shared modules
+use domain::usecases::sitemap_usecases::{SitemapEntry, SitemapUseCase};
+use shared::models::failure::Failure;
+
+// internal modules
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::utilities::conditional_request::CacheValidators;
+
+/// Load the sitemap entries and render them with `render`, answering 304 when nothing changed.
+pub async fn execute(
+    state: &Arc<AppState>,
+    headers: &HeaderMap,
+    path: &str,
+    render: impl FnOnce(&[SitemapEntry]) -> Option<String>,
+) -> Result<Response, HttpFailure> {
+    // 1. Load the entries and answer conditional requests without rendering
+    let entries = state.sitemap_app_service.find_sitemap_entries().await.map_err(HttpFailure::new)?;
+    let last_modified = entries.iter().map(|entry| entry.updated_at).max().unwrap_or(0);
+    // Ids make the tag change when a page leaves the sitemap, the timestamps alone would miss it
+    let entry_ids: Vec<&str> = entries.iter().map(|entry| entry.id.as_str()).collect();
+    let validators = CacheValidators::new((path, entry_ids), last_modified);
+    if validators.is_not_modified(headers) {
+        return Ok(validators.not_modified());
+    }
+
+    // 2. Render the file, unknown pages are not found
+    let body =
+        render(&entries).ok_or_else(|| HttpFailure::new(Failure::NotFound(format!("Sitemap {} not found", path))))?;
+
+    let mut response = (StatusCode::OK, body).into_response();
+    validators.apply(&mut response);
+    let response_headers = response.headers_mut();
+    response_headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/xml; charset=utf-8"));
+    response_headers.insert(CACHE_CONTROL, HeaderValue::from_static("public, max-age=3600"));
+
+    Ok(response)
+}
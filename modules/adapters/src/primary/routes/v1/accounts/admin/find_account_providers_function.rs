@@ -0,0 +1,20 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::provider_entity::ProviderEntity;
+use domain::usecases::admin_usecases::ModerateAccountUseCase;
+
+// internal modules
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::models::response::HttpResponse;
+use crate::shared::types::AxumResponse;
+
+pub async fn execute(State(state): State<Arc<AppState>>, Path(id): Path<String>) -> AxumResponse<Vec<ProviderEntity>> {
+    match state.admin_app_service.find_account_providers(&id).await {
+        Ok(providers) => Ok(HttpResponse::new(StatusCode::OK, "Account providers found successfully 🪼", providers)),
+        Err(failure) => Err(HttpFailure::new(failure)),
+    }
+}
@@ -0,0 +1,20 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::session_entity::SessionEntity;
+use domain::usecases::admin_usecases::ModerateAccountUseCase;
+
+// internal modules
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::models::response::HttpResponse;
+use crate::shared::types::AxumResponse;
+
+pub async fn execute(State(state): State<Arc<AppState>>, Path(id): Path<String>) -> AxumResponse<Vec<SessionEntity>> {
+    match state.admin_app_service.find_account_sessions(&id).await {
+        Ok(sessions) => Ok(HttpResponse::new(StatusCode::OK, "Account sessions found successfully 🪼", sessions)),
+        Err(failure) => Err(HttpFailure::new(failure)),
+    }
+}
@@ -0,0 +1,19 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+// shared modules
+use domain::usecases::admin_usecases::ModerateAccountUseCase;
+
+// internal modules
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::models::response::HttpResponse;
+use crate::shared::types::AxumResponse;
+
+pub async fn execute(State(state): State<Arc<AppState>>, Path(id): Path<String>) -> AxumResponse<()> {
+    match state.admin_app_service.force_sign_out(&id).await {
+        Ok(_) => Ok(HttpResponse::new(StatusCode::OK, "Account signed out everywhere successfully 🪼", ())),
+        Err(failure) => Err(HttpFailure::new(failure)),
+    }
+}
@@ -0,0 +1,44 @@
+use axum::extract::{ConnectInfo, Path, State};
+use axum::http::{HeaderMap, StatusCode};
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+// shared modules
+use domain::usecases::admin_usecases::{ImpersonateAccountUseCase, ImpersonationResponse};
+use domain::usecases::auth_usecases::SessionMetadata;
+
+// internal modules
+use crate::primary::middlewares::auth_middleware::AuthClaims;
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::models::response::HttpResponse;
+use crate::shared::types::AxumResponse;
+use crate::shared::utilities::request_extractor;
+
+pub async fn execute(
+    State(state): State<Arc<AppState>>,
+    AuthClaims { account_id: admin_id, .. }: AuthClaims,
+    Path(id): Path<String>,
+    ConnectInfo(peer): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+) -> AxumResponse<ImpersonationResponse> {
+    let ip_address = request_extractor::extract_ip(&headers, Some(peer));
+    let user_agent = request_extractor::extract_user_agent(&headers);
+    let device_type = request_extractor::detect_device_type(&user_agent);
+    let session_metadata = SessionMetadata { ip_address, user_agent, device_type };
+
+    match state.admin_app_service.impersonate_account(&admin_id, &id, &session_metadata).await {
+        Ok(response) => {
+            tracing::info!(
+                target: "audit",
+                actor_id = %admin_id,
+                account_id = %id,
+                ip_address = %session_metadata.ip_address,
+                expires_at = response.expires_at,
+                "Impersonation started"
+            );
+            Ok(HttpResponse::new(StatusCode::OK, "Impersonation token issued successfully 🪼", response))
+        },
+        Err(failure) => Err(HttpFailure::new(failure)),
+    }
+}
@@ -0,0 +1,20 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::account_entity::AccountEntity;
+use domain::usecases::admin_usecases::ModerateAccountUseCase;
+
+// internal modules
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::models::response::HttpResponse;
+use crate::shared::types::AxumResponse;
+
+pub async fn execute(State(state): State<Arc<AppState>>, Path(id): Path<String>) -> AxumResponse<AccountEntity> {
+    match state.admin_app_service.lift_suspension(&id).await {
+        Ok(account) => Ok(HttpResponse::new(StatusCode::OK, "Account suspension lifted successfully 🪼", account)),
+        Err(failure) => Err(HttpFailure::new(failure)),
+    }
+}
@@ -0,0 +1,6 @@
+pub mod find_account_providers_function;
+pub mod find_account_sessions_function;
+pub mod force_sign_out_function;
+pub mod impersonate_account_function;
+pub mod lift_suspension_function;
+pub mod suspend_account_function;
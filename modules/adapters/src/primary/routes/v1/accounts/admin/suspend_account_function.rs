@@ -0,0 +1,27 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::account_entity::AccountEntity;
+use domain::usecases::admin_usecases::{ModerateAccountUseCase, SuspendAccountParams};
+
+// internal modules
+use crate::primary::middlewares::auth_middleware::AuthClaims;
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::models::response::HttpResponse;
+use crate::shared::types::AxumResponse;
+use crate::shared::utilities::validated_payload::ValidatedPayload;
+
+pub async fn execute(
+    State(state): State<Arc<AppState>>,
+    AuthClaims { account_id: admin_id, .. }: AuthClaims,
+    Path(id): Path<String>,
+    ValidatedPayload(params): ValidatedPayload<SuspendAccountParams>,
+) -> AxumResponse<AccountEntity> {
+    match state.admin_app_service.suspend_account(&admin_id, &id, &params).await {
+        Ok(account) => Ok(HttpResponse::new(StatusCode::OK, "Account suspended successfully 🪼", account)),
+        Err(failure) => Err(HttpFailure::new(failure)),
+    }
+}
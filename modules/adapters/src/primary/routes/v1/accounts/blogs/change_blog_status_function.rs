@@ -0,0 +1,30 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+// shared modules
+use domain::{
+    entities::blog_entity::BlogEntity,
+    usecases::account_usecases::{ChangeBlogStatusParams, MangeBlogAccountUseCase},
+};
+
+// internal modules
+use crate::primary::middlewares::auth_middleware::AuthClaims;
+use crate::shared::{
+    di::state::AppState,
+    models::{failure::HttpFailure, response::HttpResponse},
+    types::AxumResponse,
+    utilities::validated_payload::ValidatedPayload,
+};
+
+pub async fn execute(
+    State(state): State<Arc<AppState>>,
+    AuthClaims { account_id, .. }: AuthClaims,
+    Path(blog_id): Path<String>,
+    ValidatedPayload(params): ValidatedPayload<ChangeBlogStatusParams>,
+) -> AxumResponse<BlogEntity> {
+    match state.account_app_service.change_account_blog_status(&account_id, &blog_id, &params).await {
+        Ok(blog_entity) => Ok(HttpResponse::new(StatusCode::OK, "Blog status changed successfully 🪼", blog_entity)),
+        Err(err) => Err(HttpFailure::new(err)),
+    }
+}
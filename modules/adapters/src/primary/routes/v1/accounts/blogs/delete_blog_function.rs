@@ -6,13 +6,15 @@ use std::sync::Arc;
 use domain::usecases::account_usecases::MangeBlogAccountUseCase;
 
 // internal modules
+use crate::primary::middlewares::auth_middleware::AuthClaims;
 use crate::shared::di::state::AppState;
 use crate::shared::models::failure::HttpFailure;
 use crate::shared::{models::response::HttpResponse, types::AxumResponse};
 
 pub async fn execute(
     State(state): State<Arc<AppState>>,
-    Path((account_id, blog_id)): Path<(String, String)>,
+    AuthClaims { account_id, .. }: AuthClaims,
+    Path(blog_id): Path<String>,
 ) -> AxumResponse<()> {
     match state.account_app_service.delete_account_blog(&account_id, &blog_id).await {
         Ok(_) => Ok(HttpResponse::new(StatusCode::OK, "Blog deleted successfully", ())),
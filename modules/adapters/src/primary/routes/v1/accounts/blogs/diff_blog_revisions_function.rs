@@ -0,0 +1,30 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+// shared modules
+use domain::{
+    usecases::account_usecases::{DiffBlogRevisionsQuery, ManageBlogRevisionUseCase},
+    values::text_diff::TextDiff,
+};
+
+// internal modules
+use crate::primary::middlewares::auth_middleware::AuthClaims;
+use crate::shared::{
+    di::state::AppState,
+    models::{failure::HttpFailure, response::HttpResponse},
+    types::AxumResponse,
+    utilities::validated_query::ValidatedQuery,
+};
+
+pub async fn execute(
+    State(state): State<Arc<AppState>>,
+    AuthClaims { account_id, .. }: AuthClaims,
+    Path(blog_id): Path<String>,
+    ValidatedQuery(query): ValidatedQuery<DiffBlogRevisionsQuery>,
+) -> AxumResponse<TextDiff> {
+    match state.account_app_service.diff_blog_revisions(&account_id, &blog_id, &query).await {
+        Ok(diff) => Ok(HttpResponse::new(StatusCode::OK, "Blog revisions compared successfully ✅", diff)),
+        Err(failure) => Err(HttpFailure::new(failure)),
+    }
+}
@@ -0,0 +1,27 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+// shared modules
+use domain::{
+    entities::blog_revision_entity::BlogRevisionEntity, usecases::account_usecases::ManageBlogRevisionUseCase,
+};
+
+// internal modules
+use crate::primary::middlewares::auth_middleware::AuthClaims;
+use crate::shared::{
+    di::state::AppState,
+    models::{failure::HttpFailure, response::HttpResponse},
+    types::AxumResponse,
+};
+
+pub async fn execute(
+    State(state): State<Arc<AppState>>,
+    AuthClaims { account_id, .. }: AuthClaims,
+    Path(blog_id): Path<String>,
+) -> AxumResponse<Vec<BlogRevisionEntity>> {
+    match state.account_app_service.find_blog_revisions(&account_id, &blog_id).await {
+        Ok(revisions) => Ok(HttpResponse::new(StatusCode::OK, "Blog revisions retrieved successfully ✅", revisions)),
+        Err(failure) => Err(HttpFailure::new(failure)),
+    }
+}
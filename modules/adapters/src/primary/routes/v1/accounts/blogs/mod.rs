@@ -1,3 +1,7 @@
+pub mod change_blog_status_function;
 pub mod delete_blog_function;
+pub mod diff_blog_revisions_function;
+pub mod find_blog_revisions_function;
 pub mod publish_blog_function;
+pub mod restore_blog_revision_function;
 pub mod update_blog_function;
@@ -1,4 +1,4 @@
-use axum::extract::{Path, State};
+use axum::extract::State;
 use axum::http::StatusCode;
 use std::sync::Arc;
 
@@ -9,6 +9,7 @@ use domain::{
 };
 
 // internal modules
+use crate::primary::middlewares::auth_middleware::AuthClaims;
 use crate::shared::{
     di::state::AppState,
     models::{failure::HttpFailure, response::HttpResponse},
@@ -18,10 +19,10 @@ use crate::shared::{
 
 pub async fn execute(
     State(state): State<Arc<AppState>>,
-    Path(id): Path<String>,
+    AuthClaims { account_id, .. }: AuthClaims,
     ValidatedPayload(params): ValidatedPayload<PublishBlogParams>,
 ) -> AxumResponse<BlogEntity> {
-    match state.account_app_service.publish_account_blog(&id, &params).await {
+    match state.account_app_service.publish_account_blog(&account_id, &params).await {
         Ok(blog_entity) => Ok(HttpResponse::new(StatusCode::CREATED, "Blog published successfully", blog_entity)),
         Err(err) => Err(HttpFailure::new(err)),
     }
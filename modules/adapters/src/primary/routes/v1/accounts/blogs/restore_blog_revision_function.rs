@@ -0,0 +1,25 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+// shared modules
+use domain::{entities::blog_entity::BlogEntity, usecases::account_usecases::ManageBlogRevisionUseCase};
+
+// internal modules
+use crate::primary::middlewares::auth_middleware::AuthClaims;
+use crate::shared::{
+    di::state::AppState,
+    models::{failure::HttpFailure, response::HttpResponse},
+    types::AxumResponse,
+};
+
+pub async fn execute(
+    State(state): State<Arc<AppState>>,
+    AuthClaims { account_id, .. }: AuthClaims,
+    Path((blog_id, version)): Path<(String, i32)>,
+) -> AxumResponse<BlogEntity> {
+    match state.account_app_service.restore_blog_revision(&account_id, &blog_id, version).await {
+        Ok(blog_entity) => Ok(HttpResponse::new(StatusCode::OK, "Blog revision restored successfully ✅", blog_entity)),
+        Err(failure) => Err(HttpFailure::new(failure)),
+    }
+}
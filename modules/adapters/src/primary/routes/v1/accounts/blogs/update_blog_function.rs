@@ -9,6 +9,7 @@ use domain::{
 };
 
 // internal modules
+use crate::primary::middlewares::auth_middleware::AuthClaims;
 use crate::shared::{
     di::state::AppState,
     models::{failure::HttpFailure, response::HttpResponse},
@@ -18,7 +19,8 @@ use crate::shared::{
 
 pub async fn execute(
     State(state): State<Arc<AppState>>,
-    Path((account_id, blog_id)): Path<(String, String)>,
+    AuthClaims { account_id, .. }: AuthClaims,
+    Path(blog_id): Path<String>,
     ValidatedPayload(params): ValidatedPayload<UpdateBlogParams>,
 ) -> AxumResponse<BlogEntity> {
     match state.account_app_service.update_account_blog(&account_id, &blog_id, &params).await {
@@ -0,0 +1,27 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::account_entity::AccountEntity;
+use domain::usecases::privacy_usecases::ManageAccountStatusUseCase;
+
+// internal modules
+use crate::primary::middlewares::auth_middleware::AuthClaims;
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::models::response::HttpResponse;
+use crate::shared::types::AxumResponse;
+
+/// The account is only erased once the grace period passed, `deletionDueAt` tells when.
+pub async fn execute(
+    State(state): State<Arc<AppState>>,
+    AuthClaims { account_id, .. }: AuthClaims,
+) -> AxumResponse<AccountEntity> {
+    match state.privacy_app_service.schedule_account_deletion(&account_id).await {
+        Ok(account) => {
+            Ok(HttpResponse::new(StatusCode::ACCEPTED, "Account deletion scheduled successfully ✅", account))
+        },
+        Err(failure) => Err(HttpFailure::new(failure)),
+    }
+}
@@ -0,0 +1,34 @@
+use axum::extract::{Path, State};
+use axum::http::header::{CACHE_CONTROL, CONTENT_DISPOSITION, CONTENT_TYPE};
+use axum::http::{HeaderValue, StatusCode};
+use axum::response::{IntoResponse, Response};
+use std::sync::Arc;
+
+// shared modules
+use domain::usecases::privacy_usecases::DataExportUseCase;
+
+// internal modules
+use crate::primary::middlewares::auth_middleware::AuthClaims;
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+
+/// Stream the archive back as an attachment, it holds personal data so it is never cached.
+pub async fn execute(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    AuthClaims { account_id, .. }: AuthClaims,
+) -> Result<Response, HttpFailure> {
+    let blob = state.privacy_app_service.download_export(&account_id, &id).await.map_err(HttpFailure::new)?;
+    let content_type =
+        HeaderValue::from_str(&blob.content_type).unwrap_or(HeaderValue::from_static("application/octet-stream"));
+    let disposition = HeaderValue::from_str(&format!("attachment; filename=\"visionary-export-{}.zip\"", id))
+        .unwrap_or(HeaderValue::from_static("attachment"));
+
+    let mut response = (StatusCode::OK, blob.bytes).into_response();
+    let headers = response.headers_mut();
+    headers.insert(CONTENT_TYPE, content_type);
+    headers.insert(CONTENT_DISPOSITION, disposition);
+    headers.insert(CACHE_CONTROL, HeaderValue::from_static("private, no-store"));
+
+    Ok(response)
+}
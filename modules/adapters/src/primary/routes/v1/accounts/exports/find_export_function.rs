@@ -0,0 +1,25 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::data_export_entity::DataExportEntity;
+use domain::usecases::privacy_usecases::DataExportUseCase;
+
+// internal modules
+use crate::primary::middlewares::auth_middleware::AuthClaims;
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::models::response::HttpResponse;
+use crate::shared::types::AxumResponse;
+
+pub async fn execute(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    AuthClaims { account_id, .. }: AuthClaims,
+) -> AxumResponse<DataExportEntity> {
+    match state.privacy_app_service.find_export(&account_id, &id).await {
+        Ok(export) => Ok(HttpResponse::new(StatusCode::OK, "Data export found successfully 🪼", export)),
+        Err(failure) => Err(HttpFailure::new(failure)),
+    }
+}
@@ -0,0 +1,4 @@
+pub mod download_export_function;
+pub mod find_export_function;
+pub mod find_exports_function;
+pub mod request_export_function;
@@ -0,0 +1,24 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::data_export_entity::DataExportEntity;
+use domain::usecases::privacy_usecases::DataExportUseCase;
+
+// internal modules
+use crate::primary::middlewares::auth_middleware::AuthClaims;
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::models::response::HttpResponse;
+use crate::shared::types::AxumResponse;
+
+pub async fn execute(
+    State(state): State<Arc<AppState>>,
+    AuthClaims { account_id, .. }: AuthClaims,
+) -> AxumResponse<DataExportEntity> {
+    match state.privacy_app_service.request_export(&account_id).await {
+        Ok(export) => Ok(HttpResponse::new(StatusCode::ACCEPTED, "Data export requested successfully ✅", export)),
+        Err(failure) => Err(HttpFailure::new(failure)),
+    }
+}
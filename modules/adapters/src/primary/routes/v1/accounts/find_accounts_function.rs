@@ -1,9 +1,9 @@
 use axum::extract::State;
 use axum::http::StatusCode;
+use serde_json::Value;
 use std::sync::Arc;
 
 // shared modules
-use domain::entities::account_entity::AccountEntity;
 use domain::usecases::account_usecases::{FindAccountsQuery, ManageAccountsUseCase};
 
 // internal modules
@@ -16,7 +16,7 @@ use crate::shared::utilities::validated_query::ValidatedQuery;
 pub async fn execute(
     State(state): State<Arc<AppState>>,
     ValidatedQuery(query): ValidatedQuery<FindAccountsQuery>,
-) -> AxumPaginatedResponse<AccountEntity> {
+) -> AxumPaginatedResponse<Value> {
     match state.account_app_service.find_accounts(&query).await {
         Ok((paginate, accounts)) => Ok(HttpPaginatedResponse::new(
             StatusCode::OK,
@@ -1,6 +1,11 @@
+pub mod admin;
 pub mod blogs;
+mod deactivate_account_function;
+mod delete_account_function;
+pub mod exports;
 mod find_account_with_email_function;
 mod find_accounts_function;
 mod find_profile_function;
 pub mod roles;
 pub mod routes;
+mod update_profile_function;
@@ -1,50 +1,155 @@
 use axum::Router;
 use axum::middleware;
-use axum::routing::{delete, get, post, put};
+use axum::routing::{delete, get, patch, post, put};
 use std::sync::Arc;
 
 // internal modules
-use crate::primary::middlewares::auth_middleware::auth_middleware;
+use crate::primary::middlewares::auth_middleware::{auth_middleware, deny_impersonation};
 use crate::primary::middlewares::role_middleware::require_admin;
+use crate::primary::routes::v1::accounts::admin::{
+    find_account_providers_function, find_account_sessions_function, force_sign_out_function,
+    impersonate_account_function, lift_suspension_function, suspend_account_function,
+};
+use crate::primary::routes::v1::accounts::blogs::change_blog_status_function;
 use crate::primary::routes::v1::accounts::blogs::delete_blog_function;
+use crate::primary::routes::v1::accounts::blogs::diff_blog_revisions_function;
+use crate::primary::routes::v1::accounts::blogs::find_blog_revisions_function;
 use crate::primary::routes::v1::accounts::blogs::publish_blog_function;
+use crate::primary::routes::v1::accounts::blogs::restore_blog_revision_function;
 use crate::primary::routes::v1::accounts::blogs::update_blog_function;
+use crate::primary::routes::v1::accounts::exports::{
+    download_export_function, find_export_function, find_exports_function, request_export_function,
+};
 use crate::primary::routes::v1::accounts::roles::add_role_function;
 use crate::primary::routes::v1::accounts::roles::find_role_function;
 use crate::primary::routes::v1::accounts::roles::update_role_function;
 use crate::primary::routes::v1::accounts::{
-    find_account_with_email_function, find_accounts_function, find_profile_function,
+    deactivate_account_function, delete_account_function, find_account_with_email_function, find_accounts_function,
+    find_profile_function, update_profile_function,
 };
 use crate::shared::di::state::AppState;
 use crate::shared::utilities::route_logger;
 
 pub fn execute() -> Router<Arc<AppState>> {
     register_routes_for_logging();
-    let public_routes = Router::new().route("/find-account-with-email", get(find_account_with_email_function::execute));
-
     let protected_routes = Router::new()
         .route("/", get(find_accounts_function::execute).layer(middleware::from_fn(require_admin)))
         .route("/{id}/roles", post(add_role_function::execute))
         .route("/{id}/roles", put(update_role_function::execute))
         .route("/{id}/roles", get(find_role_function::execute))
-        .route("/{id}/blogs", post(publish_blog_function::execute))
-        .route("/{account_id}/blogs/{blog_id}", put(update_blog_function::execute))
-        .route("/{account_id}/blogs/{blog_id}", delete(delete_blog_function::execute))
+        .route("/me/blogs", post(publish_blog_function::execute).layer(middleware::from_fn(deny_impersonation)))
+        .route("/me/blogs/{blog_id}", put(update_blog_function::execute).layer(middleware::from_fn(deny_impersonation)))
+        .route(
+            "/me/blogs/{blog_id}",
+            delete(delete_blog_function::execute).layer(middleware::from_fn(deny_impersonation)),
+        )
+        .route(
+            "/me/blogs/{blog_id}/status",
+            patch(change_blog_status_function::execute).layer(middleware::from_fn(deny_impersonation)),
+        )
+        .route("/me/blogs/{blog_id}/revisions", get(find_blog_revisions_function::execute))
+        .route("/me/blogs/{blog_id}/revisions/diff", get(diff_blog_revisions_function::execute))
+        .route(
+            "/me/blogs/{blog_id}/revisions/{version}/restore",
+            post(restore_blog_revision_function::execute).layer(middleware::from_fn(deny_impersonation)),
+        )
         .route("/find-profile", get(find_profile_function::execute))
+        .route("/me", patch(update_profile_function::execute).layer(middleware::from_fn(deny_impersonation)))
+        .route("/me", delete(delete_account_function::execute).layer(middleware::from_fn(deny_impersonation)))
+        .route(
+            "/me/deactivate",
+            post(deactivate_account_function::execute).layer(middleware::from_fn(deny_impersonation)),
+        )
+        .route("/me/exports", post(request_export_function::execute).layer(middleware::from_fn(deny_impersonation)))
+        .route("/me/exports", get(find_exports_function::execute).layer(middleware::from_fn(deny_impersonation)))
+        .route("/me/exports/{id}", get(find_export_function::execute).layer(middleware::from_fn(deny_impersonation)))
+        .route(
+            "/me/exports/{id}/download",
+            get(download_export_function::execute).layer(middleware::from_fn(deny_impersonation)),
+        )
+        .layer(middleware::from_fn(auth_middleware));
+    let admin_routes = Router::new()
+        .route("/find-account-with-email", get(find_account_with_email_function::execute))
+        .route("/{id}/suspension", post(suspend_account_function::execute))
+        .route("/{id}/suspension", delete(lift_suspension_function::execute))
+        .route("/{id}/sign-out", post(force_sign_out_function::execute))
+        .route("/{id}/providers", get(find_account_providers_function::execute))
+        .route("/{id}/sessions", get(find_account_sessions_function::execute))
+        .route(
+            "/{id}/impersonate",
+            post(impersonate_account_function::execute).layer(middleware::from_fn(deny_impersonation)),
+        )
+        .layer(middleware::from_fn(require_admin))
         .layer(middleware::from_fn(auth_middleware));
 
-    Router::new().merge(public_routes).merge(protected_routes)
+    Router::new().merge(protected_routes).merge(admin_routes)
 }
 
 fn register_routes_for_logging() {
-    route_logger::track_route("GET", "/api/v1/accounts/find-account-with-email", vec![]);
-
     route_logger::track_route("GET", "/api/v1/accounts/", vec!["admin".to_string()]);
     route_logger::track_route("POST", "/api/v1/accounts/{id}/roles", vec![]);
     route_logger::track_route("PUT", "/api/v1/accounts/{id}/roles", vec![]);
     route_logger::track_route("GET", "/api/v1/accounts/{id}/roles", vec![]);
-    route_logger::track_route("POST", "/api/v1/accounts/{id}/blogs", vec![]);
-    route_logger::track_route("PUT", "/api/v1/accounts/{account_id}/blogs/{blog_id}", vec![]);
-    route_logger::track_route("DELETE", "/api/v1/accounts/{account_id}/blogs/{blog_id}", vec![]);
+    route_logger::track_route("POST", "/api/v1/accounts/me/blogs", vec![]);
+    route_logger::track_route("PUT", "/api/v1/accounts/me/blogs/{blog_id}", vec![]);
+    route_logger::track_route("DELETE", "/api/v1/accounts/me/blogs/{blog_id}", vec![]);
+    route_logger::track_route("PATCH", "/api/v1/accounts/me/blogs/{blog_id}/status", vec![]);
+    route_logger::track_route("GET", "/api/v1/accounts/me/blogs/{blog_id}/revisions", vec![]);
+    route_logger::track_route("GET", "/api/v1/accounts/me/blogs/{blog_id}/revisions/diff", vec![]);
+    route_logger::track_route("POST", "/api/v1/accounts/me/blogs/{blog_id}/revisions/{version}/restore", vec![]);
     route_logger::track_route("GET", "/api/v1/accounts/find-profile", vec!["auth".to_string()]);
+    route_logger::track_route("PATCH", "/api/v1/accounts/me", vec!["auth".to_string(), "no-impersonation".to_string()]);
+    route_logger::track_route(
+        "DELETE",
+        "/api/v1/accounts/me",
+        vec!["auth".to_string(), "no-impersonation".to_string()],
+    );
+    route_logger::track_route(
+        "POST",
+        "/api/v1/accounts/me/deactivate",
+        vec!["auth".to_string(), "no-impersonation".to_string()],
+    );
+    route_logger::track_route(
+        "POST",
+        "/api/v1/accounts/me/exports",
+        vec!["auth".to_string(), "no-impersonation".to_string()],
+    );
+    route_logger::track_route(
+        "GET",
+        "/api/v1/accounts/me/exports",
+        vec!["auth".to_string(), "no-impersonation".to_string()],
+    );
+    route_logger::track_route(
+        "GET",
+        "/api/v1/accounts/me/exports/{id}",
+        vec!["auth".to_string(), "no-impersonation".to_string()],
+    );
+    route_logger::track_route(
+        "GET",
+        "/api/v1/accounts/me/exports/{id}/download",
+        vec!["auth".to_string(), "no-impersonation".to_string()],
+    );
+    route_logger::track_route(
+        "GET",
+        "/api/v1/accounts/find-account-with-email",
+        vec!["auth".to_string(), "admin".to_string()],
+    );
+    route_logger::track_route(
+        "POST",
+        "/api/v1/accounts/{id}/suspension",
+        vec!["auth".to_string(), "admin".to_string()],
+    );
+    route_logger::track_route(
+        "DELETE",
+        "/api/v1/accounts/{id}/suspension",
+        vec!["auth".to_string(), "admin".to_string()],
+    );
+    route_logger::track_route("POST", "/api/v1/accounts/{id}/sign-out", vec!["auth".to_string(), "admin".to_string()]);
+    route_logger::track_route("GET", "/api/v1/accounts/{id}/providers", vec!["auth".to_string(), "admin".to_string()]);
+    route_logger::track_route("GET", "/api/v1/accounts/{id}/sessions", vec!["auth".to_string(), "admin".to_string()]);
+    route_logger::track_route(
+        "POST",
+        "/api/v1/accounts/{id}/impersonate",
+        vec!["auth".to_string(), "admin".to_string(), "no-impersonation".to_string()],
+    );
 }
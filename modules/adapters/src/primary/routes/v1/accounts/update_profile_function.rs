@@ -0,0 +1,26 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::account_entity::AccountEntity;
+use domain::usecases::account_usecases::{ManageProfileUseCase, UpdateProfileParams};
+
+// internal modules
+use crate::primary::middlewares::auth_middleware::AuthClaims;
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::models::response::HttpResponse;
+use crate::shared::types::AxumResponse;
+use crate::shared::utilities::validated_payload::ValidatedPayload;
+
+pub async fn execute(
+    State(state): State<Arc<AppState>>,
+    AuthClaims { account_id, .. }: AuthClaims,
+    ValidatedPayload(params): ValidatedPayload<UpdateProfileParams>,
+) -> AxumResponse<AccountEntity> {
+    match state.account_app_service.update_profile(&account_id, &params).await {
+        Ok(account) => Ok(HttpResponse::new(StatusCode::OK, "Profile updated successfully ✅", account)),
+        Err(failure) => Err(HttpFailure::new(failure)),
+    }
+}
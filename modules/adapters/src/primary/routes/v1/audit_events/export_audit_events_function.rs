@@ -0,0 +1,93 @@
+use axum::extract::State;
+use axum::http::header::{CACHE_CONTROL, CONTENT_DISPOSITION, CONTENT_TYPE};
+use axum::http::{HeaderValue, StatusCode};
+use axum::response::{IntoResponse, Response};
+use chrono::Utc;
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::audit_event_entity::AuditEventEntity;
+use domain::usecases::audit_usecases::{ExportAuditEventsQuery, QueryAuditLogUseCase};
+use shared::models::failure::Failure;
+
+// internal modules
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::utilities::validated_query::ValidatedQuery;
+
+static CSV_HEADER: [&str; 11] = [
+    "id",
+    "createdAt",
+    "actorId",
+    "impersonatorId",
+    "action",
+    "targetType",
+    "targetId",
+    "ipAddress",
+    "userAgent",
+    "requestId",
+    "changes",
+];
+
+/// One row per event, the changes are kept as a JSON array in the last column.
+fn render_csv(events: &[AuditEventEntity]) -> Result<Vec<u8>, Failure> {
+    let failure = |e: csv::Error| Failure::InternalServerError(format!("Failed to write audit events: {}", e));
+    let mut writer = csv::Writer::from_writer(vec![]);
+    writer.write_record(CSV_HEADER).map_err(failure)?;
+    for event in events {
+        let changes = serde_json::to_string(&event.changes).unwrap_or_default();
+        writer
+            .write_record([
+                event.base.id.as_deref().unwrap_or_default(),
+                &event.base.created_at.to_string(),
+                event.actor_id.as_deref().unwrap_or_default(),
+                event.impersonator_id.as_deref().unwrap_or_default(),
+                &event.action,
+                &event.target_type,
+                event.target_id.as_deref().unwrap_or_default(),
+                event.ip_address.as_deref().unwrap_or_default(),
+                event.user_agent.as_deref().unwrap_or_default(),
+                event.request_id.as_deref().unwrap_or_default(),
+                &changes,
+            ])
+            .map_err(failure)?;
+    }
+
+    writer.into_inner().map_err(|e| Failure::InternalServerError(format!("Failed to write audit events: {}", e)))
+}
+
+/// Send the matching events back as a `csv` or `json` attachment.
+pub async fn execute(
+    State(state): State<Arc<AppState>>,
+    ValidatedQuery(query): ValidatedQuery<ExportAuditEventsQuery>,
+) -> Result<Response, HttpFailure> {
+    let format = query.format.as_deref().unwrap_or("csv").to_lowercase();
+    let content_type = match format.as_str() {
+        "csv" => "text/csv; charset=utf-8",
+        "json" => "application/json",
+        _ => {
+            let message = format!("Unsupported export format: {}, expected csv or json", format);
+            return Err(HttpFailure::new(Failure::BadRequest(message)));
+        },
+    };
+
+    let events = state.audit_app_service.export_audit_events(&query).await.map_err(HttpFailure::new)?;
+    let bytes = match format.as_str() {
+        "csv" => render_csv(&events),
+        _ => serde_json::to_vec(&events)
+            .map_err(|e| Failure::InternalServerError(format!("Failed to serialize audit events: {}", e))),
+    }
+    .map_err(HttpFailure::new)?;
+
+    let filename = format!("audit-events-{}.{}", Utc::now().format("%Y%m%d%H%M%S"), format);
+    let disposition = HeaderValue::from_str(&format!("attachment; filename=\"{}\"", filename))
+        .unwrap_or(HeaderValue::from_static("attachment"));
+
+    let mut response = (StatusCode::OK, bytes).into_response();
+    let headers = response.headers_mut();
+    headers.insert(CONTENT_TYPE, HeaderValue::from_static(content_type));
+    headers.insert(CONTENT_DISPOSITION, disposition);
+    headers.insert(CACHE_CONTROL, HeaderValue::from_static("private, no-store"));
+
+    Ok(response)
+}
@@ -0,0 +1,29 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::audit_event_entity::AuditEventEntity;
+use domain::usecases::audit_usecases::{FindAuditEventsQuery, QueryAuditLogUseCase};
+
+// internal modules
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::models::response::HttpPaginatedResponse;
+use crate::shared::types::AxumPaginatedResponse;
+use crate::shared::utilities::validated_query::ValidatedQuery;
+
+pub async fn execute(
+    State(state): State<Arc<AppState>>,
+    ValidatedQuery(query): ValidatedQuery<FindAuditEventsQuery>,
+) -> AxumPaginatedResponse<AuditEventEntity> {
+    match state.audit_app_service.find_audit_events(&query).await {
+        Ok((paginate, events)) => Ok(HttpPaginatedResponse::new(
+            StatusCode::OK,
+            "Audit events retrieved successfully ✅".to_string(),
+            paginate,
+            events,
+        )),
+        Err(failure) => Err(HttpFailure::new(failure)),
+    }
+}
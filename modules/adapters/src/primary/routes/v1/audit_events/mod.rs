@@ -0,0 +1,3 @@
+pub mod export_audit_events_function;
+pub mod find_audit_events_function;
+pub mod routes;
@@ -0,0 +1,25 @@
+use axum::Router;
+use axum::middleware;
+use axum::routing::get;
+use std::sync::Arc;
+
+// internal modules
+use crate::primary::middlewares::auth_middleware::auth_middleware;
+use crate::primary::middlewares::role_middleware::require_admin;
+use crate::primary::routes::v1::audit_events::{export_audit_events_function, find_audit_events_function};
+use crate::shared::di::state::AppState;
+use crate::shared::utilities::route_logger;
+
+pub fn execute() -> Router<Arc<AppState>> {
+    register_routes_for_logging();
+    Router::new()
+        .route("/", get(find_audit_events_function::execute))
+        .route("/export", get(export_audit_events_function::execute))
+        .layer(middleware::from_fn(require_admin))
+        .layer(middleware::from_fn(auth_middleware))
+}
+
+fn register_routes_for_logging() {
+    route_logger::track_route("GET", "/api/v1/audit-events/", vec!["auth".to_string(), "admin".to_string()]);
+    route_logger::track_route("GET", "/api/v1/audit-events/export", vec!["auth".to_string(), "admin".to_string()]);
+}
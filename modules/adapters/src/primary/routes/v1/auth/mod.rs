@@ -1,5 +1,6 @@
 mod oauth_google_callback_function;
 mod oauth_init_function;
+mod reactivate_function;
 mod refresh_token_function;
 pub mod routes;
 mod sign_in_function;
@@ -0,0 +1,32 @@
+use axum::extract::{ConnectInfo, State};
+use axum::http::{HeaderMap, StatusCode};
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+// shared modules
+use domain::usecases::auth_usecases::{AuthParams, AuthResponse, ManageSessionAuthUseCase, SessionMetadata};
+
+// internal modules
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::models::response::HttpResponse;
+use crate::shared::types::AxumResponse;
+use crate::shared::utilities::request_extractor;
+use crate::shared::utilities::validated_payload::ValidatedPayload;
+
+pub async fn execute(
+    State(state): State<Arc<AppState>>,
+    ConnectInfo(peer): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+    ValidatedPayload(params): ValidatedPayload<AuthParams>,
+) -> AxumResponse<AuthResponse> {
+    let ip_address = request_extractor::extract_ip(&headers, Some(peer));
+    let user_agent = request_extractor::extract_user_agent(&headers);
+    let device_type = request_extractor::detect_device_type(&user_agent);
+    let session_metadata = SessionMetadata { ip_address, user_agent, device_type };
+
+    match state.auth_app_service.reactivate(&params, &session_metadata).await {
+        Ok(response) => Ok(HttpResponse::new(StatusCode::OK, "Account reactivated successfully ✅", response)),
+        Err(failure) => Err(HttpFailure::new(failure)),
+    }
+}
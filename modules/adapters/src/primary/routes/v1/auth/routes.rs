@@ -5,6 +5,7 @@ use std::sync::Arc;
 // internal modules
 use crate::primary::routes::v1::auth::oauth_google_callback_function;
 use crate::primary::routes::v1::auth::oauth_init_function;
+use crate::primary::routes::v1::auth::reactivate_function;
 use crate::primary::routes::v1::auth::refresh_token_function;
 use crate::primary::routes::v1::auth::sign_in_function;
 use crate::primary::routes::v1::auth::sign_out_function;
@@ -17,6 +18,7 @@ pub fn execute() -> Router<Arc<AppState>> {
     let public_routes = Router::new()
         .route("/sign-in", post(sign_in_function::execute))
         .route("/sign-up", post(sign_up_function::execute))
+        .route("/reactivate", post(reactivate_function::execute))
         .route("/refresh-token", post(refresh_token_function::execute))
         .route("/sign-out", post(sign_out_function::execute))
         .route("/oauth2/init", get(oauth_init_function::execute))
@@ -29,6 +31,7 @@ pub fn execute() -> Router<Arc<AppState>> {
 fn register_routes_for_logging() {
     route_logger::track_route("POST", "/api/v1/auth/sign-in", vec![]);
     route_logger::track_route("POST", "/api/v1/auth/sign-up", vec![]);
+    route_logger::track_route("POST", "/api/v1/auth/reactivate", vec![]);
     route_logger::track_route("POST", "/api/v1/auth/refresh-token", vec![]);
     route_logger::track_route("POST", "/api/v1/auth/sign-out", vec![]);
     route_logger::track_route("GET", "/api/v1/auth/oauth2/init", vec![]);
@@ -1,5 +1,6 @@
-use axum::extract::State;
+use axum::extract::{ConnectInfo, State};
 use axum::http::{HeaderMap, StatusCode};
+use std::net::SocketAddr;
 use std::sync::Arc;
 
 // shared modules
@@ -15,10 +16,11 @@ use crate::shared::utilities::validated_payload::ValidatedPayload;
 
 pub async fn execute(
     State(state): State<Arc<AppState>>,
+    ConnectInfo(peer): ConnectInfo<SocketAddr>,
     headers: HeaderMap,
     ValidatedPayload(params): ValidatedPayload<AuthParams>,
 ) -> AxumResponse<AuthResponse> {
-    let ip_address = request_extractor::extract_ip(&headers);
+    let ip_address = request_extractor::extract_ip(&headers, Some(peer));
     let user_agent = request_extractor::extract_user_agent(&headers);
     let device_type = request_extractor::detect_device_type(&user_agent);
     let session_metadata = SessionMetadata { ip_address, user_agent, device_type };
@@ -0,0 +1,30 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::comment_entity::CommentEntity;
+use domain::usecases::comment_usecases::{FindCommentsQuery, ManageCommentUseCase};
+
+// internal modules
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::models::response::HttpPaginatedResponse;
+use crate::shared::types::AxumPaginatedResponse;
+use crate::shared::utilities::validated_query::ValidatedQuery;
+
+pub async fn execute(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    ValidatedQuery(query): ValidatedQuery<FindCommentsQuery>,
+) -> AxumPaginatedResponse<CommentEntity> {
+    match state.comment_app_service.find_blog_comments(&id, &query).await {
+        Ok((paginate, comments)) => Ok(HttpPaginatedResponse::new(
+            StatusCode::OK,
+            "Retrieved blog comments successfully 🪼".to_string(),
+            paginate,
+            comments,
+        )),
+        Err(failure) => Err(HttpFailure::new(failure)),
+    }
+}
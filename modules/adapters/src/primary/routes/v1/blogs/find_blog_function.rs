@@ -0,0 +1,34 @@
+use axum::extract::{ConnectInfo, Path, State};
+use axum::http::{HeaderMap, StatusCode};
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::blog_entity::BlogEntity;
+use domain::usecases::blog_usecases::ManageBlogUseCase;
+
+// internal modules
+use crate::primary::middlewares::auth_middleware::AuthClaims;
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::models::response::HttpResponse;
+use crate::shared::types::AxumResponse;
+use crate::shared::utilities::request_extractor::extract_ip;
+
+pub async fn execute(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    ConnectInfo(peer): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
+) -> AxumResponse<BlogEntity> {
+    // Signed in readers are deduplicated by account, anonymous readers by IP
+    let viewer_key = match AuthClaims::from_headers(&headers) {
+        Ok(claims) => format!("account:{}", claims.account_id),
+        Err(_) => format!("ip:{}", extract_ip(&headers, Some(peer))),
+    };
+
+    match state.blog_app_service.find_blog(&id, &viewer_key).await {
+        Ok(blog) => Ok(HttpResponse::new(StatusCode::OK, "Retrieved blog successfully 🪼", blog)),
+        Err(failure) => Err(HttpFailure::new(failure)),
+    }
+}
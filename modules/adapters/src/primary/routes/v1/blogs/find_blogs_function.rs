@@ -1,8 +1,29 @@
+use axum::extract::State;
 use axum::http::StatusCode;
+use serde_json::Value;
+use std::sync::Arc;
+
+// shared modules
+use domain::usecases::blog_usecases::{FindBlogsQuery, ManageBlogUseCase};
 
 // internal modules
-use crate::shared::{models::response::HttpResponse, types::AxumResponse};
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::models::response::HttpPaginatedResponse;
+use crate::shared::types::AxumPaginatedResponse;
+use crate::shared::utilities::validated_query::ValidatedQuery;
 
-pub async fn execute() -> AxumResponse<()> {
-    Ok(HttpResponse::new(StatusCode::OK, "Retrieved all blog successfully 🪼", ()))
+pub async fn execute(
+    State(state): State<Arc<AppState>>,
+    ValidatedQuery(query): ValidatedQuery<FindBlogsQuery>,
+) -> AxumPaginatedResponse<Value> {
+    match state.blog_app_service.find_blogs(&query).await {
+        Ok((paginate, blogs)) => Ok(HttpPaginatedResponse::new(
+            StatusCode::OK,
+            "Retrieved all blog successfully 🪼".to_string(),
+            paginate,
+            blogs,
+        )),
+        Err(failure) => Err(HttpFailure::new(failure)),
+    }
 }
@@ -1,2 +1,6 @@
+mod create_blog_comment_function;
+mod find_blog_comments_function;
+mod find_blog_function;
 mod find_blogs_function;
 pub mod routes;
+mod toggle_blog_star_function;
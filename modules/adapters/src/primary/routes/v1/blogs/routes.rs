@@ -1,12 +1,30 @@
-use axum::{Router, routing::get};
+use axum::routing::{get, post};
+use axum::{Router, middleware};
 use std::sync::Arc;
 
 // internal modules
+use crate::primary::middlewares::auth_middleware::auth_middleware;
 use crate::shared::di::state::AppState;
+use crate::shared::utilities::route_logger;
 
 pub fn execute() -> Router<Arc<AppState>> {
-    let public_routes = Router::new().route("/", get(super::find_blogs_function::execute));
-    let protected_routes = Router::new();
+    register_routes_for_logging();
+    let public_routes = Router::new()
+        .route("/", get(super::find_blogs_function::execute))
+        .route("/{id}", get(super::find_blog_function::execute))
+        .route("/{id}/comments", get(super::find_blog_comments_function::execute));
+    let protected_routes = Router::new()
+        .route("/{id}/star", post(super::toggle_blog_star_function::execute))
+        .route("/{id}/comments", post(super::create_blog_comment_function::execute))
+        .layer(middleware::from_fn(auth_middleware));
 
     Router::new().merge(public_routes).merge(protected_routes)
 }
+
+fn register_routes_for_logging() {
+    route_logger::track_route("GET", "/api/v1/blogs/", vec![]);
+    route_logger::track_route("GET", "/api/v1/blogs/{id}", vec![]);
+    route_logger::track_route("GET", "/api/v1/blogs/{id}/comments", vec![]);
+    route_logger::track_route("POST", "/api/v1/blogs/{id}/star", vec!["auth".to_string()]);
+    route_logger::track_route("POST", "/api/v1/blogs/{id}/comments", vec!["auth".to_string()]);
+}
@@ -0,0 +1,24 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+// shared modules
+use domain::usecases::blog_usecases::{ManageBlogUseCase, ToggleBlogStarResponse};
+
+// internal modules
+use crate::primary::middlewares::auth_middleware::AuthClaims;
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::models::response::HttpResponse;
+use crate::shared::types::AxumResponse;
+
+pub async fn execute(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    AuthClaims { account_id, .. }: AuthClaims,
+) -> AxumResponse<ToggleBlogStarResponse> {
+    match state.blog_app_service.toggle_blog_star(&account_id, &id).await {
+        Ok(response) => Ok(HttpResponse::new(StatusCode::OK, "Toggled blog star successfully 🪼", response)),
+        Err(failure) => Err(HttpFailure::new(failure)),
+    }
+}
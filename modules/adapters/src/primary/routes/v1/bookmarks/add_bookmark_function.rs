@@ -0,0 +1,26 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::bookmark_entity::BookmarkEntity;
+use domain::usecases::bookmark_usecases::{AddBookmarkParams, BookmarkUseCase};
+
+// internal modules
+use crate::primary::middlewares::auth_middleware::AuthClaims;
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::models::response::HttpResponse;
+use crate::shared::types::AxumResponse;
+use crate::shared::utilities::validated_payload::ValidatedPayload;
+
+pub async fn execute(
+    State(state): State<Arc<AppState>>,
+    AuthClaims { account_id, .. }: AuthClaims,
+    ValidatedPayload(params): ValidatedPayload<AddBookmarkParams>,
+) -> AxumResponse<BookmarkEntity> {
+    match state.bookmark_app_service.add_bookmark(&account_id, &params).await {
+        Ok(bookmark) => Ok(HttpResponse::new(StatusCode::OK, "Bookmarked successfully 🪼", bookmark)),
+        Err(failure) => Err(HttpFailure::new(failure)),
+    }
+}
@@ -0,0 +1,26 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+// shared modules
+use domain::usecases::bookmark_usecases::{BookmarkUseCase, CheckBookmarksParams};
+
+// internal modules
+use crate::primary::middlewares::auth_middleware::AuthClaims;
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::models::response::HttpResponse;
+use crate::shared::types::AxumResponse;
+use crate::shared::utilities::validated_payload::ValidatedPayload;
+
+pub async fn execute(
+    State(state): State<Arc<AppState>>,
+    AuthClaims { account_id, .. }: AuthClaims,
+    ValidatedPayload(params): ValidatedPayload<CheckBookmarksParams>,
+) -> AxumResponse<HashMap<String, bool>> {
+    match state.bookmark_app_service.check_bookmarks(&account_id, &params).await {
+        Ok(statuses) => Ok(HttpResponse::new(StatusCode::OK, "Checked bookmarks successfully 🪼", statuses)),
+        Err(failure) => Err(HttpFailure::new(failure)),
+    }
+}
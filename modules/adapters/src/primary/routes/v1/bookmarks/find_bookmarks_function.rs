@@ -0,0 +1,31 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::bookmark_entity::BookmarkEntity;
+use domain::usecases::bookmark_usecases::{BookmarkUseCase, FindBookmarksQuery};
+
+// internal modules
+use crate::primary::middlewares::auth_middleware::AuthClaims;
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::models::response::HttpPaginatedResponse;
+use crate::shared::types::AxumPaginatedResponse;
+use crate::shared::utilities::validated_query::ValidatedQuery;
+
+pub async fn execute(
+    State(state): State<Arc<AppState>>,
+    AuthClaims { account_id, .. }: AuthClaims,
+    ValidatedQuery(query): ValidatedQuery<FindBookmarksQuery>,
+) -> AxumPaginatedResponse<BookmarkEntity> {
+    match state.bookmark_app_service.find_bookmarks(&account_id, &query).await {
+        Ok((paginate, bookmarks)) => Ok(HttpPaginatedResponse::new(
+            StatusCode::OK,
+            "Retrieved bookmarks successfully 🪼".to_string(),
+            paginate,
+            bookmarks,
+        )),
+        Err(failure) => Err(HttpFailure::new(failure)),
+    }
+}
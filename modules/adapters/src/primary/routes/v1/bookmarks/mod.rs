@@ -0,0 +1,5 @@
+mod add_bookmark_function;
+mod check_bookmarks_function;
+mod find_bookmarks_function;
+mod remove_bookmark_function;
+pub mod routes;
@@ -0,0 +1,24 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+// shared modules
+use domain::usecases::bookmark_usecases::BookmarkUseCase;
+
+// internal modules
+use crate::primary::middlewares::auth_middleware::AuthClaims;
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::models::response::HttpResponse;
+use crate::shared::types::AxumResponse;
+
+pub async fn execute(
+    State(state): State<Arc<AppState>>,
+    Path((target_type, target_id)): Path<(String, String)>,
+    AuthClaims { account_id, .. }: AuthClaims,
+) -> AxumResponse<()> {
+    match state.bookmark_app_service.remove_bookmark(&account_id, &target_type, &target_id).await {
+        Ok(_) => Ok(HttpResponse::new(StatusCode::OK, "Bookmark removed successfully 🪼", ())),
+        Err(failure) => Err(HttpFailure::new(failure)),
+    }
+}
@@ -0,0 +1,25 @@
+use axum::routing::{delete, get, post};
+use axum::{Router, middleware};
+use std::sync::Arc;
+
+// internal modules
+use crate::primary::middlewares::auth_middleware::auth_middleware;
+use crate::shared::di::state::AppState;
+use crate::shared::utilities::route_logger;
+
+pub fn execute() -> Router<Arc<AppState>> {
+    register_routes_for_logging();
+    Router::new()
+        .route("/", get(super::find_bookmarks_function::execute))
+        .route("/", post(super::add_bookmark_function::execute))
+        .route("/check", post(super::check_bookmarks_function::execute))
+        .route("/{target_type}/{target_id}", delete(super::remove_bookmark_function::execute))
+        .layer(middleware::from_fn(auth_middleware))
+}
+
+fn register_routes_for_logging() {
+    route_logger::track_route("GET", "/api/v1/bookmarks/", vec!["auth".to_string()]);
+    route_logger::track_route("POST", "/api/v1/bookmarks/", vec!["auth".to_string()]);
+    route_logger::track_route("POST", "/api/v1/bookmarks/check", vec!["auth".to_string()]);
+    route_logger::track_route("DELETE", "/api/v1/bookmarks/{target_type}/{target_id}", vec!["auth".to_string()]);
+}
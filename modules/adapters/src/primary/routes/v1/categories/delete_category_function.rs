@@ -0,0 +1,25 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::category_entity::CategoryEntity;
+use domain::usecases::category_usecases::{DeleteCategoryQuery, ManageCategoryUseCase};
+
+// internal modules
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::models::response::HttpResponse;
+use crate::shared::types::AxumResponse;
+use crate::shared::utilities::validated_query::ValidatedQuery;
+
+pub async fn execute(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    ValidatedQuery(query): ValidatedQuery<DeleteCategoryQuery>,
+) -> AxumResponse<CategoryEntity> {
+    match state.category_app_service.delete_category(&id, &query).await {
+        Ok(category) => Ok(HttpResponse::new(StatusCode::OK, "Category deleted successfully 🪼", category)),
+        Err(err) => Err(HttpFailure::new(err)),
+    }
+}
@@ -0,0 +1,20 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::category_entity::CategoryEntity;
+use domain::usecases::category_usecases::ManageCategoryUseCase;
+
+// internal modules
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::models::response::HttpResponse;
+use crate::shared::types::AxumResponse;
+
+pub async fn execute(State(state): State<Arc<AppState>>, Path(id): Path<String>) -> AxumResponse<CategoryEntity> {
+    match state.category_app_service.find_category_by_id(&id).await {
+        Ok(category) => Ok(HttpResponse::new(StatusCode::OK, "Category retrieved successfully 🪼", category)),
+        Err(err) => Err(HttpFailure::new(err)),
+    }
+}
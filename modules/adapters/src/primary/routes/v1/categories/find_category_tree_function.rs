@@ -0,0 +1,19 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+// shared modules
+use domain::usecases::category_usecases::{CategoryTreeNode, ManageCategoryUseCase};
+
+// internal modules
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::models::response::HttpResponse;
+use crate::shared::types::AxumResponse;
+
+pub async fn execute(State(state): State<Arc<AppState>>) -> AxumResponse<Vec<CategoryTreeNode>> {
+    match state.category_app_service.find_category_tree().await {
+        Ok(tree) => Ok(HttpResponse::new(StatusCode::OK, "Category tree retrieved successfully 🪼", tree)),
+        Err(err) => Err(HttpFailure::new(err)),
+    }
+}
@@ -1,4 +1,8 @@
 mod create_category_function;
+mod delete_category_function;
 mod find_categories_function;
+mod find_category_function;
+mod find_category_tree_function;
+mod move_category_function;
 pub mod routes;
 mod update_category_function;
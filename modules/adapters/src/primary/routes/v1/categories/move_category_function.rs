@@ -0,0 +1,25 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::category_entity::CategoryEntity;
+use domain::usecases::category_usecases::{ManageCategoryUseCase, MoveCategoryParams};
+
+// internal modules
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::models::response::HttpResponse;
+use crate::shared::types::AxumResponse;
+use crate::shared::utilities::validated_payload::ValidatedPayload;
+
+pub async fn execute(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    ValidatedPayload(params): ValidatedPayload<MoveCategoryParams>,
+) -> AxumResponse<CategoryEntity> {
+    match state.category_app_service.move_category(&id, &params).await {
+        Ok(category) => Ok(HttpResponse::new(StatusCode::OK, "Category moved successfully 🪼", category)),
+        Err(err) => Err(HttpFailure::new(err)),
+    }
+}
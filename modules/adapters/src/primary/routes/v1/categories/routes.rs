@@ -1,4 +1,4 @@
-use axum::routing::{get, post, put};
+use axum::routing::{delete, get, post, put};
 use axum::{Router, middleware};
 use std::sync::Arc;
 
@@ -10,11 +10,15 @@ use crate::shared::utilities::route_logger;
 
 pub fn execute() -> Router<Arc<AppState>> {
     register_routes_for_logging();
-    let public_routes = Router::new();
-    let protected_routes = Router::new()
+    let public_routes = Router::new()
         .route("/", get(super::find_categories_function::execute))
+        .route("/tree", get(super::find_category_tree_function::execute))
+        .route("/{id}", get(super::find_category_function::execute));
+    let protected_routes = Router::new()
         .route("/", post(super::create_category_function::execute))
         .route("/{id}", put(super::update_category_function::execute))
+        .route("/{id}", delete(super::delete_category_function::execute))
+        .route("/{id}/parent", put(super::move_category_function::execute))
         .layer(middleware::from_fn(require_admin))
         .layer(middleware::from_fn(auth_middleware));
 
@@ -22,7 +26,11 @@ pub fn execute() -> Router<Arc<AppState>> {
 }
 
 fn register_routes_for_logging() {
-    route_logger::track_route("GET", "/api/v1/categories/", vec!["auth".to_string(), "admin".to_string()]);
+    route_logger::track_route("GET", "/api/v1/categories/", vec![]);
+    route_logger::track_route("GET", "/api/v1/categories/tree", vec![]);
+    route_logger::track_route("GET", "/api/v1/categories/{id}", vec![]);
     route_logger::track_route("POST", "/api/v1/categories/", vec!["auth".to_string(), "admin".to_string()]);
     route_logger::track_route("PUT", "/api/v1/categories/{id}", vec!["auth".to_string(), "admin".to_string()]);
+    route_logger::track_route("DELETE", "/api/v1/categories/{id}", vec!["auth".to_string(), "admin".to_string()]);
+    route_logger::track_route("PUT", "/api/v1/categories/{id}/parent", vec!["auth".to_string(), "admin".to_string()]);
 }
@@ -0,0 +1,27 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::comment_ban_entity::CommentBanEntity;
+use domain::usecases::comment_usecases::{BanCommentAuthorParams, ModerateCommentUseCase};
+
+// internal modules
+use crate::primary::middlewares::auth_middleware::AuthClaims;
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::models::response::HttpResponse;
+use crate::shared::types::AxumResponse;
+use crate::shared::utilities::validated_payload::ValidatedPayload;
+
+pub async fn execute(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    AuthClaims { account_id, .. }: AuthClaims,
+    ValidatedPayload(params): ValidatedPayload<BanCommentAuthorParams>,
+) -> AxumResponse<CommentBanEntity> {
+    match state.comment_app_service.ban_comment_author(&account_id, &id, &params).await {
+        Ok(ban) => Ok(HttpResponse::new(StatusCode::CREATED, "Comment author banned successfully 🪼", ban)),
+        Err(failure) => Err(HttpFailure::new(failure)),
+    }
+}
@@ -0,0 +1,24 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+// shared modules
+use domain::usecases::comment_usecases::ManageCommentUseCase;
+
+// internal modules
+use crate::primary::middlewares::auth_middleware::AuthClaims;
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::models::response::HttpResponse;
+use crate::shared::types::AxumResponse;
+
+pub async fn execute(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    AuthClaims { account_id, .. }: AuthClaims,
+) -> AxumResponse<()> {
+    match state.comment_app_service.delete_comment(&account_id, &id).await {
+        Ok(_) => Ok(HttpResponse::new(StatusCode::OK, "Comment deleted successfully 🪼", ())),
+        Err(failure) => Err(HttpFailure::new(failure)),
+    }
+}
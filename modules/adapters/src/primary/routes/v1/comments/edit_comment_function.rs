@@ -0,0 +1,27 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::comment_entity::CommentEntity;
+use domain::usecases::comment_usecases::{EditCommentParams, ManageCommentUseCase};
+
+// internal modules
+use crate::primary::middlewares::auth_middleware::AuthClaims;
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::models::response::HttpResponse;
+use crate::shared::types::AxumResponse;
+use crate::shared::utilities::validated_payload::ValidatedPayload;
+
+pub async fn execute(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    AuthClaims { account_id, .. }: AuthClaims,
+    ValidatedPayload(params): ValidatedPayload<EditCommentParams>,
+) -> AxumResponse<CommentEntity> {
+    match state.comment_app_service.edit_comment(&account_id, &id, &params).await {
+        Ok(comment) => Ok(HttpResponse::new(StatusCode::OK, "Comment updated successfully 🪼", comment)),
+        Err(failure) => Err(HttpFailure::new(failure)),
+    }
+}
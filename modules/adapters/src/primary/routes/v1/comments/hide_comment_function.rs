@@ -0,0 +1,20 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::comment_entity::CommentEntity;
+use domain::usecases::comment_usecases::ModerateCommentUseCase;
+
+// internal modules
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::models::response::HttpResponse;
+use crate::shared::types::AxumResponse;
+
+pub async fn execute(State(state): State<Arc<AppState>>, Path(id): Path<String>) -> AxumResponse<CommentEntity> {
+    match state.comment_app_service.hide_comment(&id).await {
+        Ok(comment) => Ok(HttpResponse::new(StatusCode::OK, "Comment hidden successfully 🪼", comment)),
+        Err(failure) => Err(HttpFailure::new(failure)),
+    }
+}
@@ -0,0 +1,6 @@
+mod approve_comment_function;
+mod ban_comment_author_function;
+mod delete_comment_function;
+mod edit_comment_function;
+mod hide_comment_function;
+pub mod routes;
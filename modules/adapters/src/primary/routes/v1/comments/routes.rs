@@ -0,0 +1,37 @@
+use axum::routing::{delete, post, put};
+use axum::{Router, middleware};
+use std::sync::Arc;
+
+// internal modules
+use crate::primary::middlewares::auth_middleware::auth_middleware;
+use crate::primary::middlewares::role_middleware::require_admin;
+use crate::shared::di::state::AppState;
+use crate::shared::utilities::route_logger;
+
+pub fn execute() -> Router<Arc<AppState>> {
+    register_routes_for_logging();
+    let protected_routes = Router::new()
+        .route("/{id}", put(super::edit_comment_function::execute))
+        .route("/{id}", delete(super::delete_comment_function::execute))
+        .layer(middleware::from_fn(auth_middleware));
+    let admin_routes = Router::new()
+        .route("/{id}/hide", post(super::hide_comment_function::execute))
+        .route("/{id}/approve", post(super::approve_comment_function::execute))
+        .route("/{id}/ban-author", post(super::ban_comment_author_function::execute))
+        .layer(middleware::from_fn(require_admin))
+        .layer(middleware::from_fn(auth_middleware));
+
+    Router::new().merge(protected_routes).merge(admin_routes)
+}
+
+fn register_routes_for_logging() {
+    route_logger::track_route("PUT", "/api/v1/comments/{id}", vec!["auth".to_string()]);
+    route_logger::track_route("DELETE", "/api/v1/comments/{id}", vec!["auth".to_string()]);
+    route_logger::track_route("POST", "/api/v1/comments/{id}/hide", vec!["auth".to_string(), "admin".to_string()]);
+    route_logger::track_route("POST", "/api/v1/comments/{id}/approve", vec!["auth".to_string(), "admin".to_string()]);
+    route_logger::track_route(
+        "POST",
+        "/api/v1/comments/{id}/ban-author",
+        vec!["auth".to_string(), "admin".to_string()],
+    );
+}
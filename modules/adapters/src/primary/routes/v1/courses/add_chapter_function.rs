@@ -0,0 +1,27 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::chapter_entity::ChapterEntity;
+use domain::usecases::course_usecases::{CreateChapterParams, ManageCourseUseCase};
+
+// internal modules
+use crate::primary::middlewares::auth_middleware::AuthClaims;
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::models::response::HttpResponse;
+use crate::shared::types::AxumResponse;
+use crate::shared::utilities::validated_payload::ValidatedPayload;
+
+pub async fn execute(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    AuthClaims { account_id, .. }: AuthClaims,
+    ValidatedPayload(params): ValidatedPayload<CreateChapterParams>,
+) -> AxumResponse<ChapterEntity> {
+    match state.course_app_service.add_chapter(&account_id, &id, &params).await {
+        Ok(chapter) => Ok(HttpResponse::new(StatusCode::CREATED, "Chapter added successfully 🪼", chapter)),
+        Err(failure) => Err(HttpFailure::new(failure)),
+    }
+}
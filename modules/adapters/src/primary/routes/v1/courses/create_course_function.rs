@@ -0,0 +1,26 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::course_entity::CourseEntity;
+use domain::usecases::course_usecases::{CreateCourseParams, ManageCourseUseCase};
+
+// internal modules
+use crate::primary::middlewares::auth_middleware::AuthClaims;
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::models::response::HttpResponse;
+use crate::shared::types::AxumResponse;
+use crate::shared::utilities::validated_payload::ValidatedPayload;
+
+pub async fn execute(
+    State(state): State<Arc<AppState>>,
+    AuthClaims { account_id, .. }: AuthClaims,
+    ValidatedPayload(params): ValidatedPayload<CreateCourseParams>,
+) -> AxumResponse<CourseEntity> {
+    match state.course_app_service.create_course(&account_id, &params).await {
+        Ok(course) => Ok(HttpResponse::new(StatusCode::CREATED, "Course created successfully 🪼", course)),
+        Err(failure) => Err(HttpFailure::new(failure)),
+    }
+}
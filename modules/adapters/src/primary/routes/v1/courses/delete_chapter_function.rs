@@ -0,0 +1,24 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+// shared modules
+use domain::usecases::course_usecases::ManageCourseUseCase;
+
+// internal modules
+use crate::primary::middlewares::auth_middleware::AuthClaims;
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::models::response::HttpResponse;
+use crate::shared::types::AxumResponse;
+
+pub async fn execute(
+    State(state): State<Arc<AppState>>,
+    Path((id, chapter_id)): Path<(String, String)>,
+    AuthClaims { account_id, .. }: AuthClaims,
+) -> AxumResponse<()> {
+    match state.course_app_service.delete_chapter(&account_id, &id, &chapter_id).await {
+        Ok(_) => Ok(HttpResponse::new(StatusCode::OK, "Chapter deleted successfully 🪼", ())),
+        Err(failure) => Err(HttpFailure::new(failure)),
+    }
+}
@@ -0,0 +1,25 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::enrollment_entity::EnrollmentEntity;
+use domain::usecases::enrollment_usecases::LearnCourseUseCase;
+
+// internal modules
+use crate::primary::middlewares::auth_middleware::AuthClaims;
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::models::response::HttpResponse;
+use crate::shared::types::AxumResponse;
+
+pub async fn execute(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    AuthClaims { account_id, .. }: AuthClaims,
+) -> AxumResponse<EnrollmentEntity> {
+    match state.enrollment_app_service.enroll(&account_id, &id).await {
+        Ok(enrollment) => Ok(HttpResponse::new(StatusCode::CREATED, "Enrolled successfully 🪼", enrollment)),
+        Err(failure) => Err(HttpFailure::new(failure)),
+    }
+}
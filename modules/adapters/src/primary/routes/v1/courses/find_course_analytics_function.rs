@@ -0,0 +1,24 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+// shared modules
+use domain::usecases::enrollment_usecases::{CourseAnalytics, CourseAnalyticsUseCase};
+
+// internal modules
+use crate::primary::middlewares::auth_middleware::AuthClaims;
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::models::response::HttpResponse;
+use crate::shared::types::AxumResponse;
+
+pub async fn execute(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    AuthClaims { account_id, .. }: AuthClaims,
+) -> AxumResponse<CourseAnalytics> {
+    match state.enrollment_app_service.find_course_analytics(&account_id, &id).await {
+        Ok(analytics) => Ok(HttpResponse::new(StatusCode::OK, "Retrieved course analytics successfully 🪼", analytics)),
+        Err(failure) => Err(HttpFailure::new(failure)),
+    }
+}
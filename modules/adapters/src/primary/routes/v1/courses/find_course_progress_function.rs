@@ -0,0 +1,24 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+// shared modules
+use domain::usecases::enrollment_usecases::{CourseProgress, LearnCourseUseCase};
+
+// internal modules
+use crate::primary::middlewares::auth_middleware::AuthClaims;
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::models::response::HttpResponse;
+use crate::shared::types::AxumResponse;
+
+pub async fn execute(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    AuthClaims { account_id, .. }: AuthClaims,
+) -> AxumResponse<CourseProgress> {
+    match state.enrollment_app_service.find_course_progress(&account_id, &id).await {
+        Ok(progress) => Ok(HttpResponse::new(StatusCode::OK, "Retrieved course progress successfully 🪼", progress)),
+        Err(failure) => Err(HttpFailure::new(failure)),
+    }
+}
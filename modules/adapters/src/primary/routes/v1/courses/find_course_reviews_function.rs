@@ -0,0 +1,30 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::review_entity::ReviewEntity;
+use domain::usecases::review_usecases::{FindReviewsQuery, ManageReviewUseCase};
+
+// internal modules
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::models::response::HttpPaginatedResponse;
+use crate::shared::types::AxumPaginatedResponse;
+use crate::shared::utilities::validated_query::ValidatedQuery;
+
+pub async fn execute(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    ValidatedQuery(query): ValidatedQuery<FindReviewsQuery>,
+) -> AxumPaginatedResponse<ReviewEntity> {
+    match state.review_app_service.find_course_reviews(&id, &query).await {
+        Ok((paginate, reviews)) => Ok(HttpPaginatedResponse::new(
+            StatusCode::OK,
+            "Retrieved course reviews successfully 🪼".to_string(),
+            paginate,
+            reviews,
+        )),
+        Err(failure) => Err(HttpFailure::new(failure)),
+    }
+}
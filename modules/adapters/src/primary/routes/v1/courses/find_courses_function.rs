@@ -0,0 +1,29 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use serde_json::Value;
+use std::sync::Arc;
+
+// shared modules
+use domain::usecases::course_usecases::{BrowseCourseUseCase, FindCoursesQuery};
+
+// internal modules
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::models::response::HttpPaginatedResponse;
+use crate::shared::types::AxumPaginatedResponse;
+use crate::shared::utilities::validated_query::ValidatedQuery;
+
+pub async fn execute(
+    State(state): State<Arc<AppState>>,
+    ValidatedQuery(query): ValidatedQuery<FindCoursesQuery>,
+) -> AxumPaginatedResponse<Value> {
+    match state.course_app_service.find_courses(&query).await {
+        Ok((paginate, courses)) => Ok(HttpPaginatedResponse::new(
+            StatusCode::OK,
+            "Retrieved courses successfully 🪼".to_string(),
+            paginate,
+            courses,
+        )),
+        Err(failure) => Err(HttpFailure::new(failure)),
+    }
+}
@@ -0,0 +1,28 @@
+use axum::extract::{Path, State};
+use axum::http::{HeaderMap, StatusCode};
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::lesson_entity::LessonEntity;
+use domain::usecases::course_usecases::BrowseCourseUseCase;
+
+// internal modules
+use crate::primary::middlewares::auth_middleware::AuthClaims;
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::models::response::HttpResponse;
+use crate::shared::types::AxumResponse;
+
+pub async fn execute(
+    State(state): State<Arc<AppState>>,
+    Path((id, lesson_id)): Path<(String, String)>,
+    headers: HeaderMap,
+) -> AxumResponse<LessonEntity> {
+    // Signed in authors can also see their unpublished content
+    let viewer_id = AuthClaims::from_headers(&headers).ok().map(|claims| claims.account_id);
+
+    match state.course_app_service.find_lesson(viewer_id.as_deref(), &id, &lesson_id).await {
+        Ok(lesson) => Ok(HttpResponse::new(StatusCode::OK, "Retrieved lesson successfully 🪼", lesson)),
+        Err(failure) => Err(HttpFailure::new(failure)),
+    }
+}
@@ -0,0 +1,24 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::course_entity::CourseEntity;
+use domain::usecases::course_usecases::ManageCourseUseCase;
+
+// internal modules
+use crate::primary::middlewares::auth_middleware::AuthClaims;
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::models::response::HttpResponse;
+use crate::shared::types::AxumResponse;
+
+pub async fn execute(
+    State(state): State<Arc<AppState>>,
+    AuthClaims { account_id, .. }: AuthClaims,
+) -> AxumResponse<Vec<CourseEntity>> {
+    match state.course_app_service.find_own_courses(&account_id).await {
+        Ok(courses) => Ok(HttpResponse::new(StatusCode::OK, "Retrieved your courses successfully 🪼", courses)),
+        Err(failure) => Err(HttpFailure::new(failure)),
+    }
+}
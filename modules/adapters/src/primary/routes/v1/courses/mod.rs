@@ -0,0 +1,29 @@
+mod add_chapter_function;
+mod add_lesson_function;
+mod archive_course_function;
+mod create_course_function;
+mod create_review_function;
+mod delete_chapter_function;
+mod delete_course_function;
+mod delete_lesson_function;
+mod delete_review_function;
+mod enroll_course_function;
+mod find_course_analytics_function;
+mod find_course_function;
+mod find_course_progress_function;
+mod find_course_reviews_function;
+mod find_courses_function;
+mod find_lesson_function;
+mod find_own_courses_function;
+mod find_own_review_function;
+mod publish_course_function;
+mod record_progress_function;
+mod reorder_chapters_function;
+mod reorder_lessons_function;
+pub mod routes;
+mod unenroll_course_function;
+mod unpublish_course_function;
+mod update_chapter_function;
+mod update_course_function;
+mod update_lesson_function;
+mod update_review_function;
@@ -0,0 +1,26 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+// shared modules
+use domain::usecases::enrollment_usecases::{CourseProgress, LearnCourseUseCase, RecordProgressParams};
+
+// internal modules
+use crate::primary::middlewares::auth_middleware::AuthClaims;
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::models::response::HttpResponse;
+use crate::shared::types::AxumResponse;
+use crate::shared::utilities::validated_payload::ValidatedPayload;
+
+pub async fn execute(
+    State(state): State<Arc<AppState>>,
+    Path((id, lesson_id)): Path<(String, String)>,
+    AuthClaims { account_id, .. }: AuthClaims,
+    ValidatedPayload(params): ValidatedPayload<RecordProgressParams>,
+) -> AxumResponse<CourseProgress> {
+    match state.enrollment_app_service.record_progress(&account_id, &id, &lesson_id, &params).await {
+        Ok(progress) => Ok(HttpResponse::new(StatusCode::OK, "Recorded lesson progress successfully 🪼", progress)),
+        Err(failure) => Err(HttpFailure::new(failure)),
+    }
+}
@@ -0,0 +1,80 @@
+use axum::routing::{delete, get, post, put};
+use axum::{Router, middleware};
+use std::sync::Arc;
+
+// internal modules
+use crate::primary::middlewares::auth_middleware::auth_middleware;
+use crate::shared::di::state::AppState;
+use crate::shared::utilities::route_logger;
+
+pub fn execute() -> Router<Arc<AppState>> {
+    register_routes_for_logging();
+    let public_routes = Router::new()
+        .route("/", get(super::find_courses_function::execute))
+        .route("/{id}", get(super::find_course_function::execute))
+        .route("/{id}/lessons/{lesson_id}", get(super::find_lesson_function::execute))
+        .route("/{id}/reviews", get(super::find_course_reviews_function::execute));
+    let protected_routes = Router::new()
+        .route("/mine", get(super::find_own_courses_function::execute))
+        .route("/", post(super::create_course_function::execute))
+        .route("/{id}", put(super::update_course_function::execute))
+        .route("/{id}", delete(super::delete_course_function::execute))
+        .route("/{id}/publish", post(super::publish_course_function::execute))
+        .route("/{id}/unpublish", post(super::unpublish_course_function::execute))
+        .route("/{id}/archive", post(super::archive_course_function::execute))
+        .route("/{id}/analytics", get(super::find_course_analytics_function::execute))
+        .route("/{id}/enrollment", get(super::find_course_progress_function::execute))
+        .route("/{id}/enrollment", post(super::enroll_course_function::execute))
+        .route("/{id}/enrollment", delete(super::unenroll_course_function::execute))
+        .route("/{id}/chapters", post(super::add_chapter_function::execute))
+        .route("/{id}/chapters/order", put(super::reorder_chapters_function::execute))
+        .route("/{id}/chapters/{chapter_id}", put(super::update_chapter_function::execute))
+        .route("/{id}/chapters/{chapter_id}", delete(super::delete_chapter_function::execute))
+        .route("/{id}/chapters/{chapter_id}/lessons", post(super::add_lesson_function::execute))
+        .route("/{id}/chapters/{chapter_id}/lessons/order", put(super::reorder_lessons_function::execute))
+        .route("/{id}/lessons/{lesson_id}", put(super::update_lesson_function::execute))
+        .route("/{id}/lessons/{lesson_id}", delete(super::delete_lesson_function::execute))
+        .route("/{id}/lessons/{lesson_id}/progress", post(super::record_progress_function::execute))
+        .route("/{id}/reviews", post(super::create_review_function::execute))
+        .route("/{id}/reviews/mine", get(super::find_own_review_function::execute))
+        .route("/{id}/reviews/mine", put(super::update_review_function::execute))
+        .route("/{id}/reviews/mine", delete(super::delete_review_function::execute))
+        .layer(middleware::from_fn(auth_middleware));
+
+    Router::new().merge(public_routes).merge(protected_routes)
+}
+
+fn register_routes_for_logging() {
+    route_logger::track_route("GET", "/api/v1/courses/", vec![]);
+    route_logger::track_route("GET", "/api/v1/courses/{id}", vec![]);
+    route_logger::track_route("GET", "/api/v1/courses/{id}/lessons/{lesson_id}", vec![]);
+    route_logger::track_route("GET", "/api/v1/courses/{id}/reviews", vec![]);
+    route_logger::track_route("GET", "/api/v1/courses/mine", vec!["auth".to_string()]);
+    route_logger::track_route("POST", "/api/v1/courses/", vec!["auth".to_string()]);
+    route_logger::track_route("PUT", "/api/v1/courses/{id}", vec!["auth".to_string()]);
+    route_logger::track_route("DELETE", "/api/v1/courses/{id}", vec!["auth".to_string()]);
+    route_logger::track_route("POST", "/api/v1/courses/{id}/publish", vec!["auth".to_string()]);
+    route_logger::track_route("POST", "/api/v1/courses/{id}/unpublish", vec!["auth".to_string()]);
+    route_logger::track_route("POST", "/api/v1/courses/{id}/archive", vec!["auth".to_string()]);
+    route_logger::track_route("GET", "/api/v1/courses/{id}/analytics", vec!["auth".to_string()]);
+    route_logger::track_route("GET", "/api/v1/courses/{id}/enrollment", vec!["auth".to_string()]);
+    route_logger::track_route("POST", "/api/v1/courses/{id}/enrollment", vec!["auth".to_string()]);
+    route_logger::track_route("DELETE", "/api/v1/courses/{id}/enrollment", vec!["auth".to_string()]);
+    route_logger::track_route("POST", "/api/v1/courses/{id}/chapters", vec!["auth".to_string()]);
+    route_logger::track_route("PUT", "/api/v1/courses/{id}/chapters/order", vec!["auth".to_string()]);
+    route_logger::track_route("PUT", "/api/v1/courses/{id}/chapters/{chapter_id}", vec!["auth".to_string()]);
+    route_logger::track_route("DELETE", "/api/v1/courses/{id}/chapters/{chapter_id}", vec!["auth".to_string()]);
+    route_logger::track_route("POST", "/api/v1/courses/{id}/chapters/{chapter_id}/lessons", vec!["auth".to_string()]);
+    route_logger::track_route(
+        "PUT",
+        "/api/v1/courses/{id}/chapters/{chapter_id}/lessons/order",
+        vec!["auth".to_string()],
+    );
+    route_logger::track_route("PUT", "/api/v1/courses/{id}/lessons/{lesson_id}", vec!["auth".to_string()]);
+    route_logger::track_route("DELETE", "/api/v1/courses/{id}/lessons/{lesson_id}", vec!["auth".to_string()]);
+    route_logger::track_route("POST", "/api/v1/courses/{id}/lessons/{lesson_id}/progress", vec!["auth".to_string()]);
+    route_logger::track_route("POST", "/api/v1/courses/{id}/reviews", vec!["auth".to_string()]);
+    route_logger::track_route("GET", "/api/v1/courses/{id}/reviews/mine", vec!["auth".to_string()]);
+    route_logger::track_route("PUT", "/api/v1/courses/{id}/reviews/mine", vec!["auth".to_string()]);
+    route_logger::track_route("DELETE", "/api/v1/courses/{id}/reviews/mine", vec!["auth".to_string()]);
+}
@@ -0,0 +1,25 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::course_entity::CourseEntity;
+use domain::usecases::course_usecases::ManageCourseUseCase;
+
+// internal modules
+use crate::primary::middlewares::auth_middleware::AuthClaims;
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::models::response::HttpResponse;
+use crate::shared::types::AxumResponse;
+
+pub async fn execute(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    AuthClaims { account_id, .. }: AuthClaims,
+) -> AxumResponse<CourseEntity> {
+    match state.course_app_service.unpublish_course(&account_id, &id).await {
+        Ok(course) => Ok(HttpResponse::new(StatusCode::OK, "Course unpublished successfully 🪼", course)),
+        Err(failure) => Err(HttpFailure::new(failure)),
+    }
+}
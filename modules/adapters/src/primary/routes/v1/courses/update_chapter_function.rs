@@ -0,0 +1,27 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::chapter_entity::ChapterEntity;
+use domain::usecases::course_usecases::{ManageCourseUseCase, UpdateChapterParams};
+
+// internal modules
+use crate::primary::middlewares::auth_middleware::AuthClaims;
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::models::response::HttpResponse;
+use crate::shared::types::AxumResponse;
+use crate::shared::utilities::validated_payload::ValidatedPayload;
+
+pub async fn execute(
+    State(state): State<Arc<AppState>>,
+    Path((id, chapter_id)): Path<(String, String)>,
+    AuthClaims { account_id, .. }: AuthClaims,
+    ValidatedPayload(params): ValidatedPayload<UpdateChapterParams>,
+) -> AxumResponse<ChapterEntity> {
+    match state.course_app_service.update_chapter(&account_id, &id, &chapter_id, &params).await {
+        Ok(chapter) => Ok(HttpResponse::new(StatusCode::OK, "Chapter updated successfully 🪼", chapter)),
+        Err(failure) => Err(HttpFailure::new(failure)),
+    }
+}
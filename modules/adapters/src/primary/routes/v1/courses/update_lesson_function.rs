@@ -0,0 +1,27 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::lesson_entity::LessonEntity;
+use domain::usecases::course_usecases::{ManageCourseUseCase, UpdateLessonParams};
+
+// internal modules
+use crate::primary::middlewares::auth_middleware::AuthClaims;
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::models::response::HttpResponse;
+use crate::shared::types::AxumResponse;
+use crate::shared::utilities::validated_payload::ValidatedPayload;
+
+pub async fn execute(
+    State(state): State<Arc<AppState>>,
+    Path((id, lesson_id)): Path<(String, String)>,
+    AuthClaims { account_id, .. }: AuthClaims,
+    ValidatedPayload(params): ValidatedPayload<UpdateLessonParams>,
+) -> AxumResponse<LessonEntity> {
+    match state.course_app_service.update_lesson(&account_id, &id, &lesson_id, &params).await {
+        Ok(lesson) => Ok(HttpResponse::new(StatusCode::OK, "Lesson updated successfully 🪼", lesson)),
+        Err(failure) => Err(HttpFailure::new(failure)),
+    }
+}
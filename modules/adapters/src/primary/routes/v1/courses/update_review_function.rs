@@ -0,0 +1,27 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::review_entity::ReviewEntity;
+use domain::usecases::review_usecases::{ManageReviewUseCase, UpdateReviewParams};
+
+// internal modules
+use crate::primary::middlewares::auth_middleware::AuthClaims;
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::models::response::HttpResponse;
+use crate::shared::types::AxumResponse;
+use crate::shared::utilities::validated_payload::ValidatedPayload;
+
+pub async fn execute(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    AuthClaims { account_id, .. }: AuthClaims,
+    ValidatedPayload(params): ValidatedPayload<UpdateReviewParams>,
+) -> AxumResponse<ReviewEntity> {
+    match state.review_app_service.update_review(&account_id, &id, &params).await {
+        Ok(review) => Ok(HttpResponse::new(StatusCode::OK, "Review updated successfully 🪼", review)),
+        Err(failure) => Err(HttpFailure::new(failure)),
+    }
+}
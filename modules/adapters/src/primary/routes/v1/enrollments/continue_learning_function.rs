@@ -0,0 +1,23 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+// shared modules
+use domain::usecases::enrollment_usecases::{ContinueLearning, LearnCourseUseCase};
+
+// internal modules
+use crate::primary::middlewares::auth_middleware::AuthClaims;
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::models::response::HttpResponse;
+use crate::shared::types::AxumResponse;
+
+pub async fn execute(
+    State(state): State<Arc<AppState>>,
+    AuthClaims { account_id, .. }: AuthClaims,
+) -> AxumResponse<ContinueLearning> {
+    match state.enrollment_app_service.continue_learning(&account_id).await {
+        Ok(next) => Ok(HttpResponse::new(StatusCode::OK, "Retrieved next lesson successfully 🪼", next)),
+        Err(failure) => Err(HttpFailure::new(failure)),
+    }
+}
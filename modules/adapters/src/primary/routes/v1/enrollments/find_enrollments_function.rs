@@ -0,0 +1,24 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::enrollment_entity::EnrollmentEntity;
+use domain::usecases::enrollment_usecases::LearnCourseUseCase;
+
+// internal modules
+use crate::primary::middlewares::auth_middleware::AuthClaims;
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::models::response::HttpResponse;
+use crate::shared::types::AxumResponse;
+
+pub async fn execute(
+    State(state): State<Arc<AppState>>,
+    AuthClaims { account_id, .. }: AuthClaims,
+) -> AxumResponse<Vec<EnrollmentEntity>> {
+    match state.enrollment_app_service.find_enrollments(&account_id).await {
+        Ok(enrollments) => Ok(HttpResponse::new(StatusCode::OK, "Retrieved enrollments successfully 🪼", enrollments)),
+        Err(failure) => Err(HttpFailure::new(failure)),
+    }
+}
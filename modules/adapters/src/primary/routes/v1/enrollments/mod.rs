@@ -0,0 +1,3 @@
+mod continue_learning_function;
+mod find_enrollments_function;
+pub mod routes;
@@ -0,0 +1,21 @@
+use axum::routing::get;
+use axum::{Router, middleware};
+use std::sync::Arc;
+
+// internal modules
+use crate::primary::middlewares::auth_middleware::auth_middleware;
+use crate::shared::di::state::AppState;
+use crate::shared::utilities::route_logger;
+
+pub fn execute() -> Router<Arc<AppState>> {
+    register_routes_for_logging();
+    Router::new()
+        .route("/", get(super::find_enrollments_function::execute))
+        .route("/continue", get(super::continue_learning_function::execute))
+        .layer(middleware::from_fn(auth_middleware))
+}
+
+fn register_routes_for_logging() {
+    route_logger::track_route("GET", "/api/v1/enrollments/", vec!["auth".to_string()]);
+    route_logger::track_route("GET", "/api/v1/enrollments/continue", vec!["auth".to_string()]);
+}
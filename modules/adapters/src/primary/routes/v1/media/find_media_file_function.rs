@@ -0,0 +1,26 @@
+use axum::extract::{Path, State};
+use axum::http::header::{CACHE_CONTROL, CONTENT_TYPE};
+use axum::http::{HeaderValue, StatusCode};
+use axum::response::{IntoResponse, Response};
+use std::sync::Arc;
+
+// shared modules
+use domain::usecases::media_usecases::ManageMediaUseCase;
+
+// internal modules
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+
+/// Serve a locally stored file. Keys are never reused, so clients may cache them for good.
+pub async fn execute(State(state): State<Arc<AppState>>, Path(key): Path<String>) -> Result<Response, HttpFailure> {
+    let blob = state.media_app_service.find_file(&key).await.map_err(HttpFailure::new)?;
+    let content_type =
+        HeaderValue::from_str(&blob.content_type).unwrap_or(HeaderValue::from_static("application/octet-stream"));
+
+    let mut response = (StatusCode::OK, blob.bytes).into_response();
+    let headers = response.headers_mut();
+    headers.insert(CONTENT_TYPE, content_type);
+    headers.insert(CACHE_CONTROL, HeaderValue::from_static("public, max-age=31536000, immutable"));
+
+    Ok(response)
+}
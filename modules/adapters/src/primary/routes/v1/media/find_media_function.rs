@@ -0,0 +1,20 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::media_asset_entity::MediaAssetEntity;
+use domain::usecases::media_usecases::ManageMediaUseCase;
+
+// internal modules
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::models::response::HttpResponse;
+use crate::shared::types::AxumResponse;
+
+pub async fn execute(State(state): State<Arc<AppState>>, Path(id): Path<String>) -> AxumResponse<MediaAssetEntity> {
+    match state.media_app_service.find_asset(&id).await {
+        Ok(asset) => Ok(HttpResponse::new(StatusCode::OK, "Media found successfully 🪼", asset)),
+        Err(failure) => Err(HttpFailure::new(failure)),
+    }
+}
@@ -0,0 +1,7 @@
+mod delete_media_function;
+mod find_media_file_function;
+mod find_media_function;
+pub mod routes;
+mod upload_avatar_function;
+mod upload_blog_image_function;
+mod upload_project_cover_function;
@@ -0,0 +1,51 @@
+use axum::extract::DefaultBodyLimit;
+use axum::routing::{delete, get, post};
+use axum::{Router, middleware};
+use std::sync::Arc;
+
+// shared modules
+use shared::configs::APP_CONFIG;
+
+// internal modules
+use crate::primary::middlewares::auth_middleware::auth_middleware;
+use crate::primary::middlewares::role_middleware::require_admin;
+use crate::shared::di::state::AppState;
+use crate::shared::utilities::route_logger;
+
+// Room for the multipart boundaries and headers around the file
+static MULTIPART_OVERHEAD_BYTES: usize = 64 * 1024;
+
+pub fn execute() -> Router<Arc<AppState>> {
+    register_routes_for_logging();
+    let upload_limit = DefaultBodyLimit::max(APP_CONFIG.media.max_upload_bytes + MULTIPART_OVERHEAD_BYTES);
+
+    let public_routes = Router::new()
+        .route("/files/{*key}", get(super::find_media_file_function::execute))
+        .route("/{id}", get(super::find_media_function::execute));
+    let protected_routes = Router::new()
+        .route("/avatar", post(super::upload_avatar_function::execute))
+        .route("/blogs/{blog_id}/images", post(super::upload_blog_image_function::execute))
+        .route("/{id}", delete(super::delete_media_function::execute))
+        .layer(upload_limit)
+        .layer(middleware::from_fn(auth_middleware));
+    let admin_routes = Router::new()
+        .route("/projects/{project_id}/cover", post(super::upload_project_cover_function::execute))
+        .layer(upload_limit)
+        .layer(middleware::from_fn(require_admin))
+        .layer(middleware::from_fn(auth_middleware));
+
+    Router::new().merge(public_routes).merge(protected_routes).merge(admin_routes)
+}
+
+fn register_routes_for_logging() {
+    route_logger::track_route("GET", "/api/v1/media/files/{*key}", vec![]);
+    route_logger::track_route("GET", "/api/v1/media/{id}", vec![]);
+    route_logger::track_route("POST", "/api/v1/media/avatar", vec!["auth".to_string()]);
+    route_logger::track_route("POST", "/api/v1/media/blogs/{blog_id}/images", vec!["auth".to_string()]);
+    route_logger::track_route("DELETE", "/api/v1/media/{id}", vec!["auth".to_string()]);
+    route_logger::track_route(
+        "POST",
+        "/api/v1/media/projects/{project_id}/cover",
+        vec!["auth".to_string(), "admin".to_string()],
+    );
+}
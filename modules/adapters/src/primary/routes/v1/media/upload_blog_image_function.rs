@@ -0,0 +1,27 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::media_asset_entity::MediaAssetEntity;
+use domain::usecases::media_usecases::UploadMediaUseCase;
+
+// internal modules
+use crate::primary::middlewares::auth_middleware::AuthClaims;
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::models::response::HttpResponse;
+use crate::shared::types::AxumResponse;
+use crate::shared::utilities::multipart_upload::MultipartUpload;
+
+pub async fn execute(
+    State(state): State<Arc<AppState>>,
+    Path(blog_id): Path<String>,
+    AuthClaims { account_id, .. }: AuthClaims,
+    MultipartUpload(upload): MultipartUpload,
+) -> AxumResponse<MediaAssetEntity> {
+    match state.media_app_service.upload_blog_image(&account_id, &blog_id, &upload).await {
+        Ok(asset) => Ok(HttpResponse::new(StatusCode::CREATED, "Blog image uploaded successfully 🪼", asset)),
+        Err(failure) => Err(HttpFailure::new(failure)),
+    }
+}
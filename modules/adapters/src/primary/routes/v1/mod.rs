@@ -1,6 +1,18 @@
 pub mod accounts;
+pub mod audit_events;
 pub mod auth;
 pub mod blogs;
+pub mod bookmarks;
 pub mod categories;
+pub mod comments;
+pub mod courses;
+pub mod enrollments;
 pub mod globals;
+pub mod media;
+pub mod notifications;
+pub mod realtime;
+pub mod reviews;
+pub mod search;
 pub mod sessions;
+pub mod uploads;
+pub mod users;
@@ -0,0 +1,25 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+// shared modules
+use domain::usecases::notification_usecases::{ManageNotificationPreferenceUseCase, NotificationPreferences};
+
+// internal modules
+use crate::primary::middlewares::auth_middleware::AuthClaims;
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::models::response::HttpResponse;
+use crate::shared::types::AxumResponse;
+
+pub async fn execute(
+    State(state): State<Arc<AppState>>,
+    AuthClaims { account_id, .. }: AuthClaims,
+) -> AxumResponse<NotificationPreferences> {
+    match state.notification_app_service.find_preferences(&account_id).await {
+        Ok(preferences) => {
+            Ok(HttpResponse::new(StatusCode::OK, "Found notification preferences successfully 🪼", preferences))
+        },
+        Err(failure) => Err(HttpFailure::new(failure)),
+    }
+}
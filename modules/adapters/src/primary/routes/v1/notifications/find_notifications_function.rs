@@ -0,0 +1,31 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::notification_entity::NotificationEntity;
+use domain::usecases::notification_usecases::{FindNotificationsQuery, ManageNotificationUseCase};
+
+// internal modules
+use crate::primary::middlewares::auth_middleware::AuthClaims;
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::models::response::HttpPaginatedResponse;
+use crate::shared::types::AxumPaginatedResponse;
+use crate::shared::utilities::validated_query::ValidatedQuery;
+
+pub async fn execute(
+    State(state): State<Arc<AppState>>,
+    AuthClaims { account_id, .. }: AuthClaims,
+    ValidatedQuery(query): ValidatedQuery<FindNotificationsQuery>,
+) -> AxumPaginatedResponse<NotificationEntity> {
+    match state.notification_app_service.find_notifications(&account_id, &query).await {
+        Ok((paginate, notifications)) => Ok(HttpPaginatedResponse::new(
+            StatusCode::OK,
+            "Retrieved notifications successfully 🪼".to_string(),
+            paginate,
+            notifications,
+        )),
+        Err(failure) => Err(HttpFailure::new(failure)),
+    }
+}
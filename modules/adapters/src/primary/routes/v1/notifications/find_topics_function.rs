@@ -0,0 +1,24 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::topic_subscription_entity::TopicSubscriptionEntity;
+use domain::usecases::notification_usecases::ManagePushSubscriptionUseCase;
+
+// internal modules
+use crate::primary::middlewares::auth_middleware::AuthClaims;
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::models::response::HttpResponse;
+use crate::shared::types::AxumResponse;
+
+pub async fn execute(
+    State(state): State<Arc<AppState>>,
+    AuthClaims { account_id, .. }: AuthClaims,
+) -> AxumResponse<Vec<TopicSubscriptionEntity>> {
+    match state.notification_app_service.find_topics(&account_id).await {
+        Ok(topics) => Ok(HttpResponse::new(StatusCode::OK, "Found topics successfully 🪼", topics)),
+        Err(failure) => Err(HttpFailure::new(failure)),
+    }
+}
@@ -0,0 +1,23 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+// shared modules
+use domain::usecases::notification_usecases::{ManageNotificationUseCase, MarkAllReadResult};
+
+// internal modules
+use crate::primary::middlewares::auth_middleware::AuthClaims;
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::models::response::HttpResponse;
+use crate::shared::types::AxumResponse;
+
+pub async fn execute(
+    State(state): State<Arc<AppState>>,
+    AuthClaims { account_id, .. }: AuthClaims,
+) -> AxumResponse<MarkAllReadResult> {
+    match state.notification_app_service.mark_all_read(&account_id).await {
+        Ok(result) => Ok(HttpResponse::new(StatusCode::OK, "Marked all notifications as read successfully 🪼", result)),
+        Err(failure) => Err(HttpFailure::new(failure)),
+    }
+}
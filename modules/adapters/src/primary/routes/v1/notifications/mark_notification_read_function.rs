@@ -0,0 +1,27 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::notification_entity::NotificationEntity;
+use domain::usecases::notification_usecases::ManageNotificationUseCase;
+
+// internal modules
+use crate::primary::middlewares::auth_middleware::AuthClaims;
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::models::response::HttpResponse;
+use crate::shared::types::AxumResponse;
+
+pub async fn execute(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    AuthClaims { account_id, .. }: AuthClaims,
+) -> AxumResponse<NotificationEntity> {
+    match state.notification_app_service.mark_read(&account_id, &id).await {
+        Ok(notification) => {
+            Ok(HttpResponse::new(StatusCode::OK, "Marked notification as read successfully 🪼", notification))
+        },
+        Err(failure) => Err(HttpFailure::new(failure)),
+    }
+}
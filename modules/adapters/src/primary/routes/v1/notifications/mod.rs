@@ -0,0 +1,15 @@
+mod count_unread_notifications_function;
+mod delete_notification_function;
+mod find_notification_preferences_function;
+mod find_notifications_function;
+mod find_topics_function;
+mod mark_all_notifications_read_function;
+mod mark_notification_read_function;
+mod push_to_account_function;
+mod push_to_topic_function;
+mod register_device_function;
+pub mod routes;
+mod subscribe_topic_function;
+mod unregister_device_function;
+mod unsubscribe_topic_function;
+mod update_notification_preferences_function;
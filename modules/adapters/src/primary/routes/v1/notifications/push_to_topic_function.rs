@@ -0,0 +1,24 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+// shared modules
+use domain::usecases::notification_usecases::{PushNotification, PushNotificationUseCase, SendNotificationResponse};
+
+// internal modules
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::models::response::HttpResponse;
+use crate::shared::types::AxumResponse;
+use crate::shared::utilities::validated_payload::ValidatedPayload;
+
+pub async fn execute(
+    State(state): State<Arc<AppState>>,
+    Path(topic): Path<String>,
+    ValidatedPayload(params): ValidatedPayload<PushNotification>,
+) -> AxumResponse<SendNotificationResponse> {
+    match state.notification_app_service.send_to_topic(&topic, &params).await {
+        Ok(response) => Ok(HttpResponse::new(StatusCode::OK, "Sent push notification successfully 🪼", response)),
+        Err(failure) => Err(HttpFailure::new(failure)),
+    }
+}
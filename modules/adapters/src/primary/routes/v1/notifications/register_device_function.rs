@@ -0,0 +1,25 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+// shared modules
+use domain::usecases::notification_usecases::{ManagePushSubscriptionUseCase, RegisterDeviceParams};
+
+// internal modules
+use crate::primary::middlewares::auth_middleware::AuthClaims;
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::models::response::HttpResponse;
+use crate::shared::types::AxumResponse;
+use crate::shared::utilities::validated_payload::ValidatedPayload;
+
+pub async fn execute(
+    State(state): State<Arc<AppState>>,
+    AuthClaims { account_id, jti, .. }: AuthClaims,
+    ValidatedPayload(params): ValidatedPayload<RegisterDeviceParams>,
+) -> AxumResponse<()> {
+    match state.notification_app_service.register_device(&account_id, &jti, &params).await {
+        Ok(_) => Ok(HttpResponse::new(StatusCode::OK, "Registered device successfully 🪼", ())),
+        Err(failure) => Err(HttpFailure::new(failure)),
+    }
+}
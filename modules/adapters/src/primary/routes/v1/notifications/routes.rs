@@ -0,0 +1,59 @@
+use axum::routing::{delete, get, post, put};
+use axum::{Router, middleware};
+use std::sync::Arc;
+
+// internal modules
+use crate::primary::middlewares::auth_middleware::auth_middleware;
+use crate::primary::middlewares::role_middleware::require_admin;
+use crate::shared::di::state::AppState;
+use crate::shared::utilities::route_logger;
+
+pub fn execute() -> Router<Arc<AppState>> {
+    register_routes_for_logging();
+    let protected_routes = Router::new()
+        .route("/", get(super::find_notifications_function::execute))
+        .route("/unread-count", get(super::count_unread_notifications_function::execute))
+        .route("/read-all", post(super::mark_all_notifications_read_function::execute))
+        .route("/preferences", get(super::find_notification_preferences_function::execute))
+        .route("/preferences", put(super::update_notification_preferences_function::execute))
+        .route("/device", put(super::register_device_function::execute))
+        .route("/device", delete(super::unregister_device_function::execute))
+        .route("/topics", get(super::find_topics_function::execute))
+        .route("/topics/{topic}", put(super::subscribe_topic_function::execute))
+        .route("/topics/{topic}", delete(super::unsubscribe_topic_function::execute))
+        .route("/{id}", delete(super::delete_notification_function::execute))
+        .route("/{id}/read", post(super::mark_notification_read_function::execute))
+        .layer(middleware::from_fn(auth_middleware));
+    let admin_routes = Router::new()
+        .route("/push/accounts/{account_id}", post(super::push_to_account_function::execute))
+        .route("/push/topics/{topic}", post(super::push_to_topic_function::execute))
+        .layer(middleware::from_fn(require_admin))
+        .layer(middleware::from_fn(auth_middleware));
+
+    Router::new().merge(protected_routes).merge(admin_routes)
+}
+
+fn register_routes_for_logging() {
+    route_logger::track_route("GET", "/api/v1/notifications/", vec!["auth".to_string()]);
+    route_logger::track_route("GET", "/api/v1/notifications/unread-count", vec!["auth".to_string()]);
+    route_logger::track_route("POST", "/api/v1/notifications/read-all", vec!["auth".to_string()]);
+    route_logger::track_route("GET", "/api/v1/notifications/preferences", vec!["auth".to_string()]);
+    route_logger::track_route("PUT", "/api/v1/notifications/preferences", vec!["auth".to_string()]);
+    route_logger::track_route("PUT", "/api/v1/notifications/device", vec!["auth".to_string()]);
+    route_logger::track_route("DELETE", "/api/v1/notifications/device", vec!["auth".to_string()]);
+    route_logger::track_route("GET", "/api/v1/notifications/topics", vec!["auth".to_string()]);
+    route_logger::track_route("PUT", "/api/v1/notifications/topics/{topic}", vec!["auth".to_string()]);
+    route_logger::track_route("DELETE", "/api/v1/notifications/topics/{topic}", vec!["auth".to_string()]);
+    route_logger::track_route("DELETE", "/api/v1/notifications/{id}", vec!["auth".to_string()]);
+    route_logger::track_route("POST", "/api/v1/notifications/{id}/read", vec!["auth".to_string()]);
+    route_logger::track_route(
+        "POST",
+        "/api/v1/notifications/push/accounts/{account_id}",
+        vec!["auth".to_string(), "admin".to_string()],
+    );
+    route_logger::track_route(
+        "POST",
+        "/api/v1/notifications/push/topics/{topic}",
+        vec!["auth".to_string(), "admin".to_string()],
+    );
+}
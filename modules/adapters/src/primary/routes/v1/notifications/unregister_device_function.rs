@@ -0,0 +1,23 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+// shared modules
+use domain::usecases::notification_usecases::ManagePushSubscriptionUseCase;
+
+// internal modules
+use crate::primary::middlewares::auth_middleware::AuthClaims;
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::models::response::HttpResponse;
+use crate::shared::types::AxumResponse;
+
+pub async fn execute(
+    State(state): State<Arc<AppState>>,
+    AuthClaims { account_id, jti, .. }: AuthClaims,
+) -> AxumResponse<()> {
+    match state.notification_app_service.unregister_device(&account_id, &jti).await {
+        Ok(_) => Ok(HttpResponse::new(StatusCode::OK, "Unregistered device successfully 🪼", ())),
+        Err(failure) => Err(HttpFailure::new(failure)),
+    }
+}
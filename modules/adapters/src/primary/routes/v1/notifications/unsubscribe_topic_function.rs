@@ -0,0 +1,24 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+// shared modules
+use domain::usecases::notification_usecases::ManagePushSubscriptionUseCase;
+
+// internal modules
+use crate::primary::middlewares::auth_middleware::AuthClaims;
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::models::response::HttpResponse;
+use crate::shared::types::AxumResponse;
+
+pub async fn execute(
+    State(state): State<Arc<AppState>>,
+    Path(topic): Path<String>,
+    AuthClaims { account_id, .. }: AuthClaims,
+) -> AxumResponse<()> {
+    match state.notification_app_service.unsubscribe_topic(&account_id, &topic).await {
+        Ok(_) => Ok(HttpResponse::new(StatusCode::OK, "Unsubscribed from topic successfully 🪼", ())),
+        Err(failure) => Err(HttpFailure::new(failure)),
+    }
+}
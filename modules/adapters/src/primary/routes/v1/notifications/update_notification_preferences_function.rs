@@ -0,0 +1,29 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+// shared modules
+use domain::usecases::notification_usecases::{
+    ManageNotificationPreferenceUseCase, NotificationPreferences, UpdateNotificationPreferencesParams,
+};
+
+// internal modules
+use crate::primary::middlewares::auth_middleware::AuthClaims;
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::models::response::HttpResponse;
+use crate::shared::types::AxumResponse;
+use crate::shared::utilities::validated_payload::ValidatedPayload;
+
+pub async fn execute(
+    State(state): State<Arc<AppState>>,
+    AuthClaims { account_id, .. }: AuthClaims,
+    ValidatedPayload(params): ValidatedPayload<UpdateNotificationPreferencesParams>,
+) -> AxumResponse<NotificationPreferences> {
+    match state.notification_app_service.update_preferences(&account_id, &params).await {
+        Ok(preferences) => {
+            Ok(HttpResponse::new(StatusCode::OK, "Updated notification preferences successfully 🪼", preferences))
+        },
+        Err(failure) => Err(HttpFailure::new(failure)),
+    }
+}
@@ -0,0 +1,5 @@
+mod realtime_query;
+pub mod routes;
+mod session_watch;
+mod stream_events_function;
+mod stream_websocket_function;
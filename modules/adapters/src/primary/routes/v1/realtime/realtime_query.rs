@@ -0,0 +1,10 @@
+use serde::Deserialize;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct RealtimeQuery {
+    /// Id of the last event the client received, the events after it are replayed first.
+    #[validate(length(max = 64, message = "Last event id is too long"))]
+    pub last_event_id: Option<String>,
+}
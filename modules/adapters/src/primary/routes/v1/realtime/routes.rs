@@ -0,0 +1,21 @@
+use axum::routing::get;
+use axum::{Router, middleware};
+use std::sync::Arc;
+
+// internal modules
+use crate::primary::middlewares::auth_middleware::stream_auth_middleware;
+use crate::shared::di::state::AppState;
+use crate::shared::utilities::route_logger;
+
+pub fn execute() -> Router<Arc<AppState>> {
+    register_routes_for_logging();
+    Router::new()
+        .route("/ws", get(super::stream_websocket_function::execute))
+        .route("/events", get(super::stream_events_function::execute))
+        .layer(middleware::from_fn(stream_auth_middleware))
+}
+
+fn register_routes_for_logging() {
+    route_logger::track_route("GET", "/api/v1/realtime/ws", vec!["auth".to_string()]);
+    route_logger::track_route("GET", "/api/v1/realtime/events", vec!["auth".to_string()]);
+}
@@ -0,0 +1,27 @@
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::{MissedTickBehavior, interval};
+
+// shared modules
+use domain::usecases::auth_usecases::ManageSessionAuthUseCase;
+use shared::configs::APP_CONFIG;
+use shared::models::failure::Failure;
+
+// internal modules
+use crate::shared::di::state::AppState;
+
+/// Resolves once the session a stream was opened with ends, whether it was signed out, revoked or its account
+/// suspended. It is checked on every heartbeat, a failed lookup keeps the stream open until the next one.
+pub async fn session_ended(state: Arc<AppState>, account_id: String, jti: String) {
+    let mut ticker = interval(Duration::from_secs(APP_CONFIG.realtime.heartbeat_seconds));
+    ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    ticker.tick().await;
+
+    loop {
+        ticker.tick().await;
+        let checked = state.auth_app_service.ensure_session_active(&account_id, &jti).await;
+        if matches!(checked, Err(Failure::Unauthorized(_) | Failure::Forbidden(_))) {
+            return;
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use axum::extract::State;
+use axum::http::HeaderMap;
+use axum::response::sse::{Event, KeepAlive, Sse};
+use futures::{Stream, StreamExt};
+use std::sync::Arc;
+use std::time::Duration;
+
+// shared modules
+use shared::configs::APP_CONFIG;
+
+// internal modules
+use super::realtime_query::RealtimeQuery;
+use super::session_watch::session_ended;
+use crate::primary::middlewares::auth_middleware::AuthClaims;
+use crate::shared::di::state::AppState;
+use crate::shared::utilities::validated_query::ValidatedQuery;
+
+/// Server-sent events fallback for clients that can not open a WebSocket.
+pub async fn execute(
+    State(state): State<Arc<AppState>>,
+    AuthClaims { account_id, jti, .. }: AuthClaims,
+    headers: HeaderMap,
+    ValidatedQuery(query): ValidatedQuery<RealtimeQuery>,
+) -> Sse<impl Stream<Item = Result<Event, axum::Error>>> {
+    // EventSource sends the Last-Event-ID header when it reconnects, the query covers the first connection
+    let last_event_id =
+        headers.get("Last-Event-ID").and_then(|value| value.to_str().ok()).map(str::to_string).or(query.last_event_id);
+
+    let events = state
+        .event_hub
+        .stream(&account_id, last_event_id.as_deref())
+        .map(|event| Event::default().id(&event.id).event(&event.event).json_data(&event))
+        .take_until(session_ended(state.clone(), account_id.clone(), jti));
+
+    let heartbeat = Duration::from_secs(APP_CONFIG.realtime.heartbeat_seconds);
+    Sse::new(events).keep_alive(KeepAlive::new().interval(heartbeat).text("heartbeat"))
+}
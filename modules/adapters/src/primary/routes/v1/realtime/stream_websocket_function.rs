@@ -0,0 +1,79 @@
+use axum::body::Bytes;
+use axum::extract::State;
+use axum::extract::ws::{CloseFrame, Message, WebSocket, WebSocketUpgrade, close_code};
+use axum::response::Response;
+use futures::{SinkExt, StreamExt};
+use std::pin::pin;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::{Instant, MissedTickBehavior, interval};
+
+// shared modules
+use shared::configs::APP_CONFIG;
+
+// internal modules
+use super::realtime_query::RealtimeQuery;
+use super::session_watch::session_ended;
+use crate::primary::middlewares::auth_middleware::AuthClaims;
+use crate::shared::di::state::AppState;
+use crate::shared::utilities::validated_query::ValidatedQuery;
+
+pub async fn execute(
+    websocket: WebSocketUpgrade,
+    State(state): State<Arc<AppState>>,
+    AuthClaims { account_id, jti, .. }: AuthClaims,
+    ValidatedQuery(query): ValidatedQuery<RealtimeQuery>,
+) -> Response {
+    websocket.on_upgrade(move |socket| serve(socket, state, account_id, jti, query.last_event_id))
+}
+
+async fn serve(
+    socket: WebSocket,
+    state: Arc<AppState>,
+    account_id: String,
+    jti: String,
+    last_event_id: Option<String>,
+) {
+    let (mut sender, mut receiver) = socket.split();
+    let mut events = pin!(state.event_hub.stream(&account_id, last_event_id.as_deref()));
+    let mut ended = pin!(session_ended(state.clone(), account_id.clone(), jti));
+
+    let heartbeat = Duration::from_secs(APP_CONFIG.realtime.heartbeat_seconds);
+    let mut ticker = interval(heartbeat);
+    ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    let mut last_seen = Instant::now();
+
+    loop {
+        tokio::select! {
+            event = events.next() => {
+                let Some(event) = event else { break };
+                let Ok(text) = serde_json::to_string(&event) else { continue };
+
+                // Sending waits for the socket, a slow client lags behind and gets caught up by replay
+                if sender.send(Message::Text(text.into())).await.is_err() {
+                    break;
+                }
+            },
+            message = receiver.next() => match message {
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                Some(Ok(_)) => last_seen = Instant::now(),
+            },
+            _ = &mut ended => {
+                let frame = CloseFrame { code: close_code::POLICY, reason: "Session has ended".into() };
+                let _ = sender.send(Message::Close(Some(frame))).await;
+                break;
+            },
+            _ = ticker.tick() => {
+                // Pongs keep the session alive, a client that missed two heartbeats is gone
+                if last_seen.elapsed() > heartbeat * 2 {
+                    break;
+                }
+                if sender.send(Message::Ping(Bytes::new())).await.is_err() {
+                    break;
+                }
+            },
+        }
+    }
+
+    tracing::debug!("📡 WebSocket session of {} closed", account_id);
+}
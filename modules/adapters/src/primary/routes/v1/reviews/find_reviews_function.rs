@@ -0,0 +1,29 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::review_entity::ReviewEntity;
+use domain::usecases::review_usecases::{FindModeratedReviewsQuery, ModerateReviewUseCase};
+
+// internal modules
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::models::response::HttpPaginatedResponse;
+use crate::shared::types::AxumPaginatedResponse;
+use crate::shared::utilities::validated_query::ValidatedQuery;
+
+pub async fn execute(
+    State(state): State<Arc<AppState>>,
+    ValidatedQuery(query): ValidatedQuery<FindModeratedReviewsQuery>,
+) -> AxumPaginatedResponse<ReviewEntity> {
+    match state.review_app_service.find_reviews(&query).await {
+        Ok((paginate, reviews)) => Ok(HttpPaginatedResponse::new(
+            StatusCode::OK,
+            "Retrieved reviews successfully 🪼".to_string(),
+            paginate,
+            reviews,
+        )),
+        Err(failure) => Err(HttpFailure::new(failure)),
+    }
+}
@@ -0,0 +1,20 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::review_entity::ReviewEntity;
+use domain::usecases::review_usecases::ModerateReviewUseCase;
+
+// internal modules
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::models::response::HttpResponse;
+use crate::shared::types::AxumResponse;
+
+pub async fn execute(State(state): State<Arc<AppState>>, Path(id): Path<String>) -> AxumResponse<ReviewEntity> {
+    match state.review_app_service.hide_review(&id).await {
+        Ok(review) => Ok(HttpResponse::new(StatusCode::OK, "Review hidden successfully 🪼", review)),
+        Err(failure) => Err(HttpFailure::new(failure)),
+    }
+}
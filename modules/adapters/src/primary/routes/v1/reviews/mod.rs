@@ -0,0 +1,5 @@
+mod approve_review_function;
+mod find_reviews_function;
+mod hide_review_function;
+mod remove_review_function;
+pub mod routes;
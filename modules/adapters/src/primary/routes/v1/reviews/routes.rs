@@ -0,0 +1,27 @@
+use axum::routing::{delete, get, post};
+use axum::{Router, middleware};
+use std::sync::Arc;
+
+// internal modules
+use crate::primary::middlewares::auth_middleware::auth_middleware;
+use crate::primary::middlewares::role_middleware::require_admin;
+use crate::shared::di::state::AppState;
+use crate::shared::utilities::route_logger;
+
+pub fn execute() -> Router<Arc<AppState>> {
+    register_routes_for_logging();
+    Router::new()
+        .route("/", get(super::find_reviews_function::execute))
+        .route("/{id}", delete(super::remove_review_function::execute))
+        .route("/{id}/hide", post(super::hide_review_function::execute))
+        .route("/{id}/approve", post(super::approve_review_function::execute))
+        .layer(middleware::from_fn(require_admin))
+        .layer(middleware::from_fn(auth_middleware))
+}
+
+fn register_routes_for_logging() {
+    route_logger::track_route("GET", "/api/v1/reviews/", vec!["auth".to_string(), "admin".to_string()]);
+    route_logger::track_route("DELETE", "/api/v1/reviews/{id}", vec!["auth".to_string(), "admin".to_string()]);
+    route_logger::track_route("POST", "/api/v1/reviews/{id}/hide", vec!["auth".to_string(), "admin".to_string()]);
+    route_logger::track_route("POST", "/api/v1/reviews/{id}/approve", vec!["auth".to_string(), "admin".to_string()]);
+}
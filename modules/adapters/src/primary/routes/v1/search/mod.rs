@@ -0,0 +1,2 @@
+pub mod routes;
+mod search_function;
@@ -0,0 +1,18 @@
+use axum::Router;
+use axum::routing::get;
+use std::sync::Arc;
+
+// internal modules
+use crate::shared::di::state::AppState;
+use crate::shared::utilities::route_logger;
+
+pub fn execute() -> Router<Arc<AppState>> {
+    register_routes_for_logging();
+    let public_routes = Router::new().route("/", get(super::search_function::execute));
+
+    Router::new().merge(public_routes)
+}
+
+fn register_routes_for_logging() {
+    route_logger::track_route("GET", "/api/v1/search/", vec![]);
+}
@@ -0,0 +1,23 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+// shared modules
+use domain::usecases::search_usecases::{SearchQuery, SearchResult, SearchUseCase};
+
+// internal modules
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::models::response::HttpResponse;
+use crate::shared::types::AxumResponse;
+use crate::shared::utilities::validated_query::ValidatedQuery;
+
+pub async fn execute(
+    State(state): State<Arc<AppState>>,
+    ValidatedQuery(query): ValidatedQuery<SearchQuery>,
+) -> AxumResponse<SearchResult> {
+    match state.search_app_service.search(&query).await {
+        Ok(result) => Ok(HttpResponse::new(StatusCode::OK, "Search completed successfully 🪼", result)),
+        Err(failure) => Err(HttpFailure::new(failure)),
+    }
+}
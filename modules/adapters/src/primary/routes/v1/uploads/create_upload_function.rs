@@ -0,0 +1,25 @@
+use axum::extract::State;
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+// shared modules
+use domain::usecases::upload_usecases::{CreateUploadParams, DirectUploadUseCase, PresignedUpload};
+
+// internal modules
+use crate::primary::middlewares::auth_middleware::AuthClaims;
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::models::response::HttpResponse;
+use crate::shared::types::AxumResponse;
+use crate::shared::utilities::validated_payload::ValidatedPayload;
+
+pub async fn execute(
+    State(state): State<Arc<AppState>>,
+    AuthClaims { account_id, .. }: AuthClaims,
+    ValidatedPayload(params): ValidatedPayload<CreateUploadParams>,
+) -> AxumResponse<PresignedUpload> {
+    match state.upload_app_service.create_upload(&account_id, &params).await {
+        Ok(upload) => Ok(HttpResponse::new(StatusCode::CREATED, "Upload created successfully 🪼", upload)),
+        Err(failure) => Err(HttpFailure::new(failure)),
+    }
+}
@@ -0,0 +1,25 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::upload_entity::UploadEntity;
+use domain::usecases::upload_usecases::DirectUploadUseCase;
+
+// internal modules
+use crate::primary::middlewares::auth_middleware::AuthClaims;
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::models::response::HttpResponse;
+use crate::shared::types::AxumResponse;
+
+pub async fn execute(
+    State(state): State<Arc<AppState>>,
+    Path(id): Path<String>,
+    AuthClaims { account_id, .. }: AuthClaims,
+) -> AxumResponse<UploadEntity> {
+    match state.upload_app_service.find_upload(&account_id, &id).await {
+        Ok(upload) => Ok(HttpResponse::new(StatusCode::OK, "Upload found successfully 🪼", upload)),
+        Err(failure) => Err(HttpFailure::new(failure)),
+    }
+}
@@ -0,0 +1,4 @@
+mod complete_upload_function;
+mod create_upload_function;
+mod find_upload_function;
+pub mod routes;
@@ -0,0 +1,23 @@
+use axum::routing::{get, post};
+use axum::{Router, middleware};
+use std::sync::Arc;
+
+// internal modules
+use crate::primary::middlewares::auth_middleware::auth_middleware;
+use crate::shared::di::state::AppState;
+use crate::shared::utilities::route_logger;
+
+pub fn execute() -> Router<Arc<AppState>> {
+    register_routes_for_logging();
+    Router::new()
+        .route("/", post(super::create_upload_function::execute))
+        .route("/{id}", get(super::find_upload_function::execute))
+        .route("/{id}/complete", post(super::complete_upload_function::execute))
+        .layer(middleware::from_fn(auth_middleware))
+}
+
+fn register_routes_for_logging() {
+    route_logger::track_route("POST", "/api/v1/uploads/", vec!["auth".to_string()]);
+    route_logger::track_route("GET", "/api/v1/uploads/{id}", vec!["auth".to_string()]);
+    route_logger::track_route("POST", "/api/v1/uploads/{id}/complete", vec!["auth".to_string()]);
+}
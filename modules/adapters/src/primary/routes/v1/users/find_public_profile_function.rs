@@ -0,0 +1,24 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use std::sync::Arc;
+
+// shared modules
+use domain::usecases::account_usecases::{FindPublicProfileQuery, ManageProfileUseCase, PublicProfile};
+
+// internal modules
+use crate::shared::di::state::AppState;
+use crate::shared::models::failure::HttpFailure;
+use crate::shared::models::response::HttpResponse;
+use crate::shared::types::AxumResponse;
+use crate::shared::utilities::validated_query::ValidatedQuery;
+
+pub async fn execute(
+    State(state): State<Arc<AppState>>,
+    Path(username): Path<String>,
+    ValidatedQuery(query): ValidatedQuery<FindPublicProfileQuery>,
+) -> AxumResponse<PublicProfile> {
+    match state.account_app_service.find_public_profile(&username, &query).await {
+        Ok(profile) => Ok(HttpResponse::new(StatusCode::OK, "Profile retrieved successfully ✅", profile)),
+        Err(failure) => Err(HttpFailure::new(failure)),
+    }
+}
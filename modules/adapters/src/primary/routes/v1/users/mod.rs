@@ -0,0 +1,2 @@
+mod find_public_profile_function;
+pub mod routes;
@@ -0,0 +1,16 @@
+use axum::Router;
+use axum::routing::get;
+use std::sync::Arc;
+
+// internal modules
+use crate::shared::di::state::AppState;
+use crate::shared::utilities::route_logger;
+
+pub fn execute() -> Router<Arc<AppState>> {
+    register_routes_for_logging();
+    Router::new().route("/{username}", get(super::find_public_profile_function::execute))
+}
+
+fn register_routes_for_logging() {
+    route_logger::track_route("GET", "/api/v1/users/{username}", vec![]);
+}
@@ -0,0 +1,41 @@
+use std::sync::Arc;
+
+// shared modules
+use domain::apis::blob_storage::BlobStorage;
+use shared::configs::APP_CONFIG;
+
+// internal modules
+use crate::secondary::apis::{local_blob_storage_impl::LocalBlobStorageImpl, s3_blob_storage_impl::S3BlobStorageImpl};
+
+pub enum BlobStorageBackend {
+    Local,
+    S3,
+}
+
+impl BlobStorageBackend {
+    pub fn as_str(&self) -> &str {
+        match self {
+            BlobStorageBackend::Local => "LOCAL",
+            BlobStorageBackend::S3 => "S3",
+        }
+    }
+
+    pub fn from_string(backend: &str) -> Result<Self, String> {
+        match backend.to_uppercase().as_str() {
+            "LOCAL" => Ok(BlobStorageBackend::Local),
+            "S3" => Ok(BlobStorageBackend::S3),
+            _ => Err(format!("Unknown media storage: {}", backend)),
+        }
+    }
+}
+
+/// The blob storage picked by the media config.
+pub fn build_blob_storage() -> Result<Arc<dyn BlobStorage>, Box<dyn std::error::Error>> {
+    let backend = BlobStorageBackend::from_string(&APP_CONFIG.media.storage)?;
+    tracing::info!("🗄️ Using media storage: {}", backend.as_str());
+
+    match backend {
+        BlobStorageBackend::Local => Ok(Arc::new(LocalBlobStorageImpl::new())),
+        BlobStorageBackend::S3 => Ok(Arc::new(S3BlobStorageImpl::new()?)),
+    }
+}
@@ -0,0 +1,259 @@
+use async_trait::async_trait;
+use futures::{Stream, StreamExt, stream};
+use redis::AsyncCommands;
+use redis::aio::MultiplexedConnection;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::broadcast::{self, error::RecvError};
+use uuid::{NoContext, Timestamp, Uuid};
+
+// shared modules
+use domain::apis::event_hub::{EventHub, RealtimeEvent};
+use domain::values::realtime_event_kind::RealtimeEventKind;
+use shared::configs::APP_CONFIG;
+
+static REDIS_CHANNEL: &str = "visionary:realtime";
+static REDIS_RETRY_DELAY: Duration = Duration::from_secs(5);
+
+/// Event as it travels between replicas, tagged with the replica that published it.
+#[derive(Serialize, Deserialize)]
+struct Envelope {
+    origin: String,
+    event: RealtimeEvent,
+}
+
+struct AccountChannel {
+    sender: broadcast::Sender<RealtimeEvent>,
+    history: VecDeque<RealtimeEvent>,
+    /// Newest event dropped from the history, anything up to it can no longer be replayed.
+    evicted_id: Option<String>,
+}
+
+impl AccountChannel {
+    fn new(capacity: usize) -> Self {
+        Self { sender: broadcast::channel(capacity).0, history: VecDeque::new(), evicted_id: None }
+    }
+
+    fn evict_front(&mut self) {
+        if let Some(event) = self.history.pop_front() {
+            self.evicted_id = Some(event.id);
+        }
+    }
+}
+
+pub struct Subscription {
+    /// Missed events, to be sent before the live ones.
+    pub replay: Vec<RealtimeEvent>,
+    pub receiver: broadcast::Receiver<RealtimeEvent>,
+}
+
+/// In-process hub keeping one broadcast channel per account, with an optional Redis pub/sub fan-out
+/// so events published on one replica reach sessions connected to the others.
+pub struct EventHubImpl {
+    /// Id of this replica, events it published itself come back from Redis and are skipped.
+    origin: String,
+    /// Nothing published before the hub started can be replayed.
+    started_id: String,
+    channels: Mutex<HashMap<String, AccountChannel>>,
+    redis: Option<redis::Client>,
+    publisher: tokio::sync::Mutex<Option<MultiplexedConnection>>,
+}
+
+impl EventHubImpl {
+    pub fn new() -> Arc<Self> {
+        let redis = if APP_CONFIG.realtime.redis_fanout {
+            redis::Client::open(APP_CONFIG.redis.url())
+                .inspect_err(|e| tracing::error!("📡 Invalid Redis config, realtime fan-out disabled: {}", e))
+                .ok()
+        } else {
+            None
+        };
+
+        let hub = Arc::new(Self {
+            origin: Uuid::now_v7().to_string(),
+            started_id: Uuid::now_v7().to_string(),
+            channels: Mutex::new(HashMap::new()),
+            redis: redis.clone(),
+            publisher: tokio::sync::Mutex::new(None),
+        });
+
+        if let Some(client) = redis {
+            tokio::spawn(hub.clone().listen(client));
+        }
+
+        hub
+    }
+
+    /// Start receiving the events of an account, replaying what was missed since `last_event_id`.
+    pub fn subscribe(&self, account_id: &str, last_event_id: Option<&str>) -> Subscription {
+        let mut channels = self.channels.lock().unwrap();
+        let channel = channels
+            .entry(account_id.to_string())
+            .or_insert_with(|| AccountChannel::new(APP_CONFIG.realtime.session_buffer));
+
+        // Subscribe while holding the lock so nothing falls between the replay and the live events
+        let receiver = channel.sender.subscribe();
+        let Some(last_event_id) = last_event_id else {
+            return Subscription { replay: vec![], receiver };
+        };
+
+        if let Some(position) = channel.history.iter().position(|event| event.id == last_event_id) {
+            let replay = channel.history.iter().skip(position + 1).cloned().collect();
+            return Subscription { replay, receiver };
+        }
+
+        // Event ids are time ordered, an unknown id older than anything still replayable means events are lost
+        let window_start = chrono::Utc::now().timestamp() - APP_CONFIG.realtime.replay_seconds;
+        let window_id = Uuid::new_v7(Timestamp::from_unix(NoContext, window_start.max(0) as u64, 0)).to_string();
+        let cutoff = [Some(&self.started_id), channel.evicted_id.as_ref(), Some(&window_id)]
+            .into_iter()
+            .flatten()
+            .max()
+            .cloned()
+            .unwrap_or_default();
+        if last_event_id >= cutoff.as_str() {
+            let replay = channel.history.iter().filter(|event| event.id.as_str() > last_event_id).cloned().collect();
+            return Subscription { replay, receiver };
+        }
+
+        // Hand out the newest known id so the client resumes from there once it refetched its state
+        let mut sync = RealtimeEvent::new(account_id, RealtimeEventKind::SyncRequired, serde_json::json!({}));
+        sync.id = channel.history.back().map(|event| event.id.clone()).unwrap_or(cutoff);
+        Subscription { replay: vec![sync], receiver }
+    }
+
+    /// Events of an account as a stream. A session that falls behind is caught up from the replay
+    /// history instead of silently skipping events.
+    pub fn stream(
+        self: &Arc<Self>,
+        account_id: &str,
+        last_event_id: Option<&str>,
+    ) -> impl Stream<Item = RealtimeEvent> + Send + use<> {
+        let subscription = self.subscribe(account_id, last_event_id);
+        let state = (
+            self.clone(),
+            account_id.to_string(),
+            last_event_id.map(str::to_string),
+            VecDeque::from(subscription.replay),
+            subscription.receiver,
+        );
+
+        stream::unfold(state, |(hub, account_id, mut last_event_id, mut pending, mut receiver)| async move {
+            loop {
+                if let Some(event) = pending.pop_front() {
+                    last_event_id = Some(event.id.clone());
+                    return Some((event, (hub, account_id, last_event_id, pending, receiver)));
+                }
+
+                match receiver.recv().await {
+                    Ok(event) => {
+                        last_event_id = Some(event.id.clone());
+                        return Some((event, (hub, account_id, last_event_id, pending, receiver)));
+                    },
+                    Err(RecvError::Lagged(skipped)) => {
+                        tracing::warn!("📡 Session of {} lagged behind by {} event(s)", account_id, skipped);
+                        let subscription = hub.subscribe(&account_id, last_event_id.as_deref());
+                        pending = VecDeque::from(subscription.replay);
+                        receiver = subscription.receiver;
+                    },
+                    Err(RecvError::Closed) => return None,
+                }
+            }
+        })
+    }
+
+    /// Drop expired history, and the channels of accounts without sessions once nothing is left to replay.
+    pub fn prune(&self) -> usize {
+        let expired_before = chrono::Utc::now().timestamp() - APP_CONFIG.realtime.replay_seconds;
+        let mut channels = self.channels.lock().unwrap();
+
+        for channel in channels.values_mut() {
+            while channel.history.front().is_some_and(|event| event.created_at < expired_before) {
+                channel.evict_front();
+            }
+        }
+
+        let before = channels.len();
+        channels.retain(|_, channel| channel.sender.receiver_count() > 0 || !channel.history.is_empty());
+        before - channels.len()
+    }
+
+    fn deliver(&self, event: RealtimeEvent) {
+        let mut channels = self.channels.lock().unwrap();
+        let channel = channels
+            .entry(event.account_id.clone())
+            .or_insert_with(|| AccountChannel::new(APP_CONFIG.realtime.session_buffer));
+
+        channel.history.push_back(event.clone());
+        while channel.history.len() > APP_CONFIG.realtime.replay_size {
+            channel.evict_front();
+        }
+
+        // Sending only fails when the account has no live session, the history covers it
+        let _ = channel.sender.send(event);
+    }
+
+    async fn fan_out(&self, client: &redis::Client, event: &RealtimeEvent) -> redis::RedisResult<()> {
+        let mut connection = {
+            let mut publisher = self.publisher.lock().await;
+            match publisher.as_ref() {
+                Some(connection) => connection.clone(),
+                None => publisher.insert(client.get_multiplexed_async_connection().await?).clone(),
+            }
+        };
+
+        let envelope = Envelope { origin: self.origin.clone(), event: event.clone() };
+        let payload = serde_json::to_string(&envelope).unwrap_or_default();
+        if let Err(e) = connection.publish::<_, _, usize>(REDIS_CHANNEL, payload).await {
+            // Reconnect on the next publish
+            self.publisher.lock().await.take();
+            return Err(e);
+        }
+
+        Ok(())
+    }
+
+    async fn listen(self: Arc<Self>, client: redis::Client) {
+        loop {
+            match self.relay(&client).await {
+                Ok(()) => tracing::warn!("📡 Redis realtime subscription closed, reconnecting"),
+                Err(e) => tracing::error!("📡 Redis realtime subscription failed: {}", e),
+            }
+            tokio::time::sleep(REDIS_RETRY_DELAY).await;
+        }
+    }
+
+    /// Deliver the events other replicas publish to the sessions connected here.
+    async fn relay(&self, client: &redis::Client) -> redis::RedisResult<()> {
+        let mut pubsub = client.get_async_pubsub().await?;
+        pubsub.subscribe(REDIS_CHANNEL).await?;
+        tracing::info!("📡 Subscribed to Redis channel {}", REDIS_CHANNEL);
+
+        let mut messages = pubsub.on_message();
+        while let Some(message) = messages.next().await {
+            let payload: String = message.get_payload()?;
+            match serde_json::from_str::<Envelope>(&payload) {
+                Ok(envelope) if envelope.origin != self.origin => self.deliver(envelope.event),
+                Ok(_) => {},
+                Err(e) => tracing::warn!("📡 Skipped malformed realtime event: {}", e),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl EventHub for EventHubImpl {
+    async fn publish(&self, event: RealtimeEvent) {
+        self.deliver(event.clone());
+
+        if let Some(client) = &self.redis
+            && let Err(e) = self.fan_out(client, &event).await
+        {
+            tracing::warn!("📡 Failed to fan out realtime event {}: {}", event.id, e);
+        }
+    }
+}
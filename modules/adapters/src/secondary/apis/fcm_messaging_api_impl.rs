@@ -0,0 +1,236 @@
+use async_trait::async_trait;
+use jsonwebtoken::{Algorithm, EncodingKey, Header, encode};
+use reqwest::{Client, StatusCode};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use tokio::sync::Mutex;
+
+// shared modules
+use domain::apis::messaging_api::{MessagingApi, PushDelivery};
+use domain::usecases::notification_usecases::PushNotification;
+use shared::configs::APP_CONFIG;
+use shared::functions::path_functions::PathFunctions;
+use shared::models::failure::Failure;
+use shared::types::DomainResponse;
+
+static MESSAGING_SCOPE: &str = "https://www.googleapis.com/auth/firebase.messaging";
+static TOPIC_API_URL: &str = "https://iid.googleapis.com/iid/v1";
+
+// Topic management accepts at most this many tokens per request
+static TOPIC_BATCH_SIZE: usize = 1000;
+
+// Refresh the access token this long before Google expires it
+static TOKEN_EXPIRY_MARGIN: i64 = 60;
+
+#[derive(Deserialize)]
+struct ServiceAccount {
+    project_id: String,
+    client_email: String,
+    private_key: String,
+    token_uri: String,
+}
+
+#[derive(Serialize)]
+struct AssertionClaims<'a> {
+    iss: &'a str,
+    scope: &'a str,
+    aud: &'a str,
+    iat: i64,
+    exp: i64,
+}
+
+#[derive(Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    expires_in: i64,
+}
+
+struct AccessToken {
+    value: String,
+    expires_at: i64,
+}
+
+#[derive(Deserialize)]
+struct SendResponse {
+    name: String,
+}
+
+#[derive(Deserialize, Default)]
+struct TopicResponse {
+    #[serde(default)]
+    results: Vec<TopicResult>,
+}
+
+#[derive(Deserialize)]
+struct TopicResult {
+    error: Option<String>,
+}
+
+/// Firebase Cloud Messaging over the HTTP v1 API, authenticated with a service account.
+pub struct FcmMessagingApiImpl {
+    client: Client,
+    project_id: String,
+    service_account: ServiceAccount,
+    encoding_key: EncodingKey,
+    access_token: Mutex<Option<AccessToken>>,
+}
+
+impl FcmMessagingApiImpl {
+    pub fn new() -> Result<Self, Box<dyn std::error::Error>> {
+        let path = format!("{}/{}", PathFunctions::root_path(), APP_CONFIG.messaging.fcm_credentials_path);
+        let service_account: ServiceAccount = serde_json::from_str(&std::fs::read_to_string(path)?)?;
+        let encoding_key = EncodingKey::from_rsa_pem(service_account.private_key.as_bytes())?;
+
+        let project_id = if APP_CONFIG.messaging.fcm_project_id.is_empty() {
+            service_account.project_id.clone()
+        } else {
+            APP_CONFIG.messaging.fcm_project_id.clone()
+        };
+
+        Ok(Self { client: Client::new(), project_id, service_account, encoding_key, access_token: Mutex::new(None) })
+    }
+
+    /// Cached OAuth access token, exchanged again for a signed assertion shortly before it expires.
+    async fn access_token(&self) -> DomainResponse<String> {
+        let mut access_token = self.access_token.lock().await;
+        let now = chrono::Utc::now().timestamp();
+        if let Some(token) = access_token.as_ref().filter(|token| token.expires_at - TOKEN_EXPIRY_MARGIN > now) {
+            return Ok(token.value.clone());
+        }
+
+        let claims = AssertionClaims {
+            iss: &self.service_account.client_email,
+            scope: MESSAGING_SCOPE,
+            aud: &self.service_account.token_uri,
+            iat: now,
+            exp: now + 3600,
+        };
+        let assertion = encode(&Header::new(Algorithm::RS256), &claims, &self.encoding_key)
+            .map_err(|e| Failure::InternalServerError(format!("Failed to sign FCM assertion: {}", e)))?;
+
+        let response = self
+            .client
+            .post(&self.service_account.token_uri)
+            .form(&[("grant_type", "urn:ietf:params:oauth:grant-type:jwt-bearer"), ("assertion", &assertion)])
+            .send()
+            .await
+            .map_err(|e| Failure::ExternalServiceError(format!("Failed to request FCM access token: {}", e)))?;
+
+        if !response.status().is_success() {
+            return Err(Failure::ExternalServiceError(format!(
+                "FCM access token request failed with {}",
+                response.status()
+            )));
+        }
+
+        let token: TokenResponse = response
+            .json()
+            .await
+            .map_err(|e| Failure::ExternalServiceError(format!("Failed to read FCM access token: {}", e)))?;
+
+        let value = token.access_token.clone();
+        *access_token = Some(AccessToken { value: token.access_token, expires_at: now + token.expires_in });
+        Ok(value)
+    }
+
+    /// Send one message, `None` when FCM rejected the target token.
+    async fn send(&self, message: serde_json::Value) -> DomainResponse<Option<String>> {
+        let url = format!("https://fcm.googleapis.com/v1/projects/{}/messages:send", self.project_id);
+        let response = self
+            .client
+            .post(&url)
+            .bearer_auth(self.access_token().await?)
+            .json(&json!({ "message": message }))
+            .send()
+            .await
+            .map_err(|e| Failure::ExternalServiceError(format!("Failed to send push notification: {}", e)))?;
+
+        let status = response.status();
+        if status.is_success() {
+            let sent: SendResponse = response
+                .json()
+                .await
+                .map_err(|e| Failure::ExternalServiceError(format!("Failed to read FCM response: {}", e)))?;
+            return Ok(Some(sent.name));
+        }
+
+        // Uninstalled apps answer 404, or UNREGISTERED in the error details
+        let body = response.text().await.unwrap_or_default();
+        if status == StatusCode::NOT_FOUND || body.contains("\"UNREGISTERED\"") {
+            return Ok(None);
+        }
+
+        Err(Failure::ExternalServiceError(format!("FCM rejected the push notification with {}: {}", status, body)))
+    }
+
+    /// Run a topic management batch, returns the tokens FCM does not know.
+    async fn manage_topic(&self, action: &str, tokens: &[String], topic: &str) -> DomainResponse<Vec<String>> {
+        let mut invalid = vec![];
+
+        for batch in tokens.chunks(TOPIC_BATCH_SIZE) {
+            let response = self
+                .client
+                .post(format!("{}:{}", TOPIC_API_URL, action))
+                .bearer_auth(self.access_token().await?)
+                .header("access_token_auth", "true")
+                .json(&json!({ "to": format!("/topics/{}", topic), "registration_tokens": batch }))
+                .send()
+                .await
+                .map_err(|e| Failure::ExternalServiceError(format!("Failed to update topic {}: {}", topic, e)))?;
+
+            if !response.status().is_success() {
+                return Err(Failure::ExternalServiceError(format!(
+                    "FCM rejected the topic update with {}",
+                    response.status()
+                )));
+            }
+
+            // Results come back in the order of the tokens
+            let results: TopicResponse = response.json().await.unwrap_or_default();
+            invalid.extend(batch.iter().zip(results.results).filter_map(|(token, result)| {
+                matches!(result.error.as_deref(), Some("NOT_FOUND" | "INVALID_ARGUMENT")).then(|| token.clone())
+            }));
+        }
+
+        Ok(invalid)
+    }
+
+    fn message(notification: &PushNotification) -> serde_json::Value {
+        json!({
+            "notification": { "title": notification.title, "body": notification.body },
+            "data": notification.data.clone().unwrap_or_default(),
+        })
+    }
+}
+
+#[async_trait]
+impl MessagingApi for FcmMessagingApiImpl {
+    async fn send_push_notification(
+        &self,
+        token: &str,
+        notification: &PushNotification,
+    ) -> DomainResponse<PushDelivery> {
+        let mut message = Self::message(notification);
+        message["token"] = json!(token);
+
+        match self.send(message).await? {
+            Some(message_id) => Ok(PushDelivery::Sent(message_id)),
+            None => Ok(PushDelivery::InvalidToken),
+        }
+    }
+
+    async fn send_push_to_topic(&self, topic: &str, notification: &PushNotification) -> DomainResponse<String> {
+        let mut message = Self::message(notification);
+        message["topic"] = json!(topic);
+
+        self.send(message).await?.ok_or_else(|| Failure::ExternalServiceError(format!("FCM rejected topic {}", topic)))
+    }
+
+    async fn subscribe_to_topic(&self, tokens: &[String], topic: &str) -> DomainResponse<Vec<String>> {
+        self.manage_topic("batchAdd", tokens, topic).await
+    }
+
+    async fn unsubscribe_from_topic(&self, tokens: &[String], topic: &str) -> DomainResponse<Vec<String>> {
+        self.manage_topic("batchRemove", tokens, topic).await
+    }
+}
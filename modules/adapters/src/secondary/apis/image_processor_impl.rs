@@ -0,0 +1,110 @@
+use async_trait::async_trait;
+use image::codecs::webp::WebPEncoder;
+use image::imageops::FilterType;
+use image::metadata::Orientation;
+use image::{DynamicImage, ImageDecoder, ImageReader, Limits};
+use std::io::Cursor;
+
+// shared modules
+use domain::apis::image_processor::{ImageProcessor, ImageVariant, ProcessedImage};
+use domain::values::image_format::ImageFormat;
+use shared::configs::APP_CONFIG;
+use shared::models::failure::Failure;
+use shared::types::DomainResponse;
+
+/// Decodes and re-encodes on the blocking pool, images are CPU work the runtime threads should not carry.
+pub struct ImageProcessorImpl;
+
+impl ImageProcessorImpl {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn format(format: ImageFormat) -> image::ImageFormat {
+        match format {
+            ImageFormat::Jpeg => image::ImageFormat::Jpeg,
+            ImageFormat::Png => image::ImageFormat::Png,
+            ImageFormat::Gif => image::ImageFormat::Gif,
+            ImageFormat::Webp => image::ImageFormat::WebP,
+        }
+    }
+
+    fn reader(bytes: &[u8], format: ImageFormat) -> ImageReader<Cursor<&[u8]>> {
+        let mut reader = ImageReader::new(Cursor::new(bytes));
+        reader.set_format(Self::format(format));
+        reader
+    }
+
+    fn decode(bytes: &[u8], format: ImageFormat) -> DomainResponse<DynamicImage> {
+        let max_dimension = APP_CONFIG.media.max_dimension;
+
+        // The header is enough to refuse decompression bombs before any pixel is allocated
+        let (width, height) = Self::reader(bytes, format)
+            .into_dimensions()
+            .map_err(|e| Failure::ValidationError(format!("The image could not be read: {}", e)))?;
+        if width > max_dimension || height > max_dimension {
+            return Err(Failure::ValidationError(format!(
+                "The image is {}x{}, at most {}x{} is accepted",
+                width, height, max_dimension, max_dimension
+            )));
+        }
+
+        let mut limits = Limits::default();
+        limits.max_image_width = Some(max_dimension);
+        limits.max_image_height = Some(max_dimension);
+
+        let mut reader = Self::reader(bytes, format);
+        reader.limits(limits);
+        let decode_failure = |e| Failure::ValidationError(format!("The image could not be decoded: {}", e));
+        let mut decoder = reader.into_decoder().map_err(decode_failure)?;
+
+        // Cameras store portrait shots sideways with an EXIF orientation, re-encoding drops the tag so the
+        // rotation is applied to the pixels instead
+        let orientation = decoder.orientation().unwrap_or(Orientation::NoTransforms);
+        let mut image = DynamicImage::from_decoder(decoder).map_err(decode_failure)?;
+        image.apply_orientation(orientation);
+
+        Ok(image)
+    }
+
+    fn encode(image: &DynamicImage, variant: &ImageVariant) -> DomainResponse<ProcessedImage> {
+        let resized = if image.width() > variant.max_width {
+            image.resize(variant.max_width, u32::MAX, FilterType::Lanczos3)
+        } else {
+            image.clone()
+        };
+        let rgba = resized.to_rgba8();
+
+        let mut bytes = vec![];
+        rgba.write_with_encoder(WebPEncoder::new_lossless(&mut bytes))
+            .map_err(|e| Failure::InternalServerError(format!("Failed to encode {} variant: {}", variant.name, e)))?;
+
+        Ok(ProcessedImage { variant: variant.name.clone(), bytes, width: rgba.width(), height: rgba.height() })
+    }
+}
+
+impl Default for ImageProcessorImpl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl ImageProcessor for ImageProcessorImpl {
+    async fn process(
+        &self,
+        bytes: &[u8],
+        format: ImageFormat,
+        variants: &[ImageVariant],
+    ) -> DomainResponse<Vec<ProcessedImage>> {
+        let bytes = bytes.to_vec();
+        let variants = variants.to_vec();
+
+        tokio::task::spawn_blocking(move || {
+            let image = Self::decode(&bytes, format)?;
+            variants.iter().map(|variant| Self::encode(&image, variant)).collect()
+        })
+        .await
+        .map_err(|e| Failure::InternalServerError(format!("Image processing was interrupted: {}", e)))?
+    }
+}
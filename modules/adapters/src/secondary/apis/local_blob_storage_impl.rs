@@ -0,0 +1,109 @@
+use async_trait::async_trait;
+use std::io::ErrorKind;
+use std::path::PathBuf;
+
+// shared modules
+use domain::apis::blob_storage::{Blob, BlobMetadata, BlobStorage};
+use domain::values::image_format::ImageFormat;
+use shared::configs::APP_CONFIG;
+use shared::functions::path_functions::PathFunctions;
+use shared::models::failure::Failure;
+use shared::types::DomainResponse;
+
+/// Blobs as plain files under the configured media directory, served back through the API.
+pub struct LocalBlobStorageImpl {
+    root: PathBuf,
+}
+
+impl LocalBlobStorageImpl {
+    pub fn new() -> Self {
+        Self { root: PathBuf::from(PathFunctions::root_path()).join(&APP_CONFIG.media.local_path) }
+    }
+
+    /// Keys come from requests when files are served, so nothing may escape the media directory.
+    fn path(&self, key: &str) -> DomainResponse<PathBuf> {
+        if key.is_empty() || key.starts_with('/') || key.contains('\\') || key.split('/').any(|part| part == "..") {
+            return Err(Failure::BadRequest(format!("Invalid file key: {}", key)));
+        }
+
+        Ok(self.root.join(key))
+    }
+}
+
+impl Default for LocalBlobStorageImpl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl BlobStorage for LocalBlobStorageImpl {
+    async fn put(&self, key: &str, bytes: Vec<u8>, _content_type: &str) -> DomainResponse<()> {
+        let path = self.path(key)?;
+        if let Some(parent) = path.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .map_err(|e| Failure::InternalServerError(format!("Failed to create media directory: {}", e)))?;
+        }
+
+        tokio::fs::write(&path, bytes)
+            .await
+            .map_err(|e| Failure::InternalServerError(format!("Failed to write {}: {}", key, e)))
+    }
+
+    async fn get(&self, key: &str) -> DomainResponse<Option<Blob>> {
+        match tokio::fs::read(self.path(key)?).await {
+            Ok(bytes) => {
+                // Only images are stored, the content tells which one
+                let content_type = ImageFormat::from_magic_bytes(&bytes)
+                    .map(|format| format.mime_type().to_string())
+                    .unwrap_or_else(|_| "application/octet-stream".to_string());
+                Ok(Some(Blob { bytes, content_type }))
+            },
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(Failure::InternalServerError(format!("Failed to read {}: {}", key, e))),
+        }
+    }
+
+    async fn head(&self, key: &str) -> DomainResponse<Option<BlobMetadata>> {
+        match tokio::fs::metadata(self.path(key)?).await {
+            Ok(metadata) => Ok(Some(BlobMetadata { size: metadata.len() as i64, etag: None })),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(Failure::InternalServerError(format!("Failed to read {}: {}", key, e))),
+        }
+    }
+
+    async fn copy(&self, from: &str, to: &str) -> DomainResponse<()> {
+        let target = self.path(to)?;
+        if let Some(parent) = target.parent() {
+            tokio::fs::create_dir_all(parent)
+                .await
+                .map_err(|e| Failure::InternalServerError(format!("Failed to create media directory: {}", e)))?;
+        }
+
+        tokio::fs::copy(self.path(from)?, &target)
+            .await
+            .map_err(|e| Failure::InternalServerError(format!("Failed to copy {} to {}: {}", from, to, e)))?;
+        Ok(())
+    }
+
+    async fn delete(&self, key: &str) -> DomainResponse<()> {
+        match tokio::fs::remove_file(self.path(key)?).await {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(Failure::InternalServerError(format!("Failed to delete {}: {}", key, e))),
+        }
+    }
+
+    fn public_url(&self, key: &str) -> String {
+        if APP_CONFIG.media.public_base_url.is_empty() {
+            APP_CONFIG.site.public_url(&format!("/api/v1/media/files/{}", key))
+        } else {
+            format!("{}/{}", APP_CONFIG.media.public_base_url.trim_end_matches('/'), key)
+        }
+    }
+
+    async fn presign_put(&self, _key: &str, _content_type: &str, _expires_in: u32) -> DomainResponse<String> {
+        Err(Failure::NotImplemented("Direct uploads require the S3 media storage".to_string()))
+    }
+}
@@ -0,0 +1,41 @@
+use std::sync::Arc;
+
+// shared modules
+use domain::apis::mail_api::MailApi;
+use shared::configs::APP_CONFIG;
+
+// internal modules
+use crate::secondary::apis::{mock_mail_api_impl::MockMailApiImpl, smtp_mail_api_impl::SmtpMailApiImpl};
+
+pub enum MailTransport {
+    Smtp,
+    Mock,
+}
+
+impl MailTransport {
+    pub fn as_str(&self) -> &str {
+        match self {
+            MailTransport::Smtp => "SMTP",
+            MailTransport::Mock => "MOCK",
+        }
+    }
+
+    pub fn from_string(transport: &str) -> Result<Self, String> {
+        match transport.to_uppercase().as_str() {
+            "SMTP" => Ok(MailTransport::Smtp),
+            "MOCK" => Ok(MailTransport::Mock),
+            _ => Err(format!("Unknown mail transport: {}", transport)),
+        }
+    }
+}
+
+/// The mail transport picked by the mail config.
+pub fn build_mail_api() -> Result<Arc<dyn MailApi>, Box<dyn std::error::Error>> {
+    let transport = MailTransport::from_string(&APP_CONFIG.mail.transport)?;
+    tracing::info!("📧 Using mail transport: {}", transport.as_str());
+
+    match transport {
+        MailTransport::Smtp => Ok(Arc::new(SmtpMailApiImpl::new()?)),
+        MailTransport::Mock => Ok(Arc::new(MockMailApiImpl::new())),
+    }
+}
@@ -0,0 +1,43 @@
+use std::sync::Arc;
+
+// shared modules
+use domain::apis::messaging_api::MessagingApi;
+use shared::configs::APP_CONFIG;
+
+// internal modules
+use crate::secondary::apis::{
+    fcm_messaging_api_impl::FcmMessagingApiImpl, mock_messaging_api_impl::MockMessagingApiImpl,
+};
+
+pub enum MessagingTransport {
+    Fcm,
+    Mock,
+}
+
+impl MessagingTransport {
+    pub fn as_str(&self) -> &str {
+        match self {
+            MessagingTransport::Fcm => "FCM",
+            MessagingTransport::Mock => "MOCK",
+        }
+    }
+
+    pub fn from_string(transport: &str) -> Result<Self, String> {
+        match transport.to_uppercase().as_str() {
+            "FCM" => Ok(MessagingTransport::Fcm),
+            "MOCK" => Ok(MessagingTransport::Mock),
+            _ => Err(format!("Unknown messaging transport: {}", transport)),
+        }
+    }
+}
+
+/// The push transport picked by the messaging config.
+pub fn build_messaging_api() -> Result<Arc<dyn MessagingApi>, Box<dyn std::error::Error>> {
+    let transport = MessagingTransport::from_string(&APP_CONFIG.messaging.transport)?;
+    tracing::info!("📨 Using messaging transport: {}", transport.as_str());
+
+    match transport {
+        MessagingTransport::Fcm => Ok(Arc::new(FcmMessagingApiImpl::new()?)),
+        MessagingTransport::Mock => Ok(Arc::new(MockMessagingApiImpl::new())),
+    }
+}
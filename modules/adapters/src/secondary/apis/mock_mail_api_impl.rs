@@ -0,0 +1,31 @@
+use async_trait::async_trait;
+use std::sync::Mutex;
+
+// shared modules
+use domain::apis::mail_api::{Mail, MailApi};
+use shared::types::DomainResponse;
+
+/// Offline transport for local runs and tests, mails stay in memory and are only logged.
+#[derive(Default)]
+pub struct MockMailApiImpl {
+    sent: Mutex<Vec<Mail>>,
+}
+
+impl MockMailApiImpl {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn sent(&self) -> Vec<Mail> {
+        self.sent.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl MailApi for MockMailApiImpl {
+    async fn send_mail(&self, mail: &Mail) -> DomainResponse<()> {
+        tracing::info!("📧 [mock] Mail \"{}\" to {}", mail.subject, mail.to);
+        self.sent.lock().unwrap().push(mail.clone());
+        Ok(())
+    }
+}
@@ -0,0 +1,90 @@
+use async_trait::async_trait;
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+
+// shared modules
+use domain::apis::messaging_api::{MessagingApi, PushDelivery};
+use domain::usecases::notification_usecases::PushNotification;
+use shared::types::DomainResponse;
+
+// Tokens starting with this prefix are treated as uninstalled apps
+static INVALID_TOKEN_PREFIX: &str = "invalid";
+
+/// A push accepted by the mock transport, addressed to a device token or a `/topics/` name.
+#[derive(Debug, Clone)]
+pub struct MockPush {
+    pub message_id: String,
+    pub target: String,
+    pub notification: PushNotification,
+}
+
+/// Offline transport for local runs and tests, pushes stay in memory and are only logged. Tokens
+/// starting with `invalid` are reported dead, so pruning can be exercised without Firebase.
+#[derive(Default)]
+pub struct MockMessagingApiImpl {
+    sent: Mutex<Vec<MockPush>>,
+    topics: Mutex<HashMap<String, HashSet<String>>>,
+}
+
+impl MockMessagingApiImpl {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn sent(&self) -> Vec<MockPush> {
+        self.sent.lock().unwrap().clone()
+    }
+
+    pub fn topic_tokens(&self, topic: &str) -> HashSet<String> {
+        self.topics.lock().unwrap().get(topic).cloned().unwrap_or_default()
+    }
+
+    fn record(&self, target: String, notification: &PushNotification) -> String {
+        let message_id = format!("mock/{}", uuid::Uuid::now_v7());
+        tracing::info!("📨 [mock] Push \"{}\" to {} as {}", notification.title, target, message_id);
+
+        let push = MockPush { message_id: message_id.clone(), target, notification: notification.clone() };
+        self.sent.lock().unwrap().push(push);
+        message_id
+    }
+
+    fn invalid_tokens(tokens: &[String]) -> Vec<String> {
+        tokens.iter().filter(|token| token.starts_with(INVALID_TOKEN_PREFIX)).cloned().collect()
+    }
+}
+
+#[async_trait]
+impl MessagingApi for MockMessagingApiImpl {
+    async fn send_push_notification(
+        &self,
+        token: &str,
+        notification: &PushNotification,
+    ) -> DomainResponse<PushDelivery> {
+        if token.starts_with(INVALID_TOKEN_PREFIX) {
+            return Ok(PushDelivery::InvalidToken);
+        }
+
+        Ok(PushDelivery::Sent(self.record(token.to_string(), notification)))
+    }
+
+    async fn send_push_to_topic(&self, topic: &str, notification: &PushNotification) -> DomainResponse<String> {
+        Ok(self.record(format!("/topics/{}", topic), notification))
+    }
+
+    async fn subscribe_to_topic(&self, tokens: &[String], topic: &str) -> DomainResponse<Vec<String>> {
+        let invalid = Self::invalid_tokens(tokens);
+        let mut topics = self.topics.lock().unwrap();
+        let subscribers = topics.entry(topic.to_string()).or_default();
+        subscribers.extend(tokens.iter().filter(|token| !invalid.contains(token)).cloned());
+
+        Ok(invalid)
+    }
+
+    async fn unsubscribe_from_topic(&self, tokens: &[String], topic: &str) -> DomainResponse<Vec<String>> {
+        if let Some(subscribers) = self.topics.lock().unwrap().get_mut(topic) {
+            subscribers.retain(|token| !tokens.contains(token));
+        }
+
+        Ok(Self::invalid_tokens(tokens))
+    }
+}
@@ -1 +1,14 @@
 pub mod auth_api_impl;
+pub mod blob_storage_backend;
+pub mod event_hub_impl;
+pub mod fcm_messaging_api_impl;
+pub mod image_processor_impl;
+pub mod local_blob_storage_impl;
+pub mod mail_transport;
+pub mod messaging_transport;
+pub mod mock_mail_api_impl;
+pub mod mock_messaging_api_impl;
+pub mod s3_blob_storage_impl;
+pub mod search_index_impl;
+pub mod smtp_mail_api_impl;
+pub mod zip_archive_api_impl;
@@ -0,0 +1,101 @@
+use async_trait::async_trait;
+use axum::http::header::CONTENT_TYPE;
+use axum::http::{HeaderMap, HeaderValue};
+use s3::creds::Credentials;
+use s3::error::S3Error;
+use s3::{Bucket, Region};
+
+// shared modules
+use domain::apis::blob_storage::{Blob, BlobMetadata, BlobStorage};
+use shared::configs::APP_CONFIG;
+use shared::models::failure::Failure;
+use shared::types::DomainResponse;
+
+/// Blobs in an S3-compatible bucket (AWS, MinIO, R2...), served straight from the bucket or a CDN in front of it.
+pub struct S3BlobStorageImpl {
+    bucket: Box<Bucket>,
+}
+
+impl S3BlobStorageImpl {
+    pub fn new() -> Result<Self, Box<dyn std::error::Error>> {
+        let config = &APP_CONFIG.media;
+        let region = Region::Custom { region: config.s3_region.clone(), endpoint: config.s3_endpoint.clone() };
+        let credentials = Credentials::new(Some(&config.s3_access_key), Some(&config.s3_secret_key), None, None, None)?;
+
+        let mut bucket = Bucket::new(&config.s3_bucket, region, credentials)?;
+        if config.s3_path_style {
+            bucket = bucket.with_path_style();
+        }
+
+        Ok(Self { bucket })
+    }
+}
+
+#[async_trait]
+impl BlobStorage for S3BlobStorageImpl {
+    async fn put(&self, key: &str, bytes: Vec<u8>, content_type: &str) -> DomainResponse<()> {
+        self.bucket
+            .put_object_with_content_type(key, &bytes, content_type)
+            .await
+            .map_err(|e| Failure::ExternalServiceError(format!("Failed to upload {}: {}", key, e)))?;
+        Ok(())
+    }
+
+    async fn get(&self, key: &str) -> DomainResponse<Option<Blob>> {
+        match self.bucket.get_object(key).await {
+            Ok(response) => {
+                let content_type = response
+                    .headers()
+                    .get("content-type")
+                    .cloned()
+                    .unwrap_or_else(|| "application/octet-stream".to_string());
+                Ok(Some(Blob { bytes: response.to_vec(), content_type }))
+            },
+            Err(S3Error::HttpFailWithBody(404, _)) => Ok(None),
+            Err(e) => Err(Failure::ExternalServiceError(format!("Failed to download {}: {}", key, e))),
+        }
+    }
+
+    async fn head(&self, key: &str) -> DomainResponse<Option<BlobMetadata>> {
+        match self.bucket.head_object(key).await {
+            Ok((head, _)) => Ok(Some(BlobMetadata { size: head.content_length.unwrap_or_default(), etag: head.e_tag })),
+            Err(S3Error::HttpFailWithBody(404, _)) => Ok(None),
+            Err(e) => Err(Failure::ExternalServiceError(format!("Failed to inspect {}: {}", key, e))),
+        }
+    }
+
+    async fn copy(&self, from: &str, to: &str) -> DomainResponse<()> {
+        self.bucket
+            .copy_object_internal(from, to)
+            .await
+            .map_err(|e| Failure::ExternalServiceError(format!("Failed to copy {} to {}: {}", from, to, e)))?;
+        Ok(())
+    }
+
+    async fn delete(&self, key: &str) -> DomainResponse<()> {
+        match self.bucket.delete_object(key).await {
+            Ok(_) | Err(S3Error::HttpFailWithBody(404, _)) => Ok(()),
+            Err(e) => Err(Failure::ExternalServiceError(format!("Failed to delete {}: {}", key, e))),
+        }
+    }
+
+    fn public_url(&self, key: &str) -> String {
+        if APP_CONFIG.media.public_base_url.is_empty() {
+            format!("{}/{}", self.bucket.url(), key)
+        } else {
+            format!("{}/{}", APP_CONFIG.media.public_base_url.trim_end_matches('/'), key)
+        }
+    }
+
+    async fn presign_put(&self, key: &str, content_type: &str, expires_in: u32) -> DomainResponse<String> {
+        // Signing the content type makes storage refuse a PUT declaring anything else
+        let content_type = HeaderValue::from_str(content_type)
+            .map_err(|_| Failure::ValidationError(format!("Invalid content type: {}", content_type)))?;
+        let headers = HeaderMap::from_iter([(CONTENT_TYPE, content_type)]);
+
+        self.bucket
+            .presign_put(key, expires_in, Some(headers), None)
+            .await
+            .map_err(|e| Failure::ExternalServiceError(format!("Failed to presign {}: {}", key, e)))
+    }
+}
@@ -0,0 +1,374 @@
+use async_trait::async_trait;
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Mutex, RwLock};
+
+// shared modules
+use domain::apis::search_index::{SearchDocument, SearchFilter, SearchIndex};
+use domain::usecases::search_usecases::{SearchFacet, SearchFacets, SearchHit, SearchResult};
+use shared::models::paginate::Paginate;
+use shared::types::DomainResponse;
+
+// BM25 parameters, the usual defaults
+static BM25_K1: f32 = 1.2;
+static BM25_B: f32 = 0.75;
+
+// Characters kept on each side of the first match in a snippet
+static SNIPPET_RADIUS: usize = 80;
+static MAX_FACET_VALUES: usize = 20;
+
+#[derive(Debug, Clone, Copy)]
+enum Field {
+    Title,
+    Description,
+    Tags,
+    Content,
+}
+
+impl Field {
+    const ALL: [Field; 4] = [Field::Title, Field::Description, Field::Tags, Field::Content];
+
+    fn weight(&self) -> f32 {
+        match self {
+            Field::Title => 3.0,
+            Field::Description => 1.5,
+            Field::Tags => 2.0,
+            Field::Content => 1.0,
+        }
+    }
+
+    fn text(&self, document: &SearchDocument) -> String {
+        match self {
+            Field::Title => document.title.clone(),
+            Field::Description => document.description.clone(),
+            Field::Tags => document.tags.join(" "),
+            Field::Content => document.content.clone(),
+        }
+    }
+}
+
+struct Token {
+    term: String,
+    start: usize,
+    end: usize,
+}
+
+struct IndexedDocument {
+    document: SearchDocument,
+    term_frequencies: [HashMap<String, u32>; 4],
+    lengths: [u32; 4],
+}
+
+#[derive(Default)]
+struct IndexState {
+    documents: HashMap<String, IndexedDocument>,
+    postings: HashMap<String, HashSet<String>>,
+    total_lengths: [u64; 4],
+}
+
+impl IndexState {
+    fn insert(&mut self, document: SearchDocument) {
+        self.remove(&document.id);
+
+        let mut term_frequencies: [HashMap<String, u32>; 4] = Default::default();
+        let mut lengths = [0u32; 4];
+        for (index, field) in Field::ALL.iter().enumerate() {
+            for token in tokenize(&field.text(&document)) {
+                *term_frequencies[index].entry(token.term).or_insert(0) += 1;
+                lengths[index] += 1;
+            }
+            self.total_lengths[index] += lengths[index] as u64;
+        }
+
+        for term in term_frequencies.iter().flat_map(|frequencies| frequencies.keys()) {
+            self.postings.entry(term.clone()).or_default().insert(document.id.clone());
+        }
+
+        self.documents.insert(document.id.clone(), IndexedDocument { document, term_frequencies, lengths });
+    }
+
+    fn remove(&mut self, id: &str) {
+        let Some(indexed) = self.documents.remove(id) else {
+            return;
+        };
+
+        for (index, length) in indexed.lengths.iter().enumerate() {
+            self.total_lengths[index] -= *length as u64;
+        }
+
+        for term in indexed.term_frequencies.iter().flat_map(|frequencies| frequencies.keys()) {
+            let is_empty = match self.postings.get_mut(term) {
+                Some(ids) => {
+                    ids.remove(id);
+                    ids.is_empty()
+                },
+                None => false,
+            };
+            if is_empty {
+                self.postings.remove(term);
+            }
+        }
+    }
+
+    fn score(&self, terms: &[String], filter: &SearchFilter) -> Vec<(&IndexedDocument, f32)> {
+        let document_count = self.documents.len() as f32;
+        let average_lengths = self.total_lengths.map(|total| (total as f32 / document_count.max(1.0)).max(1.0));
+
+        let mut scores: HashMap<&str, f32> = HashMap::new();
+        for term in terms {
+            let Some(ids) = self.postings.get(term) else {
+                continue;
+            };
+
+            let document_frequency = ids.len() as f32;
+            let idf = ((document_count - document_frequency + 0.5) / (document_frequency + 0.5) + 1.0).ln();
+
+            for id in ids {
+                let indexed = &self.documents[id];
+                if !matches_filter(&indexed.document, filter) {
+                    continue;
+                }
+
+                let mut score = 0.0;
+                for (index, field) in Field::ALL.iter().enumerate() {
+                    let frequency = indexed.term_frequencies[index].get(term).copied().unwrap_or(0) as f32;
+                    if frequency == 0.0 {
+                        continue;
+                    }
+
+                    let norm = 1.0 - BM25_B + BM25_B * indexed.lengths[index] as f32 / average_lengths[index];
+                    score += field.weight() * idf * frequency * (BM25_K1 + 1.0) / (frequency + BM25_K1 * norm);
+                }
+                *scores.entry(id.as_str()).or_insert(0.0) += score;
+            }
+        }
+
+        let mut ranked: Vec<(&IndexedDocument, f32)> =
+            scores.into_iter().map(|(id, score)| (&self.documents[id], score)).collect();
+        ranked.sort_by(|a, b| {
+            b.1.partial_cmp(&a.1).unwrap_or(Ordering::Equal).then_with(|| a.0.document.id.cmp(&b.0.document.id))
+        });
+        ranked
+    }
+}
+
+enum PendingWrite {
+    Upsert(SearchDocument),
+    Remove(String),
+}
+
+/// Embedded inverted index ranked with BM25, rebuilt from the database on startup.
+///
+/// The index lives in the memory of each replica. Upserts and removes only reach the replica that served the
+/// write, the others pick it up on their next rebuild.
+pub struct SearchIndexImpl {
+    state: RwLock<IndexState>,
+    // Writes made since a rebuild began, replayed onto the new index before it is swapped in
+    pending: Mutex<Option<Vec<PendingWrite>>>,
+}
+
+impl SearchIndexImpl {
+    pub fn new() -> Self {
+        SearchIndexImpl { state: RwLock::new(IndexState::default()), pending: Mutex::new(None) }
+    }
+
+    /// Apply a write, keeping it while a rebuild is running. The state lock is held while keeping it, so the
+    /// replay in `replace_all` sees the writes in the order they were applied.
+    fn apply(&self, write: PendingWrite) {
+        let mut state = self.state.write().unwrap();
+        match &write {
+            PendingWrite::Upsert(document) => state.insert(document.clone()),
+            PendingWrite::Remove(id) => state.remove(id),
+        }
+        if let Some(pending) = self.pending.lock().unwrap().as_mut() {
+            pending.push(write);
+        }
+    }
+}
+
+impl Default for SearchIndexImpl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl SearchIndex for SearchIndexImpl {
+    async fn upsert(&self, document: SearchDocument) -> DomainResponse<()> {
+        self.apply(PendingWrite::Upsert(document));
+        Ok(())
+    }
+
+    async fn remove(&self, id: &str) -> DomainResponse<()> {
+        self.apply(PendingWrite::Remove(id.to_string()));
+        Ok(())
+    }
+
+    async fn begin_rebuild(&self) -> DomainResponse<()> {
+        self.pending.lock().unwrap().get_or_insert_with(Vec::new);
+        Ok(())
+    }
+
+    async fn replace_all(&self, documents: Vec<SearchDocument>) -> DomainResponse<usize> {
+        // Build aside so searches keep hitting the old index until the swap
+        let mut state = IndexState::default();
+        documents.into_iter().for_each(|document| state.insert(document));
+
+        // Writes made while the documents were read may be missing from them, the lock keeps new ones out
+        let mut current = self.state.write().unwrap();
+        for write in self.pending.lock().unwrap().take().unwrap_or_default() {
+            match write {
+                PendingWrite::Upsert(document) => state.insert(document),
+                PendingWrite::Remove(id) => state.remove(&id),
+            }
+        }
+        let count = state.documents.len();
+
+        *current = state;
+        Ok(count)
+    }
+
+    async fn search(
+        &self,
+        text: &str,
+        filter: &SearchFilter,
+        page: u32,
+        page_size: u32,
+    ) -> DomainResponse<SearchResult> {
+        let mut terms: Vec<String> = tokenize(text).into_iter().map(|token| token.term).collect();
+        terms.sort();
+        terms.dedup();
+
+        let state = self.state.read().unwrap();
+        let ranked = state.score(&terms, filter);
+
+        let facets = SearchFacets {
+            categories: count_facets(ranked.iter().flat_map(|(indexed, _)| indexed.document.categories.iter())),
+            tags: count_facets(ranked.iter().flat_map(|(indexed, _)| indexed.document.tags.iter())),
+        };
+
+        let total_record = ranked.len() as u32;
+        let hits = ranked
+            .into_iter()
+            .skip(((page - 1) * page_size) as usize)
+            .take(page_size as usize)
+            .map(|(indexed, score)| SearchHit {
+                id: indexed.document.id.clone(),
+                kind: indexed.document.kind.clone(),
+                title: indexed.document.title.clone(),
+                description: indexed.document.description.clone(),
+                score,
+                snippet: build_snippet(&indexed.document, &terms),
+            })
+            .collect();
+
+        let paginate = Paginate {
+            page,
+            page_size,
+            total_page: ((total_record as f32) / (page_size as f32)).ceil() as u32,
+            total_record,
+        };
+
+        Ok(SearchResult { paginate, hits, facets })
+    }
+}
+
+/// Split on anything that is not a letter or digit and lowercase, offsets point into `text`.
+fn tokenize(text: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut start = None;
+
+    for (index, ch) in text.char_indices() {
+        match (ch.is_alphanumeric(), start) {
+            (true, None) => start = Some(index),
+            (false, Some(token_start)) => {
+                tokens.push(Token { term: text[token_start..index].to_lowercase(), start: token_start, end: index });
+                start = None;
+            },
+            _ => {},
+        }
+    }
+
+    if let Some(token_start) = start {
+        tokens.push(Token { term: text[token_start..].to_lowercase(), start: token_start, end: text.len() });
+    }
+
+    tokens
+}
+
+fn matches_filter(document: &SearchDocument, filter: &SearchFilter) -> bool {
+    let kind_matches = filter.kind.as_ref().is_none_or(|kind| &document.kind == kind);
+    let category_matches = filter.category.as_ref().is_none_or(|category| document.categories.contains(category));
+    let tag_matches =
+        filter.tag.as_ref().is_none_or(|tag| document.tags.iter().any(|value| value.eq_ignore_ascii_case(tag)));
+
+    kind_matches && category_matches && tag_matches
+}
+
+fn count_facets<'a>(values: impl Iterator<Item = &'a String>) -> Vec<SearchFacet> {
+    let mut counts: HashMap<&str, u32> = HashMap::new();
+    values.for_each(|value| *counts.entry(value.as_str()).or_insert(0) += 1);
+
+    let mut facets: Vec<SearchFacet> =
+        counts.into_iter().map(|(value, count)| SearchFacet { value: value.to_string(), count }).collect();
+    facets.sort_by(|a, b| b.count.cmp(&a.count).then_with(|| a.value.cmp(&b.value)));
+    facets.truncate(MAX_FACET_VALUES);
+    facets
+}
+
+/// Window around the first match, matches are wrapped in `<mark>` and everything else is HTML escaped.
+fn build_snippet(document: &SearchDocument, terms: &[String]) -> String {
+    for text in [&document.content, &document.description, &document.title] {
+        let tokens = tokenize(text);
+        let Some(first) = tokens.iter().find(|token| terms.contains(&token.term)) else {
+            continue;
+        };
+
+        let start = floor_char_boundary(text, first.start.saturating_sub(SNIPPET_RADIUS));
+        let end = ceil_char_boundary(text, (first.end + SNIPPET_RADIUS).min(text.len()));
+
+        let mut snippet = String::new();
+        if start > 0 {
+            snippet.push('…');
+        }
+
+        let mut cursor = start;
+        for token in tokens.iter().filter(|token| token.start >= start && token.end <= end) {
+            if !terms.contains(&token.term) {
+                continue;
+            }
+            snippet.push_str(&escape_html(&text[cursor..token.start]));
+            snippet.push_str("<mark>");
+            snippet.push_str(&escape_html(&text[token.start..token.end]));
+            snippet.push_str("</mark>");
+            cursor = token.end;
+        }
+        snippet.push_str(&escape_html(&text[cursor..end]));
+
+        if end < text.len() {
+            snippet.push('…');
+        }
+        return snippet;
+    }
+
+    let end = ceil_char_boundary(&document.description, (SNIPPET_RADIUS * 2).min(document.description.len()));
+    escape_html(&document.description[..end])
+}
+
+fn floor_char_boundary(text: &str, mut index: usize) -> usize {
+    while !text.is_char_boundary(index) {
+        index -= 1;
+    }
+    index
+}
+
+fn ceil_char_boundary(text: &str, mut index: usize) -> usize {
+    while index < text.len() && !text.is_char_boundary(index) {
+        index += 1;
+    }
+    index
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
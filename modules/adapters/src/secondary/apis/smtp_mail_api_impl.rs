@@ -0,0 +1,51 @@
+use async_trait::async_trait;
+use lettre::message::{Mailbox, MultiPart, SinglePart};
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+
+// shared modules
+use domain::apis::mail_api::{Mail, MailApi};
+use shared::configs::APP_CONFIG;
+use shared::models::failure::Failure;
+use shared::types::DomainResponse;
+
+/// Mails sent through an SMTP relay over STARTTLS.
+pub struct SmtpMailApiImpl {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from: Mailbox,
+}
+
+impl SmtpMailApiImpl {
+    pub fn new() -> Result<Self, Box<dyn std::error::Error>> {
+        let config = &APP_CONFIG.mail;
+        let mut builder =
+            AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&config.smtp_host)?.port(config.smtp_port);
+        if !config.smtp_username.is_empty() {
+            builder = builder.credentials(Credentials::new(config.smtp_username.clone(), config.smtp_password.clone()));
+        }
+
+        Ok(Self { transport: builder.build(), from: config.from.parse()? })
+    }
+}
+
+#[async_trait]
+impl MailApi for SmtpMailApiImpl {
+    async fn send_mail(&self, mail: &Mail) -> DomainResponse<()> {
+        let to: Mailbox =
+            mail.to.parse().map_err(|e| Failure::BadRequest(format!("Invalid recipient {}: {}", mail.to, e)))?;
+        let builder = Message::builder().from(self.from.clone()).to(to).subject(&mail.subject);
+
+        let message = match &mail.html {
+            Some(html) => builder.multipart(MultiPart::alternative_plain_html(mail.text.clone(), html.clone())),
+            None => builder.singlepart(SinglePart::plain(mail.text.clone())),
+        }
+        .map_err(|e| Failure::InternalServerError(format!("Failed to build mail: {}", e)))?;
+
+        self.transport
+            .send(message)
+            .await
+            .map_err(|e| Failure::ExternalServiceError(format!("Failed to send mail: {}", e)))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,53 @@
+use async_trait::async_trait;
+use std::io::{Cursor, Write};
+use zip::CompressionMethod;
+use zip::ZipWriter;
+use zip::write::SimpleFileOptions;
+
+// shared modules
+use domain::apis::archive_api::{ArchiveApi, ArchiveEntry};
+use shared::models::failure::Failure;
+use shared::types::DomainResponse;
+
+/// Compresses on the blocking pool, an account with a lot of content makes for a sizeable archive.
+pub struct ZipArchiveApiImpl;
+
+impl ZipArchiveApiImpl {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn write(entries: Vec<ArchiveEntry>) -> DomainResponse<Vec<u8>> {
+        let mut writer = ZipWriter::new(Cursor::new(Vec::new()));
+        let options = SimpleFileOptions::default().compression_method(CompressionMethod::Deflated);
+
+        for entry in entries {
+            writer
+                .start_file(entry.path.as_str(), options)
+                .and_then(|_| writer.write_all(&entry.bytes).map_err(Into::into))
+                .map_err(|e| {
+                    Failure::InternalServerError(format!("Failed to add {} to the archive: {}", entry.path, e))
+                })?;
+        }
+
+        let cursor = writer
+            .finish()
+            .map_err(|e| Failure::InternalServerError(format!("Failed to finish the archive: {}", e)))?;
+        Ok(cursor.into_inner())
+    }
+}
+
+impl Default for ZipArchiveApiImpl {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl ArchiveApi for ZipArchiveApiImpl {
+    async fn zip(&self, entries: Vec<ArchiveEntry>) -> DomainResponse<Vec<u8>> {
+        tokio::task::spawn_blocking(move || Self::write(entries))
+            .await
+            .map_err(|e| Failure::InternalServerError(format!("Archiving was interrupted: {}", e)))?
+    }
+}
@@ -1,5 +1,6 @@
 use domain::entities::base_entity::BaseEntity;
-use scylla::SerializeRow;
+use scylla::value::CqlValue;
+use scylla::{DeserializeRow, SerializeRow};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -10,6 +11,7 @@ use domain::entities::account_entity::AccountEntity;
 // internal modules
 use crate::secondary::repositories::mongodb::mongo_base_repository;
 use crate::secondary::repositories::scylla::scylla_base_repository;
+use crate::shared::utilities::databases::{ACCOUNT_BY_EMAIL_TABLE, ACCOUNT_BY_USERNAME_TABLE};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AccountMongoSchema {
@@ -21,6 +23,11 @@ pub struct AccountMongoSchema {
     pub email_verified: bool,
     pub bio: String,
     pub is_active: bool,
+    pub deactivated_at: Option<i64>,
+    pub deletion_due_at: Option<i64>,
+    pub suspended_at: Option<i64>,
+    pub suspended_until: Option<i64>,
+    pub suspension_reason: Option<String>,
 }
 
 impl mongo_base_repository::EntitySchema<AccountEntity> for AccountMongoSchema {
@@ -33,6 +40,11 @@ impl mongo_base_repository::EntitySchema<AccountEntity> for AccountMongoSchema {
             email_verified: entity.email_verified,
             bio: entity.bio.clone(),
             is_active: entity.is_active,
+            deactivated_at: entity.deactivated_at,
+            deletion_due_at: entity.deletion_due_at,
+            suspended_at: entity.suspended_at,
+            suspended_until: entity.suspended_until,
+            suspension_reason: entity.suspension_reason.clone(),
         }
     }
 
@@ -45,11 +57,16 @@ impl mongo_base_repository::EntitySchema<AccountEntity> for AccountMongoSchema {
             email_verified: self.email_verified,
             bio: self.bio.clone(),
             is_active: self.is_active,
+            deactivated_at: self.deactivated_at,
+            deletion_due_at: self.deletion_due_at,
+            suspended_at: self.suspended_at,
+            suspended_until: self.suspended_until,
+            suspension_reason: self.suspension_reason.clone(),
         }
     }
 }
 
-#[derive(Debug, Clone, SerializeRow)]
+#[derive(Debug, Clone, SerializeRow, DeserializeRow)]
 pub struct ScyllaAccountSchema {
     pub id: Option<Uuid>,
     pub username: String,
@@ -58,6 +75,11 @@ pub struct ScyllaAccountSchema {
     pub email_verified: bool,
     pub bio: String,
     pub is_active: bool,
+    pub deactivated_at: Option<i64>,
+    pub deletion_due_at: Option<i64>,
+    pub suspended_at: Option<i64>,
+    pub suspended_until: Option<i64>,
+    pub suspension_reason: Option<String>,
     pub created_at: i64,
     pub updated_at: i64,
     pub deleted_at: Option<i64>,
@@ -73,6 +95,11 @@ impl scylla_base_repository::EntitySchema<AccountEntity> for ScyllaAccountSchema
             email_verified: entity.email_verified,
             bio: entity.bio.clone(),
             is_active: entity.is_active,
+            deactivated_at: entity.deactivated_at,
+            deletion_due_at: entity.deletion_due_at,
+            suspended_at: entity.suspended_at,
+            suspended_until: entity.suspended_until,
+            suspension_reason: entity.suspension_reason.clone(),
             created_at: entity.base.created_at,
             updated_at: entity.base.updated_at,
             deleted_at: entity.base.deleted_at,
@@ -93,14 +120,53 @@ impl scylla_base_repository::EntitySchema<AccountEntity> for ScyllaAccountSchema
             email_verified: self.email_verified,
             bio: self.bio.clone(),
             is_active: self.is_active,
+            deactivated_at: self.deactivated_at,
+            deletion_due_at: self.deletion_due_at,
+            suspended_at: self.suspended_at,
+            suspended_until: self.suspended_until,
+            suspension_reason: self.suspension_reason.clone(),
         }
     }
 
     fn columns() -> &'static str {
-        "id, username, avatar, email, email_verified, bio, is_active, created_at, updated_at, deleted_at"
+        "id, username, avatar, email, email_verified, bio, is_active, deactivated_at, deletion_due_at, suspended_at, suspended_until, suspension_reason, created_at, updated_at, deleted_at"
     }
 
     fn insert_placeholders() -> &'static str {
-        "?, ?, ?, ?, ?, ?, ?, ?, ?, ?"
+        "?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?"
+    }
+
+    fn id(&self) -> Option<Uuid> {
+        self.id
+    }
+
+    fn set_id(&mut self, id: Uuid) {
+        self.id = Some(id);
+    }
+
+    fn deleted_at(&self) -> Option<i64> {
+        self.deleted_at
+    }
+
+    fn created_at(&self) -> i64 {
+        self.created_at
+    }
+
+    fn lookup_tables() -> &'static [&'static str] {
+        static TABLES: &[&str] = &[ACCOUNT_BY_EMAIL_TABLE, ACCOUNT_BY_USERNAME_TABLE];
+        TABLES
+    }
+
+    fn lookups(&self) -> Vec<scylla_base_repository::Lookup> {
+        vec![
+            scylla_base_repository::Lookup::new(
+                ACCOUNT_BY_EMAIL_TABLE,
+                vec![("email", CqlValue::Text(self.email.clone()))],
+            ),
+            scylla_base_repository::Lookup::new(
+                ACCOUNT_BY_USERNAME_TABLE,
+                vec![("username", CqlValue::Text(self.username.to_lowercase()))],
+            ),
+        ]
     }
 }
@@ -0,0 +1,163 @@
+use chrono::DateTime;
+use scylla::{DeserializeRow, SerializeRow};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+// shared modules
+use domain::entities::audit_event_entity::{AuditChange, AuditEventEntity};
+use domain::entities::base_entity::BaseEntity;
+use shared::models::filters::{CqlColumnType, CqlKey, CqlKeyKind};
+
+// internal modules
+use crate::secondary::repositories::models::base_schema::MongoBaseSchema;
+use crate::secondary::repositories::mongodb::mongo_base_repository;
+use crate::secondary::repositories::scylla::scylla_base_repository;
+
+/// Partition key of the Scylla table, the UTC day an event was recorded on.
+pub fn audit_event_day(timestamp: i64) -> String {
+    DateTime::from_timestamp(timestamp, 0).unwrap_or_default().format("%Y-%m-%d").to_string()
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MongoAuditEventSchema {
+    #[serde(flatten)]
+    pub base: MongoBaseSchema,
+    pub actor_id: Option<String>,
+    pub impersonator_id: Option<String>,
+    pub action: String,
+    pub target_type: String,
+    pub target_id: Option<String>,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+    pub request_id: Option<String>,
+    pub changes: Vec<AuditChange>,
+}
+
+impl mongo_base_repository::EntitySchema<AuditEventEntity> for MongoAuditEventSchema {
+    fn from_entity(entity: &AuditEventEntity) -> Self {
+        MongoAuditEventSchema {
+            base: MongoBaseSchema::from_entity(&entity.base),
+            actor_id: entity.actor_id.clone(),
+            impersonator_id: entity.impersonator_id.clone(),
+            action: entity.action.clone(),
+            target_type: entity.target_type.clone(),
+            target_id: entity.target_id.clone(),
+            ip_address: entity.ip_address.clone(),
+            user_agent: entity.user_agent.clone(),
+            request_id: entity.request_id.clone(),
+            changes: entity.changes.clone(),
+        }
+    }
+
+    fn to_entity(&self) -> AuditEventEntity {
+        AuditEventEntity {
+            base: self.base.to_entity(),
+            actor_id: self.actor_id.clone(),
+            impersonator_id: self.impersonator_id.clone(),
+            action: self.action.clone(),
+            target_type: self.target_type.clone(),
+            target_id: self.target_id.clone(),
+            ip_address: self.ip_address.clone(),
+            user_agent: self.user_agent.clone(),
+            request_id: self.request_id.clone(),
+            changes: self.changes.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, SerializeRow, DeserializeRow)]
+pub struct ScyllaAuditEventSchema {
+    pub day: String,
+    pub id: Option<Uuid>,
+    pub actor_id: Option<String>,
+    pub impersonator_id: Option<String>,
+    pub action: String,
+    pub target_type: String,
+    pub target_id: Option<String>,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+    pub request_id: Option<String>,
+    /// JSON array of the changes, they are only ever read back as a whole.
+    pub changes: String,
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub deleted_at: Option<i64>,
+}
+
+impl scylla_base_repository::EntitySchema<AuditEventEntity> for ScyllaAuditEventSchema {
+    fn from_entity(entity: &AuditEventEntity) -> Self {
+        ScyllaAuditEventSchema {
+            day: audit_event_day(entity.base.created_at),
+            id: entity.base.id.as_ref().and_then(|id| Uuid::parse_str(id).ok()),
+            actor_id: entity.actor_id.clone(),
+            impersonator_id: entity.impersonator_id.clone(),
+            action: entity.action.clone(),
+            target_type: entity.target_type.clone(),
+            target_id: entity.target_id.clone(),
+            ip_address: entity.ip_address.clone(),
+            user_agent: entity.user_agent.clone(),
+            request_id: entity.request_id.clone(),
+            changes: serde_json::to_string(&entity.changes).unwrap_or_else(|_| "[]".to_string()),
+            created_at: entity.base.created_at,
+            updated_at: entity.base.updated_at,
+            deleted_at: entity.base.deleted_at,
+        }
+    }
+
+    fn to_entity(&self) -> AuditEventEntity {
+        AuditEventEntity {
+            base: BaseEntity {
+                id: self.id.as_ref().map(|id| id.to_string()),
+                created_at: self.created_at,
+                updated_at: self.updated_at,
+                deleted_at: self.deleted_at,
+            },
+            actor_id: self.actor_id.clone(),
+            impersonator_id: self.impersonator_id.clone(),
+            action: self.action.clone(),
+            target_type: self.target_type.clone(),
+            target_id: self.target_id.clone(),
+            ip_address: self.ip_address.clone(),
+            user_agent: self.user_agent.clone(),
+            request_id: self.request_id.clone(),
+            changes: serde_json::from_str(&self.changes).unwrap_or_default(),
+        }
+    }
+
+    fn columns() -> &'static str {
+        "day, id, actor_id, impersonator_id, action, target_type, target_id, ip_address, user_agent, request_id, \
+         changes, created_at, updated_at, deleted_at"
+    }
+
+    fn insert_placeholders() -> &'static str {
+        "?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?"
+    }
+
+    fn id(&self) -> Option<Uuid> {
+        self.id
+    }
+
+    fn set_id(&mut self, id: Uuid) {
+        self.id = Some(id);
+    }
+
+    fn deleted_at(&self) -> Option<i64> {
+        self.deleted_at
+    }
+
+    fn created_at(&self) -> i64 {
+        self.created_at
+    }
+
+    fn primary_key() -> &'static [&'static str] {
+        &["day", "created_at", "id"]
+    }
+
+    fn keys() -> &'static [CqlKey] {
+        static KEYS: &[CqlKey] = &[
+            CqlKey::new("day", CqlKeyKind::Partition, CqlColumnType::Text),
+            CqlKey::new("created_at", CqlKeyKind::Clustering, CqlColumnType::BigInt),
+        ];
+        KEYS
+    }
+}
@@ -0,0 +1,137 @@
+use mongodb::bson::oid::ObjectId;
+use scylla::{DeserializeRow, SerializeRow};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+// shared modules
+use domain::entities::base_entity::BaseEntity;
+use domain::entities::blog_revision_entity::BlogRevisionEntity;
+use shared::models::filters::{CqlColumnType, CqlKey, CqlKeyKind};
+
+// internal modules
+use crate::secondary::repositories::models::base_schema::MongoBaseSchema;
+use crate::secondary::repositories::mongodb::mongo_base_repository;
+use crate::secondary::repositories::scylla::scylla_base_repository;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MongoBlogRevisionSchema {
+    #[serde(flatten)]
+    pub base: MongoBaseSchema,
+    pub blog_id: ObjectId,
+    pub author_id: ObjectId,
+    pub version: i32,
+    pub markdown: String,
+    pub content_hash: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub restored_from: Option<i32>,
+}
+
+impl mongo_base_repository::EntitySchema<BlogRevisionEntity> for MongoBlogRevisionSchema {
+    fn from_entity(entity: &BlogRevisionEntity) -> Self {
+        MongoBlogRevisionSchema {
+            base: MongoBaseSchema::from_entity(&entity.base),
+            blog_id: ObjectId::parse_str(&entity.blog_id).unwrap(),
+            author_id: ObjectId::parse_str(&entity.author_id).unwrap(),
+            version: entity.version,
+            markdown: entity.markdown.clone(),
+            content_hash: entity.content_hash.clone(),
+            restored_from: entity.restored_from,
+        }
+    }
+
+    fn to_entity(&self) -> BlogRevisionEntity {
+        BlogRevisionEntity {
+            base: self.base.to_entity(),
+            blog_id: self.blog_id.to_hex(),
+            author_id: self.author_id.to_hex(),
+            version: self.version,
+            markdown: self.markdown.clone(),
+            content_hash: self.content_hash.clone(),
+            restored_from: self.restored_from,
+        }
+    }
+}
+
+#[derive(Debug, Clone, SerializeRow, DeserializeRow)]
+pub struct ScyllaBlogRevisionSchema {
+    pub id: Option<Uuid>,
+    pub blog_id: Uuid,
+    pub author_id: Uuid,
+    pub version: i32,
+    pub markdown: String,
+    pub content_hash: String,
+    pub restored_from: Option<i32>,
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub deleted_at: Option<i64>,
+}
+
+impl scylla_base_repository::EntitySchema<BlogRevisionEntity> for ScyllaBlogRevisionSchema {
+    fn from_entity(entity: &BlogRevisionEntity) -> Self {
+        ScyllaBlogRevisionSchema {
+            id: entity.base.id.as_ref().and_then(|id| Uuid::parse_str(id).ok()),
+            created_at: entity.base.created_at,
+            updated_at: entity.base.updated_at,
+            deleted_at: entity.base.deleted_at,
+            blog_id: Uuid::parse_str(&entity.blog_id).unwrap(),
+            author_id: Uuid::parse_str(&entity.author_id).unwrap(),
+            version: entity.version,
+            markdown: entity.markdown.clone(),
+            content_hash: entity.content_hash.clone(),
+            restored_from: entity.restored_from,
+        }
+    }
+
+    fn to_entity(&self) -> BlogRevisionEntity {
+        BlogRevisionEntity {
+            base: BaseEntity {
+                id: self.id.map(|id| id.to_string()),
+                created_at: self.created_at,
+                updated_at: self.updated_at,
+                deleted_at: self.deleted_at,
+            },
+            blog_id: self.blog_id.to_string(),
+            author_id: self.author_id.to_string(),
+            version: self.version,
+            markdown: self.markdown.clone(),
+            content_hash: self.content_hash.clone(),
+            restored_from: self.restored_from,
+        }
+    }
+
+    fn columns() -> &'static str {
+        "id, blog_id, author_id, version, markdown, content_hash, restored_from, created_at, updated_at, deleted_at"
+    }
+
+    fn insert_placeholders() -> &'static str {
+        "?, ?, ?, ?, ?, ?, ?, ?, ?, ?"
+    }
+
+    fn id(&self) -> Option<Uuid> {
+        self.id
+    }
+
+    fn set_id(&mut self, id: Uuid) {
+        self.id = Some(id);
+    }
+
+    fn deleted_at(&self) -> Option<i64> {
+        self.deleted_at
+    }
+
+    fn created_at(&self) -> i64 {
+        self.created_at
+    }
+
+    fn primary_key() -> &'static [&'static str] {
+        &["blog_id", "version"]
+    }
+
+    fn keys() -> &'static [CqlKey] {
+        static KEYS: &[CqlKey] = &[
+            CqlKey::new("blog_id", CqlKeyKind::Partition, CqlColumnType::Uuid),
+            CqlKey::new("version", CqlKeyKind::Clustering, CqlColumnType::Int),
+        ];
+        KEYS
+    }
+}
@@ -1,16 +1,19 @@
 use mongodb::bson::oid::ObjectId;
-use scylla::SerializeRow;
+use scylla::value::CqlValue;
+use scylla::{DeserializeRow, SerializeRow};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 // shared modules
 use domain::entities::base_entity::BaseEntity;
-use domain::entities::blog_entity::BlogEntity;
+use domain::entities::blog_entity::{BlogEntity, DEFAULT_REVISION_RETENTION};
+use domain::values::blog_status::BlogStatus;
 
 // internal modules
 use crate::secondary::repositories::models::base_schema::MongoBaseSchema;
 use crate::secondary::repositories::mongodb::mongo_base_repository;
 use crate::secondary::repositories::scylla::scylla_base_repository;
+use crate::shared::utilities::databases::{BLOG_BY_AUTHOR_TABLE, BLOG_BY_CATEGORY_TABLE, BLOG_BY_STATUS_TABLE};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MongoBlogSchema {
@@ -20,11 +23,28 @@ pub struct MongoBlogSchema {
     pub categories: Vec<String>,
     pub name: String,
     pub description: String,
+    #[serde(default)]
+    pub status: Option<String>,
+    pub publish_at: Option<i64>,
+    pub published_at: Option<i64>,
+    /// Legacy flag written before the publishing workflow existed, only read to derive `status`.
+    #[serde(default, skip_serializing)]
     pub is_published: bool,
     pub markdown: String,
     pub stars: i32,
     pub views: i32,
     pub estimated_read_time: i32,
+    #[serde(default = "default_revision_retention")]
+    pub revision_retention: i32,
+}
+
+fn default_revision_retention() -> i32 {
+    DEFAULT_REVISION_RETENTION
+}
+
+fn legacy_status(is_published: bool) -> String {
+    let status = if is_published { BlogStatus::Published } else { BlogStatus::Draft };
+    status.as_str().to_string()
 }
 
 impl mongo_base_repository::EntitySchema<BlogEntity> for MongoBlogSchema {
@@ -35,11 +55,15 @@ impl mongo_base_repository::EntitySchema<BlogEntity> for MongoBlogSchema {
             categories: entity.categories.clone(),
             name: entity.name.clone(),
             description: entity.description.clone(),
-            is_published: entity.is_published,
+            status: Some(entity.status.clone()),
+            publish_at: entity.publish_at,
+            published_at: entity.published_at,
+            is_published: entity.is_published(),
             markdown: entity.markdown.clone(),
             stars: entity.stars,
             views: entity.views,
             estimated_read_time: entity.estimated_read_time,
+            revision_retention: entity.revision_retention,
         }
     }
 
@@ -50,27 +74,33 @@ impl mongo_base_repository::EntitySchema<BlogEntity> for MongoBlogSchema {
             categories: self.categories.clone(),
             name: self.name.clone(),
             description: self.description.clone(),
-            is_published: self.is_published,
+            status: self.status.clone().unwrap_or_else(|| legacy_status(self.is_published)),
+            publish_at: self.publish_at,
+            published_at: self.published_at,
             markdown: self.markdown.clone(),
             stars: self.stars,
             views: self.views,
             estimated_read_time: self.estimated_read_time,
+            revision_retention: self.revision_retention,
         }
     }
 }
 
-#[derive(Debug, Clone, SerializeRow)]
+#[derive(Debug, Clone, SerializeRow, DeserializeRow)]
 pub struct ScyllaBlogSchema {
     pub id: Option<Uuid>,
     pub author_id: Uuid,
     pub categories: Vec<String>,
     pub name: String,
     pub description: String,
-    pub is_published: bool,
+    pub status: String,
+    pub publish_at: Option<i64>,
+    pub published_at: Option<i64>,
     pub markdown: String,
     pub stars: i32,
     pub views: i32,
     pub estimated_read_time: i32,
+    pub revision_retention: i32,
     pub created_at: i64,
     pub updated_at: i64,
     pub deleted_at: Option<i64>,
@@ -87,11 +117,14 @@ impl scylla_base_repository::EntitySchema<BlogEntity> for ScyllaBlogSchema {
             categories: entity.categories.clone(),
             name: entity.name.clone(),
             description: entity.description.clone(),
-            is_published: entity.is_published,
+            status: entity.status.clone(),
+            publish_at: entity.publish_at,
+            published_at: entity.published_at,
             markdown: entity.markdown.clone(),
             stars: entity.stars,
             views: entity.views,
             estimated_read_time: entity.estimated_read_time,
+            revision_retention: entity.revision_retention,
         }
     }
 
@@ -107,19 +140,69 @@ impl scylla_base_repository::EntitySchema<BlogEntity> for ScyllaBlogSchema {
             categories: self.categories.clone(),
             name: self.name.clone(),
             description: self.description.clone(),
-            is_published: self.is_published,
+            status: self.status.clone(),
+            publish_at: self.publish_at,
+            published_at: self.published_at,
             markdown: self.markdown.clone(),
             stars: self.stars,
             views: self.views,
             estimated_read_time: self.estimated_read_time,
+            revision_retention: self.revision_retention,
         }
     }
 
     fn columns() -> &'static str {
-        "id, author_id, category_id, name, description, is_published, markdown, stars, views, estimated_read_time, created_at, updated_at, deleted_at"
+        "id, author_id, categories, name, description, status, publish_at, published_at, markdown, stars, views, estimated_read_time, revision_retention, created_at, updated_at, deleted_at"
     }
 
     fn insert_placeholders() -> &'static str {
-        "?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?"
+        "?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?"
+    }
+
+    fn id(&self) -> Option<Uuid> {
+        self.id
+    }
+
+    fn set_id(&mut self, id: Uuid) {
+        self.id = Some(id);
+    }
+
+    fn deleted_at(&self) -> Option<i64> {
+        self.deleted_at
+    }
+
+    fn created_at(&self) -> i64 {
+        self.created_at
+    }
+
+    fn lookup_tables() -> &'static [&'static str] {
+        static TABLES: &[&str] = &[BLOG_BY_AUTHOR_TABLE, BLOG_BY_CATEGORY_TABLE, BLOG_BY_STATUS_TABLE];
+        TABLES
+    }
+
+    fn lookups(&self) -> Vec<scylla_base_repository::Lookup> {
+        let by_author = scylla_base_repository::Lookup::new(
+            BLOG_BY_AUTHOR_TABLE,
+            vec![("author_id", CqlValue::Uuid(self.author_id)), ("created_at", CqlValue::BigInt(self.created_at))],
+        );
+        let by_status = scylla_base_repository::Lookup::new(
+            BLOG_BY_STATUS_TABLE,
+            vec![
+                ("status", CqlValue::Text(self.status.clone())),
+                ("bucket", CqlValue::Int(scylla_base_repository::lookup_bucket(self.created_at))),
+                ("created_at", CqlValue::BigInt(self.created_at)),
+            ],
+        );
+        let by_category = self.categories.iter().map(|category_id| {
+            scylla_base_repository::Lookup::new(
+                BLOG_BY_CATEGORY_TABLE,
+                vec![
+                    ("category_id", CqlValue::Text(category_id.clone())),
+                    ("created_at", CqlValue::BigInt(self.created_at)),
+                ],
+            )
+        });
+
+        [by_author, by_status].into_iter().chain(by_category).collect()
     }
 }
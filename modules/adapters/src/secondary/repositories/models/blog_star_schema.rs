@@ -0,0 +1,112 @@
+use mongodb::bson::oid::ObjectId;
+use scylla::{DeserializeRow, SerializeRow};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+// shared modules
+use domain::entities::base_entity::BaseEntity;
+use domain::entities::blog_star_entity::BlogStarEntity;
+use shared::models::filters::{CqlColumnType, CqlKey, CqlKeyKind};
+
+// internal modules
+use crate::secondary::repositories::models::base_schema::MongoBaseSchema;
+use crate::secondary::repositories::mongodb::mongo_base_repository;
+use crate::secondary::repositories::scylla::scylla_base_repository;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MongoBlogStarSchema {
+    #[serde(flatten)]
+    pub base: MongoBaseSchema,
+    pub blog_id: ObjectId,
+    pub account_id: ObjectId,
+}
+
+impl mongo_base_repository::EntitySchema<BlogStarEntity> for MongoBlogStarSchema {
+    fn from_entity(entity: &BlogStarEntity) -> Self {
+        MongoBlogStarSchema {
+            base: MongoBaseSchema::from_entity(&entity.base),
+            blog_id: ObjectId::parse_str(&entity.blog_id).unwrap(),
+            account_id: ObjectId::parse_str(&entity.account_id).unwrap(),
+        }
+    }
+
+    fn to_entity(&self) -> BlogStarEntity {
+        BlogStarEntity {
+            base: self.base.to_entity(),
+            blog_id: self.blog_id.to_hex(),
+            account_id: self.account_id.to_hex(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, SerializeRow, DeserializeRow)]
+pub struct ScyllaBlogStarSchema {
+    pub id: Option<Uuid>,
+    pub blog_id: Uuid,
+    pub account_id: Uuid,
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub deleted_at: Option<i64>,
+}
+
+impl scylla_base_repository::EntitySchema<BlogStarEntity> for ScyllaBlogStarSchema {
+    fn from_entity(entity: &BlogStarEntity) -> Self {
+        ScyllaBlogStarSchema {
+            id: entity.base.id.as_ref().and_then(|id| Uuid::parse_str(id).ok()),
+            created_at: entity.base.created_at,
+            updated_at: entity.base.updated_at,
+            deleted_at: entity.base.deleted_at,
+            blog_id: Uuid::parse_str(&entity.blog_id).unwrap(),
+            account_id: Uuid::parse_str(&entity.account_id).unwrap(),
+        }
+    }
+
+    fn to_entity(&self) -> BlogStarEntity {
+        BlogStarEntity {
+            base: BaseEntity {
+                id: self.id.map(|id| id.to_string()),
+                created_at: self.created_at,
+                updated_at: self.updated_at,
+                deleted_at: self.deleted_at,
+            },
+            blog_id: self.blog_id.to_string(),
+            account_id: self.account_id.to_string(),
+        }
+    }
+
+    fn columns() -> &'static str {
+        "id, blog_id, account_id, created_at, updated_at, deleted_at"
+    }
+
+    fn insert_placeholders() -> &'static str {
+        "?, ?, ?, ?, ?, ?"
+    }
+
+    fn id(&self) -> Option<Uuid> {
+        self.id
+    }
+
+    fn set_id(&mut self, id: Uuid) {
+        self.id = Some(id);
+    }
+
+    fn deleted_at(&self) -> Option<i64> {
+        self.deleted_at
+    }
+
+    fn created_at(&self) -> i64 {
+        self.created_at
+    }
+
+    fn primary_key() -> &'static [&'static str] {
+        &["blog_id", "account_id"]
+    }
+
+    fn keys() -> &'static [CqlKey] {
+        static KEYS: &[CqlKey] = &[
+            CqlKey::new("blog_id", CqlKeyKind::Partition, CqlColumnType::Uuid),
+            CqlKey::new("account_id", CqlKeyKind::Clustering, CqlColumnType::Uuid),
+        ];
+        KEYS
+    }
+}
@@ -0,0 +1,111 @@
+use mongodb::bson::oid::ObjectId;
+use scylla::{DeserializeRow, SerializeRow};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+// shared modules
+use domain::entities::base_entity::BaseEntity;
+use domain::entities::bookmark_entity::BookmarkEntity;
+use shared::models::filters::{CqlColumnType, CqlKey, CqlKeyKind};
+
+// internal modules
+use crate::secondary::repositories::models::base_schema::MongoBaseSchema;
+use crate::secondary::repositories::mongodb::mongo_base_repository;
+use crate::secondary::repositories::scylla::scylla_base_repository;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MongoBookmarkSchema {
+    #[serde(flatten)]
+    pub base: MongoBaseSchema,
+    pub account_id: ObjectId,
+    pub target_type: String,
+    pub target_id: String,
+}
+
+impl mongo_base_repository::EntitySchema<BookmarkEntity> for MongoBookmarkSchema {
+    fn from_entity(entity: &BookmarkEntity) -> Self {
+        MongoBookmarkSchema {
+            base: MongoBaseSchema::from_entity(&entity.base),
+            account_id: ObjectId::parse_str(&entity.account_id).unwrap(),
+            target_type: entity.target_type.clone(),
+            target_id: entity.target_id.clone(),
+        }
+    }
+
+    fn to_entity(&self) -> BookmarkEntity {
+        BookmarkEntity {
+            base: self.base.to_entity(),
+            account_id: self.account_id.to_hex(),
+            target_type: self.target_type.clone(),
+            target_id: self.target_id.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, SerializeRow, DeserializeRow)]
+pub struct ScyllaBookmarkSchema {
+    pub id: Option<Uuid>,
+    pub account_id: Uuid,
+    pub target_type: String,
+    pub target_id: String,
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub deleted_at: Option<i64>,
+}
+
+impl scylla_base_repository::EntitySchema<BookmarkEntity> for ScyllaBookmarkSchema {
+    fn from_entity(entity: &BookmarkEntity) -> Self {
+        ScyllaBookmarkSchema {
+            id: entity.base.id.as_ref().and_then(|id| Uuid::parse_str(id).ok()),
+            created_at: entity.base.created_at,
+            updated_at: entity.base.updated_at,
+            deleted_at: entity.base.deleted_at,
+            account_id: Uuid::parse_str(&entity.account_id).unwrap(),
+            target_type: entity.target_type.clone(),
+            target_id: entity.target_id.clone(),
+        }
+    }
+
+    fn to_entity(&self) -> BookmarkEntity {
+        BookmarkEntity {
+            base: BaseEntity {
+                id: self.id.map(|id| id.to_string()),
+                created_at: self.created_at,
+                updated_at: self.updated_at,
+                deleted_at: self.deleted_at,
+            },
+            account_id: self.account_id.to_string(),
+            target_type: self.target_type.clone(),
+            target_id: self.target_id.clone(),
+        }
+    }
+
+    fn columns() -> &'static str {
+        "id, account_id, target_type, target_id, created_at, updated_at, deleted_at"
+    }
+
+    fn insert_placeholders() -> &'static str {
+        "?, ?, ?, ?, ?, ?, ?"
+    }
+
+    fn id(&self) -> Option<Uuid> {
+        self.id
+    }
+
+    fn set_id(&mut self, id: Uuid) {
+        self.id = Some(id);
+    }
+
+    fn deleted_at(&self) -> Option<i64> {
+        self.deleted_at
+    }
+
+    fn created_at(&self) -> i64 {
+        self.created_at
+    }
+
+    fn keys() -> &'static [CqlKey] {
+        static KEYS: &[CqlKey] = &[CqlKey::new("account_id", CqlKeyKind::Indexed, CqlColumnType::Uuid)];
+        KEYS
+    }
+}
@@ -1,36 +1,68 @@
-use scylla::SerializeRow;
+use mongodb::bson::oid::ObjectId;
+use scylla::value::CqlValue;
+use scylla::{DeserializeRow, SerializeRow};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 // shared modules
-use domain::entities::{base_entity::BaseEntity, category_entity::CategoryEntity};
+use domain::entities::{
+    base_entity::BaseEntity,
+    category_entity::{CategoryEntity, ROOT_CATEGORY_PATH},
+};
 
 // internal modules
 use crate::secondary::repositories::{
     models::base_schema::MongoBaseSchema, mongodb::mongo_base_repository, scylla::scylla_base_repository,
 };
+use crate::shared::utilities::databases::CATEGORY_BY_NAME_TABLE;
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct MongoCategorySchema {
     #[serde(flatten)]
     pub base: MongoBaseSchema,
     pub name: String,
+    /// Categories written before nesting existed have no parent, path or depth and are roots.
+    #[serde(default)]
+    pub parent_id: Option<ObjectId>,
+    #[serde(default = "root_path")]
+    pub path: String,
+    #[serde(default)]
+    pub depth: i32,
+}
+
+fn root_path() -> String {
+    ROOT_CATEGORY_PATH.to_string()
 }
 
 impl mongo_base_repository::EntitySchema<CategoryEntity> for MongoCategorySchema {
     fn from_entity(entity: &CategoryEntity) -> Self {
-        MongoCategorySchema { base: MongoBaseSchema::from_entity(&entity.base), name: entity.name.clone() }
+        MongoCategorySchema {
+            base: MongoBaseSchema::from_entity(&entity.base),
+            name: entity.name.clone(),
+            parent_id: entity.parent_id.as_ref().and_then(|id| ObjectId::parse_str(id).ok()),
+            path: entity.path.clone(),
+            depth: entity.depth,
+        }
     }
 
     fn to_entity(&self) -> CategoryEntity {
-        CategoryEntity { base: self.base.to_entity(), name: self.name.clone() }
+        CategoryEntity {
+            base: self.base.to_entity(),
+            name: self.name.clone(),
+            parent_id: self.parent_id.map(|id| id.to_hex()),
+            path: self.path.clone(),
+            depth: self.depth,
+        }
     }
 }
 
-#[derive(Debug, Clone, SerializeRow)]
+#[derive(Debug, Clone, SerializeRow, DeserializeRow)]
 pub struct ScyllaCategorySchema {
     pub id: Option<Uuid>,
     pub name: String,
+    pub parent_id: Option<Uuid>,
+    pub path: String,
+    pub depth: i32,
     pub created_at: i64,
     pub updated_at: i64,
     pub deleted_at: Option<i64>,
@@ -41,6 +73,9 @@ impl scylla_base_repository::EntitySchema<CategoryEntity> for ScyllaCategorySche
         ScyllaCategorySchema {
             id: entity.base.id.as_ref().and_then(|id| Uuid::parse_str(id).ok()),
             name: entity.name.clone(),
+            parent_id: entity.parent_id.as_ref().and_then(|id| Uuid::parse_str(id).ok()),
+            path: entity.path.clone(),
+            depth: entity.depth,
             created_at: entity.base.created_at,
             updated_at: entity.base.updated_at,
             deleted_at: entity.base.deleted_at,
@@ -56,14 +91,45 @@ impl scylla_base_repository::EntitySchema<CategoryEntity> for ScyllaCategorySche
                 deleted_at: self.deleted_at,
             },
             name: self.name.clone(),
+            parent_id: self.parent_id.map(|id| id.to_string()),
+            path: self.path.clone(),
+            depth: self.depth,
         }
     }
 
     fn columns() -> &'static str {
-        "id, name, created_at, updated_at, deleted_at"
+        "id, name, parent_id, path, depth, created_at, updated_at, deleted_at"
     }
 
     fn insert_placeholders() -> &'static str {
-        "?, ?, ?, ?, ?"
+        "?, ?, ?, ?, ?, ?, ?, ?"
+    }
+
+    fn id(&self) -> Option<Uuid> {
+        self.id
+    }
+
+    fn set_id(&mut self, id: Uuid) {
+        self.id = Some(id);
+    }
+
+    fn deleted_at(&self) -> Option<i64> {
+        self.deleted_at
+    }
+
+    fn created_at(&self) -> i64 {
+        self.created_at
+    }
+
+    fn lookup_tables() -> &'static [&'static str] {
+        static TABLES: &[&str] = &[CATEGORY_BY_NAME_TABLE];
+        TABLES
+    }
+
+    fn lookups(&self) -> Vec<scylla_base_repository::Lookup> {
+        vec![scylla_base_repository::Lookup::new(
+            CATEGORY_BY_NAME_TABLE,
+            vec![("name", CqlValue::Text(self.name.clone()))],
+        )]
     }
 }
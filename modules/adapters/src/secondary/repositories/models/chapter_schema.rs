@@ -0,0 +1,111 @@
+use mongodb::bson::oid::ObjectId;
+use scylla::{DeserializeRow, SerializeRow};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+// shared modules
+use domain::entities::base_entity::BaseEntity;
+use domain::entities::chapter_entity::ChapterEntity;
+
+// internal modules
+use crate::secondary::repositories::models::base_schema::MongoBaseSchema;
+use crate::secondary::repositories::mongodb::mongo_base_repository;
+use crate::secondary::repositories::scylla::scylla_base_repository;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MongoChapterSchema {
+    #[serde(flatten)]
+    pub base: MongoBaseSchema,
+    pub course_id: ObjectId,
+    pub order_index: i32,
+    pub name: String,
+    pub description: String,
+}
+
+impl mongo_base_repository::EntitySchema<ChapterEntity> for MongoChapterSchema {
+    fn from_entity(entity: &ChapterEntity) -> Self {
+        MongoChapterSchema {
+            base: MongoBaseSchema::from_entity(&entity.base),
+            course_id: ObjectId::parse_str(&entity.course_id).unwrap(),
+            order_index: entity.order_index,
+            name: entity.name.clone(),
+            description: entity.description.clone(),
+        }
+    }
+
+    fn to_entity(&self) -> ChapterEntity {
+        ChapterEntity {
+            base: self.base.to_entity(),
+            course_id: self.course_id.to_hex(),
+            order_index: self.order_index,
+            name: self.name.clone(),
+            description: self.description.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, SerializeRow, DeserializeRow)]
+pub struct ScyllaChapterSchema {
+    pub id: Option<Uuid>,
+    pub course_id: Uuid,
+    pub order_index: i32,
+    pub name: String,
+    pub description: String,
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub deleted_at: Option<i64>,
+}
+
+impl scylla_base_repository::EntitySchema<ChapterEntity> for ScyllaChapterSchema {
+    fn from_entity(entity: &ChapterEntity) -> Self {
+        ScyllaChapterSchema {
+            id: entity.base.id.as_ref().and_then(|id| Uuid::parse_str(id).ok()),
+            created_at: entity.base.created_at,
+            updated_at: entity.base.updated_at,
+            deleted_at: entity.base.deleted_at,
+            course_id: Uuid::parse_str(&entity.course_id).unwrap(),
+            order_index: entity.order_index,
+            name: entity.name.clone(),
+            description: entity.description.clone(),
+        }
+    }
+
+    fn to_entity(&self) -> ChapterEntity {
+        ChapterEntity {
+            base: BaseEntity {
+                id: self.id.map(|id| id.to_string()),
+                created_at: self.created_at,
+                updated_at: self.updated_at,
+                deleted_at: self.deleted_at,
+            },
+            course_id: self.course_id.to_string(),
+            order_index: self.order_index,
+            name: self.name.clone(),
+            description: self.description.clone(),
+        }
+    }
+
+    fn columns() -> &'static str {
+        "id, course_id, order_index, name, description, created_at, updated_at, deleted_at"
+    }
+
+    fn insert_placeholders() -> &'static str {
+        "?, ?, ?, ?, ?, ?, ?, ?"
+    }
+
+    fn id(&self) -> Option<Uuid> {
+        self.id
+    }
+
+    fn set_id(&mut self, id: Uuid) {
+        self.id = Some(id);
+    }
+
+    fn deleted_at(&self) -> Option<i64> {
+        self.deleted_at
+    }
+
+    fn created_at(&self) -> i64 {
+        self.created_at
+    }
+}
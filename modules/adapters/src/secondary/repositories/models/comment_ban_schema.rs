@@ -0,0 +1,105 @@
+use mongodb::bson::oid::ObjectId;
+use scylla::{DeserializeRow, SerializeRow};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+// shared modules
+use domain::entities::base_entity::BaseEntity;
+use domain::entities::comment_ban_entity::CommentBanEntity;
+
+// internal modules
+use crate::secondary::repositories::models::base_schema::MongoBaseSchema;
+use crate::secondary::repositories::mongodb::mongo_base_repository;
+use crate::secondary::repositories::scylla::scylla_base_repository;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MongoCommentBanSchema {
+    #[serde(flatten)]
+    pub base: MongoBaseSchema,
+    pub account_id: ObjectId,
+    pub banned_by: ObjectId,
+    pub reason: String,
+}
+
+impl mongo_base_repository::EntitySchema<CommentBanEntity> for MongoCommentBanSchema {
+    fn from_entity(entity: &CommentBanEntity) -> Self {
+        MongoCommentBanSchema {
+            base: MongoBaseSchema::from_entity(&entity.base),
+            account_id: ObjectId::parse_str(&entity.account_id).unwrap(),
+            banned_by: ObjectId::parse_str(&entity.banned_by).unwrap(),
+            reason: entity.reason.clone(),
+        }
+    }
+
+    fn to_entity(&self) -> CommentBanEntity {
+        CommentBanEntity {
+            base: self.base.to_entity(),
+            account_id: self.account_id.to_hex(),
+            banned_by: self.banned_by.to_hex(),
+            reason: self.reason.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, SerializeRow, DeserializeRow)]
+pub struct ScyllaCommentBanSchema {
+    pub id: Option<Uuid>,
+    pub account_id: Uuid,
+    pub banned_by: Uuid,
+    pub reason: String,
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub deleted_at: Option<i64>,
+}
+
+impl scylla_base_repository::EntitySchema<CommentBanEntity> for ScyllaCommentBanSchema {
+    fn from_entity(entity: &CommentBanEntity) -> Self {
+        ScyllaCommentBanSchema {
+            id: entity.base.id.as_ref().and_then(|id| Uuid::parse_str(id).ok()),
+            created_at: entity.base.created_at,
+            updated_at: entity.base.updated_at,
+            deleted_at: entity.base.deleted_at,
+            account_id: Uuid::parse_str(&entity.account_id).unwrap(),
+            banned_by: Uuid::parse_str(&entity.banned_by).unwrap(),
+            reason: entity.reason.clone(),
+        }
+    }
+
+    fn to_entity(&self) -> CommentBanEntity {
+        CommentBanEntity {
+            base: BaseEntity {
+                id: self.id.map(|id| id.to_string()),
+                created_at: self.created_at,
+                updated_at: self.updated_at,
+                deleted_at: self.deleted_at,
+            },
+            account_id: self.account_id.to_string(),
+            banned_by: self.banned_by.to_string(),
+            reason: self.reason.clone(),
+        }
+    }
+
+    fn columns() -> &'static str {
+        "id, account_id, banned_by, reason, created_at, updated_at, deleted_at"
+    }
+
+    fn insert_placeholders() -> &'static str {
+        "?, ?, ?, ?, ?, ?, ?"
+    }
+
+    fn id(&self) -> Option<Uuid> {
+        self.id
+    }
+
+    fn set_id(&mut self, id: Uuid) {
+        self.id = Some(id);
+    }
+
+    fn deleted_at(&self) -> Option<i64> {
+        self.deleted_at
+    }
+
+    fn created_at(&self) -> i64 {
+        self.created_at
+    }
+}
@@ -0,0 +1,123 @@
+use mongodb::bson::oid::ObjectId;
+use scylla::{DeserializeRow, SerializeRow};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+// shared modules
+use domain::entities::base_entity::BaseEntity;
+use domain::entities::comment_entity::CommentEntity;
+
+// internal modules
+use crate::secondary::repositories::models::base_schema::MongoBaseSchema;
+use crate::secondary::repositories::mongodb::mongo_base_repository;
+use crate::secondary::repositories::scylla::scylla_base_repository;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MongoCommentSchema {
+    #[serde(flatten)]
+    pub base: MongoBaseSchema,
+    pub blog_id: ObjectId,
+    pub author_id: ObjectId,
+    pub parent_id: Option<ObjectId>,
+    pub content: String,
+    pub status: String,
+    pub edited_at: Option<i64>,
+}
+
+impl mongo_base_repository::EntitySchema<CommentEntity> for MongoCommentSchema {
+    fn from_entity(entity: &CommentEntity) -> Self {
+        MongoCommentSchema {
+            base: MongoBaseSchema::from_entity(&entity.base),
+            blog_id: ObjectId::parse_str(&entity.blog_id).unwrap(),
+            author_id: ObjectId::parse_str(&entity.author_id).unwrap(),
+            parent_id: entity.parent_id.as_ref().and_then(|id| ObjectId::parse_str(id).ok()),
+            content: entity.content.clone(),
+            status: entity.status.clone(),
+            edited_at: entity.edited_at,
+        }
+    }
+
+    fn to_entity(&self) -> CommentEntity {
+        CommentEntity {
+            base: self.base.to_entity(),
+            blog_id: self.blog_id.to_hex(),
+            author_id: self.author_id.to_hex(),
+            parent_id: self.parent_id.map(|id| id.to_hex()),
+            content: self.content.clone(),
+            status: self.status.clone(),
+            edited_at: self.edited_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, SerializeRow, DeserializeRow)]
+pub struct ScyllaCommentSchema {
+    pub id: Option<Uuid>,
+    pub blog_id: Uuid,
+    pub author_id: Uuid,
+    pub parent_id: Option<Uuid>,
+    pub content: String,
+    pub status: String,
+    pub edited_at: Option<i64>,
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub deleted_at: Option<i64>,
+}
+
+impl scylla_base_repository::EntitySchema<CommentEntity> for ScyllaCommentSchema {
+    fn from_entity(entity: &CommentEntity) -> Self {
+        ScyllaCommentSchema {
+            id: entity.base.id.as_ref().and_then(|id| Uuid::parse_str(id).ok()),
+            created_at: entity.base.created_at,
+            updated_at: entity.base.updated_at,
+            deleted_at: entity.base.deleted_at,
+            blog_id: Uuid::parse_str(&entity.blog_id).unwrap(),
+            author_id: Uuid::parse_str(&entity.author_id).unwrap(),
+            parent_id: entity.parent_id.as_ref().and_then(|id| Uuid::parse_str(id).ok()),
+            content: entity.content.clone(),
+            status: entity.status.clone(),
+            edited_at: entity.edited_at,
+        }
+    }
+
+    fn to_entity(&self) -> CommentEntity {
+        CommentEntity {
+            base: BaseEntity {
+                id: self.id.map(|id| id.to_string()),
+                created_at: self.created_at,
+                updated_at: self.updated_at,
+                deleted_at: self.deleted_at,
+            },
+            blog_id: self.blog_id.to_string(),
+            author_id: self.author_id.to_string(),
+            parent_id: self.parent_id.map(|id| id.to_string()),
+            content: self.content.clone(),
+            status: self.status.clone(),
+            edited_at: self.edited_at,
+        }
+    }
+
+    fn columns() -> &'static str {
+        "id, blog_id, author_id, parent_id, content, status, edited_at, created_at, updated_at, deleted_at"
+    }
+
+    fn insert_placeholders() -> &'static str {
+        "?, ?, ?, ?, ?, ?, ?, ?, ?, ?"
+    }
+
+    fn id(&self) -> Option<Uuid> {
+        self.id
+    }
+
+    fn set_id(&mut self, id: Uuid) {
+        self.id = Some(id);
+    }
+
+    fn deleted_at(&self) -> Option<i64> {
+        self.deleted_at
+    }
+
+    fn created_at(&self) -> i64 {
+        self.created_at
+    }
+}
@@ -0,0 +1,148 @@
+use mongodb::bson::oid::ObjectId;
+use scylla::{DeserializeRow, SerializeRow};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+// shared modules
+use domain::entities::base_entity::BaseEntity;
+use domain::entities::course_entity::CourseEntity;
+
+// internal modules
+use crate::secondary::repositories::models::base_schema::MongoBaseSchema;
+use crate::secondary::repositories::mongodb::mongo_base_repository;
+use crate::secondary::repositories::scylla::scylla_base_repository;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MongoCourseSchema {
+    #[serde(flatten)]
+    pub base: MongoBaseSchema,
+    pub author_id: ObjectId,
+    pub category_id: ObjectId,
+    pub name: String,
+    pub description: String,
+    pub level: String,
+    pub status: String,
+    pub rating: f32,
+    #[serde(default)]
+    pub review_count: i32,
+    pub approximate: i32,
+    pub enrollments: i32,
+}
+
+impl mongo_base_repository::EntitySchema<CourseEntity> for MongoCourseSchema {
+    fn from_entity(entity: &CourseEntity) -> Self {
+        MongoCourseSchema {
+            base: MongoBaseSchema::from_entity(&entity.base),
+            author_id: ObjectId::parse_str(&entity.author_id).unwrap(),
+            category_id: ObjectId::parse_str(&entity.category_id).unwrap(),
+            name: entity.name.clone(),
+            description: entity.description.clone(),
+            level: entity.level.clone(),
+            status: entity.status.clone(),
+            rating: entity.rating,
+            review_count: entity.review_count,
+            approximate: entity.approximate,
+            enrollments: entity.enrollments,
+        }
+    }
+
+    fn to_entity(&self) -> CourseEntity {
+        CourseEntity {
+            base: self.base.to_entity(),
+            author_id: self.author_id.to_hex(),
+            category_id: self.category_id.to_hex(),
+            name: self.name.clone(),
+            description: self.description.clone(),
+            level: self.level.clone(),
+            status: self.status.clone(),
+            rating: self.rating,
+            review_count: self.review_count,
+            approximate: self.approximate,
+            enrollments: self.enrollments,
+        }
+    }
+}
+
+#[derive(Debug, Clone, SerializeRow, DeserializeRow)]
+pub struct ScyllaCourseSchema {
+    pub id: Option<Uuid>,
+    pub author_id: Uuid,
+    pub category_id: Uuid,
+    pub name: String,
+    pub description: String,
+    pub level: String,
+    pub status: String,
+    pub rating: f32,
+    pub review_count: i32,
+    pub approximate: i32,
+    pub enrollments: i32,
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub deleted_at: Option<i64>,
+}
+
+impl scylla_base_repository::EntitySchema<CourseEntity> for ScyllaCourseSchema {
+    fn from_entity(entity: &CourseEntity) -> Self {
+        ScyllaCourseSchema {
+            id: entity.base.id.as_ref().and_then(|id| Uuid::parse_str(id).ok()),
+            created_at: entity.base.created_at,
+            updated_at: entity.base.updated_at,
+            deleted_at: entity.base.deleted_at,
+            author_id: Uuid::parse_str(&entity.author_id).unwrap(),
+            category_id: Uuid::parse_str(&entity.category_id).unwrap(),
+            name: entity.name.clone(),
+            description: entity.description.clone(),
+            level: entity.level.clone(),
+            status: entity.status.clone(),
+            rating: entity.rating,
+            review_count: entity.review_count,
+            approximate: entity.approximate,
+            enrollments: entity.enrollments,
+        }
+    }
+
+    fn to_entity(&self) -> CourseEntity {
+        CourseEntity {
+            base: BaseEntity {
+                id: self.id.map(|id| id.to_string()),
+                created_at: self.created_at,
+                updated_at: self.updated_at,
+                deleted_at: self.deleted_at,
+            },
+            author_id: self.author_id.to_string(),
+            category_id: self.category_id.to_string(),
+            name: self.name.clone(),
+            description: self.description.clone(),
+            level: self.level.clone(),
+            status: self.status.clone(),
+            rating: self.rating,
+            review_count: self.review_count,
+            approximate: self.approximate,
+            enrollments: self.enrollments,
+        }
+    }
+
+    fn columns() -> &'static str {
+        "id, author_id, category_id, name, description, level, status, rating, review_count, approximate, enrollments, created_at, updated_at, deleted_at"
+    }
+
+    fn insert_placeholders() -> &'static str {
+        "?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?"
+    }
+
+    fn id(&self) -> Option<Uuid> {
+        self.id
+    }
+
+    fn set_id(&mut self, id: Uuid) {
+        self.id = Some(id);
+    }
+
+    fn deleted_at(&self) -> Option<i64> {
+        self.deleted_at
+    }
+
+    fn created_at(&self) -> i64 {
+        self.created_at
+    }
+}
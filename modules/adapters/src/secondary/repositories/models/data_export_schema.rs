@@ -0,0 +1,129 @@
+use mongodb::bson::oid::ObjectId;
+use scylla::{DeserializeRow, SerializeRow};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+// shared modules
+use domain::entities::base_entity::BaseEntity;
+use domain::entities::data_export_entity::DataExportEntity;
+
+// internal modules
+use crate::secondary::repositories::models::base_schema::MongoBaseSchema;
+use crate::secondary::repositories::mongodb::mongo_base_repository;
+use crate::secondary::repositories::scylla::scylla_base_repository;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MongoDataExportSchema {
+    #[serde(flatten)]
+    pub base: MongoBaseSchema,
+    pub account_id: ObjectId,
+    pub status: String,
+    pub storage_key: Option<String>,
+    pub size: Option<i64>,
+    pub error: Option<String>,
+    pub completed_at: Option<i64>,
+    pub expires_at: Option<i64>,
+}
+
+impl mongo_base_repository::EntitySchema<DataExportEntity> for MongoDataExportSchema {
+    fn from_entity(entity: &DataExportEntity) -> Self {
+        MongoDataExportSchema {
+            base: MongoBaseSchema::from_entity(&entity.base),
+            account_id: ObjectId::parse_str(&entity.account_id).unwrap(),
+            status: entity.status.clone(),
+            storage_key: entity.storage_key.clone(),
+            size: entity.size,
+            error: entity.error.clone(),
+            completed_at: entity.completed_at,
+            expires_at: entity.expires_at,
+        }
+    }
+
+    fn to_entity(&self) -> DataExportEntity {
+        DataExportEntity {
+            base: self.base.to_entity(),
+            account_id: self.account_id.to_hex(),
+            status: self.status.clone(),
+            storage_key: self.storage_key.clone(),
+            size: self.size,
+            error: self.error.clone(),
+            completed_at: self.completed_at,
+            expires_at: self.expires_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, SerializeRow, DeserializeRow)]
+pub struct ScyllaDataExportSchema {
+    pub id: Option<Uuid>,
+    pub account_id: Uuid,
+    pub status: String,
+    pub storage_key: Option<String>,
+    pub size: Option<i64>,
+    pub error: Option<String>,
+    pub completed_at: Option<i64>,
+    pub expires_at: Option<i64>,
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub deleted_at: Option<i64>,
+}
+
+impl scylla_base_repository::EntitySchema<DataExportEntity> for ScyllaDataExportSchema {
+    fn from_entity(entity: &DataExportEntity) -> Self {
+        ScyllaDataExportSchema {
+            id: entity.base.id.as_ref().and_then(|id| Uuid::parse_str(id).ok()),
+            account_id: Uuid::parse_str(&entity.account_id).unwrap(),
+            status: entity.status.clone(),
+            storage_key: entity.storage_key.clone(),
+            size: entity.size,
+            error: entity.error.clone(),
+            completed_at: entity.completed_at,
+            expires_at: entity.expires_at,
+            created_at: entity.base.created_at,
+            updated_at: entity.base.updated_at,
+            deleted_at: entity.base.deleted_at,
+        }
+    }
+
+    fn to_entity(&self) -> DataExportEntity {
+        DataExportEntity {
+            base: BaseEntity {
+                id: self.id.as_ref().map(|id| id.to_string()),
+                created_at: self.created_at,
+                updated_at: self.updated_at,
+                deleted_at: self.deleted_at,
+            },
+            account_id: self.account_id.to_string(),
+            status: self.status.clone(),
+            storage_key: self.storage_key.clone(),
+            size: self.size,
+            error: self.error.clone(),
+            completed_at: self.completed_at,
+            expires_at: self.expires_at,
+        }
+    }
+
+    fn columns() -> &'static str {
+        "id, account_id, status, storage_key, size, error, completed_at, expires_at, created_at, updated_at, deleted_at"
+    }
+
+    fn insert_placeholders() -> &'static str {
+        "?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?"
+    }
+
+    fn id(&self) -> Option<Uuid> {
+        self.id
+    }
+
+    fn set_id(&mut self, id: Uuid) {
+        self.id = Some(id);
+    }
+
+    fn deleted_at(&self) -> Option<i64> {
+        self.deleted_at
+    }
+
+    fn created_at(&self) -> i64 {
+        self.created_at
+    }
+}
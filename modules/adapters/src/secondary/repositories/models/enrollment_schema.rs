@@ -0,0 +1,138 @@
+use mongodb::bson::oid::ObjectId;
+use scylla::value::CqlValue;
+use scylla::{DeserializeRow, SerializeRow};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+// shared modules
+use domain::entities::base_entity::BaseEntity;
+use domain::entities::enrollment_entity::EnrollmentEntity;
+
+// internal modules
+use crate::secondary::repositories::models::base_schema::MongoBaseSchema;
+use crate::secondary::repositories::mongodb::mongo_base_repository;
+use crate::secondary::repositories::scylla::scylla_base_repository;
+use crate::shared::utilities::databases::{ENROLLMENT_BY_ACCOUNT_TABLE, ENROLLMENT_BY_COURSE_TABLE};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MongoEnrollmentSchema {
+    #[serde(flatten)]
+    pub base: MongoBaseSchema,
+    pub account_id: ObjectId,
+    pub course_id: ObjectId,
+    pub status: String,
+    pub progress: f32,
+    pub completed_at: Option<i64>,
+}
+
+impl mongo_base_repository::EntitySchema<EnrollmentEntity> for MongoEnrollmentSchema {
+    fn from_entity(entity: &EnrollmentEntity) -> Self {
+        MongoEnrollmentSchema {
+            base: MongoBaseSchema::from_entity(&entity.base),
+            account_id: ObjectId::parse_str(&entity.account_id).unwrap(),
+            course_id: ObjectId::parse_str(&entity.course_id).unwrap(),
+            status: entity.status.clone(),
+            progress: entity.progress,
+            completed_at: entity.completed_at,
+        }
+    }
+
+    fn to_entity(&self) -> EnrollmentEntity {
+        EnrollmentEntity {
+            base: self.base.to_entity(),
+            account_id: self.account_id.to_hex(),
+            course_id: self.course_id.to_hex(),
+            status: self.status.clone(),
+            progress: self.progress,
+            completed_at: self.completed_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, SerializeRow, DeserializeRow)]
+pub struct ScyllaEnrollmentSchema {
+    pub id: Option<Uuid>,
+    pub account_id: Uuid,
+    pub course_id: Uuid,
+    pub status: String,
+    pub progress: f32,
+    pub completed_at: Option<i64>,
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub deleted_at: Option<i64>,
+}
+
+impl scylla_base_repository::EntitySchema<EnrollmentEntity> for ScyllaEnrollmentSchema {
+    fn from_entity(entity: &EnrollmentEntity) -> Self {
+        ScyllaEnrollmentSchema {
+            id: entity.base.id.as_ref().and_then(|id| Uuid::parse_str(id).ok()),
+            created_at: entity.base.created_at,
+            updated_at: entity.base.updated_at,
+            deleted_at: entity.base.deleted_at,
+            account_id: Uuid::parse_str(&entity.account_id).unwrap(),
+            course_id: Uuid::parse_str(&entity.course_id).unwrap(),
+            status: entity.status.clone(),
+            progress: entity.progress,
+            completed_at: entity.completed_at,
+        }
+    }
+
+    fn to_entity(&self) -> EnrollmentEntity {
+        EnrollmentEntity {
+            base: BaseEntity {
+                id: self.id.map(|id| id.to_string()),
+                created_at: self.created_at,
+                updated_at: self.updated_at,
+                deleted_at: self.deleted_at,
+            },
+            account_id: self.account_id.to_string(),
+            course_id: self.course_id.to_string(),
+            status: self.status.clone(),
+            progress: self.progress,
+            completed_at: self.completed_at,
+        }
+    }
+
+    fn columns() -> &'static str {
+        "id, account_id, course_id, status, progress, completed_at, created_at, updated_at, deleted_at"
+    }
+
+    fn insert_placeholders() -> &'static str {
+        "?, ?, ?, ?, ?, ?, ?, ?, ?"
+    }
+
+    fn id(&self) -> Option<Uuid> {
+        self.id
+    }
+
+    fn set_id(&mut self, id: Uuid) {
+        self.id = Some(id);
+    }
+
+    fn deleted_at(&self) -> Option<i64> {
+        self.deleted_at
+    }
+
+    fn created_at(&self) -> i64 {
+        self.created_at
+    }
+
+    fn lookup_tables() -> &'static [&'static str] {
+        static TABLES: &[&str] = &[ENROLLMENT_BY_ACCOUNT_TABLE, ENROLLMENT_BY_COURSE_TABLE];
+        TABLES
+    }
+
+    fn lookups(&self) -> Vec<scylla_base_repository::Lookup> {
+        vec![
+            // Unique, an account enrolls in a course once
+            scylla_base_repository::Lookup::unique(
+                ENROLLMENT_BY_ACCOUNT_TABLE,
+                vec![("account_id", CqlValue::Uuid(self.account_id)), ("course_id", CqlValue::Uuid(self.course_id))],
+            ),
+            scylla_base_repository::Lookup::new(
+                ENROLLMENT_BY_COURSE_TABLE,
+                vec![("course_id", CqlValue::Uuid(self.course_id))],
+            ),
+        ]
+    }
+}
@@ -1,4 +1,4 @@
-use scylla::SerializeRow;
+use scylla::{DeserializeRow, SerializeRow};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -54,7 +54,7 @@ impl EntitySchema<ExperienceEntity> for MongoExperienceSchema {
     }
 }
 
-#[derive(Debug, Clone, SerializeRow)]
+#[derive(Debug, Clone, SerializeRow, DeserializeRow)]
 pub struct ScyllaExperienceSchema {
     pub id: Option<Uuid>,
     pub technologies: Vec<String>,
@@ -114,4 +114,20 @@ impl scylla_base_repository::EntitySchema<ExperienceEntity> for ScyllaExperience
     fn insert_placeholders() -> &'static str {
         "?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?"
     }
+
+    fn id(&self) -> Option<Uuid> {
+        self.id
+    }
+
+    fn set_id(&mut self, id: Uuid) {
+        self.id = Some(id);
+    }
+
+    fn deleted_at(&self) -> Option<i64> {
+        self.deleted_at
+    }
+
+    fn created_at(&self) -> i64 {
+        self.created_at
+    }
 }
@@ -0,0 +1,123 @@
+use mongodb::bson::oid::ObjectId;
+use scylla::{DeserializeRow, SerializeRow};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+// shared modules
+use domain::entities::base_entity::BaseEntity;
+use domain::entities::lesson_entity::LessonEntity;
+
+// internal modules
+use crate::secondary::repositories::models::base_schema::MongoBaseSchema;
+use crate::secondary::repositories::mongodb::mongo_base_repository;
+use crate::secondary::repositories::scylla::scylla_base_repository;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MongoLessonSchema {
+    #[serde(flatten)]
+    pub base: MongoBaseSchema,
+    pub chapter_id: ObjectId,
+    pub order_index: i32,
+    pub name: String,
+    pub description: String,
+    pub markdown: String,
+    pub estimated_time_ms: i32,
+}
+
+impl mongo_base_repository::EntitySchema<LessonEntity> for MongoLessonSchema {
+    fn from_entity(entity: &LessonEntity) -> Self {
+        MongoLessonSchema {
+            base: MongoBaseSchema::from_entity(&entity.base),
+            chapter_id: ObjectId::parse_str(&entity.chapter_id).unwrap(),
+            order_index: entity.order_index,
+            name: entity.name.clone(),
+            description: entity.description.clone(),
+            markdown: entity.markdown.clone(),
+            estimated_time_ms: entity.estimated_time_ms,
+        }
+    }
+
+    fn to_entity(&self) -> LessonEntity {
+        LessonEntity {
+            base: self.base.to_entity(),
+            chapter_id: self.chapter_id.to_hex(),
+            order_index: self.order_index,
+            name: self.name.clone(),
+            description: self.description.clone(),
+            markdown: self.markdown.clone(),
+            estimated_time_ms: self.estimated_time_ms,
+        }
+    }
+}
+
+#[derive(Debug, Clone, SerializeRow, DeserializeRow)]
+pub struct ScyllaLessonSchema {
+    pub id: Option<Uuid>,
+    pub chapter_id: Uuid,
+    pub order_index: i32,
+    pub name: String,
+    pub description: String,
+    pub markdown: String,
+    pub estimated_time_ms: i32,
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub deleted_at: Option<i64>,
+}
+
+impl scylla_base_repository::EntitySchema<LessonEntity> for ScyllaLessonSchema {
+    fn from_entity(entity: &LessonEntity) -> Self {
+        ScyllaLessonSchema {
+            id: entity.base.id.as_ref().and_then(|id| Uuid::parse_str(id).ok()),
+            created_at: entity.base.created_at,
+            updated_at: entity.base.updated_at,
+            deleted_at: entity.base.deleted_at,
+            chapter_id: Uuid::parse_str(&entity.chapter_id).unwrap(),
+            order_index: entity.order_index,
+            name: entity.name.clone(),
+            description: entity.description.clone(),
+            markdown: entity.markdown.clone(),
+            estimated_time_ms: entity.estimated_time_ms,
+        }
+    }
+
+    fn to_entity(&self) -> LessonEntity {
+        LessonEntity {
+            base: BaseEntity {
+                id: self.id.map(|id| id.to_string()),
+                created_at: self.created_at,
+                updated_at: self.updated_at,
+                deleted_at: self.deleted_at,
+            },
+            chapter_id: self.chapter_id.to_string(),
+            order_index: self.order_index,
+            name: self.name.clone(),
+            description: self.description.clone(),
+            markdown: self.markdown.clone(),
+            estimated_time_ms: self.estimated_time_ms,
+        }
+    }
+
+    fn columns() -> &'static str {
+        "id, chapter_id, order_index, name, description, markdown, estimated_time_ms, created_at, updated_at, deleted_at"
+    }
+
+    fn insert_placeholders() -> &'static str {
+        "?, ?, ?, ?, ?, ?, ?, ?, ?, ?"
+    }
+
+    fn id(&self) -> Option<Uuid> {
+        self.id
+    }
+
+    fn set_id(&mut self, id: Uuid) {
+        self.id = Some(id);
+    }
+
+    fn deleted_at(&self) -> Option<i64> {
+        self.deleted_at
+    }
+
+    fn created_at(&self) -> i64 {
+        self.created_at
+    }
+}
@@ -0,0 +1,154 @@
+use mongodb::bson::oid::ObjectId;
+use scylla::{DeserializeRow, SerializeRow};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+// shared modules
+use domain::entities::base_entity::BaseEntity;
+use domain::entities::media_asset_entity::MediaAssetEntity;
+
+// internal modules
+use crate::secondary::repositories::models::base_schema::MongoBaseSchema;
+use crate::secondary::repositories::mongodb::mongo_base_repository;
+use crate::secondary::repositories::scylla::scylla_base_repository;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MongoMediaAssetSchema {
+    #[serde(flatten)]
+    pub base: MongoBaseSchema,
+    pub account_id: ObjectId,
+    pub purpose: String,
+    pub reference_id: Option<String>,
+    pub original_name: String,
+    pub content_type: String,
+    pub size: i64,
+    pub width: i32,
+    pub height: i32,
+    pub variants: HashMap<String, String>,
+    pub storage_keys: Vec<String>,
+    pub detached_at: Option<i64>,
+}
+
+impl mongo_base_repository::EntitySchema<MediaAssetEntity> for MongoMediaAssetSchema {
+    fn from_entity(entity: &MediaAssetEntity) -> Self {
+        MongoMediaAssetSchema {
+            base: MongoBaseSchema::from_entity(&entity.base),
+            account_id: ObjectId::parse_str(&entity.account_id).unwrap(),
+            purpose: entity.purpose.clone(),
+            reference_id: entity.reference_id.clone(),
+            original_name: entity.original_name.clone(),
+            content_type: entity.content_type.clone(),
+            size: entity.size,
+            width: entity.width,
+            height: entity.height,
+            variants: entity.variants.clone(),
+            storage_keys: entity.storage_keys.clone(),
+            detached_at: entity.detached_at,
+        }
+    }
+
+    fn to_entity(&self) -> MediaAssetEntity {
+        MediaAssetEntity {
+            base: self.base.to_entity(),
+            account_id: self.account_id.to_hex(),
+            purpose: self.purpose.clone(),
+            reference_id: self.reference_id.clone(),
+            original_name: self.original_name.clone(),
+            content_type: self.content_type.clone(),
+            size: self.size,
+            width: self.width,
+            height: self.height,
+            variants: self.variants.clone(),
+            storage_keys: self.storage_keys.clone(),
+            detached_at: self.detached_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, SerializeRow, DeserializeRow)]
+pub struct ScyllaMediaAssetSchema {
+    pub id: Option<Uuid>,
+    pub account_id: Uuid,
+    pub purpose: String,
+    pub reference_id: Option<String>,
+    pub original_name: String,
+    pub content_type: String,
+    pub size: i64,
+    pub width: i32,
+    pub height: i32,
+    pub variants: HashMap<String, String>,
+    pub storage_keys: Vec<String>,
+    pub detached_at: Option<i64>,
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub deleted_at: Option<i64>,
+}
+
+impl scylla_base_repository::EntitySchema<MediaAssetEntity> for ScyllaMediaAssetSchema {
+    fn from_entity(entity: &MediaAssetEntity) -> Self {
+        ScyllaMediaAssetSchema {
+            id: entity.base.id.as_ref().and_then(|id| Uuid::parse_str(id).ok()),
+            account_id: Uuid::parse_str(&entity.account_id).unwrap(),
+            purpose: entity.purpose.clone(),
+            reference_id: entity.reference_id.clone(),
+            original_name: entity.original_name.clone(),
+            content_type: entity.content_type.clone(),
+            size: entity.size,
+            width: entity.width,
+            height: entity.height,
+            variants: entity.variants.clone(),
+            storage_keys: entity.storage_keys.clone(),
+            detached_at: entity.detached_at,
+            created_at: entity.base.created_at,
+            updated_at: entity.base.updated_at,
+            deleted_at: entity.base.deleted_at,
+        }
+    }
+
+    fn to_entity(&self) -> MediaAssetEntity {
+        MediaAssetEntity {
+            base: BaseEntity {
+                id: self.id.as_ref().map(|id| id.to_string()),
+                created_at: self.created_at,
+                updated_at: self.updated_at,
+                deleted_at: self.deleted_at,
+            },
+            account_id: self.account_id.to_string(),
+            purpose: self.purpose.clone(),
+            reference_id: self.reference_id.clone(),
+            original_name: self.original_name.clone(),
+            content_type: self.content_type.clone(),
+            size: self.size,
+            width: self.width,
+            height: self.height,
+            variants: self.variants.clone(),
+            storage_keys: self.storage_keys.clone(),
+            detached_at: self.detached_at,
+        }
+    }
+
+    fn columns() -> &'static str {
+        "id, account_id, purpose, reference_id, original_name, content_type, size, width, height, variants, storage_keys, detached_at, created_at, updated_at, deleted_at"
+    }
+
+    fn insert_placeholders() -> &'static str {
+        "?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?"
+    }
+
+    fn id(&self) -> Option<Uuid> {
+        self.id
+    }
+
+    fn set_id(&mut self, id: Uuid) {
+        self.id = Some(id);
+    }
+
+    fn deleted_at(&self) -> Option<i64> {
+        self.deleted_at
+    }
+
+    fn created_at(&self) -> i64 {
+        self.created_at
+    }
+}
@@ -1,10 +1,27 @@
 pub mod account_schema;
+pub mod audit_event_schema;
 pub mod base_schema;
+pub mod blog_revision_schema;
 pub mod blog_schema;
+pub mod blog_star_schema;
+pub mod bookmark_schema;
 pub mod category_schema;
+pub mod chapter_schema;
+pub mod comment_ban_schema;
+pub mod comment_schema;
+pub mod course_schema;
+pub mod data_export_schema;
+pub mod enrollment_schema;
 pub mod experience_schema;
+pub mod lesson_schema;
+pub mod media_asset_schema;
+pub mod notification_preference_schema;
 pub mod notification_schema;
+pub mod progress_schema;
 pub mod project_schema;
 pub mod provider_schema;
+pub mod review_schema;
 pub mod role_schema;
 pub mod session_schema;
+pub mod topic_subscription_schema;
+pub mod upload_schema;
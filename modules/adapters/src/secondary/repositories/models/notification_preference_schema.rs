@@ -0,0 +1,152 @@
+use mongodb::bson::oid::ObjectId;
+use scylla::value::CqlValue;
+use scylla::{DeserializeRow, SerializeRow};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use uuid::Uuid;
+
+// shared modules
+use domain::entities::base_entity::BaseEntity;
+use domain::entities::notification_preference_entity::NotificationPreferenceEntity;
+
+// internal modules
+use crate::secondary::repositories::models::base_schema::MongoBaseSchema;
+use crate::secondary::repositories::mongodb::mongo_base_repository;
+use crate::secondary::repositories::scylla::scylla_base_repository;
+use crate::shared::utilities::databases::NOTIFICATION_PREFERENCE_BY_ACCOUNT_TABLE;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MongoNotificationPreferenceSchema {
+    #[serde(flatten)]
+    pub base: MongoBaseSchema,
+    pub account_id: ObjectId,
+    #[serde(default)]
+    pub muted_channels: HashMap<String, Vec<String>>,
+    pub quiet_hours_start: Option<i32>,
+    pub quiet_hours_end: Option<i32>,
+    #[serde(default)]
+    pub utc_offset_minutes: i32,
+    pub digest_frequency: String,
+    pub last_digest_at: Option<i64>,
+    pub next_digest_at: Option<i64>,
+}
+
+impl mongo_base_repository::EntitySchema<NotificationPreferenceEntity> for MongoNotificationPreferenceSchema {
+    fn from_entity(entity: &NotificationPreferenceEntity) -> Self {
+        MongoNotificationPreferenceSchema {
+            base: MongoBaseSchema::from_entity(&entity.base),
+            account_id: ObjectId::parse_str(&entity.account_id).unwrap(),
+            muted_channels: entity.muted_channels.clone(),
+            quiet_hours_start: entity.quiet_hours_start,
+            quiet_hours_end: entity.quiet_hours_end,
+            utc_offset_minutes: entity.utc_offset_minutes,
+            digest_frequency: entity.digest_frequency.clone(),
+            last_digest_at: entity.last_digest_at,
+            next_digest_at: entity.next_digest_at,
+        }
+    }
+
+    fn to_entity(&self) -> NotificationPreferenceEntity {
+        NotificationPreferenceEntity {
+            base: self.base.to_entity(),
+            account_id: self.account_id.to_hex(),
+            muted_channels: self.muted_channels.clone(),
+            quiet_hours_start: self.quiet_hours_start,
+            quiet_hours_end: self.quiet_hours_end,
+            utc_offset_minutes: self.utc_offset_minutes,
+            digest_frequency: self.digest_frequency.clone(),
+            last_digest_at: self.last_digest_at,
+            next_digest_at: self.next_digest_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, SerializeRow, DeserializeRow)]
+pub struct ScyllaNotificationPreferenceSchema {
+    pub id: Option<Uuid>,
+    pub account_id: Uuid,
+    pub muted_channels: HashMap<String, Vec<String>>,
+    pub quiet_hours_start: Option<i32>,
+    pub quiet_hours_end: Option<i32>,
+    pub utc_offset_minutes: i32,
+    pub digest_frequency: String,
+    pub last_digest_at: Option<i64>,
+    pub next_digest_at: Option<i64>,
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub deleted_at: Option<i64>,
+}
+
+impl scylla_base_repository::EntitySchema<NotificationPreferenceEntity> for ScyllaNotificationPreferenceSchema {
+    fn from_entity(entity: &NotificationPreferenceEntity) -> Self {
+        ScyllaNotificationPreferenceSchema {
+            id: entity.base.id.as_ref().and_then(|id| Uuid::parse_str(id).ok()),
+            account_id: Uuid::parse_str(&entity.account_id).unwrap(),
+            muted_channels: entity.muted_channels.clone(),
+            quiet_hours_start: entity.quiet_hours_start,
+            quiet_hours_end: entity.quiet_hours_end,
+            utc_offset_minutes: entity.utc_offset_minutes,
+            digest_frequency: entity.digest_frequency.clone(),
+            last_digest_at: entity.last_digest_at,
+            next_digest_at: entity.next_digest_at,
+            created_at: entity.base.created_at,
+            updated_at: entity.base.updated_at,
+            deleted_at: entity.base.deleted_at,
+        }
+    }
+
+    fn to_entity(&self) -> NotificationPreferenceEntity {
+        NotificationPreferenceEntity {
+            base: BaseEntity {
+                id: self.id.as_ref().map(|id| id.to_string()),
+                created_at: self.created_at,
+                updated_at: self.updated_at,
+                deleted_at: self.deleted_at,
+            },
+            account_id: self.account_id.to_string(),
+            muted_channels: self.muted_channels.clone(),
+            quiet_hours_start: self.quiet_hours_start,
+            quiet_hours_end: self.quiet_hours_end,
+            utc_offset_minutes: self.utc_offset_minutes,
+            digest_frequency: self.digest_frequency.clone(),
+            last_digest_at: self.last_digest_at,
+            next_digest_at: self.next_digest_at,
+        }
+    }
+
+    fn columns() -> &'static str {
+        "id, account_id, muted_channels, quiet_hours_start, quiet_hours_end, utc_offset_minutes, digest_frequency, last_digest_at, next_digest_at, created_at, updated_at, deleted_at"
+    }
+
+    fn insert_placeholders() -> &'static str {
+        "?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?"
+    }
+
+    fn id(&self) -> Option<Uuid> {
+        self.id
+    }
+
+    fn set_id(&mut self, id: Uuid) {
+        self.id = Some(id);
+    }
+
+    fn deleted_at(&self) -> Option<i64> {
+        self.deleted_at
+    }
+
+    fn created_at(&self) -> i64 {
+        self.created_at
+    }
+
+    fn lookup_tables() -> &'static [&'static str] {
+        static TABLES: &[&str] = &[NOTIFICATION_PREFERENCE_BY_ACCOUNT_TABLE];
+        TABLES
+    }
+
+    fn lookups(&self) -> Vec<scylla_base_repository::Lookup> {
+        vec![scylla_base_repository::Lookup::new(
+            NOTIFICATION_PREFERENCE_BY_ACCOUNT_TABLE,
+            vec![("account_id", CqlValue::Uuid(self.account_id))],
+        )]
+    }
+}
@@ -1,23 +1,38 @@
 use mongodb::bson::oid::ObjectId;
-use scylla::SerializeRow;
+use scylla::value::CqlValue;
+use scylla::{DeserializeRow, SerializeRow};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use uuid::Uuid;
 
 // shared modules
 use domain::entities::{base_entity::BaseEntity, notification_entity::NotificationEntity};
+use domain::values::notification_kind::NotificationKind;
 
 // internal modules
 use crate::secondary::repositories::{
     models::base_schema::MongoBaseSchema, mongodb::mongo_base_repository, scylla::scylla_base_repository,
 };
+use crate::shared::utilities::databases::NOTIFICATION_BY_ACCOUNT_TABLE;
 
 #[derive(Clone, Serialize, Deserialize)]
 pub struct MongoNotificationSchema {
     #[serde(flatten)]
     base: MongoBaseSchema,
     pub account_id: ObjectId,
+    #[serde(default = "legacy_kind")]
+    pub kind: String,
     pub message: String,
+    #[serde(default)]
+    pub payload: HashMap<String, String>,
     pub is_read: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub read_at: Option<i64>,
+}
+
+/// Notifications stored before kinds existed were plain messages.
+fn legacy_kind() -> String {
+    NotificationKind::System.as_str().to_string()
 }
 
 impl mongo_base_repository::EntitySchema<NotificationEntity> for MongoNotificationSchema {
@@ -25,8 +40,11 @@ impl mongo_base_repository::EntitySchema<NotificationEntity> for MongoNotificati
         Self {
             base: MongoBaseSchema::from_entity(&entity.base),
             account_id: ObjectId::parse_str(&entity.account_id).unwrap(),
+            kind: entity.kind.clone(),
             message: entity.message.clone(),
+            payload: entity.payload.clone(),
             is_read: entity.is_read,
+            read_at: entity.read_at,
         }
     }
 
@@ -34,18 +52,24 @@ impl mongo_base_repository::EntitySchema<NotificationEntity> for MongoNotificati
         NotificationEntity {
             base: self.base.to_entity(),
             account_id: self.account_id.to_hex(),
+            kind: self.kind.clone(),
             message: self.message.clone(),
+            payload: self.payload.clone(),
             is_read: self.is_read,
+            read_at: self.read_at,
         }
     }
 }
 
-#[derive(Clone, Debug, SerializeRow)]
+#[derive(Clone, Debug, SerializeRow, DeserializeRow)]
 pub struct ScyllaNotificationSchema {
     pub id: Option<Uuid>,
     pub account_id: Uuid,
+    pub kind: String,
     pub message: String,
+    pub payload: HashMap<String, String>,
     pub is_read: bool,
+    pub read_at: Option<i64>,
     pub created_at: i64,
     pub updated_at: i64,
     pub deleted_at: Option<i64>,
@@ -56,8 +80,11 @@ impl scylla_base_repository::EntitySchema<NotificationEntity> for ScyllaNotifica
         Self {
             id: entity.base.id.as_ref().and_then(|id| Uuid::parse_str(id).ok()),
             account_id: Uuid::parse_str(&entity.account_id).unwrap(),
+            kind: entity.kind.clone(),
             message: entity.message.clone(),
+            payload: entity.payload.clone(),
             is_read: entity.is_read,
+            read_at: entity.read_at,
             created_at: entity.base.created_at,
             updated_at: entity.base.updated_at,
             deleted_at: entity.base.deleted_at,
@@ -73,16 +100,47 @@ impl scylla_base_repository::EntitySchema<NotificationEntity> for ScyllaNotifica
                 deleted_at: self.deleted_at,
             },
             account_id: self.account_id.to_string(),
+            kind: self.kind.clone(),
             message: self.message.clone(),
+            payload: self.payload.clone(),
             is_read: self.is_read,
+            read_at: self.read_at,
         }
     }
 
     fn columns() -> &'static str {
-        "id, account_id, message, is_read, created_at, updated_at, deleted_at"
+        "id, account_id, kind, message, payload, is_read, read_at, created_at, updated_at, deleted_at"
     }
 
     fn insert_placeholders() -> &'static str {
-        "?, ?, ?, ?, ?, ?, ?"
+        "?, ?, ?, ?, ?, ?, ?, ?, ?, ?"
+    }
+
+    fn id(&self) -> Option<Uuid> {
+        self.id
+    }
+
+    fn set_id(&mut self, id: Uuid) {
+        self.id = Some(id);
+    }
+
+    fn deleted_at(&self) -> Option<i64> {
+        self.deleted_at
+    }
+
+    fn created_at(&self) -> i64 {
+        self.created_at
+    }
+
+    fn lookup_tables() -> &'static [&'static str] {
+        static TABLES: &[&str] = &[NOTIFICATION_BY_ACCOUNT_TABLE];
+        TABLES
+    }
+
+    fn lookups(&self) -> Vec<scylla_base_repository::Lookup> {
+        vec![scylla_base_repository::Lookup::new(
+            NOTIFICATION_BY_ACCOUNT_TABLE,
+            vec![("account_id", CqlValue::Uuid(self.account_id)), ("created_at", CqlValue::BigInt(self.created_at))],
+        )]
     }
 }
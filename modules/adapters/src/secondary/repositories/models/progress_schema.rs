@@ -0,0 +1,123 @@
+use mongodb::bson::oid::ObjectId;
+use scylla::{DeserializeRow, SerializeRow};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+// shared modules
+use domain::entities::base_entity::BaseEntity;
+use domain::entities::progress_entity::ProgressEntity;
+
+// internal modules
+use crate::secondary::repositories::models::base_schema::MongoBaseSchema;
+use crate::secondary::repositories::mongodb::mongo_base_repository;
+use crate::secondary::repositories::scylla::scylla_base_repository;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MongoProgressSchema {
+    #[serde(flatten)]
+    pub base: MongoBaseSchema,
+    pub enrollment_id: ObjectId,
+    pub lesson_id: ObjectId,
+    pub is_completed: bool,
+    pub completed_at: Option<i64>,
+    pub time_spend_second: i32,
+    pub visits: i32,
+}
+
+impl mongo_base_repository::EntitySchema<ProgressEntity> for MongoProgressSchema {
+    fn from_entity(entity: &ProgressEntity) -> Self {
+        MongoProgressSchema {
+            base: MongoBaseSchema::from_entity(&entity.base),
+            enrollment_id: ObjectId::parse_str(&entity.enrollment_id).unwrap(),
+            lesson_id: ObjectId::parse_str(&entity.lesson_id).unwrap(),
+            is_completed: entity.is_completed,
+            completed_at: entity.completed_at,
+            time_spend_second: entity.time_spend_second,
+            visits: entity.visits,
+        }
+    }
+
+    fn to_entity(&self) -> ProgressEntity {
+        ProgressEntity {
+            base: self.base.to_entity(),
+            enrollment_id: self.enrollment_id.to_hex(),
+            lesson_id: self.lesson_id.to_hex(),
+            is_completed: self.is_completed,
+            completed_at: self.completed_at,
+            time_spend_second: self.time_spend_second,
+            visits: self.visits,
+        }
+    }
+}
+
+#[derive(Debug, Clone, SerializeRow, DeserializeRow)]
+pub struct ScyllaProgressSchema {
+    pub id: Option<Uuid>,
+    pub enrollment_id: Uuid,
+    pub lesson_id: Uuid,
+    pub is_completed: bool,
+    pub completed_at: Option<i64>,
+    pub time_spend_second: i32,
+    pub visits: i32,
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub deleted_at: Option<i64>,
+}
+
+impl scylla_base_repository::EntitySchema<ProgressEntity> for ScyllaProgressSchema {
+    fn from_entity(entity: &ProgressEntity) -> Self {
+        ScyllaProgressSchema {
+            id: entity.base.id.as_ref().and_then(|id| Uuid::parse_str(id).ok()),
+            created_at: entity.base.created_at,
+            updated_at: entity.base.updated_at,
+            deleted_at: entity.base.deleted_at,
+            enrollment_id: Uuid::parse_str(&entity.enrollment_id).unwrap(),
+            lesson_id: Uuid::parse_str(&entity.lesson_id).unwrap(),
+            is_completed: entity.is_completed,
+            completed_at: entity.completed_at,
+            time_spend_second: entity.time_spend_second,
+            visits: entity.visits,
+        }
+    }
+
+    fn to_entity(&self) -> ProgressEntity {
+        ProgressEntity {
+            base: BaseEntity {
+                id: self.id.map(|id| id.to_string()),
+                created_at: self.created_at,
+                updated_at: self.updated_at,
+                deleted_at: self.deleted_at,
+            },
+            enrollment_id: self.enrollment_id.to_string(),
+            lesson_id: self.lesson_id.to_string(),
+            is_completed: self.is_completed,
+            completed_at: self.completed_at,
+            time_spend_second: self.time_spend_second,
+            visits: self.visits,
+        }
+    }
+
+    fn columns() -> &'static str {
+        "id, enrollment_id, lesson_id, is_completed, completed_at, time_spend_second, visits, created_at, updated_at, deleted_at"
+    }
+
+    fn insert_placeholders() -> &'static str {
+        "?, ?, ?, ?, ?, ?, ?, ?, ?, ?"
+    }
+
+    fn id(&self) -> Option<Uuid> {
+        self.id
+    }
+
+    fn set_id(&mut self, id: Uuid) {
+        self.id = Some(id);
+    }
+
+    fn deleted_at(&self) -> Option<i64> {
+        self.deleted_at
+    }
+
+    fn created_at(&self) -> i64 {
+        self.created_at
+    }
+}
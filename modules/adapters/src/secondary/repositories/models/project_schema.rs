@@ -1,4 +1,5 @@
-use scylla::SerializeRow;
+use scylla::value::CqlValue;
+use scylla::{DeserializeRow, SerializeRow};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -9,6 +10,7 @@ use domain::entities::{base_entity::BaseEntity, project_entity::ProjectEntity};
 use crate::secondary::repositories::{
     models::base_schema::MongoBaseSchema, mongodb::mongo_base_repository, scylla::scylla_base_repository,
 };
+use crate::shared::utilities::databases::PROJECT_BY_NAME_TABLE;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MongoProjectSchema {
@@ -51,7 +53,7 @@ impl mongo_base_repository::EntitySchema<ProjectEntity> for MongoProjectSchema {
     }
 }
 
-#[derive(Debug, Clone, SerializeRow)]
+#[derive(Debug, Clone, SerializeRow, DeserializeRow)]
 pub struct ScyllaProjectSchema {
     pub id: Option<Uuid>,
     pub cover: String,
@@ -108,4 +110,32 @@ impl scylla_base_repository::EntitySchema<ProjectEntity> for ScyllaProjectSchema
     fn insert_placeholders() -> &'static str {
         "?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?"
     }
+
+    fn id(&self) -> Option<Uuid> {
+        self.id
+    }
+
+    fn set_id(&mut self, id: Uuid) {
+        self.id = Some(id);
+    }
+
+    fn deleted_at(&self) -> Option<i64> {
+        self.deleted_at
+    }
+
+    fn created_at(&self) -> i64 {
+        self.created_at
+    }
+
+    fn lookup_tables() -> &'static [&'static str] {
+        static TABLES: &[&str] = &[PROJECT_BY_NAME_TABLE];
+        TABLES
+    }
+
+    fn lookups(&self) -> Vec<scylla_base_repository::Lookup> {
+        vec![scylla_base_repository::Lookup::new(
+            PROJECT_BY_NAME_TABLE,
+            vec![("name", CqlValue::Text(self.name.clone()))],
+        )]
+    }
 }
@@ -1,5 +1,6 @@
 use mongodb::bson::oid::ObjectId;
-use scylla::SerializeRow;
+use scylla::value::CqlValue;
+use scylla::{DeserializeRow, SerializeRow};
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 use uuid::Uuid;
@@ -11,6 +12,7 @@ use domain::entities::{base_entity::BaseEntity, provider_entity::ProviderEntity}
 use crate::secondary::repositories::{
     models::base_schema::MongoBaseSchema, mongodb::mongo_base_repository, scylla::scylla_base_repository,
 };
+use crate::shared::utilities::databases::PROVIDER_BY_ACCOUNT_TABLE;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MongoProviderSchema {
@@ -41,10 +43,10 @@ impl mongo_base_repository::EntitySchema<ProviderEntity> for MongoProviderSchema
     }
 }
 
-#[derive(Debug, Clone, SerializeRow)]
+#[derive(Debug, Clone, SerializeRow, DeserializeRow)]
 pub struct ScyllaProviderSchema {
     pub id: Option<Uuid>,
-    pub account_id: String,
+    pub account_id: Uuid,
     pub auth_provider: String,
     pub identify: String,
     pub created_at: i64,
@@ -59,7 +61,7 @@ impl scylla_base_repository::EntitySchema<ProviderEntity> for ScyllaProviderSche
             created_at: entity.base.created_at,
             updated_at: entity.base.updated_at,
             deleted_at: entity.base.deleted_at,
-            account_id: entity.account_id.clone(),
+            account_id: Uuid::parse_str(&entity.account_id).unwrap(),
             auth_provider: entity.auth_provider.clone(),
             identify: entity.identify.clone(),
         }
@@ -73,7 +75,7 @@ impl scylla_base_repository::EntitySchema<ProviderEntity> for ScyllaProviderSche
                 updated_at: self.updated_at,
                 deleted_at: self.deleted_at,
             },
-            account_id: self.account_id.clone(),
+            account_id: self.account_id.to_string(),
             auth_provider: self.auth_provider.clone(),
             identify: self.identify.clone(),
         }
@@ -86,4 +88,32 @@ impl scylla_base_repository::EntitySchema<ProviderEntity> for ScyllaProviderSche
     fn insert_placeholders() -> &'static str {
         "?, ?, ?, ?, ?, ?, ?"
     }
+
+    fn id(&self) -> Option<Uuid> {
+        self.id
+    }
+
+    fn set_id(&mut self, id: Uuid) {
+        self.id = Some(id);
+    }
+
+    fn deleted_at(&self) -> Option<i64> {
+        self.deleted_at
+    }
+
+    fn created_at(&self) -> i64 {
+        self.created_at
+    }
+
+    fn lookup_tables() -> &'static [&'static str] {
+        static TABLES: &[&str] = &[PROVIDER_BY_ACCOUNT_TABLE];
+        TABLES
+    }
+
+    fn lookups(&self) -> Vec<scylla_base_repository::Lookup> {
+        vec![scylla_base_repository::Lookup::new(
+            PROVIDER_BY_ACCOUNT_TABLE,
+            vec![("account_id", CqlValue::Uuid(self.account_id))],
+        )]
+    }
 }
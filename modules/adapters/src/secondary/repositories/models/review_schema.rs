@@ -0,0 +1,117 @@
+use mongodb::bson::oid::ObjectId;
+use scylla::{DeserializeRow, SerializeRow};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+// shared modules
+use domain::entities::base_entity::BaseEntity;
+use domain::entities::review_entity::ReviewEntity;
+
+// internal modules
+use crate::secondary::repositories::models::base_schema::MongoBaseSchema;
+use crate::secondary::repositories::mongodb::mongo_base_repository;
+use crate::secondary::repositories::scylla::scylla_base_repository;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MongoReviewSchema {
+    #[serde(flatten)]
+    pub base: MongoBaseSchema,
+    pub course_id: ObjectId,
+    pub account_id: ObjectId,
+    pub rating: i32,
+    pub content: String,
+    pub status: String,
+}
+
+impl mongo_base_repository::EntitySchema<ReviewEntity> for MongoReviewSchema {
+    fn from_entity(entity: &ReviewEntity) -> Self {
+        MongoReviewSchema {
+            base: MongoBaseSchema::from_entity(&entity.base),
+            course_id: ObjectId::parse_str(&entity.course_id).unwrap(),
+            account_id: ObjectId::parse_str(&entity.account_id).unwrap(),
+            rating: entity.rating,
+            content: entity.content.clone(),
+            status: entity.status.clone(),
+        }
+    }
+
+    fn to_entity(&self) -> ReviewEntity {
+        ReviewEntity {
+            base: self.base.to_entity(),
+            course_id: self.course_id.to_hex(),
+            account_id: self.account_id.to_hex(),
+            rating: self.rating,
+            content: self.content.clone(),
+            status: self.status.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, SerializeRow, DeserializeRow)]
+pub struct ScyllaReviewSchema {
+    pub id: Option<Uuid>,
+    pub course_id: Uuid,
+    pub account_id: Uuid,
+    pub rating: i32,
+    pub content: String,
+    pub status: String,
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub deleted_at: Option<i64>,
+}
+
+impl scylla_base_repository::EntitySchema<ReviewEntity> for ScyllaReviewSchema {
+    fn from_entity(entity: &ReviewEntity) -> Self {
+        ScyllaReviewSchema {
+            id: entity.base.id.as_ref().and_then(|id| Uuid::parse_str(id).ok()),
+            created_at: entity.base.created_at,
+            updated_at: entity.base.updated_at,
+            deleted_at: entity.base.deleted_at,
+            course_id: Uuid::parse_str(&entity.course_id).unwrap(),
+            account_id: Uuid::parse_str(&entity.account_id).unwrap(),
+            rating: entity.rating,
+            content: entity.content.clone(),
+            status: entity.status.clone(),
+        }
+    }
+
+    fn to_entity(&self) -> ReviewEntity {
+        ReviewEntity {
+            base: BaseEntity {
+                id: self.id.map(|id| id.to_string()),
+                created_at: self.created_at,
+                updated_at: self.updated_at,
+                deleted_at: self.deleted_at,
+            },
+            course_id: self.course_id.to_string(),
+            account_id: self.account_id.to_string(),
+            rating: self.rating,
+            content: self.content.clone(),
+            status: self.status.clone(),
+        }
+    }
+
+    fn columns() -> &'static str {
+        "id, course_id, account_id, rating, content, status, created_at, updated_at, deleted_at"
+    }
+
+    fn insert_placeholders() -> &'static str {
+        "?, ?, ?, ?, ?, ?, ?, ?, ?"
+    }
+
+    fn id(&self) -> Option<Uuid> {
+        self.id
+    }
+
+    fn set_id(&mut self, id: Uuid) {
+        self.id = Some(id);
+    }
+
+    fn deleted_at(&self) -> Option<i64> {
+        self.deleted_at
+    }
+
+    fn created_at(&self) -> i64 {
+        self.created_at
+    }
+}
@@ -1,5 +1,6 @@
 use mongodb::bson::oid::ObjectId;
-use scylla::SerializeRow;
+use scylla::value::CqlValue;
+use scylla::{DeserializeRow, SerializeRow};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
@@ -10,6 +11,7 @@ use domain::entities::{base_entity::BaseEntity, role_entity::RoleEntity};
 use crate::secondary::repositories::{
     models::base_schema::MongoBaseSchema, mongodb::mongo_base_repository, scylla::scylla_base_repository,
 };
+use crate::shared::utilities::databases::{ROLE_BY_ACCOUNT_TABLE, ROLE_BY_NAME_TABLE};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MongoRoleSchema {
@@ -37,10 +39,10 @@ impl mongo_base_repository::EntitySchema<RoleEntity> for MongoRoleSchema {
     }
 }
 
-#[derive(Debug, Clone, SerializeRow)]
+#[derive(Debug, Clone, SerializeRow, DeserializeRow)]
 pub struct ScyllaRoleSchema {
     pub id: Option<Uuid>,
-    pub account_id: String,
+    pub account_id: Uuid,
     pub role_name: String,
     pub created_at: i64,
     pub updated_at: i64,
@@ -54,7 +56,7 @@ impl scylla_base_repository::EntitySchema<RoleEntity> for ScyllaRoleSchema {
             created_at: entity.base.created_at,
             updated_at: entity.base.updated_at,
             deleted_at: entity.base.deleted_at,
-            account_id: entity.account_id.clone(),
+            account_id: Uuid::parse_str(&entity.account_id).unwrap(),
             role_name: entity.role_name.clone(),
         }
     }
@@ -67,7 +69,7 @@ impl scylla_base_repository::EntitySchema<RoleEntity> for ScyllaRoleSchema {
                 updated_at: self.updated_at,
                 deleted_at: self.deleted_at,
             },
-            account_id: self.account_id.clone(),
+            account_id: self.account_id.to_string(),
             role_name: self.role_name.clone(),
         }
     }
@@ -79,4 +81,41 @@ impl scylla_base_repository::EntitySchema<RoleEntity> for ScyllaRoleSchema {
     fn insert_placeholders() -> &'static str {
         "?, ?, ?, ?, ?, ?"
     }
+
+    fn id(&self) -> Option<Uuid> {
+        self.id
+    }
+
+    fn set_id(&mut self, id: Uuid) {
+        self.id = Some(id);
+    }
+
+    fn deleted_at(&self) -> Option<i64> {
+        self.deleted_at
+    }
+
+    fn created_at(&self) -> i64 {
+        self.created_at
+    }
+
+    fn lookup_tables() -> &'static [&'static str] {
+        static TABLES: &[&str] = &[ROLE_BY_ACCOUNT_TABLE, ROLE_BY_NAME_TABLE];
+        TABLES
+    }
+
+    fn lookups(&self) -> Vec<scylla_base_repository::Lookup> {
+        vec![
+            scylla_base_repository::Lookup::new(
+                ROLE_BY_ACCOUNT_TABLE,
+                vec![("account_id", CqlValue::Uuid(self.account_id))],
+            ),
+            scylla_base_repository::Lookup::new(
+                ROLE_BY_NAME_TABLE,
+                vec![
+                    ("role_name", CqlValue::Text(self.role_name.clone())),
+                    ("bucket", CqlValue::Int(scylla_base_repository::lookup_bucket(self.created_at))),
+                ],
+            ),
+        ]
+    }
 }
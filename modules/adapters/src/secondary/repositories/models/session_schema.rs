@@ -1,5 +1,6 @@
 use mongodb::bson::oid::ObjectId;
-use scylla::SerializeRow;
+use scylla::value::CqlValue;
+use scylla::{DeserializeRow, SerializeRow};
 use serde::{Deserialize, Serialize};
 
 // shared modules
@@ -10,6 +11,7 @@ use uuid::Uuid;
 use crate::secondary::repositories::{
     models::base_schema::MongoBaseSchema, mongodb::mongo_base_repository, scylla::scylla_base_repository,
 };
+use crate::shared::utilities::databases::{SESSION_BY_ACCOUNT_TABLE, SESSION_BY_JTI_TABLE};
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct MongoSessionSchema {
@@ -21,6 +23,8 @@ pub struct MongoSessionSchema {
     pub ip_address: String,
     pub user_agent: String,
     pub device_type: String,
+    #[serde(default)]
+    pub device_token: Option<String>,
 }
 
 impl mongo_base_repository::EntitySchema<SessionEntity> for MongoSessionSchema {
@@ -33,6 +37,7 @@ impl mongo_base_repository::EntitySchema<SessionEntity> for MongoSessionSchema {
             ip_address: entity.ip_address.clone(),
             user_agent: entity.user_agent.clone(),
             device_type: entity.device_type.clone(),
+            device_token: entity.device_token.clone(),
         }
     }
 
@@ -45,19 +50,21 @@ impl mongo_base_repository::EntitySchema<SessionEntity> for MongoSessionSchema {
             ip_address: self.ip_address.clone(),
             user_agent: self.user_agent.clone(),
             device_type: self.device_type.clone(),
+            device_token: self.device_token.clone(),
         }
     }
 }
 
-#[derive(Debug, Clone, SerializeRow)]
+#[derive(Debug, Clone, SerializeRow, DeserializeRow)]
 pub struct ScyllaSessionSchema {
     pub id: Option<Uuid>,
-    pub account_id: String,
+    pub account_id: Uuid,
     pub jti: String,
     pub expires_at: i64,
     pub ip_address: String,
     pub user_agent: String,
     pub device_type: String,
+    pub device_token: Option<String>,
     pub created_at: i64,
     pub updated_at: i64,
     pub deleted_at: Option<i64>,
@@ -70,12 +77,13 @@ impl scylla_base_repository::EntitySchema<SessionEntity> for ScyllaSessionSchema
             created_at: entity.base.created_at,
             updated_at: entity.base.updated_at,
             deleted_at: entity.base.deleted_at,
-            account_id: entity.account_id.clone(),
+            account_id: Uuid::parse_str(&entity.account_id).unwrap(),
             jti: entity.jti.clone(),
             expires_at: entity.expires_at,
             ip_address: entity.ip_address.clone(),
             user_agent: entity.user_agent.clone(),
             device_type: entity.device_type.clone(),
+            device_token: entity.device_token.clone(),
         }
     }
 
@@ -87,20 +95,55 @@ impl scylla_base_repository::EntitySchema<SessionEntity> for ScyllaSessionSchema
                 updated_at: self.updated_at,
                 deleted_at: self.deleted_at,
             },
-            account_id: self.account_id.clone(),
+            account_id: self.account_id.to_string(),
             jti: self.jti.clone(),
             expires_at: self.expires_at,
             ip_address: self.ip_address.clone(),
             user_agent: self.user_agent.clone(),
             device_type: self.device_type.clone(),
+            device_token: self.device_token.clone(),
         }
     }
 
     fn columns() -> &'static str {
-        "id, account_id, jti, expires_at, ip_address, user_agent, device_type, created_at, updated_at, deleted_at"
+        "id, account_id, jti, expires_at, ip_address, user_agent, device_type, device_token, created_at, updated_at, deleted_at"
     }
 
     fn insert_placeholders() -> &'static str {
-        "?, ?, ?, ?, ?, ?, ?, ?, ?, ?"
+        "?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?"
+    }
+
+    fn id(&self) -> Option<Uuid> {
+        self.id
+    }
+
+    fn set_id(&mut self, id: Uuid) {
+        self.id = Some(id);
+    }
+
+    fn deleted_at(&self) -> Option<i64> {
+        self.deleted_at
+    }
+
+    fn created_at(&self) -> i64 {
+        self.created_at
+    }
+
+    fn lookup_tables() -> &'static [&'static str] {
+        static TABLES: &[&str] = &[SESSION_BY_JTI_TABLE, SESSION_BY_ACCOUNT_TABLE];
+        TABLES
+    }
+
+    fn lookups(&self) -> Vec<scylla_base_repository::Lookup> {
+        vec![
+            scylla_base_repository::Lookup::new(SESSION_BY_JTI_TABLE, vec![("jti", CqlValue::Text(self.jti.clone()))]),
+            scylla_base_repository::Lookup::new(
+                SESSION_BY_ACCOUNT_TABLE,
+                vec![
+                    ("account_id", CqlValue::Uuid(self.account_id)),
+                    ("created_at", CqlValue::BigInt(self.created_at)),
+                ],
+            ),
+        ]
     }
 }
@@ -0,0 +1,99 @@
+use mongodb::bson::oid::ObjectId;
+use scylla::{DeserializeRow, SerializeRow};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+// shared modules
+use domain::entities::base_entity::BaseEntity;
+use domain::entities::topic_subscription_entity::TopicSubscriptionEntity;
+
+// internal modules
+use crate::secondary::repositories::models::base_schema::MongoBaseSchema;
+use crate::secondary::repositories::mongodb::mongo_base_repository;
+use crate::secondary::repositories::scylla::scylla_base_repository;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MongoTopicSubscriptionSchema {
+    #[serde(flatten)]
+    pub base: MongoBaseSchema,
+    pub account_id: ObjectId,
+    pub topic: String,
+}
+
+impl mongo_base_repository::EntitySchema<TopicSubscriptionEntity> for MongoTopicSubscriptionSchema {
+    fn from_entity(entity: &TopicSubscriptionEntity) -> Self {
+        MongoTopicSubscriptionSchema {
+            base: MongoBaseSchema::from_entity(&entity.base),
+            account_id: ObjectId::parse_str(&entity.account_id).unwrap(),
+            topic: entity.topic.clone(),
+        }
+    }
+
+    fn to_entity(&self) -> TopicSubscriptionEntity {
+        TopicSubscriptionEntity {
+            base: self.base.to_entity(),
+            account_id: self.account_id.to_hex(),
+            topic: self.topic.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, SerializeRow, DeserializeRow)]
+pub struct ScyllaTopicSubscriptionSchema {
+    pub id: Option<Uuid>,
+    pub account_id: Uuid,
+    pub topic: String,
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub deleted_at: Option<i64>,
+}
+
+impl scylla_base_repository::EntitySchema<TopicSubscriptionEntity> for ScyllaTopicSubscriptionSchema {
+    fn from_entity(entity: &TopicSubscriptionEntity) -> Self {
+        ScyllaTopicSubscriptionSchema {
+            id: entity.base.id.as_ref().and_then(|id| Uuid::parse_str(id).ok()),
+            created_at: entity.base.created_at,
+            updated_at: entity.base.updated_at,
+            deleted_at: entity.base.deleted_at,
+            account_id: Uuid::parse_str(&entity.account_id).unwrap(),
+            topic: entity.topic.clone(),
+        }
+    }
+
+    fn to_entity(&self) -> TopicSubscriptionEntity {
+        TopicSubscriptionEntity {
+            base: BaseEntity {
+                id: self.id.as_ref().map(|id| id.to_string()),
+                created_at: self.created_at,
+                updated_at: self.updated_at,
+                deleted_at: self.deleted_at,
+            },
+            account_id: self.account_id.to_string(),
+            topic: self.topic.clone(),
+        }
+    }
+
+    fn columns() -> &'static str {
+        "id, account_id, topic, created_at, updated_at, deleted_at"
+    }
+
+    fn insert_placeholders() -> &'static str {
+        "?, ?, ?, ?, ?, ?"
+    }
+
+    fn id(&self) -> Option<Uuid> {
+        self.id
+    }
+
+    fn set_id(&mut self, id: Uuid) {
+        self.id = Some(id);
+    }
+
+    fn deleted_at(&self) -> Option<i64> {
+        self.deleted_at
+    }
+
+    fn created_at(&self) -> i64 {
+        self.created_at
+    }
+}
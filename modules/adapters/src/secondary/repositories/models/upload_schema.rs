@@ -0,0 +1,159 @@
+use mongodb::bson::oid::ObjectId;
+use scylla::{DeserializeRow, SerializeRow};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+// shared modules
+use domain::entities::base_entity::BaseEntity;
+use domain::entities::upload_entity::UploadEntity;
+
+// internal modules
+use crate::secondary::repositories::models::base_schema::MongoBaseSchema;
+use crate::secondary::repositories::mongodb::mongo_base_repository;
+use crate::secondary::repositories::scylla::scylla_base_repository;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MongoUploadSchema {
+    #[serde(flatten)]
+    pub base: MongoBaseSchema,
+    pub account_id: ObjectId,
+    pub purpose: String,
+    pub reference_id: String,
+    pub file_name: String,
+    pub content_type: String,
+    pub size: i64,
+    pub checksum: String,
+    pub storage_key: String,
+    pub status: String,
+    pub url: Option<String>,
+    pub expires_at: i64,
+    pub completed_at: Option<i64>,
+}
+
+impl mongo_base_repository::EntitySchema<UploadEntity> for MongoUploadSchema {
+    fn from_entity(entity: &UploadEntity) -> Self {
+        MongoUploadSchema {
+            base: MongoBaseSchema::from_entity(&entity.base),
+            account_id: ObjectId::parse_str(&entity.account_id).unwrap(),
+            purpose: entity.purpose.clone(),
+            reference_id: entity.reference_id.clone(),
+            file_name: entity.file_name.clone(),
+            content_type: entity.content_type.clone(),
+            size: entity.size,
+            checksum: entity.checksum.clone(),
+            storage_key: entity.storage_key.clone(),
+            status: entity.status.clone(),
+            url: entity.url.clone(),
+            expires_at: entity.expires_at,
+            completed_at: entity.completed_at,
+        }
+    }
+
+    fn to_entity(&self) -> UploadEntity {
+        UploadEntity {
+            base: self.base.to_entity(),
+            account_id: self.account_id.to_hex(),
+            purpose: self.purpose.clone(),
+            reference_id: self.reference_id.clone(),
+            file_name: self.file_name.clone(),
+            content_type: self.content_type.clone(),
+            size: self.size,
+            checksum: self.checksum.clone(),
+            storage_key: self.storage_key.clone(),
+            status: self.status.clone(),
+            url: self.url.clone(),
+            expires_at: self.expires_at,
+            completed_at: self.completed_at,
+        }
+    }
+}
+
+#[derive(Debug, Clone, SerializeRow, DeserializeRow)]
+pub struct ScyllaUploadSchema {
+    pub id: Option<Uuid>,
+    pub account_id: Uuid,
+    pub purpose: String,
+    pub reference_id: String,
+    pub file_name: String,
+    pub content_type: String,
+    pub size: i64,
+    pub checksum: String,
+    pub storage_key: String,
+    pub status: String,
+    pub url: Option<String>,
+    pub expires_at: i64,
+    pub completed_at: Option<i64>,
+    pub created_at: i64,
+    pub updated_at: i64,
+    pub deleted_at: Option<i64>,
+}
+
+impl scylla_base_repository::EntitySchema<UploadEntity> for ScyllaUploadSchema {
+    fn from_entity(entity: &UploadEntity) -> Self {
+        ScyllaUploadSchema {
+            id: entity.base.id.as_ref().and_then(|id| Uuid::parse_str(id).ok()),
+            account_id: Uuid::parse_str(&entity.account_id).unwrap(),
+            purpose: entity.purpose.clone(),
+            reference_id: entity.reference_id.clone(),
+            file_name: entity.file_name.clone(),
+            content_type: entity.content_type.clone(),
+            size: entity.size,
+            checksum: entity.checksum.clone(),
+            storage_key: entity.storage_key.clone(),
+            status: entity.status.clone(),
+            url: entity.url.clone(),
+            expires_at: entity.expires_at,
+            completed_at: entity.completed_at,
+            created_at: entity.base.created_at,
+            updated_at: entity.base.updated_at,
+            deleted_at: entity.base.deleted_at,
+        }
+    }
+
+    fn to_entity(&self) -> UploadEntity {
+        UploadEntity {
+            base: BaseEntity {
+                id: self.id.as_ref().map(|id| id.to_string()),
+                created_at: self.created_at,
+                updated_at: self.updated_at,
+                deleted_at: self.deleted_at,
+            },
+            account_id: self.account_id.to_string(),
+            purpose: self.purpose.clone(),
+            reference_id: self.reference_id.clone(),
+            file_name: self.file_name.clone(),
+            content_type: self.content_type.clone(),
+            size: self.size,
+            checksum: self.checksum.clone(),
+            storage_key: self.storage_key.clone(),
+            status: self.status.clone(),
+            url: self.url.clone(),
+            expires_at: self.expires_at,
+            completed_at: self.completed_at,
+        }
+    }
+
+    fn columns() -> &'static str {
+        "id, account_id, purpose, reference_id, file_name, content_type, size, checksum, storage_key, status, url, expires_at, completed_at, created_at, updated_at, deleted_at"
+    }
+
+    fn insert_placeholders() -> &'static str {
+        "?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?"
+    }
+
+    fn id(&self) -> Option<Uuid> {
+        self.id
+    }
+
+    fn set_id(&mut self, id: Uuid) {
+        self.id = Some(id);
+    }
+
+    fn deleted_at(&self) -> Option<i64> {
+        self.deleted_at
+    }
+
+    fn created_at(&self) -> i64 {
+        self.created_at
+    }
+}
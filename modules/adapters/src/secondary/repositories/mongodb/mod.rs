@@ -1,10 +1,27 @@
 pub mod mongo_account_repository;
+pub mod mongo_audit_event_repository;
 pub mod mongo_base_repository;
 pub mod mongo_blog_repository;
+pub mod mongo_blog_revision_repository;
+pub mod mongo_blog_star_repository;
+pub mod mongo_bookmark_repository;
 pub mod mongo_category_repository;
+pub mod mongo_chapter_repository;
+pub mod mongo_comment_ban_repository;
+pub mod mongo_comment_repository;
+pub mod mongo_course_repository;
+pub mod mongo_data_export_repository;
+pub mod mongo_enrollment_repository;
 pub mod mongo_experience_repository;
+pub mod mongo_lesson_repository;
+pub mod mongo_media_asset_repository;
+pub mod mongo_notification_preference_repository;
 pub mod mongo_notification_repository;
+pub mod mongo_progress_repository;
 pub mod mongo_project_repository;
 pub mod mongo_provider_repository;
+pub mod mongo_review_repository;
 pub mod mongo_role_repository;
 pub mod mongo_session_repository;
+pub mod mongo_topic_subscription_repository;
+pub mod mongo_upload_repository;
@@ -1,12 +1,18 @@
 use async_trait::async_trait;
+use futures::stream::TryStreamExt;
 use mongodb::Collection;
-use mongodb::bson::doc;
+use mongodb::bson::oid::ObjectId;
+use mongodb::bson::{Bson, Document, doc};
+use mongodb::options::{Collation, CollationStrength};
 use std::sync::Arc;
 
 // shared modules
 use domain::entities::account_entity::AccountEntity;
-use domain::repositories::account_repository::AccountRepository;
+use domain::repositories::account_repository::{AccountRepository, AccountSearch};
+use domain::values::account_status::AccountStatus;
 use shared::models::failure::Failure;
+use shared::models::filters::QuerySpec;
+use shared::models::paginate::Paginate;
 use shared::types::DomainResponse;
 
 // internal modules
@@ -22,6 +28,49 @@ impl MongoAccountRepository {
     pub fn new(collection: Arc<Collection<AccountMongoSchema>>) -> Self {
         Self { base: MongoBaseRepository::new(collection) }
     }
+
+    fn search_filter(search: &AccountSearch, now: i64) -> Document {
+        let mut filter = doc! { "deleted_at": { "$exists": false } };
+
+        if let Some(email) = &search.email {
+            filter.insert("email", doc! { "$regex": regex::escape(email), "$options": "i" });
+        }
+        if let Some(username) = &search.username {
+            filter.insert("username", doc! { "$regex": regex::escape(username), "$options": "i" });
+        }
+        if let Some(email_verified) = search.email_verified {
+            filter.insert("email_verified", email_verified);
+        }
+        if let Some(account_ids) = &search.account_ids {
+            // Ids that do not parse can not match any account
+            let object_ids: Vec<ObjectId> = account_ids.iter().filter_map(|id| ObjectId::parse_str(id).ok()).collect();
+            filter.insert("_id", doc! { "$in": object_ids });
+        }
+
+        // Fields unset on the account are stored as null, which also matches older documents missing them
+        match search.status {
+            Some(AccountStatus::Active) => {
+                filter.insert("is_active", true);
+                filter.insert(
+                    "$or",
+                    vec![doc! { "suspended_until": Bson::Null }, doc! { "suspended_until": { "$lte": now } }],
+                );
+            },
+            Some(AccountStatus::Deactivated) => {
+                filter.insert("is_active", false);
+                filter.insert("deletion_due_at", Bson::Null);
+            },
+            Some(AccountStatus::Suspended) => {
+                filter.insert("suspended_until", doc! { "$gt": now });
+            },
+            Some(AccountStatus::PendingDeletion) => {
+                filter.insert("deletion_due_at", doc! { "$ne": Bson::Null });
+            },
+            None => {},
+        }
+
+        filter
+    }
 }
 
 // Delegate base methods to MongoBaseRepository
@@ -41,4 +90,57 @@ impl AccountRepository for MongoAccountRepository {
             Err(e) => Err(Failure::DatabaseError(format!("Failed to find account by email: {}", e))),
         }
     }
+
+    async fn find_by_username(&self, username: &str) -> DomainResponse<Option<AccountEntity>> {
+        let filter = doc! {
+            "username": username,
+            "deleted_at": { "$exists": false }
+        };
+        let case_insensitive = Collation::builder().locale("en").strength(CollationStrength::Secondary).build();
+
+        match self.base.collection.find_one(filter).collation(case_insensitive).await {
+            Ok(Some(schema)) => Ok(Some(schema.to_entity())),
+            Ok(None) => Ok(None),
+            Err(e) => Err(Failure::DatabaseError(format!("Failed to find account by username: {}", e))),
+        }
+    }
+
+    async fn find_deletion_due(&self, now: i64, limit: u32) -> DomainResponse<Vec<AccountEntity>> {
+        let filter = doc! {
+            "deletion_due_at": { "$lte": now },
+            "deleted_at": { "$exists": false }
+        };
+
+        let cursor = self
+            .base
+            .collection
+            .find(filter)
+            .sort(doc! { "deletion_due_at": 1 })
+            .limit(limit as i64)
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to find accounts due for deletion: {}", e)))?;
+
+        let accounts = cursor
+            .try_collect::<Vec<AccountMongoSchema>>()
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to iterate over accounts due for deletion: {}", e)))?
+            .into_iter()
+            .map(|schema| schema.to_entity())
+            .collect();
+
+        Ok(accounts)
+    }
+
+    async fn search(
+        &self,
+        search: &AccountSearch,
+        spec: &QuerySpec,
+        now: i64,
+        page: u32,
+        page_size: u32,
+    ) -> DomainResponse<(Paginate, Vec<AccountEntity>)> {
+        let filter = Self::search_filter(search, now);
+
+        self.base.find_by_spec(filter, spec, doc! { "created_at": -1 }, page, page_size).await
+    }
 }
@@ -0,0 +1,89 @@
+use async_trait::async_trait;
+use futures::stream::TryStreamExt;
+use mongodb::Collection;
+use mongodb::bson::{Document, doc};
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::audit_event_entity::AuditEventEntity;
+use domain::repositories::audit_event_repository::{AuditEventFilter, AuditEventRepository};
+use domain::repositories::base_repository::BaseRepository;
+use shared::models::failure::Failure;
+use shared::models::paginate::Paginate;
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::secondary::repositories::models::audit_event_schema::MongoAuditEventSchema;
+use crate::secondary::repositories::mongodb::mongo_base_repository::{EntitySchema, MongoBaseRepository};
+
+pub struct MongoAuditEventRepository {
+    base: MongoBaseRepository<AuditEventEntity, MongoAuditEventSchema>,
+}
+
+impl MongoAuditEventRepository {
+    pub fn new(collection: Arc<Collection<MongoAuditEventSchema>>) -> Self {
+        MongoAuditEventRepository { base: MongoBaseRepository::new(collection) }
+    }
+
+    fn filter_document(filter: &AuditEventFilter) -> Document {
+        let mut document = doc! { "created_at": { "$gte": filter.from, "$lte": filter.to } };
+        if let Some(actor_id) = &filter.actor_id {
+            document.insert("actor_id", actor_id);
+        }
+        if let Some(action) = &filter.action {
+            document.insert("action", action);
+        }
+        if let Some(target_type) = &filter.target_type {
+            document.insert("target_type", target_type);
+        }
+        if let Some(target_id) = &filter.target_id {
+            document.insert("target_id", target_id);
+        }
+
+        document
+    }
+
+    async fn find_sorted(&self, filter: Document, skip: u64, limit: u32) -> DomainResponse<Vec<AuditEventEntity>> {
+        let cursor = self
+            .base
+            .collection
+            .find(filter)
+            .sort(doc! { "created_at": -1, "_id": -1 })
+            .skip(skip)
+            .limit(limit as i64)
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to find audit events: {}", e)))?;
+
+        let events = cursor
+            .try_collect::<Vec<MongoAuditEventSchema>>()
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to iterate over audit events: {}", e)))?
+            .into_iter()
+            .map(|schema| schema.to_entity())
+            .collect();
+
+        Ok(events)
+    }
+}
+
+#[async_trait]
+impl AuditEventRepository for MongoAuditEventRepository {
+    async fn create(&self, event: &AuditEventEntity) -> DomainResponse<AuditEventEntity> {
+        self.base.create(event).await
+    }
+
+    async fn find_filtered_paginated(
+        &self,
+        filter: &AuditEventFilter,
+        page: u32,
+        page_size: u32,
+    ) -> DomainResponse<(Paginate, Vec<AuditEventEntity>)> {
+        let filter = Self::filter_document(filter);
+
+        self.base.find_page(filter, doc! { "created_at": -1, "_id": -1 }, page, page_size).await
+    }
+
+    async fn find_filtered(&self, filter: &AuditEventFilter, limit: u32) -> DomainResponse<Vec<AuditEventEntity>> {
+        self.find_sorted(Self::filter_document(filter), 0, limit).await
+    }
+}
@@ -2,7 +2,8 @@ use async_trait::async_trait;
 use futures::stream::TryStreamExt;
 use mongodb::Collection;
 use mongodb::bson::oid::ObjectId;
-use mongodb::bson::{doc, to_document};
+use mongodb::bson::{Document, doc, to_document};
+use mongodb::error::{Error, ErrorKind, WriteFailure};
 use serde::Serialize;
 use serde::de::DeserializeOwned;
 use std::marker::PhantomData;
@@ -11,6 +12,7 @@ use std::sync::Arc;
 // shared modules
 use domain::repositories::base_repository::BaseRepository;
 use shared::models::failure::Failure;
+use shared::models::filters::{MongoFilterConverter, QuerySpec};
 use shared::models::paginate::Paginate;
 use shared::types::DomainResponse;
 
@@ -19,6 +21,20 @@ pub trait EntitySchema<S>: Serialize + DeserializeOwned + Unpin + Send + Sync {
     fn to_entity(&self) -> S;
 }
 
+// Server error code of a write rejected by a unique index
+static DUPLICATE_KEY_CODE: i32 = 11000;
+
+pub fn is_duplicate_key(error: &Error) -> bool {
+    matches!(
+        error.kind.as_ref(),
+        ErrorKind::Write(WriteFailure::WriteError(write_error)) if write_error.code == DUPLICATE_KEY_CODE
+    )
+}
+
+pub fn parse_object_id(field: &str, value: &str) -> DomainResponse<ObjectId> {
+    ObjectId::parse_str(value).map_err(|e| Failure::BadRequest(format!("Invalid {} format: {}: {}", field, value, e)))
+}
+
 pub struct MongoBaseRepository<E, S>
 where
     E: Send + Sync + Clone,
@@ -36,6 +52,94 @@ where
     pub fn new(collection: Arc<Collection<S>>) -> Self {
         MongoBaseRepository { collection, _phantom: PhantomData }
     }
+
+    /// Every document matching `filter`, in `sort` order.
+    pub async fn find_all(&self, filter: Document, sort: Document) -> DomainResponse<Vec<E>> {
+        let cursor = self
+            .collection
+            .find(filter)
+            .sort(sort)
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to find entities: {}", e)))?;
+
+        let entities = cursor
+            .try_collect::<Vec<S>>()
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to iterate over entities: {}", e)))?
+            .into_iter()
+            .map(|schema| schema.to_entity())
+            .collect();
+
+        Ok(entities)
+    }
+
+    /// Hard deletes every document matching `filter`, soft deleted ones included.
+    pub async fn remove_many(&self, filter: Document) -> DomainResponse<usize> {
+        let deleted_result = self
+            .collection
+            .delete_many(filter)
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to remove entities: {}", e)))?;
+
+        Ok(deleted_result.deleted_count as usize)
+    }
+
+    /// A page of the documents matching both `filter` and the spec, in the spec order or `default_sort`.
+    pub async fn find_by_spec(
+        &self,
+        filter: Document,
+        spec: &QuerySpec,
+        default_sort: Document,
+        page: u32,
+        page_size: u32,
+    ) -> DomainResponse<(Paginate, Vec<E>)> {
+        let filter = doc! { "$and": [filter, MongoFilterConverter::convert_to_mongo_filter(spec)] };
+        let sort = MongoFilterConverter::convert_to_mongo_sort(spec)
+            .unwrap_or_else(|| MongoFilterConverter::with_tiebreaker(default_sort));
+
+        self.find_page(filter, sort, page, page_size).await
+    }
+
+    /// A page of the documents matching `filter` in `sort` order, with the totals of the whole match.
+    pub async fn find_page(
+        &self,
+        filter: Document,
+        sort: Document,
+        page: u32,
+        page_size: u32,
+    ) -> DomainResponse<(Paginate, Vec<E>)> {
+        let total_count = self
+            .collection
+            .count_documents(filter.clone())
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to count entities: {}", e)))?;
+
+        let cursor = self
+            .collection
+            .find(filter)
+            .sort(sort)
+            .skip(((page - 1) * page_size) as u64)
+            .limit(page_size as i64)
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to find entities with pagination: {}", e)))?;
+
+        let entities = cursor
+            .try_collect::<Vec<S>>()
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to iterate over paginated entities: {}", e)))?
+            .into_iter()
+            .map(|schema| schema.to_entity())
+            .collect();
+
+        let paginate = Paginate {
+            page,
+            page_size,
+            total_page: ((total_count as f32) / (page_size as f32)).ceil() as u32,
+            total_record: total_count as u32,
+        };
+
+        Ok((paginate, entities))
+    }
 }
 
 #[macro_export]
@@ -82,6 +186,15 @@ macro_rules! impl_mongo_base_repository {
             ) -> shared::types::DomainResponse<(shared::models::paginate::Paginate, Vec<$entity>)> {
                 self.base.finds_paginated(page, page_size).await
             }
+
+            async fn find_by(
+                &self,
+                spec: &shared::models::filters::QuerySpec,
+                page: u32,
+                page_size: u32,
+            ) -> shared::types::DomainResponse<(shared::models::paginate::Paginate, Vec<$entity>)> {
+                self.base.find_by(spec, page, page_size).await
+            }
         }
     };
 }
@@ -94,11 +207,10 @@ where
 {
     async fn create(&self, entity: &E) -> DomainResponse<E> {
         let schema = S::from_entity(entity);
-        let inserted_result = self
-            .collection
-            .insert_one(&schema)
-            .await
-            .map_err(|e| Failure::DatabaseError(format!("Failed to create entity: {}", e)))?;
+        let inserted_result = self.collection.insert_one(&schema).await.map_err(|e| match is_duplicate_key(&e) {
+            true => Failure::Conflict(format!("Entity already exists: {}", e)),
+            false => Failure::DatabaseError(format!("Failed to create entity: {}", e)),
+        })?;
 
         // Get the inserted _id and update entity
         let inserted_id = inserted_result
@@ -134,11 +246,11 @@ where
                 .map_err(|e| Failure::DatabaseError(format!("Failed to serialize entity for update: {}", e)))?,
         };
 
-        let updated_result = self
-            .collection
-            .update_one(query, update)
-            .await
-            .map_err(|e| Failure::DatabaseError(format!("Failed to update entity: {}", e)))?;
+        let updated_result =
+            self.collection.update_one(query, update).await.map_err(|e| match is_duplicate_key(&e) {
+                true => Failure::Conflict(format!("Entity conflicts with an existing one: {}", e)),
+                false => Failure::DatabaseError(format!("Failed to update entity: {}", e)),
+            })?;
 
         if updated_result.matched_count == 0 {
             return Err(Failure::NotFound(format!("Entity with id {} not found", id)));
@@ -266,38 +378,11 @@ where
             "deleted_at": { "$exists": false }
         };
 
-        let total_count = self
-            .collection
-            .count_documents(filter.clone())
-            .await
-            .map_err(|e| Failure::DatabaseError(format!("Failed to count entities: {}", e)))?;
-
-        let skip = ((page - 1) * page_size) as u64;
-        let limit = page_size as i64;
-
-        let cursor = self
-            .collection
-            .find(filter)
-            .skip(skip)
-            .limit(limit)
-            .await
-            .map_err(|e| Failure::DatabaseError(format!("Failed to find entities with pagination: {}", e)))?;
-
-        let entities = cursor
-            .try_collect::<Vec<S>>()
-            .await
-            .map_err(|e| Failure::DatabaseError(format!("Failed to iterate over paginated entities: {}", e)))?
-            .into_iter()
-            .map(|schema| schema.to_entity())
-            .collect();
-
-        let paginate = Paginate {
-            page,
-            page_size,
-            total_page: ((total_count as f32) / (page_size as f32)).ceil() as u32,
-            total_record: total_count as u32,
-        };
+        self.find_page(filter, doc! { "_id": 1 }, page, page_size).await
+    }
 
-        Ok((paginate, entities))
+    async fn find_by(&self, spec: &QuerySpec, page: u32, page_size: u32) -> DomainResponse<(Paginate, Vec<E>)> {
+        let filter = doc! { "deleted_at": { "$exists": false } };
+        self.find_by_spec(filter, spec, doc! { "created_at": -1 }, page, page_size).await
     }
 }
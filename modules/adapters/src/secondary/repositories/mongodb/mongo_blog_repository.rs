@@ -1,15 +1,26 @@
 use async_trait::async_trait;
+use futures::stream::TryStreamExt;
 use mongodb::Collection;
+use mongodb::bson::oid::ObjectId;
+use mongodb::bson::{Document, doc, to_document};
+use std::collections::HashMap;
 use std::sync::Arc;
 
 // shared modules
 use domain::entities::blog_entity::BlogEntity;
 use domain::repositories::blog_repository::BlogRepository;
+use domain::values::blog_status::BlogStatus;
 use processors::MongoRepository;
+use shared::models::failure::Failure;
+use shared::models::filters::QuerySpec;
+use shared::models::paginate::Paginate;
+use shared::types::DomainResponse;
 
 // internal modules
 use crate::secondary::repositories::models::blog_schema::MongoBlogSchema;
-use crate::secondary::repositories::mongodb::mongo_base_repository::MongoBaseRepository;
+use crate::secondary::repositories::mongodb::mongo_base_repository::{
+    EntitySchema, MongoBaseRepository, parse_object_id,
+};
 
 #[derive(MongoRepository)]
 pub struct MongoBlogRepository {
@@ -20,7 +31,326 @@ impl MongoBlogRepository {
     pub fn new(collection: Arc<Collection<MongoBlogSchema>>) -> Self {
         MongoBlogRepository { base: MongoBaseRepository::new(collection) }
     }
+
+    /// Published blogs, including documents written before `status` existed.
+    fn published_filter() -> Document {
+        doc! {
+            "deleted_at": { "$exists": false },
+            "$or": [
+                { "status": BlogStatus::Published.as_str() },
+                { "status": { "$exists": false }, "is_published": true },
+            ]
+        }
+    }
+
+    async fn find_many(&self, filter: Document) -> DomainResponse<Vec<BlogEntity>> {
+        let cursor = self
+            .base
+            .collection
+            .find(filter)
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to find blogs: {}", e)))?;
+
+        let blogs = cursor
+            .try_collect::<Vec<MongoBlogSchema>>()
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to iterate over blogs: {}", e)))?
+            .into_iter()
+            .map(|schema| schema.to_entity())
+            .collect();
+
+        Ok(blogs)
+    }
 }
 
 #[async_trait]
-impl BlogRepository for MongoBlogRepository {}
+impl BlogRepository for MongoBlogRepository {
+    async fn find_published_paginated(
+        &self,
+        author_id: Option<&str>,
+        spec: &QuerySpec,
+        page: u32,
+        page_size: u32,
+    ) -> DomainResponse<(Paginate, Vec<BlogEntity>)> {
+        let mut filter = Self::published_filter();
+        if let Some(author_id) = author_id {
+            let object_id = ObjectId::parse_str(author_id)
+                .map_err(|e| Failure::BadRequest(format!("Invalid author_id format: {}: {}", author_id, e)))?;
+            filter.insert("author_id", object_id);
+        }
+
+        let default_sort = doc! { "published_at": -1, "created_at": -1 };
+        self.base.find_by_spec(filter, spec, default_sort, page, page_size).await
+    }
+
+    async fn find_latest_published(
+        &self,
+        category_id: Option<&str>,
+        author_id: Option<&str>,
+        limit: u32,
+    ) -> DomainResponse<Vec<BlogEntity>> {
+        let mut filter = Self::published_filter();
+        if let Some(category_id) = category_id {
+            filter.insert("categories", category_id);
+        }
+        if let Some(author_id) = author_id {
+            let object_id = ObjectId::parse_str(author_id)
+                .map_err(|e| Failure::BadRequest(format!("Invalid author_id format: {}: {}", author_id, e)))?;
+            filter.insert("author_id", object_id);
+        }
+
+        let cursor = self
+            .base
+            .collection
+            .find(filter)
+            .sort(doc! { "published_at": -1, "created_at": -1 })
+            .limit(limit as i64)
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to find latest published blogs: {}", e)))?;
+
+        let blogs = cursor
+            .try_collect::<Vec<MongoBlogSchema>>()
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to iterate over latest published blogs: {}", e)))?
+            .into_iter()
+            .map(|schema| schema.to_entity())
+            .collect();
+
+        Ok(blogs)
+    }
+
+    async fn find_published_modification_times(&self) -> DomainResponse<Vec<(String, i64)>> {
+        let cursor = self
+            .base
+            .collection
+            .clone_with_type::<Document>()
+            .find(Self::published_filter())
+            .projection(doc! { "_id": 1, "updated_at": 1 })
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to find published blogs: {}", e)))?;
+
+        let modification_times = cursor
+            .try_collect::<Vec<Document>>()
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to iterate over published blogs: {}", e)))?
+            .into_iter()
+            .filter_map(|document| {
+                let blog_id = document.get_object_id("_id").ok()?.to_hex();
+                let updated_at = document.get_i64("updated_at").ok()?;
+                Some((blog_id, updated_at))
+            })
+            .collect();
+
+        Ok(modification_times)
+    }
+
+    async fn find_scheduled_before(&self, timestamp: i64) -> DomainResponse<Vec<BlogEntity>> {
+        let filter = doc! {
+            "status": BlogStatus::Scheduled.as_str(),
+            "publish_at": { "$lte": timestamp },
+            "deleted_at": { "$exists": false }
+        };
+
+        self.find_many(filter).await
+    }
+
+    async fn publish_scheduled(&self, blog: &BlogEntity) -> DomainResponse<Option<BlogEntity>> {
+        let blog_id = blog.base.id.clone().unwrap_or_default();
+        let object_id = parse_object_id("blog_id", &blog_id)?;
+
+        // Replicas run the job alike, the status in the filter lets only the first update through
+        let filter = doc! { "_id": object_id, "status": BlogStatus::Scheduled.as_str() };
+        let schema = MongoBlogSchema::from_entity(blog);
+        let update = doc! {
+            "$set": to_document(&schema)
+                .map_err(|e| Failure::DatabaseError(format!("Failed to serialize blog for update: {}", e)))?,
+        };
+
+        let result = self
+            .base
+            .collection
+            .update_one(filter, update)
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to publish scheduled blog: {}", e)))?;
+
+        Ok((result.matched_count > 0).then(|| schema.to_entity()))
+    }
+
+    async fn increment_counters(&self, blog_id: &str, stars: i32, views: i32) -> DomainResponse<()> {
+        let object_id = ObjectId::parse_str(blog_id)
+            .map_err(|e| Failure::BadRequest(format!("Invalid blog_id format: {}: {}", blog_id, e)))?;
+
+        let filter = doc! { "_id": object_id };
+        let update = doc! { "$inc": { "stars": stars, "views": views } };
+
+        self.base
+            .collection
+            .update_one(filter, update)
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to increment blog counters: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn count_by_categories(&self, category_ids: &[String]) -> DomainResponse<u64> {
+        let filter = doc! {
+            "categories": { "$in": category_ids },
+            "deleted_at": { "$exists": false }
+        };
+
+        self.base
+            .collection
+            .count_documents(filter)
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to count blogs by categories: {}", e)))
+    }
+
+    async fn count_only_in_categories(&self, category_ids: &[String]) -> DomainResponse<u64> {
+        let filter = doc! {
+            "categories": { "$in": category_ids, "$not": { "$elemMatch": { "$nin": category_ids } } },
+            "deleted_at": { "$exists": false }
+        };
+
+        self.base
+            .collection
+            .count_documents(filter)
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to count blogs by categories: {}", e)))
+    }
+
+    async fn count_published_by_category(&self) -> DomainResponse<HashMap<String, u32>> {
+        let pipeline = vec![
+            doc! { "$match": Self::published_filter() },
+            doc! { "$unwind": "$categories" },
+            doc! { "$group": { "_id": "$categories", "count": { "$sum": 1 } } },
+        ];
+
+        let cursor = self
+            .base
+            .collection
+            .aggregate(pipeline)
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to count blogs by category: {}", e)))?;
+
+        let counts = cursor
+            .try_collect::<Vec<Document>>()
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to iterate over blog counts: {}", e)))?
+            .into_iter()
+            .filter_map(|document| {
+                let category_id = document.get_str("_id").ok()?.to_string();
+                let count = document.get_i32("count").ok()?;
+                Some((category_id, count as u32))
+            })
+            .collect();
+
+        Ok(counts)
+    }
+
+    async fn replace_categories(&self, from: &[String], to: Option<&str>) -> DomainResponse<usize> {
+        let filter = doc! { "categories": { "$in": from } };
+        let updated_at = chrono::Utc::now().timestamp();
+
+        // Add the target first, the second update then only has to drop the old categories
+        if let Some(to) = to {
+            self.base
+                .collection
+                .update_many(
+                    filter.clone(),
+                    doc! { "$addToSet": { "categories": to }, "$set": { "updated_at": updated_at } },
+                )
+                .await
+                .map_err(|e| Failure::DatabaseError(format!("Failed to reassign blog categories: {}", e)))?;
+        }
+
+        let updated_result = self
+            .base
+            .collection
+            .update_many(
+                filter,
+                doc! { "$pull": { "categories": { "$in": from } }, "$set": { "updated_at": updated_at } },
+            )
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to remove blog categories: {}", e)))?;
+
+        Ok(updated_result.modified_count as usize)
+    }
+
+    async fn drop_categories(&self, from: &[String], fallback: &str) -> DomainResponse<usize> {
+        let filter = doc! { "categories": { "$in": from } };
+
+        // One pipeline update keeps the other categories in order and falls back when none is left
+        let kept = doc! { "$filter": { "input": "$categories", "cond": { "$not": { "$in": ["$$this", from] } } } };
+        let pipeline = vec![doc! {
+            "$set": {
+                "categories": {
+                    "$let": {
+                        "vars": { "kept": kept },
+                        "in": { "$cond": [{ "$eq": [{ "$size": "$$kept" }, 0] }, [fallback], "$$kept"] },
+                    }
+                },
+                "updated_at": chrono::Utc::now().timestamp(),
+            }
+        }];
+
+        let updated_result = self
+            .base
+            .collection
+            .update_many(filter, pipeline)
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to remove blog categories: {}", e)))?;
+
+        Ok(updated_result.modified_count as usize)
+    }
+
+    async fn find_by_author(&self, author_id: &str) -> DomainResponse<Vec<BlogEntity>> {
+        let object_id = ObjectId::parse_str(author_id)
+            .map_err(|e| Failure::BadRequest(format!("Invalid author_id format: {}: {}", author_id, e)))?;
+        let filter = doc! { "author_id": object_id, "deleted_at": { "$exists": false } };
+
+        let cursor = self
+            .base
+            .collection
+            .find(filter)
+            .sort(doc! { "created_at": -1 })
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to find blogs by author: {}", e)))?;
+
+        let blogs = cursor
+            .try_collect::<Vec<MongoBlogSchema>>()
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to iterate over blogs by author: {}", e)))?
+            .into_iter()
+            .map(|schema| schema.to_entity())
+            .collect();
+
+        Ok(blogs)
+    }
+
+    async fn replace_author(&self, from: &str, to: &str) -> DomainResponse<usize> {
+        let from_id = ObjectId::parse_str(from)
+            .map_err(|e| Failure::BadRequest(format!("Invalid author_id format: {}: {}", from, e)))?;
+        let to_id = ObjectId::parse_str(to)
+            .map_err(|e| Failure::BadRequest(format!("Invalid author_id format: {}: {}", to, e)))?;
+
+        let updated_result = self
+            .base
+            .collection
+            .update_many(
+                doc! { "author_id": from_id },
+                doc! { "$set": { "author_id": to_id, "updated_at": chrono::Utc::now().timestamp() } },
+            )
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to reassign blog author: {}", e)))?;
+
+        Ok(updated_result.modified_count as usize)
+    }
+
+    async fn find_and_remove_by_author(&self, author_id: &str) -> DomainResponse<Vec<BlogEntity>> {
+        let filter = doc! { "author_id": parse_object_id("author_id", author_id)? };
+
+        let blogs = self.base.find_all(filter.clone(), doc! { "created_at": -1 }).await?;
+        self.base.remove_many(filter).await?;
+        Ok(blogs)
+    }
+}
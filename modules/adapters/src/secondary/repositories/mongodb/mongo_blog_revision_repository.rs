@@ -0,0 +1,94 @@
+use async_trait::async_trait;
+use futures::stream::TryStreamExt;
+use mongodb::Collection;
+use mongodb::bson::doc;
+use mongodb::bson::oid::ObjectId;
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::blog_revision_entity::BlogRevisionEntity;
+use domain::repositories::blog_revision_repository::BlogRevisionRepository;
+use processors::MongoRepository;
+use shared::models::failure::Failure;
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::secondary::repositories::models::blog_revision_schema::MongoBlogRevisionSchema;
+use crate::secondary::repositories::mongodb::mongo_base_repository::{EntitySchema, MongoBaseRepository};
+
+#[derive(MongoRepository)]
+pub struct MongoBlogRevisionRepository {
+    base: MongoBaseRepository<BlogRevisionEntity, MongoBlogRevisionSchema>,
+}
+
+impl MongoBlogRevisionRepository {
+    pub fn new(collection: Arc<Collection<MongoBlogRevisionSchema>>) -> Self {
+        MongoBlogRevisionRepository { base: MongoBaseRepository::new(collection) }
+    }
+}
+
+fn parse_blog_id(blog_id: &str) -> DomainResponse<ObjectId> {
+    ObjectId::parse_str(blog_id).map_err(|e| Failure::BadRequest(format!("Invalid blog_id format: {}: {}", blog_id, e)))
+}
+
+#[async_trait]
+impl BlogRevisionRepository for MongoBlogRevisionRepository {
+    async fn find_by_blog_id(&self, blog_id: &str) -> DomainResponse<Vec<BlogRevisionEntity>> {
+        let filter = doc! { "blog_id": parse_blog_id(blog_id)? };
+
+        let cursor = self
+            .base
+            .collection
+            .find(filter)
+            .sort(doc! { "version": -1 })
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to find blog revisions: {}", e)))?;
+
+        let revisions = cursor
+            .try_collect::<Vec<MongoBlogRevisionSchema>>()
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to iterate over blog revisions: {}", e)))?
+            .into_iter()
+            .map(|schema| schema.to_entity())
+            .collect();
+
+        Ok(revisions)
+    }
+
+    async fn find_by_version(&self, blog_id: &str, version: i32) -> DomainResponse<Option<BlogRevisionEntity>> {
+        let filter = doc! { "blog_id": parse_blog_id(blog_id)?, "version": version };
+
+        match self.base.collection.find_one(filter).await {
+            Ok(Some(schema)) => Ok(Some(schema.to_entity())),
+            Ok(None) => Ok(None),
+            Err(e) => Err(Failure::DatabaseError(format!("Failed to find blog revision by version: {}", e))),
+        }
+    }
+
+    async fn find_latest_by_blog_id(&self, blog_id: &str) -> DomainResponse<Option<BlogRevisionEntity>> {
+        let filter = doc! { "blog_id": parse_blog_id(blog_id)? };
+
+        match self.base.collection.find_one(filter).sort(doc! { "version": -1 }).await {
+            Ok(Some(schema)) => Ok(Some(schema.to_entity())),
+            Ok(None) => Ok(None),
+            Err(e) => Err(Failure::DatabaseError(format!("Failed to find latest blog revision: {}", e))),
+        }
+    }
+
+    async fn remove_older_than_version(&self, blog_id: &str, version: i32) -> DomainResponse<usize> {
+        let filter = doc! { "blog_id": parse_blog_id(blog_id)?, "version": { "$lt": version } };
+
+        let delete_result = self
+            .base
+            .collection
+            .delete_many(filter)
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to prune blog revisions: {}", e)))?;
+
+        Ok(delete_result.deleted_count as usize)
+    }
+
+    async fn remove_by_blog_id(&self, blog_id: &str) -> DomainResponse<usize> {
+        self.base.remove_many(doc! { "blog_id": parse_blog_id(blog_id)? }).await
+    }
+}
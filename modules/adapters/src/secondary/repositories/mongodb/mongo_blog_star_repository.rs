@@ -0,0 +1,64 @@
+use async_trait::async_trait;
+use mongodb::Collection;
+use mongodb::bson::doc;
+use mongodb::bson::oid::ObjectId;
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::blog_star_entity::BlogStarEntity;
+use domain::repositories::blog_star_repository::BlogStarRepository;
+use processors::MongoRepository;
+use shared::models::failure::Failure;
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::secondary::repositories::models::blog_star_schema::MongoBlogStarSchema;
+use crate::secondary::repositories::mongodb::mongo_base_repository::{
+    EntitySchema, MongoBaseRepository, is_duplicate_key, parse_object_id,
+};
+
+#[derive(MongoRepository)]
+pub struct MongoBlogStarRepository {
+    base: MongoBaseRepository<BlogStarEntity, MongoBlogStarSchema>,
+}
+
+impl MongoBlogStarRepository {
+    pub fn new(collection: Arc<Collection<MongoBlogStarSchema>>) -> Self {
+        MongoBlogStarRepository { base: MongoBaseRepository::new(collection) }
+    }
+}
+
+#[async_trait]
+impl BlogStarRepository for MongoBlogStarRepository {
+    async fn find_by_blog_id_and_account_id(
+        &self,
+        blog_id: &str,
+        account_id: &str,
+    ) -> DomainResponse<Option<BlogStarEntity>> {
+        let blog_id = ObjectId::parse_str(blog_id)
+            .map_err(|e| Failure::BadRequest(format!("Invalid blog_id format: {}: {}", blog_id, e)))?;
+        let account_id = ObjectId::parse_str(account_id)
+            .map_err(|e| Failure::BadRequest(format!("Invalid account_id format: {}: {}", account_id, e)))?;
+
+        let filter = doc! { "blog_id": blog_id, "account_id": account_id };
+        match self.base.collection.find_one(filter).await {
+            Ok(Some(schema)) => Ok(Some(schema.to_entity())),
+            Ok(None) => Ok(None),
+            Err(e) => Err(Failure::DatabaseError(format!("Failed to find blog star: {}", e))),
+        }
+    }
+
+    async fn create_if_absent(&self, star: &BlogStarEntity) -> DomainResponse<bool> {
+        match self.base.collection.insert_one(MongoBlogStarSchema::from_entity(star)).await {
+            Ok(_) => Ok(true),
+            // The unique (blog_id, account_id) index turns a second star away
+            Err(e) if is_duplicate_key(&e) => Ok(false),
+            Err(e) => Err(Failure::DatabaseError(format!("Failed to create blog star: {}", e))),
+        }
+    }
+
+    async fn find_by_account_id(&self, account_id: &str) -> DomainResponse<Vec<BlogStarEntity>> {
+        let filter = doc! { "account_id": parse_object_id("account_id", account_id)? };
+        self.base.find_all(filter, doc! { "created_at": -1 }).await
+    }
+}
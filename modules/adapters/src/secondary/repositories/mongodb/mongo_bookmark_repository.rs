@@ -0,0 +1,141 @@
+use async_trait::async_trait;
+use futures::stream::TryStreamExt;
+use mongodb::Collection;
+use mongodb::bson::oid::ObjectId;
+use mongodb::bson::{Document, doc, to_document};
+use mongodb::options::ReturnDocument;
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::bookmark_entity::BookmarkEntity;
+use domain::repositories::bookmark_repository::BookmarkRepository;
+use processors::MongoRepository;
+use shared::models::failure::Failure;
+use shared::models::paginate::Paginate;
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::secondary::repositories::models::bookmark_schema::MongoBookmarkSchema;
+use crate::secondary::repositories::mongodb::mongo_base_repository::{
+    EntitySchema, MongoBaseRepository, is_duplicate_key,
+};
+
+#[derive(MongoRepository)]
+pub struct MongoBookmarkRepository {
+    base: MongoBaseRepository<BookmarkEntity, MongoBookmarkSchema>,
+}
+
+impl MongoBookmarkRepository {
+    pub fn new(collection: Arc<Collection<MongoBookmarkSchema>>) -> Self {
+        MongoBookmarkRepository { base: MongoBaseRepository::new(collection) }
+    }
+
+    fn parse_account_id(account_id: &str) -> DomainResponse<ObjectId> {
+        ObjectId::parse_str(account_id)
+            .map_err(|e| Failure::BadRequest(format!("Invalid account_id format: {}: {}", account_id, e)))
+    }
+
+    fn target_filter(account_id: &str, target_type: &str, target_id: &str) -> DomainResponse<Document> {
+        Ok(doc! {
+            "account_id": Self::parse_account_id(account_id)?,
+            "target_type": target_type,
+            "target_id": target_id
+        })
+    }
+}
+
+#[async_trait]
+impl BookmarkRepository for MongoBookmarkRepository {
+    async fn upsert(&self, bookmark: &BookmarkEntity) -> DomainResponse<BookmarkEntity> {
+        let filter = Self::target_filter(&bookmark.account_id, &bookmark.target_type, &bookmark.target_id)?;
+
+        // Only the first insert writes the document, later calls return it untouched
+        let mut schema = MongoBookmarkSchema::from_entity(bookmark);
+        schema.base.id = None;
+        let document =
+            to_document(&schema).map_err(|e| Failure::DatabaseError(format!("Failed to serialize bookmark: {}", e)))?;
+
+        let upserted = self
+            .base
+            .collection
+            .find_one_and_update(filter.clone(), doc! { "$setOnInsert": document })
+            .upsert(true)
+            .return_document(ReturnDocument::After)
+            .await;
+
+        // A concurrent upsert of the same target lost the race on the unique index, the bookmark is there
+        let schema = match upserted {
+            Err(error) if is_duplicate_key(&error) => self.base.collection.find_one(filter).await,
+            upserted => upserted,
+        };
+
+        schema
+            .map_err(|e| Failure::DatabaseError(format!("Failed to upsert bookmark: {}", e)))?
+            .map(|schema| schema.to_entity())
+            .ok_or(Failure::DatabaseError("Failed to upsert bookmark".to_string()))
+    }
+
+    async fn remove_by_target(&self, account_id: &str, target_type: &str, target_id: &str) -> DomainResponse<usize> {
+        let filter = Self::target_filter(account_id, target_type, target_id)?;
+
+        let deleted_result = self
+            .base
+            .collection
+            .delete_many(filter)
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to remove bookmark: {}", e)))?;
+
+        Ok(deleted_result.deleted_count as usize)
+    }
+
+    async fn find_by_account_id(
+        &self,
+        account_id: &str,
+        target_type: Option<&str>,
+        page: u32,
+        page_size: u32,
+    ) -> DomainResponse<(Paginate, Vec<BookmarkEntity>)> {
+        let mut filter = doc! { "account_id": Self::parse_account_id(account_id)? };
+        if let Some(target_type) = target_type {
+            filter.insert("target_type", target_type);
+        }
+
+        self.base.find_page(filter, doc! { "created_at": -1 }, page, page_size).await
+    }
+
+    async fn find_bookmarked_ids(
+        &self,
+        account_id: &str,
+        target_type: &str,
+        target_ids: &[String],
+    ) -> DomainResponse<Vec<String>> {
+        let filter = doc! {
+            "account_id": Self::parse_account_id(account_id)?,
+            "target_type": target_type,
+            "target_id": { "$in": target_ids }
+        };
+
+        let cursor = self
+            .base
+            .collection
+            .clone_with_type::<Document>()
+            .find(filter)
+            .projection(doc! { "target_id": 1 })
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to find bookmarks: {}", e)))?;
+
+        let target_ids = cursor
+            .try_collect::<Vec<Document>>()
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to iterate over bookmarks: {}", e)))?
+            .into_iter()
+            .filter_map(|document| document.get_str("target_id").ok().map(str::to_string))
+            .collect();
+
+        Ok(target_ids)
+    }
+
+    async fn remove_by_account_id(&self, account_id: &str) -> DomainResponse<usize> {
+        self.base.remove_many(doc! { "account_id": Self::parse_account_id(account_id)? }).await
+    }
+}
@@ -1,5 +1,9 @@
 use async_trait::async_trait;
-use mongodb::{Collection, bson::doc};
+use futures::stream::TryStreamExt;
+use mongodb::{
+    Collection,
+    bson::{Document, doc, oid::ObjectId},
+};
 use std::sync::Arc;
 
 // shared modules
@@ -23,6 +27,25 @@ impl MongoCategoryRepository {
     pub fn new(collection: Arc<Collection<MongoCategorySchema>>) -> Self {
         MongoCategoryRepository { base: MongoBaseRepository::new(collection) }
     }
+
+    async fn find_many(&self, filter: Document) -> DomainResponse<Vec<CategoryEntity>> {
+        let cursor = self
+            .base
+            .collection
+            .find(filter)
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to find categories: {}", e)))?;
+
+        let categories = cursor
+            .try_collect::<Vec<MongoCategorySchema>>()
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to iterate over categories: {}", e)))?
+            .into_iter()
+            .map(|schema| schema.to_entity())
+            .collect();
+
+        Ok(categories)
+    }
 }
 
 #[async_trait]
@@ -39,4 +62,25 @@ impl CategoryRepository for MongoCategoryRepository {
             Err(e) => Err(Failure::DatabaseError(format!("Failed to find category by name: {}", e))),
         }
     }
+
+    async fn find_by_ids(&self, ids: &[String]) -> DomainResponse<Vec<CategoryEntity>> {
+        // Malformed ids can not match any category, they are reported as missing by the caller
+        let object_ids: Vec<ObjectId> = ids.iter().filter_map(|id| ObjectId::parse_str(id).ok()).collect();
+
+        let filter = doc! {
+            "_id": { "$in": object_ids },
+            "deleted_at": { "$exists": false }
+        };
+
+        self.find_many(filter).await
+    }
+
+    async fn find_by_path_prefix(&self, path_prefix: &str) -> DomainResponse<Vec<CategoryEntity>> {
+        let filter = doc! {
+            "path": { "$regex": format!("^{}", regex::escape(path_prefix)) },
+            "deleted_at": { "$exists": false }
+        };
+
+        self.find_many(filter).await
+    }
 }
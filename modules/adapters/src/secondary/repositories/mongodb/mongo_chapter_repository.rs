@@ -0,0 +1,65 @@
+use async_trait::async_trait;
+use futures::stream::TryStreamExt;
+use mongodb::Collection;
+use mongodb::bson::doc;
+use mongodb::bson::oid::ObjectId;
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::chapter_entity::ChapterEntity;
+use domain::repositories::chapter_repository::ChapterRepository;
+use processors::MongoRepository;
+use shared::models::failure::Failure;
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::secondary::repositories::models::chapter_schema::MongoChapterSchema;
+use crate::secondary::repositories::mongodb::mongo_base_repository::{
+    EntitySchema, MongoBaseRepository, parse_object_id,
+};
+
+#[derive(MongoRepository)]
+pub struct MongoChapterRepository {
+    base: MongoBaseRepository<ChapterEntity, MongoChapterSchema>,
+}
+
+impl MongoChapterRepository {
+    pub fn new(collection: Arc<Collection<MongoChapterSchema>>) -> Self {
+        MongoChapterRepository { base: MongoBaseRepository::new(collection) }
+    }
+}
+
+#[async_trait]
+impl ChapterRepository for MongoChapterRepository {
+    async fn find_by_course_id(&self, course_id: &str) -> DomainResponse<Vec<ChapterEntity>> {
+        let course_id = ObjectId::parse_str(course_id)
+            .map_err(|e| Failure::BadRequest(format!("Invalid course_id format: {}: {}", course_id, e)))?;
+
+        let filter = doc! { "course_id": course_id, "deleted_at": { "$exists": false } };
+        let cursor = self
+            .base
+            .collection
+            .find(filter)
+            .sort(doc! { "order_index": 1, "created_at": 1 })
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to find chapters: {}", e)))?;
+
+        let chapters = cursor
+            .try_collect::<Vec<MongoChapterSchema>>()
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to iterate over chapters: {}", e)))?
+            .into_iter()
+            .map(|schema| schema.to_entity())
+            .collect();
+
+        Ok(chapters)
+    }
+
+    async fn find_and_remove_by_course_id(&self, course_id: &str) -> DomainResponse<Vec<ChapterEntity>> {
+        let filter = doc! { "course_id": parse_object_id("course_id", course_id)? };
+
+        let chapters = self.base.find_all(filter.clone(), doc! { "order_index": 1, "created_at": 1 }).await?;
+        self.base.remove_many(filter).await?;
+        Ok(chapters)
+    }
+}
@@ -0,0 +1,42 @@
+use async_trait::async_trait;
+use mongodb::Collection;
+use mongodb::bson::doc;
+use mongodb::bson::oid::ObjectId;
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::comment_ban_entity::CommentBanEntity;
+use domain::repositories::comment_ban_repository::CommentBanRepository;
+use processors::MongoRepository;
+use shared::models::failure::Failure;
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::secondary::repositories::models::comment_ban_schema::MongoCommentBanSchema;
+use crate::secondary::repositories::mongodb::mongo_base_repository::{EntitySchema, MongoBaseRepository};
+
+#[derive(MongoRepository)]
+pub struct MongoCommentBanRepository {
+    base: MongoBaseRepository<CommentBanEntity, MongoCommentBanSchema>,
+}
+
+impl MongoCommentBanRepository {
+    pub fn new(collection: Arc<Collection<MongoCommentBanSchema>>) -> Self {
+        MongoCommentBanRepository { base: MongoBaseRepository::new(collection) }
+    }
+}
+
+#[async_trait]
+impl CommentBanRepository for MongoCommentBanRepository {
+    async fn find_by_account_id(&self, account_id: &str) -> DomainResponse<Option<CommentBanEntity>> {
+        let account_id = ObjectId::parse_str(account_id)
+            .map_err(|e| Failure::BadRequest(format!("Invalid account_id format: {}: {}", account_id, e)))?;
+
+        let filter = doc! { "account_id": account_id, "deleted_at": { "$exists": false } };
+        match self.base.collection.find_one(filter).await {
+            Ok(Some(schema)) => Ok(Some(schema.to_entity())),
+            Ok(None) => Ok(None),
+            Err(e) => Err(Failure::DatabaseError(format!("Failed to find comment ban by account_id: {}", e))),
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use async_trait::async_trait;
+use mongodb::Collection;
+use mongodb::bson::doc;
+use mongodb::bson::oid::ObjectId;
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::comment_entity::CommentEntity;
+use domain::repositories::comment_repository::CommentRepository;
+use processors::MongoRepository;
+use shared::models::failure::Failure;
+use shared::models::paginate::Paginate;
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::secondary::repositories::models::comment_schema::MongoCommentSchema;
+use crate::secondary::repositories::mongodb::mongo_base_repository::{MongoBaseRepository, parse_object_id};
+
+#[derive(MongoRepository)]
+pub struct MongoCommentRepository {
+    base: MongoBaseRepository<CommentEntity, MongoCommentSchema>,
+}
+
+impl MongoCommentRepository {
+    pub fn new(collection: Arc<Collection<MongoCommentSchema>>) -> Self {
+        MongoCommentRepository { base: MongoBaseRepository::new(collection) }
+    }
+}
+
+#[async_trait]
+impl CommentRepository for MongoCommentRepository {
+    async fn find_by_blog_id_paginated(
+        &self,
+        blog_id: &str,
+        status: &str,
+        page: u32,
+        page_size: u32,
+    ) -> DomainResponse<(Paginate, Vec<CommentEntity>)> {
+        let blog_id = ObjectId::parse_str(blog_id)
+            .map_err(|e| Failure::BadRequest(format!("Invalid blog_id format: {}: {}", blog_id, e)))?;
+
+        let filter = doc! {
+            "blog_id": blog_id,
+            "status": status,
+            "deleted_at": { "$exists": false }
+        };
+
+        // Oldest first so replies always come after the comment they answer
+        self.base.find_page(filter, doc! { "created_at": 1 }, page, page_size).await
+    }
+
+    async fn update_status_by_author_id(&self, author_id: &str, status: &str) -> DomainResponse<usize> {
+        let author_id = ObjectId::parse_str(author_id)
+            .map_err(|e| Failure::BadRequest(format!("Invalid author_id format: {}: {}", author_id, e)))?;
+
+        let filter = doc! { "author_id": author_id };
+        let update = doc! { "$set": { "status": status, "updated_at": chrono::Utc::now().timestamp() } };
+
+        let updated_result = self
+            .base
+            .collection
+            .update_many(filter, update)
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to update comments by author: {}", e)))?;
+
+        Ok(updated_result.modified_count as usize)
+    }
+
+    async fn find_by_author_id(&self, author_id: &str) -> DomainResponse<Vec<CommentEntity>> {
+        let filter = doc! { "author_id": parse_object_id("author_id", author_id)?, "deleted_at": { "$exists": false } };
+        self.base.find_all(filter, doc! { "created_at": -1 }).await
+    }
+
+    async fn remove_by_author_id(&self, author_id: &str) -> DomainResponse<usize> {
+        self.base.remove_many(doc! { "author_id": parse_object_id("author_id", author_id)? }).await
+    }
+}
@@ -0,0 +1,189 @@
+use async_trait::async_trait;
+use futures::stream::TryStreamExt;
+use mongodb::Collection;
+use mongodb::bson::oid::ObjectId;
+use mongodb::bson::{Document, doc};
+use mongodb::options::ReturnDocument;
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::course_entity::CourseEntity;
+use domain::repositories::course_repository::CourseRepository;
+use domain::values::course_status::CourseStatus;
+use processors::MongoRepository;
+use shared::models::failure::Failure;
+use shared::models::filters::QuerySpec;
+use shared::models::paginate::Paginate;
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::secondary::repositories::models::course_schema::MongoCourseSchema;
+use crate::secondary::repositories::mongodb::mongo_base_repository::{
+    EntitySchema, MongoBaseRepository, parse_object_id,
+};
+
+#[derive(MongoRepository)]
+pub struct MongoCourseRepository {
+    base: MongoBaseRepository<CourseEntity, MongoCourseSchema>,
+}
+
+impl MongoCourseRepository {
+    pub fn new(collection: Arc<Collection<MongoCourseSchema>>) -> Self {
+        MongoCourseRepository { base: MongoBaseRepository::new(collection) }
+    }
+
+    fn parse_object_ids(ids: &[String], field: &str) -> DomainResponse<Vec<ObjectId>> {
+        ids.iter()
+            .map(|id| {
+                ObjectId::parse_str(id)
+                    .map_err(|e| Failure::BadRequest(format!("Invalid {} format: {}: {}", field, id, e)))
+            })
+            .collect()
+    }
+}
+
+#[async_trait]
+impl CourseRepository for MongoCourseRepository {
+    async fn find_published_paginated(
+        &self,
+        category_ids: Option<&[String]>,
+        level: Option<&str>,
+        spec: &QuerySpec,
+        page: u32,
+        page_size: u32,
+    ) -> DomainResponse<(Paginate, Vec<CourseEntity>)> {
+        let mut filter: Document = doc! {
+            "status": CourseStatus::Published.as_str(),
+            "deleted_at": { "$exists": false }
+        };
+        if let Some(category_ids) = category_ids {
+            filter.insert("category_id", doc! { "$in": Self::parse_object_ids(category_ids, "category_id")? });
+        }
+        if let Some(level) = level {
+            filter.insert("level", level);
+        }
+
+        self.base.find_by_spec(filter, spec, doc! { "created_at": -1 }, page, page_size).await
+    }
+
+    async fn find_by_author_id(&self, author_id: &str) -> DomainResponse<Vec<CourseEntity>> {
+        let author_id = ObjectId::parse_str(author_id)
+            .map_err(|e| Failure::BadRequest(format!("Invalid author_id format: {}: {}", author_id, e)))?;
+
+        let filter = doc! { "author_id": author_id, "deleted_at": { "$exists": false } };
+        let cursor = self
+            .base
+            .collection
+            .find(filter)
+            .sort(doc! { "created_at": -1 })
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to find courses: {}", e)))?;
+
+        let courses = cursor
+            .try_collect::<Vec<MongoCourseSchema>>()
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to iterate over courses: {}", e)))?
+            .into_iter()
+            .map(|schema| schema.to_entity())
+            .collect();
+
+        Ok(courses)
+    }
+
+    async fn update_details(&self, course: &CourseEntity) -> DomainResponse<CourseEntity> {
+        let course_id = course.base.id.clone().unwrap_or_default();
+        let object_id = ObjectId::parse_str(&course_id)
+            .map_err(|e| Failure::BadRequest(format!("Invalid course_id format: {}: {}", course_id, e)))?;
+        let category_id = ObjectId::parse_str(&course.category_id)
+            .map_err(|e| Failure::BadRequest(format!("Invalid category_id format: {}: {}", course.category_id, e)))?;
+
+        let update = doc! {
+            "$set": {
+                "category_id": category_id,
+                "name": &course.name,
+                "description": &course.description,
+                "level": &course.level,
+                "status": &course.status,
+                "approximate": course.approximate,
+                "updated_at": course.base.updated_at,
+            }
+        };
+
+        self.base
+            .collection
+            .find_one_and_update(doc! { "_id": object_id }, update)
+            .return_document(ReturnDocument::After)
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to update course: {}", e)))?
+            .map(|schema| schema.to_entity())
+            .ok_or_else(|| Failure::NotFound(format!("Course with id {} not found", course_id)))
+    }
+
+    async fn increment_enrollments(&self, course_id: &str, delta: i32) -> DomainResponse<()> {
+        let object_id = ObjectId::parse_str(course_id)
+            .map_err(|e| Failure::BadRequest(format!("Invalid course_id format: {}: {}", course_id, e)))?;
+
+        self.base
+            .collection
+            .update_one(doc! { "_id": object_id }, doc! { "$inc": { "enrollments": delta } })
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to increment course enrollments: {}", e)))?;
+
+        Ok(())
+    }
+    async fn apply_review(&self, course_id: &str, rating_delta: i32, count_delta: i32) -> DomainResponse<()> {
+        let object_id = ObjectId::parse_str(course_id)
+            .map_err(|e| Failure::BadRequest(format!("Invalid course_id format: {}: {}", course_id, e)))?;
+
+        // Same arithmetic as `CourseEntity::reviewed_rating`, done in the update so concurrent reviews do not race.
+        // Expressions in one $set stage all read the values from before the update
+        let review_count = doc! { "$ifNull": ["$review_count", 0] };
+        let next_count = doc! { "$add": [review_count.clone(), count_delta] };
+        let rating_sum = doc! { "$multiply": [{ "$ifNull": ["$rating", 0.0] }, review_count] };
+        let pipeline = vec![doc! {
+            "$set": {
+                "rating": {
+                    "$cond": [
+                        { "$gt": [next_count.clone(), 0] },
+                        { "$divide": [{ "$add": [rating_sum, rating_delta] }, next_count.clone()] },
+                        0.0
+                    ]
+                },
+                "review_count": { "$max": [next_count, 0] }
+            }
+        }];
+
+        self.base
+            .collection
+            .update_one(doc! { "_id": object_id }, pipeline)
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to update course rating: {}", e)))?;
+
+        Ok(())
+    }
+
+    async fn replace_author(&self, from: &str, to: &str) -> DomainResponse<usize> {
+        let from_id = parse_object_id("author_id", from)?;
+        let to_id = parse_object_id("author_id", to)?;
+
+        let updated_result = self
+            .base
+            .collection
+            .update_many(
+                doc! { "author_id": from_id },
+                doc! { "$set": { "author_id": to_id, "updated_at": chrono::Utc::now().timestamp() } },
+            )
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to reassign course author: {}", e)))?;
+
+        Ok(updated_result.modified_count as usize)
+    }
+
+    async fn find_and_remove_by_author_id(&self, author_id: &str) -> DomainResponse<Vec<CourseEntity>> {
+        let filter = doc! { "author_id": parse_object_id("author_id", author_id)? };
+
+        let courses = self.base.find_all(filter.clone(), doc! { "created_at": -1 }).await?;
+        self.base.remove_many(filter).await?;
+        Ok(courses)
+    }
+}
@@ -0,0 +1,80 @@
+use async_trait::async_trait;
+use futures::stream::TryStreamExt;
+use mongodb::Collection;
+use mongodb::bson::{Document, doc, oid::ObjectId};
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::data_export_entity::DataExportEntity;
+use domain::repositories::data_export_repository::DataExportRepository;
+use domain::values::data_export_status::DataExportStatus;
+use processors::MongoRepository;
+use shared::models::failure::Failure;
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::secondary::repositories::models::data_export_schema::MongoDataExportSchema;
+use crate::secondary::repositories::mongodb::mongo_base_repository::{EntitySchema, MongoBaseRepository};
+
+#[derive(MongoRepository)]
+pub struct MongoDataExportRepository {
+    base: MongoBaseRepository<DataExportEntity, MongoDataExportSchema>,
+}
+
+impl MongoDataExportRepository {
+    pub fn new(collection: Arc<Collection<MongoDataExportSchema>>) -> Self {
+        MongoDataExportRepository { base: MongoBaseRepository::new(collection) }
+    }
+
+    async fn find_sorted(
+        &self,
+        filter: Document,
+        sort: Document,
+        limit: Option<u32>,
+    ) -> DomainResponse<Vec<DataExportEntity>> {
+        let cursor = self
+            .base
+            .collection
+            .find(filter)
+            .sort(sort)
+            .limit(limit.map(|limit| limit as i64).unwrap_or_default())
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to find data exports: {}", e)))?;
+
+        let exports = cursor
+            .try_collect::<Vec<MongoDataExportSchema>>()
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to iterate over data exports: {}", e)))?
+            .into_iter()
+            .map(|schema| schema.to_entity())
+            .collect();
+
+        Ok(exports)
+    }
+}
+
+#[async_trait]
+impl DataExportRepository for MongoDataExportRepository {
+    async fn find_by_account_id(&self, account_id: &str) -> DomainResponse<Vec<DataExportEntity>> {
+        let object_id = ObjectId::parse_str(account_id)
+            .map_err(|_| Failure::BadRequest(format!("Invalid ID format: {}", account_id)))?;
+        let filter = doc! { "account_id": object_id, "deleted_at": { "$exists": false } };
+
+        self.find_sorted(filter, doc! { "created_at": -1 }, None).await
+    }
+
+    async fn find_pending(&self, limit: u32) -> DomainResponse<Vec<DataExportEntity>> {
+        let filter = doc! { "status": DataExportStatus::Pending.as_str(), "deleted_at": { "$exists": false } };
+        self.find_sorted(filter, doc! { "created_at": 1 }, Some(limit)).await
+    }
+
+    async fn find_expired(&self, expires_before: i64, limit: u32) -> DomainResponse<Vec<DataExportEntity>> {
+        let filter = doc! {
+            "status": DataExportStatus::Ready.as_str(),
+            "expires_at": { "$lte": expires_before },
+            "deleted_at": { "$exists": false },
+        };
+
+        self.find_sorted(filter, doc! { "expires_at": 1 }, Some(limit)).await
+    }
+}
@@ -0,0 +1,113 @@
+use async_trait::async_trait;
+use futures::stream::TryStreamExt;
+use mongodb::Collection;
+use mongodb::bson::oid::ObjectId;
+use mongodb::bson::{Document, doc};
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::enrollment_entity::EnrollmentEntity;
+use domain::repositories::enrollment_repository::EnrollmentRepository;
+use processors::MongoRepository;
+use shared::models::failure::Failure;
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::secondary::repositories::models::enrollment_schema::MongoEnrollmentSchema;
+use crate::secondary::repositories::mongodb::mongo_base_repository::{
+    EntitySchema, MongoBaseRepository, is_duplicate_key,
+};
+
+#[derive(MongoRepository)]
+pub struct MongoEnrollmentRepository {
+    base: MongoBaseRepository<EnrollmentEntity, MongoEnrollmentSchema>,
+}
+
+impl MongoEnrollmentRepository {
+    pub fn new(collection: Arc<Collection<MongoEnrollmentSchema>>) -> Self {
+        MongoEnrollmentRepository { base: MongoBaseRepository::new(collection) }
+    }
+
+    fn parse_object_id(id: &str, field: &str) -> DomainResponse<ObjectId> {
+        ObjectId::parse_str(id).map_err(|e| Failure::BadRequest(format!("Invalid {} format: {}: {}", field, id, e)))
+    }
+
+    async fn find_many(&self, filter: Document) -> DomainResponse<Vec<EnrollmentEntity>> {
+        let cursor = self
+            .base
+            .collection
+            .find(filter)
+            .sort(doc! { "updated_at": -1 })
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to find enrollments: {}", e)))?;
+
+        let enrollments = cursor
+            .try_collect::<Vec<MongoEnrollmentSchema>>()
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to iterate over enrollments: {}", e)))?
+            .into_iter()
+            .map(|schema| schema.to_entity())
+            .collect();
+
+        Ok(enrollments)
+    }
+}
+
+#[async_trait]
+impl EnrollmentRepository for MongoEnrollmentRepository {
+    async fn find_by_account_and_course(
+        &self,
+        account_id: &str,
+        course_id: &str,
+    ) -> DomainResponse<Option<EnrollmentEntity>> {
+        let filter = doc! {
+            "account_id": Self::parse_object_id(account_id, "account_id")?,
+            "course_id": Self::parse_object_id(course_id, "course_id")?,
+            "deleted_at": { "$exists": false }
+        };
+
+        let enrollment = self
+            .base
+            .collection
+            .find_one(filter)
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to find enrollment: {}", e)))?;
+
+        Ok(enrollment.map(|schema| schema.to_entity()))
+    }
+
+    async fn create_if_absent(&self, enrollment: &EnrollmentEntity) -> DomainResponse<Option<EnrollmentEntity>> {
+        let mut schema = MongoEnrollmentSchema::from_entity(enrollment);
+        match self.base.collection.insert_one(&schema).await {
+            Ok(inserted) => {
+                schema.base.id = inserted.inserted_id.as_object_id();
+                Ok(Some(schema.to_entity()))
+            },
+            // The unique (account_id, course_id) index turns a second enrollment away
+            Err(e) if is_duplicate_key(&e) => Ok(None),
+            Err(e) => Err(Failure::DatabaseError(format!("Failed to create enrollment: {}", e))),
+        }
+    }
+
+    async fn find_by_account_id(&self, account_id: &str) -> DomainResponse<Vec<EnrollmentEntity>> {
+        let filter = doc! {
+            "account_id": Self::parse_object_id(account_id, "account_id")?,
+            "deleted_at": { "$exists": false }
+        };
+
+        self.find_many(filter).await
+    }
+
+    async fn find_by_course_id(&self, course_id: &str) -> DomainResponse<Vec<EnrollmentEntity>> {
+        let filter = doc! {
+            "course_id": Self::parse_object_id(course_id, "course_id")?,
+            "deleted_at": { "$exists": false }
+        };
+
+        self.find_many(filter).await
+    }
+
+    async fn remove_by_account_id(&self, account_id: &str) -> DomainResponse<usize> {
+        self.base.remove_many(doc! { "account_id": Self::parse_object_id(account_id, "account_id")? }).await
+    }
+}
@@ -0,0 +1,66 @@
+use async_trait::async_trait;
+use futures::stream::TryStreamExt;
+use mongodb::Collection;
+use mongodb::bson::doc;
+use mongodb::bson::oid::ObjectId;
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::lesson_entity::LessonEntity;
+use domain::repositories::lesson_repository::LessonRepository;
+use processors::MongoRepository;
+use shared::models::failure::Failure;
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::secondary::repositories::models::lesson_schema::MongoLessonSchema;
+use crate::secondary::repositories::mongodb::mongo_base_repository::{
+    EntitySchema, MongoBaseRepository, parse_object_id,
+};
+
+#[derive(MongoRepository)]
+pub struct MongoLessonRepository {
+    base: MongoBaseRepository<LessonEntity, MongoLessonSchema>,
+}
+
+impl MongoLessonRepository {
+    pub fn new(collection: Arc<Collection<MongoLessonSchema>>) -> Self {
+        MongoLessonRepository { base: MongoBaseRepository::new(collection) }
+    }
+}
+
+#[async_trait]
+impl LessonRepository for MongoLessonRepository {
+    async fn find_by_chapter_ids(&self, chapter_ids: &[String]) -> DomainResponse<Vec<LessonEntity>> {
+        let chapter_ids = chapter_ids
+            .iter()
+            .map(|id| {
+                ObjectId::parse_str(id)
+                    .map_err(|e| Failure::BadRequest(format!("Invalid chapter_id format: {}: {}", id, e)))
+            })
+            .collect::<DomainResponse<Vec<ObjectId>>>()?;
+
+        let filter = doc! { "chapter_id": { "$in": chapter_ids }, "deleted_at": { "$exists": false } };
+        let cursor = self
+            .base
+            .collection
+            .find(filter)
+            .sort(doc! { "order_index": 1, "created_at": 1 })
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to find lessons: {}", e)))?;
+
+        let lessons = cursor
+            .try_collect::<Vec<MongoLessonSchema>>()
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to iterate over lessons: {}", e)))?
+            .into_iter()
+            .map(|schema| schema.to_entity())
+            .collect();
+
+        Ok(lessons)
+    }
+
+    async fn remove_by_chapter_id(&self, chapter_id: &str) -> DomainResponse<usize> {
+        self.base.remove_many(doc! { "chapter_id": parse_object_id("chapter_id", chapter_id)? }).await
+    }
+}
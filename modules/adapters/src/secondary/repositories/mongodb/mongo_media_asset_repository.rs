@@ -0,0 +1,71 @@
+use async_trait::async_trait;
+use futures::stream::TryStreamExt;
+use mongodb::Collection;
+use mongodb::bson::{Document, doc};
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::media_asset_entity::MediaAssetEntity;
+use domain::repositories::media_asset_repository::MediaAssetRepository;
+use processors::MongoRepository;
+use shared::models::failure::Failure;
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::secondary::repositories::models::media_asset_schema::MongoMediaAssetSchema;
+use crate::secondary::repositories::mongodb::mongo_base_repository::{
+    EntitySchema, MongoBaseRepository, parse_object_id,
+};
+
+#[derive(MongoRepository)]
+pub struct MongoMediaAssetRepository {
+    base: MongoBaseRepository<MediaAssetEntity, MongoMediaAssetSchema>,
+}
+
+impl MongoMediaAssetRepository {
+    pub fn new(collection: Arc<Collection<MongoMediaAssetSchema>>) -> Self {
+        MongoMediaAssetRepository { base: MongoBaseRepository::new(collection) }
+    }
+
+    async fn find_many(
+        &self,
+        filter: Document,
+        sort: Document,
+        limit: Option<i64>,
+    ) -> DomainResponse<Vec<MediaAssetEntity>> {
+        let mut find = self.base.collection.find(filter).sort(sort);
+        if let Some(limit) = limit {
+            find = find.limit(limit);
+        }
+
+        let cursor = find.await.map_err(|e| Failure::DatabaseError(format!("Failed to find media assets: {}", e)))?;
+        let assets = cursor
+            .try_collect::<Vec<MongoMediaAssetSchema>>()
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to iterate over media assets: {}", e)))?
+            .into_iter()
+            .map(|schema| schema.to_entity())
+            .collect();
+
+        Ok(assets)
+    }
+}
+
+#[async_trait]
+impl MediaAssetRepository for MongoMediaAssetRepository {
+    async fn find_by_reference(&self, purpose: &str, reference_id: &str) -> DomainResponse<Vec<MediaAssetEntity>> {
+        let filter = doc! { "purpose": purpose, "reference_id": reference_id, "deleted_at": { "$exists": false } };
+        self.find_many(filter, doc! { "created_at": -1 }, None).await
+    }
+
+    async fn find_orphans(&self, detached_before: i64, limit: u32) -> DomainResponse<Vec<MediaAssetEntity>> {
+        let filter = doc! { "detached_at": { "$lte": detached_before }, "deleted_at": { "$exists": false } };
+        self.find_many(filter, doc! { "detached_at": 1 }, Some(limit as i64)).await
+    }
+
+    async fn find_by_account_id(&self, account_id: &str) -> DomainResponse<Vec<MediaAssetEntity>> {
+        let filter =
+            doc! { "account_id": parse_object_id("account_id", account_id)?, "deleted_at": { "$exists": false } };
+        self.base.find_all(filter, doc! { "created_at": -1 }).await
+    }
+}
@@ -0,0 +1,81 @@
+use async_trait::async_trait;
+use futures::stream::TryStreamExt;
+use mongodb::Collection;
+use mongodb::bson::doc;
+use mongodb::bson::oid::ObjectId;
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::notification_preference_entity::NotificationPreferenceEntity;
+use domain::repositories::notification_preference_repository::NotificationPreferenceRepository;
+use processors::MongoRepository;
+use shared::models::failure::Failure;
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::secondary::repositories::models::notification_preference_schema::MongoNotificationPreferenceSchema;
+use crate::secondary::repositories::mongodb::mongo_base_repository::{EntitySchema, MongoBaseRepository};
+
+#[derive(MongoRepository)]
+pub struct MongoNotificationPreferenceRepository {
+    base: MongoBaseRepository<NotificationPreferenceEntity, MongoNotificationPreferenceSchema>,
+}
+
+impl MongoNotificationPreferenceRepository {
+    pub fn new(collection: Arc<Collection<MongoNotificationPreferenceSchema>>) -> Self {
+        MongoNotificationPreferenceRepository { base: MongoBaseRepository::new(collection) }
+    }
+
+    fn parse_account_id(account_id: &str) -> DomainResponse<ObjectId> {
+        ObjectId::parse_str(account_id)
+            .map_err(|e| Failure::BadRequest(format!("Invalid account_id format: {}: {}", account_id, e)))
+    }
+}
+
+#[async_trait]
+impl NotificationPreferenceRepository for MongoNotificationPreferenceRepository {
+    async fn find_by_account_id(&self, account_id: &str) -> DomainResponse<Option<NotificationPreferenceEntity>> {
+        let filter = doc! { "account_id": Self::parse_account_id(account_id)?, "deleted_at": { "$exists": false } };
+
+        let preferences = self
+            .base
+            .collection
+            .find_one(filter)
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to find notification preferences: {}", e)))?;
+
+        Ok(preferences.map(|schema| schema.to_entity()))
+    }
+
+    async fn find_digest_due(&self, now: i64, limit: u32) -> DomainResponse<Vec<NotificationPreferenceEntity>> {
+        let filter = doc! { "next_digest_at": { "$lte": now }, "deleted_at": { "$exists": false } };
+
+        let cursor = self
+            .base
+            .collection
+            .find(filter)
+            .sort(doc! { "next_digest_at": 1 })
+            .limit(limit as i64)
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to find due digests: {}", e)))?;
+
+        let preferences = cursor
+            .try_collect::<Vec<MongoNotificationPreferenceSchema>>()
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to iterate over due digests: {}", e)))?
+            .into_iter()
+            .map(|schema| schema.to_entity())
+            .collect();
+
+        Ok(preferences)
+    }
+
+    async fn remove_by_account_id(&self, account_id: &str) -> DomainResponse<usize> {
+        let object_id = Self::parse_account_id(account_id)?;
+        let result = self.base.collection.delete_many(doc! { "account_id": object_id }).await.map_err(|e| {
+            Failure::DatabaseError(format!("Failed to remove notification preferences by account id: {}", e))
+        })?;
+
+        Ok(result.deleted_count as usize)
+    }
+}
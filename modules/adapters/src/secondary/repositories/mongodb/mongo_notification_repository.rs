@@ -1,15 +1,21 @@
 use async_trait::async_trait;
+use futures::stream::TryStreamExt;
 use mongodb::Collection;
+use mongodb::bson::doc;
+use mongodb::bson::oid::ObjectId;
 use std::sync::Arc;
 
 // shared modules
 use domain::entities::notification_entity::NotificationEntity;
 use domain::repositories::notification_repository::NotificationRepository;
 use processors::MongoRepository;
+use shared::models::failure::Failure;
+use shared::models::paginate::Paginate;
+use shared::types::DomainResponse;
 
+// internal modules
 use crate::secondary::repositories::models::notification_schema::MongoNotificationSchema;
-// shared modules
-use crate::secondary::repositories::mongodb::mongo_base_repository::MongoBaseRepository;
+use crate::secondary::repositories::mongodb::mongo_base_repository::{EntitySchema, MongoBaseRepository};
 
 #[derive(MongoRepository)]
 pub struct MongoNotificationRepository {
@@ -20,7 +26,111 @@ impl MongoNotificationRepository {
     pub fn new(collection: Arc<Collection<MongoNotificationSchema>>) -> Self {
         MongoNotificationRepository { base: MongoBaseRepository::new(collection) }
     }
+
+    fn parse_account_id(account_id: &str) -> DomainResponse<ObjectId> {
+        ObjectId::parse_str(account_id)
+            .map_err(|e| Failure::BadRequest(format!("Invalid account_id format: {}: {}", account_id, e)))
+    }
 }
 
 #[async_trait]
-impl NotificationRepository for MongoNotificationRepository {}
+impl NotificationRepository for MongoNotificationRepository {
+    async fn find_by_account_id_paginated(
+        &self,
+        account_id: &str,
+        unread_only: bool,
+        muted_kinds: &[String],
+        page: u32,
+        page_size: u32,
+    ) -> DomainResponse<(Paginate, Vec<NotificationEntity>)> {
+        let mut filter = doc! {
+            "account_id": Self::parse_account_id(account_id)?,
+            "kind": { "$nin": muted_kinds },
+            "deleted_at": { "$exists": false }
+        };
+        if unread_only {
+            filter.insert("is_read", false);
+        }
+
+        self.base.find_page(filter, doc! { "created_at": -1 }, page, page_size).await
+    }
+
+    async fn find_unread_since(
+        &self,
+        account_id: &str,
+        since: i64,
+        limit: u32,
+    ) -> DomainResponse<Vec<NotificationEntity>> {
+        let filter = doc! {
+            "account_id": Self::parse_account_id(account_id)?,
+            "is_read": false,
+            "created_at": { "$gt": since },
+            "deleted_at": { "$exists": false }
+        };
+
+        let cursor = self
+            .base
+            .collection
+            .find(filter)
+            .sort(doc! { "created_at": -1 })
+            .limit(limit as i64)
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to find unread notifications: {}", e)))?;
+
+        let notifications = cursor
+            .try_collect::<Vec<MongoNotificationSchema>>()
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to iterate over unread notifications: {}", e)))?
+            .into_iter()
+            .map(|schema| schema.to_entity())
+            .collect();
+
+        Ok(notifications)
+    }
+
+    async fn count_unread(&self, account_id: &str, muted_kinds: &[String]) -> DomainResponse<u64> {
+        let filter = doc! {
+            "account_id": Self::parse_account_id(account_id)?,
+            "kind": { "$nin": muted_kinds },
+            "is_read": false,
+            "deleted_at": { "$exists": false }
+        };
+
+        self.base
+            .collection
+            .count_documents(filter)
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to count unread notifications: {}", e)))
+    }
+
+    async fn mark_all_read(&self, account_id: &str, muted_kinds: &[String], read_at: i64) -> DomainResponse<usize> {
+        let filter = doc! {
+            "account_id": Self::parse_account_id(account_id)?,
+            "kind": { "$nin": muted_kinds },
+            "is_read": false,
+            "deleted_at": { "$exists": false }
+        };
+        let update = doc! { "$set": { "is_read": true, "read_at": read_at, "updated_at": read_at } };
+
+        let updated_result = self
+            .base
+            .collection
+            .update_many(filter, update)
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to mark notifications as read: {}", e)))?;
+
+        Ok(updated_result.modified_count as usize)
+    }
+
+    async fn remove_by_account_id(&self, account_id: &str) -> DomainResponse<usize> {
+        let object_id = Self::parse_account_id(account_id)?;
+        let result = self
+            .base
+            .collection
+            .delete_many(doc! { "account_id": object_id })
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to remove notifications by account id: {}", e)))?;
+
+        Ok(result.deleted_count as usize)
+    }
+}
@@ -0,0 +1,96 @@
+use async_trait::async_trait;
+use futures::stream::TryStreamExt;
+use mongodb::Collection;
+use mongodb::bson::doc;
+use mongodb::bson::oid::ObjectId;
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::progress_entity::ProgressEntity;
+use domain::repositories::progress_repository::ProgressRepository;
+use processors::MongoRepository;
+use shared::models::failure::Failure;
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::secondary::repositories::models::progress_schema::MongoProgressSchema;
+use crate::secondary::repositories::mongodb::mongo_base_repository::{
+    EntitySchema, MongoBaseRepository, parse_object_id,
+};
+
+#[derive(MongoRepository)]
+pub struct MongoProgressRepository {
+    base: MongoBaseRepository<ProgressEntity, MongoProgressSchema>,
+}
+
+impl MongoProgressRepository {
+    pub fn new(collection: Arc<Collection<MongoProgressSchema>>) -> Self {
+        MongoProgressRepository { base: MongoBaseRepository::new(collection) }
+    }
+}
+
+#[async_trait]
+impl ProgressRepository for MongoProgressRepository {
+    async fn find_by_enrollment_and_lesson(
+        &self,
+        enrollment_id: &str,
+        lesson_id: &str,
+    ) -> DomainResponse<Option<ProgressEntity>> {
+        let enrollment_object_id = ObjectId::parse_str(enrollment_id)
+            .map_err(|e| Failure::BadRequest(format!("Invalid enrollment_id format: {}: {}", enrollment_id, e)))?;
+        let lesson_object_id = ObjectId::parse_str(lesson_id)
+            .map_err(|e| Failure::BadRequest(format!("Invalid lesson_id format: {}: {}", lesson_id, e)))?;
+
+        let filter = doc! {
+            "enrollment_id": enrollment_object_id,
+            "lesson_id": lesson_object_id,
+            "deleted_at": { "$exists": false }
+        };
+
+        let progress = self
+            .base
+            .collection
+            .find_one(filter)
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to find progress: {}", e)))?;
+
+        Ok(progress.map(|schema| schema.to_entity()))
+    }
+
+    async fn find_by_enrollment_ids(&self, enrollment_ids: &[String]) -> DomainResponse<Vec<ProgressEntity>> {
+        let enrollment_ids = enrollment_ids
+            .iter()
+            .map(|id| {
+                ObjectId::parse_str(id)
+                    .map_err(|e| Failure::BadRequest(format!("Invalid enrollment_id format: {}: {}", id, e)))
+            })
+            .collect::<DomainResponse<Vec<ObjectId>>>()?;
+
+        let filter = doc! { "enrollment_id": { "$in": enrollment_ids }, "deleted_at": { "$exists": false } };
+        let cursor = self
+            .base
+            .collection
+            .find(filter)
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to find progresses: {}", e)))?;
+
+        let progresses = cursor
+            .try_collect::<Vec<MongoProgressSchema>>()
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to iterate over progresses: {}", e)))?
+            .into_iter()
+            .map(|schema| schema.to_entity())
+            .collect();
+
+        Ok(progresses)
+    }
+
+    async fn remove_by_enrollment_ids(&self, enrollment_ids: &[String]) -> DomainResponse<usize> {
+        let enrollment_ids = enrollment_ids
+            .iter()
+            .map(|id| parse_object_id("enrollment_id", id))
+            .collect::<DomainResponse<Vec<ObjectId>>>()?;
+
+        self.base.remove_many(doc! { "enrollment_id": { "$in": enrollment_ids } }).await
+    }
+}
@@ -52,4 +52,17 @@ impl ProviderRepository for MongoProviderRepository {
 
         Ok(entities)
     }
+
+    async fn remove_by_account_id(&self, account_id: &str) -> DomainResponse<usize> {
+        let object_id = ObjectId::parse_str(account_id)
+            .map_err(|_| Failure::BadRequest(format!("Invalid ID format: {}", account_id)))?;
+        let result = self
+            .base
+            .collection
+            .delete_many(doc! { "account_id": object_id })
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to remove providers by account id: {}", e)))?;
+
+        Ok(result.deleted_count as usize)
+    }
 }
@@ -0,0 +1,111 @@
+use async_trait::async_trait;
+use mongodb::Collection;
+use mongodb::bson::doc;
+use mongodb::bson::oid::ObjectId;
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::review_entity::ReviewEntity;
+use domain::repositories::review_repository::ReviewRepository;
+use processors::MongoRepository;
+use shared::models::failure::Failure;
+use shared::models::paginate::Paginate;
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::secondary::repositories::models::review_schema::MongoReviewSchema;
+use crate::secondary::repositories::mongodb::mongo_base_repository::{
+    EntitySchema, MongoBaseRepository, is_duplicate_key,
+};
+
+#[derive(MongoRepository)]
+pub struct MongoReviewRepository {
+    base: MongoBaseRepository<ReviewEntity, MongoReviewSchema>,
+}
+
+impl MongoReviewRepository {
+    pub fn new(collection: Arc<Collection<MongoReviewSchema>>) -> Self {
+        MongoReviewRepository { base: MongoBaseRepository::new(collection) }
+    }
+
+    fn parse_object_id(id: &str, field: &str) -> DomainResponse<ObjectId> {
+        ObjectId::parse_str(id).map_err(|e| Failure::BadRequest(format!("Invalid {} format: {}: {}", field, id, e)))
+    }
+}
+
+#[async_trait]
+impl ReviewRepository for MongoReviewRepository {
+    async fn find_by_course_and_account(
+        &self,
+        course_id: &str,
+        account_id: &str,
+    ) -> DomainResponse<Option<ReviewEntity>> {
+        let filter = doc! {
+            "course_id": Self::parse_object_id(course_id, "course_id")?,
+            "account_id": Self::parse_object_id(account_id, "account_id")?,
+            "deleted_at": { "$exists": false }
+        };
+
+        let review = self
+            .base
+            .collection
+            .find_one(filter)
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to find review: {}", e)))?;
+
+        Ok(review.map(|schema| schema.to_entity()))
+    }
+
+    async fn create_if_absent(&self, review: &ReviewEntity) -> DomainResponse<Option<ReviewEntity>> {
+        let mut schema = MongoReviewSchema::from_entity(review);
+        match self.base.collection.insert_one(&schema).await {
+            Ok(inserted) => {
+                schema.base.id = inserted.inserted_id.as_object_id();
+                Ok(Some(schema.to_entity()))
+            },
+            // The unique (course_id, account_id) index turns a second review away
+            Err(e) if is_duplicate_key(&e) => Ok(None),
+            Err(e) => Err(Failure::DatabaseError(format!("Failed to create review: {}", e))),
+        }
+    }
+
+    async fn find_by_course_id_paginated(
+        &self,
+        course_id: &str,
+        status: &str,
+        page: u32,
+        page_size: u32,
+    ) -> DomainResponse<(Paginate, Vec<ReviewEntity>)> {
+        let filter = doc! {
+            "course_id": Self::parse_object_id(course_id, "course_id")?,
+            "status": status,
+            "deleted_at": { "$exists": false }
+        };
+
+        self.base.find_page(filter, doc! { "created_at": -1 }, page, page_size).await
+    }
+
+    async fn find_by_status_paginated(
+        &self,
+        status: &str,
+        page: u32,
+        page_size: u32,
+    ) -> DomainResponse<(Paginate, Vec<ReviewEntity>)> {
+        let filter = doc! { "status": status, "deleted_at": { "$exists": false } };
+
+        self.base.find_page(filter, doc! { "created_at": -1 }, page, page_size).await
+    }
+
+    async fn find_by_account_id(&self, account_id: &str) -> DomainResponse<Vec<ReviewEntity>> {
+        let filter = doc! {
+            "account_id": Self::parse_object_id(account_id, "account_id")?,
+            "deleted_at": { "$exists": false }
+        };
+
+        self.base.find_all(filter, doc! { "created_at": -1 }).await
+    }
+
+    async fn remove_by_account_id(&self, account_id: &str) -> DomainResponse<usize> {
+        self.base.remove_many(doc! { "account_id": Self::parse_object_id(account_id, "account_id")? }).await
+    }
+}
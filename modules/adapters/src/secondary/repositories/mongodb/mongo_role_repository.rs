@@ -58,4 +58,31 @@ impl RoleRepository for MongoRoleRepository {
             Err(e) => Err(Failure::DatabaseError(format!("Failed to update role by account_id: {}", e))),
         }
     }
+
+    async fn find_account_ids_by_role(&self, role_name: &str) -> DomainResponse<Vec<String>> {
+        let account_ids = self
+            .base
+            .collection
+            .distinct("account_id", doc! { "role_name": role_name })
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to find accounts by role: {}", e)))?
+            .into_iter()
+            .filter_map(|account_id| account_id.as_object_id().map(|object_id| object_id.to_hex()))
+            .collect();
+
+        Ok(account_ids)
+    }
+
+    async fn remove_by_account_id(&self, account_id: &str) -> DomainResponse<usize> {
+        let object_id = ObjectId::parse_str(account_id)
+            .map_err(|_| Failure::BadRequest(format!("Invalid ID format: {}", account_id)))?;
+        let result = self
+            .base
+            .collection
+            .delete_many(doc! { "account_id": object_id })
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to remove roles by account id: {}", e)))?;
+
+        Ok(result.deleted_count as usize)
+    }
 }
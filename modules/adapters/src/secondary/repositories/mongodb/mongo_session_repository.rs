@@ -1,4 +1,5 @@
 use async_trait::async_trait;
+use futures::stream::TryStreamExt;
 use mongodb::{
     Collection,
     bson::{doc, oid::ObjectId},
@@ -49,4 +50,61 @@ impl SessionRepository for MongoSessionRepository {
 
         Ok(result.map(|schema| schema.to_entity()))
     }
+
+    async fn find_with_device_token(&self, account_id: &str) -> DomainResponse<Vec<SessionEntity>> {
+        let object_id = ObjectId::parse_str(account_id)
+            .map_err(|_| Failure::BadRequest(format!("Invalid ID format: {}", account_id)))?;
+        let query = doc! {
+            "account_id": object_id,
+            "device_token": { "$type": "string" },
+            "expires_at": { "$gt": chrono::Utc::now().timestamp() },
+            "deleted_at": { "$exists": false }
+        };
+
+        let cursor = self.base.collection.find(query).await.map_err(|e| {
+            Failure::DatabaseError(format!("Failed to find sessions with device token with {:?}", e).to_string())
+        })?;
+
+        let sessions = cursor
+            .try_collect::<Vec<MongoSessionSchema>>()
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to iterate over sessions with {:?}", e).to_string()))?
+            .into_iter()
+            .map(|schema| schema.to_entity())
+            .collect();
+
+        Ok(sessions)
+    }
+
+    async fn clear_device_token(&self, device_token: &str) -> DomainResponse<usize> {
+        let query = doc! { "device_token": device_token };
+        let update = doc! { "$set": { "device_token": null, "updated_at": chrono::Utc::now().timestamp() } };
+
+        let result =
+            self.base.collection.update_many(query, update).await.map_err(|e| {
+                Failure::DatabaseError(format!("Failed to clear device token with {:?}", e).to_string())
+            })?;
+
+        Ok(result.modified_count as usize)
+    }
+
+    async fn find_by_account_id(&self, account_id: &str) -> DomainResponse<Vec<SessionEntity>> {
+        let object_id = ObjectId::parse_str(account_id)
+            .map_err(|_| Failure::BadRequest(format!("Invalid ID format: {}", account_id)))?;
+        let query = doc! { "account_id": object_id, "deleted_at": { "$exists": false } };
+
+        let cursor = self.base.collection.find(query).sort(doc! { "created_at": -1 }).await.map_err(|e| {
+            Failure::DatabaseError(format!("Failed to find sessions by account id with {:?}", e).to_string())
+        })?;
+
+        let sessions = cursor
+            .try_collect::<Vec<MongoSessionSchema>>()
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to iterate over sessions with {:?}", e).to_string()))?
+            .into_iter()
+            .map(|schema| schema.to_entity())
+            .collect();
+
+        Ok(sessions)
+    }
 }
@@ -0,0 +1,83 @@
+use async_trait::async_trait;
+use futures::stream::TryStreamExt;
+use mongodb::Collection;
+use mongodb::bson::doc;
+use mongodb::bson::oid::ObjectId;
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::topic_subscription_entity::TopicSubscriptionEntity;
+use domain::repositories::topic_subscription_repository::TopicSubscriptionRepository;
+use processors::MongoRepository;
+use shared::models::failure::Failure;
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::secondary::repositories::models::topic_subscription_schema::MongoTopicSubscriptionSchema;
+use crate::secondary::repositories::mongodb::mongo_base_repository::{EntitySchema, MongoBaseRepository};
+
+#[derive(MongoRepository)]
+pub struct MongoTopicSubscriptionRepository {
+    base: MongoBaseRepository<TopicSubscriptionEntity, MongoTopicSubscriptionSchema>,
+}
+
+impl MongoTopicSubscriptionRepository {
+    pub fn new(collection: Arc<Collection<MongoTopicSubscriptionSchema>>) -> Self {
+        MongoTopicSubscriptionRepository { base: MongoBaseRepository::new(collection) }
+    }
+
+    fn parse_account_id(account_id: &str) -> DomainResponse<ObjectId> {
+        ObjectId::parse_str(account_id)
+            .map_err(|e| Failure::BadRequest(format!("Invalid account_id format: {}: {}", account_id, e)))
+    }
+}
+
+#[async_trait]
+impl TopicSubscriptionRepository for MongoTopicSubscriptionRepository {
+    async fn find_by_account_id(&self, account_id: &str) -> DomainResponse<Vec<TopicSubscriptionEntity>> {
+        let filter = doc! { "account_id": Self::parse_account_id(account_id)?, "deleted_at": { "$exists": false } };
+
+        let cursor = self
+            .base
+            .collection
+            .find(filter)
+            .sort(doc! { "topic": 1 })
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to find topic subscriptions: {}", e)))?;
+
+        let subscriptions = cursor
+            .try_collect::<Vec<MongoTopicSubscriptionSchema>>()
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to iterate over topic subscriptions: {}", e)))?
+            .into_iter()
+            .map(|schema| schema.to_entity())
+            .collect();
+
+        Ok(subscriptions)
+    }
+
+    async fn find_by_account_and_topic(
+        &self,
+        account_id: &str,
+        topic: &str,
+    ) -> DomainResponse<Option<TopicSubscriptionEntity>> {
+        let filter = doc! {
+            "account_id": Self::parse_account_id(account_id)?,
+            "topic": topic,
+            "deleted_at": { "$exists": false }
+        };
+
+        let subscription = self
+            .base
+            .collection
+            .find_one(filter)
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to find topic subscription: {}", e)))?;
+
+        Ok(subscription.map(|schema| schema.to_entity()))
+    }
+
+    async fn remove_by_account_id(&self, account_id: &str) -> DomainResponse<usize> {
+        self.base.remove_many(doc! { "account_id": Self::parse_account_id(account_id)? }).await
+    }
+}
@@ -0,0 +1,66 @@
+use async_trait::async_trait;
+use futures::stream::TryStreamExt;
+use mongodb::Collection;
+use mongodb::bson::doc;
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::upload_entity::UploadEntity;
+use domain::repositories::upload_repository::UploadRepository;
+use domain::values::upload_status::UploadStatus;
+use processors::MongoRepository;
+use shared::models::failure::Failure;
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::secondary::repositories::models::upload_schema::MongoUploadSchema;
+use crate::secondary::repositories::mongodb::mongo_base_repository::{
+    EntitySchema, MongoBaseRepository, parse_object_id,
+};
+
+#[derive(MongoRepository)]
+pub struct MongoUploadRepository {
+    base: MongoBaseRepository<UploadEntity, MongoUploadSchema>,
+}
+
+impl MongoUploadRepository {
+    pub fn new(collection: Arc<Collection<MongoUploadSchema>>) -> Self {
+        MongoUploadRepository { base: MongoBaseRepository::new(collection) }
+    }
+}
+
+#[async_trait]
+impl UploadRepository for MongoUploadRepository {
+    async fn find_expired(&self, expires_before: i64, limit: u32) -> DomainResponse<Vec<UploadEntity>> {
+        let filter = doc! {
+            "status": UploadStatus::Pending.as_str(),
+            "expires_at": { "$lte": expires_before },
+            "deleted_at": { "$exists": false },
+        };
+
+        let cursor = self
+            .base
+            .collection
+            .find(filter)
+            .sort(doc! { "expires_at": 1 })
+            .limit(limit as i64)
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to find expired uploads: {}", e)))?;
+
+        let uploads = cursor
+            .try_collect::<Vec<MongoUploadSchema>>()
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to iterate over expired uploads: {}", e)))?
+            .into_iter()
+            .map(|schema| schema.to_entity())
+            .collect();
+
+        Ok(uploads)
+    }
+
+    async fn find_by_account_id(&self, account_id: &str) -> DomainResponse<Vec<UploadEntity>> {
+        let filter =
+            doc! { "account_id": parse_object_id("account_id", account_id)?, "deleted_at": { "$exists": false } };
+        self.base.find_all(filter, doc! { "created_at": -1 }).await
+    }
+}
@@ -1,10 +1,27 @@
 pub mod scylla_account_repository;
+pub mod scylla_audit_event_repository;
 pub mod scylla_base_repository;
 pub mod scylla_blog_repository;
+pub mod scylla_blog_revision_repository;
+pub mod scylla_blog_star_repository;
+pub mod scylla_bookmark_repository;
 pub mod scylla_category_repository;
+pub mod scylla_chapter_repository;
+pub mod scylla_comment_ban_repository;
+pub mod scylla_comment_repository;
+pub mod scylla_course_repository;
+pub mod scylla_data_export_repository;
+pub mod scylla_enrollment_repository;
 pub mod scylla_experience_repository;
+pub mod scylla_lesson_repository;
+pub mod scylla_media_asset_repository;
+pub mod scylla_notification_preference_repository;
 pub mod scylla_notification_repository;
+pub mod scylla_progress_repository;
 pub mod scylla_project_repository;
 pub mod scylla_provider_repository;
+pub mod scylla_review_repository;
 pub mod scylla_role_repository;
 pub mod scylla_session_repository;
+pub mod scylla_topic_subscription_repository;
+pub mod scylla_upload_repository;
@@ -4,16 +4,18 @@ use std::sync::Arc;
 
 // shared modules
 use domain::entities::account_entity::AccountEntity;
-use domain::repositories::account_repository::AccountRepository;
-use shared::models::failure::Failure;
+use domain::repositories::account_repository::{AccountRepository, AccountSearch};
+use domain::values::account_status::AccountStatus;
+use shared::models::filters::{Filterable, MemoryFilter, QuerySpec};
+use shared::models::paginate::Paginate;
 use shared::types::DomainResponse;
 
 // internal modules
 use crate::impl_scylla_base_repository;
 use crate::secondary::repositories::models::account_schema::ScyllaAccountSchema;
-use crate::secondary::repositories::scylla::scylla_base_repository::ScyllaBaseRepository;
+use crate::secondary::repositories::scylla::scylla_base_repository::{ScyllaBaseRepository, paginate_entities};
+use crate::shared::utilities::databases::{ACCOUNT_BY_EMAIL_TABLE, ACCOUNT_BY_USERNAME_TABLE};
 
-#[allow(dead_code)]
 pub struct ScyllaAccountRepository {
     base: ScyllaBaseRepository<AccountEntity, ScyllaAccountSchema>,
 }
@@ -22,6 +24,32 @@ impl ScyllaAccountRepository {
     pub fn new(session: Arc<Session>, keyspace: &str, table_name: &str) -> Self {
         ScyllaAccountRepository { base: ScyllaBaseRepository::new(session, keyspace, table_name) }
     }
+
+    /// CQL has no substring or case-insensitive match, the search criteria are applied to the scanned rows.
+    fn matches(account: &AccountEntity, search: &AccountSearch, now: i64) -> bool {
+        let contains = |value: &str, part: &Option<String>| {
+            part.as_ref().is_none_or(|part| value.to_lowercase().contains(&part.to_lowercase()))
+        };
+
+        let status = match search.status {
+            Some(AccountStatus::Active) => {
+                account.is_active && account.suspended_until.is_none_or(|until| until <= now)
+            },
+            Some(AccountStatus::Deactivated) => !account.is_active && account.deletion_due_at.is_none(),
+            Some(AccountStatus::Suspended) => account.suspended_until.is_some_and(|until| until > now),
+            Some(AccountStatus::PendingDeletion) => account.deletion_due_at.is_some(),
+            None => true,
+        };
+
+        status
+            && contains(&account.email, &search.email)
+            && contains(&account.username, &search.username)
+            && search.email_verified.is_none_or(|email_verified| account.email_verified == email_verified)
+            && search
+                .account_ids
+                .as_ref()
+                .is_none_or(|account_ids| account.base.id.as_ref().is_some_and(|id| account_ids.contains(id)))
+    }
 }
 
 impl_scylla_base_repository!(ScyllaAccountRepository, AccountEntity, ScyllaAccountSchema);
@@ -29,26 +57,46 @@ impl_scylla_base_repository!(ScyllaAccountRepository, AccountEntity, ScyllaAccou
 #[async_trait]
 impl AccountRepository for ScyllaAccountRepository {
     async fn find_by_email(&self, email: &str) -> DomainResponse<Option<AccountEntity>> {
-        let query = r#"
-            SELECT id, avatar, bio, username, email, email_verified, is_active, created_at, updated_at, deleted_at
-            FROM visionary.accounts
-            WHERE email = ?
-            ALLOW FILTERING
-        "#;
-
-        let prepared = self
-            .base
-            .session
-            .prepare(query)
-            .await
-            .map_err(|e| Failure::DatabaseError(format!("Failed to prepare query: {}", e)))?;
-
-        self.base
-            .session
-            .execute_unpaged(&prepared, (email,))
-            .await
-            .map_err(|e| Failure::DatabaseError(format!("Failed to find account by email: {}", e)))?;
-
-        Ok(None)
+        let accounts = self.base.select_by_lookup(ACCOUNT_BY_EMAIL_TABLE, "WHERE email = ?", (email,)).await?;
+        Ok(accounts.into_iter().next())
+    }
+
+    async fn find_by_username(&self, username: &str) -> DomainResponse<Option<AccountEntity>> {
+        // Usernames are unique regardless of case, which CQL can not compare on, so the lookup holds them lowercase
+        let username = username.to_lowercase();
+        let accounts = self.base.select_by_lookup(ACCOUNT_BY_USERNAME_TABLE, "WHERE username = ?", (username,)).await?;
+        Ok(accounts.into_iter().next())
+    }
+
+    async fn find_deletion_due(&self, now: i64, limit: u32) -> DomainResponse<Vec<AccountEntity>> {
+        let mut accounts = self.base.select("WHERE deletion_due_at <= ? ALLOW FILTERING", (now,)).await?;
+        accounts.sort_by_key(|account| account.deletion_due_at);
+        accounts.truncate(limit as usize);
+
+        Ok(accounts)
+    }
+
+    async fn search(
+        &self,
+        search: &AccountSearch,
+        spec: &QuerySpec,
+        now: i64,
+        page: u32,
+        page_size: u32,
+    ) -> DomainResponse<(Paginate, Vec<AccountEntity>)> {
+        let mut accounts: Vec<AccountEntity> =
+            self.base.select("", ()).await?.into_iter().filter(|account| Self::matches(account, search, now)).collect();
+        accounts.sort_by_key(|account| std::cmp::Reverse(account.base.created_at));
+
+        let fields = AccountEntity::filter_fields();
+        let mut accounts: Vec<(serde_json::Value, AccountEntity)> = accounts
+            .into_iter()
+            .map(|account| (serde_json::to_value(&account).unwrap_or_default(), account))
+            .filter(|(value, _)| MemoryFilter::matches(spec, fields, value))
+            .collect();
+        accounts.sort_by(|(a, _), (b, _)| MemoryFilter::compare(spec, fields, a, b));
+
+        let accounts = accounts.into_iter().map(|(_, account)| account).collect();
+        Ok(paginate_entities(accounts, page, page_size))
     }
 }
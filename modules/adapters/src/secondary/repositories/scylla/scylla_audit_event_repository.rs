@@ -0,0 +1,132 @@
+use async_trait::async_trait;
+use futures::stream::TryStreamExt;
+use scylla::client::session::Session;
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::audit_event_entity::AuditEventEntity;
+use domain::repositories::audit_event_repository::{AuditEventFilter, AuditEventRepository};
+use domain::repositories::base_repository::BaseRepository;
+use shared::models::failure::Failure;
+use shared::models::paginate::Paginate;
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::secondary::repositories::models::audit_event_schema::{ScyllaAuditEventSchema, audit_event_day};
+use crate::secondary::repositories::scylla::scylla_base_repository::{EntitySchema, ScyllaBaseRepository};
+
+static SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+/// Events are partitioned by the day they were recorded on, a range query walks its days newest first.
+pub struct ScyllaAuditEventRepository {
+    base: ScyllaBaseRepository<AuditEventEntity, ScyllaAuditEventSchema>,
+}
+
+impl ScyllaAuditEventRepository {
+    pub fn new(session: Arc<Session>, keyspace: &str, table_name: &str) -> Self {
+        ScyllaAuditEventRepository { base: ScyllaBaseRepository::new(session, keyspace, table_name) }
+    }
+
+    /// Partitions covered by the range, newest first.
+    fn days(filter: &AuditEventFilter) -> Vec<String> {
+        let first = filter.from.div_euclid(SECONDS_PER_DAY);
+        let last = filter.to.div_euclid(SECONDS_PER_DAY);
+        (first..=last).rev().map(|day| audit_event_day(day * SECONDS_PER_DAY)).collect()
+    }
+
+    /// Only the day and time range are part of the key, the other criteria are checked on the rows read.
+    fn matches(filter: &AuditEventFilter, schema: &ScyllaAuditEventSchema) -> bool {
+        let matches = |criterion: &Option<String>, value: Option<&String>| {
+            criterion.as_ref().is_none_or(|criterion| value == Some(criterion))
+        };
+
+        matches(&filter.actor_id, schema.actor_id.as_ref())
+            && matches(&filter.action, Some(&schema.action))
+            && matches(&filter.target_type, Some(&schema.target_type))
+            && matches(&filter.target_id, schema.target_id.as_ref())
+    }
+
+    /// Walk the matching events newest first, `visit` returns `false` to stop the walk.
+    async fn walk<F>(&self, filter: &AuditEventFilter, mut visit: F) -> DomainResponse<()>
+    where
+        F: FnMut(ScyllaAuditEventSchema) -> bool + Send,
+    {
+        let query = format!(
+            "SELECT {} FROM {}.{} WHERE day = ? AND created_at >= ? AND created_at <= ?",
+            ScyllaAuditEventSchema::columns(),
+            self.base.keyspace,
+            self.base.table_name
+        );
+        let prepared = self.base.prepare_statement(&query).await?;
+
+        for day in Self::days(filter) {
+            let mut rows = self
+                .base
+                .session
+                .execute_iter(prepared.clone(), (day, filter.from, filter.to))
+                .await
+                .map_err(|e| Failure::DatabaseError(format!("Failed to find audit events: {}", e)))?
+                .rows_stream::<ScyllaAuditEventSchema>()
+                .map_err(|e| Failure::DatabaseError(format!("Failed to read audit events: {}", e)))?;
+
+            while let Some(schema) = rows
+                .try_next()
+                .await
+                .map_err(|e| Failure::DatabaseError(format!("Failed to deserialize audit events: {}", e)))?
+            {
+                if Self::matches(filter, &schema) && !visit(schema) {
+                    return Ok(());
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl AuditEventRepository for ScyllaAuditEventRepository {
+    async fn create(&self, event: &AuditEventEntity) -> DomainResponse<AuditEventEntity> {
+        self.base.create(event).await
+    }
+
+    async fn find_filtered_paginated(
+        &self,
+        filter: &AuditEventFilter,
+        page: u32,
+        page_size: u32,
+    ) -> DomainResponse<(Paginate, Vec<AuditEventEntity>)> {
+        // The total needs every match to be counted, only the requested page is kept
+        let skip = ((page - 1) * page_size) as usize;
+        let mut total_count = 0usize;
+        let mut events = vec![];
+        self.walk(filter, |schema| {
+            if total_count >= skip && events.len() < page_size as usize {
+                events.push(schema.to_entity());
+            }
+            total_count += 1;
+            true
+        })
+        .await?;
+
+        let paginate = Paginate {
+            page,
+            page_size,
+            total_page: ((total_count as f32) / (page_size as f32)).ceil() as u32,
+            total_record: total_count as u32,
+        };
+
+        Ok((paginate, events))
+    }
+
+    async fn find_filtered(&self, filter: &AuditEventFilter, limit: u32) -> DomainResponse<Vec<AuditEventEntity>> {
+        let mut events = vec![];
+        self.walk(filter, |schema| {
+            events.push(schema.to_entity());
+            events.len() < limit as usize
+        })
+        .await?;
+
+        Ok(events)
+    }
+}
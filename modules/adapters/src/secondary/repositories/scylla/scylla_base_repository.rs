@@ -1,22 +1,164 @@
 use async_trait::async_trait;
-use scylla::_macro_internal::SerializeRow;
+use futures::stream::TryStreamExt;
 use scylla::client::session::Session;
+use scylla::deserialize::row::DeserializeRow;
+use scylla::response::PagingState;
+use scylla::serialize::row::SerializeRow;
+use scylla::statement::batch::{Batch, BatchType};
 use scylla::statement::prepared::PreparedStatement;
+use scylla::value::{CqlValue, Row};
+use std::collections::HashMap;
 use std::marker::PhantomData;
+use std::ops::ControlFlow;
 use std::sync::Arc;
 use std::vec;
+use uuid::Uuid;
 
 // shared modules
 use domain::repositories::base_repository::BaseRepository;
 use shared::models::failure::Failure;
+use shared::models::filters::{
+    CqlKey, CqlKeyKind, QuerySpec, ScyllaFilter, ScyllaFilterConverter, ScyllaOperator, ScyllaValue,
+};
 use shared::models::paginate::Paginate;
 use shared::types::DomainResponse;
 
-pub trait EntitySchema<E>: Send + Sync + Clone + SerializeRow {
+// internal modules
+use crate::shared::utilities::databases::LOOKUP_BACKFILL_TABLE;
+
+// Rows fetched per round trip while walking a result through its paging state
+static SCAN_PAGE_SIZE: i32 = 500;
+
+// Ids fetched per query when resolving lookup rows, IN lists are kept short
+static LOOKUP_BATCH_SIZE: usize = 100;
+
+// Span of a time bucket of the lookup tables a single value would otherwise grow without bound, 30 days
+static LOOKUP_BUCKET_SECONDS: i64 = 30 * 24 * 60 * 60;
+
+/// Time bucket of a lookup row, for lookup tables partitioned by a value and the bucket of the row creation.
+pub fn lookup_bucket(created_at: i64) -> i32 {
+    created_at.div_euclid(LOOKUP_BUCKET_SECONDS) as i32
+}
+
+/// A row of a denormalised lookup table. Lookup tables are keyed by `key` followed by the id
+/// of the entity the row points at, which is stored in an `id` column. A unique lookup table is
+/// keyed by `key` alone and doubles as the claim of that key, see `claim`.
+#[derive(Clone, PartialEq)]
+pub struct Lookup {
+    pub table: &'static str,
+    pub key: Vec<(&'static str, CqlValue)>,
+    pub unique: bool,
+}
+
+impl Lookup {
+    pub fn new(table: &'static str, key: Vec<(&'static str, CqlValue)>) -> Self {
+        Lookup { table, key, unique: false }
+    }
+
+    pub fn unique(table: &'static str, key: Vec<(&'static str, CqlValue)>) -> Self {
+        Lookup { table, key, unique: true }
+    }
+
+    fn key_condition(&self) -> String {
+        self.key.iter().map(|(column, _)| format!("{} = ?", column)).collect::<Vec<_>>().join(" AND ")
+    }
+
+    fn key_values(&self) -> Vec<CqlValue> {
+        self.key.iter().map(|(_, value)| value.clone()).collect()
+    }
+
+    fn columns(&self) -> Vec<&'static str> {
+        self.key.iter().map(|(column, _)| *column).chain(["id"]).collect()
+    }
+
+    fn values(&self, id: Uuid) -> Vec<CqlValue> {
+        self.key.iter().map(|(_, value)| value.clone()).chain([CqlValue::Uuid(id)]).collect()
+    }
+}
+
+type BatchRow<'a> = Box<dyn SerializeRow + Send + Sync + 'a>;
+
+pub trait EntitySchema<E>:
+    Send + Sync + Clone + SerializeRow + for<'frame, 'metadata> DeserializeRow<'frame, 'metadata>
+{
     fn from_entity(entity: &E) -> Self;
     fn to_entity(&self) -> E;
     fn columns() -> &'static str;
     fn insert_placeholders() -> &'static str;
+    fn id(&self) -> Option<Uuid>;
+    fn set_id(&mut self, id: Uuid);
+
+    /// Soft delete marker, rows carrying one are hidden from every read.
+    fn deleted_at(&self) -> Option<i64>;
+
+    /// Creation time, what reads without a sort order by, newest first.
+    fn created_at(&self) -> i64;
+
+    /// Primary key of the table. Tables keyed by anything but `id` keep a secondary index on `id`,
+    /// rows are found through it and written back by their full key.
+    fn primary_key() -> &'static [&'static str] {
+        &["id"]
+    }
+
+    /// Columns a query can restrict without `ALLOW FILTERING`, filters on any other column are rejected.
+    fn keys() -> &'static [CqlKey] {
+        &[]
+    }
+
+    /// Lookup rows pointing at this row, written and removed in the same logged batch as the row.
+    /// A schema with lookup tables always has at least one row in them.
+    fn lookups(&self) -> Vec<Lookup> {
+        vec![]
+    }
+
+    /// Every table `lookups` writes into, filled from the existing rows when it is added.
+    fn lookup_tables() -> &'static [&'static str] {
+        &[]
+    }
+}
+
+fn to_cql_value(value: ScyllaValue) -> CqlValue {
+    match value {
+        ScyllaValue::String(v) => CqlValue::Text(v),
+        ScyllaValue::Int(v) => CqlValue::Int(v),
+        ScyllaValue::BigInt(v) => CqlValue::BigInt(v),
+        ScyllaValue::Boolean(v) => CqlValue::Boolean(v),
+        ScyllaValue::Uuid(v) => CqlValue::Uuid(v),
+        ScyllaValue::List(v) => CqlValue::List(v.into_iter().map(to_cql_value).collect()),
+    }
+}
+
+pub fn parse_uuid(field: &str, value: &str) -> DomainResponse<Uuid> {
+    Uuid::parse_str(value).map_err(|_| Failure::BadRequest(format!("Invalid {} format: {}", field, value)))
+}
+
+pub fn paginate(page: u32, page_size: u32, total_count: usize) -> Paginate {
+    Paginate {
+        page,
+        page_size,
+        total_page: ((total_count as f32) / (page_size as f32)).ceil() as u32,
+        total_record: total_count as u32,
+    }
+}
+
+/// Totals of a page read without counting the whole result, which would take a full scan. `seen` counts the rows
+/// read up to and including one row past the page, so the totals run up to the next page while more rows follow.
+pub fn paginate_seen(page: u32, page_size: u32, seen: usize) -> Paginate {
+    let has_more = seen > (page * page_size) as usize;
+    Paginate {
+        page,
+        page_size,
+        total_page: if has_more { page + 1 } else { ((seen as f32) / (page_size as f32)).ceil() as u32 },
+        total_record: seen as u32,
+    }
+}
+
+/// Pages items already in memory, for results ordered or filtered by something CQL can not express.
+pub fn paginate_entities<T>(entities: Vec<T>, page: u32, page_size: u32) -> (Paginate, Vec<T>) {
+    let paginate = paginate(page, page_size, entities.len());
+    let entities = entities.into_iter().skip(((page - 1) * page_size) as usize).take(page_size as usize).collect();
+
+    (paginate, entities)
 }
 
 pub struct ScyllaBaseRepository<E, S>
@@ -51,6 +193,661 @@ where
             .await
             .map_err(|e| Failure::DatabaseError(format!("Failed to prepare statement: {}", e)))
     }
+
+    /// Fully qualified name of the table
+    pub fn table(&self) -> String {
+        format!("{}.{}", self.keyspace, self.table_name)
+    }
+
+    /// Runs a statement that returns no rows.
+    pub async fn execute(&self, query: &str, values: impl SerializeRow) -> DomainResponse<()> {
+        let prepared = self.prepare_statement(query).await?;
+        self.session
+            .execute_unpaged(&prepared, values)
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to execute statement: {}", e)))?;
+
+        Ok(())
+    }
+
+    /// Runs the statements and their values as one logged batch, they are applied all or not at all.
+    async fn execute_batch(&self, statements: Vec<(String, BatchRow<'_>)>) -> DomainResponse<()> {
+        let mut batch = Batch::new(BatchType::Logged);
+        let mut values = Vec::with_capacity(statements.len());
+        for (query, value) in statements {
+            batch.append_statement(self.prepare_statement(&query).await?);
+            values.push(value);
+        }
+
+        self.session
+            .batch(&batch, values)
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to execute batch: {}", e)))?;
+
+        Ok(())
+    }
+
+    fn insert_lookup(&self, lookup: &Lookup, id: Uuid) -> (String, BatchRow<'static>) {
+        let columns = lookup.columns();
+        let query = format!(
+            "INSERT INTO {}.{} ({}) VALUES ({})",
+            self.keyspace,
+            lookup.table,
+            columns.join(", "),
+            vec!["?"; columns.len()].join(", ")
+        );
+
+        (query, Box::new(lookup.values(id)))
+    }
+
+    fn delete_lookup(&self, lookup: &Lookup, id: Uuid) -> (String, BatchRow<'static>) {
+        if lookup.unique {
+            let query = format!("DELETE FROM {}.{} WHERE {}", self.keyspace, lookup.table, lookup.key_condition());
+            return (query, Box::new(lookup.key_values()));
+        }
+
+        let condition = lookup.columns().iter().map(|column| format!("{} = ?", column)).collect::<Vec<_>>();
+        let query = format!("DELETE FROM {}.{} WHERE {}", self.keyspace, lookup.table, condition.join(" AND "));
+
+        (query, Box::new(lookup.values(id)))
+    }
+
+    /// Writes the row and its lookup rows, the lookup rows of `previous` it no longer has are removed.
+    async fn write(&self, schema: &S, previous: Option<&S>) -> DomainResponse<()> {
+        let query = format!("INSERT INTO {} ({}) VALUES ({})", self.table(), S::columns(), S::insert_placeholders());
+        let (Some(id), lookups) = (schema.id(), schema.lookups()) else {
+            return self.execute(&query, schema).await;
+        };
+        let stale: Vec<Lookup> = previous
+            .map(|previous| previous.lookups())
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|lookup| !lookups.contains(lookup))
+            .collect();
+        if lookups.is_empty() && stale.is_empty() {
+            return self.execute(&query, schema).await;
+        }
+
+        // A delete and an insert of the same lookup row in one batch share a timestamp and the delete
+        // would win, so only the rows that changed are deleted
+        let mut statements: Vec<(String, BatchRow)> = vec![(query, Box::new(schema))];
+        statements.extend(stale.iter().map(|lookup| self.delete_lookup(lookup, id)));
+        statements.extend(lookups.iter().map(|lookup| self.insert_lookup(lookup, id)));
+
+        self.execute_batch(statements).await
+    }
+
+    /// Writes the row over the one stored under the same id, moving its lookup rows along.
+    async fn overwrite(&self, schema: &S) -> DomainResponse<()> {
+        // The stored row may have lookup rows the new one has none of, it is read whenever the table has lookups
+        let previous = match schema.id() {
+            Some(id) if !S::lookup_tables().is_empty() => self.find_row(id).await?,
+            _ => None,
+        };
+
+        self.write(schema, previous.as_ref()).await
+    }
+
+    /// Runs a lightweight transaction, returns whether its condition held and it was applied.
+    pub async fn execute_conditional(&self, query: &str, values: impl SerializeRow) -> DomainResponse<bool> {
+        let prepared = self.prepare_statement(query).await?;
+        let applied = self
+            .session
+            .execute_unpaged(&prepared, values)
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to execute conditional statement: {}", e)))?
+            .into_rows_result()
+            .map_err(|e| Failure::DatabaseError(format!("Failed to read conditional statement: {}", e)))?
+            .first_row::<Row>()
+            .map_err(|e| Failure::DatabaseError(format!("Failed to read conditional statement: {}", e)))?;
+
+        Ok(matches!(applied.columns.first(), Some(Some(CqlValue::Boolean(true)))))
+    }
+
+    /// Updates the row only while `column` holds `expected`. A lightweight transaction moves `column` to `value`
+    /// first, so of concurrent callers only one applies, the others get `None`.
+    pub async fn update_if(
+        &self,
+        id: &str,
+        entity: &E,
+        column: &str,
+        expected: CqlValue,
+        value: CqlValue,
+    ) -> DomainResponse<Option<E>> {
+        let uuid = parse_uuid("ID", id)?;
+        let not_found = || Failure::NotFound(format!("Entity with id {} not found", id));
+        let previous = self.find_row(uuid).await?.ok_or_else(not_found)?;
+        let key = self.find_key(uuid).await?.ok_or_else(not_found)?;
+
+        let query =
+            format!("UPDATE {} SET {} = ? WHERE {} IF {} = ?", self.table(), column, Self::key_condition(), column);
+        let values: Vec<CqlValue> = [value].into_iter().chain(key).chain([expected]).collect();
+        if !self.execute_conditional(&query, values).await? {
+            return Ok(None);
+        }
+
+        // The row read before the transaction still names the lookup rows to move
+        let mut schema = S::from_entity(entity);
+        schema.set_id(uuid);
+        self.write(&schema, Some(&previous)).await?;
+
+        Ok(Some(schema.to_entity()))
+    }
+
+    /// Inserts the row unless one with the same primary key exists, returns whether it was written.
+    pub async fn insert_if_absent(&self, schema: &S) -> DomainResponse<bool> {
+        let query = format!(
+            "INSERT INTO {} ({}) VALUES ({}) IF NOT EXISTS",
+            self.table(),
+            S::columns(),
+            S::insert_placeholders()
+        );
+        if !self.execute_conditional(&query, schema).await? {
+            return Ok(false);
+        }
+
+        // Lookup rows can not join a conditional batch, they are written once the row is in place
+        if let Some(id) = schema.id() {
+            for lookup in schema.lookups() {
+                let (query, values) = self.insert_lookup(&lookup, id);
+                self.execute(&query, values).await?;
+            }
+        }
+
+        Ok(true)
+    }
+
+    /// Takes the unique key of a claim table for the row with `id`. Claim tables, unique lookup tables among them,
+    /// are keyed by `key` alone and hold the row in their `id` column, lightweight transactions keep two writers
+    /// from both holding a key.
+    /// A claim whose row is gone is taken over. Returns whether `id` holds the key.
+    pub async fn claim(&self, claim: &Lookup, id: Uuid) -> DomainResponse<bool> {
+        let columns = claim.columns();
+        let query = format!(
+            "INSERT INTO {}.{} ({}) VALUES ({}) IF NOT EXISTS",
+            self.keyspace,
+            claim.table,
+            columns.join(", "),
+            vec!["?"; columns.len()].join(", ")
+        );
+        if self.execute_conditional(&query, claim.values(id)).await? {
+            return Ok(true);
+        }
+
+        let condition = claim.key_condition();
+        let key = claim.key_values();
+        let query = format!("SELECT id FROM {}.{} WHERE {}", self.keyspace, claim.table, condition);
+        let prepared = self.prepare_statement(&query).await?;
+        let holder = self
+            .session
+            .execute_unpaged(&prepared, &key)
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to find claim: {}", e)))?
+            .into_rows_result()
+            .map_err(|e| Failure::DatabaseError(format!("Failed to read claim: {}", e)))?
+            .maybe_first_row::<(Uuid,)>()
+            .map_err(|e| Failure::DatabaseError(format!("Failed to deserialize claim: {}", e)))?;
+        let Some((holder,)) = holder else {
+            return Ok(false);
+        };
+        if self.find_row(holder).await?.is_some() {
+            return Ok(false);
+        }
+
+        // The condition on the previous holder keeps two writers taking over the same claim from both succeeding
+        let query = format!("UPDATE {}.{} SET id = ? WHERE {} IF id = ?", self.keyspace, claim.table, condition);
+        let mut values = vec![CqlValue::Uuid(id)];
+        values.extend(key);
+        values.push(CqlValue::Uuid(holder));
+        self.execute_conditional(&query, values).await
+    }
+
+    /// Gives up a claim held by `id`, one another row took over since is left alone.
+    pub async fn release(&self, claim: &Lookup, id: Uuid) -> DomainResponse<()> {
+        let query = format!("DELETE FROM {}.{} WHERE {} IF id = ?", self.keyspace, claim.table, claim.key_condition());
+        let mut values = claim.key_values();
+        values.push(CqlValue::Uuid(id));
+
+        self.execute_conditional(&query, values).await.map(|_| ())
+    }
+
+    /// Writes the whole row of an entity that already has an id.
+    pub async fn save(&self, entity: &E) -> DomainResponse<E> {
+        let schema = S::from_entity(entity);
+        self.overwrite(&schema).await?;
+
+        Ok(schema.to_entity())
+    }
+
+    /// Hard deletes every row matching `condition`, soft deleted ones included.
+    pub async fn remove_where(
+        &self,
+        condition: &str,
+        values: impl SerializeRow + Send + Sync,
+    ) -> DomainResponse<usize> {
+        let query = format!("SELECT {} FROM {} {}", S::columns(), self.table(), condition);
+
+        let mut rows = vec![];
+        self.scan(&query, values, true, |schema| rows.push(schema)).await?;
+
+        self.remove_rows(rows).await
+    }
+
+    /// Hard deletes every row a lookup table points at under `condition`, soft deleted ones included.
+    pub async fn remove_by_lookup(
+        &self,
+        table: &str,
+        condition: &str,
+        values: impl SerializeRow + Send + Sync,
+    ) -> DomainResponse<usize> {
+        let rows = self.lookup_rows(table, condition, values, true).await?;
+        self.remove_rows(rows).await
+    }
+
+    /// Like `remove_where`, but returns the entities it removed.
+    pub async fn find_and_remove_where(
+        &self,
+        condition: &str,
+        values: impl SerializeRow + Send + Sync,
+    ) -> DomainResponse<Vec<E>> {
+        let query = format!("SELECT {} FROM {} {}", S::columns(), self.table(), condition);
+
+        let mut rows = vec![];
+        self.scan(&query, values, true, |schema| rows.push(schema)).await?;
+
+        let entities = rows.iter().map(|schema| schema.to_entity()).collect();
+        self.remove_rows(rows).await?;
+        Ok(entities)
+    }
+
+    /// Like `remove_by_lookup`, but returns the entities it removed.
+    pub async fn find_and_remove_by_lookup(
+        &self,
+        table: &str,
+        condition: &str,
+        values: impl SerializeRow + Send + Sync,
+    ) -> DomainResponse<Vec<E>> {
+        let rows = self.lookup_rows(table, condition, values, true).await?;
+
+        let entities = rows.iter().map(|schema| schema.to_entity()).collect();
+        self.remove_rows(rows).await?;
+        Ok(entities)
+    }
+
+    async fn remove_rows(&self, rows: Vec<S>) -> DomainResponse<usize> {
+        let removed_count = rows.len();
+        for schema in rows {
+            self.hard_delete(&schema).await?;
+        }
+
+        Ok(removed_count)
+    }
+
+    /// Walks every row of a select a page at a time through the driver paging state,
+    /// so large results never have to be held in memory at once.
+    async fn scan<V, F>(&self, query: &str, values: V, include_deleted: bool, mut visit: F) -> DomainResponse<()>
+    where
+        V: SerializeRow + Send + Sync,
+        F: FnMut(S) + Send,
+    {
+        self.scan_until(query, values, include_deleted, SCAN_PAGE_SIZE, |schema| {
+            visit(schema);
+            ControlFlow::Continue(())
+        })
+        .await
+    }
+
+    /// Like `scan`, but stops fetching pages as soon as `visit` breaks.
+    async fn scan_until<V, F>(
+        &self,
+        query: &str,
+        values: V,
+        include_deleted: bool,
+        page_size: i32,
+        mut visit: F,
+    ) -> DomainResponse<()>
+    where
+        V: SerializeRow + Send + Sync,
+        F: FnMut(S) -> ControlFlow<()> + Send,
+    {
+        let mut prepared = self.prepare_statement(query).await?;
+        prepared.set_page_size(page_size);
+
+        let mut paging_state = PagingState::start();
+        loop {
+            let (result, paging_state_response) = self
+                .session
+                .execute_single_page(&prepared, &values, paging_state)
+                .await
+                .map_err(|e| Failure::DatabaseError(format!("Failed to find entities: {}", e)))?;
+
+            let rows = result
+                .into_rows_result()
+                .map_err(|e| Failure::DatabaseError(format!("Failed to read entities: {}", e)))?;
+            for row in
+                rows.rows::<S>().map_err(|e| Failure::DatabaseError(format!("Failed to read entities: {}", e)))?
+            {
+                let schema = row.map_err(|e| Failure::DatabaseError(format!("Failed to deserialize entity: {}", e)))?;
+                if (include_deleted || schema.deleted_at().is_none()) && visit(schema).is_break() {
+                    return Ok(());
+                }
+            }
+
+            match paging_state_response.into_paging_control_flow() {
+                ControlFlow::Break(()) => return Ok(()),
+                ControlFlow::Continue(next_paging_state) => paging_state = next_paging_state,
+            }
+        }
+    }
+
+    /// Live entities matching `condition`, the part of the select that follows the table name.
+    pub async fn select(&self, condition: &str, values: impl SerializeRow + Send + Sync) -> DomainResponse<Vec<E>> {
+        let query = format!("SELECT {} FROM {} {}", S::columns(), self.table(), condition);
+
+        let mut entities = vec![];
+        self.scan(&query, values, false, |schema| entities.push(schema.to_entity())).await?;
+
+        Ok(entities)
+    }
+
+    pub async fn select_one(
+        &self,
+        condition: &str,
+        values: impl SerializeRow + Send + Sync,
+    ) -> DomainResponse<Option<E>> {
+        Ok(self.select(condition, values).await?.into_iter().next())
+    }
+
+    /// A page of the live entities matching `condition`. CQL has no offset, so the rows before
+    /// the page are counted while the scan moves past them.
+    pub async fn select_paginated(
+        &self,
+        condition: &str,
+        values: impl SerializeRow + Send + Sync,
+        page: u32,
+        page_size: u32,
+    ) -> DomainResponse<(Paginate, Vec<E>)> {
+        let query = format!("SELECT {} FROM {} {}", S::columns(), self.table(), condition);
+
+        // Rows are walked up to the requested page and one row past it, never through the rest of the table
+        let skip = ((page - 1) * page_size) as usize;
+        let mut seen = 0usize;
+        let mut entities = vec![];
+        let fetch_size = (page_size as i32 + 1).min(SCAN_PAGE_SIZE);
+        self.scan_until(&query, values, false, fetch_size, |schema| {
+            seen += 1;
+            if seen > skip + page_size as usize {
+                return ControlFlow::Break(());
+            }
+            if seen > skip {
+                entities.push(schema.to_entity());
+            }
+            ControlFlow::Continue(())
+        })
+        .await?;
+
+        Ok((paginate_seen(page, page_size, seen), entities))
+    }
+
+    /// Live entities a lookup table points at under `condition`, in the order of the lookup table.
+    pub async fn select_by_lookup(
+        &self,
+        table: &str,
+        condition: &str,
+        values: impl SerializeRow + Send + Sync,
+    ) -> DomainResponse<Vec<E>> {
+        let rows = self.lookup_rows(table, condition, values, false).await?;
+        Ok(rows.into_iter().map(|schema| schema.to_entity()).collect())
+    }
+
+    /// Like `select_by_lookup` on a table partitioned by `column` and a `bucket`, the buckets holding `value` are
+    /// read one partition at a time, newest first.
+    pub async fn select_by_bucketed_lookup(&self, table: &str, column: &str, value: &str) -> DomainResponse<Vec<E>> {
+        // Only partition keys are read, one row per partition
+        let query = format!("SELECT DISTINCT {}, bucket FROM {}.{}", column, self.keyspace, table);
+        let prepared = self.prepare_statement(&query).await?;
+        let mut buckets: Vec<i32> = self
+            .session
+            .execute_iter(prepared, ())
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to find lookup buckets: {}", e)))?
+            .rows_stream::<(String, i32)>()
+            .map_err(|e| Failure::DatabaseError(format!("Failed to read lookup buckets: {}", e)))?
+            .try_filter_map(|(partition, bucket)| async move { Ok((partition == value).then_some(bucket)) })
+            .try_collect()
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to deserialize lookup buckets: {}", e)))?;
+        buckets.sort_unstable_by(|a, b| b.cmp(a));
+
+        let condition = format!("WHERE {} = ? AND bucket = ?", column);
+        let mut entities = vec![];
+        for bucket in buckets {
+            entities.extend(self.select_by_lookup(table, &condition, (value, bucket)).await?);
+        }
+
+        Ok(entities)
+    }
+
+    async fn lookup_rows(
+        &self,
+        table: &str,
+        condition: &str,
+        values: impl SerializeRow + Send + Sync,
+        include_deleted: bool,
+    ) -> DomainResponse<Vec<S>> {
+        let query = format!("SELECT * FROM {}.{} {}", self.keyspace, table, condition);
+        let prepared = self.prepare_statement(&query).await?;
+        let mut stream = self
+            .session
+            .execute_iter(prepared, values)
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to find lookup rows: {}", e)))?
+            .rows_stream::<Row>()
+            .map_err(|e| Failure::DatabaseError(format!("Failed to read lookup rows: {}", e)))?;
+
+        // Each lookup row is kept with its key, so a row it no longer describes is told apart below
+        let mut ids = vec![];
+        let mut pointers: HashMap<Uuid, Vec<Vec<(String, CqlValue)>>> = HashMap::new();
+        while let Some(row) = stream
+            .try_next()
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to deserialize lookup rows: {}", e)))?
+        {
+            let names: Vec<String> = stream.column_specs().iter().map(|spec| spec.name().to_string()).collect();
+            let mut id = None;
+            let mut key = vec![];
+            for (name, value) in names.into_iter().zip(row.columns) {
+                match (name.as_str(), value) {
+                    ("id", Some(CqlValue::Uuid(value))) => id = Some(value),
+                    (_, Some(value)) => key.push((name, value)),
+                    _ => {},
+                }
+            }
+            if let Some(id) = id {
+                ids.push(id);
+                pointers.entry(id).or_default().push(key);
+            }
+        }
+
+        let query = format!("SELECT {} FROM {} WHERE id IN ?", S::columns(), self.table());
+        let mut rows = HashMap::new();
+        for chunk in ids.chunks(LOOKUP_BATCH_SIZE) {
+            self.scan(&query, (chunk,), include_deleted, |schema| {
+                if let Some(id) = schema.id() {
+                    rows.insert(id, schema);
+                }
+            })
+            .await?;
+        }
+
+        // A lookup row left behind by a write that raced another is ignored, the row has to still carry its key
+        let current = |schema: &S, id: &Uuid| {
+            let lookups = schema.lookups();
+            pointers[id].iter().any(|key| {
+                lookups.iter().filter(|lookup| lookup.table == table).any(|lookup| {
+                    lookup
+                        .key
+                        .iter()
+                        .all(|(column, value)| key.iter().any(|(name, kept)| name == column && kept == value))
+                })
+            })
+        };
+
+        Ok(ids.iter().filter_map(|id| rows.remove(id).filter(|schema| current(schema, id))).collect())
+    }
+
+    /// Fills the lookup tables that have not been backfilled yet from every row of the table, soft deleted ones
+    /// included as their lookup rows are kept until the row is removed. Rows written meanwhile already carry their
+    /// lookup rows and writing one twice is harmless, so a backfill interrupted half way is simply run again.
+    pub async fn backfill_lookups(&self) -> DomainResponse<()> {
+        let query = format!("SELECT lookup_table FROM {}.{}", self.keyspace, LOOKUP_BACKFILL_TABLE);
+        let prepared = self.prepare_statement(&query).await?;
+        let completed: Vec<String> = self
+            .session
+            .execute_iter(prepared, ())
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to find lookup backfills: {}", e)))?
+            .rows_stream::<(String,)>()
+            .map_err(|e| Failure::DatabaseError(format!("Failed to read lookup backfills: {}", e)))?
+            .map_ok(|(table,)| table)
+            .try_collect()
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to deserialize lookup backfills: {}", e)))?;
+        let pending: Vec<&str> =
+            S::lookup_tables().iter().copied().filter(|table| !completed.iter().any(|done| done == table)).collect();
+        if pending.is_empty() {
+            return Ok(());
+        }
+
+        let query = format!("SELECT {} FROM {}", S::columns(), self.table());
+        let prepared = self.prepare_statement(&query).await?;
+        let mut rows = self
+            .session
+            .execute_iter(prepared, ())
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to find entities: {}", e)))?
+            .rows_stream::<S>()
+            .map_err(|e| Failure::DatabaseError(format!("Failed to read entities: {}", e)))?;
+
+        let mut statements: HashMap<String, PreparedStatement> = HashMap::new();
+        while let Some(schema) =
+            rows.try_next().await.map_err(|e| Failure::DatabaseError(format!("Failed to deserialize entity: {}", e)))?
+        {
+            let Some(id) = schema.id() else {
+                continue;
+            };
+            for lookup in schema.lookups().iter().filter(|lookup| pending.contains(&lookup.table)) {
+                let (query, values) = self.insert_lookup(lookup, id);
+                if !statements.contains_key(&query) {
+                    let prepared = self.prepare_statement(&query).await?;
+                    statements.insert(query.clone(), prepared);
+                }
+                self.session
+                    .execute_unpaged(&statements[&query], values)
+                    .await
+                    .map_err(|e| Failure::DatabaseError(format!("Failed to backfill lookup row: {}", e)))?;
+            }
+        }
+
+        let query = format!(
+            "INSERT INTO {}.{} (lookup_table, completed_at) VALUES (?, ?)",
+            self.keyspace, LOOKUP_BACKFILL_TABLE
+        );
+        let completed_at = chrono::Utc::now().timestamp();
+        for table in pending {
+            self.execute(&query, (table, completed_at)).await?;
+        }
+
+        Ok(())
+    }
+
+    /// The row with `id` whether soft deleted or not
+    async fn find_row(&self, id: Uuid) -> DomainResponse<Option<S>> {
+        let query = format!("SELECT {} FROM {} WHERE id = ?", S::columns(), self.table());
+
+        let mut rows = vec![];
+        self.scan(&query, (id,), true, |schema| rows.push(schema)).await?;
+
+        Ok(rows.into_iter().next())
+    }
+
+    /// Values of the full primary key of the row with `id`, in `primary_key` order.
+    async fn find_key(&self, id: Uuid) -> DomainResponse<Option<Vec<CqlValue>>> {
+        if S::primary_key() == ["id"] {
+            return Ok(self.find_row(id).await?.map(|_| vec![CqlValue::Uuid(id)]));
+        }
+
+        let query = format!("SELECT {} FROM {} WHERE id = ?", S::primary_key().join(", "), self.table());
+        let prepared = self.prepare_statement(&query).await?;
+        let row = self
+            .session
+            .execute_unpaged(&prepared, (id,))
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to find entity by ID: {}", e)))?
+            .into_rows_result()
+            .map_err(|e| Failure::DatabaseError(format!("Failed to read entity: {}", e)))?
+            .maybe_first_row::<Row>()
+            .map_err(|e| Failure::DatabaseError(format!("Failed to deserialize entity: {}", e)))?;
+
+        Ok(row.map(Self::key_values))
+    }
+
+    fn key_values(row: Row) -> Vec<CqlValue> {
+        row.columns.into_iter().map(|column| column.unwrap_or(CqlValue::Empty)).collect()
+    }
+
+    /// Whether the filter reads a single partition whose rows cluster by `created_at`.
+    fn clustered_by_creation(filter: &ScyllaFilter) -> bool {
+        let keys = S::keys();
+        let partition: Vec<&CqlKey> = keys.iter().filter(|key| key.kind == CqlKeyKind::Partition).collect();
+        let single_partition = !partition.is_empty()
+            && partition.iter().all(|key| {
+                filter
+                    .conditions
+                    .iter()
+                    .any(|condition| condition.column == key.column && condition.operator == ScyllaOperator::Eq)
+            });
+        let first_clustering = keys.iter().find(|key| key.kind == CqlKeyKind::Clustering);
+
+        single_partition && first_clustering.is_some_and(|key| key.column == "created_at")
+    }
+
+    fn key_condition() -> String {
+        S::primary_key().iter().map(|column| format!("{} = ?", column)).collect::<Vec<_>>().join(" AND ")
+    }
+
+    async fn soft_delete(&self, key: Vec<CqlValue>) -> DomainResponse<()> {
+        let query = format!("UPDATE {} SET deleted_at = ? WHERE {}", self.table(), Self::key_condition());
+
+        let mut values = vec![CqlValue::BigInt(chrono::Utc::now().timestamp())];
+        values.extend(key);
+        self.execute(&query, values).await
+    }
+
+    /// Deletes the row together with its lookup rows.
+    async fn hard_delete(&self, schema: &S) -> DomainResponse<()> {
+        let Some(id) = schema.id() else {
+            return Ok(());
+        };
+        let key = match S::primary_key() {
+            ["id"] => vec![CqlValue::Uuid(id)],
+            _ => match self.find_key(id).await? {
+                Some(key) => key,
+                None => return Ok(()),
+            },
+        };
+
+        let query = format!("DELETE FROM {} WHERE {}", self.table(), Self::key_condition());
+        let lookups = schema.lookups();
+        if lookups.is_empty() {
+            return self.execute(&query, key).await;
+        }
+
+        let mut statements: Vec<(String, BatchRow)> = vec![(query, Box::new(key))];
+        statements.extend(lookups.iter().map(|lookup| self.delete_lookup(lookup, id)));
+
+        self.execute_batch(statements).await
+    }
 }
 
 #[macro_export]
@@ -97,6 +894,15 @@ macro_rules! impl_scylla_base_repository {
             ) -> shared::types::DomainResponse<(shared::models::paginate::Paginate, Vec<$entity>)> {
                 self.base.finds_paginated(page, page_size).await
             }
+
+            async fn find_by(
+                &self,
+                spec: &shared::models::filters::QuerySpec,
+                page: u32,
+                page_size: u32,
+            ) -> shared::types::DomainResponse<(shared::models::paginate::Paginate, Vec<$entity>)> {
+                self.base.find_by(spec, page, page_size).await
+            }
         }
     };
 }
@@ -108,75 +914,99 @@ where
     S: EntitySchema<E>,
 {
     async fn create(&self, entity: &E) -> DomainResponse<E> {
-        let schema = S::from_entity(entity);
-        let insert_columns = S::columns();
-        let insert_placeholders = S::insert_placeholders();
+        let mut schema = S::from_entity(entity);
+        if schema.id().is_none() {
+            schema.set_id(Uuid::now_v7());
+            self.write(&schema, None).await?;
+        } else {
+            self.overwrite(&schema).await?;
+        }
 
-        let query = format!(
-            "INSERT INTO {}.{} ({}) VALUES ({})",
-            self.keyspace, self.table_name, insert_columns, insert_placeholders
-        );
+        Ok(schema.to_entity())
+    }
 
-        println!("Insert query: {}", query);
-        let prepared = self.prepare_statement(&query).await?;
+    async fn update(&self, id: &str, entity: &E) -> DomainResponse<E> {
+        let uuid = parse_uuid("ID", id)?;
+        let previous =
+            self.find_row(uuid).await?.ok_or_else(|| Failure::NotFound(format!("Entity with id {} not found", id)))?;
 
-        let result = self
-            .session
-            .execute_unpaged(&prepared, &schema)
-            .await
-            .map_err(|e| Failure::DatabaseError(format!("Failed to create entity: {}", e)))?;
-
-        println!("Insert result: {:?}", result);
+        // Inserts are upserts in CQL, writing the whole row replaces it
+        let mut schema = S::from_entity(entity);
+        schema.set_id(uuid);
+        self.write(&schema, Some(&previous)).await?;
 
-        // Return the entity with the schema converted back
         Ok(schema.to_entity())
     }
 
-    async fn update(&self, _id: &str, _entity: &E) -> DomainResponse<E> {
-        todo!()
+    async fn delete(&self, id: &str) -> DomainResponse<usize> {
+        match self.find_key(parse_uuid("ID", id)?).await? {
+            Some(key) => self.soft_delete(key).await.map(|_| 1),
+            None => Ok(0),
+        }
     }
 
-    async fn delete(&self, _id: &str) -> DomainResponse<usize> {
-        todo!()
+    async fn remove(&self, id: &str) -> DomainResponse<usize> {
+        match self.find_row(parse_uuid("ID", id)?).await? {
+            Some(schema) => self.hard_delete(&schema).await.map(|_| 1),
+            None => Ok(0),
+        }
     }
 
-    async fn remove(&self, _id: &str) -> DomainResponse<usize> {
-        todo!()
+    async fn find(&self, id: &str) -> DomainResponse<Option<E>> {
+        let row = self.find_row(parse_uuid("ID", id)?).await?;
+        Ok(row.filter(|schema| schema.deleted_at().is_none()).map(|schema| schema.to_entity()))
     }
 
-    async fn find(&self, _id: &str) -> DomainResponse<Option<E>> {
-        todo!()
-    }
+    async fn find_and_delete(&self, id: &str) -> DomainResponse<E> {
+        let uuid = parse_uuid("ID", id)?;
+        let schema = self
+            .find_row(uuid)
+            .await?
+            .filter(|schema| schema.deleted_at().is_none())
+            .ok_or_else(|| Failure::NotFound(format!("Entity with id {} not found", id)))?;
+
+        if let Some(key) = self.find_key(uuid).await? {
+            self.soft_delete(key).await?;
+        }
 
-    async fn find_and_delete(&self, _id: &str) -> DomainResponse<E> {
-        todo!()
+        Ok(schema.to_entity())
     }
 
-    async fn find_and_remove(&self, _id: &str) -> DomainResponse<E> {
-        todo!()
+    async fn find_and_remove(&self, id: &str) -> DomainResponse<E> {
+        let uuid = parse_uuid("ID", id)?;
+        let schema =
+            self.find_row(uuid).await?.ok_or_else(|| Failure::NotFound(format!("Entity with id {} not found", id)))?;
+
+        self.hard_delete(&schema).await?;
+
+        Ok(schema.to_entity())
     }
 
     async fn finds(&self) -> DomainResponse<Vec<E>> {
-        let query = format!(
-            "SELECT {} FROM {}.{} WHERE deleted_at = null ALLOW FILTERING",
-            S::columns(),
-            self.keyspace,
-            self.table_name,
-        );
-
-        let prepared = self.prepare_statement(&query).await?;
-        let entities = self
-            .session
-            .execute_unpaged(&prepared, ())
-            .await
-            .map_err(|e| Failure::DatabaseError(format!("Failed to find entities: {}", e)))?
-            .into_rows_result();
+        self.select("", ()).await
+    }
 
-        println!("Select result: {:?}", entities);
-        Ok(vec![])
+    async fn finds_paginated(&self, page: u32, page_size: u32) -> DomainResponse<(Paginate, Vec<E>)> {
+        self.select_paginated("", (), page, page_size).await
     }
 
-    async fn finds_paginated(&self, _page: u32, _page_size: u32) -> DomainResponse<(Paginate, Vec<E>)> {
-        todo!()
+    async fn find_by(&self, spec: &QuerySpec, page: u32, page_size: u32) -> DomainResponse<(Paginate, Vec<E>)> {
+        let filter = ScyllaFilterConverter::convert_to_scylla_filter(spec, S::keys())?;
+        let condition = format!("{}{}", filter.where_clause(), filter.order_clause());
+        let values: Vec<CqlValue> = filter.values().into_iter().map(to_cql_value).collect();
+
+        // Without a sort the newest come first. Only a single partition clustered by creation time, which tables
+        // keep newest first, is already in that order, any other match is read whole and ordered here
+        if spec.sort.is_empty() && !Self::clustered_by_creation(&filter) {
+            let query = format!("SELECT {} FROM {} {}", S::columns(), self.table(), condition.trim_start());
+            let mut rows = vec![];
+            self.scan(&query, values, false, |schema| rows.push(schema)).await?;
+            rows.sort_by_key(|schema| std::cmp::Reverse(schema.created_at()));
+
+            let entities = rows.iter().map(|schema| schema.to_entity()).collect();
+            return Ok(paginate_entities(entities, page, page_size));
+        }
+
+        self.select_paginated(condition.trim_start(), values, page, page_size).await
     }
 }
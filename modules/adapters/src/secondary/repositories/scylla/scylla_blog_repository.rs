@@ -1,17 +1,32 @@
 use async_trait::async_trait;
 use scylla::client::session::Session;
+use scylla::value::{Counter, CqlValue};
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
+use uuid::Uuid;
 
 // shared modules
 use domain::entities::blog_entity::BlogEntity;
+use domain::repositories::base_repository::BaseRepository;
 use domain::repositories::blog_repository::BlogRepository;
+use domain::values::blog_status::BlogStatus;
+use shared::models::failure::Failure;
+use shared::models::filters::{Filterable, MemoryFilter, QuerySpec};
+use shared::models::paginate::Paginate;
+use shared::types::DomainResponse;
 
 // internal modules
-use crate::impl_scylla_base_repository;
 use crate::secondary::repositories::models::blog_schema::ScyllaBlogSchema;
-use crate::secondary::repositories::scylla::scylla_base_repository::ScyllaBaseRepository;
+use crate::secondary::repositories::scylla::scylla_base_repository::{
+    ScyllaBaseRepository, paginate_entities, parse_uuid,
+};
+use crate::shared::utilities::databases::{
+    BLOG_BY_AUTHOR_TABLE, BLOG_BY_CATEGORY_TABLE, BLOG_BY_STATUS_TABLE, BLOG_COUNTER_TABLE,
+};
+
+// Blog ids looked up per counter query, IN lists are kept short
+static COUNTER_BATCH_SIZE: usize = 100;
 
-#[allow(dead_code)]
 pub struct ScyllaBlogRepository {
     base: ScyllaBaseRepository<BlogEntity, ScyllaBlogSchema>,
 }
@@ -20,9 +35,314 @@ impl ScyllaBlogRepository {
     pub fn new(session: Arc<Session>, keyspace: &str, table_name: &str) -> Self {
         ScyllaBlogRepository { base: ScyllaBaseRepository::new(session, keyspace, table_name) }
     }
+
+    /// Stars and views are counted in the counter table, its values replace the ones the blog row was written with.
+    async fn with_counters(&self, mut blogs: Vec<BlogEntity>) -> DomainResponse<Vec<BlogEntity>> {
+        let blog_ids: Vec<Uuid> =
+            blogs.iter().filter_map(|blog| blog.base.id.as_ref().and_then(|id| Uuid::parse_str(id).ok())).collect();
+
+        let query = format!(
+            "SELECT blog_id, stars, views FROM {}.{} WHERE blog_id IN ?",
+            self.base.keyspace, BLOG_COUNTER_TABLE
+        );
+        let prepared = self.base.prepare_statement(&query).await?;
+
+        let mut counters = HashMap::new();
+        for chunk in blog_ids.chunks(COUNTER_BATCH_SIZE) {
+            let rows = self
+                .base
+                .session
+                .execute_unpaged(&prepared, (chunk,))
+                .await
+                .map_err(|e| Failure::DatabaseError(format!("Failed to find blog counters: {}", e)))?
+                .into_rows_result()
+                .map_err(|e| Failure::DatabaseError(format!("Failed to read blog counters: {}", e)))?;
+
+            for row in rows
+                .rows::<(Uuid, Option<Counter>, Option<Counter>)>()
+                .map_err(|e| Failure::DatabaseError(format!("Failed to read blog counters: {}", e)))?
+            {
+                let (blog_id, stars, views) =
+                    row.map_err(|e| Failure::DatabaseError(format!("Failed to deserialize blog counters: {}", e)))?;
+                counters.insert(blog_id.to_string(), (stars.map_or(0, |c| c.0), views.map_or(0, |c| c.0)));
+            }
+        }
+
+        for blog in blogs.iter_mut() {
+            if let Some((stars, views)) = blog.base.id.as_ref().and_then(|id| counters.get(id)) {
+                blog.stars = *stars as i32;
+                blog.views = *views as i32;
+            }
+        }
+
+        Ok(blogs)
+    }
+
+    async fn find_published(&self, author_id: Option<&str>) -> DomainResponse<Vec<BlogEntity>> {
+        let blogs = match author_id {
+            Some(author_id) => self
+                .select_by_author(author_id)
+                .await?
+                .into_iter()
+                .filter(|blog| blog.status == BlogStatus::Published.as_str())
+                .collect(),
+            None => self.select_by_status(BlogStatus::Published).await?,
+        };
+
+        self.with_counters(blogs).await
+    }
+
+    /// Blogs of an author newest first, read through the author lookup table.
+    async fn select_by_author(&self, author_id: &str) -> DomainResponse<Vec<BlogEntity>> {
+        let author_id = parse_uuid("author_id", author_id)?;
+        self.base.select_by_lookup(BLOG_BY_AUTHOR_TABLE, "WHERE author_id = ?", (author_id,)).await
+    }
+
+    /// Blogs with a status newest first, read through the status lookup table a time bucket at a time.
+    async fn select_by_status(&self, status: BlogStatus) -> DomainResponse<Vec<BlogEntity>> {
+        self.base.select_by_bucketed_lookup(BLOG_BY_STATUS_TABLE, "status", status.as_str()).await
+    }
+
+    /// Blogs filed under any of the categories, each once, read through the category lookup table.
+    async fn select_by_categories(&self, category_ids: &[String]) -> DomainResponse<Vec<BlogEntity>> {
+        let mut seen = HashSet::new();
+        let mut blogs = vec![];
+        for category_id in category_ids {
+            let in_category =
+                self.base.select_by_lookup(BLOG_BY_CATEGORY_TABLE, "WHERE category_id = ?", (category_id,)).await?;
+            blogs.extend(in_category.into_iter().filter(|blog| seen.insert(blog.base.id.clone())));
+        }
+
+        Ok(blogs)
+    }
+
+    fn sort_latest_first(blogs: &mut [BlogEntity]) {
+        blogs.sort_by_key(|blog| std::cmp::Reverse((blog.published_at, blog.base.created_at)));
+    }
 }
 
-impl_scylla_base_repository!(ScyllaBlogRepository, BlogEntity, ScyllaBlogSchema);
+// Written out rather than generated, every read goes through the counter table
+#[async_trait]
+impl BaseRepository<BlogEntity> for ScyllaBlogRepository {
+    async fn create(&self, entity: &BlogEntity) -> DomainResponse<BlogEntity> {
+        self.base.create(entity).await
+    }
+
+    async fn update(&self, id: &str, entity: &BlogEntity) -> DomainResponse<BlogEntity> {
+        self.base.update(id, entity).await
+    }
+
+    async fn delete(&self, id: &str) -> DomainResponse<usize> {
+        self.base.delete(id).await
+    }
+
+    async fn remove(&self, id: &str) -> DomainResponse<usize> {
+        self.base.remove(id).await
+    }
+
+    async fn find(&self, id: &str) -> DomainResponse<Option<BlogEntity>> {
+        match self.base.find(id).await? {
+            Some(blog) => Ok(self.with_counters(vec![blog]).await?.into_iter().next()),
+            None => Ok(None),
+        }
+    }
+
+    async fn find_and_delete(&self, id: &str) -> DomainResponse<BlogEntity> {
+        self.base.find_and_delete(id).await
+    }
+
+    async fn find_and_remove(&self, id: &str) -> DomainResponse<BlogEntity> {
+        self.base.find_and_remove(id).await
+    }
+
+    async fn finds(&self) -> DomainResponse<Vec<BlogEntity>> {
+        let blogs = self.base.finds().await?;
+        self.with_counters(blogs).await
+    }
+
+    async fn finds_paginated(&self, page: u32, page_size: u32) -> DomainResponse<(Paginate, Vec<BlogEntity>)> {
+        let (paginate, blogs) = self.base.finds_paginated(page, page_size).await?;
+        Ok((paginate, self.with_counters(blogs).await?))
+    }
+
+    async fn find_by(
+        &self,
+        spec: &QuerySpec,
+        page: u32,
+        page_size: u32,
+    ) -> DomainResponse<(Paginate, Vec<BlogEntity>)> {
+        let (paginate, blogs) = self.base.find_by(spec, page, page_size).await?;
+        Ok((paginate, self.with_counters(blogs).await?))
+    }
+}
 
 #[async_trait]
-impl BlogRepository for ScyllaBlogRepository {}
+impl BlogRepository for ScyllaBlogRepository {
+    async fn find_published_paginated(
+        &self,
+        author_id: Option<&str>,
+        spec: &QuerySpec,
+        page: u32,
+        page_size: u32,
+    ) -> DomainResponse<(Paginate, Vec<BlogEntity>)> {
+        let mut blogs = self.find_published(author_id).await?;
+        Self::sort_latest_first(&mut blogs);
+
+        // The spec is run over the published blogs, none of its fields are keys of the table
+        let fields = BlogEntity::filter_fields();
+        let mut blogs: Vec<(serde_json::Value, BlogEntity)> = blogs
+            .into_iter()
+            .map(|blog| (serde_json::to_value(&blog).unwrap_or_default(), blog))
+            .filter(|(value, _)| MemoryFilter::matches(spec, fields, value))
+            .collect();
+        blogs.sort_by(|(a, _), (b, _)| MemoryFilter::compare(spec, fields, a, b));
+
+        let blogs = blogs.into_iter().map(|(_, blog)| blog).collect();
+        Ok(paginate_entities(blogs, page, page_size))
+    }
+
+    async fn find_latest_published(
+        &self,
+        category_id: Option<&str>,
+        author_id: Option<&str>,
+        limit: u32,
+    ) -> DomainResponse<Vec<BlogEntity>> {
+        let mut blogs = match category_id {
+            Some(category_id) => {
+                let blogs: Vec<BlogEntity> = self
+                    .base
+                    .select_by_lookup(BLOG_BY_CATEGORY_TABLE, "WHERE category_id = ?", (category_id,))
+                    .await?
+                    .into_iter()
+                    .filter(|blog| blog.status == BlogStatus::Published.as_str())
+                    .filter(|blog| author_id.is_none_or(|author_id| blog.author_id == author_id))
+                    .collect();
+                self.with_counters(blogs).await?
+            },
+            None => self.find_published(author_id).await?,
+        };
+        Self::sort_latest_first(&mut blogs);
+        blogs.truncate(limit as usize);
+
+        Ok(blogs)
+    }
+
+    async fn find_published_modification_times(&self) -> DomainResponse<Vec<(String, i64)>> {
+        let blogs = self.select_by_status(BlogStatus::Published).await?;
+        Ok(blogs.into_iter().filter_map(|blog| blog.base.id.map(|id| (id, blog.base.updated_at))).collect())
+    }
+
+    async fn find_scheduled_before(&self, timestamp: i64) -> DomainResponse<Vec<BlogEntity>> {
+        let blogs = self
+            .select_by_status(BlogStatus::Scheduled)
+            .await?
+            .into_iter()
+            .filter(|blog| blog.publish_at.is_some_and(|publish_at| publish_at <= timestamp))
+            .collect();
+
+        self.with_counters(blogs).await
+    }
+
+    async fn publish_scheduled(&self, blog: &BlogEntity) -> DomainResponse<Option<BlogEntity>> {
+        // Replicas run the job alike, the lightweight transaction on the status lets only the first one through
+        let blog_id = blog.base.id.clone().unwrap_or_default();
+        let scheduled = CqlValue::Text(BlogStatus::Scheduled.as_str().to_string());
+        let published = CqlValue::Text(blog.status.clone());
+
+        self.base.update_if(&blog_id, blog, "status", scheduled, published).await
+    }
+
+    async fn increment_counters(&self, blog_id: &str, stars: i32, views: i32) -> DomainResponse<()> {
+        // Counters can not share a table with regular columns, they live in their own table
+        let blog_id = parse_uuid("blog_id", blog_id)?;
+
+        let query = format!(
+            "UPDATE {}.{} SET stars = stars + ?, views = views + ? WHERE blog_id = ?",
+            self.base.keyspace, BLOG_COUNTER_TABLE
+        );
+        self.base.execute(&query, (Counter(stars as i64), Counter(views as i64), blog_id)).await
+    }
+
+    async fn count_by_categories(&self, category_ids: &[String]) -> DomainResponse<u64> {
+        let blogs = self.select_by_categories(category_ids).await?;
+        Ok(blogs.len() as u64)
+    }
+
+    async fn count_only_in_categories(&self, category_ids: &[String]) -> DomainResponse<u64> {
+        let blogs = self.select_by_categories(category_ids).await?;
+        let stranded =
+            blogs.iter().filter(|blog| blog.categories.iter().all(|category_id| category_ids.contains(category_id)));
+        Ok(stranded.count() as u64)
+    }
+
+    async fn count_published_by_category(&self) -> DomainResponse<HashMap<String, u32>> {
+        let blogs = self.select_by_status(BlogStatus::Published).await?;
+
+        let mut counts = HashMap::new();
+        for category_id in blogs.into_iter().flat_map(|blog| blog.categories) {
+            *counts.entry(category_id).or_insert(0) += 1;
+        }
+
+        Ok(counts)
+    }
+
+    async fn replace_categories(&self, from: &[String], to: Option<&str>) -> DomainResponse<usize> {
+        let blogs = self.select_by_categories(from).await?;
+
+        let replaced_count = blogs.len();
+        for mut blog in blogs {
+            blog.categories.retain(|category_id| !from.contains(category_id));
+            if let Some(to) = to
+                && !blog.categories.iter().any(|category_id| category_id == to)
+            {
+                blog.categories.push(to.to_string());
+            }
+            blog.base.updated_at = chrono::Utc::now().timestamp();
+
+            self.base.save(&blog).await?;
+        }
+
+        Ok(replaced_count)
+    }
+
+    async fn drop_categories(&self, from: &[String], fallback: &str) -> DomainResponse<usize> {
+        let blogs = self.select_by_categories(from).await?;
+
+        let dropped_count = blogs.len();
+        for mut blog in blogs {
+            blog.categories.retain(|category_id| !from.contains(category_id));
+            if blog.categories.is_empty() {
+                blog.categories.push(fallback.to_string());
+            }
+            blog.base.updated_at = chrono::Utc::now().timestamp();
+
+            self.base.save(&blog).await?;
+        }
+
+        Ok(dropped_count)
+    }
+
+    async fn find_by_author(&self, author_id: &str) -> DomainResponse<Vec<BlogEntity>> {
+        let blogs = self.select_by_author(author_id).await?;
+        self.with_counters(blogs).await
+    }
+
+    async fn replace_author(&self, from: &str, to: &str) -> DomainResponse<usize> {
+        parse_uuid("author_id", to)?;
+        let blogs = self.select_by_author(from).await?;
+
+        let replaced_count = blogs.len();
+        for mut blog in blogs {
+            blog.author_id = to.to_string();
+            blog.base.updated_at = chrono::Utc::now().timestamp();
+            self.base.save(&blog).await?;
+        }
+
+        Ok(replaced_count)
+    }
+
+    async fn find_and_remove_by_author(&self, author_id: &str) -> DomainResponse<Vec<BlogEntity>> {
+        let author_id = parse_uuid("author_id", author_id)?;
+        self.base.find_and_remove_by_lookup(BLOG_BY_AUTHOR_TABLE, "WHERE author_id = ?", (author_id,)).await
+    }
+}
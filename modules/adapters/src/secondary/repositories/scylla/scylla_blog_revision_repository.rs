@@ -0,0 +1,54 @@
+use async_trait::async_trait;
+use scylla::client::session::Session;
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::blog_revision_entity::BlogRevisionEntity;
+use domain::repositories::blog_revision_repository::BlogRevisionRepository;
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::impl_scylla_base_repository;
+use crate::secondary::repositories::models::blog_revision_schema::ScyllaBlogRevisionSchema;
+use crate::secondary::repositories::scylla::scylla_base_repository::{ScyllaBaseRepository, parse_uuid};
+
+pub struct ScyllaBlogRevisionRepository {
+    base: ScyllaBaseRepository<BlogRevisionEntity, ScyllaBlogRevisionSchema>,
+}
+
+impl ScyllaBlogRevisionRepository {
+    pub fn new(session: Arc<Session>, keyspace: &str, table_name: &str) -> Self {
+        ScyllaBlogRevisionRepository { base: ScyllaBaseRepository::new(session, keyspace, table_name) }
+    }
+}
+
+impl_scylla_base_repository!(ScyllaBlogRevisionRepository, BlogRevisionEntity, ScyllaBlogRevisionSchema);
+
+#[async_trait]
+impl BlogRevisionRepository for ScyllaBlogRevisionRepository {
+    async fn find_by_blog_id(&self, blog_id: &str) -> DomainResponse<Vec<BlogRevisionEntity>> {
+        // Revisions of a blog share its partition, newest version first
+        let blog_id = parse_uuid("blog_id", blog_id)?;
+        self.base.select("WHERE blog_id = ?", (blog_id,)).await
+    }
+
+    async fn find_by_version(&self, blog_id: &str, version: i32) -> DomainResponse<Option<BlogRevisionEntity>> {
+        let blog_id = parse_uuid("blog_id", blog_id)?;
+        self.base.select_one("WHERE blog_id = ? AND version = ?", (blog_id, version)).await
+    }
+
+    async fn find_latest_by_blog_id(&self, blog_id: &str) -> DomainResponse<Option<BlogRevisionEntity>> {
+        let blog_id = parse_uuid("blog_id", blog_id)?;
+        self.base.select_one("WHERE blog_id = ? LIMIT 1", (blog_id,)).await
+    }
+
+    async fn remove_older_than_version(&self, blog_id: &str, version: i32) -> DomainResponse<usize> {
+        let blog_id = parse_uuid("blog_id", blog_id)?;
+        self.base.remove_where("WHERE blog_id = ? AND version < ?", (blog_id, version)).await
+    }
+
+    async fn remove_by_blog_id(&self, blog_id: &str) -> DomainResponse<usize> {
+        let blog_id = parse_uuid("blog_id", blog_id)?;
+        self.base.remove_where("WHERE blog_id = ?", (blog_id,)).await
+    }
+}
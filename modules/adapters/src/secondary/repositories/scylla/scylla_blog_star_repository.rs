@@ -0,0 +1,56 @@
+use async_trait::async_trait;
+use scylla::client::session::Session;
+use std::sync::Arc;
+use uuid::Uuid;
+
+// shared modules
+use domain::entities::blog_star_entity::BlogStarEntity;
+use domain::repositories::blog_star_repository::BlogStarRepository;
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::impl_scylla_base_repository;
+use crate::secondary::repositories::models::blog_star_schema::ScyllaBlogStarSchema;
+use crate::secondary::repositories::scylla::scylla_base_repository::{EntitySchema, ScyllaBaseRepository, parse_uuid};
+
+pub struct ScyllaBlogStarRepository {
+    base: ScyllaBaseRepository<BlogStarEntity, ScyllaBlogStarSchema>,
+}
+
+impl ScyllaBlogStarRepository {
+    pub fn new(session: Arc<Session>, keyspace: &str, table_name: &str) -> Self {
+        ScyllaBlogStarRepository { base: ScyllaBaseRepository::new(session, keyspace, table_name) }
+    }
+}
+
+impl_scylla_base_repository!(ScyllaBlogStarRepository, BlogStarEntity, ScyllaBlogStarSchema);
+
+#[async_trait]
+impl BlogStarRepository for ScyllaBlogStarRepository {
+    async fn find_by_blog_id_and_account_id(
+        &self,
+        blog_id: &str,
+        account_id: &str,
+    ) -> DomainResponse<Option<BlogStarEntity>> {
+        let blog_id = parse_uuid("blog_id", blog_id)?;
+        let account_id = parse_uuid("account_id", account_id)?;
+
+        self.base.select_one("WHERE blog_id = ? AND account_id = ?", (blog_id, account_id)).await
+    }
+
+    async fn create_if_absent(&self, star: &BlogStarEntity) -> DomainResponse<bool> {
+        let mut schema = ScyllaBlogStarSchema::from_entity(star);
+        schema.id = Some(Uuid::now_v7());
+
+        // Keyed by (blog_id, account_id), the lightweight transaction keeps a second star from overwriting the first
+        self.base.insert_if_absent(&schema).await
+    }
+
+    async fn find_by_account_id(&self, account_id: &str) -> DomainResponse<Vec<BlogStarEntity>> {
+        let account_id = parse_uuid("account_id", account_id)?;
+        let mut stars = self.base.select("WHERE account_id = ? ALLOW FILTERING", (account_id,)).await?;
+        stars.sort_by_key(|star| std::cmp::Reverse(star.base.created_at));
+
+        Ok(stars)
+    }
+}
@@ -0,0 +1,172 @@
+use async_trait::async_trait;
+use scylla::client::session::Session;
+use scylla::response::query_result::QueryResult;
+use sha2::{Digest, Sha256};
+use std::sync::Arc;
+use uuid::{Builder, Uuid};
+
+// shared modules
+use domain::entities::bookmark_entity::BookmarkEntity;
+use domain::repositories::bookmark_repository::BookmarkRepository;
+use shared::models::failure::Failure;
+use shared::models::paginate::Paginate;
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::impl_scylla_base_repository;
+use crate::secondary::repositories::models::bookmark_schema::ScyllaBookmarkSchema;
+use crate::secondary::repositories::scylla::scylla_base_repository::{
+    EntitySchema, ScyllaBaseRepository, paginate_entities,
+};
+
+pub struct ScyllaBookmarkRepository {
+    base: ScyllaBaseRepository<BookmarkEntity, ScyllaBookmarkSchema>,
+}
+
+impl ScyllaBookmarkRepository {
+    pub fn new(session: Arc<Session>, keyspace: &str, table_name: &str) -> Self {
+        ScyllaBookmarkRepository { base: ScyllaBaseRepository::new(session, keyspace, table_name) }
+    }
+
+    /// Bookmarks are keyed by their target so inserting the same bookmark twice writes the same row.
+    fn bookmark_id(account_id: &str, target_type: &str, target_id: &str) -> Uuid {
+        let digest = Sha256::digest(format!("{}:{}:{}", account_id, target_type, target_id).as_bytes());
+        let mut bytes = [0u8; 16];
+        bytes.copy_from_slice(&digest[..16]);
+        Builder::from_custom_bytes(bytes).into_uuid()
+    }
+
+    fn parse_account_id(account_id: &str) -> DomainResponse<Uuid> {
+        Uuid::parse_str(account_id)
+            .map_err(|e| Failure::BadRequest(format!("Invalid account_id format: {}: {}", account_id, e)))
+    }
+
+    fn to_schemas(result: QueryResult) -> DomainResponse<Vec<ScyllaBookmarkSchema>> {
+        result
+            .into_rows_result()
+            .map_err(|e| Failure::DatabaseError(format!("Failed to read bookmarks: {}", e)))?
+            .rows::<ScyllaBookmarkSchema>()
+            .map_err(|e| Failure::DatabaseError(format!("Failed to read bookmarks: {}", e)))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| Failure::DatabaseError(format!("Failed to deserialize bookmarks: {}", e)))
+    }
+
+    async fn find_by_ids(&self, ids: Vec<Uuid>) -> DomainResponse<Vec<ScyllaBookmarkSchema>> {
+        let query = format!(
+            "SELECT {} FROM {}.{} WHERE id IN ?",
+            ScyllaBookmarkSchema::columns(),
+            self.base.keyspace,
+            self.base.table_name
+        );
+
+        let prepared = self.base.prepare_statement(&query).await?;
+        let result = self
+            .base
+            .session
+            .execute_unpaged(&prepared, (ids,))
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to find bookmarks: {}", e)))?;
+
+        Self::to_schemas(result)
+    }
+}
+
+impl_scylla_base_repository!(ScyllaBookmarkRepository, BookmarkEntity, ScyllaBookmarkSchema);
+
+#[async_trait]
+impl BookmarkRepository for ScyllaBookmarkRepository {
+    async fn upsert(&self, bookmark: &BookmarkEntity) -> DomainResponse<BookmarkEntity> {
+        let mut schema = ScyllaBookmarkSchema::from_entity(bookmark);
+        let bookmark_id = Self::bookmark_id(&bookmark.account_id, &bookmark.target_type, &bookmark.target_id);
+
+        // Keep the original row, and its creation time, when the target is already bookmarked
+        if let Some(existing) = self.find_by_ids(vec![bookmark_id]).await?.into_iter().next() {
+            return Ok(existing.to_entity());
+        }
+
+        schema.id = Some(bookmark_id);
+        let query = format!(
+            "INSERT INTO {}.{} ({}) VALUES ({})",
+            self.base.keyspace,
+            self.base.table_name,
+            ScyllaBookmarkSchema::columns(),
+            ScyllaBookmarkSchema::insert_placeholders()
+        );
+
+        let prepared = self.base.prepare_statement(&query).await?;
+        self.base
+            .session
+            .execute_unpaged(&prepared, &schema)
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to create bookmark: {}", e)))?;
+
+        Ok(schema.to_entity())
+    }
+
+    async fn remove_by_target(&self, account_id: &str, target_type: &str, target_id: &str) -> DomainResponse<usize> {
+        let bookmark_id = Self::bookmark_id(account_id, target_type, target_id);
+        if self.find_by_ids(vec![bookmark_id]).await?.is_empty() {
+            return Ok(0);
+        }
+
+        let query = format!("DELETE FROM {}.{} WHERE id = ?", self.base.keyspace, self.base.table_name);
+        let prepared = self.base.prepare_statement(&query).await?;
+        self.base
+            .session
+            .execute_unpaged(&prepared, (bookmark_id,))
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to remove bookmark: {}", e)))?;
+
+        Ok(1)
+    }
+
+    async fn find_by_account_id(
+        &self,
+        account_id: &str,
+        target_type: Option<&str>,
+        page: u32,
+        page_size: u32,
+    ) -> DomainResponse<(Paginate, Vec<BookmarkEntity>)> {
+        // Served by the account_id secondary index, ordering and paging happen here
+        let query = format!(
+            "SELECT {} FROM {}.{} WHERE account_id = ?",
+            ScyllaBookmarkSchema::columns(),
+            self.base.keyspace,
+            self.base.table_name
+        );
+
+        let prepared = self.base.prepare_statement(&query).await?;
+        let result = self
+            .base
+            .session
+            .execute_unpaged(&prepared, (Self::parse_account_id(account_id)?,))
+            .await
+            .map_err(|e| Failure::DatabaseError(format!("Failed to find bookmarks: {}", e)))?;
+
+        let mut bookmarks: Vec<BookmarkEntity> = Self::to_schemas(result)?
+            .into_iter()
+            .filter(|schema| target_type.is_none_or(|target_type| schema.target_type == target_type))
+            .map(|schema| schema.to_entity())
+            .collect();
+        bookmarks.sort_by_key(|bookmark| std::cmp::Reverse(bookmark.base.created_at));
+
+        Ok(paginate_entities(bookmarks, page, page_size))
+    }
+
+    async fn find_bookmarked_ids(
+        &self,
+        account_id: &str,
+        target_type: &str,
+        target_ids: &[String],
+    ) -> DomainResponse<Vec<String>> {
+        let ids = target_ids.iter().map(|target_id| Self::bookmark_id(account_id, target_type, target_id)).collect();
+
+        let bookmarked = self.find_by_ids(ids).await?.into_iter().map(|schema| schema.target_id).collect();
+
+        Ok(bookmarked)
+    }
+
+    async fn remove_by_account_id(&self, account_id: &str) -> DomainResponse<usize> {
+        self.base.remove_where("WHERE account_id = ?", (Self::parse_account_id(account_id)?,)).await
+    }
+}
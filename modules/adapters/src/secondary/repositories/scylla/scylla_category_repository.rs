@@ -1,7 +1,7 @@
 use async_trait::async_trait;
 use scylla::client::session::Session;
-use shared::models::failure::Failure;
 use std::sync::Arc;
+use uuid::Uuid;
 
 // shared modules
 use domain::entities::category_entity::CategoryEntity;
@@ -11,9 +11,9 @@ use shared::types::DomainResponse;
 // internal modules
 use crate::impl_scylla_base_repository;
 use crate::secondary::repositories::models::category_schema::ScyllaCategorySchema;
-use crate::secondary::repositories::scylla::scylla_base_repository::{EntitySchema, ScyllaBaseRepository};
+use crate::secondary::repositories::scylla::scylla_base_repository::ScyllaBaseRepository;
+use crate::shared::utilities::databases::CATEGORY_BY_NAME_TABLE;
 
-#[allow(dead_code)]
 pub struct ScyllaCategoryRepository {
     base: ScyllaBaseRepository<CategoryEntity, ScyllaCategorySchema>,
 }
@@ -29,23 +29,18 @@ impl_scylla_base_repository!(ScyllaCategoryRepository, CategoryEntity, ScyllaCat
 #[async_trait]
 impl CategoryRepository for ScyllaCategoryRepository {
     async fn find_by_name(&self, name: &str) -> DomainResponse<Option<CategoryEntity>> {
-        let query = format!(
-            "SELECT {} FROM {}.{} WHERE name = ? ALLOW FILTERING;",
-            ScyllaCategorySchema::columns(),
-            self.base.keyspace,
-            self.base.table_name
-        );
-
-        let prepared = self.base.prepare_statement(&query).await?;
-        let rows = self
-            .base
-            .session
-            .execute_unpaged(&prepared, vec![name])
-            .await
-            .map_err(|e| Failure::DatabaseError(format!("Failed to execute query: {}", e)))?
-            .into_rows_result();
-
-        println!("find_by_name rows: {:?}", rows);
-        Ok(None)
+        let categories = self.base.select_by_lookup(CATEGORY_BY_NAME_TABLE, "WHERE name = ?", (name,)).await?;
+        Ok(categories.into_iter().next())
+    }
+
+    async fn find_by_ids(&self, ids: &[String]) -> DomainResponse<Vec<CategoryEntity>> {
+        // Malformed ids can not match any category, they are reported as missing by the caller
+        let ids: Vec<Uuid> = ids.iter().filter_map(|id| Uuid::parse_str(id).ok()).collect();
+        self.base.select("WHERE id IN ?", (ids,)).await
+    }
+
+    async fn find_by_path_prefix(&self, path_prefix: &str) -> DomainResponse<Vec<CategoryEntity>> {
+        let categories = self.base.select("", ()).await?;
+        Ok(categories.into_iter().filter(|category| category.path.starts_with(path_prefix)).collect())
     }
 }
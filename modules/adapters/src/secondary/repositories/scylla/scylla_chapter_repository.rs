@@ -0,0 +1,41 @@
+use async_trait::async_trait;
+use scylla::client::session::Session;
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::chapter_entity::ChapterEntity;
+use domain::repositories::chapter_repository::ChapterRepository;
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::impl_scylla_base_repository;
+use crate::secondary::repositories::models::chapter_schema::ScyllaChapterSchema;
+use crate::secondary::repositories::scylla::scylla_base_repository::{ScyllaBaseRepository, parse_uuid};
+
+pub struct ScyllaChapterRepository {
+    base: ScyllaBaseRepository<ChapterEntity, ScyllaChapterSchema>,
+}
+
+impl ScyllaChapterRepository {
+    pub fn new(session: Arc<Session>, keyspace: &str, table_name: &str) -> Self {
+        ScyllaChapterRepository { base: ScyllaBaseRepository::new(session, keyspace, table_name) }
+    }
+}
+
+impl_scylla_base_repository!(ScyllaChapterRepository, ChapterEntity, ScyllaChapterSchema);
+
+#[async_trait]
+impl ChapterRepository for ScyllaChapterRepository {
+    async fn find_by_course_id(&self, course_id: &str) -> DomainResponse<Vec<ChapterEntity>> {
+        let course_id = parse_uuid("course_id", course_id)?;
+        let mut chapters = self.base.select("WHERE course_id = ? ALLOW FILTERING", (course_id,)).await?;
+        chapters.sort_by_key(|chapter| (chapter.order_index, chapter.base.created_at));
+
+        Ok(chapters)
+    }
+
+    async fn find_and_remove_by_course_id(&self, course_id: &str) -> DomainResponse<Vec<ChapterEntity>> {
+        let course_id = parse_uuid("course_id", course_id)?;
+        self.base.find_and_remove_where("WHERE course_id = ? ALLOW FILTERING", (course_id,)).await
+    }
+}
@@ -0,0 +1,33 @@
+use async_trait::async_trait;
+use scylla::client::session::Session;
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::comment_ban_entity::CommentBanEntity;
+use domain::repositories::comment_ban_repository::CommentBanRepository;
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::impl_scylla_base_repository;
+use crate::secondary::repositories::models::comment_ban_schema::ScyllaCommentBanSchema;
+use crate::secondary::repositories::scylla::scylla_base_repository::{ScyllaBaseRepository, parse_uuid};
+
+pub struct ScyllaCommentBanRepository {
+    base: ScyllaBaseRepository<CommentBanEntity, ScyllaCommentBanSchema>,
+}
+
+impl ScyllaCommentBanRepository {
+    pub fn new(session: Arc<Session>, keyspace: &str, table_name: &str) -> Self {
+        ScyllaCommentBanRepository { base: ScyllaBaseRepository::new(session, keyspace, table_name) }
+    }
+}
+
+impl_scylla_base_repository!(ScyllaCommentBanRepository, CommentBanEntity, ScyllaCommentBanSchema);
+
+#[async_trait]
+impl CommentBanRepository for ScyllaCommentBanRepository {
+    async fn find_by_account_id(&self, account_id: &str) -> DomainResponse<Option<CommentBanEntity>> {
+        let account_id = parse_uuid("account_id", account_id)?;
+        self.base.select_one("WHERE account_id = ? ALLOW FILTERING", (account_id,)).await
+    }
+}
@@ -0,0 +1,75 @@
+use async_trait::async_trait;
+use scylla::client::session::Session;
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::comment_entity::CommentEntity;
+use domain::repositories::comment_repository::CommentRepository;
+use shared::models::paginate::Paginate;
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::impl_scylla_base_repository;
+use crate::secondary::repositories::models::comment_schema::ScyllaCommentSchema;
+use crate::secondary::repositories::scylla::scylla_base_repository::{
+    ScyllaBaseRepository, paginate_entities, parse_uuid,
+};
+
+pub struct ScyllaCommentRepository {
+    base: ScyllaBaseRepository<CommentEntity, ScyllaCommentSchema>,
+}
+
+impl ScyllaCommentRepository {
+    pub fn new(session: Arc<Session>, keyspace: &str, table_name: &str) -> Self {
+        ScyllaCommentRepository { base: ScyllaBaseRepository::new(session, keyspace, table_name) }
+    }
+}
+
+impl_scylla_base_repository!(ScyllaCommentRepository, CommentEntity, ScyllaCommentSchema);
+
+#[async_trait]
+impl CommentRepository for ScyllaCommentRepository {
+    async fn find_by_blog_id_paginated(
+        &self,
+        blog_id: &str,
+        status: &str,
+        page: u32,
+        page_size: u32,
+    ) -> DomainResponse<(Paginate, Vec<CommentEntity>)> {
+        let blog_id = parse_uuid("blog_id", blog_id)?;
+        let mut comments =
+            self.base.select("WHERE blog_id = ? AND status = ? ALLOW FILTERING", (blog_id, status)).await?;
+
+        // Oldest first so replies always come after the comment they answer
+        comments.sort_by_key(|comment| comment.base.created_at);
+
+        Ok(paginate_entities(comments, page, page_size))
+    }
+
+    async fn update_status_by_author_id(&self, author_id: &str, status: &str) -> DomainResponse<usize> {
+        let author_id = parse_uuid("author_id", author_id)?;
+        let comments = self.base.select("WHERE author_id = ? ALLOW FILTERING", (author_id,)).await?;
+
+        let updated_count = comments.len();
+        for mut comment in comments {
+            comment.status = status.to_string();
+            comment.base.updated_at = chrono::Utc::now().timestamp();
+            self.base.save(&comment).await?;
+        }
+
+        Ok(updated_count)
+    }
+
+    async fn find_by_author_id(&self, author_id: &str) -> DomainResponse<Vec<CommentEntity>> {
+        let author_id = parse_uuid("author_id", author_id)?;
+        let mut comments = self.base.select("WHERE author_id = ? ALLOW FILTERING", (author_id,)).await?;
+        comments.sort_by_key(|comment| std::cmp::Reverse(comment.base.created_at));
+
+        Ok(comments)
+    }
+
+    async fn remove_by_author_id(&self, author_id: &str) -> DomainResponse<usize> {
+        let author_id = parse_uuid("author_id", author_id)?;
+        self.base.remove_where("WHERE author_id = ? ALLOW FILTERING", (author_id,)).await
+    }
+}
@@ -0,0 +1,215 @@
+use async_trait::async_trait;
+use scylla::client::session::Session;
+use scylla::value::{CqlValue, Row};
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::course_entity::CourseEntity;
+use domain::repositories::base_repository::BaseRepository;
+use domain::repositories::course_repository::CourseRepository;
+use domain::values::course_status::CourseStatus;
+use shared::models::failure::Failure;
+use shared::models::filters::{Filterable, MemoryFilter, QuerySpec};
+use shared::models::paginate::Paginate;
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::impl_scylla_base_repository;
+use crate::secondary::repositories::models::course_schema::ScyllaCourseSchema;
+use crate::secondary::repositories::scylla::scylla_base_repository::{
+    ScyllaBaseRepository, paginate_entities, parse_uuid,
+};
+
+// Attempts at a statistics update before giving up under contention
+static STATS_UPDATE_ATTEMPTS: usize = 5;
+
+#[derive(Clone, Copy)]
+struct CourseStats {
+    rating: f32,
+    review_count: i32,
+    enrollments: i32,
+}
+
+pub struct ScyllaCourseRepository {
+    base: ScyllaBaseRepository<CourseEntity, ScyllaCourseSchema>,
+}
+
+impl ScyllaCourseRepository {
+    pub fn new(session: Arc<Session>, keyspace: &str, table_name: &str) -> Self {
+        ScyllaCourseRepository { base: ScyllaBaseRepository::new(session, keyspace, table_name) }
+    }
+
+    /// Applies `update` to the course statistics with a lightweight transaction, starting over from the
+    /// current values whenever a concurrent write got there first. Unknown courses are left alone.
+    async fn update_stats(
+        &self,
+        course_id: &str,
+        update: impl Fn(CourseStats) -> CourseStats + Send + Sync,
+    ) -> DomainResponse<()> {
+        let course_id = parse_uuid("course_id", course_id)?;
+
+        let select = format!("SELECT rating, review_count, enrollments FROM {} WHERE id = ?", self.base.table());
+        let select = self.base.prepare_statement(&select).await?;
+        let update_query = format!(
+            "UPDATE {} SET rating = ?, review_count = ?, enrollments = ? WHERE id = ? \
+             IF rating = ? AND review_count = ? AND enrollments = ?",
+            self.base.table()
+        );
+        let update_query = self.base.prepare_statement(&update_query).await?;
+
+        for _ in 0..STATS_UPDATE_ATTEMPTS {
+            let current = self
+                .base
+                .session
+                .execute_unpaged(&select, (course_id,))
+                .await
+                .map_err(|e| Failure::DatabaseError(format!("Failed to find course: {}", e)))?
+                .into_rows_result()
+                .map_err(|e| Failure::DatabaseError(format!("Failed to read course: {}", e)))?
+                .maybe_first_row::<(Option<f32>, Option<i32>, Option<i32>)>()
+                .map_err(|e| Failure::DatabaseError(format!("Failed to deserialize course: {}", e)))?;
+            let Some(current) = current else {
+                return Ok(());
+            };
+
+            let stats = CourseStats {
+                rating: current.0.unwrap_or_default(),
+                review_count: current.1.unwrap_or_default(),
+                enrollments: current.2.unwrap_or_default(),
+            };
+            let next = update(stats);
+            let applied = self
+                .base
+                .session
+                .execute_unpaged(
+                    &update_query,
+                    (next.rating, next.review_count, next.enrollments, course_id, current.0, current.1, current.2),
+                )
+                .await
+                .map_err(|e| Failure::DatabaseError(format!("Failed to update course statistics: {}", e)))?
+                .into_rows_result()
+                .map_err(|e| Failure::DatabaseError(format!("Failed to read course update: {}", e)))?
+                .first_row::<Row>()
+                .map_err(|e| Failure::DatabaseError(format!("Failed to read course update: {}", e)))?;
+
+            if matches!(applied.columns.first(), Some(Some(CqlValue::Boolean(true)))) {
+                return Ok(());
+            }
+        }
+
+        Err(Failure::DatabaseError(format!("Failed to update course statistics of {}: too much contention", course_id)))
+    }
+}
+
+impl_scylla_base_repository!(ScyllaCourseRepository, CourseEntity, ScyllaCourseSchema);
+
+#[async_trait]
+impl CourseRepository for ScyllaCourseRepository {
+    async fn find_published_paginated(
+        &self,
+        category_ids: Option<&[String]>,
+        level: Option<&str>,
+        spec: &QuerySpec,
+        page: u32,
+        page_size: u32,
+    ) -> DomainResponse<(Paginate, Vec<CourseEntity>)> {
+        if let Some(category_ids) = category_ids {
+            for category_id in category_ids {
+                parse_uuid("category_id", category_id)?;
+            }
+        }
+
+        let mut courses: Vec<CourseEntity> = self
+            .base
+            .select("WHERE status = ? ALLOW FILTERING", (CourseStatus::Published.as_str(),))
+            .await?
+            .into_iter()
+            .filter(|course| category_ids.is_none_or(|category_ids| category_ids.contains(&course.category_id)))
+            .filter(|course| level.is_none_or(|level| course.level == level))
+            .collect();
+        courses.sort_by_key(|course| std::cmp::Reverse(course.base.created_at));
+
+        // The spec is run over the published courses, none of its fields are keys of the table
+        let fields = CourseEntity::filter_fields();
+        let mut courses: Vec<(serde_json::Value, CourseEntity)> = courses
+            .into_iter()
+            .map(|course| (serde_json::to_value(&course).unwrap_or_default(), course))
+            .filter(|(value, _)| MemoryFilter::matches(spec, fields, value))
+            .collect();
+        courses.sort_by(|(a, _), (b, _)| MemoryFilter::compare(spec, fields, a, b));
+
+        let courses = courses.into_iter().map(|(_, course)| course).collect();
+        Ok(paginate_entities(courses, page, page_size))
+    }
+
+    async fn find_by_author_id(&self, author_id: &str) -> DomainResponse<Vec<CourseEntity>> {
+        let author_id = parse_uuid("author_id", author_id)?;
+        let mut courses = self.base.select("WHERE author_id = ? ALLOW FILTERING", (author_id,)).await?;
+        courses.sort_by_key(|course| std::cmp::Reverse(course.base.created_at));
+
+        Ok(courses)
+    }
+
+    async fn update_details(&self, course: &CourseEntity) -> DomainResponse<CourseEntity> {
+        let course_id = course.base.id.clone().unwrap_or_default();
+        let id = parse_uuid("course_id", &course_id)?;
+        let category_id = parse_uuid("category_id", &course.category_id)?;
+        if self.base.find(&course_id).await?.is_none() {
+            return Err(Failure::NotFound(format!("Course with id {} not found", course_id)));
+        }
+
+        let query = format!(
+            "UPDATE {} SET category_id = ?, name = ?, description = ?, level = ?, status = ?, approximate = ?, \
+             updated_at = ? WHERE id = ?",
+            self.base.table()
+        );
+        let values = (
+            category_id,
+            &course.name,
+            &course.description,
+            &course.level,
+            &course.status,
+            course.approximate,
+            course.base.updated_at,
+            id,
+        );
+        self.base.execute(&query, values).await?;
+
+        self.base
+            .find(&course_id)
+            .await?
+            .ok_or_else(|| Failure::NotFound(format!("Course with id {} not found", course_id)))
+    }
+
+    async fn increment_enrollments(&self, course_id: &str, delta: i32) -> DomainResponse<()> {
+        self.update_stats(course_id, |stats| CourseStats { enrollments: stats.enrollments + delta, ..stats }).await
+    }
+
+    async fn apply_review(&self, course_id: &str, rating_delta: i32, count_delta: i32) -> DomainResponse<()> {
+        self.update_stats(course_id, |stats| {
+            let (rating, review_count) =
+                CourseEntity::reviewed_rating(stats.rating, stats.review_count, rating_delta, count_delta);
+            CourseStats { rating, review_count, ..stats }
+        })
+        .await
+    }
+
+    async fn replace_author(&self, from: &str, to: &str) -> DomainResponse<usize> {
+        let to_id = parse_uuid("author_id", to)?;
+        let courses = self.find_by_author_id(from).await?;
+
+        // Only the author column is written, the aggregates keep moving through their own updates
+        let query = format!("UPDATE {} SET author_id = ?, updated_at = ? WHERE id = ?", self.base.table());
+        for course in &courses {
+            let id = parse_uuid("course_id", course.base.id.as_deref().unwrap_or_default())?;
+            self.base.execute(&query, (to_id, chrono::Utc::now().timestamp(), id)).await?;
+        }
+
+        Ok(courses.len())
+    }
+
+    async fn find_and_remove_by_author_id(&self, author_id: &str) -> DomainResponse<Vec<CourseEntity>> {
+        let author_id = parse_uuid("author_id", author_id)?;
+        self.base.find_and_remove_where("WHERE author_id = ? ALLOW FILTERING", (author_id,)).await
+    }
+}
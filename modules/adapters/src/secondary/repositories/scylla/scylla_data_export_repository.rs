@@ -0,0 +1,60 @@
+use async_trait::async_trait;
+use scylla::client::session::Session;
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::data_export_entity::DataExportEntity;
+use domain::repositories::data_export_repository::DataExportRepository;
+use domain::values::data_export_status::DataExportStatus;
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::impl_scylla_base_repository;
+use crate::secondary::repositories::models::data_export_schema::ScyllaDataExportSchema;
+use crate::secondary::repositories::scylla::scylla_base_repository::{ScyllaBaseRepository, parse_uuid};
+
+pub struct ScyllaDataExportRepository {
+    base: ScyllaBaseRepository<DataExportEntity, ScyllaDataExportSchema>,
+}
+
+impl ScyllaDataExportRepository {
+    pub fn new(session: Arc<Session>, keyspace: &str, table_name: &str) -> Self {
+        ScyllaDataExportRepository { base: ScyllaBaseRepository::new(session, keyspace, table_name) }
+    }
+}
+
+impl_scylla_base_repository!(ScyllaDataExportRepository, DataExportEntity, ScyllaDataExportSchema);
+
+#[async_trait]
+impl DataExportRepository for ScyllaDataExportRepository {
+    async fn find_by_account_id(&self, account_id: &str) -> DomainResponse<Vec<DataExportEntity>> {
+        let account_id = parse_uuid("ID", account_id)?;
+        let mut exports = self.base.select("WHERE account_id = ? ALLOW FILTERING", (account_id,)).await?;
+        exports.sort_by_key(|export| std::cmp::Reverse(export.base.created_at));
+
+        Ok(exports)
+    }
+
+    async fn find_pending(&self, limit: u32) -> DomainResponse<Vec<DataExportEntity>> {
+        let mut exports =
+            self.base.select("WHERE status = ? ALLOW FILTERING", (DataExportStatus::Pending.as_str(),)).await?;
+        exports.sort_by_key(|export| export.base.created_at);
+        exports.truncate(limit as usize);
+
+        Ok(exports)
+    }
+
+    async fn find_expired(&self, expires_before: i64, limit: u32) -> DomainResponse<Vec<DataExportEntity>> {
+        let mut exports = self
+            .base
+            .select(
+                "WHERE status = ? AND expires_at <= ? ALLOW FILTERING",
+                (DataExportStatus::Ready.as_str(), expires_before),
+            )
+            .await?;
+        exports.sort_by_key(|export| export.expires_at);
+        exports.truncate(limit as usize);
+
+        Ok(exports)
+    }
+}
@@ -0,0 +1,80 @@
+use async_trait::async_trait;
+use scylla::client::session::Session;
+use std::sync::Arc;
+use uuid::Uuid;
+
+// shared modules
+use domain::entities::enrollment_entity::EnrollmentEntity;
+use domain::repositories::enrollment_repository::EnrollmentRepository;
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::impl_scylla_base_repository;
+use crate::secondary::repositories::models::enrollment_schema::ScyllaEnrollmentSchema;
+use crate::secondary::repositories::scylla::scylla_base_repository::{EntitySchema, ScyllaBaseRepository, parse_uuid};
+use crate::shared::utilities::databases::{ENROLLMENT_BY_ACCOUNT_TABLE, ENROLLMENT_BY_COURSE_TABLE};
+
+pub struct ScyllaEnrollmentRepository {
+    base: ScyllaBaseRepository<EnrollmentEntity, ScyllaEnrollmentSchema>,
+}
+
+impl ScyllaEnrollmentRepository {
+    pub fn new(session: Arc<Session>, keyspace: &str, table_name: &str) -> Self {
+        ScyllaEnrollmentRepository { base: ScyllaBaseRepository::new(session, keyspace, table_name) }
+    }
+}
+
+impl_scylla_base_repository!(ScyllaEnrollmentRepository, EnrollmentEntity, ScyllaEnrollmentSchema);
+
+#[async_trait]
+impl EnrollmentRepository for ScyllaEnrollmentRepository {
+    async fn find_by_account_and_course(
+        &self,
+        account_id: &str,
+        course_id: &str,
+    ) -> DomainResponse<Option<EnrollmentEntity>> {
+        let account_id = parse_uuid("account_id", account_id)?;
+        let course_id = parse_uuid("course_id", course_id)?;
+
+        let enrollments = self
+            .base
+            .select_by_lookup(
+                ENROLLMENT_BY_ACCOUNT_TABLE,
+                "WHERE account_id = ? AND course_id = ?",
+                (account_id, course_id),
+            )
+            .await?;
+        Ok(enrollments.into_iter().next())
+    }
+
+    async fn create_if_absent(&self, enrollment: &EnrollmentEntity) -> DomainResponse<Option<EnrollmentEntity>> {
+        let mut schema = ScyllaEnrollmentSchema::from_entity(enrollment);
+        let id = Uuid::now_v7();
+        schema.set_id(id);
+
+        // The row of enrollments_by_account is the claim, the write below puts the same row back in its batch
+        let claim = schema.lookups().into_iter().find(|lookup| lookup.table == ENROLLMENT_BY_ACCOUNT_TABLE);
+        if let Some(claim) = claim
+            && !self.base.claim(&claim, id).await?
+        {
+            return Ok(None);
+        }
+
+        self.base.save(&schema.to_entity()).await.map(Some)
+    }
+
+    async fn find_by_account_id(&self, account_id: &str) -> DomainResponse<Vec<EnrollmentEntity>> {
+        let account_id = parse_uuid("account_id", account_id)?;
+        self.base.select_by_lookup(ENROLLMENT_BY_ACCOUNT_TABLE, "WHERE account_id = ?", (account_id,)).await
+    }
+
+    async fn find_by_course_id(&self, course_id: &str) -> DomainResponse<Vec<EnrollmentEntity>> {
+        let course_id = parse_uuid("course_id", course_id)?;
+        self.base.select_by_lookup(ENROLLMENT_BY_COURSE_TABLE, "WHERE course_id = ?", (course_id,)).await
+    }
+
+    async fn remove_by_account_id(&self, account_id: &str) -> DomainResponse<usize> {
+        let account_id = parse_uuid("account_id", account_id)?;
+        self.base.remove_by_lookup(ENROLLMENT_BY_ACCOUNT_TABLE, "WHERE account_id = ?", (account_id,)).await
+    }
+}
@@ -4,15 +4,16 @@ use std::sync::Arc;
 
 // shared modules
 use domain::entities::experience_entity::ExperienceEntity;
+use domain::repositories::base_repository::BaseRepository;
 use domain::repositories::experience_repository::ExperienceRepository;
+use shared::models::failure::Failure;
 use shared::types::DomainResponse;
 
 // internal modules
 use crate::impl_scylla_base_repository;
 use crate::secondary::repositories::models::experience_schema::ScyllaExperienceSchema;
-use crate::secondary::repositories::scylla::scylla_base_repository::ScyllaBaseRepository;
+use crate::secondary::repositories::scylla::scylla_base_repository::{ScyllaBaseRepository, parse_uuid};
 
-#[allow(dead_code)]
 pub struct ScyllaExperienceRepository {
     base: ScyllaBaseRepository<ExperienceEntity, ScyllaExperienceSchema>,
 }
@@ -27,12 +28,13 @@ impl_scylla_base_repository!(ScyllaExperienceRepository, ExperienceEntity, Scyll
 
 #[async_trait]
 impl ExperienceRepository for ScyllaExperienceRepository {
-    async fn find_by_account_id(&self, _account_id: &str) -> DomainResponse<Vec<ExperienceEntity>> {
-        todo!()
+    async fn find_by_account_id(&self, account_id: &str) -> DomainResponse<Vec<ExperienceEntity>> {
+        let account_id = parse_uuid("account_id", account_id)?;
+        self.base.select("WHERE account_id = ? ALLOW FILTERING", (account_id,)).await
     }
 
-    async fn find_by_company(&self, _company: &str) -> DomainResponse<Option<ExperienceEntity>> {
-        todo!()
+    async fn find_by_company(&self, company: &str) -> DomainResponse<Option<ExperienceEntity>> {
+        self.base.select_one("WHERE company = ? ALLOW FILTERING", (company,)).await
     }
 
     async fn update_experience_partial(
@@ -47,6 +49,34 @@ impl ExperienceRepository for ScyllaExperienceRepository {
         end_date: Option<Option<i64>>,
         is_current: Option<bool>,
     ) -> DomainResponse<ExperienceEntity> {
-        unimplemented!()
+        if technologies.is_none()
+            && position.is_none()
+            && responsibility.is_none()
+            && company.is_none()
+            && location.is_none()
+            && start_date.is_none()
+            && end_date.is_none()
+            && is_current.is_none()
+        {
+            return Err(Failure::BadRequest("No fields provided for update".to_string()));
+        }
+
+        let mut experience = self
+            .base
+            .find(id)
+            .await?
+            .ok_or_else(|| Failure::NotFound(format!("Experience with id {} not found", id)))?;
+
+        experience.technologies = technologies.unwrap_or(experience.technologies);
+        experience.position = position.unwrap_or(experience.position);
+        experience.responsibility = responsibility.unwrap_or(experience.responsibility);
+        experience.company = company.unwrap_or(experience.company);
+        experience.location = location.unwrap_or(experience.location);
+        experience.start_date = start_date.unwrap_or(experience.start_date);
+        experience.end_date = end_date.unwrap_or(experience.end_date);
+        experience.is_current = is_current.unwrap_or(experience.is_current);
+        experience.base.updated_at = chrono::Utc::now().timestamp();
+
+        self.base.save(&experience).await
     }
 }
@@ -0,0 +1,52 @@
+use async_trait::async_trait;
+use scylla::client::session::Session;
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::lesson_entity::LessonEntity;
+use domain::repositories::lesson_repository::LessonRepository;
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::impl_scylla_base_repository;
+use crate::secondary::repositories::models::lesson_schema::ScyllaLessonSchema;
+use crate::secondary::repositories::scylla::scylla_base_repository::{ScyllaBaseRepository, parse_uuid};
+
+pub struct ScyllaLessonRepository {
+    base: ScyllaBaseRepository<LessonEntity, ScyllaLessonSchema>,
+}
+
+impl ScyllaLessonRepository {
+    pub fn new(session: Arc<Session>, keyspace: &str, table_name: &str) -> Self {
+        ScyllaLessonRepository { base: ScyllaBaseRepository::new(session, keyspace, table_name) }
+    }
+}
+
+impl_scylla_base_repository!(ScyllaLessonRepository, LessonEntity, ScyllaLessonSchema);
+
+#[async_trait]
+impl LessonRepository for ScyllaLessonRepository {
+    async fn find_by_chapter_ids(&self, chapter_ids: &[String]) -> DomainResponse<Vec<LessonEntity>> {
+        let chapter_ids = chapter_ids
+            .iter()
+            .map(|chapter_id| parse_uuid("chapter_id", chapter_id).map(|chapter_id| chapter_id.to_string()))
+            .collect::<DomainResponse<Vec<String>>>()?;
+
+        // IN is only served on key columns, the lessons of the chapters are picked from a scan
+        let mut lessons: Vec<LessonEntity> = self
+            .base
+            .select("", ())
+            .await?
+            .into_iter()
+            .filter(|lesson| chapter_ids.contains(&lesson.chapter_id))
+            .collect();
+        lessons.sort_by_key(|lesson| (lesson.order_index, lesson.base.created_at));
+
+        Ok(lessons)
+    }
+
+    async fn remove_by_chapter_id(&self, chapter_id: &str) -> DomainResponse<usize> {
+        let chapter_id = parse_uuid("chapter_id", chapter_id)?;
+        self.base.remove_where("WHERE chapter_id = ? ALLOW FILTERING", (chapter_id,)).await
+    }
+}
@@ -0,0 +1,52 @@
+use async_trait::async_trait;
+use scylla::client::session::Session;
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::media_asset_entity::MediaAssetEntity;
+use domain::repositories::media_asset_repository::MediaAssetRepository;
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::impl_scylla_base_repository;
+use crate::secondary::repositories::models::media_asset_schema::ScyllaMediaAssetSchema;
+use crate::secondary::repositories::scylla::scylla_base_repository::{ScyllaBaseRepository, parse_uuid};
+
+pub struct ScyllaMediaAssetRepository {
+    base: ScyllaBaseRepository<MediaAssetEntity, ScyllaMediaAssetSchema>,
+}
+
+impl ScyllaMediaAssetRepository {
+    pub fn new(session: Arc<Session>, keyspace: &str, table_name: &str) -> Self {
+        ScyllaMediaAssetRepository { base: ScyllaBaseRepository::new(session, keyspace, table_name) }
+    }
+}
+
+impl_scylla_base_repository!(ScyllaMediaAssetRepository, MediaAssetEntity, ScyllaMediaAssetSchema);
+
+#[async_trait]
+impl MediaAssetRepository for ScyllaMediaAssetRepository {
+    async fn find_by_reference(&self, purpose: &str, reference_id: &str) -> DomainResponse<Vec<MediaAssetEntity>> {
+        let mut assets =
+            self.base.select("WHERE purpose = ? AND reference_id = ? ALLOW FILTERING", (purpose, reference_id)).await?;
+        assets.sort_by_key(|asset| std::cmp::Reverse(asset.base.created_at));
+
+        Ok(assets)
+    }
+
+    async fn find_orphans(&self, detached_before: i64, limit: u32) -> DomainResponse<Vec<MediaAssetEntity>> {
+        let mut assets = self.base.select("WHERE detached_at <= ? ALLOW FILTERING", (detached_before,)).await?;
+        assets.sort_by_key(|asset| asset.detached_at);
+        assets.truncate(limit as usize);
+
+        Ok(assets)
+    }
+
+    async fn find_by_account_id(&self, account_id: &str) -> DomainResponse<Vec<MediaAssetEntity>> {
+        let account_id = parse_uuid("account_id", account_id)?;
+        let mut assets = self.base.select("WHERE account_id = ? ALLOW FILTERING", (account_id,)).await?;
+        assets.sort_by_key(|asset| std::cmp::Reverse(asset.base.created_at));
+
+        Ok(assets)
+    }
+}
@@ -0,0 +1,57 @@
+use async_trait::async_trait;
+use scylla::client::session::Session;
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::notification_preference_entity::NotificationPreferenceEntity;
+use domain::repositories::notification_preference_repository::NotificationPreferenceRepository;
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::impl_scylla_base_repository;
+use crate::secondary::repositories::models::notification_preference_schema::ScyllaNotificationPreferenceSchema;
+use crate::secondary::repositories::scylla::scylla_base_repository::{ScyllaBaseRepository, parse_uuid};
+use crate::shared::utilities::databases::NOTIFICATION_PREFERENCE_BY_ACCOUNT_TABLE;
+
+pub struct ScyllaNotificationPreferenceRepository {
+    base: ScyllaBaseRepository<NotificationPreferenceEntity, ScyllaNotificationPreferenceSchema>,
+}
+
+impl ScyllaNotificationPreferenceRepository {
+    pub fn new(session: Arc<Session>, keyspace: &str, table_name: &str) -> Self {
+        ScyllaNotificationPreferenceRepository { base: ScyllaBaseRepository::new(session, keyspace, table_name) }
+    }
+}
+
+impl_scylla_base_repository!(
+    ScyllaNotificationPreferenceRepository,
+    NotificationPreferenceEntity,
+    ScyllaNotificationPreferenceSchema
+);
+
+#[async_trait]
+impl NotificationPreferenceRepository for ScyllaNotificationPreferenceRepository {
+    async fn find_by_account_id(&self, account_id: &str) -> DomainResponse<Option<NotificationPreferenceEntity>> {
+        let account_id = parse_uuid("account_id", account_id)?;
+        let preferences = self
+            .base
+            .select_by_lookup(NOTIFICATION_PREFERENCE_BY_ACCOUNT_TABLE, "WHERE account_id = ?", (account_id,))
+            .await?;
+        Ok(preferences.into_iter().next())
+    }
+
+    async fn find_digest_due(&self, now: i64, limit: u32) -> DomainResponse<Vec<NotificationPreferenceEntity>> {
+        let mut preferences = self.base.select("WHERE next_digest_at <= ? ALLOW FILTERING", (now,)).await?;
+        preferences.sort_by_key(|preference| preference.next_digest_at);
+        preferences.truncate(limit as usize);
+
+        Ok(preferences)
+    }
+
+    async fn remove_by_account_id(&self, account_id: &str) -> DomainResponse<usize> {
+        let account_id = parse_uuid("account_id", account_id)?;
+        self.base
+            .remove_by_lookup(NOTIFICATION_PREFERENCE_BY_ACCOUNT_TABLE, "WHERE account_id = ?", (account_id,))
+            .await
+    }
+}
@@ -5,13 +5,17 @@ use std::sync::Arc;
 // shared modules
 use domain::entities::notification_entity::NotificationEntity;
 use domain::repositories::notification_repository::NotificationRepository;
+use shared::models::paginate::Paginate;
+use shared::types::DomainResponse;
 
 // internal modules
 use crate::impl_scylla_base_repository;
 use crate::secondary::repositories::models::notification_schema::ScyllaNotificationSchema;
-use crate::secondary::repositories::scylla::scylla_base_repository::ScyllaBaseRepository;
+use crate::secondary::repositories::scylla::scylla_base_repository::{
+    ScyllaBaseRepository, paginate_entities, parse_uuid,
+};
+use crate::shared::utilities::databases::NOTIFICATION_BY_ACCOUNT_TABLE;
 
-#[allow(dead_code)]
 pub struct ScyllaNotificationRepository {
     base: ScyllaBaseRepository<NotificationEntity, ScyllaNotificationSchema>,
 }
@@ -20,9 +24,86 @@ impl ScyllaNotificationRepository {
     pub fn new(session: Arc<Session>, keyspace: &str, table_name: &str) -> Self {
         ScyllaNotificationRepository { base: ScyllaBaseRepository::new(session, keyspace, table_name) }
     }
+
+    /// The notifications of an account, newest first.
+    async fn find_by_account_id(&self, account_id: &str) -> DomainResponse<Vec<NotificationEntity>> {
+        let account_id = parse_uuid("account_id", account_id)?;
+        self.base.select_by_lookup(NOTIFICATION_BY_ACCOUNT_TABLE, "WHERE account_id = ?", (account_id,)).await
+    }
+
+    async fn find_unread(&self, account_id: &str, muted_kinds: &[String]) -> DomainResponse<Vec<NotificationEntity>> {
+        let notifications = self.find_by_account_id(account_id).await?;
+        let unread = notifications.into_iter().filter(|notification| !notification.is_read);
+        Ok(unread.filter(|notification| !muted_kinds.contains(&notification.kind)).collect())
+    }
 }
 
 impl_scylla_base_repository!(ScyllaNotificationRepository, NotificationEntity, ScyllaNotificationSchema);
 
 #[async_trait]
-impl NotificationRepository for ScyllaNotificationRepository {}
+impl NotificationRepository for ScyllaNotificationRepository {
+    async fn find_by_account_id_paginated(
+        &self,
+        account_id: &str,
+        unread_only: bool,
+        muted_kinds: &[String],
+        page: u32,
+        page_size: u32,
+    ) -> DomainResponse<(Paginate, Vec<NotificationEntity>)> {
+        let notifications = self
+            .find_by_account_id(account_id)
+            .await?
+            .into_iter()
+            .filter(|notification| !unread_only || !notification.is_read)
+            .filter(|notification| !muted_kinds.contains(&notification.kind))
+            .collect();
+
+        Ok(paginate_entities(notifications, page, page_size))
+    }
+
+    async fn find_unread_since(
+        &self,
+        account_id: &str,
+        since: i64,
+        limit: u32,
+    ) -> DomainResponse<Vec<NotificationEntity>> {
+        let account_id = parse_uuid("account_id", account_id)?;
+        let mut notifications: Vec<NotificationEntity> = self
+            .base
+            .select_by_lookup(
+                NOTIFICATION_BY_ACCOUNT_TABLE,
+                "WHERE account_id = ? AND created_at > ?",
+                (account_id, since),
+            )
+            .await?
+            .into_iter()
+            .filter(|notification| !notification.is_read)
+            .collect();
+        notifications.truncate(limit as usize);
+
+        Ok(notifications)
+    }
+
+    async fn count_unread(&self, account_id: &str, muted_kinds: &[String]) -> DomainResponse<u64> {
+        Ok(self.find_unread(account_id, muted_kinds).await?.len() as u64)
+    }
+
+    async fn mark_all_read(&self, account_id: &str, muted_kinds: &[String], read_at: i64) -> DomainResponse<usize> {
+        let notifications = self.find_unread(account_id, muted_kinds).await?;
+
+        let marked_count = notifications.len();
+        for mut notification in notifications {
+            notification.is_read = true;
+            notification.read_at = Some(read_at);
+            notification.base.updated_at = read_at;
+            self.base.save(&notification).await?;
+        }
+
+        Ok(marked_count)
+    }
+
+    async fn remove_by_account_id(&self, account_id: &str) -> DomainResponse<usize> {
+        let account_id = parse_uuid("account_id", account_id)?;
+        self.base.remove_by_lookup(NOTIFICATION_BY_ACCOUNT_TABLE, "WHERE account_id = ?", (account_id,)).await
+    }
+}
@@ -0,0 +1,63 @@
+use async_trait::async_trait;
+use scylla::client::session::Session;
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::progress_entity::ProgressEntity;
+use domain::repositories::progress_repository::ProgressRepository;
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::impl_scylla_base_repository;
+use crate::secondary::repositories::models::progress_schema::ScyllaProgressSchema;
+use crate::secondary::repositories::scylla::scylla_base_repository::{ScyllaBaseRepository, parse_uuid};
+
+pub struct ScyllaProgressRepository {
+    base: ScyllaBaseRepository<ProgressEntity, ScyllaProgressSchema>,
+}
+
+impl ScyllaProgressRepository {
+    pub fn new(session: Arc<Session>, keyspace: &str, table_name: &str) -> Self {
+        ScyllaProgressRepository { base: ScyllaBaseRepository::new(session, keyspace, table_name) }
+    }
+}
+
+impl_scylla_base_repository!(ScyllaProgressRepository, ProgressEntity, ScyllaProgressSchema);
+
+#[async_trait]
+impl ProgressRepository for ScyllaProgressRepository {
+    async fn find_by_enrollment_and_lesson(
+        &self,
+        enrollment_id: &str,
+        lesson_id: &str,
+    ) -> DomainResponse<Option<ProgressEntity>> {
+        let enrollment_id = parse_uuid("enrollment_id", enrollment_id)?;
+        let lesson_id = parse_uuid("lesson_id", lesson_id)?;
+
+        self.base
+            .select_one("WHERE enrollment_id = ? AND lesson_id = ? ALLOW FILTERING", (enrollment_id, lesson_id))
+            .await
+    }
+
+    async fn find_by_enrollment_ids(&self, enrollment_ids: &[String]) -> DomainResponse<Vec<ProgressEntity>> {
+        let enrollment_ids = enrollment_ids
+            .iter()
+            .map(|enrollment_id| parse_uuid("enrollment_id", enrollment_id).map(|id| id.to_string()))
+            .collect::<DomainResponse<Vec<String>>>()?;
+
+        // IN is only served on key columns, the progresses of the enrollments are picked from a scan
+        let progresses = self.base.select("", ()).await?;
+        Ok(progresses.into_iter().filter(|progress| enrollment_ids.contains(&progress.enrollment_id)).collect())
+    }
+
+    async fn remove_by_enrollment_ids(&self, enrollment_ids: &[String]) -> DomainResponse<usize> {
+        let mut removed_count = 0;
+        for enrollment_id in enrollment_ids {
+            let enrollment_id = parse_uuid("enrollment_id", enrollment_id)?;
+            removed_count +=
+                self.base.remove_where("WHERE enrollment_id = ? ALLOW FILTERING", (enrollment_id,)).await?;
+        }
+
+        Ok(removed_count)
+    }
+}
@@ -3,8 +3,11 @@ use scylla::client::session::Session;
 use std::sync::Arc;
 
 // shared modules
-use domain::{entities::project_entity::ProjectEntity, repositories::project_repository::ProjectRepository};
-use shared::types::DomainResponse;
+use domain::{
+    entities::project_entity::ProjectEntity,
+    repositories::{base_repository::BaseRepository, project_repository::ProjectRepository},
+};
+use shared::{models::failure::Failure, types::DomainResponse};
 
 // internal modules
 use crate::{
@@ -12,9 +15,9 @@ use crate::{
     secondary::repositories::{
         models::project_schema::ScyllaProjectSchema, scylla::scylla_base_repository::ScyllaBaseRepository,
     },
+    shared::utilities::databases::PROJECT_BY_NAME_TABLE,
 };
 
-#[allow(dead_code)]
 pub struct ScyllaProjectRepository {
     base: ScyllaBaseRepository<ProjectEntity, ScyllaProjectSchema>,
 }
@@ -29,21 +32,44 @@ impl_scylla_base_repository!(ScyllaProjectRepository, ProjectEntity, ScyllaProje
 
 #[async_trait]
 impl ProjectRepository for ScyllaProjectRepository {
-    async fn find_by_name(&self, _name: &str) -> DomainResponse<Option<ProjectEntity>> {
-        todo!()
+    async fn find_by_name(&self, name: &str) -> DomainResponse<Option<ProjectEntity>> {
+        let projects = self.base.select_by_lookup(PROJECT_BY_NAME_TABLE, "WHERE name = ?", (name,)).await?;
+        Ok(projects.into_iter().next())
     }
 
     async fn update_project_partial(
         &self,
-        _id: &str,
-        _cover: Option<String>,
-        _name: Option<String>,
-        _description: Option<String>,
-        _link: Option<String>,
-        _github: Option<String>,
-        _tags: Option<Vec<String>>,
-        _markdown: Option<String>,
+        id: &str,
+        cover: Option<String>,
+        name: Option<String>,
+        description: Option<String>,
+        link: Option<String>,
+        github: Option<String>,
+        tags: Option<Vec<String>>,
+        markdown: Option<String>,
     ) -> DomainResponse<ProjectEntity> {
-        unimplemented!()
+        if cover.is_none()
+            && name.is_none()
+            && description.is_none()
+            && link.is_none()
+            && github.is_none()
+            && tags.is_none()
+            && markdown.is_none()
+        {
+            return Err(Failure::BadRequest("No fields provided for update".to_string()));
+        }
+
+        let mut project =
+            self.base.find(id).await?.ok_or_else(|| Failure::NotFound(format!("Project with id {} not found", id)))?;
+
+        project.cover = cover.unwrap_or(project.cover);
+        project.name = name.unwrap_or(project.name);
+        project.description = description.unwrap_or(project.description);
+        project.link = link.unwrap_or(project.link);
+        project.github = github.unwrap_or(project.github);
+        project.tags = tags.unwrap_or(project.tags);
+        project.markdown = markdown.unwrap_or(project.markdown);
+
+        self.base.save(&project).await
     }
 }
@@ -10,9 +10,9 @@ use shared::types::DomainResponse;
 // internal modules
 use crate::impl_scylla_base_repository;
 use crate::secondary::repositories::models::provider_schema::ScyllaProviderSchema;
-use crate::secondary::repositories::scylla::scylla_base_repository::ScyllaBaseRepository;
+use crate::secondary::repositories::scylla::scylla_base_repository::{ScyllaBaseRepository, parse_uuid};
+use crate::shared::utilities::databases::PROVIDER_BY_ACCOUNT_TABLE;
 
-#[allow(dead_code)]
 pub struct ScyllaProviderRepository {
     base: ScyllaBaseRepository<ProviderEntity, ScyllaProviderSchema>,
 }
@@ -27,7 +27,13 @@ impl_scylla_base_repository!(ScyllaProviderRepository, ProviderEntity, ScyllaPro
 
 #[async_trait]
 impl ProviderRepository for ScyllaProviderRepository {
-    async fn find_by_account_id(&self, _account_id: &str) -> DomainResponse<Vec<ProviderEntity>> {
-        todo!()
+    async fn find_by_account_id(&self, account_id: &str) -> DomainResponse<Vec<ProviderEntity>> {
+        let account_id = parse_uuid("account_id", account_id)?;
+        self.base.select_by_lookup(PROVIDER_BY_ACCOUNT_TABLE, "WHERE account_id = ?", (account_id,)).await
+    }
+
+    async fn remove_by_account_id(&self, account_id: &str) -> DomainResponse<usize> {
+        let account_id = parse_uuid("ID", account_id)?;
+        self.base.remove_by_lookup(PROVIDER_BY_ACCOUNT_TABLE, "WHERE account_id = ?", (account_id,)).await
     }
 }
@@ -0,0 +1,115 @@
+use async_trait::async_trait;
+use scylla::client::session::Session;
+use scylla::value::CqlValue;
+use std::sync::Arc;
+use uuid::Uuid;
+
+// shared modules
+use domain::entities::review_entity::ReviewEntity;
+use domain::repositories::review_repository::ReviewRepository;
+use shared::models::paginate::Paginate;
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::impl_scylla_base_repository;
+use crate::secondary::repositories::models::review_schema::ScyllaReviewSchema;
+use crate::secondary::repositories::scylla::scylla_base_repository::{
+    EntitySchema, Lookup, ScyllaBaseRepository, paginate_entities, parse_uuid,
+};
+use crate::shared::utilities::databases::REVIEW_BY_COURSE_ACCOUNT_TABLE;
+
+pub struct ScyllaReviewRepository {
+    base: ScyllaBaseRepository<ReviewEntity, ScyllaReviewSchema>,
+}
+
+impl ScyllaReviewRepository {
+    pub fn new(session: Arc<Session>, keyspace: &str, table_name: &str) -> Self {
+        ScyllaReviewRepository { base: ScyllaBaseRepository::new(session, keyspace, table_name) }
+    }
+
+    /// The claim that keeps an account to one review of a course
+    fn claim(schema: &ScyllaReviewSchema) -> Lookup {
+        Lookup::new(
+            REVIEW_BY_COURSE_ACCOUNT_TABLE,
+            vec![("course_id", CqlValue::Uuid(schema.course_id)), ("account_id", CqlValue::Uuid(schema.account_id))],
+        )
+    }
+
+    fn newest_first(mut reviews: Vec<ReviewEntity>, page: u32, page_size: u32) -> (Paginate, Vec<ReviewEntity>) {
+        reviews.sort_by_key(|review| std::cmp::Reverse(review.base.created_at));
+        paginate_entities(reviews, page, page_size)
+    }
+}
+
+impl_scylla_base_repository!(ScyllaReviewRepository, ReviewEntity, ScyllaReviewSchema);
+
+#[async_trait]
+impl ReviewRepository for ScyllaReviewRepository {
+    async fn find_by_course_and_account(
+        &self,
+        course_id: &str,
+        account_id: &str,
+    ) -> DomainResponse<Option<ReviewEntity>> {
+        let course_id = parse_uuid("course_id", course_id)?;
+        let account_id = parse_uuid("account_id", account_id)?;
+
+        self.base.select_one("WHERE course_id = ? AND account_id = ? ALLOW FILTERING", (course_id, account_id)).await
+    }
+
+    async fn create_if_absent(&self, review: &ReviewEntity) -> DomainResponse<Option<ReviewEntity>> {
+        let mut schema = ScyllaReviewSchema::from_entity(review);
+        let id = Uuid::now_v7();
+        schema.set_id(id);
+
+        if !self.base.claim(&Self::claim(&schema), id).await? {
+            return Ok(None);
+        }
+
+        self.base.save(&schema.to_entity()).await.map(Some)
+    }
+
+    async fn find_by_course_id_paginated(
+        &self,
+        course_id: &str,
+        status: &str,
+        page: u32,
+        page_size: u32,
+    ) -> DomainResponse<(Paginate, Vec<ReviewEntity>)> {
+        let course_id = parse_uuid("course_id", course_id)?;
+        let reviews =
+            self.base.select("WHERE course_id = ? AND status = ? ALLOW FILTERING", (course_id, status)).await?;
+
+        Ok(Self::newest_first(reviews, page, page_size))
+    }
+
+    async fn find_by_status_paginated(
+        &self,
+        status: &str,
+        page: u32,
+        page_size: u32,
+    ) -> DomainResponse<(Paginate, Vec<ReviewEntity>)> {
+        let reviews = self.base.select("WHERE status = ? ALLOW FILTERING", (status,)).await?;
+        Ok(Self::newest_first(reviews, page, page_size))
+    }
+
+    async fn find_by_account_id(&self, account_id: &str) -> DomainResponse<Vec<ReviewEntity>> {
+        let account_id = parse_uuid("account_id", account_id)?;
+        let mut reviews = self.base.select("WHERE account_id = ? ALLOW FILTERING", (account_id,)).await?;
+        reviews.sort_by_key(|review| std::cmp::Reverse(review.base.created_at));
+
+        Ok(reviews)
+    }
+
+    async fn remove_by_account_id(&self, account_id: &str) -> DomainResponse<usize> {
+        let account_id = parse_uuid("account_id", account_id)?;
+        let reviews = self.base.find_and_remove_where("WHERE account_id = ? ALLOW FILTERING", (account_id,)).await?;
+        for review in &reviews {
+            let schema = ScyllaReviewSchema::from_entity(review);
+            if let Some(id) = schema.id {
+                self.base.release(&Self::claim(&schema), id).await?;
+            }
+        }
+
+        Ok(reviews.len())
+    }
+}
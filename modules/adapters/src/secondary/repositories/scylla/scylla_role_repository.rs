@@ -5,14 +5,15 @@ use std::sync::Arc;
 // shared modules
 use domain::entities::role_entity::RoleEntity;
 use domain::repositories::role_repository::RoleRepository;
+use shared::models::failure::Failure;
 use shared::types::DomainResponse;
 
 // internal modules
 use crate::impl_scylla_base_repository;
 use crate::secondary::repositories::models::role_schema::ScyllaRoleSchema;
-use crate::secondary::repositories::scylla::scylla_base_repository::ScyllaBaseRepository;
+use crate::secondary::repositories::scylla::scylla_base_repository::{ScyllaBaseRepository, parse_uuid};
+use crate::shared::utilities::databases::{ROLE_BY_ACCOUNT_TABLE, ROLE_BY_NAME_TABLE};
 
-#[allow(dead_code)]
 pub struct ScyllaRoleRepository {
     base: ScyllaBaseRepository<RoleEntity, ScyllaRoleSchema>,
 }
@@ -27,15 +28,38 @@ impl_scylla_base_repository!(ScyllaRoleRepository, RoleEntity, ScyllaRoleSchema)
 
 #[async_trait]
 impl RoleRepository for ScyllaRoleRepository {
-    async fn find_by_account_id(&self, _account_id: &str) -> DomainResponse<Option<RoleEntity>> {
-        todo!()
+    async fn find_by_account_id(&self, account_id: &str) -> DomainResponse<Option<RoleEntity>> {
+        let account_id = parse_uuid("account_id", account_id)?;
+        let roles = self.base.select_by_lookup(ROLE_BY_ACCOUNT_TABLE, "WHERE account_id = ?", (account_id,)).await?;
+        Ok(roles.into_iter().next())
     }
 
     async fn find_and_update_role_by_account_id(
         &self,
-        _account_id: &str,
-        _role_name: &str,
+        account_id: &str,
+        role_name: &str,
     ) -> DomainResponse<RoleEntity> {
-        todo!()
+        let mut role = self
+            .find_by_account_id(account_id)
+            .await?
+            .ok_or_else(|| Failure::NotFound(format!("Role not found for account_id: {}", account_id)))?;
+
+        role.role_name = role_name.to_string();
+        self.base.save(&role).await
+    }
+
+    async fn find_account_ids_by_role(&self, role_name: &str) -> DomainResponse<Vec<String>> {
+        let roles = self.base.select_by_bucketed_lookup(ROLE_BY_NAME_TABLE, "role_name", role_name).await?;
+
+        let mut account_ids: Vec<String> = roles.into_iter().map(|role| role.account_id).collect();
+        account_ids.sort();
+        account_ids.dedup();
+
+        Ok(account_ids)
+    }
+
+    async fn remove_by_account_id(&self, account_id: &str) -> DomainResponse<usize> {
+        let account_id = parse_uuid("ID", account_id)?;
+        self.base.remove_by_lookup(ROLE_BY_ACCOUNT_TABLE, "WHERE account_id = ?", (account_id,)).await
     }
 }
@@ -10,9 +10,9 @@ use shared::types::DomainResponse;
 // internal modules
 use crate::impl_scylla_base_repository;
 use crate::secondary::repositories::models::session_schema::ScyllaSessionSchema;
-use crate::secondary::repositories::scylla::scylla_base_repository::ScyllaBaseRepository;
+use crate::secondary::repositories::scylla::scylla_base_repository::{ScyllaBaseRepository, parse_uuid};
+use crate::shared::utilities::databases::{SESSION_BY_ACCOUNT_TABLE, SESSION_BY_JTI_TABLE};
 
-#[allow(dead_code)]
 pub struct ScyllaSessionRepository {
     base: ScyllaBaseRepository<SessionEntity, ScyllaSessionSchema>,
 }
@@ -27,11 +27,41 @@ impl_scylla_base_repository!(ScyllaSessionRepository, SessionEntity, ScyllaSessi
 
 #[async_trait]
 impl SessionRepository for ScyllaSessionRepository {
-    async fn remove_by_account_id(&self, _account_id: &str) -> DomainResponse<()> {
-        todo!()
+    async fn remove_by_account_id(&self, account_id: &str) -> DomainResponse<()> {
+        let account_id = parse_uuid("ID", account_id)?;
+        self.base.remove_by_lookup(SESSION_BY_ACCOUNT_TABLE, "WHERE account_id = ?", (account_id,)).await?;
+
+        Ok(())
+    }
+
+    async fn find_by_jti(&self, jti: &str) -> DomainResponse<Option<SessionEntity>> {
+        let sessions = self.base.select_by_lookup(SESSION_BY_JTI_TABLE, "WHERE jti = ?", (jti,)).await?;
+        Ok(sessions.into_iter().next())
+    }
+
+    async fn find_with_device_token(&self, account_id: &str) -> DomainResponse<Vec<SessionEntity>> {
+        let now = chrono::Utc::now().timestamp();
+        let sessions = self.find_by_account_id(account_id).await?;
+
+        Ok(sessions.into_iter().filter(|session| session.device_token.is_some() && session.expires_at > now).collect())
+    }
+
+    async fn clear_device_token(&self, device_token: &str) -> DomainResponse<usize> {
+        let sessions = self.base.select("WHERE device_token = ? ALLOW FILTERING", (device_token,)).await?;
+
+        let cleared_count = sessions.len();
+        for mut session in sessions {
+            session.device_token = None;
+            session.base.updated_at = chrono::Utc::now().timestamp();
+            self.base.save(&session).await?;
+        }
+
+        Ok(cleared_count)
     }
 
-    async fn find_by_jti(&self, _jti: &str) -> DomainResponse<Option<SessionEntity>> {
-        todo!()
+    async fn find_by_account_id(&self, account_id: &str) -> DomainResponse<Vec<SessionEntity>> {
+        let account_id = parse_uuid("ID", account_id)?;
+        // The lookup table is clustered newest first
+        self.base.select_by_lookup(SESSION_BY_ACCOUNT_TABLE, "WHERE account_id = ?", (account_id,)).await
     }
 }
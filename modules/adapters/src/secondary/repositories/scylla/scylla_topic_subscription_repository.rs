@@ -0,0 +1,50 @@
+use async_trait::async_trait;
+use scylla::client::session::Session;
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::topic_subscription_entity::TopicSubscriptionEntity;
+use domain::repositories::topic_subscription_repository::TopicSubscriptionRepository;
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::impl_scylla_base_repository;
+use crate::secondary::repositories::models::topic_subscription_schema::ScyllaTopicSubscriptionSchema;
+use crate::secondary::repositories::scylla::scylla_base_repository::{ScyllaBaseRepository, parse_uuid};
+
+pub struct ScyllaTopicSubscriptionRepository {
+    base: ScyllaBaseRepository<TopicSubscriptionEntity, ScyllaTopicSubscriptionSchema>,
+}
+
+impl ScyllaTopicSubscriptionRepository {
+    pub fn new(session: Arc<Session>, keyspace: &str, table_name: &str) -> Self {
+        ScyllaTopicSubscriptionRepository { base: ScyllaBaseRepository::new(session, keyspace, table_name) }
+    }
+}
+
+impl_scylla_base_repository!(ScyllaTopicSubscriptionRepository, TopicSubscriptionEntity, ScyllaTopicSubscriptionSchema);
+
+#[async_trait]
+impl TopicSubscriptionRepository for ScyllaTopicSubscriptionRepository {
+    async fn find_by_account_id(&self, account_id: &str) -> DomainResponse<Vec<TopicSubscriptionEntity>> {
+        let account_id = parse_uuid("account_id", account_id)?;
+        let mut subscriptions = self.base.select("WHERE account_id = ? ALLOW FILTERING", (account_id,)).await?;
+        subscriptions.sort_by(|a, b| a.topic.cmp(&b.topic));
+
+        Ok(subscriptions)
+    }
+
+    async fn find_by_account_and_topic(
+        &self,
+        account_id: &str,
+        topic: &str,
+    ) -> DomainResponse<Option<TopicSubscriptionEntity>> {
+        let account_id = parse_uuid("account_id", account_id)?;
+        self.base.select_one("WHERE account_id = ? AND topic = ? ALLOW FILTERING", (account_id, topic)).await
+    }
+
+    async fn remove_by_account_id(&self, account_id: &str) -> DomainResponse<usize> {
+        let account_id = parse_uuid("account_id", account_id)?;
+        self.base.remove_where("WHERE account_id = ? ALLOW FILTERING", (account_id,)).await
+    }
+}
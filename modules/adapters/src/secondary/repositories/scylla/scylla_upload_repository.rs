@@ -0,0 +1,51 @@
+use async_trait::async_trait;
+use scylla::client::session::Session;
+use std::sync::Arc;
+
+// shared modules
+use domain::entities::upload_entity::UploadEntity;
+use domain::repositories::upload_repository::UploadRepository;
+use domain::values::upload_status::UploadStatus;
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::impl_scylla_base_repository;
+use crate::secondary::repositories::models::upload_schema::ScyllaUploadSchema;
+use crate::secondary::repositories::scylla::scylla_base_repository::{ScyllaBaseRepository, parse_uuid};
+
+pub struct ScyllaUploadRepository {
+    base: ScyllaBaseRepository<UploadEntity, ScyllaUploadSchema>,
+}
+
+impl ScyllaUploadRepository {
+    pub fn new(session: Arc<Session>, keyspace: &str, table_name: &str) -> Self {
+        ScyllaUploadRepository { base: ScyllaBaseRepository::new(session, keyspace, table_name) }
+    }
+}
+
+impl_scylla_base_repository!(ScyllaUploadRepository, UploadEntity, ScyllaUploadSchema);
+
+#[async_trait]
+impl UploadRepository for ScyllaUploadRepository {
+    async fn find_expired(&self, expires_before: i64, limit: u32) -> DomainResponse<Vec<UploadEntity>> {
+        let mut uploads = self
+            .base
+            .select(
+                "WHERE status = ? AND expires_at <= ? ALLOW FILTERING",
+                (UploadStatus::Pending.as_str(), expires_before),
+            )
+            .await?;
+        uploads.sort_by_key(|upload| upload.expires_at);
+        uploads.truncate(limit as usize);
+
+        Ok(uploads)
+    }
+
+    async fn find_by_account_id(&self, account_id: &str) -> DomainResponse<Vec<UploadEntity>> {
+        let account_id = parse_uuid("account_id", account_id)?;
+        let mut uploads = self.base.select("WHERE account_id = ? ALLOW FILTERING", (account_id,)).await?;
+        uploads.sort_by_key(|upload| std::cmp::Reverse(upload.base.created_at));
+
+        Ok(uploads)
+    }
+}
@@ -1,34 +1,91 @@
+use scylla::client::session::Session;
 use std::sync::Arc;
 
 // shared modules
+use domain::entities::{
+    account_entity::AccountEntity, blog_entity::BlogEntity, category_entity::CategoryEntity,
+    enrollment_entity::EnrollmentEntity, notification_entity::NotificationEntity,
+    notification_preference_entity::NotificationPreferenceEntity, project_entity::ProjectEntity,
+    provider_entity::ProviderEntity, role_entity::RoleEntity, session_entity::SessionEntity,
+};
 use domain::repositories::{
-    account_repository::AccountRepository, blog_repository::BlogRepository, category_repository::CategoryRepository,
-    experience_repository::ExperienceRepository, notification_repository::NotificationRepository,
-    project_repository::ProjectRepository, provider_repository::ProviderRepository, role_repository::RoleRepository,
-    session_repository::SessionRepository,
+    account_repository::AccountRepository, audit_event_repository::AuditEventRepository,
+    blog_repository::BlogRepository, blog_revision_repository::BlogRevisionRepository,
+    blog_star_repository::BlogStarRepository, bookmark_repository::BookmarkRepository,
+    category_repository::CategoryRepository, chapter_repository::ChapterRepository,
+    comment_ban_repository::CommentBanRepository, comment_repository::CommentRepository,
+    course_repository::CourseRepository, data_export_repository::DataExportRepository,
+    enrollment_repository::EnrollmentRepository, experience_repository::ExperienceRepository,
+    lesson_repository::LessonRepository, media_asset_repository::MediaAssetRepository,
+    notification_preference_repository::NotificationPreferenceRepository,
+    notification_repository::NotificationRepository, progress_repository::ProgressRepository,
+    project_repository::ProjectRepository, provider_repository::ProviderRepository,
+    review_repository::ReviewRepository, role_repository::RoleRepository, session_repository::SessionRepository,
+    topic_subscription_repository::TopicSubscriptionRepository, upload_repository::UploadRepository,
 };
 use shared::configs::APP_CONFIG;
 
 // internal modules
 use crate::secondary::repositories::{
+    models::{
+        account_schema::ScyllaAccountSchema, blog_schema::ScyllaBlogSchema, category_schema::ScyllaCategorySchema,
+        enrollment_schema::ScyllaEnrollmentSchema, notification_preference_schema::ScyllaNotificationPreferenceSchema,
+        notification_schema::ScyllaNotificationSchema, project_schema::ScyllaProjectSchema,
+        provider_schema::ScyllaProviderSchema, role_schema::ScyllaRoleSchema, session_schema::ScyllaSessionSchema,
+    },
     mongodb::{
-        mongo_account_repository::MongoAccountRepository, mongo_blog_repository::MongoBlogRepository,
-        mongo_category_repository::MongoCategoryRepository, mongo_experience_repository::MongoExperienceRepository,
-        mongo_notification_repository::MongoNotificationRepository, mongo_project_repository::MongoProjectRepository,
-        mongo_provider_repository::MongoProviderRepository, mongo_role_repository::MongoRoleRepository,
+        mongo_account_repository::MongoAccountRepository, mongo_audit_event_repository::MongoAuditEventRepository,
+        mongo_blog_repository::MongoBlogRepository, mongo_blog_revision_repository::MongoBlogRevisionRepository,
+        mongo_blog_star_repository::MongoBlogStarRepository, mongo_bookmark_repository::MongoBookmarkRepository,
+        mongo_category_repository::MongoCategoryRepository, mongo_chapter_repository::MongoChapterRepository,
+        mongo_comment_ban_repository::MongoCommentBanRepository, mongo_comment_repository::MongoCommentRepository,
+        mongo_course_repository::MongoCourseRepository, mongo_data_export_repository::MongoDataExportRepository,
+        mongo_enrollment_repository::MongoEnrollmentRepository, mongo_experience_repository::MongoExperienceRepository,
+        mongo_lesson_repository::MongoLessonRepository, mongo_media_asset_repository::MongoMediaAssetRepository,
+        mongo_notification_preference_repository::MongoNotificationPreferenceRepository,
+        mongo_notification_repository::MongoNotificationRepository, mongo_progress_repository::MongoProgressRepository,
+        mongo_project_repository::MongoProjectRepository, mongo_provider_repository::MongoProviderRepository,
+        mongo_review_repository::MongoReviewRepository, mongo_role_repository::MongoRoleRepository,
         mongo_session_repository::MongoSessionRepository,
+        mongo_topic_subscription_repository::MongoTopicSubscriptionRepository,
+        mongo_upload_repository::MongoUploadRepository,
     },
     scylla::{
-        scylla_account_repository::ScyllaAccountRepository, scylla_blog_repository::ScyllaBlogRepository,
-        scylla_category_repository::ScyllaCategoryRepository, scylla_experience_repository::ScyllaExperienceRepository,
+        scylla_account_repository::ScyllaAccountRepository,
+        scylla_audit_event_repository::ScyllaAuditEventRepository,
+        scylla_base_repository::{EntitySchema, ScyllaBaseRepository},
+        scylla_blog_repository::ScyllaBlogRepository,
+        scylla_blog_revision_repository::ScyllaBlogRevisionRepository,
+        scylla_blog_star_repository::ScyllaBlogStarRepository,
+        scylla_bookmark_repository::ScyllaBookmarkRepository,
+        scylla_category_repository::ScyllaCategoryRepository,
+        scylla_chapter_repository::ScyllaChapterRepository,
+        scylla_comment_ban_repository::ScyllaCommentBanRepository,
+        scylla_comment_repository::ScyllaCommentRepository,
+        scylla_course_repository::ScyllaCourseRepository,
+        scylla_data_export_repository::ScyllaDataExportRepository,
+        scylla_enrollment_repository::ScyllaEnrollmentRepository,
+        scylla_experience_repository::ScyllaExperienceRepository,
+        scylla_lesson_repository::ScyllaLessonRepository,
+        scylla_media_asset_repository::ScyllaMediaAssetRepository,
+        scylla_notification_preference_repository::ScyllaNotificationPreferenceRepository,
         scylla_notification_repository::ScyllaNotificationRepository,
-        scylla_project_repository::ScyllaProjectRepository, scylla_provider_repository::ScyllaProviderRepository,
-        scylla_role_repository::ScyllaRoleRepository, scylla_session_repository::ScyllaSessionRepository,
+        scylla_progress_repository::ScyllaProgressRepository,
+        scylla_project_repository::ScyllaProjectRepository,
+        scylla_provider_repository::ScyllaProviderRepository,
+        scylla_review_repository::ScyllaReviewRepository,
+        scylla_role_repository::ScyllaRoleRepository,
+        scylla_session_repository::ScyllaSessionRepository,
+        scylla_topic_subscription_repository::ScyllaTopicSubscriptionRepository,
+        scylla_upload_repository::ScyllaUploadRepository,
     },
 };
 use crate::shared::utilities::databases::{
-    ACCOUNT_TABLE, BLOG_TABLE, CATEGORY_TABLE, DatabaseType, EXPERIENCE_TABLE, NOTIFICATION_TABLE, PROJECT_TABLE,
-    PROVIDER_TABLE, ROLE_TABLE, SESSION_TABLE, mongo_client, scylla_session,
+    ACCOUNT_TABLE, AUDIT_EVENT_TABLE, BLOG_REVISION_TABLE, BLOG_STAR_TABLE, BLOG_TABLE, BOOKMARK_TABLE, CATEGORY_TABLE,
+    CHAPTER_TABLE, COMMENT_BAN_TABLE, COMMENT_TABLE, COURSE_TABLE, DATA_EXPORT_TABLE, DatabaseType, ENROLLMENT_TABLE,
+    EXPERIENCE_TABLE, LESSON_TABLE, MEDIA_ASSET_TABLE, NOTIFICATION_PREFERENCE_TABLE, NOTIFICATION_TABLE,
+    PROGRESS_TABLE, PROJECT_TABLE, PROVIDER_TABLE, REVIEW_TABLE, ROLE_TABLE, SESSION_TABLE, TOPIC_SUBSCRIPTION_TABLE,
+    UPLOAD_TABLE, mongo_client, scylla_session,
 };
 
 pub trait RepositoryModule: Send + Sync {
@@ -40,7 +97,24 @@ pub trait RepositoryModule: Send + Sync {
     fn get_category_repository(&self) -> Arc<dyn CategoryRepository>;
     fn get_notification_repository(&self) -> Arc<dyn NotificationRepository>;
     fn get_blog_repository(&self) -> Arc<dyn BlogRepository>;
+    fn get_blog_revision_repository(&self) -> Arc<dyn BlogRevisionRepository>;
+    fn get_blog_star_repository(&self) -> Arc<dyn BlogStarRepository>;
+    fn get_comment_repository(&self) -> Arc<dyn CommentRepository>;
+    fn get_comment_ban_repository(&self) -> Arc<dyn CommentBanRepository>;
     fn get_project_repository(&self) -> Arc<dyn ProjectRepository>;
+    fn get_course_repository(&self) -> Arc<dyn CourseRepository>;
+    fn get_chapter_repository(&self) -> Arc<dyn ChapterRepository>;
+    fn get_lesson_repository(&self) -> Arc<dyn LessonRepository>;
+    fn get_enrollment_repository(&self) -> Arc<dyn EnrollmentRepository>;
+    fn get_progress_repository(&self) -> Arc<dyn ProgressRepository>;
+    fn get_bookmark_repository(&self) -> Arc<dyn BookmarkRepository>;
+    fn get_review_repository(&self) -> Arc<dyn ReviewRepository>;
+    fn get_topic_subscription_repository(&self) -> Arc<dyn TopicSubscriptionRepository>;
+    fn get_notification_preference_repository(&self) -> Arc<dyn NotificationPreferenceRepository>;
+    fn get_media_asset_repository(&self) -> Arc<dyn MediaAssetRepository>;
+    fn get_upload_repository(&self) -> Arc<dyn UploadRepository>;
+    fn get_data_export_repository(&self) -> Arc<dyn DataExportRepository>;
+    fn get_audit_event_repository(&self) -> Arc<dyn AuditEventRepository>;
 }
 
 #[macro_export]
@@ -71,7 +145,24 @@ struct MongoRepositoryModule {
     category_repository: Arc<dyn CategoryRepository>,
     notification_repository: Arc<dyn NotificationRepository>,
     blog_repository: Arc<dyn BlogRepository>,
+    blog_revision_repository: Arc<dyn BlogRevisionRepository>,
+    blog_star_repository: Arc<dyn BlogStarRepository>,
+    comment_repository: Arc<dyn CommentRepository>,
+    comment_ban_repository: Arc<dyn CommentBanRepository>,
     project_repository: Arc<dyn ProjectRepository>,
+    course_repository: Arc<dyn CourseRepository>,
+    chapter_repository: Arc<dyn ChapterRepository>,
+    lesson_repository: Arc<dyn LessonRepository>,
+    enrollment_repository: Arc<dyn EnrollmentRepository>,
+    progress_repository: Arc<dyn ProgressRepository>,
+    bookmark_repository: Arc<dyn BookmarkRepository>,
+    review_repository: Arc<dyn ReviewRepository>,
+    topic_subscription_repository: Arc<dyn TopicSubscriptionRepository>,
+    notification_preference_repository: Arc<dyn NotificationPreferenceRepository>,
+    media_asset_repository: Arc<dyn MediaAssetRepository>,
+    upload_repository: Arc<dyn UploadRepository>,
+    data_export_repository: Arc<dyn DataExportRepository>,
+    audit_event_repository: Arc<dyn AuditEventRepository>,
 }
 
 impl MongoRepositoryModule {
@@ -89,7 +180,38 @@ impl MongoRepositoryModule {
                 db.collection(NOTIFICATION_TABLE),
             ))),
             blog_repository: Arc::new(MongoBlogRepository::new(Arc::new(db.collection(BLOG_TABLE)))),
+            blog_revision_repository: Arc::new(MongoBlogRevisionRepository::new(Arc::new(
+                db.collection(BLOG_REVISION_TABLE),
+            ))),
+            blog_star_repository: Arc::new(MongoBlogStarRepository::new(Arc::new(db.collection(BLOG_STAR_TABLE)))),
+            comment_repository: Arc::new(MongoCommentRepository::new(Arc::new(db.collection(COMMENT_TABLE)))),
+            comment_ban_repository: Arc::new(MongoCommentBanRepository::new(Arc::new(
+                db.collection(COMMENT_BAN_TABLE),
+            ))),
             project_repository: Arc::new(MongoProjectRepository::new(Arc::new(db.collection(PROJECT_TABLE)))),
+            course_repository: Arc::new(MongoCourseRepository::new(Arc::new(db.collection(COURSE_TABLE)))),
+            chapter_repository: Arc::new(MongoChapterRepository::new(Arc::new(db.collection(CHAPTER_TABLE)))),
+            lesson_repository: Arc::new(MongoLessonRepository::new(Arc::new(db.collection(LESSON_TABLE)))),
+            enrollment_repository: Arc::new(MongoEnrollmentRepository::new(Arc::new(db.collection(ENROLLMENT_TABLE)))),
+            progress_repository: Arc::new(MongoProgressRepository::new(Arc::new(db.collection(PROGRESS_TABLE)))),
+            bookmark_repository: Arc::new(MongoBookmarkRepository::new(Arc::new(db.collection(BOOKMARK_TABLE)))),
+            review_repository: Arc::new(MongoReviewRepository::new(Arc::new(db.collection(REVIEW_TABLE)))),
+            topic_subscription_repository: Arc::new(MongoTopicSubscriptionRepository::new(Arc::new(
+                db.collection(TOPIC_SUBSCRIPTION_TABLE),
+            ))),
+            notification_preference_repository: Arc::new(MongoNotificationPreferenceRepository::new(Arc::new(
+                db.collection(NOTIFICATION_PREFERENCE_TABLE),
+            ))),
+            media_asset_repository: Arc::new(MongoMediaAssetRepository::new(Arc::new(
+                db.collection(MEDIA_ASSET_TABLE),
+            ))),
+            upload_repository: Arc::new(MongoUploadRepository::new(Arc::new(db.collection(UPLOAD_TABLE)))),
+            data_export_repository: Arc::new(MongoDataExportRepository::new(Arc::new(
+                db.collection(DATA_EXPORT_TABLE),
+            ))),
+            audit_event_repository: Arc::new(MongoAuditEventRepository::new(Arc::new(
+                db.collection(AUDIT_EVENT_TABLE),
+            ))),
         }
     }
 }
@@ -104,7 +226,24 @@ impl_repository_module!(
     get_category_repository -> CategoryRepository: category_repository,
     get_notification_repository -> NotificationRepository: notification_repository,
     get_blog_repository -> BlogRepository: blog_repository,
+    get_blog_revision_repository -> BlogRevisionRepository: blog_revision_repository,
+    get_blog_star_repository -> BlogStarRepository: blog_star_repository,
+    get_comment_repository -> CommentRepository: comment_repository,
+    get_comment_ban_repository -> CommentBanRepository: comment_ban_repository,
     get_project_repository -> ProjectRepository: project_repository,
+    get_course_repository -> CourseRepository: course_repository,
+    get_chapter_repository -> ChapterRepository: chapter_repository,
+    get_lesson_repository -> LessonRepository: lesson_repository,
+    get_enrollment_repository -> EnrollmentRepository: enrollment_repository,
+    get_progress_repository -> ProgressRepository: progress_repository,
+    get_bookmark_repository -> BookmarkRepository: bookmark_repository,
+    get_review_repository -> ReviewRepository: review_repository,
+    get_topic_subscription_repository -> TopicSubscriptionRepository: topic_subscription_repository,
+    get_notification_preference_repository -> NotificationPreferenceRepository: notification_preference_repository,
+    get_media_asset_repository -> MediaAssetRepository: media_asset_repository,
+    get_upload_repository -> UploadRepository: upload_repository,
+    get_data_export_repository -> DataExportRepository: data_export_repository,
+    get_audit_event_repository -> AuditEventRepository: audit_event_repository,
 );
 
 // Scylla Implementation
@@ -117,7 +256,24 @@ struct ScyllaRepositoryModule {
     category_repository: Arc<dyn CategoryRepository>,
     notification_repository: Arc<dyn NotificationRepository>,
     blog_repository: Arc<dyn BlogRepository>,
+    blog_revision_repository: Arc<dyn BlogRevisionRepository>,
+    blog_star_repository: Arc<dyn BlogStarRepository>,
+    comment_repository: Arc<dyn CommentRepository>,
+    comment_ban_repository: Arc<dyn CommentBanRepository>,
     project_repository: Arc<dyn ProjectRepository>,
+    course_repository: Arc<dyn CourseRepository>,
+    chapter_repository: Arc<dyn ChapterRepository>,
+    lesson_repository: Arc<dyn LessonRepository>,
+    enrollment_repository: Arc<dyn EnrollmentRepository>,
+    progress_repository: Arc<dyn ProgressRepository>,
+    bookmark_repository: Arc<dyn BookmarkRepository>,
+    review_repository: Arc<dyn ReviewRepository>,
+    topic_subscription_repository: Arc<dyn TopicSubscriptionRepository>,
+    notification_preference_repository: Arc<dyn NotificationPreferenceRepository>,
+    media_asset_repository: Arc<dyn MediaAssetRepository>,
+    upload_repository: Arc<dyn UploadRepository>,
+    data_export_repository: Arc<dyn DataExportRepository>,
+    audit_event_repository: Arc<dyn AuditEventRepository>,
 }
 
 impl ScyllaRepositoryModule {
@@ -125,6 +281,23 @@ impl ScyllaRepositoryModule {
         let session = scylla_session().await;
         let keyspace = APP_CONFIG.database.scylla_keyspace.clone();
 
+        // The lookup tables must point at every existing row before the repositories read through them
+        backfill_lookups::<AccountEntity, ScyllaAccountSchema>(&session, &keyspace, ACCOUNT_TABLE).await;
+        backfill_lookups::<SessionEntity, ScyllaSessionSchema>(&session, &keyspace, SESSION_TABLE).await;
+        backfill_lookups::<CategoryEntity, ScyllaCategorySchema>(&session, &keyspace, CATEGORY_TABLE).await;
+        backfill_lookups::<BlogEntity, ScyllaBlogSchema>(&session, &keyspace, BLOG_TABLE).await;
+        backfill_lookups::<NotificationEntity, ScyllaNotificationSchema>(&session, &keyspace, NOTIFICATION_TABLE).await;
+        backfill_lookups::<ProviderEntity, ScyllaProviderSchema>(&session, &keyspace, PROVIDER_TABLE).await;
+        backfill_lookups::<RoleEntity, ScyllaRoleSchema>(&session, &keyspace, ROLE_TABLE).await;
+        backfill_lookups::<ProjectEntity, ScyllaProjectSchema>(&session, &keyspace, PROJECT_TABLE).await;
+        backfill_lookups::<EnrollmentEntity, ScyllaEnrollmentSchema>(&session, &keyspace, ENROLLMENT_TABLE).await;
+        backfill_lookups::<NotificationPreferenceEntity, ScyllaNotificationPreferenceSchema>(
+            &session,
+            &keyspace,
+            NOTIFICATION_PREFERENCE_TABLE,
+        )
+        .await;
+
         Self {
             account_repository: Arc::new(ScyllaAccountRepository::new(session.clone(), &keyspace, ACCOUNT_TABLE)),
             provider_repository: Arc::new(ScyllaProviderRepository::new(session.clone(), &keyspace, PROVIDER_TABLE)),
@@ -142,11 +315,71 @@ impl ScyllaRepositoryModule {
                 NOTIFICATION_TABLE,
             )),
             blog_repository: Arc::new(ScyllaBlogRepository::new(session.clone(), &keyspace, BLOG_TABLE)),
+            blog_revision_repository: Arc::new(ScyllaBlogRevisionRepository::new(
+                session.clone(),
+                &keyspace,
+                BLOG_REVISION_TABLE,
+            )),
+            blog_star_repository: Arc::new(ScyllaBlogStarRepository::new(session.clone(), &keyspace, BLOG_STAR_TABLE)),
+            comment_repository: Arc::new(ScyllaCommentRepository::new(session.clone(), &keyspace, COMMENT_TABLE)),
+            comment_ban_repository: Arc::new(ScyllaCommentBanRepository::new(
+                session.clone(),
+                &keyspace,
+                COMMENT_BAN_TABLE,
+            )),
             project_repository: Arc::new(ScyllaProjectRepository::new(session.clone(), &keyspace, PROJECT_TABLE)),
+            course_repository: Arc::new(ScyllaCourseRepository::new(session.clone(), &keyspace, COURSE_TABLE)),
+            chapter_repository: Arc::new(ScyllaChapterRepository::new(session.clone(), &keyspace, CHAPTER_TABLE)),
+            lesson_repository: Arc::new(ScyllaLessonRepository::new(session.clone(), &keyspace, LESSON_TABLE)),
+            enrollment_repository: Arc::new(ScyllaEnrollmentRepository::new(
+                session.clone(),
+                &keyspace,
+                ENROLLMENT_TABLE,
+            )),
+            progress_repository: Arc::new(ScyllaProgressRepository::new(session.clone(), &keyspace, PROGRESS_TABLE)),
+            bookmark_repository: Arc::new(ScyllaBookmarkRepository::new(session.clone(), &keyspace, BOOKMARK_TABLE)),
+            review_repository: Arc::new(ScyllaReviewRepository::new(session.clone(), &keyspace, REVIEW_TABLE)),
+            topic_subscription_repository: Arc::new(ScyllaTopicSubscriptionRepository::new(
+                session.clone(),
+                &keyspace,
+                TOPIC_SUBSCRIPTION_TABLE,
+            )),
+            notification_preference_repository: Arc::new(ScyllaNotificationPreferenceRepository::new(
+                session.clone(),
+                &keyspace,
+                NOTIFICATION_PREFERENCE_TABLE,
+            )),
+            media_asset_repository: Arc::new(ScyllaMediaAssetRepository::new(
+                session.clone(),
+                &keyspace,
+                MEDIA_ASSET_TABLE,
+            )),
+            upload_repository: Arc::new(ScyllaUploadRepository::new(session.clone(), &keyspace, UPLOAD_TABLE)),
+            data_export_repository: Arc::new(ScyllaDataExportRepository::new(
+                session.clone(),
+                &keyspace,
+                DATA_EXPORT_TABLE,
+            )),
+            audit_event_repository: Arc::new(ScyllaAuditEventRepository::new(
+                session.clone(),
+                &keyspace,
+                AUDIT_EVENT_TABLE,
+            )),
         }
     }
 }
 
+async fn backfill_lookups<E, S>(session: &Arc<Session>, keyspace: &str, table_name: &str)
+where
+    E: Send + Sync,
+    S: EntitySchema<E>,
+{
+    ScyllaBaseRepository::<E, S>::new(session.clone(), keyspace, table_name)
+        .backfill_lookups()
+        .await
+        .expect("Failed to backfill ScyllaDB lookup tables");
+}
+
 impl_repository_module!(
     ScyllaRepositoryModule,
     get_account_repository -> AccountRepository: account_repository,
@@ -157,7 +390,24 @@ impl_repository_module!(
     get_category_repository -> CategoryRepository: category_repository,
     get_notification_repository -> NotificationRepository: notification_repository,
     get_blog_repository -> BlogRepository: blog_repository,
+    get_blog_revision_repository -> BlogRevisionRepository: blog_revision_repository,
+    get_blog_star_repository -> BlogStarRepository: blog_star_repository,
+    get_comment_repository -> CommentRepository: comment_repository,
+    get_comment_ban_repository -> CommentBanRepository: comment_ban_repository,
     get_project_repository -> ProjectRepository: project_repository,
+    get_course_repository -> CourseRepository: course_repository,
+    get_chapter_repository -> ChapterRepository: chapter_repository,
+    get_lesson_repository -> LessonRepository: lesson_repository,
+    get_enrollment_repository -> EnrollmentRepository: enrollment_repository,
+    get_progress_repository -> ProgressRepository: progress_repository,
+    get_bookmark_repository -> BookmarkRepository: bookmark_repository,
+    get_review_repository -> ReviewRepository: review_repository,
+    get_topic_subscription_repository -> TopicSubscriptionRepository: topic_subscription_repository,
+    get_notification_preference_repository -> NotificationPreferenceRepository: notification_preference_repository,
+    get_media_asset_repository -> MediaAssetRepository: media_asset_repository,
+    get_upload_repository -> UploadRepository: upload_repository,
+    get_data_export_repository -> DataExportRepository: data_export_repository,
+    get_audit_event_repository -> AuditEventRepository: audit_event_repository,
 );
 
 // Factory function
@@ -1,17 +1,36 @@
 use std::sync::Arc;
 
 // shared modules
+use domain::apis::{
+    archive_api::ArchiveApi, blob_storage::BlobStorage, event_hub::EventHub, image_processor::ImageProcessor,
+    mail_api::MailApi, messaging_api::MessagingApi, search_index::SearchIndex,
+};
 use domain::services::{
     account_service::{AccountService, AccountServiceImpl},
+    audit_service::{AuditService, AuditServiceImpl},
     auth_service::{AuthService, AuthServiceImpl},
+    blog_engagement_service::{BlogEngagementService, BlogEngagementServiceImpl},
+    blog_revision_service::{BlogRevisionService, BlogRevisionServiceImpl},
     blog_service::{BlogService, BlogServiceImpl},
+    bookmark_service::{BookmarkService, BookmarkServiceImpl},
     category_service::{CategoryService, CategoryServiceImpl},
+    comment_service::{CommentService, CommentServiceImpl},
+    course_service::{CourseService, CourseServiceImpl},
+    data_export_service::{DataExportService, DataExportServiceImpl},
+    digest_service::{DigestService, DigestServiceImpl},
+    enrollment_service::{EnrollmentService, EnrollmentServiceImpl},
     experience_service::{ExperienceService, ExperienceServiceImpl},
+    media_service::{MediaService, MediaServiceImpl},
+    notification_preference_service::{NotificationPreferenceService, NotificationPreferenceServiceImpl},
     notification_service::{NotificationService, NotificationServiceImpl},
     project_service::{ProjectService, ProjectServiceImpl},
     provider_service::{ProviderService, ProviderServiceImpl},
+    push_service::{PushService, PushServiceImpl},
+    review_service::{ReviewService, ReviewServiceImpl},
     role_service::{RoleService, RoleServiceImpl},
+    search_service::{SearchService, SearchServiceImpl},
     session_service::{SessionService, SessionServiceImpl},
+    upload_service::{UploadService, UploadServiceImpl},
 };
 
 // internal modules
@@ -27,11 +46,45 @@ pub trait ServiceModule {
     fn get_category_service(&self) -> Arc<dyn CategoryService>;
     fn get_notification_service(&self) -> Arc<dyn NotificationService>;
     fn get_blog_service(&self) -> Arc<dyn BlogService>;
+    fn get_blog_revision_service(&self) -> Arc<dyn BlogRevisionService>;
+    fn get_blog_engagement_service(&self) -> Arc<dyn BlogEngagementService>;
+    fn get_comment_service(&self) -> Arc<dyn CommentService>;
     fn get_project_service(&self) -> Arc<dyn ProjectService>;
+    fn get_search_service(&self) -> Arc<dyn SearchService>;
+    fn get_course_service(&self) -> Arc<dyn CourseService>;
+    fn get_enrollment_service(&self) -> Arc<dyn EnrollmentService>;
+    fn get_bookmark_service(&self) -> Arc<dyn BookmarkService>;
+    fn get_review_service(&self) -> Arc<dyn ReviewService>;
+    fn get_push_service(&self) -> Arc<dyn PushService>;
+    fn get_notification_preference_service(&self) -> Arc<dyn NotificationPreferenceService>;
+    fn get_digest_service(&self) -> Arc<dyn DigestService>;
+    fn get_media_service(&self) -> Arc<dyn MediaService>;
+    fn get_upload_service(&self) -> Arc<dyn UploadService>;
+    fn get_data_export_service(&self) -> Arc<dyn DataExportService>;
+    fn get_audit_service(&self) -> Arc<dyn AuditService>;
 }
 
-pub fn build_service_module(repository_module: Arc<dyn RepositoryModule>) -> Arc<dyn ServiceModule> {
-    Arc::new(ServiceModuleImpl::new(repository_module))
+#[allow(clippy::too_many_arguments)]
+pub fn build_service_module(
+    repository_module: Arc<dyn RepositoryModule>,
+    search_index: Arc<dyn SearchIndex>,
+    event_hub: Arc<dyn EventHub>,
+    messaging_api: Arc<dyn MessagingApi>,
+    mail_api: Arc<dyn MailApi>,
+    blob_storage: Arc<dyn BlobStorage>,
+    image_processor: Arc<dyn ImageProcessor>,
+    archive_api: Arc<dyn ArchiveApi>,
+) -> Arc<dyn ServiceModule> {
+    Arc::new(ServiceModuleImpl::new(
+        repository_module,
+        search_index,
+        event_hub,
+        messaging_api,
+        mail_api,
+        blob_storage,
+        image_processor,
+        archive_api,
+    ))
 }
 
 struct ServiceModuleImpl {
@@ -44,11 +97,36 @@ struct ServiceModuleImpl {
     category_service: Arc<dyn CategoryService>,
     notification_service: Arc<dyn NotificationService>,
     blog_service: Arc<dyn BlogService>,
+    blog_revision_service: Arc<dyn BlogRevisionService>,
+    blog_engagement_service: Arc<dyn BlogEngagementService>,
+    comment_service: Arc<dyn CommentService>,
     project_service: Arc<dyn ProjectService>,
+    search_service: Arc<dyn SearchService>,
+    course_service: Arc<dyn CourseService>,
+    enrollment_service: Arc<dyn EnrollmentService>,
+    bookmark_service: Arc<dyn BookmarkService>,
+    review_service: Arc<dyn ReviewService>,
+    push_service: Arc<dyn PushService>,
+    notification_preference_service: Arc<dyn NotificationPreferenceService>,
+    digest_service: Arc<dyn DigestService>,
+    media_service: Arc<dyn MediaService>,
+    upload_service: Arc<dyn UploadService>,
+    data_export_service: Arc<dyn DataExportService>,
+    audit_service: Arc<dyn AuditService>,
 }
 
 impl ServiceModuleImpl {
-    pub fn new(repository_module: Arc<dyn RepositoryModule>) -> Self {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        repository_module: Arc<dyn RepositoryModule>,
+        search_index: Arc<dyn SearchIndex>,
+        event_hub: Arc<dyn EventHub>,
+        messaging_api: Arc<dyn MessagingApi>,
+        mail_api: Arc<dyn MailApi>,
+        blob_storage: Arc<dyn BlobStorage>,
+        image_processor: Arc<dyn ImageProcessor>,
+        archive_api: Arc<dyn ArchiveApi>,
+    ) -> Self {
         let auth_service = Arc::new(AuthServiceImpl::new());
         let account_service = Arc::new(AccountServiceImpl::new(repository_module.get_account_repository()));
         let provider_service = Arc::new(ProviderServiceImpl::new(repository_module.get_provider_repository()));
@@ -56,10 +134,67 @@ impl ServiceModuleImpl {
         let experience_service = Arc::new(ExperienceServiceImpl::new(repository_module.get_experience_repository()));
         let role_service = Arc::new(RoleServiceImpl::new(repository_module.get_role_repository()));
         let category_service = Arc::new(CategoryServiceImpl::new(repository_module.get_category_repository()));
-        let notification_service =
-            Arc::new(NotificationServiceImpl::new(repository_module.get_notification_repository()));
+        let notification_service = Arc::new(NotificationServiceImpl::new(
+            repository_module.get_notification_repository(),
+            repository_module.get_notification_preference_repository(),
+            event_hub,
+        ));
         let blog_service = Arc::new(BlogServiceImpl::new(repository_module.get_blog_repository()));
+        let blog_revision_service =
+            Arc::new(BlogRevisionServiceImpl::new(repository_module.get_blog_revision_repository()));
+        let blog_engagement_service = Arc::new(BlogEngagementServiceImpl::new(
+            repository_module.get_blog_repository(),
+            repository_module.get_blog_star_repository(),
+        ));
+        let comment_service = Arc::new(CommentServiceImpl::new(
+            repository_module.get_comment_repository(),
+            repository_module.get_comment_ban_repository(),
+        ));
         let project_service = Arc::new(ProjectServiceImpl::new(repository_module.get_project_repository()));
+        let search_service = Arc::new(SearchServiceImpl::new(search_index));
+        let course_service = Arc::new(CourseServiceImpl::new(
+            repository_module.get_course_repository(),
+            repository_module.get_chapter_repository(),
+            repository_module.get_lesson_repository(),
+        ));
+        let enrollment_service = Arc::new(EnrollmentServiceImpl::new(
+            repository_module.get_enrollment_repository(),
+            repository_module.get_progress_repository(),
+            repository_module.get_course_repository(),
+        ));
+        let bookmark_service = Arc::new(BookmarkServiceImpl::new(repository_module.get_bookmark_repository()));
+        let review_service = Arc::new(ReviewServiceImpl::new(
+            repository_module.get_review_repository(),
+            repository_module.get_course_repository(),
+        ));
+        let push_service = Arc::new(PushServiceImpl::new(
+            repository_module.get_session_repository(),
+            repository_module.get_topic_subscription_repository(),
+            repository_module.get_notification_preference_repository(),
+            messaging_api,
+        ));
+        let notification_preference_service = Arc::new(NotificationPreferenceServiceImpl::new(
+            repository_module.get_notification_preference_repository(),
+        ));
+        let digest_service = Arc::new(DigestServiceImpl::new(
+            repository_module.get_notification_preference_repository(),
+            repository_module.get_notification_repository(),
+            repository_module.get_account_repository(),
+            mail_api,
+        ));
+        let media_service = Arc::new(MediaServiceImpl::new(
+            repository_module.get_media_asset_repository(),
+            blob_storage.clone(),
+            image_processor,
+        ));
+        let upload_service =
+            Arc::new(UploadServiceImpl::new(repository_module.get_upload_repository(), blob_storage.clone()));
+        let data_export_service = Arc::new(DataExportServiceImpl::new(
+            repository_module.get_data_export_repository(),
+            blob_storage,
+            archive_api,
+        ));
+        let audit_service = Arc::new(AuditServiceImpl::new(repository_module.get_audit_event_repository()));
 
         ServiceModuleImpl {
             auth_service,
@@ -71,7 +206,22 @@ impl ServiceModuleImpl {
             category_service,
             notification_service,
             blog_service,
+            blog_revision_service,
+            blog_engagement_service,
+            comment_service,
             project_service,
+            search_service,
+            course_service,
+            enrollment_service,
+            bookmark_service,
+            review_service,
+            push_service,
+            notification_preference_service,
+            digest_service,
+            media_service,
+            upload_service,
+            data_export_service,
+            audit_service,
         }
     }
 }
@@ -116,9 +266,69 @@ macro_rules! impl_service_module {
                 self.blog_service.clone()
             }
 
+            fn get_blog_revision_service(&self) -> Arc<dyn BlogRevisionService> {
+                self.blog_revision_service.clone()
+            }
+
+            fn get_blog_engagement_service(&self) -> Arc<dyn BlogEngagementService> {
+                self.blog_engagement_service.clone()
+            }
+
+            fn get_comment_service(&self) -> Arc<dyn CommentService> {
+                self.comment_service.clone()
+            }
+
             fn get_project_service(&self) -> Arc<dyn ProjectService> {
                 self.project_service.clone()
             }
+
+            fn get_search_service(&self) -> Arc<dyn SearchService> {
+                self.search_service.clone()
+            }
+
+            fn get_course_service(&self) -> Arc<dyn CourseService> {
+                self.course_service.clone()
+            }
+
+            fn get_enrollment_service(&self) -> Arc<dyn EnrollmentService> {
+                self.enrollment_service.clone()
+            }
+
+            fn get_bookmark_service(&self) -> Arc<dyn BookmarkService> {
+                self.bookmark_service.clone()
+            }
+
+            fn get_review_service(&self) -> Arc<dyn ReviewService> {
+                self.review_service.clone()
+            }
+
+            fn get_push_service(&self) -> Arc<dyn PushService> {
+                self.push_service.clone()
+            }
+
+            fn get_notification_preference_service(&self) -> Arc<dyn NotificationPreferenceService> {
+                self.notification_preference_service.clone()
+            }
+
+            fn get_digest_service(&self) -> Arc<dyn DigestService> {
+                self.digest_service.clone()
+            }
+
+            fn get_media_service(&self) -> Arc<dyn MediaService> {
+                self.media_service.clone()
+            }
+
+            fn get_upload_service(&self) -> Arc<dyn UploadService> {
+                self.upload_service.clone()
+            }
+
+            fn get_data_export_service(&self) -> Arc<dyn DataExportService> {
+                self.data_export_service.clone()
+            }
+
+            fn get_audit_service(&self) -> Arc<dyn AuditService> {
+                self.audit_service.clone()
+            }
         }
     };
 }
@@ -4,14 +4,24 @@ use tracing::info;
 
 // shared modules
 use domain::applications::{
-    account_app_service::AccountAppService, auth_app_service::AuthAppService, category_app_service::CategoryAppService,
-    global_app_service::GlobalAppService, notification_app_service::NotificationAppService,
-    session_app_service::SessionAppService,
+    account_app_service::AccountAppService, admin_app_service::AdminAppService, audit_app_service::AuditAppService,
+    auth_app_service::AuthAppService, blog_app_service::BlogAppService, bookmark_app_service::BookmarkAppService,
+    category_app_service::CategoryAppService, comment_app_service::CommentAppService,
+    course_app_service::CourseAppService, enrollment_app_service::EnrollmentAppService,
+    feed_app_service::FeedAppService, global_app_service::GlobalAppService, media_app_service::MediaAppService,
+    notification_app_service::NotificationAppService, privacy_app_service::PrivacyAppService,
+    review_app_service::ReviewAppService, search_app_service::SearchAppService, session_app_service::SessionAppService,
+    sitemap_app_service::SitemapAppService, upload_app_service::UploadAppService,
 };
 
 // internal modules
 use crate::{
-    secondary::apis::auth_api_impl::AuthApiImpl,
+    secondary::apis::{
+        auth_api_impl::AuthApiImpl, blob_storage_backend::build_blob_storage, event_hub_impl::EventHubImpl,
+        image_processor_impl::ImageProcessorImpl, mail_transport::build_mail_api,
+        messaging_transport::build_messaging_api, search_index_impl::SearchIndexImpl,
+        zip_archive_api_impl::ZipArchiveApiImpl,
+    },
     shared::{
         di::{repository_module, service_module},
         utilities::databases::DatabaseType,
@@ -22,10 +32,25 @@ use crate::{
 pub struct AppState {
     pub auth_app_service: Arc<AuthAppService>,
     pub account_app_service: Arc<AccountAppService>,
+    pub blog_app_service: Arc<BlogAppService>,
+    pub comment_app_service: Arc<CommentAppService>,
     pub notification_app_service: Arc<NotificationAppService>,
     pub session_app_service: Arc<SessionAppService>,
     pub category_app_service: Arc<CategoryAppService>,
     pub global_app_service: Arc<GlobalAppService>,
+    pub search_app_service: Arc<SearchAppService>,
+    pub feed_app_service: Arc<FeedAppService>,
+    pub sitemap_app_service: Arc<SitemapAppService>,
+    pub course_app_service: Arc<CourseAppService>,
+    pub enrollment_app_service: Arc<EnrollmentAppService>,
+    pub bookmark_app_service: Arc<BookmarkAppService>,
+    pub review_app_service: Arc<ReviewAppService>,
+    pub media_app_service: Arc<MediaAppService>,
+    pub upload_app_service: Arc<UploadAppService>,
+    pub privacy_app_service: Arc<PrivacyAppService>,
+    pub admin_app_service: Arc<AdminAppService>,
+    pub audit_app_service: Arc<AuditAppService>,
+    pub event_hub: Arc<EventHubImpl>,
 }
 
 impl AppState {
@@ -40,9 +65,25 @@ impl AppState {
 
         // Initialize apis
         let auth_api = Arc::new(AuthApiImpl::new());
+        let search_index = Arc::new(SearchIndexImpl::new());
+        let event_hub = EventHubImpl::new();
+        let messaging_api = build_messaging_api()?;
+        let mail_api = build_mail_api()?;
+        let blob_storage = build_blob_storage()?;
+        let image_processor = Arc::new(ImageProcessorImpl::new());
+        let archive_api = Arc::new(ZipArchiveApiImpl::new());
 
         // Initialize services
-        let services = service_module::build_service_module(repositories.clone());
+        let services = service_module::build_service_module(
+            repositories.clone(),
+            search_index,
+            event_hub.clone(),
+            messaging_api,
+            mail_api,
+            blob_storage,
+            image_processor,
+            archive_api,
+        );
         info!("📦 Services initialized");
 
         // Initialize application services
@@ -52,30 +93,147 @@ impl AppState {
             services.get_provider_service(),
             services.get_session_service(),
             services.get_role_service(),
+            services.get_audit_service(),
             auth_api.clone(),
         ));
 
-        let notification_app_service = Arc::new(NotificationAppService::new(services.get_notification_service()));
+        let notification_app_service = Arc::new(NotificationAppService::new(
+            services.get_notification_service(),
+            services.get_push_service(),
+            services.get_session_service(),
+            services.get_notification_preference_service(),
+            services.get_digest_service(),
+        ));
         let session_app_service = Arc::new(SessionAppService::new(services.get_session_service()));
 
         let account_app_service = Arc::new(AccountAppService::new(
             services.get_account_service(),
             services.get_role_service(),
             services.get_blog_service(),
+            services.get_blog_revision_service(),
+            services.get_category_service(),
+            services.get_search_service(),
+            services.get_audit_service(),
         ));
 
-        let category_app_service = Arc::new(CategoryAppService::new(services.get_category_service()));
-        let global_app_service =
-            Arc::new(GlobalAppService::new(services.get_experience_service(), services.get_project_service()));
+        let blog_app_service = Arc::new(BlogAppService::new(
+            services.get_blog_service(),
+            services.get_blog_engagement_service(),
+            services.get_search_service(),
+        ));
+        let comment_app_service = Arc::new(CommentAppService::new(
+            services.get_comment_service(),
+            services.get_blog_service(),
+            services.get_notification_service(),
+        ));
+        let category_app_service = Arc::new(CategoryAppService::new(
+            services.get_category_service(),
+            services.get_blog_service(),
+            services.get_audit_service(),
+        ));
+        let global_app_service = Arc::new(GlobalAppService::new(
+            services.get_experience_service(),
+            services.get_project_service(),
+            services.get_search_service(),
+            services.get_audit_service(),
+        ));
+        let search_app_service = Arc::new(SearchAppService::new(
+            services.get_search_service(),
+            services.get_blog_service(),
+            services.get_project_service(),
+        ));
+        let feed_app_service = Arc::new(FeedAppService::new(
+            services.get_blog_service(),
+            services.get_category_service(),
+            services.get_account_service(),
+        ));
+        let sitemap_app_service = Arc::new(SitemapAppService::new(
+            services.get_blog_service(),
+            services.get_project_service(),
+            services.get_category_service(),
+        ));
+        let course_app_service =
+            Arc::new(CourseAppService::new(services.get_course_service(), services.get_category_service()));
+        let enrollment_app_service =
+            Arc::new(EnrollmentAppService::new(services.get_enrollment_service(), services.get_course_service()));
+
+        let bookmark_app_service = Arc::new(BookmarkAppService::new(
+            services.get_bookmark_service(),
+            services.get_blog_service(),
+            services.get_project_service(),
+            services.get_course_service(),
+        ));
+        let review_app_service = Arc::new(ReviewAppService::new(
+            services.get_review_service(),
+            services.get_course_service(),
+            services.get_enrollment_service(),
+            services.get_notification_service(),
+        ));
+        let media_app_service = Arc::new(MediaAppService::new(
+            services.get_media_service(),
+            services.get_account_service(),
+            services.get_blog_service(),
+            services.get_project_service(),
+        ));
+        let upload_app_service = Arc::new(UploadAppService::new(
+            services.get_upload_service(),
+            services.get_blog_service(),
+            services.get_course_service(),
+        ));
+        let privacy_app_service = Arc::new(PrivacyAppService::new(
+            services.get_account_service(),
+            services.get_session_service(),
+            services.get_provider_service(),
+            services.get_role_service(),
+            services.get_notification_service(),
+            services.get_notification_preference_service(),
+            services.get_push_service(),
+            services.get_blog_service(),
+            services.get_blog_revision_service(),
+            services.get_blog_engagement_service(),
+            services.get_comment_service(),
+            services.get_course_service(),
+            services.get_enrollment_service(),
+            services.get_review_service(),
+            services.get_bookmark_service(),
+            services.get_media_service(),
+            services.get_upload_service(),
+            services.get_search_service(),
+            services.get_data_export_service(),
+            services.get_audit_service(),
+        ));
+        let admin_app_service = Arc::new(AdminAppService::new(
+            services.get_account_service(),
+            services.get_role_service(),
+            services.get_provider_service(),
+            services.get_session_service(),
+            services.get_audit_service(),
+        ));
+        let audit_app_service = Arc::new(AuditAppService::new(services.get_audit_service()));
 
         info!("📦 AppState initialized successfully");
         Ok(AppState {
             auth_app_service,
             account_app_service,
+            blog_app_service,
+            comment_app_service,
             notification_app_service,
             session_app_service,
             category_app_service,
             global_app_service,
+            search_app_service,
+            feed_app_service,
+            sitemap_app_service,
+            course_app_service,
+            enrollment_app_service,
+            bookmark_app_service,
+            review_app_service,
+            media_app_service,
+            upload_app_service,
+            privacy_app_service,
+            admin_app_service,
+            audit_app_service,
+            event_hub,
         })
     }
 }
@@ -0,0 +1,62 @@
+use axum::http::header::{ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED};
+use axum::http::{HeaderMap, HeaderValue, StatusCode};
+use axum::response::{IntoResponse, Response};
+use chrono::{DateTime, NaiveDateTime, Utc};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+static HTTP_DATE_FORMAT: &str = "%a, %d %b %Y %H:%M:%S GMT";
+
+/// Validators for a cacheable representation, `last_modified` is a unix timestamp in seconds.
+pub struct CacheValidators {
+    pub etag: String,
+    pub last_modified: i64,
+}
+
+impl CacheValidators {
+    /// Weak ETag over whatever identifies the representation.
+    pub fn new(parts: impl Hash, last_modified: i64) -> Self {
+        let mut hasher = DefaultHasher::new();
+        parts.hash(&mut hasher);
+        last_modified.hash(&mut hasher);
+
+        CacheValidators { etag: format!("W/\"{:x}\"", hasher.finish()), last_modified }
+    }
+
+    /// True when the client copy is still fresh, If-None-Match wins over If-Modified-Since.
+    pub fn is_not_modified(&self, headers: &HeaderMap) -> bool {
+        if let Some(if_none_match) = headers.get(IF_NONE_MATCH).and_then(|h| h.to_str().ok()) {
+            return if_none_match.split(',').map(str::trim).any(|tag| tag == "*" || weak_eq(tag, &self.etag));
+        }
+
+        headers
+            .get(IF_MODIFIED_SINCE)
+            .and_then(|h| h.to_str().ok())
+            .and_then(|value| NaiveDateTime::parse_from_str(value.trim(), HTTP_DATE_FORMAT).ok())
+            .is_some_and(|since| self.last_modified <= since.and_utc().timestamp())
+    }
+
+    pub fn apply(&self, response: &mut Response) {
+        let headers = response.headers_mut();
+        if let Ok(etag) = HeaderValue::from_str(&self.etag) {
+            headers.insert(ETAG, etag);
+        }
+        if let Ok(last_modified) = HeaderValue::from_str(&format_http_date(self.last_modified)) {
+            headers.insert(LAST_MODIFIED, last_modified);
+        }
+    }
+
+    pub fn not_modified(&self) -> Response {
+        let mut response = StatusCode::NOT_MODIFIED.into_response();
+        self.apply(&mut response);
+        response
+    }
+}
+
+pub fn format_http_date(timestamp: i64) -> String {
+    DateTime::<Utc>::from_timestamp(timestamp, 0).unwrap_or_default().format(HTTP_DATE_FORMAT).to_string()
+}
+
+fn weak_eq(a: &str, b: &str) -> bool {
+    a.trim_start_matches("W/") == b.trim_start_matches("W/")
+}
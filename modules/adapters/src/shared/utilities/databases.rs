@@ -1,6 +1,7 @@
 use mongodb::{
-    Client, Database,
-    options::{ClientOptions, ServerApi, ServerApiVersion},
+    Client, Database, IndexModel,
+    bson::{Document, doc},
+    options::{ClientOptions, Collation, CollationStrength, IndexOptions, ServerApi, ServerApiVersion},
 };
 use scylla::client::{session::Session, session_builder::SessionBuilder};
 use scylla_migrate::Migrator;
@@ -17,8 +18,58 @@ pub static EXPERIENCE_TABLE: &str = "experiences";
 pub static ROLE_TABLE: &str = "roles";
 pub static CATEGORY_TABLE: &str = "categories";
 pub static BLOG_TABLE: &str = "blogs";
+pub static BLOG_REVISION_TABLE: &str = "blog_revisions";
+pub static BLOG_STAR_TABLE: &str = "blog_stars";
+pub static BLOG_COUNTER_TABLE: &str = "blog_counters";
+pub static COMMENT_TABLE: &str = "comments";
+pub static COMMENT_BAN_TABLE: &str = "comment_bans";
 pub static NOTIFICATION_TABLE: &str = "notifications";
 pub static PROJECT_TABLE: &str = "projects";
+pub static COURSE_TABLE: &str = "courses";
+pub static CHAPTER_TABLE: &str = "chapters";
+pub static LESSON_TABLE: &str = "lessons";
+pub static ENROLLMENT_TABLE: &str = "enrollments";
+pub static PROGRESS_TABLE: &str = "progresses";
+pub static BOOKMARK_TABLE: &str = "bookmarks";
+pub static REVIEW_TABLE: &str = "reviews";
+pub static TOPIC_SUBSCRIPTION_TABLE: &str = "topic_subscriptions";
+pub static NOTIFICATION_PREFERENCE_TABLE: &str = "notification_preferences";
+pub static MEDIA_ASSET_TABLE: &str = "media_assets";
+pub static UPLOAD_TABLE: &str = "uploads";
+pub static DATA_EXPORT_TABLE: &str = "data_exports";
+pub static AUDIT_EVENT_TABLE: &str = "audit_events";
+
+// Scylla lookup tables, kept in step with the tables they point into
+pub static ACCOUNT_BY_EMAIL_TABLE: &str = "accounts_by_email";
+pub static ACCOUNT_BY_USERNAME_TABLE: &str = "accounts_by_username";
+pub static SESSION_BY_JTI_TABLE: &str = "sessions_by_jti";
+pub static SESSION_BY_ACCOUNT_TABLE: &str = "sessions_by_account";
+pub static CATEGORY_BY_NAME_TABLE: &str = "categories_by_name";
+pub static BLOG_BY_AUTHOR_TABLE: &str = "blogs_by_author";
+pub static BLOG_BY_CATEGORY_TABLE: &str = "blogs_by_category";
+pub static NOTIFICATION_BY_ACCOUNT_TABLE: &str = "notifications_by_account";
+pub static BLOG_BY_STATUS_TABLE: &str = "blogs_by_status";
+pub static PROVIDER_BY_ACCOUNT_TABLE: &str = "providers_by_account";
+pub static ROLE_BY_ACCOUNT_TABLE: &str = "roles_by_account";
+pub static ROLE_BY_NAME_TABLE: &str = "roles_by_name";
+pub static PROJECT_BY_NAME_TABLE: &str = "projects_by_name";
+pub static ENROLLMENT_BY_ACCOUNT_TABLE: &str = "enrollments_by_account";
+pub static ENROLLMENT_BY_COURSE_TABLE: &str = "enrollments_by_course";
+pub static NOTIFICATION_PREFERENCE_BY_ACCOUNT_TABLE: &str = "notification_preferences_by_account";
+// Scylla claim tables, a unique key is taken with a lightweight transaction before its row is written
+pub static REVIEW_BY_COURSE_ACCOUNT_TABLE: &str = "reviews_by_course_account";
+// Lookup tables filled from the rows written before them, one row per table once it is
+pub static LOOKUP_BACKFILL_TABLE: &str = "lookup_backfills";
+
+// Unique indexes Mongo enforces, created on startup as the Scylla tables are by their migrations
+static MONGO_UNIQUE_INDEXES: &[(&str, &[&str])] = &[
+    (BLOG_STAR_TABLE, &["blog_id", "account_id"]),
+    (BOOKMARK_TABLE, &["account_id", "target_type", "target_id"]),
+    (REVIEW_TABLE, &["course_id", "account_id"]),
+    (ENROLLMENT_TABLE, &["account_id", "course_id"]),
+];
+// Unique indexes compared regardless of case, with the collation the lookups on them use
+static MONGO_CASE_INSENSITIVE_UNIQUE_INDEXES: &[(&str, &str)] = &[(ACCOUNT_TABLE, "username")];
 
 pub static MONGO_CLIENT: OnceCell<Arc<Database>> = OnceCell::const_new();
 pub static SCYLLA_SESSION: OnceCell<Arc<Session>> = OnceCell::const_new();
@@ -65,12 +116,43 @@ pub async fn mongo_client() -> Arc<Database> {
             client_options.retry_reads = Some(true);
 
             let client = Client::with_options(client_options).unwrap();
-            Arc::new(client.database(&APP_CONFIG.database.mongo_database))
+            let database = client.database(&APP_CONFIG.database.mongo_database);
+            create_mongo_indexes(&database).await;
+
+            Arc::new(database)
         })
         .await
         .clone()
 }
 
+async fn create_mongo_indexes(database: &Database) {
+    for (collection, keys) in MONGO_UNIQUE_INDEXES {
+        let keys = keys.iter().fold(Document::new(), |mut keys, key| {
+            keys.insert(*key, 1);
+            keys
+        });
+        let index = IndexModel::builder().keys(keys).options(IndexOptions::builder().unique(true).build()).build();
+
+        database
+            .collection::<Document>(collection)
+            .create_index(index)
+            .await
+            .expect("Failed to create MongoDB indexes");
+    }
+
+    for (collection, key) in MONGO_CASE_INSENSITIVE_UNIQUE_INDEXES {
+        let case_insensitive = Collation::builder().locale("en").strength(CollationStrength::Secondary).build();
+        let options = IndexOptions::builder().unique(true).collation(case_insensitive).build();
+        let index = IndexModel::builder().keys(doc! { *key: 1 }).options(options).build();
+
+        database
+            .collection::<Document>(collection)
+            .create_index(index)
+            .await
+            .expect("Failed to create MongoDB indexes");
+    }
+}
+
 pub async fn scylla_session() -> Arc<Session> {
     SCYLLA_SESSION
         .get_or_init(|| async {
@@ -0,0 +1,194 @@
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde_json::json;
+
+// shared modules
+use domain::usecases::feed_usecases::{BlogFeed, BlogFeedItem};
+use shared::functions::markdown_functions::MarkdownFunctions;
+
+// internal modules
+use crate::shared::utilities::site_links;
+
+// Length of the generated summary when a blog has no description
+static SUMMARY_MAX_CHARS: usize = 280;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FeedFormat {
+    Rss,
+    Atom,
+    Json,
+}
+
+impl FeedFormat {
+    /// Parse a feed file name such as `blogs.rss`.
+    pub fn from_file_name(file_name: &str) -> Option<Self> {
+        match file_name {
+            "blogs.rss" => Some(FeedFormat::Rss),
+            "blogs.atom" => Some(FeedFormat::Atom),
+            "blogs.json" => Some(FeedFormat::Json),
+            _ => None,
+        }
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            FeedFormat::Rss => "application/rss+xml; charset=utf-8",
+            FeedFormat::Atom => "application/atom+xml; charset=utf-8",
+            FeedFormat::Json => "application/feed+json; charset=utf-8",
+        }
+    }
+}
+
+/// Render `feed`, `self_url` is the absolute URL the feed is served from.
+pub fn render(format: FeedFormat, feed: &BlogFeed, self_url: &str) -> String {
+    match format {
+        FeedFormat::Rss => render_rss(feed, self_url),
+        FeedFormat::Atom => render_atom(feed, self_url),
+        FeedFormat::Json => render_json(feed, self_url),
+    }
+}
+
+fn render_rss(feed: &BlogFeed, self_url: &str) -> String {
+    let items: String = feed
+        .items
+        .iter()
+        .map(|item| {
+            let url = blog_url(item);
+            let categories: String = item
+                .blog
+                .categories
+                .iter()
+                .map(|category| format!("<category>{}</category>", escape_xml(category)))
+                .collect();
+
+            format!(
+                "<item><title>{}</title><link>{}</link><guid isPermaLink=\"true\">{}</guid><pubDate>{}</pubDate>\
+                 <dc:creator>{}</dc:creator>{}<description>{}</description>\
+                 <content:encoded>{}</content:encoded></item>",
+                escape_xml(&item.blog.name),
+                escape_xml(&url),
+                escape_xml(&url),
+                rfc2822(published_at(item)),
+                escape_xml(&item.author_name),
+                categories,
+                escape_xml(&summary(item)),
+                escape_xml(&MarkdownFunctions::to_html(&item.blog.markdown)),
+            )
+        })
+        .collect();
+
+    format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <rss version=\"2.0\" xmlns:atom=\"http://www.w3.org/2005/Atom\" \
+         xmlns:content=\"http://purl.org/rss/1.0/modules/content/\" xmlns:dc=\"http://purl.org/dc/elements/1.1/\">\
+         <channel><title>{}</title><link>{}</link><description>{}</description><lastBuildDate>{}</lastBuildDate>\
+         <atom:link href=\"{}\" rel=\"self\" type=\"application/rss+xml\" />{}</channel></rss>",
+        escape_xml(&feed.title),
+        escape_xml(&site_links::home_url()),
+        escape_xml(&feed.description),
+        rfc2822(feed.updated_at),
+        escape_xml(self_url),
+        items,
+    )
+}
+
+fn render_atom(feed: &BlogFeed, self_url: &str) -> String {
+    let entries: String = feed
+        .items
+        .iter()
+        .map(|item| {
+            let url = blog_url(item);
+            let categories: String = item
+                .blog
+                .categories
+                .iter()
+                .map(|category| format!("<category term=\"{}\" />", escape_xml(category)))
+                .collect();
+
+            format!(
+                "<entry><id>{}</id><title>{}</title><link href=\"{}\" /><published>{}</published>\
+                 <updated>{}</updated><author><name>{}</name></author>{}<summary>{}</summary>\
+                 <content type=\"html\">{}</content></entry>",
+                escape_xml(&url),
+                escape_xml(&item.blog.name),
+                escape_xml(&url),
+                rfc3339(published_at(item)),
+                rfc3339(item.blog.base.updated_at),
+                escape_xml(&item.author_name),
+                categories,
+                escape_xml(&summary(item)),
+                escape_xml(&MarkdownFunctions::to_html(&item.blog.markdown)),
+            )
+        })
+        .collect();
+
+    format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <feed xmlns=\"http://www.w3.org/2005/Atom\"><id>{}</id><title>{}</title><subtitle>{}</subtitle>\
+         <updated>{}</updated><link rel=\"self\" href=\"{}\" /><link href=\"{}\" />{}</feed>",
+        escape_xml(self_url),
+        escape_xml(&feed.title),
+        escape_xml(&feed.description),
+        rfc3339(feed.updated_at),
+        escape_xml(self_url),
+        escape_xml(&site_links::home_url()),
+        entries,
+    )
+}
+
+fn render_json(feed: &BlogFeed, self_url: &str) -> String {
+    let items: Vec<serde_json::Value> = feed
+        .items
+        .iter()
+        .map(|item| {
+            let url = blog_url(item);
+            json!({
+                "id": url,
+                "url": url,
+                "title": item.blog.name,
+                "summary": summary(item),
+                "content_html": MarkdownFunctions::to_html(&item.blog.markdown),
+                "date_published": rfc3339(published_at(item)),
+                "date_modified": rfc3339(item.blog.base.updated_at),
+                "authors": [{ "name": item.author_name }],
+                "tags": item.blog.categories,
+            })
+        })
+        .collect();
+
+    json!({
+        "version": "https://jsonfeed.org/version/1.1",
+        "title": feed.title,
+        "description": feed.description,
+        "home_page_url": site_links::home_url(),
+        "feed_url": self_url,
+        "items": items,
+    })
+    .to_string()
+}
+
+fn blog_url(item: &BlogFeedItem) -> String {
+    site_links::blog_url(item.blog.base.id.as_deref().unwrap_or_default())
+}
+
+fn published_at(item: &BlogFeedItem) -> i64 {
+    item.blog.published_at.unwrap_or(item.blog.base.created_at)
+}
+
+fn summary(item: &BlogFeedItem) -> String {
+    match item.blog.description.trim().is_empty() {
+        true => MarkdownFunctions::excerpt(&item.blog.markdown, SUMMARY_MAX_CHARS),
+        false => item.blog.description.clone(),
+    }
+}
+
+fn rfc2822(timestamp: i64) -> String {
+    DateTime::<Utc>::from_timestamp(timestamp, 0).unwrap_or_default().to_rfc2822()
+}
+
+fn rfc3339(timestamp: i64) -> String {
+    DateTime::<Utc>::from_timestamp(timestamp, 0).unwrap_or_default().to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+fn escape_xml(text: &str) -> String {
+    MarkdownFunctions::escape_html(text).replace('\'', "&apos;")
+}
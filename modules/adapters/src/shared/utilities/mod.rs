@@ -1,5 +1,10 @@
+pub mod conditional_request;
 pub mod databases;
+pub mod feed_writer;
+pub mod multipart_upload;
 pub mod request_extractor;
 pub mod route_logger;
+pub mod site_links;
+pub mod sitemap_writer;
 pub mod validated_payload;
 pub mod validated_query;
@@ -0,0 +1,44 @@
+use axum::extract::{FromRequest, Multipart, Request};
+
+// shared modules
+use domain::usecases::media_usecases::MediaUpload;
+use shared::models::failure::Failure;
+
+// internal modules
+use crate::shared::models::failure::HttpFailure;
+
+/// Form field the file is read from.
+static FILE_FIELD: &str = "file";
+
+/// The `file` field of a multipart form, other fields are ignored.
+#[derive(Debug, Clone)]
+pub struct MultipartUpload(pub MediaUpload);
+
+impl<S> FromRequest<S> for MultipartUpload
+where
+    S: Send + Sync,
+{
+    type Rejection = HttpFailure;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let mut multipart = Multipart::from_request(req, state)
+            .await
+            .map_err(|err| HttpFailure::new(Failure::BadRequest(err.to_string())))?;
+
+        while let Some(field) =
+            multipart.next_field().await.map_err(|err| HttpFailure::new(Failure::BadRequest(err.to_string())))?
+        {
+            if field.name() != Some(FILE_FIELD) {
+                continue;
+            }
+
+            let file_name = field.file_name().unwrap_or_default().to_string();
+            let content_type = field.content_type().unwrap_or_default().to_string();
+            let bytes = field.bytes().await.map_err(|err| HttpFailure::new(Failure::BadRequest(err.to_string())))?;
+
+            return Ok(MultipartUpload(MediaUpload { file_name, content_type, bytes: bytes.to_vec() }));
+        }
+
+        Err(HttpFailure::new(Failure::ValidationError(format!("The {} field is required", FILE_FIELD))))
+    }
+}
@@ -1,5 +1,9 @@
-use axum::extract::{FromRequest, Request};
+use axum::extract::{ConnectInfo, FromRequest, Request};
 use axum::http::HeaderMap;
+use std::net::{IpAddr, SocketAddr};
+
+// shared modules
+use shared::configs::APP_CONFIG;
 
 // internal modules
 use crate::shared::models::failure::HttpFailure;
@@ -21,7 +25,7 @@ where
 
     async fn from_request(req: Request, _state: &S) -> Result<Self, Self::Rejection> {
         let header = req.headers();
-        let ip_address = extract_ip(header);
+        let ip_address = extract_ip(header, connect_info(&req));
         let user_agent = extract_user_agent(header);
         let device_type = detect_device_type(&user_agent);
 
@@ -30,24 +34,53 @@ where
     }
 }
 
-pub fn extract_ip(headers: &HeaderMap) -> String {
-    // Try to get IP from X-Forwarded-For header (for proxied requests)
+pub fn connect_info(req: &Request) -> Option<SocketAddr> {
+    req.extensions().get::<ConnectInfo<SocketAddr>>().map(|ConnectInfo(peer)| *peer)
+}
+
+/// The client address is the connecting peer, forwarded headers are only believed when that peer is one of the
+/// configured trusted proxies, anyone else could send them to pick the address they are recorded under.
+pub fn extract_ip(headers: &HeaderMap, peer: Option<SocketAddr>) -> String {
+    client_ip(headers, peer, &APP_CONFIG.server.trusted_proxies)
+}
+
+fn client_ip(headers: &HeaderMap, peer: Option<SocketAddr>, trusted_proxies: &[String]) -> String {
+    let is_trusted_proxy =
+        |ip: &IpAddr| trusted_proxies.iter().any(|proxy| proxy.parse::<IpAddr>().is_ok_and(|proxy| proxy == *ip));
+
+    let Some(peer) = peer.map(|peer| peer.ip()) else {
+        return "unknown".to_string();
+    };
+    if !is_trusted_proxy(&peer) {
+        return peer.to_string();
+    }
+
+    // Walk X-Forwarded-For from the nearest hop, the first one that is not our own proxy is the client
     if let Some(forwarded_for) = headers.get("x-forwarded-for")
         && let Ok(value) = forwarded_for.to_str()
-        && let Some(ip) = value.split(',').next()
     {
-        return ip.trim().to_string();
+        let hops = value.split(',').rev().filter_map(|hop| hop.trim().parse::<IpAddr>().ok());
+        let mut last_hop = None;
+        for hop in hops {
+            if !is_trusted_proxy(&hop) {
+                return hop.to_string();
+            }
+            last_hop = Some(hop);
+        }
+        if let Some(hop) = last_hop {
+            return hop.to_string();
+        }
     }
 
     // Try X-Real-IP header
     if let Some(real_ip) = headers.get("x-real-ip")
         && let Ok(value) = real_ip.to_str()
+        && let Ok(ip) = value.trim().parse::<IpAddr>()
     {
-        return value.to_string();
+        return ip.to_string();
     }
 
-    // Fallback to unknown
-    "unknown".to_string()
+    peer.to_string()
 }
 
 pub fn extract_user_agent(headers: &HeaderMap) -> String {
@@ -67,3 +100,76 @@ pub fn detect_device_type(user_agent: &str) -> String {
         "desktop".to_string()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use axum::http::HeaderValue;
+
+    use super::*;
+
+    const PROXY: &str = "10.0.0.1";
+
+    fn headers(pairs: &[(&'static str, &'static str)]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for (name, value) in pairs {
+            headers.insert(*name, HeaderValue::from_static(value));
+        }
+        headers
+    }
+
+    fn peer(ip: &str) -> Option<SocketAddr> {
+        Some(SocketAddr::new(ip.parse().unwrap(), 443))
+    }
+
+    fn trusted(proxies: &[&str]) -> Vec<String> {
+        proxies.iter().map(|proxy| proxy.to_string()).collect()
+    }
+
+    #[test]
+    fn without_a_peer_the_address_is_unknown() {
+        let headers = headers(&[("x-forwarded-for", "203.0.113.7")]);
+
+        assert_eq!(client_ip(&headers, None, &trusted(&[PROXY])), "unknown");
+    }
+
+    #[test]
+    fn an_untrusted_peer_cannot_forward_an_address() {
+        let headers = headers(&[("x-forwarded-for", "203.0.113.7"), ("x-real-ip", "203.0.113.8")]);
+
+        assert_eq!(client_ip(&headers, peer("198.51.100.2"), &trusted(&[PROXY])), "198.51.100.2");
+        assert_eq!(client_ip(&headers, peer(PROXY), &[]), PROXY);
+    }
+
+    #[test]
+    fn a_trusted_peer_forwards_the_nearest_untrusted_hop() {
+        let headers = headers(&[("x-forwarded-for", "1.1.1.1, 203.0.113.7, 10.0.0.2")]);
+
+        assert_eq!(client_ip(&headers, peer(PROXY), &trusted(&[PROXY, "10.0.0.2"])), "203.0.113.7");
+    }
+
+    #[test]
+    fn when_every_hop_is_trusted_the_farthest_one_is_the_client() {
+        let headers = headers(&[("x-forwarded-for", "10.0.0.3, 10.0.0.2")]);
+
+        assert_eq!(client_ip(&headers, peer(PROXY), &trusted(&[PROXY, "10.0.0.2", "10.0.0.3"])), "10.0.0.3");
+    }
+
+    #[test]
+    fn invalid_hops_are_skipped() {
+        let headers = headers(&[("x-forwarded-for", "203.0.113.7, unknown, , 10.0.0.2")]);
+
+        assert_eq!(client_ip(&headers, peer(PROXY), &trusted(&[PROXY, "10.0.0.2", "not-an-ip"])), "203.0.113.7");
+    }
+
+    #[test]
+    fn x_real_ip_is_the_fallback_before_the_peer() {
+        let trusted = trusted(&[PROXY]);
+
+        assert_eq!(client_ip(&headers(&[("x-real-ip", " 203.0.113.8 ")]), peer(PROXY), &trusted), "203.0.113.8");
+        assert_eq!(
+            client_ip(&headers(&[("x-forwarded-for", "garbage"), ("x-real-ip", "203.0.113.8")]), peer(PROXY), &trusted),
+            "203.0.113.8"
+        );
+        assert_eq!(client_ip(&headers(&[("x-real-ip", "garbage")]), peer(PROXY), &trusted), PROXY);
+    }
+}
@@ -0,0 +1,23 @@
+// shared modules
+use shared::configs::APP_CONFIG;
+
+/// Absolute links to the public pages, rooted at `site.base_url`.
+pub fn home_url() -> String {
+    APP_CONFIG.site.public_url("/")
+}
+
+pub fn page_url(path: &str) -> String {
+    APP_CONFIG.site.public_url(path)
+}
+
+pub fn blog_url(blog_id: &str) -> String {
+    page_url(&format!("/blogs/{}", blog_id))
+}
+
+pub fn project_url(project_id: &str) -> String {
+    page_url(&format!("/projects/{}", project_id))
+}
+
+pub fn category_url(category_id: &str) -> String {
+    page_url(&format!("/categories/{}", category_id))
+}
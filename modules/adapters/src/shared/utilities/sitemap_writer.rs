@@ -0,0 +1,139 @@
+use chrono::{DateTime, SecondsFormat, Utc};
+
+// shared modules
+use domain::usecases::sitemap_usecases::{SitemapEntry, SitemapEntryKind};
+use shared::functions::markdown_functions::MarkdownFunctions;
+
+// internal modules
+use crate::shared::utilities::site_links;
+
+/// Protocol limit of URLs in a single sitemap file.
+pub static MAX_SITEMAP_URLS: usize = 50_000;
+
+/// Number of sitemap files needed, 1 means `/sitemap.xml` holds every URL itself.
+pub fn page_count(entries: &[SitemapEntry]) -> usize {
+    entries.len().div_ceil(MAX_SITEMAP_URLS).max(1)
+}
+
+/// Entries of the 1-based sitemap page, `None` when out of range.
+pub fn page(entries: &[SitemapEntry], page: usize) -> Option<&[SitemapEntry]> {
+    if page == 0 || page > page_count(entries) {
+        return None;
+    }
+
+    let start = (page - 1) * MAX_SITEMAP_URLS;
+    Some(&entries[start..(start + MAX_SITEMAP_URLS).min(entries.len())])
+}
+
+pub fn page_path(page: usize) -> String {
+    format!("/sitemaps/sitemap-{}.xml", page)
+}
+
+/// Parse a sitemap file name such as `sitemap-2.xml`.
+pub fn page_from_file_name(file_name: &str) -> Option<usize> {
+    file_name.strip_prefix("sitemap-")?.strip_suffix(".xml")?.parse().ok()
+}
+
+pub fn render_urlset(entries: &[SitemapEntry]) -> String {
+    let urls: String = entries
+        .iter()
+        .map(|entry| {
+            format!(
+                "<url><loc>{}</loc><lastmod>{}</lastmod></url>",
+                escape_xml(&entry_url(entry)),
+                w3c_date(entry.updated_at)
+            )
+        })
+        .collect();
+
+    format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <urlset xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">{}</urlset>",
+        urls
+    )
+}
+
+pub fn render_index(entries: &[SitemapEntry]) -> String {
+    let sitemaps: String = entries
+        .chunks(MAX_SITEMAP_URLS)
+        .enumerate()
+        .map(|(index, chunk)| {
+            let last_modified = chunk.iter().map(|entry| entry.updated_at).max().unwrap_or(0);
+            format!(
+                "<sitemap><loc>{}</loc><lastmod>{}</lastmod></sitemap>",
+                escape_xml(&site_links::page_url(&page_path(index + 1))),
+                w3c_date(last_modified)
+            )
+        })
+        .collect();
+
+    format!(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n\
+         <sitemapindex xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">{}</sitemapindex>",
+        sitemaps
+    )
+}
+
+fn entry_url(entry: &SitemapEntry) -> String {
+    match entry.kind {
+        SitemapEntryKind::Blog => site_links::blog_url(&entry.id),
+        SitemapEntryKind::Project => site_links::project_url(&entry.id),
+        SitemapEntryKind::Category => site_links::category_url(&entry.id),
+    }
+}
+
+fn w3c_date(timestamp: i64) -> String {
+    DateTime::<Utc>::from_timestamp(timestamp, 0).unwrap_or_default().to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+fn escape_xml(text: &str) -> String {
+    MarkdownFunctions::escape_html(text).replace('\'', "&apos;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entries(count: usize) -> Vec<SitemapEntry> {
+        (0..count)
+            .map(|index| SitemapEntry { kind: SitemapEntryKind::Blog, id: index.to_string(), updated_at: 0 })
+            .collect()
+    }
+
+    #[test]
+    fn a_single_file_holds_up_to_the_url_limit() {
+        assert_eq!(page_count(&entries(0)), 1);
+        assert_eq!(page_count(&entries(1)), 1);
+        assert_eq!(page_count(&entries(MAX_SITEMAP_URLS)), 1);
+        assert_eq!(page(&entries(MAX_SITEMAP_URLS), 1).unwrap().len(), MAX_SITEMAP_URLS);
+    }
+
+    #[test]
+    fn entries_beyond_the_limit_spill_into_another_file() {
+        let entries = entries(MAX_SITEMAP_URLS * 2 + 1);
+
+        assert_eq!(page_count(&entries), 3);
+        assert_eq!(page(&entries, 1).unwrap().len(), MAX_SITEMAP_URLS);
+        assert_eq!(page(&entries, 2).unwrap().first().unwrap().id, MAX_SITEMAP_URLS.to_string());
+        assert_eq!(page(&entries, 3).unwrap().len(), 1);
+        assert_eq!(page(&entries, 3).unwrap()[0].id, (MAX_SITEMAP_URLS * 2).to_string());
+    }
+
+    #[test]
+    fn pages_out_of_range_are_missing() {
+        let entries = entries(MAX_SITEMAP_URLS + 1);
+
+        assert!(page(&entries, 0).is_none());
+        assert!(page(&entries, 3).is_none());
+        assert!(page(&[], 1).is_some_and(|entries| entries.is_empty()));
+    }
+
+    #[test]
+    fn file_names_round_trip_through_their_page() {
+        assert_eq!(page_path(2), "/sitemaps/sitemap-2.xml");
+        assert_eq!(page_from_file_name("sitemap-2.xml"), Some(2));
+        assert_eq!(page_from_file_name("sitemap-x.xml"), None);
+        assert_eq!(page_from_file_name("sitemap-2.txt"), None);
+        assert_eq!(page_from_file_name("robots.txt"), None);
+    }
+}
@@ -0,0 +1,309 @@
+use async_trait::async_trait;
+use std::sync::{Arc, Mutex};
+
+// shared modules
+use adapters::secondary::apis::mock_messaging_api_impl::MockMessagingApiImpl;
+use domain::entities::{
+    notification_preference_entity::NotificationPreferenceEntity, session_entity::SessionEntity,
+    topic_subscription_entity::TopicSubscriptionEntity,
+};
+use domain::repositories::{
+    base_repository::BaseRepository, notification_preference_repository::NotificationPreferenceRepository,
+    session_repository::SessionRepository, topic_subscription_repository::TopicSubscriptionRepository,
+};
+use domain::services::push_service::{PushService, PushServiceImpl};
+use domain::usecases::notification_usecases::PushNotification;
+use domain::values::{notification_channel::NotificationChannel, notification_kind::NotificationKind};
+use shared::models::failure::Failure;
+use shared::models::filters::QuerySpec;
+use shared::models::paginate::Paginate;
+use shared::types::DomainResponse;
+
+static ACCOUNT_ID: &str = "0192b1f0-0000-7000-8000-000000000001";
+
+/// Rows kept in memory, enough of a repository to drive the push service.
+struct InMemory<E> {
+    rows: Mutex<Vec<E>>,
+}
+
+impl<E> Default for InMemory<E> {
+    fn default() -> Self {
+        Self { rows: Mutex::new(vec![]) }
+    }
+}
+
+macro_rules! impl_in_memory_base_repository {
+    ($entity:ty) => {
+        #[async_trait]
+        impl BaseRepository<$entity> for InMemory<$entity> {
+            async fn create(&self, entity: &$entity) -> DomainResponse<$entity> {
+                let mut entity = entity.clone();
+                entity.base.id.get_or_insert_with(|| uuid::Uuid::now_v7().to_string());
+                self.rows.lock().unwrap().push(entity.clone());
+                Ok(entity)
+            }
+
+            async fn update(&self, id: &str, entity: &$entity) -> DomainResponse<$entity> {
+                let mut rows = self.rows.lock().unwrap();
+                let row = rows
+                    .iter_mut()
+                    .find(|row| row.base.id.as_deref() == Some(id))
+                    .ok_or_else(|| Failure::NotFound(format!("Entity with id {} not found", id)))?;
+                *row = entity.clone();
+                Ok(entity.clone())
+            }
+
+            async fn delete(&self, id: &str) -> DomainResponse<usize> {
+                let mut rows = self.rows.lock().unwrap();
+                let row = rows.iter_mut().find(|row| row.base.id.as_deref() == Some(id));
+                Ok(row.map(|row| row.base.deleted_at = Some(chrono::Utc::now().timestamp())).map_or(0, |_| 1))
+            }
+
+            async fn remove(&self, id: &str) -> DomainResponse<usize> {
+                let mut rows = self.rows.lock().unwrap();
+                let before = rows.len();
+                rows.retain(|row| row.base.id.as_deref() != Some(id));
+                Ok(before - rows.len())
+            }
+
+            async fn find(&self, id: &str) -> DomainResponse<Option<$entity>> {
+                let rows = self.rows.lock().unwrap();
+                Ok(rows.iter().find(|row| row.base.id.as_deref() == Some(id) && row.base.deleted_at.is_none()).cloned())
+            }
+
+            async fn find_and_delete(&self, id: &str) -> DomainResponse<$entity> {
+                let entity = self.find(id).await?.ok_or_else(|| Failure::NotFound(id.to_string()))?;
+                self.delete(id).await?;
+                Ok(entity)
+            }
+
+            async fn find_and_remove(&self, id: &str) -> DomainResponse<$entity> {
+                let entity = self.find(id).await?.ok_or_else(|| Failure::NotFound(id.to_string()))?;
+                self.remove(id).await?;
+                Ok(entity)
+            }
+
+            async fn finds(&self) -> DomainResponse<Vec<$entity>> {
+                let rows = self.rows.lock().unwrap();
+                Ok(rows.iter().filter(|row| row.base.deleted_at.is_none()).cloned().collect())
+            }
+
+            async fn finds_paginated(&self, page: u32, page_size: u32) -> DomainResponse<(Paginate, Vec<$entity>)> {
+                let rows = self.finds().await?;
+                let paginate = Paginate {
+                    page,
+                    page_size,
+                    total_page: (rows.len() as u32).div_ceil(page_size),
+                    total_record: rows.len() as u32,
+                };
+                let start = ((page - 1) * page_size) as usize;
+                Ok((paginate, rows.into_iter().skip(start).take(page_size as usize).collect()))
+            }
+
+            async fn find_by(
+                &self,
+                _spec: &QuerySpec,
+                _page: u32,
+                _page_size: u32,
+            ) -> DomainResponse<(Paginate, Vec<$entity>)> {
+                Err(Failure::NotImplemented("Specs are not needed by the push service".to_string()))
+            }
+        }
+    };
+}
+
+impl_in_memory_base_repository!(SessionEntity);
+impl_in_memory_base_repository!(TopicSubscriptionEntity);
+impl_in_memory_base_repository!(NotificationPreferenceEntity);
+
+#[async_trait]
+impl SessionRepository for InMemory<SessionEntity> {
+    async fn remove_by_account_id(&self, account_id: &str) -> DomainResponse<()> {
+        self.rows.lock().unwrap().retain(|session| session.account_id != account_id);
+        Ok(())
+    }
+
+    async fn find_by_jti(&self, jti: &str) -> DomainResponse<Option<SessionEntity>> {
+        Ok(self.rows.lock().unwrap().iter().find(|session| session.jti == jti).cloned())
+    }
+
+    async fn find_with_device_token(&self, account_id: &str) -> DomainResponse<Vec<SessionEntity>> {
+        let rows = self.rows.lock().unwrap();
+        Ok(rows
+            .iter()
+            .filter(|session| session.account_id == account_id && session.device_token.is_some())
+            .cloned()
+            .collect())
+    }
+
+    async fn clear_device_token(&self, device_token: &str) -> DomainResponse<usize> {
+        let mut rows = self.rows.lock().unwrap();
+        let holders = rows.iter_mut().filter(|session| session.device_token.as_deref() == Some(device_token));
+        Ok(holders.map(|session| session.device_token = None).count())
+    }
+
+    async fn find_by_account_id(&self, account_id: &str) -> DomainResponse<Vec<SessionEntity>> {
+        Ok(self.rows.lock().unwrap().iter().filter(|session| session.account_id == account_id).cloned().collect())
+    }
+}
+
+#[async_trait]
+impl TopicSubscriptionRepository for InMemory<TopicSubscriptionEntity> {
+    async fn find_by_account_id(&self, account_id: &str) -> DomainResponse<Vec<TopicSubscriptionEntity>> {
+        let rows = self.rows.lock().unwrap();
+        Ok(rows.iter().filter(|subscription| subscription.account_id == account_id).cloned().collect())
+    }
+
+    async fn find_by_account_and_topic(
+        &self,
+        account_id: &str,
+        topic: &str,
+    ) -> DomainResponse<Option<TopicSubscriptionEntity>> {
+        let rows = self.rows.lock().unwrap();
+        Ok(rows
+            .iter()
+            .find(|subscription| subscription.account_id == account_id && subscription.topic == topic)
+            .cloned())
+    }
+
+    async fn remove_by_account_id(&self, account_id: &str) -> DomainResponse<usize> {
+        let mut rows = self.rows.lock().unwrap();
+        let before = rows.len();
+        rows.retain(|subscription| subscription.account_id != account_id);
+        Ok(before - rows.len())
+    }
+}
+
+#[async_trait]
+impl NotificationPreferenceRepository for InMemory<NotificationPreferenceEntity> {
+    async fn find_by_account_id(&self, account_id: &str) -> DomainResponse<Option<NotificationPreferenceEntity>> {
+        Ok(self.rows.lock().unwrap().iter().find(|preference| preference.account_id == account_id).cloned())
+    }
+
+    async fn find_digest_due(&self, _now: i64, _limit: u32) -> DomainResponse<Vec<NotificationPreferenceEntity>> {
+        Ok(vec![])
+    }
+
+    async fn remove_by_account_id(&self, account_id: &str) -> DomainResponse<usize> {
+        let mut rows = self.rows.lock().unwrap();
+        let before = rows.len();
+        rows.retain(|preference| preference.account_id != account_id);
+        Ok(before - rows.len())
+    }
+}
+
+struct Harness {
+    sessions: Arc<InMemory<SessionEntity>>,
+    preferences: Arc<InMemory<NotificationPreferenceEntity>>,
+    messaging: Arc<MockMessagingApiImpl>,
+    push_service: PushServiceImpl,
+}
+
+impl Harness {
+    fn new() -> Self {
+        let sessions = Arc::new(InMemory::<SessionEntity>::default());
+        let subscriptions = Arc::new(InMemory::<TopicSubscriptionEntity>::default());
+        let preferences = Arc::new(InMemory::<NotificationPreferenceEntity>::default());
+        let messaging = Arc::new(MockMessagingApiImpl::new());
+        let push_service =
+            PushServiceImpl::new(sessions.clone(), subscriptions, preferences.clone(), messaging.clone());
+
+        Self { sessions, preferences, messaging, push_service }
+    }
+
+    async fn session_with_device(&self, device_token: &str) -> SessionEntity {
+        let session = SessionEntity::new(
+            true,
+            ACCOUNT_ID.to_string(),
+            uuid::Uuid::now_v7().to_string(),
+            chrono::Utc::now().timestamp() + 3600,
+            "127.0.0.1".to_string(),
+            "tests".to_string(),
+            "desktop".to_string(),
+        );
+        let session = self.sessions.create(&session).await.unwrap();
+        self.push_service.register_device(&session, device_token).await.unwrap()
+    }
+}
+
+fn notification(title: &str) -> PushNotification {
+    PushNotification { title: title.to_string(), body: "Body".to_string(), data: None }
+}
+
+#[tokio::test]
+async fn push_to_account_reaches_every_device_and_prunes_dead_tokens() {
+    let harness = Harness::new();
+    harness.session_with_device("device-a").await;
+    harness.session_with_device("device-b").await;
+    let dead = harness.session_with_device("invalid-c").await;
+
+    let response = harness
+        .push_service
+        .push_to_account(ACCOUNT_ID, NotificationKind::System, &notification("Hello"))
+        .await
+        .unwrap();
+
+    assert_eq!(response.message_ids.len(), 2);
+    assert_eq!(response.pruned_tokens, 1);
+    assert!(!response.suppressed);
+
+    let mut targets: Vec<String> = harness.messaging.sent().into_iter().map(|push| push.target).collect();
+    targets.sort();
+    assert_eq!(targets, vec!["device-a", "device-b"]);
+
+    let dead = harness.sessions.find(dead.base.id.as_deref().unwrap()).await.unwrap().unwrap();
+    assert_eq!(dead.device_token, None);
+}
+
+#[tokio::test]
+async fn push_to_account_is_suppressed_when_the_kind_is_muted() {
+    let harness = Harness::new();
+    harness.session_with_device("device-a").await;
+
+    let mut preferences = NotificationPreferenceEntity::new(true, ACCOUNT_ID);
+    preferences.set_channel(NotificationKind::System, NotificationChannel::Push, false);
+    harness.preferences.create(&preferences).await.unwrap();
+
+    let response = harness
+        .push_service
+        .push_to_account(ACCOUNT_ID, NotificationKind::System, &notification("Muted"))
+        .await
+        .unwrap();
+
+    assert!(response.suppressed);
+    assert!(harness.messaging.sent().is_empty());
+}
+
+#[tokio::test]
+async fn registering_a_device_again_moves_its_token_to_the_new_session() {
+    let harness = Harness::new();
+    let first = harness.session_with_device("device-a").await;
+    let second = harness.session_with_device("device-a").await;
+
+    let first = harness.sessions.find(first.base.id.as_deref().unwrap()).await.unwrap().unwrap();
+    assert_eq!(first.device_token, None);
+    assert_eq!(second.device_token.as_deref(), Some("device-a"));
+
+    harness.push_service.push_to_account(ACCOUNT_ID, NotificationKind::System, &notification("Once")).await.unwrap();
+    assert_eq!(harness.messaging.sent().len(), 1);
+}
+
+#[tokio::test]
+async fn topic_subscriptions_follow_the_devices_of_the_account() {
+    let harness = Harness::new();
+    harness.session_with_device("device-a").await;
+
+    harness.push_service.subscribe_topic(ACCOUNT_ID, "releases").await.unwrap();
+    assert!(harness.messaging.topic_tokens("releases").contains("device-a"));
+
+    // Devices registered later join the topics the account already follows
+    harness.session_with_device("device-b").await;
+    assert!(harness.messaging.topic_tokens("releases").contains("device-b"));
+
+    let response = harness.push_service.push_to_topic("releases", &notification("New release")).await.unwrap();
+    assert_eq!(response.message_ids.len(), 1);
+    assert_eq!(harness.messaging.sent()[0].target, "/topics/releases");
+
+    harness.push_service.unsubscribe_topic(ACCOUNT_ID, "releases").await.unwrap();
+    assert!(harness.messaging.topic_tokens("releases").is_empty());
+}
@@ -0,0 +1,510 @@
+//! Behaviour every repository has to share whichever database serves it. The contract is first held against an
+//! in-memory repository and the filter specs against every backend translation, those run with every test run.
+//! The suites talking to the databases of the environment configuration run with the `live-databases` feature,
+//! `cargo test --test repository_conformance --features live-databases`.
+
+use async_trait::async_trait;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex};
+
+// shared modules
+use adapters::secondary::repositories::models::account_schema::ScyllaAccountSchema;
+use adapters::secondary::repositories::models::blog_revision_schema::ScyllaBlogRevisionSchema;
+use adapters::secondary::repositories::scylla::scylla_base_repository::{EntitySchema, paginate_entities};
+use adapters::shared::di::repository_module::{RepositoryModule, build_repository_module};
+use adapters::shared::utilities::databases::DatabaseType;
+use domain::entities::{
+    account_entity::AccountEntity, base_entity::BaseEntity, bookmark_entity::BookmarkEntity,
+    notification_entity::NotificationEntity, session_entity::SessionEntity,
+};
+use domain::repositories::account_repository::AccountSearch;
+use domain::repositories::base_repository::BaseRepository;
+use domain::values::{bookmark_target::BookmarkTarget, notification_kind::NotificationKind};
+use mongodb::bson::doc;
+use mongodb::bson::oid::ObjectId;
+use serde::Serialize;
+use shared::models::failure::Failure;
+use shared::models::filters::{
+    FilterCriteria, FilterField, FilterOperator, FilterValue, Filterable, MemoryFilter, MongoFilterConverter,
+    QuerySpec, ScyllaFilterConverter, SortCriteria, SortDirection,
+};
+use shared::models::paginate::Paginate;
+use shared::types::DomainResponse;
+
+static PAGE_SIZE: u32 = 2;
+
+/// The repositories of one database, with ids in the format that database assigns.
+struct Backend {
+    mongo: bool,
+    repositories: Arc<dyn RepositoryModule>,
+}
+
+impl Backend {
+    async fn new(database: DatabaseType) -> Self {
+        let mongo = matches!(database, DatabaseType::Mongo);
+        Self { mongo, repositories: build_repository_module(database).await }
+    }
+
+    fn new_id(&self) -> String {
+        if self.mongo { ObjectId::new().to_hex() } else { uuid::Uuid::now_v7().to_string() }
+    }
+
+    fn unique() -> String {
+        uuid::Uuid::new_v4().simple().to_string()[..12].to_string()
+    }
+
+    /// An id in the format of `id` no entity has.
+    fn unknown_id(id: &str) -> String {
+        match ObjectId::parse_str(id) {
+            Ok(_) => ObjectId::new().to_hex(),
+            Err(_) => uuid::Uuid::now_v7().to_string(),
+        }
+    }
+
+    fn new_account() -> AccountEntity {
+        let unique = Self::unique();
+        AccountEntity::new(false, format!("conf_{}", unique), format!("conf.{}@example.com", unique)).unwrap()
+    }
+
+    fn new_session(account_id: &str) -> SessionEntity {
+        SessionEntity::new(
+            false,
+            account_id.to_string(),
+            Self::unique(),
+            chrono::Utc::now().timestamp() + 3600,
+            "127.0.0.1".to_string(),
+            "conformance".to_string(),
+            "desktop".to_string(),
+        )
+    }
+
+    fn new_notification(account_id: &str, kind: NotificationKind) -> NotificationEntity {
+        NotificationEntity::new(false, account_id, kind, "conformance", HashMap::new())
+    }
+}
+
+trait Entity: Clone + Send + Sync + Serialize {
+    fn base(&self) -> &BaseEntity;
+    fn base_mut(&mut self) -> &mut BaseEntity;
+
+    fn id(&self) -> String {
+        self.base().id.clone().expect("a stored entity has an id")
+    }
+}
+
+macro_rules! impl_entity {
+    ($($entity:ty),* $(,)?) => {
+        $(
+            impl Entity for $entity {
+                fn base(&self) -> &BaseEntity {
+                    &self.base
+                }
+
+                fn base_mut(&mut self) -> &mut BaseEntity {
+                    &mut self.base
+                }
+            }
+        )*
+    };
+}
+
+impl_entity!(AccountEntity, SessionEntity, BookmarkEntity, NotificationEntity);
+
+/// The reference the database backends are held to, entities kept in a vector and specs run by `MemoryFilter`.
+struct MemoryRepository<E> {
+    entities: Mutex<Vec<E>>,
+    fields: &'static [FilterField],
+}
+
+impl<E: Entity> MemoryRepository<E> {
+    fn new(fields: &'static [FilterField]) -> Self {
+        Self { entities: Mutex::new(vec![]), fields }
+    }
+
+    fn not_found(id: &str) -> Failure {
+        Failure::NotFound(format!("Entity with id {} not found", id))
+    }
+
+    fn newest_first(mut entities: Vec<E>) -> Vec<E> {
+        entities.sort_by_key(|entity| std::cmp::Reverse(entity.base().created_at));
+        entities
+    }
+
+    fn live(&self) -> Vec<E> {
+        let entities = self.entities.lock().unwrap();
+        Self::newest_first(entities.iter().filter(|entity| entity.base().deleted_at.is_none()).cloned().collect())
+    }
+}
+
+#[async_trait]
+impl<E: Entity> BaseRepository<E> for MemoryRepository<E> {
+    async fn create(&self, entity: &E) -> DomainResponse<E> {
+        let mut created = entity.clone();
+        created.base_mut().id.get_or_insert_with(|| ObjectId::new().to_hex());
+        self.entities.lock().unwrap().push(created.clone());
+
+        Ok(created)
+    }
+
+    async fn update(&self, id: &str, entity: &E) -> DomainResponse<E> {
+        let mut entities = self.entities.lock().unwrap();
+        let stored = entities.iter_mut().find(|stored| stored.id() == id).ok_or_else(|| Self::not_found(id))?;
+        *stored = entity.clone();
+        stored.base_mut().id = Some(id.to_string());
+
+        Ok(stored.clone())
+    }
+
+    async fn delete(&self, id: &str) -> DomainResponse<usize> {
+        let mut entities = self.entities.lock().unwrap();
+        let Some(stored) = entities.iter_mut().find(|stored| stored.id() == id) else {
+            return Ok(0);
+        };
+        stored.base_mut().deleted_at = Some(chrono::Utc::now().timestamp());
+
+        Ok(1)
+    }
+
+    async fn remove(&self, id: &str) -> DomainResponse<usize> {
+        let mut entities = self.entities.lock().unwrap();
+        let count = entities.len();
+        entities.retain(|stored| stored.id() != id);
+
+        Ok(count - entities.len())
+    }
+
+    async fn find(&self, id: &str) -> DomainResponse<Option<E>> {
+        Ok(self.live().into_iter().find(|entity| entity.id() == id))
+    }
+
+    async fn find_and_delete(&self, id: &str) -> DomainResponse<E> {
+        let entity = self.find(id).await?.ok_or_else(|| Self::not_found(id))?;
+        self.delete(id).await?;
+
+        Ok(entity)
+    }
+
+    async fn find_and_remove(&self, id: &str) -> DomainResponse<E> {
+        let entities = self.entities.lock().unwrap().clone();
+        let entity = entities.into_iter().find(|entity| entity.id() == id).ok_or_else(|| Self::not_found(id))?;
+        self.remove(id).await?;
+
+        Ok(entity)
+    }
+
+    async fn finds(&self) -> DomainResponse<Vec<E>> {
+        Ok(self.live())
+    }
+
+    async fn finds_paginated(&self, page: u32, page_size: u32) -> DomainResponse<(Paginate, Vec<E>)> {
+        Ok(paginate_entities(self.live(), page, page_size))
+    }
+
+    async fn find_by(&self, spec: &QuerySpec, page: u32, page_size: u32) -> DomainResponse<(Paginate, Vec<E>)> {
+        let mut entities: Vec<(serde_json::Value, E)> = self
+            .live()
+            .into_iter()
+            .map(|entity| (serde_json::to_value(&entity).unwrap_or_default(), entity))
+            .filter(|(value, _)| MemoryFilter::matches(spec, self.fields, value))
+            .collect();
+        entities.sort_by(|(a, _), (b, _)| MemoryFilter::compare(spec, self.fields, a, b));
+
+        Ok(paginate_entities(entities.into_iter().map(|(_, entity)| entity).collect(), page, page_size))
+    }
+}
+
+/// Ids of every live entity, walking `finds_paginated` until a page comes back short.
+async fn walk_pages<E: Entity>(repository: &dyn BaseRepository<E>) -> Vec<String> {
+    let mut ids = vec![];
+    for page in 1.. {
+        let (paginate, entities) = repository.finds_paginated(page, PAGE_SIZE).await.unwrap();
+        assert_eq!(paginate.page, page);
+        assert!(entities.len() <= PAGE_SIZE as usize);
+        assert!(paginate.total_record as usize >= ids.len() + entities.len());
+
+        let last = entities.len() < PAGE_SIZE as usize;
+        ids.extend(entities.iter().map(Entity::id));
+        if last {
+            assert!(paginate.total_page <= page);
+            return ids;
+        }
+        assert!(paginate.total_page >= page);
+    }
+
+    unreachable!()
+}
+
+/// The create, read, update and delete contract of `BaseRepository`. `new_entity` has to build a different entity
+/// on every call, without an id.
+async fn base_repository_conforms<E, F>(repository: &dyn BaseRepository<E>, new_entity: F)
+where
+    E: Entity,
+    F: Fn() -> E,
+{
+    // Created entities get an id and are found by it
+    let created = repository.create(&new_entity()).await.unwrap();
+    let id = created.id();
+    let found = repository.find(&id).await.unwrap().expect("the created entity is found");
+    assert_eq!(found.id(), id);
+    assert!(repository.finds().await.unwrap().iter().any(|entity| entity.id() == id));
+
+    // Updates replace the stored entity, an unknown id is not found
+    let mut changed = found.clone();
+    changed.base_mut().updated_at += 60;
+    repository.update(&id, &changed).await.unwrap();
+    let found = repository.find(&id).await.unwrap().expect("the updated entity is found");
+    assert_eq!(found.base().updated_at, changed.base().updated_at);
+    let unknown = Backend::unknown_id(&id);
+    assert!(matches!(repository.update(&unknown, &changed).await, Err(Failure::NotFound(_))));
+    assert!(repository.find(&unknown).await.unwrap().is_none());
+
+    // Pages never overlap and together hold every live entity
+    let others = [repository.create(&new_entity()).await.unwrap(), repository.create(&new_entity()).await.unwrap()];
+    let ids = walk_pages(repository).await;
+    assert_eq!(ids.len(), ids.iter().collect::<HashSet<_>>().len());
+    assert!(ids.contains(&id));
+    assert!(others.iter().all(|other| ids.contains(&other.id())));
+
+    // The empty spec matches every live entity
+    let (_, entities) = repository.find_by(&QuerySpec::default(), 1, 100).await.unwrap();
+    assert!(!entities.is_empty());
+
+    // Soft deleted entities are hidden from every read but can still be removed
+    assert_eq!(repository.delete(&id).await.unwrap(), 1);
+    assert!(repository.find(&id).await.unwrap().is_none());
+    assert!(!repository.finds().await.unwrap().iter().any(|entity| entity.id() == id));
+    assert!(!walk_pages(repository).await.contains(&id));
+    assert_eq!(repository.remove(&id).await.unwrap(), 1);
+    assert_eq!(repository.remove(&id).await.unwrap(), 0);
+
+    // The find-and variants return the entity as it was
+    let deleted = repository.find_and_delete(&others[0].id()).await.unwrap();
+    assert_eq!(deleted.id(), others[0].id());
+    assert!(repository.find(&others[0].id()).await.unwrap().is_none());
+    repository.remove(&others[0].id()).await.unwrap();
+    let removed = repository.find_and_remove(&others[1].id()).await.unwrap();
+    assert_eq!(removed.id(), others[1].id());
+    assert!(matches!(repository.find_and_remove(&others[1].id()).await, Err(Failure::NotFound(_))));
+}
+
+async fn base_repositories_conform(backend: &Backend) {
+    let repositories = &backend.repositories;
+    let account_id = backend.new_id();
+
+    base_repository_conforms(repositories.get_account_repository().as_ref(), Backend::new_account).await;
+    base_repository_conforms(repositories.get_session_repository().as_ref(), || Backend::new_session(&account_id))
+        .await;
+    base_repository_conforms(repositories.get_bookmark_repository().as_ref(), || {
+        BookmarkEntity::new(false, &account_id, BookmarkTarget::Blog, &backend.new_id())
+    })
+    .await;
+    base_repository_conforms(repositories.get_notification_repository().as_ref(), || {
+        Backend::new_notification(&account_id, NotificationKind::System)
+    })
+    .await;
+}
+
+async fn account_repository_conforms(backend: &Backend) {
+    let repository = backend.repositories.get_account_repository();
+    let account = repository.create(&Backend::new_account()).await.unwrap();
+
+    // Emails match exactly, usernames whatever their case
+    let by_email = repository.find_by_email(&account.email).await.unwrap();
+    assert_eq!(by_email.map(|found| found.id()), Some(account.id()));
+    let by_username = repository.find_by_username(&account.username.to_uppercase()).await.unwrap();
+    assert_eq!(by_username.map(|found| found.id()), Some(account.id()));
+
+    // A renamed account is no longer found by its old username
+    let mut renamed = account.clone();
+    renamed.username = format!("conf_{}", Backend::unique());
+    repository.update(&account.id(), &renamed).await.unwrap();
+    assert!(repository.find_by_username(&account.username).await.unwrap().is_none());
+    let by_username = repository.find_by_username(&renamed.username).await.unwrap();
+    assert_eq!(by_username.map(|found| found.id()), Some(account.id()));
+
+    // Searches narrow on every criterion given
+    let search = AccountSearch { email: Some(account.email.clone()), ..Default::default() };
+    let now = chrono::Utc::now().timestamp();
+    let (_, found) = repository.search(&search, &QuerySpec::default(), now, 1, 10).await.unwrap();
+    assert_eq!(found.iter().map(Entity::id).collect::<Vec<_>>(), vec![account.id()]);
+
+    repository.remove(&account.id()).await.unwrap();
+    assert!(repository.find_by_email(&account.email).await.unwrap().is_none());
+    assert!(repository.find_by_username(&renamed.username).await.unwrap().is_none());
+}
+
+async fn session_repository_conforms(backend: &Backend) {
+    let repository = backend.repositories.get_session_repository();
+    let account_id = backend.new_id();
+    let session = repository.create(&Backend::new_session(&account_id)).await.unwrap();
+    repository.create(&Backend::new_session(&account_id)).await.unwrap();
+
+    let by_jti = repository.find_by_jti(&session.jti).await.unwrap();
+    assert_eq!(by_jti.map(|found| found.id()), Some(session.id()));
+    assert_eq!(repository.find_by_account_id(&account_id).await.unwrap().len(), 2);
+
+    repository.remove_by_account_id(&account_id).await.unwrap();
+    assert!(repository.find_by_account_id(&account_id).await.unwrap().is_empty());
+    assert!(repository.find_by_jti(&session.jti).await.unwrap().is_none());
+}
+
+async fn bookmark_repository_conforms(backend: &Backend) {
+    let repository = backend.repositories.get_bookmark_repository();
+    let account_id = backend.new_id();
+    let target_id = backend.new_id();
+
+    // Bookmarking a target twice keeps a single bookmark
+    let bookmark = BookmarkEntity::new(false, &account_id, BookmarkTarget::Course, &target_id);
+    let first = repository.upsert(&bookmark).await.unwrap();
+    let second = repository.upsert(&bookmark).await.unwrap();
+    assert_eq!(first.id(), second.id());
+    repository.upsert(&BookmarkEntity::new(false, &account_id, BookmarkTarget::Blog, &backend.new_id())).await.unwrap();
+
+    let (paginate, bookmarks) = repository.find_by_account_id(&account_id, None, 1, 10).await.unwrap();
+    assert_eq!((paginate.total_record, bookmarks.len()), (2, 2));
+    let course = BookmarkTarget::Course.as_str();
+    let (_, bookmarks) = repository.find_by_account_id(&account_id, Some(course), 1, 10).await.unwrap();
+    assert_eq!(bookmarks.iter().map(Entity::id).collect::<Vec<_>>(), vec![first.id()]);
+    let ids = [target_id.clone(), backend.new_id()];
+    assert_eq!(repository.find_bookmarked_ids(&account_id, course, &ids).await.unwrap(), vec![target_id.clone()]);
+
+    assert_eq!(repository.remove_by_target(&account_id, course, &target_id).await.unwrap(), 1);
+    assert_eq!(repository.remove_by_account_id(&account_id).await.unwrap(), 1);
+    let (_, bookmarks) = repository.find_by_account_id(&account_id, None, 1, 10).await.unwrap();
+    assert!(bookmarks.is_empty());
+}
+
+async fn notification_repository_conforms(backend: &Backend) {
+    let repository = backend.repositories.get_notification_repository();
+    let account_id = backend.new_id();
+    for kind in [NotificationKind::System, NotificationKind::BlogComment, NotificationKind::BlogComment] {
+        repository.create(&Backend::new_notification(&account_id, kind)).await.unwrap();
+    }
+
+    // Muted kinds are left out of every read and write
+    let muted = vec![NotificationKind::BlogComment.as_str().to_string()];
+    assert_eq!(repository.count_unread(&account_id, &[]).await.unwrap(), 3);
+    assert_eq!(repository.count_unread(&account_id, &muted).await.unwrap(), 1);
+    let (_, notifications) = repository.find_by_account_id_paginated(&account_id, true, &muted, 1, 10).await.unwrap();
+    assert_eq!(notifications.len(), 1);
+
+    let now = chrono::Utc::now().timestamp();
+    assert_eq!(repository.mark_all_read(&account_id, &muted, now).await.unwrap(), 1);
+    assert_eq!(repository.count_unread(&account_id, &[]).await.unwrap(), 2);
+    assert_eq!(repository.mark_all_read(&account_id, &[], now).await.unwrap(), 2);
+    assert_eq!(repository.count_unread(&account_id, &[]).await.unwrap(), 0);
+
+    assert_eq!(repository.remove_by_account_id(&account_id).await.unwrap(), 3);
+    let (_, notifications) = repository.find_by_account_id_paginated(&account_id, false, &[], 1, 10).await.unwrap();
+    assert!(notifications.is_empty());
+}
+
+async fn repositories_conform(database: DatabaseType) {
+    let backend = Backend::new(database).await;
+
+    base_repositories_conform(&backend).await;
+    account_repository_conforms(&backend).await;
+    session_repository_conforms(&backend).await;
+    bookmark_repository_conforms(&backend).await;
+    notification_repository_conforms(&backend).await;
+}
+
+/// The query string parsed against the account allow-list.
+fn account_spec(query: &[(&str, &str)]) -> QuerySpec {
+    let params = query.iter().map(|(key, value)| (key.to_string(), value.to_string())).collect();
+    QuerySpec::parse(&params, AccountEntity::filter_fields()).unwrap()
+}
+
+/// Usernames of the accounts the spec selects, in its order.
+async fn memory_usernames(spec: &QuerySpec, accounts: &[AccountEntity]) -> Vec<String> {
+    let repository = MemoryRepository::new(AccountEntity::filter_fields());
+    for account in accounts {
+        repository.create(account).await.unwrap();
+    }
+
+    let (_, found) = repository.find_by(spec, 1, 10).await.unwrap();
+    found.into_iter().map(|account| account.username).collect()
+}
+
+#[tokio::test]
+async fn memory_repositories_conform() {
+    let account_id = ObjectId::new().to_hex();
+    let fields = AccountEntity::filter_fields();
+
+    base_repository_conforms(&MemoryRepository::new(fields), Backend::new_account).await;
+    base_repository_conforms(&MemoryRepository::new(&[]), || Backend::new_session(&account_id)).await;
+    base_repository_conforms(&MemoryRepository::new(&[]), || {
+        BookmarkEntity::new(false, &account_id, BookmarkTarget::Blog, &ObjectId::new().to_hex())
+    })
+    .await;
+    base_repository_conforms(&MemoryRepository::new(&[]), || {
+        Backend::new_notification(&account_id, NotificationKind::System)
+    })
+    .await;
+}
+
+#[tokio::test]
+async fn filters_translate_alike_on_every_backend() {
+    let accounts: Vec<AccountEntity> = [("ada", 100), ("grace", 200), ("linus", 300)]
+        .into_iter()
+        .map(|(username, created_at)| {
+            let mut account =
+                AccountEntity::new(false, username.to_string(), format!("{}@example.com", username)).unwrap();
+            account.base.created_at = created_at;
+            account
+        })
+        .collect();
+
+    // Text matches ignore case and take the value literally
+    let spec = account_spec(&[("filter[username][startsWith]", "GR")]);
+    assert_eq!(
+        MongoFilterConverter::convert_to_mongo_filter(&spec),
+        doc! { "username": { "$regex": "^GR", "$options": "i" } }
+    );
+    assert_eq!(memory_usernames(&spec, &accounts).await, vec!["grace"]);
+
+    // Conditions on one field are merged into a range
+    let spec = account_spec(&[("filter[createdAt][gte]", "200"), ("filter[createdAt][lt]", "300")]);
+    assert_eq!(
+        MongoFilterConverter::convert_to_mongo_filter(&spec),
+        doc! { "created_at": { "$gte": 200_i64, "$lt": 300_i64 } }
+    );
+    assert_eq!(memory_usernames(&spec, &accounts).await, vec!["grace"]);
+
+    // Sorts keep their direction and Mongo breaks ties on the id, without a sort the newest come first
+    let spec = account_spec(&[("filter[username][in]", "ada,linus"), ("sort", "createdAt")]);
+    assert_eq!(MongoFilterConverter::convert_to_mongo_filter(&spec), doc! { "username": { "$in": ["ada", "linus"] } });
+    assert_eq!(MongoFilterConverter::convert_to_mongo_sort(&spec), Some(doc! { "created_at": 1, "_id": 1 }));
+    assert_eq!(memory_usernames(&spec, &accounts).await, vec!["ada", "linus"]);
+    assert_eq!(memory_usernames(&account_spec(&[]), &accounts).await, vec!["linus", "grace", "ada"]);
+
+    // Scylla serves no account field as a key, accounts are listed by scanning instead
+    let keys = ScyllaAccountSchema::keys();
+    assert!(ScyllaFilterConverter::convert_to_scylla_filter(&account_spec(&[]), keys).is_ok());
+    assert!(matches!(ScyllaFilterConverter::convert_to_scylla_filter(&spec, keys), Err(Failure::BadRequest(_))));
+
+    // Within a partition clustering columns take ranges and the order, without it they are refused
+    let blog_id = uuid::Uuid::now_v7().to_string();
+    let keys = ScyllaBlogRevisionSchema::keys();
+    let mut spec = QuerySpec::default()
+        .with_criteria(FilterCriteria::new("blog_id", FilterOperator::Eq, FilterValue::String(blog_id)))
+        .with_criteria(FilterCriteria::new("version", FilterOperator::Gte, FilterValue::Number(3)));
+    spec.sort.push(SortCriteria { field: "version".to_string(), direction: SortDirection::Descending });
+    let filter = ScyllaFilterConverter::convert_to_scylla_filter(&spec, keys).unwrap();
+    assert_eq!(filter.where_clause(), " WHERE blog_id = ? AND version >= ?");
+    assert_eq!(filter.order_clause(), " ORDER BY version DESC");
+    spec.criteria.remove(0);
+    assert!(matches!(ScyllaFilterConverter::convert_to_scylla_filter(&spec, keys), Err(Failure::BadRequest(_))));
+}
+
+#[tokio::test]
+#[cfg_attr(not(feature = "live-databases"), ignore = "needs the MongoDB of the environment configuration")]
+async fn mongo_repositories_conform() {
+    repositories_conform(DatabaseType::Mongo).await;
+}
+
+#[tokio::test]
+#[cfg_attr(not(feature = "live-databases"), ignore = "needs the ScyllaDB of the environment configuration")]
+async fn scylla_repositories_conform() {
+    repositories_conform(DatabaseType::Scylla).await;
+}
@@ -1,5 +1,6 @@
-use axum::Router;
-use axum::http::{HeaderName, HeaderValue, Method};
+use axum::extract::Request;
+use axum::http::{HeaderName, HeaderValue, Method, Uri};
+use axum::{Extension, Router, middleware};
 use std::sync::Arc;
 use tower_http::cors::CorsLayer;
 use tower_http::trace;
@@ -7,11 +8,35 @@ use tower_http::trace::TraceLayer;
 use tracing::Level;
 
 // shared modules
+use adapters::primary::middlewares::audit_context_middleware::audit_context_middleware;
 use adapters::primary::routes;
 use adapters::shared::di::state::AppState;
 use adapters::shared::utilities::route_logger;
 use shared::configs::APP_CONFIG;
 
+// internal modules
+use crate::schedulers::spawn_schedulers;
+
+// Query parameters that carry credentials, stream clients send their access token this way
+static REDACTED_QUERY_PARAMS: [&str; 1] = ["access_token"];
+
+/// The request URI as it is logged, with the values of credential query parameters masked.
+fn redacted_uri(uri: &Uri) -> String {
+    let Some(query) = uri.query() else {
+        return uri.to_string();
+    };
+
+    let query = query
+        .split('&')
+        .map(|pair| match pair.split_once('=') {
+            Some((key, _)) if REDACTED_QUERY_PARAMS.contains(&key) => format!("{}=[redacted]", key),
+            _ => pair.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join("&");
+    format!("{}?{}", uri.path(), query)
+}
+
 fn allow_method_from_string(method: &str) -> Result<Method, Box<dyn std::error::Error>> {
     match method.to_uppercase().as_str() {
         "GET" => Ok(Method::GET),
@@ -56,7 +81,7 @@ fn build_cors() -> Result<CorsLayer, Box<dyn std::error::Error>> {
         .allow_headers(allow_headers))
 }
 
-pub async fn initialize_app() -> Result<Router, Box<dyn std::error::Error>> {
+pub async fn initialize_app() -> Result<(Router, Arc<AppState>), Box<dyn std::error::Error>> {
     tracing_subscriber::fmt()
         .with_target(false)
         .with_thread_ids(true)
@@ -70,12 +95,22 @@ pub async fn initialize_app() -> Result<Router, Box<dyn std::error::Error>> {
     tracing::info!("🦀 Server: {}:{}", APP_CONFIG.server.host, APP_CONFIG.server.port);
 
     let traces = TraceLayer::new_for_http()
-        .make_span_with(trace::DefaultMakeSpan::new().level(Level::INFO))
+        .make_span_with(|request: &Request| {
+            tracing::info_span!(
+                "request",
+                method = %request.method(),
+                uri = %redacted_uri(request.uri()),
+                version = ?request.version()
+            )
+        })
         .on_request(trace::DefaultOnRequest::new().level(Level::INFO))
         .on_response(trace::DefaultOnResponse::new().level(Level::INFO));
 
-    let state = AppState::new().await?;
-    let routes = routes::execute();
+    let state = Arc::new(AppState::new().await?);
+    spawn_schedulers(state.clone());
+
+    // Middlewares reach the application services through this extension, they run outside the router state
+    let routes = routes::execute().layer(middleware::from_fn(audit_context_middleware)).layer(Extension(state.clone()));
     route_logger::log_all_routes();
 
     match APP_CONFIG.cors.enabled {
@@ -86,12 +121,12 @@ pub async fn initialize_app() -> Result<Router, Box<dyn std::error::Error>> {
             }
 
             let cors = build_cors()?;
-            Ok(routes.layer(cors).layer(traces).with_state(Arc::new(state)))
+            Ok((routes.layer(cors).layer(traces).with_state(state.clone()), state))
         },
 
         false => {
             tracing::info!("🌐 CORS is disabled");
-            Ok(routes.layer(traces).with_state(Arc::new(state)))
+            Ok((routes.layer(traces).with_state(state.clone()), state))
         },
     }
 }
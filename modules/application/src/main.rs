@@ -1,3 +1,6 @@
+#![recursion_limit = "256"]
+
+use std::net::SocketAddr;
 use tokio::signal;
 
 // shared modules
@@ -5,20 +8,27 @@ use shared::configs::APP_CONFIG;
 
 // internal modules
 use crate::initialize::initialize_app;
+use crate::schedulers::shutdown_schedulers;
 
 mod initialize;
+mod schedulers;
 mod study;
 
 #[tokio::main]
 async fn main() {
-    let app = initialize_app().await.expect("Error initializing app");
+    let (app, state) = initialize_app().await.expect("Error initializing app");
 
     let host = APP_CONFIG.server.host.clone();
     let port = APP_CONFIG.server.port;
     let address = format!("{}:{}", host, port);
 
     let listener = tokio::net::TcpListener::bind(address).await.unwrap();
-    axum::serve(listener, app).with_graceful_shutdown(shutdown_signal()).await.expect("Failed to start server 🐧");
+    axum::serve(listener, app.into_make_service_with_connect_info::<SocketAddr>())
+        .with_graceful_shutdown(shutdown_signal())
+        .await
+        .expect("Failed to start server 🐧");
+
+    shutdown_schedulers(state).await;
 }
 
 async fn shutdown_signal() {
@@ -0,0 +1,59 @@
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::{MissedTickBehavior, interval};
+
+// shared modules
+use adapters::shared::di::state::AppState;
+use domain::usecases::blog_usecases::ScheduleBlogUseCase;
+
+static PUBLISH_INTERVAL: Duration = Duration::from_secs(30);
+static COUNTER_FLUSH_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Publishes scheduled blogs whose `publish_at` has passed. Runs on every replica, the repository only publishes
+/// a blog that is still scheduled so each one is published once.
+pub fn spawn(state: Arc<AppState>) {
+    tokio::spawn(async move {
+        let mut ticker = interval(PUBLISH_INTERVAL);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+
+            match state.blog_app_service.publish_scheduled_blogs().await {
+                Ok(result) => {
+                    if !result.published.is_empty() {
+                        tracing::info!("⏰ Published {} scheduled blog(s)", result.published.len());
+                    }
+                    for (blog_id, reason) in &result.failed {
+                        tracing::warn!("⏰ Failed to publish scheduled blog {}: {}", blog_id, reason);
+                    }
+                },
+                Err(failure) => tracing::error!("⏰ Failed to publish scheduled blogs: {}", failure.message()),
+            }
+        }
+    });
+}
+
+/// Writes the counters still held in memory before the process exits.
+pub async fn flush_counters_on_shutdown(state: &AppState) {
+    match state.blog_app_service.flush_blog_counters().await {
+        Ok(count) => tracing::info!("⏰ Flushed the counters of {} blog(s) before shutting down", count),
+        Err(failure) => tracing::error!("⏰ Failed to flush blog counters on shutdown: {}", failure.message()),
+    }
+}
+
+/// Writes the star and view counters aggregated in memory, one increment per blog.
+pub fn spawn_counter_flush(state: Arc<AppState>) {
+    tokio::spawn(async move {
+        let mut ticker = interval(COUNTER_FLUSH_INTERVAL);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+
+            if let Err(failure) = state.blog_app_service.flush_blog_counters().await {
+                tracing::error!("⏰ Failed to flush blog counters: {}", failure.message());
+            }
+        }
+    });
+}
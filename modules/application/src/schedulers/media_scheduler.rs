@@ -0,0 +1,27 @@
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::{MissedTickBehavior, interval};
+
+// shared modules
+use adapters::shared::di::state::AppState;
+use domain::usecases::media_usecases::ManageMediaUseCase;
+
+static COLLECT_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Deletes media nothing referenced for longer than the grace period, along with its files.
+pub fn spawn(state: Arc<AppState>) {
+    tokio::spawn(async move {
+        let mut ticker = interval(COLLECT_INTERVAL);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+
+            match state.media_app_service.collect_orphans().await {
+                Ok(collected) if collected > 0 => tracing::info!("⏰ Collected {} orphaned media asset(s)", collected),
+                Ok(_) => {},
+                Err(failure) => tracing::error!("⏰ Failed to collect orphaned media: {}", failure.message()),
+            }
+        }
+    });
+}
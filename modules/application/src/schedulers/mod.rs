@@ -0,0 +1,30 @@
+use std::sync::Arc;
+
+// shared modules
+use adapters::shared::di::state::AppState;
+
+mod blog_scheduler;
+mod media_scheduler;
+mod notification_scheduler;
+mod privacy_scheduler;
+mod realtime_scheduler;
+mod search_scheduler;
+mod upload_scheduler;
+
+pub fn spawn_schedulers(state: Arc<AppState>) {
+    blog_scheduler::spawn(state.clone());
+    blog_scheduler::spawn_counter_flush(state.clone());
+    media_scheduler::spawn(state.clone());
+    notification_scheduler::spawn(state.clone());
+    privacy_scheduler::spawn(state.clone());
+    privacy_scheduler::spawn_exports(state.clone());
+    realtime_scheduler::spawn(state.clone());
+    search_scheduler::spawn(state.clone());
+    upload_scheduler::spawn(state.clone());
+    tracing::info!("⏰ Schedulers started");
+}
+
+/// Saves what the schedulers still hold in memory, called once the server stopped taking requests.
+pub async fn shutdown_schedulers(state: Arc<AppState>) {
+    blog_scheduler::flush_counters_on_shutdown(&state).await;
+}
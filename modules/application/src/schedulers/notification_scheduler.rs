@@ -0,0 +1,33 @@
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::{MissedTickBehavior, interval};
+
+// shared modules
+use adapters::shared::di::state::AppState;
+use domain::usecases::notification_usecases::NotificationDigestUseCase;
+
+static DIGEST_INTERVAL: Duration = Duration::from_secs(5 * 60);
+
+/// Mails the daily and weekly notification digests that came due.
+pub fn spawn(state: Arc<AppState>) {
+    tokio::spawn(async move {
+        let mut ticker = interval(DIGEST_INTERVAL);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+
+            match state.notification_app_service.send_digests().await {
+                Ok(result) => {
+                    if result.sent > 0 {
+                        tracing::info!("⏰ Sent {} notification digest(s)", result.sent);
+                    }
+                    if result.failed > 0 {
+                        tracing::warn!("⏰ Failed to send {} notification digest(s)", result.failed);
+                    }
+                },
+                Err(failure) => tracing::error!("⏰ Failed to send notification digests: {}", failure.message()),
+            }
+        }
+    });
+}
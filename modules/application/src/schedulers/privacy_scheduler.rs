@@ -0,0 +1,52 @@
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::{MissedTickBehavior, interval};
+
+// shared modules
+use adapters::shared::di::state::AppState;
+use domain::usecases::privacy_usecases::{DataExportUseCase, ManageAccountStatusUseCase};
+
+static ERASURE_INTERVAL: Duration = Duration::from_secs(60 * 60);
+static EXPORT_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Erases the accounts whose deletion grace period passed.
+pub fn spawn(state: Arc<AppState>) {
+    tokio::spawn(async move {
+        let mut ticker = interval(ERASURE_INTERVAL);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+
+            match state.privacy_app_service.erase_due_accounts().await {
+                Ok(erased) if erased > 0 => tracing::info!("⏰ Erased {} account(s) due for deletion", erased),
+                Ok(_) => {},
+                Err(failure) => tracing::error!("⏰ Failed to erase accounts: {}", failure.message()),
+            }
+        }
+    });
+}
+
+/// Produces the requested data exports and deletes the archives past their retention.
+pub fn spawn_exports(state: Arc<AppState>) {
+    tokio::spawn(async move {
+        let mut ticker = interval(EXPORT_INTERVAL);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+
+            match state.privacy_app_service.process_pending_exports().await {
+                Ok(processed) if processed > 0 => tracing::info!("⏰ Processed {} data export(s)", processed),
+                Ok(_) => {},
+                Err(failure) => tracing::error!("⏰ Failed to process data exports: {}", failure.message()),
+            }
+
+            match state.privacy_app_service.collect_expired_exports().await {
+                Ok(collected) if collected > 0 => tracing::info!("⏰ Deleted {} expired data export(s)", collected),
+                Ok(_) => {},
+                Err(failure) => tracing::error!("⏰ Failed to delete expired data exports: {}", failure.message()),
+            }
+        }
+    });
+}
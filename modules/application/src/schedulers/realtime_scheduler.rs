@@ -0,0 +1,25 @@
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::{MissedTickBehavior, interval};
+
+// shared modules
+use adapters::shared::di::state::AppState;
+
+static PRUNE_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Drops expired replay history and the channels of accounts that went offline.
+pub fn spawn(state: Arc<AppState>) {
+    tokio::spawn(async move {
+        let mut ticker = interval(PRUNE_INTERVAL);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+
+            let pruned = state.event_hub.prune();
+            if pruned > 0 {
+                tracing::debug!("⏰ Pruned {} idle realtime channel(s)", pruned);
+            }
+        }
+    });
+}
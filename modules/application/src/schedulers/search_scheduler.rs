@@ -0,0 +1,27 @@
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::{MissedTickBehavior, interval};
+
+// shared modules
+use adapters::shared::di::state::AppState;
+use domain::usecases::search_usecases::IndexSearchUseCase;
+
+static REBUILD_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Builds the search index on startup, then rebuilds it hourly to catch writes that bypassed the app services.
+/// Every replica holds its own index, writes served by another replica only show up here after a rebuild.
+pub fn spawn(state: Arc<AppState>) {
+    tokio::spawn(async move {
+        let mut ticker = interval(REBUILD_INTERVAL);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+
+            match state.search_app_service.rebuild_search_index().await {
+                Ok(count) => tracing::info!("⏰ Indexed {} search document(s)", count),
+                Err(failure) => tracing::error!("⏰ Failed to rebuild search index: {}", failure.message()),
+            }
+        }
+    });
+}
@@ -0,0 +1,27 @@
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::time::{MissedTickBehavior, interval};
+
+// shared modules
+use adapters::shared::di::state::AppState;
+use domain::usecases::upload_usecases::DirectUploadUseCase;
+
+static COLLECT_INTERVAL: Duration = Duration::from_secs(15 * 60);
+
+/// Deletes direct uploads that were never completed, along with whatever the client stored.
+pub fn spawn(state: Arc<AppState>) {
+    tokio::spawn(async move {
+        let mut ticker = interval(COLLECT_INTERVAL);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+
+            match state.upload_app_service.collect_expired_uploads().await {
+                Ok(collected) if collected > 0 => tracing::info!("⏰ Cleaned up {} expired upload(s)", collected),
+                Ok(_) => {},
+                Err(failure) => tracing::error!("⏰ Failed to clean up expired uploads: {}", failure.message()),
+            }
+        }
+    });
+}
@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+
+// shared modules
+use shared::types::DomainResponse;
+
+#[derive(Debug, Clone)]
+pub struct ArchiveEntry {
+    /// Path inside the archive, e.g. `blogs.json`.
+    pub path: String,
+    pub bytes: Vec<u8>,
+}
+
+#[async_trait]
+pub trait ArchiveApi: Send + Sync {
+    /// Bundle the entries into a ZIP archive.
+    async fn zip(&self, entries: Vec<ArchiveEntry>) -> DomainResponse<Vec<u8>>;
+}
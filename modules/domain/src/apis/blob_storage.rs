@@ -0,0 +1,35 @@
+use async_trait::async_trait;
+
+// shared modules
+use shared::types::DomainResponse;
+
+#[derive(Debug, Clone)]
+pub struct Blob {
+    pub bytes: Vec<u8>,
+    pub content_type: String,
+}
+
+/// What storage reports about a stored blob without downloading it.
+#[derive(Debug, Clone)]
+pub struct BlobMetadata {
+    pub size: i64,
+    /// MD5 of the content for single-part uploads, `None` when the backend does not expose it.
+    pub etag: Option<String>,
+}
+
+#[async_trait]
+pub trait BlobStorage: Send + Sync {
+    async fn put(&self, key: &str, bytes: Vec<u8>, content_type: &str) -> DomainResponse<()>;
+    async fn get(&self, key: &str) -> DomainResponse<Option<Blob>>;
+    async fn head(&self, key: &str) -> DomainResponse<Option<BlobMetadata>>;
+
+    /// Copy the blob under `from` to `to`, replacing what is there.
+    async fn copy(&self, from: &str, to: &str) -> DomainResponse<()>;
+
+    /// Deleting a missing blob is not an error.
+    async fn delete(&self, key: &str) -> DomainResponse<()>;
+    fn public_url(&self, key: &str) -> String;
+
+    /// URL the client can PUT the file to directly, valid for `expires_in` seconds.
+    async fn presign_put(&self, key: &str, content_type: &str, expires_in: u32) -> DomainResponse<String>;
+}
@@ -0,0 +1,36 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+
+// internal modules
+use crate::values::realtime_event_kind::RealtimeEventKind;
+
+/// Something that happened to an account, pushed to its live sessions.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RealtimeEvent {
+    /// Time ordered id, clients send back the last one they saw to replay what they missed.
+    pub id: String,
+    pub account_id: String,
+    pub event: String,
+    pub data: serde_json::Value,
+    pub created_at: i64,
+}
+
+impl RealtimeEvent {
+    pub fn new(account_id: &str, kind: RealtimeEventKind, data: serde_json::Value) -> Self {
+        Self {
+            id: uuid::Uuid::now_v7().to_string(),
+            account_id: account_id.to_string(),
+            event: kind.as_str().to_string(),
+            data,
+            created_at: chrono::Utc::now().timestamp(),
+        }
+    }
+}
+
+#[async_trait]
+pub trait EventHub: Send + Sync {
+    /// Deliver the event to every live session of its account, on every replica. Delivery is best
+    /// effort, sessions that miss it catch up through replay or by refetching.
+    async fn publish(&self, event: RealtimeEvent);
+}
@@ -0,0 +1,42 @@
+use async_trait::async_trait;
+
+// shared modules
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::values::image_format::ImageFormat;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImageVariant {
+    pub name: String,
+    pub max_width: u32,
+}
+
+impl ImageVariant {
+    /// Parse a `name:width` variant, `None` when malformed.
+    pub fn parse(variant: &str) -> Option<Self> {
+        let (name, width) = variant.split_once(':')?;
+        let max_width = width.trim().parse().ok().filter(|width| *width > 0)?;
+        Some(Self { name: name.trim().to_string(), max_width })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ProcessedImage {
+    pub variant: String,
+    pub bytes: Vec<u8>,
+    pub width: u32,
+    pub height: u32,
+}
+
+#[async_trait]
+pub trait ImageProcessor: Send + Sync {
+    /// Decode the image and encode one WebP per variant. Only pixels are carried over, so EXIF and
+    /// other metadata never reach storage.
+    async fn process(
+        &self,
+        bytes: &[u8],
+        format: ImageFormat,
+        variants: &[ImageVariant],
+    ) -> DomainResponse<Vec<ProcessedImage>>;
+}
@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+
+// shared modules
+use shared::types::DomainResponse;
+
+#[derive(Debug, Clone)]
+pub struct Mail {
+    pub to: String,
+    pub subject: String,
+    pub text: String,
+    pub html: Option<String>,
+}
+
+#[async_trait]
+pub trait MailApi: Send + Sync {
+    async fn send_mail(&self, mail: &Mail) -> DomainResponse<()>;
+}
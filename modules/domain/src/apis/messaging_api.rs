@@ -6,8 +6,26 @@ use shared::types::DomainResponse;
 // internal modules
 use crate::usecases::notification_usecases::PushNotification;
 
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PushDelivery {
+    /// Accepted by the transport, with the id it gave the message.
+    Sent(String),
+    /// The token no longer belongs to an installed app and should be forgotten.
+    InvalidToken,
+}
+
 #[async_trait]
 pub trait MessagingApi: Send + Sync {
-    async fn send_push_notification(&self, token: &str, notification: &PushNotification) -> DomainResponse<String>;
+    async fn send_push_notification(
+        &self,
+        token: &str,
+        notification: &PushNotification,
+    ) -> DomainResponse<PushDelivery>;
     async fn send_push_to_topic(&self, topic: &str, notification: &PushNotification) -> DomainResponse<String>;
+
+    /// Returns the tokens the transport rejected as invalid.
+    async fn subscribe_to_topic(&self, tokens: &[String], topic: &str) -> DomainResponse<Vec<String>>;
+
+    /// Returns the tokens the transport rejected as invalid.
+    async fn unsubscribe_from_topic(&self, tokens: &[String], topic: &str) -> DomainResponse<Vec<String>>;
 }
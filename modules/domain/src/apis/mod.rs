@@ -1,2 +1,8 @@
+pub mod archive_api;
 pub mod auth_api;
+pub mod blob_storage;
+pub mod event_hub;
+pub mod image_processor;
+pub mod mail_api;
 pub mod messaging_api;
+pub mod search_index;
@@ -0,0 +1,49 @@
+use async_trait::async_trait;
+
+// shared modules
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::usecases::search_usecases::SearchResult;
+
+/// Flattened view of a searchable entity, the index never sees the entities themselves.
+#[derive(Debug, Clone)]
+pub struct SearchDocument {
+    pub id: String,
+    pub kind: String,
+    pub title: String,
+    pub description: String,
+    pub content: String,
+    pub categories: Vec<String>,
+    pub tags: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SearchFilter {
+    pub kind: Option<String>,
+    pub category: Option<String>,
+    pub tag: Option<String>,
+}
+
+#[async_trait]
+pub trait SearchIndex: Send + Sync {
+    /// Insert the document or replace the one with the same id.
+    async fn upsert(&self, document: SearchDocument) -> DomainResponse<()>;
+    async fn remove(&self, id: &str) -> DomainResponse<()>;
+
+    /// Start keeping the upserts and removes that arrive from now on, `replace_all` applies them to the documents
+    /// it is given. Called before the documents of a rebuild are read, so writes made meanwhile are not lost.
+    async fn begin_rebuild(&self) -> DomainResponse<()>;
+
+    /// Drop every document and index `documents` instead, followed by the writes kept since `begin_rebuild`.
+    /// Returns the number of indexed documents.
+    async fn replace_all(&self, documents: Vec<SearchDocument>) -> DomainResponse<usize>;
+
+    async fn search(
+        &self,
+        text: &str,
+        filter: &SearchFilter,
+        page: u32,
+        page_size: u32,
+    ) -> DomainResponse<SearchResult>;
+}
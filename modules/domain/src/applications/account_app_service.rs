@@ -1,23 +1,46 @@
 use async_trait::async_trait;
+use serde_json::Value;
 use std::sync::Arc;
 
 // shared modules
 use shared::models::failure::Failure;
+use shared::models::filters::{Filterable, QuerySpec};
 use shared::models::paginate::Paginate;
 use shared::types::DomainResponse;
 
 // internal modules
-use crate::entities::{account_entity::AccountEntity, blog_entity::BlogEntity, role_entity::RoleEntity};
+use crate::entities::audit_event_entity::AuditEventEntity;
+use crate::entities::blog_entity::UpdateBlogEntity;
+use crate::entities::blog_revision_entity::BlogRevisionEntity;
+use crate::entities::{
+    account_entity::{AccountEntity, UpdateAccountEntity},
+    blog_entity::BlogEntity,
+    role_entity::RoleEntity,
+};
+use crate::repositories::account_repository::AccountSearch;
+use crate::services::audit_service::AuditService;
+use crate::services::blog_revision_service::BlogRevisionService;
+use crate::services::category_service::CategoryService;
+use crate::services::search_service::SearchService;
 use crate::services::{account_service::AccountService, blog_service::BlogService, role_service::RoleService};
 use crate::usecases::account_usecases::{
-    AddRoleToAccountParams, FindAccountWithEmailQuery, FindAccountsQuery, ManageAccountsUseCase,
-    ManageRoleAccountUseCase, MangeBlogAccountUseCase, PublishBlogParams, UpdateBlogParams, UpdateRoleToAccountParams,
+    AddRoleToAccountParams, ChangeBlogStatusParams, DiffBlogRevisionsQuery, FindAccountWithEmailQuery,
+    FindAccountsQuery, FindPublicProfileQuery, ManageAccountsUseCase, ManageBlogRevisionUseCase, ManageProfileUseCase,
+    ManageRoleAccountUseCase, MangeBlogAccountUseCase, PublicProfile, PublishBlogParams, UpdateBlogParams,
+    UpdateProfileParams, UpdateRoleToAccountParams,
 };
+use crate::values::account_status::AccountStatus;
+use crate::values::audit_action::AuditAction;
+use crate::values::text_diff::TextDiff;
 
 pub struct AccountAppService {
     account_service: Arc<dyn AccountService>,
     role_service: Arc<dyn RoleService>,
     blog_service: Arc<dyn BlogService>,
+    blog_revision_service: Arc<dyn BlogRevisionService>,
+    category_service: Arc<dyn CategoryService>,
+    search_service: Arc<dyn SearchService>,
+    audit_service: Arc<dyn AuditService>,
 }
 
 impl AccountAppService {
@@ -25,16 +48,69 @@ impl AccountAppService {
         account_service: Arc<dyn AccountService>,
         role_service: Arc<dyn RoleService>,
         blog_service: Arc<dyn BlogService>,
+        blog_revision_service: Arc<dyn BlogRevisionService>,
+        category_service: Arc<dyn CategoryService>,
+        search_service: Arc<dyn SearchService>,
+        audit_service: Arc<dyn AuditService>,
     ) -> Self {
-        Self { account_service, role_service, blog_service }
+        Self {
+            account_service,
+            role_service,
+            blog_service,
+            blog_revision_service,
+            category_service,
+            search_service,
+            audit_service,
+        }
+    }
+
+    async fn find_owned_blog(&self, account_id: &str, blog_id: &str) -> DomainResponse<BlogEntity> {
+        let blog = self
+            .blog_service
+            .find_blog_by_id(blog_id)
+            .await?
+            .ok_or(Failure::NotFound(format!("Blog with id {} not found", blog_id)))?;
+
+        if blog.author_id != account_id {
+            return Err(Failure::Forbidden("You are not the author of this blog".to_string()));
+        }
+
+        Ok(blog)
     }
 }
 
 // region =================================== MANAGE ACCOUNT USE CASE ===================================
 #[async_trait]
 impl ManageAccountsUseCase for AccountAppService {
-    async fn find_accounts(&self, query: &FindAccountsQuery) -> DomainResponse<(Paginate, Vec<AccountEntity>)> {
-        self.account_service.find_accounts_paginated(query.paginate.page, query.paginate.page_size).await
+    async fn find_accounts(&self, query: &FindAccountsQuery) -> DomainResponse<(Paginate, Vec<Value>)> {
+        // 1. Parse the filters against the fields of an account
+        let spec = QuerySpec::parse(&query.params, AccountEntity::filter_fields())?;
+
+        // 2. Roles live apart from accounts, resolve the accounts holding the role first
+        let account_ids = match &query.role {
+            Some(role) => Some(self.role_service.find_account_ids_by_role(&role.to_uppercase()).await?),
+            None => None,
+        };
+
+        // 3. Search with every filter given
+        let search = AccountSearch {
+            email: query.email.clone().filter(|email| !email.trim().is_empty()),
+            username: query.username.clone().filter(|username| !username.trim().is_empty()),
+            email_verified: query.email_verified,
+            status: query.status.as_deref().map(AccountStatus::from_string).transpose()?,
+            account_ids,
+        };
+        let (paginate, accounts) =
+            self.account_service.search_accounts(&search, &spec, query.paginate.page, query.paginate.page_size).await?;
+
+        // 4. Keep the selected fields
+        let accounts = accounts
+            .iter()
+            .map(|account| serde_json::to_value(account).map(|value| spec.project(value)))
+            .collect::<Result<Vec<Value>, _>>()
+            .map_err(|e| Failure::InternalServerError(format!("Failed to serialize accounts: {}", e)))?;
+
+        Ok((paginate, accounts))
     }
 
     async fn find_account_with_id(&self, account_id: &str) -> DomainResponse<Option<AccountEntity>> {
@@ -71,7 +147,13 @@ impl ManageRoleAccountUseCase for AccountAppService {
         }
 
         // 3. Create role
-        Ok(self.role_service.create_role(account_id, &params.role_name).await?)
+        let role = self.role_service.create_role(account_id, &params.role_name).await?;
+        let role_id = role.base.id.clone().unwrap_or_default();
+        let event =
+            AuditEventEntity::new(false, AuditAction::RoleGranted, Some(&role_id)).with_changes(None, Some(&role));
+        self.audit_service.emit(event).await?;
+
+        Ok(role)
     }
 
     async fn update_role_for_account(
@@ -86,7 +168,18 @@ impl ManageRoleAccountUseCase for AccountAppService {
         }
 
         // 2. Find and update role
+        let previous = self.role_service.find_role_by_account_id(account_id).await?;
         let role_updated = self.role_service.find_and_update_role_by_account_id(account_id, &params.role_name).await?;
+
+        // 3. Record the change, the role returned by the update may still hold the previous name
+        if let Some(previous) = previous {
+            let role_id = previous.base.id.clone().unwrap_or_default();
+            let current = RoleEntity { role_name: params.role_name.clone(), ..previous.clone() };
+            let event = AuditEventEntity::new(false, AuditAction::RoleChanged, Some(&role_id))
+                .with_changes(Some(&previous), Some(&current));
+            self.audit_service.emit(event).await?;
+        }
+
         Ok(role_updated)
     }
 
@@ -118,7 +211,10 @@ impl MangeBlogAccountUseCase for AccountAppService {
             .await?
             .ok_or(Failure::NotFound(format!("Account with id {} not found", account_id)))?;
 
-        // 2. Publish blog
+        // 2. Every category must exist
+        self.category_service.ensure_categories_exist(&params.categories).await?;
+
+        // 3. Publish blog
         let blog_entity = self
             .blog_service
             .create_blog(
@@ -127,27 +223,177 @@ impl MangeBlogAccountUseCase for AccountAppService {
                 &params.description,
                 &params.markdown,
                 &params.categories,
-                params.is_published,
+                &params.status,
+                params.publish_at,
                 params.estimated_read_time,
+                params.revision_retention,
             )
             .await?;
 
+        // 4. Record the initial revision
+        self.blog_revision_service.record_revision(&blog_entity, account_id, None).await?;
+
+        // 5. Keep the search index in sync
+        self.search_service.index_blog(&blog_entity).await?;
+
         Ok(blog_entity)
     }
 
     async fn update_account_blog(
         &self,
-        _account_id: &str,
-        _blog_id: &str,
-        _params: &UpdateBlogParams,
+        account_id: &str,
+        blog_id: &str,
+        params: &UpdateBlogParams,
     ) -> DomainResponse<BlogEntity> {
-        // Implementation goes here
-        unimplemented!()
+        // 1. Every category must exist
+        if let Some(categories) = &params.categories {
+            self.category_service.ensure_categories_exist(categories).await?;
+        }
+
+        // 2. Update blog, the service checks ownership
+        let update = UpdateBlogEntity {
+            categories: params.categories.clone(),
+            name: params.name.clone(),
+            description: params.description.clone(),
+            markdown: params.markdown.clone(),
+            estimated_read_time: params.estimated_read_time,
+            revision_retention: params.revision_retention,
+        };
+        let blog_entity = self.blog_service.update_blog(account_id, blog_id, &update).await?;
+
+        // 3. Record a revision when the content changed
+        self.blog_revision_service.record_revision(&blog_entity, account_id, None).await?;
+
+        // 4. Keep the search index in sync
+        self.search_service.index_blog(&blog_entity).await?;
+
+        Ok(blog_entity)
+    }
+
+    async fn change_account_blog_status(
+        &self,
+        account_id: &str,
+        blog_id: &str,
+        params: &ChangeBlogStatusParams,
+    ) -> DomainResponse<BlogEntity> {
+        // 1. Change status, the service checks ownership and the transition
+        let blog_entity =
+            self.blog_service.change_blog_status(account_id, blog_id, &params.status, params.publish_at).await?;
+
+        // 2. Blogs leaving the published state also leave the search index
+        self.search_service.index_blog(&blog_entity).await?;
+
+        Ok(blog_entity)
     }
 
-    async fn delete_account_blog(&self, _account_id: &str, _blog_id: &str) -> DomainResponse<()> {
-        // Implementation goes here
-        unimplemented!()
+    async fn delete_account_blog(&self, account_id: &str, blog_id: &str) -> DomainResponse<()> {
+        // 1. Verify blog ownership
+        self.find_owned_blog(account_id, blog_id).await?;
+
+        // 2. Delete blog and drop it from the search index
+        self.blog_service.delete_blog(blog_id).await?;
+        self.search_service.remove_document(blog_id).await
     }
 }
 // endregion =================================== MANAGE BLOG ACCOUNT USE CASE ===================================
+
+// region =================================== MANAGE BLOG REVISION USE CASE ===================================
+#[async_trait]
+impl ManageBlogRevisionUseCase for AccountAppService {
+    async fn find_blog_revisions(&self, account_id: &str, blog_id: &str) -> DomainResponse<Vec<BlogRevisionEntity>> {
+        // 1. Verify blog ownership
+        self.find_owned_blog(account_id, blog_id).await?;
+
+        // 2. Find revisions
+        self.blog_revision_service.find_revisions(blog_id).await
+    }
+
+    async fn diff_blog_revisions(
+        &self,
+        account_id: &str,
+        blog_id: &str,
+        query: &DiffBlogRevisionsQuery,
+    ) -> DomainResponse<TextDiff> {
+        // 1. Verify blog ownership
+        self.find_owned_blog(account_id, blog_id).await?;
+
+        // 2. Diff revisions
+        self.blog_revision_service.diff_revisions(blog_id, query.from, query.to).await
+    }
+
+    async fn restore_blog_revision(&self, account_id: &str, blog_id: &str, version: i32) -> DomainResponse<BlogEntity> {
+        // 1. Verify blog ownership and find revision
+        self.find_owned_blog(account_id, blog_id).await?;
+        let revision = self.blog_revision_service.find_revision(blog_id, version).await?;
+
+        // 2. Write the old content back to the blog
+        let update = UpdateBlogEntity {
+            categories: None,
+            name: None,
+            description: None,
+            markdown: Some(revision.markdown),
+            estimated_read_time: None,
+            revision_retention: None,
+        };
+        let blog_entity = self.blog_service.update_blog(account_id, blog_id, &update).await?;
+
+        // 3. Record the restore as a new revision, history is never rewritten
+        self.blog_revision_service.record_revision(&blog_entity, account_id, Some(version)).await?;
+
+        // 4. Keep the search index in sync
+        self.search_service.index_blog(&blog_entity).await?;
+
+        Ok(blog_entity)
+    }
+}
+// endregion =================================== MANAGE BLOG REVISION USE CASE ===================================
+
+// region =================================== MANAGE PROFILE USE CASE ===================================
+#[async_trait]
+impl ManageProfileUseCase for AccountAppService {
+    async fn update_profile(&self, account_id: &str, params: &UpdateProfileParams) -> DomainResponse<AccountEntity> {
+        // 1. Verify account exists
+        let account = self
+            .account_service
+            .find_account_by_id(account_id)
+            .await?
+            .ok_or_else(|| Failure::NotFound(format!("Account with id {} not found", account_id)))?;
+
+        // 2. Only the profile fields are editable here
+        let update = UpdateAccountEntity {
+            username: params.username.as_ref().map(|username| username.trim().to_string()),
+            avatar: None,
+            email: None,
+            email_verified: None,
+            bio: params.bio.clone(),
+            is_active: None,
+        };
+
+        // 3. Validate and save
+        self.account_service.update_profile(&account, &update).await
+    }
+
+    async fn find_public_profile(
+        &self,
+        username: &str,
+        query: &FindPublicProfileQuery,
+    ) -> DomainResponse<PublicProfile> {
+        // 1. Deactivated and suspended accounts have no public profile
+        let account = self
+            .account_service
+            .find_by_username(username)
+            .await?
+            .filter(|account| account.is_active && !account.is_suspended())
+            .ok_or_else(|| Failure::NotFound(format!("User {} not found", username)))?;
+
+        // 2. Load a page of published blogs
+        let account_id = account.base.id.clone().unwrap_or_default();
+        let (paginate, blogs) = self
+            .blog_service
+            .find_published_blogs_by_author(&account_id, query.paginate.page, query.paginate.page_size)
+            .await?;
+
+        Ok(PublicProfile::new(&account, paginate, blogs))
+    }
+}
+// endregion =================================== MANAGE PROFILE USE CASE ===================================
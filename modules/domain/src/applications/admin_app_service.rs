@@ -0,0 +1,184 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use std::sync::Arc;
+use uuid::Uuid;
+
+// shared modules
+use shared::configs::APP_CONFIG;
+use shared::jwt::service::JwtService;
+use shared::models::failure::Failure;
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::entities::{
+    account_entity::AccountEntity, audit_event_entity::AuditEventEntity, provider_entity::ProviderEntity,
+    session_entity::SessionEntity,
+};
+use crate::services::{
+    account_service::AccountService, audit_service::AuditService, provider_service::ProviderService,
+    role_service::RoleService, session_service::SessionService,
+};
+use crate::usecases::admin_usecases::{
+    ImpersonateAccountUseCase, ImpersonationResponse, ModerateAccountUseCase, SuspendAccountParams,
+};
+use crate::usecases::auth_usecases::SessionMetadata;
+use crate::values::{audit_action::AuditAction, roles::Role};
+
+// Sessions opened by an impersonation are told apart from the account's own devices by this device type
+static IMPERSONATION_DEVICE_TYPE: &str = "IMPERSONATION";
+
+pub struct AdminAppService {
+    account_service: Arc<dyn AccountService>,
+    role_service: Arc<dyn RoleService>,
+    provider_service: Arc<dyn ProviderService>,
+    session_service: Arc<dyn SessionService>,
+    audit_service: Arc<dyn AuditService>,
+}
+
+impl AdminAppService {
+    pub fn new(
+        account_service: Arc<dyn AccountService>,
+        role_service: Arc<dyn RoleService>,
+        provider_service: Arc<dyn ProviderService>,
+        session_service: Arc<dyn SessionService>,
+        audit_service: Arc<dyn AuditService>,
+    ) -> Self {
+        Self { account_service, role_service, provider_service, session_service, audit_service }
+    }
+
+    async fn find_account(&self, account_id: &str) -> DomainResponse<AccountEntity> {
+        self.account_service
+            .find_account_by_id(account_id)
+            .await?
+            .ok_or_else(|| Failure::NotFound(format!("Account with id {} not found", account_id)))
+    }
+
+    /// Fails with forbidden when the account is an admin, admins are not moderated by their peers.
+    async fn ensure_not_admin(&self, account_id: &str) -> DomainResponse<Option<String>> {
+        let role = self.role_service.find_role_by_account_id(account_id).await?.map(|role| role.role_name);
+        if role.as_deref().is_some_and(|role| role.eq_ignore_ascii_case(Role::Admin.as_str())) {
+            return Err(Failure::Forbidden("This action can not target an admin account".to_string()));
+        }
+
+        Ok(role)
+    }
+}
+
+// region =================================== MODERATE ACCOUNT USE CASE ===================================
+#[async_trait]
+impl ModerateAccountUseCase for AdminAppService {
+    async fn suspend_account(
+        &self,
+        admin_id: &str,
+        account_id: &str,
+        params: &SuspendAccountParams,
+    ) -> DomainResponse<AccountEntity> {
+        // 1. Verify account exists and can be suspended
+        if admin_id == account_id {
+            return Err(Failure::BadRequest("You can not suspend your own account".to_string()));
+        }
+        let account = self.find_account(account_id).await?;
+        self.ensure_not_admin(account_id).await?;
+
+        // 2. Suspend and sign out every session
+        let suspended = self.account_service.suspend_account(&account, &params.reason, params.suspended_until).await?;
+        self.session_service.clean_session_by_account_id(account_id).await?;
+
+        // 3. Record it
+        let event = AuditEventEntity::new(false, AuditAction::AccountSuspended, Some(account_id))
+            .with_changes(Some(&account), Some(&suspended));
+        self.audit_service.emit(event).await?;
+
+        Ok(suspended)
+    }
+
+    async fn lift_suspension(&self, account_id: &str) -> DomainResponse<AccountEntity> {
+        // 1. Verify account exists and is suspended
+        let account = self.find_account(account_id).await?;
+        if !account.is_suspended() {
+            return Err(Failure::Conflict(format!("Account with id {} is not suspended", account_id)));
+        }
+
+        // 2. Lift the suspension
+        let lifted = self.account_service.lift_suspension(&account).await?;
+
+        // 3. Record it
+        let event = AuditEventEntity::new(false, AuditAction::AccountSuspensionLifted, Some(account_id))
+            .with_changes(Some(&account), Some(&lifted));
+        self.audit_service.emit(event).await?;
+
+        Ok(lifted)
+    }
+
+    async fn force_sign_out(&self, account_id: &str) -> DomainResponse<()> {
+        self.find_account(account_id).await?;
+        self.session_service.clean_session_by_account_id(account_id).await?;
+
+        let event = AuditEventEntity::new(false, AuditAction::AccountSignedOut, Some(account_id));
+        self.audit_service.emit(event).await?;
+
+        Ok(())
+    }
+
+    async fn find_account_providers(&self, account_id: &str) -> DomainResponse<Vec<ProviderEntity>> {
+        self.find_account(account_id).await?;
+
+        let mut providers = self.provider_service.find_by_account_id(account_id).await?;
+        for provider in &mut providers {
+            provider.identify = "[REDACTED]".to_string();
+        }
+
+        Ok(providers)
+    }
+
+    async fn find_account_sessions(&self, account_id: &str) -> DomainResponse<Vec<SessionEntity>> {
+        self.find_account(account_id).await?;
+        self.session_service.find_sessions_by_account_id(account_id).await
+    }
+}
+// endregion =================================== MODERATE ACCOUNT USE CASE ===================================
+
+// region =================================== IMPERSONATE ACCOUNT USE CASE ===================================
+#[async_trait]
+impl ImpersonateAccountUseCase for AdminAppService {
+    async fn impersonate_account(
+        &self,
+        admin_id: &str,
+        account_id: &str,
+        metadata: &SessionMetadata,
+    ) -> DomainResponse<ImpersonationResponse> {
+        // 1. Verify account exists, is active and can be impersonated
+        if admin_id == account_id {
+            return Err(Failure::BadRequest("You can not impersonate your own account".to_string()));
+        }
+        let account = self.find_account(account_id).await?;
+        if !account.is_active || account.is_suspended() {
+            return Err(Failure::Forbidden("Only active accounts can be impersonated".to_string()));
+        }
+        let role = self.ensure_not_admin(account_id).await?;
+
+        // 2. Issue the token, it carries the role of the account rather than the admin's
+        let jti = Uuid::now_v7().to_string();
+        let access_token = JwtService::generate_impersonation_token(account_id, admin_id, &jti, role)?;
+        let expires_at = Utc::now().timestamp() + APP_CONFIG.accounts.impersonation_token_seconds;
+
+        // 3. Open a session next to the account's own ones, so force sign-out also ends the impersonation
+        self.session_service
+            .create_session(
+                account_id,
+                &jti,
+                expires_at,
+                &metadata.ip_address,
+                &metadata.user_agent,
+                IMPERSONATION_DEVICE_TYPE,
+            )
+            .await?;
+
+        // 4. Record it, the admin is the actor here since the impersonation has not started yet
+        let event = AuditEventEntity::new(false, AuditAction::AccountImpersonated, Some(account_id));
+        self.audit_service.emit(event).await?;
+
+        Ok(ImpersonationResponse { access_token, account_id: account_id.to_string(), expires_at })
+    }
+}
+// endregion =================================== IMPERSONATE ACCOUNT USE CASE ===================================
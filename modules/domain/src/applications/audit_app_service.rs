@@ -0,0 +1,92 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+
+// shared modules
+use shared::models::failure::Failure;
+use shared::models::paginate::Paginate;
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::entities::audit_event_entity::AuditEventEntity;
+use crate::repositories::audit_event_repository::AuditEventFilter;
+use crate::services::audit_service::AuditService;
+use crate::usecases::audit_usecases::{
+    AuditEventsFilterQuery, ExportAuditEventsQuery, FindAuditEventsQuery, QueryAuditLogUseCase,
+    RecordImpersonationUseCase,
+};
+use crate::values::audit_action::AuditAction;
+
+// Range searched when the query gives no start, and the longest range a query may span
+static DEFAULT_RANGE_SECONDS: i64 = 30 * 24 * 60 * 60;
+static MAX_RANGE_SECONDS: i64 = 366 * 24 * 60 * 60;
+
+// Events an export holds at most, narrower filters are needed past it
+static EXPORT_LIMIT: u32 = 10_000;
+
+pub struct AuditAppService {
+    audit_service: Arc<dyn AuditService>,
+}
+
+impl AuditAppService {
+    pub fn new(audit_service: Arc<dyn AuditService>) -> Self {
+        Self { audit_service }
+    }
+
+    fn build_filter(query: &AuditEventsFilterQuery) -> DomainResponse<AuditEventFilter> {
+        let to = query.to.unwrap_or_else(|| chrono::Utc::now().timestamp());
+        let from = query.from.unwrap_or(to - DEFAULT_RANGE_SECONDS);
+        if from > to {
+            return Err(Failure::ValidationError("From must not be after to".to_string()));
+        }
+        if to - from > MAX_RANGE_SECONDS {
+            return Err(Failure::ValidationError("The audit log can be searched a year at a time at most".to_string()));
+        }
+
+        Ok(AuditEventFilter {
+            actor_id: query.actor_id.clone(),
+            action: query.action.as_deref().map(AuditAction::from_string).transpose()?.map(|a| a.as_str().to_string()),
+            target_type: query.target_type.as_ref().map(|target_type| target_type.to_uppercase()),
+            target_id: query.target_id.clone(),
+            from,
+            to,
+        })
+    }
+}
+
+// region =================================== QUERY AUDIT LOG USE CASE ===================================
+#[async_trait]
+impl QueryAuditLogUseCase for AuditAppService {
+    async fn find_audit_events(
+        &self,
+        query: &FindAuditEventsQuery,
+    ) -> DomainResponse<(Paginate, Vec<AuditEventEntity>)> {
+        let filter = Self::build_filter(&query.filter)?;
+        self.audit_service.find_events(&filter, query.paginate.page, query.paginate.page_size).await
+    }
+
+    async fn export_audit_events(&self, query: &ExportAuditEventsQuery) -> DomainResponse<Vec<AuditEventEntity>> {
+        let filter = Self::build_filter(&query.filter)?;
+        self.audit_service.find_all_events(&filter, EXPORT_LIMIT).await
+    }
+}
+// endregion =================================== QUERY AUDIT LOG USE CASE ===================================
+
+// region =================================== RECORD IMPERSONATION USE CASE ===================================
+#[async_trait]
+impl RecordImpersonationUseCase for AuditAppService {
+    async fn record_denied_impersonation(&self, account_id: &str) -> DomainResponse<()> {
+        let event = AuditEventEntity::new(false, AuditAction::ImpersonationDenied, Some(account_id));
+        self.audit_service.emit(event).await?;
+        Ok(())
+    }
+
+    async fn record_impersonated_request(&self, account_id: &str, method: &str, path: &str) -> DomainResponse<()> {
+        // The request is recorded as the state the event leads to, there is none before it
+        let request = serde_json::json!({ "method": method, "path": path });
+        let event = AuditEventEntity::new(false, AuditAction::ImpersonatedRequest, Some(account_id))
+            .with_changes(None, Some(&request));
+        self.audit_service.emit(event).await?;
+        Ok(())
+    }
+}
+// endregion =================================== RECORD IMPERSONATION USE CASE ===================================
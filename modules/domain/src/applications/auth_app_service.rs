@@ -14,7 +14,9 @@ use shared::types::DomainResponse;
 // internal modules
 use crate::apis::auth_api::AuthApi;
 use crate::entities::account_entity::AccountEntity;
+use crate::entities::audit_event_entity::AuditEventEntity;
 use crate::services::account_service::AccountService;
+use crate::services::audit_service::AuditService;
 use crate::services::auth_service::AuthService;
 use crate::services::provider_service::ProviderService;
 use crate::services::role_service::RoleService;
@@ -23,6 +25,7 @@ use crate::usecases::auth_usecases::{
     AuthParams, AuthResponse, ManageSessionAuthUseCase, OAuth2CallbackParams, OAuth2InitParams, OAuth2InitResponse,
     OAuth2UseCase, RefreshTokenParams, SessionMetadata,
 };
+use crate::values::audit_action::AuditAction;
 
 #[allow(dead_code)]
 pub struct AuthAppService {
@@ -32,6 +35,7 @@ pub struct AuthAppService {
     provider_service: Arc<dyn ProviderService>,
     session_service: Arc<dyn SessionService>,
     role_service: Arc<dyn RoleService>,
+    audit_service: Arc<dyn AuditService>,
 
     // apis
     auth_api: Arc<dyn AuthApi>,
@@ -44,9 +48,10 @@ impl AuthAppService {
         provider_service: Arc<dyn ProviderService>,
         session_service: Arc<dyn SessionService>,
         role_service: Arc<dyn RoleService>,
+        audit_service: Arc<dyn AuditService>,
         auth_api: Arc<dyn AuthApi>,
     ) -> Self {
-        Self { auth_service, account_service, provider_service, session_service, role_service, auth_api }
+        Self { auth_service, account_service, provider_service, session_service, role_service, audit_service, auth_api }
     }
 
     async fn generate_tokens_and_create_session(
@@ -75,8 +80,51 @@ impl AuthAppService {
             )
             .await?;
 
+        let event = AuditEventEntity::new(false, AuditAction::SignIn, Some(account_id)).with_actor(account_id);
+        self.audit_service.emit(event).await?;
         Ok(AuthResponse { access_token, refresh_token })
     }
+
+    /// Verify the password of the account registered with the email, returns the account and its role.
+    async fn authenticate(&self, params: &AuthParams) -> Result<(AccountEntity, Option<String>), Failure> {
+        // 1. Retrieve account by email
+        let account_entity = self
+            .account_service
+            .find_by_email(&params.email)
+            .await?
+            .ok_or(Failure::NotFound("This email is not registered".to_string()))?;
+
+        let account_id = account_entity
+            .base
+            .id
+            .clone()
+            .ok_or(Failure::InternalServerError("Account ID should be present".to_string()))?;
+
+        // 2. Retrieve account's providers and roles concurrently
+        let (provider_entities, role_entity) = tokio::try_join!(
+            self.provider_service.find_by_account_id(&account_id),
+            self.role_service.find_role_by_account_id(&account_id)
+        )?;
+
+        let password_provider = provider_entities
+            .into_iter()
+            .find(|provider| provider.auth_provider == "PASSWORD")
+            .ok_or(Failure::Unauthorized("Password provider not found for this account".to_string()))?;
+
+        // 3. Verify password
+        if !self.auth_service.verify_password(&params.password, &password_provider.identify)? {
+            self.audit_service.emit(AuditEventEntity::new(false, AuditAction::SignInFailed, Some(&account_id))).await?;
+            return Err(Failure::Unauthorized("Password is not correct !!!".to_string()));
+        }
+
+        // 4. Suspensions are lifted by an admin or run out, reactivating does not end them
+        if account_entity.is_suspended() {
+            return Err(Failure::Forbidden("This account is suspended".to_string()));
+        }
+
+        let role = if let Some(role_entity) = role_entity { Some(role_entity.role_name) } else { None };
+        Ok((account_entity, role))
+    }
 }
 
 // region ============================== MANAGE SESSION USE CASES ==============================
@@ -103,41 +151,51 @@ impl ManageSessionAuthUseCase for AuthAppService {
             .ok_or(Failure::InternalServerError("Account ID should be present after creation".to_string()))?;
 
         self.provider_service.create_provider(&account_id, "PASSWORD", &hashed_password).await?;
+        self.audit_service
+            .emit(
+                AuditEventEntity::new(false, AuditAction::SignUp, Some(&account_id))
+                    .with_actor(&account_id)
+                    .with_changes(None, Some(&account_entity)),
+            )
+            .await?;
+
         Ok(account_entity)
     }
 
     async fn sign_in(&self, params: &AuthParams, metadata: &SessionMetadata) -> Result<AuthResponse, Failure> {
-        // 1. Retrieve account by email
-        let account_entity = self
-            .account_service
-            .find_by_email(&params.email)
-            .await?
-            .ok_or(Failure::NotFound("This email is not registered".to_string()))?;
-
-        let account_id = account_entity
-            .base
-            .id
-            .clone()
-            .ok_or(Failure::InternalServerError("Account ID should be present".to_string()))?;
+        // 1. Verify credentials
+        let (account_entity, role) = self.authenticate(params).await?;
 
-        // 2. Retrieve account's providers and roles concurrently
-        let (provider_entities, role_entity) = tokio::try_join!(
-            self.provider_service.find_by_account_id(&account_id),
-            self.role_service.find_role_by_account_id(&account_id)
-        )?;
+        // 2. Deactivated accounts have to be reactivated first
+        if !account_entity.is_active {
+            return Err(Failure::Forbidden("This account is deactivated, reactivate it to sign in".to_string()));
+        }
 
-        let password_provider = provider_entities
-            .into_iter()
-            .find(|provider| provider.auth_provider == "PASSWORD")
-            .ok_or(Failure::Unauthorized("Password provider not found for this account".to_string()))?;
+        // 3. Generate tokens and create session
+        let account_id = account_entity.base.id.unwrap_or_default();
+        self.generate_tokens_and_create_session(&account_id, role, metadata).await
+    }
 
-        // 3. Verify password
-        if !self.auth_service.verify_password(&params.password, &password_provider.identify)? {
-            return Err(Failure::Unauthorized("Password is not correct !!!".to_string()));
-        }
+    async fn reactivate(&self, params: &AuthParams, metadata: &SessionMetadata) -> Result<AuthResponse, Failure> {
+        // 1. Verify credentials
+        let (account_entity, role) = self.authenticate(params).await?;
+
+        // 2. Reactivate, which also cancels a scheduled deletion
+        let account_entity = match account_entity.is_active {
+            true => account_entity,
+            false => {
+                let reactivated = self.account_service.reactivate_account(&account_entity).await?;
+                let account_id = reactivated.base.id.clone().unwrap_or_default();
+                let event = AuditEventEntity::new(false, AuditAction::AccountReactivated, Some(&account_id))
+                    .with_actor(&account_id)
+                    .with_changes(Some(&account_entity), Some(&reactivated));
+                self.audit_service.emit(event).await?;
+                reactivated
+            },
+        };
 
-        // 4. Generate tokens and create session
-        let role = if let Some(role_entity) = role_entity { Some(role_entity.role_name) } else { None };
+        // 3. Generate tokens and create session
+        let account_id = account_entity.base.id.unwrap_or_default();
         self.generate_tokens_and_create_session(&account_id, role, metadata).await
     }
 
@@ -162,6 +220,7 @@ impl ManageSessionAuthUseCase for AuthAppService {
         if session_entity.account_id != account_id || session_entity.jti != jti {
             return Err(Failure::Unauthorized("Session does not belong to the account".to_string()));
         }
+        self.account_service.ensure_active(&account_id).await?;
 
         // 3. Generate new tokens
         let jti = Uuid::now_v7().to_string();
@@ -187,6 +246,23 @@ impl ManageSessionAuthUseCase for AuthAppService {
         Ok(AuthResponse { access_token, refresh_token })
     }
 
+    async fn ensure_session_active(&self, account_id: &str, jti: &str) -> DomainResponse<()> {
+        // 1. The session is gone once signed out, refreshed or forced out by an admin
+        let session = self
+            .session_service
+            .find_by_jti(jti)
+            .await?
+            .filter(|session| session.account_id == account_id && session.base.deleted_at.is_none())
+            .ok_or(Failure::Unauthorized("Session has ended, please sign in again".to_string()))?;
+        if session.expires_at <= Utc::now().timestamp() {
+            return Err(Failure::Unauthorized("Session has expired, please sign in again".to_string()));
+        }
+
+        // 2. Deactivated and suspended accounts lose access right away
+        self.account_service.ensure_active(account_id).await?;
+        Ok(())
+    }
+
     async fn sign_out(&self) -> DomainResponse<()> {
         // Note: The actual implementation would require session identification (e.g., from access token)
         // Here, we assume the session ID or JTI is provided in some way to identify which session to invalidate.
@@ -239,6 +315,12 @@ impl OAuth2UseCase for AuthAppService {
                 self.provider_service.create_provider(&new_account_id, "GOOGLE", &google_account.id).await?;
                 new_account_id
             },
+            Some(account_entity) if !account_entity.is_active => {
+                return Err(Failure::Forbidden("This account is deactivated, reactivate it to sign in".to_string()));
+            },
+            Some(account_entity) if account_entity.is_suspended() => {
+                return Err(Failure::Forbidden("This account is suspended".to_string()));
+            },
             Some(account_entity) => account_entity
                 .base
                 .id
@@ -0,0 +1,112 @@
+use async_trait::async_trait;
+use serde_json::Value;
+use std::sync::Arc;
+
+// shared modules
+use shared::models::failure::Failure;
+use shared::models::filters::{Filterable, QuerySpec};
+use shared::models::paginate::Paginate;
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::entities::blog_entity::BlogEntity;
+use crate::services::blog_engagement_service::BlogEngagementService;
+use crate::services::blog_service::BlogService;
+use crate::services::search_service::SearchService;
+use crate::usecases::blog_usecases::{
+    FindBlogsQuery, ManageBlogUseCase, ScheduleBlogUseCase, ScheduledPublishResult, ToggleBlogStarResponse,
+};
+
+pub struct BlogAppService {
+    blog_service: Arc<dyn BlogService>,
+    blog_engagement_service: Arc<dyn BlogEngagementService>,
+    search_service: Arc<dyn SearchService>,
+}
+
+impl BlogAppService {
+    pub fn new(
+        blog_service: Arc<dyn BlogService>,
+        blog_engagement_service: Arc<dyn BlogEngagementService>,
+        search_service: Arc<dyn SearchService>,
+    ) -> Self {
+        Self { blog_service, blog_engagement_service, search_service }
+    }
+
+    async fn find_published_blog(&self, blog_id: &str) -> DomainResponse<BlogEntity> {
+        // Drafts, scheduled and archived blogs are reported as missing to readers
+        let blog = self.blog_service.find_blog_by_id(blog_id).await?;
+        match blog {
+            Some(blog) if blog.is_published() => Ok(blog),
+            _ => Err(Failure::NotFound(format!("Blog with id {} not found", blog_id))),
+        }
+    }
+}
+
+// region =================================== MANAGE BLOG USE CASE ===================================
+#[async_trait]
+impl ManageBlogUseCase for BlogAppService {
+    async fn find_blogs(&self, query: &FindBlogsQuery) -> DomainResponse<(Paginate, Vec<Value>)> {
+        // 1. Parse the filters against the fields readers may use
+        let spec = QuerySpec::parse(&query.params, BlogEntity::filter_fields())?;
+
+        // 2. Find the matching published blogs
+        let (paginate, mut blogs) =
+            self.blog_service.find_published_blogs(&spec, query.paginate.page, query.paginate.page_size).await?;
+        blogs.iter_mut().for_each(|blog| self.blog_engagement_service.apply_pending_counters(blog));
+
+        // 3. Keep the selected fields
+        let blogs = blogs
+            .iter()
+            .map(|blog| serde_json::to_value(blog).map(|value| spec.project(value)))
+            .collect::<Result<Vec<Value>, _>>()
+            .map_err(|e| Failure::InternalServerError(format!("Failed to serialize blogs: {}", e)))?;
+
+        Ok((paginate, blogs))
+    }
+
+    async fn find_blog(&self, blog_id: &str, viewer_key: &str) -> DomainResponse<BlogEntity> {
+        // 1. Find published blog
+        let mut blog = self.find_published_blog(blog_id).await?;
+
+        // 2. Count the view and expose the counters that are not flushed yet
+        self.blog_engagement_service.record_view(blog_id, viewer_key).await?;
+        self.blog_engagement_service.apply_pending_counters(&mut blog);
+
+        Ok(blog)
+    }
+
+    async fn toggle_blog_star(&self, account_id: &str, blog_id: &str) -> DomainResponse<ToggleBlogStarResponse> {
+        // 1. Only published blogs can be starred
+        let mut blog = self.find_published_blog(blog_id).await?;
+
+        // 2. Toggle star of the account
+        let starred = self.blog_engagement_service.toggle_star(blog_id, account_id).await?;
+        self.blog_engagement_service.apply_pending_counters(&mut blog);
+
+        Ok(ToggleBlogStarResponse { starred, stars: blog.stars })
+    }
+}
+// endregion =================================== MANAGE BLOG USE CASE ===================================
+
+// region =================================== SCHEDULE BLOG USE CASE ===================================
+#[async_trait]
+impl ScheduleBlogUseCase for BlogAppService {
+    async fn publish_scheduled_blogs(&self) -> DomainResponse<ScheduledPublishResult> {
+        // 1. Publish blogs whose time has come
+        let mut result = self.blog_service.publish_scheduled_blogs().await?;
+
+        // 2. Make them searchable, a blog that fails to index is still published
+        for blog in &result.published {
+            if let Err(failure) = self.search_service.index_blog(blog).await {
+                result.failed.push((blog.base.id.clone().unwrap_or_default(), failure.message().to_string()));
+            }
+        }
+
+        Ok(result)
+    }
+
+    async fn flush_blog_counters(&self) -> DomainResponse<usize> {
+        self.blog_engagement_service.flush_counters().await
+    }
+}
+// endregion =================================== SCHEDULE BLOG USE CASE ===================================
@@ -0,0 +1,109 @@
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+// shared modules
+use shared::models::failure::Failure;
+use shared::models::paginate::Paginate;
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::entities::bookmark_entity::BookmarkEntity;
+use crate::services::{
+    blog_service::BlogService, bookmark_service::BookmarkService, course_service::CourseService,
+    project_service::ProjectService,
+};
+use crate::usecases::bookmark_usecases::{
+    AddBookmarkParams, BookmarkUseCase, CheckBookmarksParams, FindBookmarksQuery,
+};
+use crate::values::bookmark_target::BookmarkTarget;
+
+pub struct BookmarkAppService {
+    bookmark_service: Arc<dyn BookmarkService>,
+    blog_service: Arc<dyn BlogService>,
+    project_service: Arc<dyn ProjectService>,
+    course_service: Arc<dyn CourseService>,
+}
+
+impl BookmarkAppService {
+    pub fn new(
+        bookmark_service: Arc<dyn BookmarkService>,
+        blog_service: Arc<dyn BlogService>,
+        project_service: Arc<dyn ProjectService>,
+        course_service: Arc<dyn CourseService>,
+    ) -> Self {
+        Self { bookmark_service, blog_service, project_service, course_service }
+    }
+
+    /// Only public content can be bookmarked.
+    async fn ensure_target_exists(&self, target_type: BookmarkTarget, target_id: &str) -> DomainResponse<()> {
+        let exists = match target_type {
+            BookmarkTarget::Blog => {
+                self.blog_service.find_blog_by_id(target_id).await?.is_some_and(|blog| blog.is_published())
+            },
+            BookmarkTarget::Project => self.project_service.find_project_with_id(target_id).await?.is_some(),
+            BookmarkTarget::Course => self.course_service.find_course_by_id(target_id).await?.is_published(),
+            BookmarkTarget::Lesson => {
+                let lesson = self.course_service.find_lesson_by_id(target_id).await?;
+                let chapter = self.course_service.find_chapter_by_id(&lesson.chapter_id).await?;
+                self.course_service.find_course_by_id(&chapter.course_id).await?.is_published()
+            },
+        };
+
+        if !exists {
+            return Err(Failure::NotFound(format!(
+                "{} with id {} not found",
+                target_type.as_str().to_lowercase(),
+                target_id
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+// region =================================== BOOKMARK USE CASE ===================================
+#[async_trait]
+impl BookmarkUseCase for BookmarkAppService {
+    async fn add_bookmark(&self, account_id: &str, params: &AddBookmarkParams) -> DomainResponse<BookmarkEntity> {
+        // 1. Check the target
+        let target_type = BookmarkTarget::from_string(&params.target_type)?;
+        self.ensure_target_exists(target_type, &params.target_id).await?;
+
+        // 2. Bookmark it, adding it twice is a no-op
+        self.bookmark_service.add_bookmark(account_id, target_type, &params.target_id).await
+    }
+
+    async fn remove_bookmark(&self, account_id: &str, target_type: &str, target_id: &str) -> DomainResponse<()> {
+        let target_type = BookmarkTarget::from_string(target_type)?;
+        self.bookmark_service.remove_bookmark(account_id, target_type, target_id).await
+    }
+
+    async fn find_bookmarks(
+        &self,
+        account_id: &str,
+        query: &FindBookmarksQuery,
+    ) -> DomainResponse<(Paginate, Vec<BookmarkEntity>)> {
+        let target_type = query.target_type.as_deref().map(BookmarkTarget::from_string).transpose()?;
+        self.bookmark_service
+            .find_bookmarks(account_id, target_type, query.paginate.page, query.paginate.page_size)
+            .await
+    }
+
+    async fn check_bookmarks(
+        &self,
+        account_id: &str,
+        params: &CheckBookmarksParams,
+    ) -> DomainResponse<HashMap<String, bool>> {
+        // 1. Find the bookmarked subset
+        let target_type = BookmarkTarget::from_string(&params.target_type)?;
+        let bookmarked = self.bookmark_service.find_bookmarked_ids(account_id, target_type, &params.target_ids).await?;
+
+        // 2. Answer for every requested target
+        let statuses =
+            params.target_ids.iter().map(|target_id| (target_id.clone(), bookmarked.contains(target_id))).collect();
+
+        Ok(statuses)
+    }
+}
+// endregion =================================== BOOKMARK USE CASE ===================================
@@ -1,4 +1,5 @@
 use async_trait::async_trait;
+use std::collections::HashMap;
 use std::sync::Arc;
 
 // shared modules
@@ -6,18 +7,66 @@ use shared::{models::failure::Failure, types::DomainResponse};
 
 // internal modules
 use crate::{
-    entities::category_entity::CategoryEntity,
-    services::category_service::CategoryService,
-    usecases::category_usecases::{CreateCategoryParams, ManageCategoryUseCase, UpdateCategoryParams},
+    entities::{audit_event_entity::AuditEventEntity, category_entity::CategoryEntity},
+    services::{audit_service::AuditService, blog_service::BlogService, category_service::CategoryService},
+    usecases::category_usecases::{
+        CategoryTreeNode, CreateCategoryParams, DeleteCategoryQuery, ManageCategoryUseCase, MoveCategoryParams,
+        UpdateCategoryParams,
+    },
+    values::{audit_action::AuditAction, category_delete_strategy::CategoryDeleteStrategy},
 };
 
 pub struct CategoryAppService {
     category_service: Arc<dyn CategoryService>,
+    blog_service: Arc<dyn BlogService>,
+    audit_service: Arc<dyn AuditService>,
 }
 
 impl CategoryAppService {
-    pub fn new(category_service: Arc<dyn CategoryService>) -> Self {
-        Self { category_service }
+    pub fn new(
+        category_service: Arc<dyn CategoryService>,
+        blog_service: Arc<dyn BlogService>,
+        audit_service: Arc<dyn AuditService>,
+    ) -> Self {
+        Self { category_service, blog_service, audit_service }
+    }
+
+    async fn audit(
+        &self,
+        action: AuditAction,
+        before: Option<&CategoryEntity>,
+        after: Option<&CategoryEntity>,
+    ) -> DomainResponse<()> {
+        let category_id = before.or(after).and_then(|category| category.base.id.clone());
+        let event = AuditEventEntity::new(false, action, category_id.as_deref()).with_changes(before, after);
+        self.audit_service.emit(event).await?;
+        Ok(())
+    }
+
+    async fn find_existing_category(&self, category_id: &str) -> DomainResponse<CategoryEntity> {
+        self.category_service
+            .find_category_by_id(category_id)
+            .await?
+            .ok_or_else(|| Failure::NotFound(format!("Category with id {} not found", category_id)))
+    }
+
+    fn build_tree_node(
+        category: CategoryEntity,
+        children_by_parent: &mut HashMap<String, Vec<CategoryEntity>>,
+        blog_counts: &HashMap<String, u32>,
+    ) -> CategoryTreeNode {
+        let category_id = category.base.id.clone().unwrap_or_default();
+        let blog_count = blog_counts.get(&category_id).copied().unwrap_or(0);
+
+        let children: Vec<CategoryTreeNode> = children_by_parent
+            .remove(&category_id)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|child| Self::build_tree_node(child, children_by_parent, blog_counts))
+            .collect();
+        let total_blog_count = blog_count + children.iter().map(|child| child.total_blog_count).sum::<u32>();
+
+        CategoryTreeNode { category, blog_count, total_blog_count, children }
     }
 }
 
@@ -29,8 +78,15 @@ impl ManageCategoryUseCase for CategoryAppService {
             return Err(Failure::Conflict(format!("Category with name '{}' already exists", params.name)));
         }
 
-        // 2. Create the new category
-        let category = self.category_service.create_category(&params.name).await?;
+        // 2. Resolve the parent category
+        let parent = match &params.parent_id {
+            Some(parent_id) => Some(self.find_existing_category(parent_id).await?),
+            None => None,
+        };
+
+        // 3. Create the new category
+        let category = self.category_service.create_category(&params.name, parent.as_ref()).await?;
+        self.audit(AuditAction::CategoryCreated, None, Some(&category)).await?;
         Ok(category)
     }
 
@@ -40,11 +96,7 @@ impl ManageCategoryUseCase for CategoryAppService {
         params: &UpdateCategoryParams,
     ) -> DomainResponse<CategoryEntity> {
         // 1. Find the existing category
-        let mut category = self
-            .category_service
-            .find_category_by_id(category_id)
-            .await?
-            .ok_or_else(|| Failure::NotFound(format!("Category with id {} not found", category_id)))?;
+        let mut category = self.find_existing_category(category_id).await?;
 
         // 2. If the name is unchanged, return the existing category
         if category.name == params.name {
@@ -57,26 +109,144 @@ impl ManageCategoryUseCase for CategoryAppService {
         }
 
         // 4. Update the category's name
+        let previous = category.clone();
         category.name = params.name.clone();
         let updated_category = self.category_service.update_category(category_id, &category).await?;
+        self.audit(AuditAction::CategoryUpdated, Some(&previous), Some(&updated_category)).await?;
         Ok(updated_category)
     }
 
-    async fn delete_category(&self, category_id: &str) -> DomainResponse<CategoryEntity> {
-        self.category_service.find_and_delete_category(category_id).await
+    async fn move_category(&self, category_id: &str, params: &MoveCategoryParams) -> DomainResponse<CategoryEntity> {
+        // 1. Find the category and its new parent
+        let category = self.find_existing_category(category_id).await?;
+        let parent = match &params.parent_id {
+            Some(parent_id) => Some(self.find_existing_category(parent_id).await?),
+            None => None,
+        };
+
+        // 2. Move the whole subtree
+        let moved = self.category_service.move_category(&category, parent.as_ref()).await?;
+        self.audit(AuditAction::CategoryMoved, Some(&category), Some(&moved)).await?;
+        Ok(moved)
     }
 
-    async fn find_category_by_id(&self, category_id: &str) -> DomainResponse<CategoryEntity> {
-        let category = self
-            .category_service
-            .find_category_by_id(category_id)
-            .await?
-            .ok_or_else(|| Failure::NotFound(format!("Category with id {} not found", category_id)))?;
+    async fn delete_category(&self, category_id: &str, query: &DeleteCategoryQuery) -> DomainResponse<CategoryEntity> {
+        // 1. Find the category and its subtree
+        let category = self.find_existing_category(category_id).await?;
+        let descendants = self.category_service.find_descendants(&category).await?;
+        let strategy = match &query.strategy {
+            Some(strategy) => CategoryDeleteStrategy::from_string(strategy)?,
+            None => CategoryDeleteStrategy::Refuse,
+        };
 
-        Ok(category)
+        // 2. Deal with whatever still references the category
+        let category_ids = vec![category_id.to_string()];
+        match strategy {
+            CategoryDeleteStrategy::Refuse => {
+                if !descendants.is_empty() {
+                    return Err(Failure::Conflict(format!(
+                        "Category has {} subcategories, reassign or cascade them first",
+                        descendants.len()
+                    )));
+                }
+
+                let blog_count = self.blog_service.count_blogs_in_categories(&category_ids).await?;
+                if blog_count > 0 {
+                    return Err(Failure::Conflict(format!(
+                        "Category is used by {} blogs, reassign or cascade them first",
+                        blog_count
+                    )));
+                }
+            },
+
+            CategoryDeleteStrategy::Reassign => {
+                let target_id = query.reassign_to.as_deref().ok_or_else(|| {
+                    Failure::ValidationError("reassignTo is required by the REASSIGN strategy".to_string())
+                })?;
+                let target = self.find_existing_category(target_id).await?;
+                if target.base.id == category.base.id || category.is_ancestor_of(&target) {
+                    return Err(Failure::ValidationError(
+                        "Can not reassign to the deleted category or one of its subcategories".to_string(),
+                    ));
+                }
+
+                // Direct children move under the target, their own subtrees follow them
+                let children =
+                    descendants.iter().filter(|descendant| descendant.parent_id.as_deref() == Some(category_id));
+                for child in children {
+                    self.category_service.move_category(child, Some(&target)).await?;
+                }
+                self.blog_service.reassign_blog_categories(&category_ids, Some(target_id)).await?;
+            },
+
+            CategoryDeleteStrategy::Cascade => {
+                let mut subtree_ids = category_ids.clone();
+                subtree_ids.extend(descendants.iter().filter_map(|descendant| descendant.base.id.clone()));
+
+                // Blogs filed only under the subtree move to the parent, without one they would be uncategorised
+                let parent = match category.parent_id.as_deref() {
+                    Some(parent_id) => self.category_service.find_category_by_id(parent_id).await?,
+                    None => None,
+                };
+                match parent.and_then(|parent| parent.base.id) {
+                    Some(parent_id) => {
+                        self.blog_service.drop_blog_categories(&subtree_ids, &parent_id).await?;
+                    },
+                    None => {
+                        let stranded_count = self.blog_service.count_blogs_only_in_categories(&subtree_ids).await?;
+                        if stranded_count > 0 {
+                            return Err(Failure::Conflict(format!(
+                                "{} blogs have no category outside this one and its subcategories, reassign them first",
+                                stranded_count
+                            )));
+                        }
+                        self.blog_service.reassign_blog_categories(&subtree_ids, None).await?;
+                    },
+                }
+
+                for descendant_id in subtree_ids.iter().skip(1) {
+                    self.category_service.find_and_delete_category(descendant_id).await?;
+                }
+            },
+        }
+
+        // 3. Delete the category itself
+        let deleted = self.category_service.find_and_delete_category(category_id).await?;
+        self.audit(AuditAction::CategoryDeleted, Some(&category), None).await?;
+        Ok(deleted)
+    }
+
+    async fn find_category_by_id(&self, category_id: &str) -> DomainResponse<CategoryEntity> {
+        self.find_existing_category(category_id).await
     }
 
     async fn find_categories(&self) -> DomainResponse<Vec<CategoryEntity>> {
         self.category_service.find_categories().await
     }
+
+    async fn find_category_tree(&self) -> DomainResponse<Vec<CategoryTreeNode>> {
+        // 1. Load every category and the published blog counts
+        let mut categories = self.category_service.find_categories().await?;
+        let blog_counts = self.blog_service.count_published_blogs_by_category().await?;
+        categories.sort_by(|a, b| a.name.cmp(&b.name));
+
+        // 2. Group by parent, categories whose parent is gone are shown as roots
+        let known_ids: Vec<String> = categories.iter().filter_map(|category| category.base.id.clone()).collect();
+        let mut roots = Vec::new();
+        let mut children_by_parent: HashMap<String, Vec<CategoryEntity>> = HashMap::new();
+        for category in categories {
+            match category.parent_id.clone() {
+                Some(parent_id) if known_ids.contains(&parent_id) => {
+                    children_by_parent.entry(parent_id).or_default().push(category)
+                },
+                _ => roots.push(category),
+            }
+        }
+
+        // 3. Build the tree from the roots down
+        let tree =
+            roots.into_iter().map(|root| Self::build_tree_node(root, &mut children_by_parent, &blog_counts)).collect();
+
+        Ok(tree)
+    }
 }
@@ -0,0 +1,139 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+
+// shared modules
+use shared::models::failure::Failure;
+use shared::models::paginate::Paginate;
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::entities::{
+    comment_ban_entity::CommentBanEntity, comment_entity::CommentEntity, notification_entity::NotificationEntity,
+};
+use crate::services::{
+    blog_service::BlogService, comment_service::CommentService, notification_service::NotificationService,
+};
+use crate::usecases::comment_usecases::{
+    BanCommentAuthorParams, CreateCommentParams, EditCommentParams, FindCommentsQuery, ManageCommentUseCase,
+    ModerateCommentUseCase,
+};
+use crate::values::comment_status::CommentStatus;
+
+pub struct CommentAppService {
+    comment_service: Arc<dyn CommentService>,
+    blog_service: Arc<dyn BlogService>,
+    notification_service: Arc<dyn NotificationService>,
+}
+
+impl CommentAppService {
+    pub fn new(
+        comment_service: Arc<dyn CommentService>,
+        blog_service: Arc<dyn BlogService>,
+        notification_service: Arc<dyn NotificationService>,
+    ) -> Self {
+        Self { comment_service, blog_service, notification_service }
+    }
+
+    /// Notifications are best effort, the comment is already stored and must not be reported as failed
+    async fn notify(&self, notification: &NotificationEntity) {
+        if let Err(failure) = self.notification_service.create_notification(notification).await {
+            tracing::warn!("Failed to notify {} about a comment: {}", notification.account_id, failure.message());
+        }
+    }
+}
+
+// region =================================== MANAGE COMMENT USE CASE ===================================
+#[async_trait]
+impl ManageCommentUseCase for CommentAppService {
+    async fn create_comment(
+        &self,
+        account_id: &str,
+        blog_id: &str,
+        params: &CreateCommentParams,
+    ) -> DomainResponse<CommentEntity> {
+        // 1. Only published blogs accept comments
+        let blog = self
+            .blog_service
+            .find_blog_by_id(blog_id)
+            .await?
+            .filter(|blog| blog.is_published())
+            .ok_or(Failure::NotFound(format!("Blog with id {} not found", blog_id)))?;
+
+        // 2. Create comment
+        let comment = self
+            .comment_service
+            .create_comment(blog_id, account_id, params.parent_id.as_deref(), &params.content)
+            .await?;
+
+        // 3. Notify the blog author, except about their own comments
+        let comment_id = comment.base.id.clone().unwrap_or_default();
+        if blog.author_id != account_id {
+            let notification = NotificationEntity::blog_comment(&blog.author_id, blog_id, &comment_id, &blog.name);
+            self.notify(&notification).await;
+        }
+
+        // 4. Notify the author of the replied comment, unless already notified as the blog author
+        if let Some(parent_id) = params.parent_id.as_deref()
+            && let Ok(parent) = self.comment_service.find_comment_by_id(parent_id).await
+            && parent.author_id != account_id
+            && parent.author_id != blog.author_id
+        {
+            let notification =
+                NotificationEntity::comment_reply(&parent.author_id, blog_id, &comment_id, parent_id, &blog.name);
+            self.notify(&notification).await;
+        }
+
+        Ok(comment)
+    }
+
+    async fn edit_comment(
+        &self,
+        account_id: &str,
+        comment_id: &str,
+        params: &EditCommentParams,
+    ) -> DomainResponse<CommentEntity> {
+        self.comment_service.edit_comment(comment_id, account_id, &params.content).await
+    }
+
+    async fn delete_comment(&self, account_id: &str, comment_id: &str) -> DomainResponse<()> {
+        self.comment_service.delete_comment(comment_id, account_id).await
+    }
+
+    async fn find_blog_comments(
+        &self,
+        blog_id: &str,
+        query: &FindCommentsQuery,
+    ) -> DomainResponse<(Paginate, Vec<CommentEntity>)> {
+        self.comment_service.find_visible_comments(blog_id, query.paginate.page, query.paginate.page_size).await
+    }
+}
+// endregion =================================== MANAGE COMMENT USE CASE ===================================
+
+// region =================================== MODERATE COMMENT USE CASE ===================================
+#[async_trait]
+impl ModerateCommentUseCase for CommentAppService {
+    async fn hide_comment(&self, comment_id: &str) -> DomainResponse<CommentEntity> {
+        self.comment_service.change_comment_status(comment_id, CommentStatus::Hidden).await
+    }
+
+    async fn approve_comment(&self, comment_id: &str) -> DomainResponse<CommentEntity> {
+        self.comment_service.change_comment_status(comment_id, CommentStatus::Approved).await
+    }
+
+    async fn ban_comment_author(
+        &self,
+        admin_id: &str,
+        comment_id: &str,
+        params: &BanCommentAuthorParams,
+    ) -> DomainResponse<CommentBanEntity> {
+        // 1. Find the author of the reported comment
+        let comment = self.comment_service.find_comment_by_id(comment_id).await?;
+        if comment.author_id == admin_id {
+            return Err(Failure::BadRequest("You can not ban yourself".to_string()));
+        }
+
+        // 2. Ban author and hide their comments
+        self.comment_service.ban_author(&comment.author_id, admin_id, &params.reason).await
+    }
+}
+// endregion =================================== MODERATE COMMENT USE CASE ===================================
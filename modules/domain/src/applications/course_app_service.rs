@@ -0,0 +1,371 @@
+use async_trait::async_trait;
+use serde_json::Value;
+use std::sync::Arc;
+
+// shared modules
+use shared::models::failure::Failure;
+use shared::models::filters::{Filterable, QuerySpec};
+use shared::models::paginate::Paginate;
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::entities::{chapter_entity::ChapterEntity, course_entity::CourseEntity, lesson_entity::LessonEntity};
+use crate::services::{category_service::CategoryService, course_service::CourseService};
+use crate::usecases::course_usecases::{
+    BrowseCourseUseCase, ChapterOutline, CourseDetail, CreateChapterParams, CreateCourseParams, CreateLessonParams,
+    FindCoursesQuery, LessonOutline, ManageCourseUseCase, ReorderParams, UpdateChapterParams, UpdateCourseParams,
+    UpdateLessonParams,
+};
+use crate::values::{course_level::CourseLevel, course_status::CourseStatus};
+
+pub struct CourseAppService {
+    course_service: Arc<dyn CourseService>,
+    category_service: Arc<dyn CategoryService>,
+}
+
+impl CourseAppService {
+    pub fn new(course_service: Arc<dyn CourseService>, category_service: Arc<dyn CategoryService>) -> Self {
+        Self { course_service, category_service }
+    }
+
+    async fn find_own_course(&self, account_id: &str, course_id: &str) -> DomainResponse<CourseEntity> {
+        let course = self.course_service.find_course_by_id(course_id).await?;
+        if course.author_id != account_id {
+            return Err(Failure::Forbidden("You are not the author of this course".to_string()));
+        }
+
+        Ok(course)
+    }
+
+    async fn find_course_chapter(&self, course_id: &str, chapter_id: &str) -> DomainResponse<ChapterEntity> {
+        let chapter = self.course_service.find_chapter_by_id(chapter_id).await?;
+        if chapter.course_id != course_id {
+            return Err(Failure::NotFound(format!("Chapter with id {} not found", chapter_id)));
+        }
+
+        Ok(chapter)
+    }
+
+    async fn find_course_lesson(&self, course_id: &str, lesson_id: &str) -> DomainResponse<LessonEntity> {
+        let lesson = self.course_service.find_lesson_by_id(lesson_id).await?;
+        self.find_course_chapter(course_id, &lesson.chapter_id)
+            .await
+            .map_err(|_| Failure::NotFound(format!("Lesson with id {} not found", lesson_id)))?;
+
+        Ok(lesson)
+    }
+
+    /// Published courses are public, anything else is only visible to its author.
+    async fn find_visible_course(&self, viewer_id: Option<&str>, course_id: &str) -> DomainResponse<CourseEntity> {
+        let course = self.course_service.find_course_by_id(course_id).await?;
+        if !course.is_published() && viewer_id != Some(course.author_id.as_str()) {
+            return Err(Failure::NotFound(format!("Course with id {} not found", course_id)));
+        }
+
+        Ok(course)
+    }
+
+    async fn change_status(
+        &self,
+        account_id: &str,
+        course_id: &str,
+        status: CourseStatus,
+    ) -> DomainResponse<CourseEntity> {
+        let course = self.find_own_course(account_id, course_id).await?;
+        self.course_service.change_course_status(&course, status).await
+    }
+}
+
+// region =================================== MANAGE COURSE USE CASE ===================================
+#[async_trait]
+impl ManageCourseUseCase for CourseAppService {
+    async fn create_course(&self, account_id: &str, params: &CreateCourseParams) -> DomainResponse<CourseEntity> {
+        // 1. Validate the category and level
+        self.category_service.ensure_categories_exist(std::slice::from_ref(&params.category_id)).await?;
+        let level = CourseLevel::from_string(&params.level)?;
+
+        // 2. Create the draft course
+        let course =
+            CourseEntity::new(false, account_id, &params.category_id, &params.name, &params.description, level);
+        self.course_service.create_course(&course).await
+    }
+
+    async fn update_course(
+        &self,
+        account_id: &str,
+        course_id: &str,
+        params: &UpdateCourseParams,
+    ) -> DomainResponse<CourseEntity> {
+        // 1. Find the course
+        let mut course = self.find_own_course(account_id, course_id).await?;
+
+        // 2. Apply the changes
+        if let Some(category_id) = &params.category_id {
+            self.category_service.ensure_categories_exist(std::slice::from_ref(category_id)).await?;
+            course.category_id = category_id.clone();
+        }
+        if let Some(level) = &params.level {
+            course.level = CourseLevel::from_string(level)?.as_str().to_string();
+        }
+        if let Some(name) = &params.name {
+            course.name = name.clone();
+        }
+        if let Some(description) = &params.description {
+            course.description = description.clone();
+        }
+
+        // 3. Save
+        self.course_service.update_course(&course).await
+    }
+
+    async fn publish_course(&self, account_id: &str, course_id: &str) -> DomainResponse<CourseEntity> {
+        self.change_status(account_id, course_id, CourseStatus::Published).await
+    }
+
+    async fn unpublish_course(&self, account_id: &str, course_id: &str) -> DomainResponse<CourseEntity> {
+        self.change_status(account_id, course_id, CourseStatus::Draft).await
+    }
+
+    async fn archive_course(&self, account_id: &str, course_id: &str) -> DomainResponse<CourseEntity> {
+        self.change_status(account_id, course_id, CourseStatus::Archived).await
+    }
+
+    async fn delete_course(&self, account_id: &str, course_id: &str) -> DomainResponse<()> {
+        self.find_own_course(account_id, course_id).await?;
+        self.course_service.delete_course(course_id).await
+    }
+
+    async fn find_own_courses(&self, account_id: &str) -> DomainResponse<Vec<CourseEntity>> {
+        self.course_service.find_courses_by_author(account_id).await
+    }
+
+    async fn add_chapter(
+        &self,
+        account_id: &str,
+        course_id: &str,
+        params: &CreateChapterParams,
+    ) -> DomainResponse<ChapterEntity> {
+        self.find_own_course(account_id, course_id).await?;
+
+        let chapter = ChapterEntity::new(false, course_id, 0, &params.name, &params.description);
+        self.course_service.add_chapter(chapter, params.order_index).await
+    }
+
+    async fn update_chapter(
+        &self,
+        account_id: &str,
+        course_id: &str,
+        chapter_id: &str,
+        params: &UpdateChapterParams,
+    ) -> DomainResponse<ChapterEntity> {
+        // 1. Find the chapter inside an owned course
+        self.find_own_course(account_id, course_id).await?;
+        let mut chapter = self.find_course_chapter(course_id, chapter_id).await?;
+
+        // 2. Apply the changes and save
+        if let Some(name) = &params.name {
+            chapter.name = name.clone();
+        }
+        if let Some(description) = &params.description {
+            chapter.description = description.clone();
+        }
+
+        self.course_service.update_chapter(&chapter).await
+    }
+
+    async fn delete_chapter(&self, account_id: &str, course_id: &str, chapter_id: &str) -> DomainResponse<()> {
+        // 1. Find the chapter inside an owned course
+        self.find_own_course(account_id, course_id).await?;
+        let chapter = self.find_course_chapter(course_id, chapter_id).await?;
+
+        // 2. Delete it with its lessons and refresh the course duration
+        self.course_service.delete_chapter(&chapter).await?;
+        self.course_service.refresh_course_duration(course_id).await?;
+        Ok(())
+    }
+
+    async fn reorder_chapters(
+        &self,
+        account_id: &str,
+        course_id: &str,
+        params: &ReorderParams,
+    ) -> DomainResponse<Vec<ChapterEntity>> {
+        self.find_own_course(account_id, course_id).await?;
+        self.course_service.reorder_chapters(course_id, &params.ids).await
+    }
+
+    async fn add_lesson(
+        &self,
+        account_id: &str,
+        course_id: &str,
+        chapter_id: &str,
+        params: &CreateLessonParams,
+    ) -> DomainResponse<LessonEntity> {
+        // 1. Find the chapter inside an owned course
+        self.find_own_course(account_id, course_id).await?;
+        self.find_course_chapter(course_id, chapter_id).await?;
+
+        // 2. Add the lesson and refresh the course duration
+        let lesson = LessonEntity::new(
+            false,
+            chapter_id,
+            0,
+            &params.name,
+            &params.description,
+            &params.markdown,
+            params.estimated_time_ms,
+        );
+        let lesson = self.course_service.add_lesson(lesson, params.order_index).await?;
+        self.course_service.refresh_course_duration(course_id).await?;
+
+        Ok(lesson)
+    }
+
+    async fn update_lesson(
+        &self,
+        account_id: &str,
+        course_id: &str,
+        lesson_id: &str,
+        params: &UpdateLessonParams,
+    ) -> DomainResponse<LessonEntity> {
+        // 1. Find the lesson inside an owned course
+        self.find_own_course(account_id, course_id).await?;
+        let mut lesson = self.find_course_lesson(course_id, lesson_id).await?;
+
+        // 2. Apply the changes
+        if let Some(name) = &params.name {
+            lesson.name = name.clone();
+        }
+        if let Some(description) = &params.description {
+            lesson.description = description.clone();
+        }
+        if let Some(markdown) = &params.markdown {
+            lesson.markdown = markdown.clone();
+        }
+        let estimate_changed = params.estimated_time_ms.is_some_and(|estimate| estimate != lesson.estimated_time_ms);
+        if let Some(estimated_time_ms) = params.estimated_time_ms {
+            lesson.estimated_time_ms = estimated_time_ms;
+        }
+
+        // 3. Save and refresh the course duration when the estimate moved
+        let lesson = self.course_service.update_lesson(&lesson).await?;
+        if estimate_changed {
+            self.course_service.refresh_course_duration(course_id).await?;
+        }
+
+        Ok(lesson)
+    }
+
+    async fn delete_lesson(&self, account_id: &str, course_id: &str, lesson_id: &str) -> DomainResponse<()> {
+        self.find_own_course(account_id, course_id).await?;
+        self.find_course_lesson(course_id, lesson_id).await?;
+
+        self.course_service.delete_lesson(lesson_id).await?;
+        self.course_service.refresh_course_duration(course_id).await?;
+        Ok(())
+    }
+
+    async fn reorder_lessons(
+        &self,
+        account_id: &str,
+        course_id: &str,
+        chapter_id: &str,
+        params: &ReorderParams,
+    ) -> DomainResponse<Vec<LessonEntity>> {
+        self.find_own_course(account_id, course_id).await?;
+        self.find_course_chapter(course_id, chapter_id).await?;
+
+        self.course_service.reorder_lessons(chapter_id, &params.ids).await
+    }
+}
+// endregion =================================== MANAGE COURSE USE CASE ===================================
+
+// region =================================== BROWSE COURSE USE CASE ===================================
+#[async_trait]
+impl BrowseCourseUseCase for CourseAppService {
+    async fn find_courses(&self, query: &FindCoursesQuery) -> DomainResponse<(Paginate, Vec<Value>)> {
+        // 1. Parse the filters against the fields readers may use
+        let spec = QuerySpec::parse(&query.params, CourseEntity::filter_fields())?;
+
+        // 2. Normalize the level filter
+        let level = match &query.level {
+            Some(level) => Some(CourseLevel::from_string(level)?.as_str().to_string()),
+            None => None,
+        };
+
+        // 3. A category also matches the courses of its subcategories
+        let category_ids = match &query.category_id {
+            Some(category_id) => {
+                let category = self
+                    .category_service
+                    .find_category_by_id(category_id)
+                    .await?
+                    .ok_or_else(|| Failure::NotFound(format!("Category with id {} not found", category_id)))?;
+
+                let mut category_ids = vec![category_id.clone()];
+                let descendants = self.category_service.find_descendants(&category).await?;
+                category_ids.extend(descendants.into_iter().filter_map(|descendant| descendant.base.id));
+                Some(category_ids)
+            },
+            None => None,
+        };
+
+        // 4. Find published courses
+        let (paginate, courses) = self
+            .course_service
+            .find_published_courses(
+                category_ids.as_deref(),
+                level.as_deref(),
+                &spec,
+                query.paginate.page,
+                query.paginate.page_size,
+            )
+            .await?;
+
+        // 5. Keep the selected fields
+        let courses = courses
+            .iter()
+            .map(|course| serde_json::to_value(course).map(|value| spec.project(value)))
+            .collect::<Result<Vec<Value>, _>>()
+            .map_err(|e| Failure::InternalServerError(format!("Failed to serialize courses: {}", e)))?;
+
+        Ok((paginate, courses))
+    }
+
+    async fn find_course(&self, viewer_id: Option<&str>, course_id: &str) -> DomainResponse<CourseDetail> {
+        // 1. Find the course
+        let course = self.find_visible_course(viewer_id, course_id).await?;
+
+        // 2. Load the outline
+        let chapters = self.course_service.find_chapters(course_id).await?;
+        let chapter_ids: Vec<String> = chapters.iter().filter_map(|chapter| chapter.base.id.clone()).collect();
+        let lessons = self.course_service.find_lessons(&chapter_ids).await?;
+
+        // 3. Group lessons under their chapters, both already ordered
+        let chapters = chapters
+            .into_iter()
+            .map(|chapter| {
+                let lessons = lessons
+                    .iter()
+                    .filter(|lesson| Some(&lesson.chapter_id) == chapter.base.id.as_ref())
+                    .cloned()
+                    .map(LessonOutline::from)
+                    .collect();
+                ChapterOutline { chapter, lessons }
+            })
+            .collect();
+
+        Ok(CourseDetail { course, chapters })
+    }
+
+    async fn find_lesson(
+        &self,
+        viewer_id: Option<&str>,
+        course_id: &str,
+        lesson_id: &str,
+    ) -> DomainResponse<LessonEntity> {
+        self.find_visible_course(viewer_id, course_id).await?;
+        self.find_course_lesson(course_id, lesson_id).await
+    }
+}
+// endregion =================================== BROWSE COURSE USE CASE ===================================
@@ -0,0 +1,217 @@
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+// shared modules
+use shared::models::failure::Failure;
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::entities::{enrollment_entity::EnrollmentEntity, lesson_entity::LessonEntity};
+use crate::services::{course_service::CourseService, enrollment_service::EnrollmentService};
+use crate::usecases::course_usecases::LessonOutline;
+use crate::usecases::enrollment_usecases::{
+    ContinueLearning, CourseAnalytics, CourseAnalyticsUseCase, CourseProgress, LearnCourseUseCase, LessonFunnelStep,
+    RecordProgressParams,
+};
+
+pub struct EnrollmentAppService {
+    enrollment_service: Arc<dyn EnrollmentService>,
+    course_service: Arc<dyn CourseService>,
+}
+
+impl EnrollmentAppService {
+    pub fn new(enrollment_service: Arc<dyn EnrollmentService>, course_service: Arc<dyn CourseService>) -> Self {
+        Self { enrollment_service, course_service }
+    }
+
+    async fn find_own_enrollment(&self, account_id: &str, course_id: &str) -> DomainResponse<EnrollmentEntity> {
+        self.enrollment_service
+            .find_enrollment(account_id, course_id)
+            .await?
+            .ok_or_else(|| Failure::NotFound("You are not enrolled in this course".to_string()))
+    }
+
+    /// Lessons of a course in outline order, chapter by chapter.
+    async fn find_ordered_lessons(&self, course_id: &str) -> DomainResponse<Vec<LessonEntity>> {
+        let chapters = self.course_service.find_chapters(course_id).await?;
+        let chapter_ids: Vec<String> = chapters.iter().filter_map(|chapter| chapter.base.id.clone()).collect();
+        let lessons = self.course_service.find_lessons(&chapter_ids).await?;
+
+        let ordered = chapter_ids
+            .iter()
+            .flat_map(|chapter_id| lessons.iter().filter(move |lesson| &lesson.chapter_id == chapter_id))
+            .cloned()
+            .collect();
+
+        Ok(ordered)
+    }
+
+    async fn find_course_progress_of(&self, enrollment: EnrollmentEntity) -> DomainResponse<CourseProgress> {
+        let enrollment_id = enrollment.base.id.clone().unwrap_or_default();
+        let lessons = self.enrollment_service.find_progresses(&[enrollment_id]).await?;
+
+        Ok(CourseProgress { enrollment, lessons })
+    }
+}
+
+// region =================================== LEARN COURSE USE CASE ===================================
+#[async_trait]
+impl LearnCourseUseCase for EnrollmentAppService {
+    async fn enroll(&self, account_id: &str, course_id: &str) -> DomainResponse<EnrollmentEntity> {
+        // 1. Only published courses are open for enrollment
+        let course = self.course_service.find_course_by_id(course_id).await?;
+        if !course.is_published() {
+            return Err(Failure::NotFound(format!("Course with id {} not found", course_id)));
+        }
+
+        // 2. Enroll
+        self.enrollment_service.enroll(account_id, course_id).await
+    }
+
+    async fn unenroll(&self, account_id: &str, course_id: &str) -> DomainResponse<()> {
+        let enrollment = self.find_own_enrollment(account_id, course_id).await?;
+        self.enrollment_service.unenroll(&enrollment).await
+    }
+
+    async fn find_enrollments(&self, account_id: &str) -> DomainResponse<Vec<EnrollmentEntity>> {
+        self.enrollment_service.find_enrollments_by_account(account_id).await
+    }
+
+    async fn find_course_progress(&self, account_id: &str, course_id: &str) -> DomainResponse<CourseProgress> {
+        let enrollment = self.find_own_enrollment(account_id, course_id).await?;
+        self.find_course_progress_of(enrollment).await
+    }
+
+    async fn record_progress(
+        &self,
+        account_id: &str,
+        course_id: &str,
+        lesson_id: &str,
+        params: &RecordProgressParams,
+    ) -> DomainResponse<CourseProgress> {
+        // 1. Find the enrollment
+        let enrollment = self.find_own_enrollment(account_id, course_id).await?;
+
+        // 2. The lesson must belong to the course
+        let lessons = self.find_ordered_lessons(course_id).await?;
+        if !lessons.iter().any(|lesson| lesson.base.id.as_deref() == Some(lesson_id)) {
+            return Err(Failure::NotFound(format!("Lesson with id {} not found", lesson_id)));
+        }
+
+        // 3. Record the visit
+        self.enrollment_service
+            .record_progress(&enrollment, lesson_id, params.time_spend_second, params.completed)
+            .await?;
+
+        // 4. Recompute the course progress
+        let lesson_ids: Vec<String> = lessons.into_iter().filter_map(|lesson| lesson.base.id).collect();
+        let enrollment = self.enrollment_service.refresh_enrollment(&enrollment, &lesson_ids).await?;
+
+        self.find_course_progress_of(enrollment).await
+    }
+
+    async fn continue_learning(&self, account_id: &str) -> DomainResponse<ContinueLearning> {
+        // 1. Enrollments come most recently active first
+        let enrollments = self.enrollment_service.find_enrollments_by_account(account_id).await?;
+
+        for enrollment in enrollments.into_iter().filter(|enrollment| !enrollment.is_completed()) {
+            // 2. Skip courses that are no longer published
+            let course = match self.course_service.find_course_by_id(&enrollment.course_id).await {
+                Ok(course) if course.is_published() => course,
+                Ok(_) | Err(Failure::NotFound(_)) => continue,
+                Err(failure) => return Err(failure),
+            };
+
+            // 3. Pick the first lesson that is not completed yet
+            let progress = self.find_course_progress_of(enrollment).await?;
+            let next = self.find_ordered_lessons(&progress.enrollment.course_id).await?.into_iter().find(|lesson| {
+                !progress
+                    .lessons
+                    .iter()
+                    .any(|done| done.is_completed && Some(&done.lesson_id) == lesson.base.id.as_ref())
+            });
+
+            if let Some(lesson) = next {
+                return Ok(ContinueLearning {
+                    course,
+                    enrollment: progress.enrollment,
+                    lesson: LessonOutline::from(lesson),
+                });
+            }
+        }
+
+        Err(Failure::NotFound("There is no course to continue".to_string()))
+    }
+}
+// endregion =================================== LEARN COURSE USE CASE ===================================
+
+// region =================================== COURSE ANALYTICS USE CASE ===================================
+#[async_trait]
+impl CourseAnalyticsUseCase for EnrollmentAppService {
+    async fn find_course_analytics(&self, account_id: &str, course_id: &str) -> DomainResponse<CourseAnalytics> {
+        // 1. Only the author can see the analytics
+        let course = self.course_service.find_course_by_id(course_id).await?;
+        if course.author_id != account_id {
+            return Err(Failure::Forbidden("You are not the author of this course".to_string()));
+        }
+
+        // 2. Load enrollments with their progress
+        let enrollments = self.enrollment_service.find_enrollments_by_course(course_id).await?;
+        let enrollment_ids: Vec<String> =
+            enrollments.iter().filter_map(|enrollment| enrollment.base.id.clone()).collect();
+        let progresses = self.enrollment_service.find_progresses(&enrollment_ids).await?;
+
+        // 3. Aggregate per lesson: (started, completed, total time spent)
+        let mut lesson_totals: HashMap<&str, (u32, u32, i64)> = HashMap::new();
+        for progress in &progresses {
+            let totals = lesson_totals.entry(progress.lesson_id.as_str()).or_default();
+            totals.0 += 1;
+            totals.1 += progress.is_completed as u32;
+            totals.2 += progress.time_spend_second as i64;
+        }
+
+        // 4. Build the funnel in outline order
+        let enrollment_count = enrollments.len() as u32;
+        let funnel = self
+            .find_ordered_lessons(course_id)
+            .await?
+            .into_iter()
+            .map(|lesson| {
+                let lesson_id = lesson.base.id.unwrap_or_default();
+                let (started, completed, time_spent) =
+                    lesson_totals.get(lesson_id.as_str()).copied().unwrap_or_default();
+
+                LessonFunnelStep {
+                    completion_rate: match enrollment_count {
+                        0 => 0.0,
+                        count => completed as f32 / count as f32 * 100.0,
+                    },
+                    average_time_spend_second: match started {
+                        0 => 0.0,
+                        started => time_spent as f32 / started as f32,
+                    },
+                    lesson_id,
+                    chapter_id: lesson.chapter_id,
+                    name: lesson.name,
+                    started,
+                    completed,
+                }
+            })
+            .collect();
+
+        let average_progress = match enrollment_count {
+            0 => 0.0,
+            count => enrollments.iter().map(|enrollment| enrollment.progress).sum::<f32>() / count as f32,
+        };
+
+        Ok(CourseAnalytics {
+            course_id: course_id.to_string(),
+            enrollments: enrollment_count,
+            completed_enrollments: enrollments.iter().filter(|enrollment| enrollment.is_completed()).count() as u32,
+            average_progress,
+            funnel,
+        })
+    }
+}
+// endregion =================================== COURSE ANALYTICS USE CASE ===================================
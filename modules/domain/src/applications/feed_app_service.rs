@@ -0,0 +1,81 @@
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+// shared modules
+use shared::{models::failure::Failure, types::DomainResponse};
+
+// internal modules
+use crate::services::{account_service::AccountService, blog_service::BlogService, category_service::CategoryService};
+use crate::usecases::feed_usecases::{BlogFeed, BlogFeedItem, BlogFeedUseCase, FeedScope};
+
+// Feed readers only poll the head of the list
+static FEED_ITEM_LIMIT: u32 = 50;
+
+pub struct FeedAppService {
+    blog_service: Arc<dyn BlogService>,
+    category_service: Arc<dyn CategoryService>,
+    account_service: Arc<dyn AccountService>,
+}
+
+impl FeedAppService {
+    pub fn new(
+        blog_service: Arc<dyn BlogService>,
+        category_service: Arc<dyn CategoryService>,
+        account_service: Arc<dyn AccountService>,
+    ) -> Self {
+        Self { blog_service, category_service, account_service }
+    }
+}
+
+// region =================================== BLOG FEED USE CASE ===================================
+#[async_trait]
+impl BlogFeedUseCase for FeedAppService {
+    async fn find_blog_feed(&self, scope: &FeedScope) -> DomainResponse<BlogFeed> {
+        // 1. Resolve the scope into a title and the blog filters
+        let (title, description, category_id, author_id) = match scope {
+            FeedScope::All => ("Blogs".to_string(), "Latest published blogs".to_string(), None, None),
+            FeedScope::Category(category_id) => {
+                let category = self
+                    .category_service
+                    .find_category_by_id(category_id)
+                    .await?
+                    .ok_or_else(|| Failure::NotFound(format!("Category with id {} not found", category_id)))?;
+                let description = format!("Latest published blogs in {}", category.name);
+                (category.name, description, Some(category_id.as_str()), None)
+            },
+            FeedScope::Author(author_id) => {
+                let account = self
+                    .account_service
+                    .find_account_by_id(author_id)
+                    .await?
+                    .ok_or_else(|| Failure::NotFound(format!("Account with id {} not found", author_id)))?;
+                let description = format!("Latest published blogs by {}", account.username);
+                (account.username, description, None, Some(author_id.as_str()))
+            },
+        };
+
+        // 2. Find the latest published blogs
+        let blogs = self.blog_service.find_latest_published_blogs(category_id, author_id, FEED_ITEM_LIMIT).await?;
+
+        // 3. Resolve author names once per author
+        let mut author_names: HashMap<String, String> = HashMap::new();
+        for blog in &blogs {
+            if author_names.contains_key(&blog.author_id) {
+                continue;
+            }
+            let account = self.account_service.find_account_by_id(&blog.author_id).await?;
+            let name = account.map(|account| account.username).unwrap_or_default();
+            author_names.insert(blog.author_id.clone(), name);
+        }
+
+        let updated_at = blogs.iter().map(|blog| blog.base.updated_at).max().unwrap_or(0);
+        let items = blogs
+            .into_iter()
+            .map(|blog| BlogFeedItem { author_name: author_names[&blog.author_id].clone(), blog })
+            .collect();
+
+        Ok(BlogFeed { title, description, updated_at, items })
+    }
+}
+// endregion =================================== BLOG FEED USE CASE ===================================
@@ -6,22 +6,35 @@ use shared::{models::failure::Failure, types::DomainResponse};
 
 // internal modules
 use crate::{
-    entities::{experience_entity::ExperienceEntity, project_entity::ProjectEntity},
-    services::{experience_service::ExperienceService, project_service::ProjectService},
+    entities::{
+        audit_event_entity::AuditEventEntity, experience_entity::ExperienceEntity, project_entity::ProjectEntity,
+    },
+    services::{
+        audit_service::AuditService, experience_service::ExperienceService, project_service::ProjectService,
+        search_service::SearchService,
+    },
     usecases::global_usecases::{
         AddExperienceParams, AddProjectParams, ManageExperienceUseCase, ManageProjectUseCase, UpdateExperienceParams,
         UpdateProjectParams,
     },
+    values::audit_action::AuditAction,
 };
 
 pub struct GlobalAppService {
     experience_service: Arc<dyn ExperienceService>,
     project_service: Arc<dyn ProjectService>,
+    search_service: Arc<dyn SearchService>,
+    audit_service: Arc<dyn AuditService>,
 }
 
 impl GlobalAppService {
-    pub fn new(experience_service: Arc<dyn ExperienceService>, project_service: Arc<dyn ProjectService>) -> Self {
-        Self { experience_service, project_service }
+    pub fn new(
+        experience_service: Arc<dyn ExperienceService>,
+        project_service: Arc<dyn ProjectService>,
+        search_service: Arc<dyn SearchService>,
+        audit_service: Arc<dyn AuditService>,
+    ) -> Self {
+        Self { experience_service, project_service, search_service, audit_service }
     }
 }
 
@@ -102,11 +115,19 @@ impl ManageProjectUseCase for GlobalAppService {
             )
             .await?;
 
+        // 3. Make it searchable
+        self.search_service.index_project(&project).await?;
+
         Ok(project)
     }
 
     async fn remove_project_with_id(&self, id: &str) -> DomainResponse<()> {
-        self.project_service.remove_project_with_id(id).await
+        let project = self.project_service.find_project_with_id(id).await?;
+        self.project_service.remove_project_with_id(id).await?;
+        let event =
+            AuditEventEntity::new(false, AuditAction::ProjectRemoved, Some(id)).with_changes(project.as_ref(), None);
+        self.audit_service.emit(event).await?;
+        self.search_service.remove_document(id).await
     }
 
     async fn find_project_with_id(&self, id: &str) -> DomainResponse<Option<ProjectEntity>> {
@@ -118,7 +139,8 @@ impl ManageProjectUseCase for GlobalAppService {
     }
 
     async fn update_project(&self, id: &str, params: &UpdateProjectParams) -> DomainResponse<ProjectEntity> {
-        self.project_service
+        let project = self
+            .project_service
             .update_project(
                 id,
                 params.cover.clone(),
@@ -129,7 +151,10 @@ impl ManageProjectUseCase for GlobalAppService {
                 params.tags.clone(),
                 params.markdown.clone(),
             )
-            .await
+            .await?;
+
+        self.search_service.index_project(&project).await?;
+        Ok(project)
     }
 }
 // endregion ============================= ManageProjectUseCase =============================
@@ -0,0 +1,148 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+
+// shared modules
+use shared::configs::APP_CONFIG;
+use shared::models::failure::Failure;
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::apis::blob_storage::Blob;
+use crate::entities::media_asset_entity::MediaAssetEntity;
+use crate::services::{
+    account_service::AccountService, blog_service::BlogService, media_service::MediaService,
+    project_service::ProjectService,
+};
+use crate::usecases::media_usecases::{ManageMediaUseCase, MediaUpload, UploadMediaUseCase};
+use crate::values::media_purpose::MediaPurpose;
+
+// Orphans collected per run, the rest waits for the next one
+static ORPHAN_BATCH_SIZE: u32 = 200;
+
+pub struct MediaAppService {
+    media_service: Arc<dyn MediaService>,
+    account_service: Arc<dyn AccountService>,
+    blog_service: Arc<dyn BlogService>,
+    project_service: Arc<dyn ProjectService>,
+}
+
+impl MediaAppService {
+    pub fn new(
+        media_service: Arc<dyn MediaService>,
+        account_service: Arc<dyn AccountService>,
+        blog_service: Arc<dyn BlogService>,
+        project_service: Arc<dyn ProjectService>,
+    ) -> Self {
+        Self { media_service, account_service, blog_service, project_service }
+    }
+
+    fn primary_url(asset: &MediaAssetEntity) -> DomainResponse<String> {
+        asset
+            .primary_url()
+            .ok_or_else(|| Failure::InternalServerError("The uploaded image has no variants".to_string()))
+    }
+}
+
+// region =================================== UPLOAD MEDIA USE CASE ===================================
+#[async_trait]
+impl UploadMediaUseCase for MediaAppService {
+    async fn upload_avatar(&self, account_id: &str, upload: &MediaUpload) -> DomainResponse<MediaAssetEntity> {
+        // 1. Make sure the account exists before storing anything
+        let account = self
+            .account_service
+            .find_account_by_id(account_id)
+            .await?
+            .ok_or_else(|| Failure::NotFound("Account not found".to_string()))?;
+
+        // 2. Store the image and point the avatar at it
+        let asset = self.media_service.upload(account_id, MediaPurpose::Avatar, upload).await?;
+        self.account_service.update_avatar(&account, &Self::primary_url(&asset)?).await?;
+
+        // 3. The previous avatar is no longer used
+        let asset = self.media_service.attach(&asset, account_id).await?;
+        self.media_service.detach_others(&asset).await?;
+
+        Ok(asset)
+    }
+
+    async fn upload_blog_image(
+        &self,
+        account_id: &str,
+        blog_id: &str,
+        upload: &MediaUpload,
+    ) -> DomainResponse<MediaAssetEntity> {
+        // 1. Only the author can add images
+        let blog = self
+            .blog_service
+            .find_blog_by_id(blog_id)
+            .await?
+            .ok_or_else(|| Failure::NotFound(format!("Blog with id {} not found", blog_id)))?;
+        if blog.author_id != account_id {
+            return Err(Failure::Forbidden("You can only add images to your own blogs".to_string()));
+        }
+
+        // 2. Blogs hold any number of images
+        let asset = self.media_service.upload(account_id, MediaPurpose::BlogImage, upload).await?;
+        self.media_service.attach(&asset, blog_id).await
+    }
+
+    async fn upload_project_cover(
+        &self,
+        account_id: &str,
+        project_id: &str,
+        upload: &MediaUpload,
+    ) -> DomainResponse<MediaAssetEntity> {
+        // 1. Make sure the project exists before storing anything
+        self.project_service
+            .find_project_with_id(project_id)
+            .await?
+            .ok_or_else(|| Failure::NotFound(format!("Project with id {} not found", project_id)))?;
+
+        // 2. Store the image and point the cover at it
+        let asset = self.media_service.upload(account_id, MediaPurpose::ProjectCover, upload).await?;
+        let cover = Self::primary_url(&asset)?;
+        self.project_service.update_project(project_id, Some(cover), None, None, None, None, None, None).await?;
+
+        // 3. The previous cover is no longer used
+        let asset = self.media_service.attach(&asset, project_id).await?;
+        self.media_service.detach_others(&asset).await?;
+
+        Ok(asset)
+    }
+}
+// endregion =================================== UPLOAD MEDIA USE CASE ===================================
+
+// region =================================== MANAGE MEDIA USE CASE ===================================
+#[async_trait]
+impl ManageMediaUseCase for MediaAppService {
+    async fn find_asset(&self, asset_id: &str) -> DomainResponse<MediaAssetEntity> {
+        self.media_service.find_asset(asset_id).await
+    }
+
+    async fn delete_asset(&self, account_id: &str, asset_id: &str) -> DomainResponse<()> {
+        let asset = self.media_service.find_asset(asset_id).await?;
+        if asset.account_id != account_id {
+            return Err(Failure::Forbidden("You can only delete your own uploads".to_string()));
+        }
+
+        // A single-asset field would be left pointing at a missing file
+        let purpose = MediaPurpose::from_string(&asset.purpose)?;
+        if asset.reference_id.is_some() && purpose != MediaPurpose::BlogImage {
+            return Err(Failure::Conflict("The image is in use, upload a replacement instead".to_string()));
+        }
+
+        // Detached assets are collected together with their files
+        self.media_service.detach(&asset).await?;
+        Ok(())
+    }
+
+    async fn find_file(&self, key: &str) -> DomainResponse<Blob> {
+        self.media_service.find_blob(key).await
+    }
+
+    async fn collect_orphans(&self) -> DomainResponse<usize> {
+        let detached_before = chrono::Utc::now().timestamp() - APP_CONFIG.media.orphan_grace_seconds;
+        self.media_service.collect_orphans(detached_before, ORPHAN_BATCH_SIZE).await
+    }
+}
+// endregion =================================== MANAGE MEDIA USE CASE ===================================
@@ -1,7 +1,21 @@
 pub mod account_app_service;
+pub mod admin_app_service;
+pub mod audit_app_service;
 pub mod auth_app_service;
+pub mod blog_app_service;
+pub mod bookmark_app_service;
 pub mod category_app_service;
+pub mod comment_app_service;
+pub mod course_app_service;
+pub mod enrollment_app_service;
 pub mod experience_app_service;
+pub mod feed_app_service;
 pub mod global_app_service;
+pub mod media_app_service;
 pub mod notification_app_service;
+pub mod privacy_app_service;
+pub mod review_app_service;
+pub mod search_app_service;
 pub mod session_app_service;
+pub mod sitemap_app_service;
+pub mod upload_app_service;
@@ -1,15 +1,185 @@
+use async_trait::async_trait;
 use std::sync::Arc;
 
+// shared modules
+use shared::models::failure::Failure;
+use shared::models::paginate::Paginate;
+use shared::types::DomainResponse;
+
 // internal modules
-use crate::services::notification_service::NotificationService;
+use crate::entities::{
+    notification_entity::NotificationEntity, session_entity::SessionEntity,
+    topic_subscription_entity::TopicSubscriptionEntity,
+};
+use crate::services::{
+    digest_service::DigestService, notification_preference_service::NotificationPreferenceService,
+    notification_service::NotificationService, push_service::PushService, session_service::SessionService,
+};
+use crate::usecases::notification_usecases::{
+    DigestResult, FindNotificationsQuery, ManageNotificationPreferenceUseCase, ManageNotificationUseCase,
+    ManagePushSubscriptionUseCase, MarkAllReadResult, NotificationDigestUseCase, NotificationPreferences,
+    PushNotification, PushNotificationUseCase, RegisterDeviceParams, SendNotificationResponse, UnreadCount,
+    UpdateNotificationPreferencesParams,
+};
+use crate::values::{
+    digest_frequency::DigestFrequency, notification_channel::NotificationChannel, notification_kind::NotificationKind,
+};
 
-#[allow(dead_code)]
 pub struct NotificationAppService {
     notification_service: Arc<dyn NotificationService>,
+    push_service: Arc<dyn PushService>,
+    session_service: Arc<dyn SessionService>,
+    preference_service: Arc<dyn NotificationPreferenceService>,
+    digest_service: Arc<dyn DigestService>,
 }
 
 impl NotificationAppService {
-    pub fn new(notification_service: Arc<dyn NotificationService>) -> Self {
-        Self { notification_service }
+    pub fn new(
+        notification_service: Arc<dyn NotificationService>,
+        push_service: Arc<dyn PushService>,
+        session_service: Arc<dyn SessionService>,
+        preference_service: Arc<dyn NotificationPreferenceService>,
+        digest_service: Arc<dyn DigestService>,
+    ) -> Self {
+        Self { notification_service, push_service, session_service, preference_service, digest_service }
+    }
+
+    async fn find_current_session(&self, account_id: &str, jti: &str) -> DomainResponse<SessionEntity> {
+        self.session_service
+            .find_by_jti(jti)
+            .await?
+            .filter(|session| session.account_id == account_id)
+            .ok_or_else(|| Failure::Unauthorized("Your session has ended, please sign in again".to_string()))
+    }
+}
+
+// region =================================== MANAGE NOTIFICATION USE CASE ===================================
+#[async_trait]
+impl ManageNotificationUseCase for NotificationAppService {
+    async fn find_notifications(
+        &self,
+        account_id: &str,
+        query: &FindNotificationsQuery,
+    ) -> DomainResponse<(Paginate, Vec<NotificationEntity>)> {
+        self.notification_service
+            .find_notifications(account_id, query.unread, query.paginate.page, query.paginate.page_size)
+            .await
+    }
+
+    async fn count_unread(&self, account_id: &str) -> DomainResponse<UnreadCount> {
+        let unread = self.notification_service.count_unread(account_id).await?;
+        Ok(UnreadCount { unread })
+    }
+
+    async fn mark_read(&self, account_id: &str, notification_id: &str) -> DomainResponse<NotificationEntity> {
+        let notification = self.notification_service.find_own_notification(account_id, notification_id).await?;
+        self.notification_service.mark_read(&notification).await
+    }
+
+    async fn mark_all_read(&self, account_id: &str) -> DomainResponse<MarkAllReadResult> {
+        let updated = self.notification_service.mark_all_read(account_id).await?;
+        Ok(MarkAllReadResult { updated })
+    }
+
+    async fn delete_notification(&self, account_id: &str, notification_id: &str) -> DomainResponse<()> {
+        let notification = self.notification_service.find_own_notification(account_id, notification_id).await?;
+        self.notification_service.delete_notification(&notification).await
+    }
+}
+// endregion =================================== MANAGE NOTIFICATION USE CASE ===================================
+
+// region =================================== PUSH NOTIFICATION USE CASE ===================================
+#[async_trait]
+impl PushNotificationUseCase for NotificationAppService {
+    async fn send_to_account(
+        &self,
+        account_id: &str,
+        params: &PushNotification,
+    ) -> DomainResponse<SendNotificationResponse> {
+        self.push_service.push_to_account(account_id, NotificationKind::System, params).await
+    }
+
+    async fn send_to_topic(&self, topic: &str, params: &PushNotification) -> DomainResponse<SendNotificationResponse> {
+        self.push_service.push_to_topic(topic, params).await
+    }
+}
+// endregion =================================== PUSH NOTIFICATION USE CASE ===================================
+
+// region =================================== MANAGE PUSH SUBSCRIPTION USE CASE ===================================
+#[async_trait]
+impl ManagePushSubscriptionUseCase for NotificationAppService {
+    async fn register_device(&self, account_id: &str, jti: &str, params: &RegisterDeviceParams) -> DomainResponse<()> {
+        let session = self.find_current_session(account_id, jti).await?;
+        self.push_service.register_device(&session, params.device_token.trim()).await?;
+        Ok(())
+    }
+
+    async fn unregister_device(&self, account_id: &str, jti: &str) -> DomainResponse<()> {
+        let session = self.find_current_session(account_id, jti).await?;
+        self.push_service.unregister_device(&session).await?;
+        Ok(())
+    }
+
+    async fn find_topics(&self, account_id: &str) -> DomainResponse<Vec<TopicSubscriptionEntity>> {
+        self.push_service.find_topics(account_id).await
+    }
+
+    async fn subscribe_topic(&self, account_id: &str, topic: &str) -> DomainResponse<TopicSubscriptionEntity> {
+        self.push_service.subscribe_topic(account_id, topic).await
+    }
+
+    async fn unsubscribe_topic(&self, account_id: &str, topic: &str) -> DomainResponse<()> {
+        self.push_service.unsubscribe_topic(account_id, topic).await
+    }
+}
+// endregion =================================== MANAGE PUSH SUBSCRIPTION USE CASE ===================================
+
+// region =================================== MANAGE NOTIFICATION PREFERENCE USE CASE ===================================
+#[async_trait]
+impl ManageNotificationPreferenceUseCase for NotificationAppService {
+    async fn find_preferences(&self, account_id: &str) -> DomainResponse<NotificationPreferences> {
+        let preferences = self.preference_service.find_preferences(account_id).await?;
+        Ok(NotificationPreferences::from_entity(&preferences))
+    }
+
+    async fn update_preferences(
+        &self,
+        account_id: &str,
+        params: &UpdateNotificationPreferencesParams,
+    ) -> DomainResponse<NotificationPreferences> {
+        // 1. Check the params that need more than field validation
+        if params.quiet_hours_start.is_some() != params.quiet_hours_end.is_some() {
+            return Err(Failure::ValidationError("Quiet hours need both a start and an end".to_string()));
+        }
+        let digest_frequency = DigestFrequency::from_string(&params.digest_frequency)?;
+
+        // 2. Replace the channels, kinds left out get every channel back
+        let mut preferences = self.preference_service.find_preferences(account_id).await?;
+        preferences.muted_channels.clear();
+        for channel in &params.channels {
+            let kind = NotificationKind::from_string(&channel.kind)?;
+            preferences.set_channel(kind, NotificationChannel::InApp, channel.in_app);
+            preferences.set_channel(kind, NotificationChannel::Push, channel.push);
+            preferences.set_channel(kind, NotificationChannel::Email, channel.email);
+        }
+
+        // 3. Quiet hours and digest
+        preferences.quiet_hours_start = params.quiet_hours_start;
+        preferences.quiet_hours_end = params.quiet_hours_end;
+        preferences.utc_offset_minutes = params.utc_offset_minutes;
+        preferences.set_digest_frequency(digest_frequency, chrono::Utc::now().timestamp());
+
+        let preferences = self.preference_service.save_preferences(&preferences).await?;
+        Ok(NotificationPreferences::from_entity(&preferences))
+    }
+}
+// endregion =================================== MANAGE NOTIFICATION PREFERENCE USE CASE ===================================
+
+// region =================================== NOTIFICATION DIGEST USE CASE ===================================
+#[async_trait]
+impl NotificationDigestUseCase for NotificationAppService {
+    async fn send_digests(&self) -> DomainResponse<DigestResult> {
+        self.digest_service.send_due_digests(chrono::Utc::now().timestamp()).await
     }
 }
+// endregion =================================== NOTIFICATION DIGEST USE CASE ===================================
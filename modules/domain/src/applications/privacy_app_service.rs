@@ -0,0 +1,382 @@
+use async_trait::async_trait;
+use serde::Serialize;
+use std::sync::Arc;
+
+// shared modules
+use shared::configs::APP_CONFIG;
+use shared::models::failure::Failure;
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::apis::archive_api::ArchiveEntry;
+use crate::apis::blob_storage::Blob;
+use crate::entities::{
+    account_entity::AccountEntity, audit_event_entity::AuditEventEntity, bookmark_entity::BookmarkEntity,
+    data_export_entity::DataExportEntity, notification_entity::NotificationEntity,
+};
+use crate::services::{
+    account_service::AccountService, audit_service::AuditService, blog_engagement_service::BlogEngagementService,
+    blog_revision_service::BlogRevisionService, blog_service::BlogService, bookmark_service::BookmarkService,
+    comment_service::CommentService, course_service::CourseService, data_export_service::DataExportService,
+    enrollment_service::EnrollmentService, media_service::MediaService,
+    notification_preference_service::NotificationPreferenceService, notification_service::NotificationService,
+    provider_service::ProviderService, push_service::PushService, review_service::ReviewService,
+    role_service::RoleService, search_service::SearchService, session_service::SessionService,
+    upload_service::UploadService,
+};
+use crate::usecases::privacy_usecases::{DataExportUseCase, ManageAccountStatusUseCase};
+use crate::values::{audit_action::AuditAction, blog_deletion_policy::BlogDeletionPolicy};
+
+// Accounts erased and exports produced per run, the rest waits for the next one
+static ERASURE_BATCH_SIZE: u32 = 50;
+static EXPORT_BATCH_SIZE: u32 = 10;
+static EXPIRED_BATCH_SIZE: u32 = 200;
+
+// Page size used to walk paginated collections into an export
+static EXPORT_PAGE_SIZE: u32 = 100;
+
+pub struct PrivacyAppService {
+    account_service: Arc<dyn AccountService>,
+    session_service: Arc<dyn SessionService>,
+    provider_service: Arc<dyn ProviderService>,
+    role_service: Arc<dyn RoleService>,
+    notification_service: Arc<dyn NotificationService>,
+    notification_preference_service: Arc<dyn NotificationPreferenceService>,
+    push_service: Arc<dyn PushService>,
+    blog_service: Arc<dyn BlogService>,
+    blog_revision_service: Arc<dyn BlogRevisionService>,
+    blog_engagement_service: Arc<dyn BlogEngagementService>,
+    comment_service: Arc<dyn CommentService>,
+    course_service: Arc<dyn CourseService>,
+    enrollment_service: Arc<dyn EnrollmentService>,
+    review_service: Arc<dyn ReviewService>,
+    bookmark_service: Arc<dyn BookmarkService>,
+    media_service: Arc<dyn MediaService>,
+    upload_service: Arc<dyn UploadService>,
+    search_service: Arc<dyn SearchService>,
+    data_export_service: Arc<dyn DataExportService>,
+    audit_service: Arc<dyn AuditService>,
+}
+
+impl PrivacyAppService {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        account_service: Arc<dyn AccountService>,
+        session_service: Arc<dyn SessionService>,
+        provider_service: Arc<dyn ProviderService>,
+        role_service: Arc<dyn RoleService>,
+        notification_service: Arc<dyn NotificationService>,
+        notification_preference_service: Arc<dyn NotificationPreferenceService>,
+        push_service: Arc<dyn PushService>,
+        blog_service: Arc<dyn BlogService>,
+        blog_revision_service: Arc<dyn BlogRevisionService>,
+        blog_engagement_service: Arc<dyn BlogEngagementService>,
+        comment_service: Arc<dyn CommentService>,
+        course_service: Arc<dyn CourseService>,
+        enrollment_service: Arc<dyn EnrollmentService>,
+        review_service: Arc<dyn ReviewService>,
+        bookmark_service: Arc<dyn BookmarkService>,
+        media_service: Arc<dyn MediaService>,
+        upload_service: Arc<dyn UploadService>,
+        search_service: Arc<dyn SearchService>,
+        data_export_service: Arc<dyn DataExportService>,
+        audit_service: Arc<dyn AuditService>,
+    ) -> Self {
+        Self {
+            account_service,
+            session_service,
+            provider_service,
+            role_service,
+            notification_service,
+            notification_preference_service,
+            push_service,
+            blog_service,
+            blog_revision_service,
+            blog_engagement_service,
+            comment_service,
+            course_service,
+            enrollment_service,
+            review_service,
+            bookmark_service,
+            media_service,
+            upload_service,
+            search_service,
+            data_export_service,
+            audit_service,
+        }
+    }
+
+    async fn find_account(&self, account_id: &str) -> DomainResponse<AccountEntity> {
+        self.account_service
+            .find_account_by_id(account_id)
+            .await?
+            .ok_or_else(|| Failure::NotFound(format!("Account with id {} not found", account_id)))
+    }
+
+    /// Remove everything the account owns, then the account itself.
+    async fn erase_account(&self, account: &AccountEntity, policy: BlogDeletionPolicy) -> DomainResponse<()> {
+        let account_id = account.base.id.clone().unwrap_or_default();
+
+        // 1. Leave its push topics while the devices are still known, then sign it out and drop its credentials
+        self.push_service.remove_topics_by_account_id(&account_id).await?;
+        self.session_service.clean_session_by_account_id(&account_id).await?;
+        self.provider_service.remove_providers_by_account_id(&account_id).await?;
+        self.role_service.remove_role_by_account_id(&account_id).await?;
+
+        // 2. Drop its notifications, preferences and exports
+        self.notification_service.remove_notifications_by_account_id(&account_id).await?;
+        self.notification_preference_service.remove_preferences_by_account_id(&account_id).await?;
+        self.data_export_service.remove_exports_by_account_id(&account_id).await?;
+
+        // 3. Drop its activity, the counters it fed are adjusted on the way out
+        self.bookmark_service.remove_bookmarks_by_account_id(&account_id).await?;
+        self.enrollment_service.remove_enrollments_by_account_id(&account_id).await?;
+        self.review_service.remove_reviews_by_account_id(&account_id).await?;
+        self.comment_service.remove_comments_by_author_id(&account_id).await?;
+        self.blog_engagement_service.remove_stars_by_account_id(&account_id).await?;
+
+        // 4. Drop its media and uploads along with the stored files
+        self.media_service.remove_assets_by_account_id(&account_id).await?;
+        self.upload_service.remove_uploads_by_account_id(&account_id).await?;
+
+        // 5. Anonymise or delete its blogs and courses, the revisions hold earlier copies of its writing either way
+        match policy {
+            BlogDeletionPolicy::Anonymize => {
+                for blog in self.blog_service.find_blogs_by_author(&account_id).await? {
+                    self.blog_revision_service.remove_revisions(&blog.base.id.unwrap_or_default()).await?;
+                }
+                let ghost = self.account_service.find_or_create_ghost().await?;
+                let ghost_id = ghost.base.id.clone().unwrap_or_default();
+                self.blog_service.reassign_blog_author(&account_id, &ghost_id).await?;
+                self.course_service.reassign_course_author(&account_id, &ghost_id).await?;
+            },
+            BlogDeletionPolicy::Delete => {
+                for blog in self.blog_service.remove_blogs_by_author(&account_id).await? {
+                    let blog_id = blog.base.id.unwrap_or_default();
+                    self.blog_revision_service.remove_revisions(&blog_id).await?;
+                    self.search_service.remove_document(&blog_id).await?;
+                }
+                self.course_service.remove_courses_by_author(&account_id).await?;
+            },
+        }
+
+        // 6. Remove the account, the erasure runs on a schedule so the event has no actor. The audit log can not be
+        // erased, so it records only which account went and not what it held
+        self.account_service.remove_account(&account_id).await?;
+        let event = AuditEventEntity::new(false, AuditAction::AccountErased, Some(&account_id));
+        self.audit_service.emit(event).await?;
+
+        Ok(())
+    }
+
+    async fn find_all_notifications(&self, account_id: &str) -> DomainResponse<Vec<NotificationEntity>> {
+        let mut notifications = vec![];
+        let mut page = 1;
+        loop {
+            let (paginate, items) =
+                self.notification_service.find_notifications(account_id, false, page, EXPORT_PAGE_SIZE).await?;
+            notifications.extend(items);
+            if page >= paginate.total_page {
+                return Ok(notifications);
+            }
+            page += 1;
+        }
+    }
+
+    async fn find_all_bookmarks(&self, account_id: &str) -> DomainResponse<Vec<BookmarkEntity>> {
+        let mut bookmarks = vec![];
+        let mut page = 1;
+        loop {
+            let (paginate, items) =
+                self.bookmark_service.find_bookmarks(account_id, None, page, EXPORT_PAGE_SIZE).await?;
+            bookmarks.extend(items);
+            if page >= paginate.total_page {
+                return Ok(bookmarks);
+            }
+            page += 1;
+        }
+    }
+
+    fn json_entry<T: Serialize + ?Sized>(path: &str, value: &T) -> DomainResponse<ArchiveEntry> {
+        let bytes = serde_json::to_vec_pretty(value)
+            .map_err(|e| Failure::InternalServerError(format!("Failed to serialize {}: {}", path, e)))?;
+        Ok(ArchiveEntry { path: path.to_string(), bytes })
+    }
+
+    /// One JSON file per kind of entity the account owns.
+    async fn collect_export_entries(&self, account_id: &str) -> DomainResponse<Vec<ArchiveEntry>> {
+        // 1. Account and credentials, password hashes and provider ids never leave the database
+        let account = self.find_account(account_id).await?;
+        let mut providers = self.provider_service.find_by_account_id(account_id).await?;
+        for provider in &mut providers {
+            provider.identify = "[REDACTED]".to_string();
+        }
+        let role = self.role_service.find_role_by_account_id(account_id).await?;
+        let sessions = self.session_service.find_sessions_by_account_id(account_id).await?;
+
+        // 2. Notifications, preferences and push topics
+        let notifications = self.find_all_notifications(account_id).await?;
+        let preferences = self.notification_preference_service.find_preferences(account_id).await?;
+
+        let topics = self.push_service.find_topics(account_id).await?;
+
+        // 3. Content and activity
+        let blogs = self.blog_service.find_blogs_by_author(account_id).await?;
+        let mut revisions = vec![];
+        for blog in &blogs {
+            revisions
+                .extend(self.blog_revision_service.find_revisions(blog.base.id.as_deref().unwrap_or_default()).await?);
+        }
+        let courses = self.course_service.find_courses_by_author(account_id).await?;
+        let comments = self.comment_service.find_comments_by_author(account_id).await?;
+        let reviews = self.review_service.find_reviews_by_account(account_id).await?;
+        let stars = self.blog_engagement_service.find_stars_by_account(account_id).await?;
+        let enrollments = self.enrollment_service.find_enrollments_by_account(account_id).await?;
+        let enrollment_ids: Vec<String> =
+            enrollments.iter().map(|enrollment| enrollment.base.id.clone().unwrap_or_default()).collect();
+        let progresses = self.enrollment_service.find_progresses(&enrollment_ids).await?;
+        let bookmarks = self.find_all_bookmarks(account_id).await?;
+
+        // 4. Files, the presigned URLs of the uploads are credentials and stay out
+        let media = self.media_service.find_assets_by_account(account_id).await?;
+        let mut uploads = self.upload_service.find_uploads_by_account(account_id).await?;
+        for upload in &mut uploads {
+            upload.url = None;
+        }
+
+        Ok(vec![
+            Self::json_entry("account.json", &account)?,
+            Self::json_entry("providers.json", &providers)?,
+            Self::json_entry("role.json", &role)?,
+            Self::json_entry("sessions.json", &sessions)?,
+            Self::json_entry("notifications.json", &notifications)?,
+            Self::json_entry("notification_preferences.json", &preferences)?,
+            Self::json_entry("topic_subscriptions.json", &topics)?,
+            Self::json_entry("blogs.json", &blogs)?,
+            Self::json_entry("blog_revisions.json", &revisions)?,
+            Self::json_entry("courses.json", &courses)?,
+            Self::json_entry("comments.json", &comments)?,
+            Self::json_entry("reviews.json", &reviews)?,
+            Self::json_entry("stars.json", &stars)?,
+            Self::json_entry("enrollments.json", &enrollments)?,
+            Self::json_entry("progresses.json", &progresses)?,
+            Self::json_entry("bookmarks.json", &bookmarks)?,
+            Self::json_entry("media.json", &media)?,
+            Self::json_entry("uploads.json", &uploads)?,
+        ])
+    }
+}
+
+// region =================================== MANAGE ACCOUNT STATUS USE CASE ===================================
+#[async_trait]
+impl ManageAccountStatusUseCase for PrivacyAppService {
+    async fn deactivate_account(&self, account_id: &str) -> DomainResponse<AccountEntity> {
+        // 1. Verify account exists
+        let account = self.find_account(account_id).await?;
+
+        // 2. Deactivate and sign out every session
+        let deactivated = self.account_service.deactivate_account(&account).await?;
+        self.session_service.clean_session_by_account_id(account_id).await?;
+
+        // 3. Record it
+        let event = AuditEventEntity::new(false, AuditAction::AccountDeactivated, Some(account_id))
+            .with_changes(Some(&account), Some(&deactivated));
+        self.audit_service.emit(event).await?;
+
+        Ok(deactivated)
+    }
+
+    async fn schedule_account_deletion(&self, account_id: &str) -> DomainResponse<AccountEntity> {
+        // 1. Verify account exists
+        let account = self.find_account(account_id).await?;
+
+        // 2. Schedule the deletion and sign out every session
+        let scheduled = self.account_service.schedule_deletion(&account).await?;
+        self.session_service.clean_session_by_account_id(account_id).await?;
+
+        // 3. Record it
+        let event = AuditEventEntity::new(false, AuditAction::AccountDeletionScheduled, Some(account_id))
+            .with_changes(Some(&account), Some(&scheduled));
+        self.audit_service.emit(event).await?;
+
+        Ok(scheduled)
+    }
+
+    async fn erase_due_accounts(&self) -> DomainResponse<usize> {
+        // 1. Resolve the policy up front, a misconfiguration should not erase half an account
+        let policy = BlogDeletionPolicy::from_string(&APP_CONFIG.accounts.blog_deletion_policy)?;
+
+        // 2. Erase every due account, one failing should not hold back the ones due after it
+        let accounts = self.account_service.find_deletion_due(ERASURE_BATCH_SIZE).await?;
+        let mut erased_count = 0;
+        for account in &accounts {
+            match self.erase_account(account, policy).await {
+                Ok(()) => erased_count += 1,
+                Err(failure) => tracing::error!(
+                    "Failed to erase account {}: {}",
+                    account.base.id.as_deref().unwrap_or_default(),
+                    failure.message()
+                ),
+            }
+        }
+
+        Ok(erased_count)
+    }
+}
+// endregion =================================== MANAGE ACCOUNT STATUS USE CASE ===================================
+
+// region =================================== DATA EXPORT USE CASE ===================================
+#[async_trait]
+impl DataExportUseCase for PrivacyAppService {
+    async fn request_export(&self, account_id: &str) -> DomainResponse<DataExportEntity> {
+        let export = self.data_export_service.request_export(account_id).await?;
+        let event = AuditEventEntity::new(false, AuditAction::DataExportRequested, export.base.id.as_deref());
+        self.audit_service.emit(event).await?;
+
+        Ok(export)
+    }
+
+    async fn find_exports(&self, account_id: &str) -> DomainResponse<Vec<DataExportEntity>> {
+        self.data_export_service.find_exports(account_id).await
+    }
+
+    async fn find_export(&self, account_id: &str, export_id: &str) -> DomainResponse<DataExportEntity> {
+        self.data_export_service.find_own_export(account_id, export_id).await
+    }
+
+    async fn download_export(&self, account_id: &str, export_id: &str) -> DomainResponse<Blob> {
+        // 1. Only the account the export belongs to can download it
+        let export = self.data_export_service.find_own_export(account_id, export_id).await?;
+
+        // 2. Load the archive and record who took it
+        let blob = self.data_export_service.download_export(&export).await?;
+        let event = AuditEventEntity::new(false, AuditAction::DataExportDownloaded, Some(export_id));
+        self.audit_service.emit(event).await?;
+
+        Ok(blob)
+    }
+
+    async fn process_pending_exports(&self) -> DomainResponse<usize> {
+        let exports = self.data_export_service.find_pending_exports(EXPORT_BATCH_SIZE).await?;
+        for export in &exports {
+            // One broken export should not hold back the others, the account can request a new one
+            let entries = self.collect_export_entries(&export.account_id).await;
+            let result = match entries {
+                Ok(entries) => self.data_export_service.complete_export(export, entries).await,
+                Err(failure) => Err(failure),
+            };
+
+            if let Err(failure) = result {
+                self.data_export_service.fail_export(export, failure.message()).await?;
+            }
+        }
+
+        Ok(exports.len())
+    }
+
+    async fn collect_expired_exports(&self) -> DomainResponse<usize> {
+        let now = chrono::Utc::now().timestamp();
+        self.data_export_service.collect_expired(now, EXPIRED_BATCH_SIZE).await
+    }
+}
+// endregion =================================== DATA EXPORT USE CASE ===================================
@@ -0,0 +1,140 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+
+// shared modules
+use shared::models::failure::Failure;
+use shared::models::paginate::Paginate;
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::entities::{notification_entity::NotificationEntity, review_entity::ReviewEntity};
+use crate::services::{
+    course_service::CourseService, enrollment_service::EnrollmentService, notification_service::NotificationService,
+    review_service::ReviewService,
+};
+use crate::usecases::review_usecases::{
+    CreateReviewParams, FindModeratedReviewsQuery, FindReviewsQuery, ManageReviewUseCase, ModerateReviewUseCase,
+    UpdateReviewParams,
+};
+use crate::values::review_status::ReviewStatus;
+
+pub struct ReviewAppService {
+    review_service: Arc<dyn ReviewService>,
+    course_service: Arc<dyn CourseService>,
+    enrollment_service: Arc<dyn EnrollmentService>,
+    notification_service: Arc<dyn NotificationService>,
+}
+
+impl ReviewAppService {
+    pub fn new(
+        review_service: Arc<dyn ReviewService>,
+        course_service: Arc<dyn CourseService>,
+        enrollment_service: Arc<dyn EnrollmentService>,
+        notification_service: Arc<dyn NotificationService>,
+    ) -> Self {
+        Self { review_service, course_service, enrollment_service, notification_service }
+    }
+
+    async fn find_review_of(&self, account_id: &str, course_id: &str) -> DomainResponse<ReviewEntity> {
+        self.review_service
+            .find_review(course_id, account_id)
+            .await?
+            .ok_or_else(|| Failure::NotFound("You have not reviewed this course".to_string()))
+    }
+}
+
+// region =================================== MANAGE REVIEW USE CASE ===================================
+#[async_trait]
+impl ManageReviewUseCase for ReviewAppService {
+    async fn create_review(
+        &self,
+        account_id: &str,
+        course_id: &str,
+        params: &CreateReviewParams,
+    ) -> DomainResponse<ReviewEntity> {
+        // 1. Only learners enrolled in a published course can review it
+        let course = self.course_service.find_course_by_id(course_id).await?;
+        if !course.is_published() {
+            return Err(Failure::NotFound(format!("Course with id {} not found", course_id)));
+        }
+        if self.enrollment_service.find_enrollment(account_id, course_id).await?.is_none() {
+            return Err(Failure::Forbidden("Only enrolled learners can review this course".to_string()));
+        }
+
+        // 2. Create the review, the course rating follows
+        let review = ReviewEntity::new(true, course_id, account_id, params.rating, params.content.trim());
+        let review = self.review_service.create_review(&review).await?;
+
+        // 3. Notify the course author
+        if course.author_id != account_id {
+            let review_id = review.base.id.clone().unwrap_or_default();
+            let notification = NotificationEntity::course_review(
+                &course.author_id,
+                course_id,
+                &review_id,
+                &course.name,
+                review.rating,
+            );
+            self.notification_service.create_notification(&notification).await?;
+        }
+
+        Ok(review)
+    }
+
+    async fn update_review(
+        &self,
+        account_id: &str,
+        course_id: &str,
+        params: &UpdateReviewParams,
+    ) -> DomainResponse<ReviewEntity> {
+        let review = self.find_review_of(account_id, course_id).await?;
+        self.review_service.update_review(&review, params.rating, params.content.as_deref().map(str::trim)).await
+    }
+
+    async fn delete_review(&self, account_id: &str, course_id: &str) -> DomainResponse<()> {
+        let review = self.find_review_of(account_id, course_id).await?;
+        self.review_service.delete_review(&review).await
+    }
+
+    async fn find_own_review(&self, account_id: &str, course_id: &str) -> DomainResponse<ReviewEntity> {
+        self.find_review_of(account_id, course_id).await
+    }
+
+    async fn find_course_reviews(
+        &self,
+        course_id: &str,
+        query: &FindReviewsQuery,
+    ) -> DomainResponse<(Paginate, Vec<ReviewEntity>)> {
+        self.review_service.find_course_reviews(course_id, query.paginate.page, query.paginate.page_size).await
+    }
+}
+// endregion =================================== MANAGE REVIEW USE CASE ===================================
+
+// region =================================== MODERATE REVIEW USE CASE ===================================
+#[async_trait]
+impl ModerateReviewUseCase for ReviewAppService {
+    async fn find_reviews(&self, query: &FindModeratedReviewsQuery) -> DomainResponse<(Paginate, Vec<ReviewEntity>)> {
+        let status = match &query.status {
+            Some(status) => ReviewStatus::from_string(status)?,
+            None => ReviewStatus::Approved,
+        };
+
+        self.review_service.find_reviews_by_status(status, query.paginate.page, query.paginate.page_size).await
+    }
+
+    async fn hide_review(&self, review_id: &str) -> DomainResponse<ReviewEntity> {
+        let review = self.review_service.find_review_by_id(review_id).await?;
+        self.review_service.change_review_status(&review, ReviewStatus::Hidden).await
+    }
+
+    async fn approve_review(&self, review_id: &str) -> DomainResponse<ReviewEntity> {
+        let review = self.review_service.find_review_by_id(review_id).await?;
+        self.review_service.change_review_status(&review, ReviewStatus::Approved).await
+    }
+
+    async fn remove_review(&self, review_id: &str) -> DomainResponse<()> {
+        let review = self.review_service.find_review_by_id(review_id).await?;
+        self.review_service.delete_review(&review).await
+    }
+}
+// endregion =================================== MODERATE REVIEW USE CASE ===================================
@@ -0,0 +1,73 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+
+// shared modules
+use shared::models::filters::QuerySpec;
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::apis::search_index::SearchFilter;
+use crate::services::{blog_service::BlogService, project_service::ProjectService, search_service::SearchService};
+use crate::usecases::search_usecases::{IndexSearchUseCase, SearchQuery, SearchResult, SearchUseCase};
+use crate::values::search_kind::SearchKind;
+
+// Page size used to walk every published blog while rebuilding the index
+static REBUILD_PAGE_SIZE: u32 = 100;
+
+pub struct SearchAppService {
+    search_service: Arc<dyn SearchService>,
+    blog_service: Arc<dyn BlogService>,
+    project_service: Arc<dyn ProjectService>,
+}
+
+impl SearchAppService {
+    pub fn new(
+        search_service: Arc<dyn SearchService>,
+        blog_service: Arc<dyn BlogService>,
+        project_service: Arc<dyn ProjectService>,
+    ) -> Self {
+        Self { search_service, blog_service, project_service }
+    }
+}
+
+// region =================================== SEARCH USE CASE ===================================
+#[async_trait]
+impl SearchUseCase for SearchAppService {
+    async fn search(&self, query: &SearchQuery) -> DomainResponse<SearchResult> {
+        // 1. Normalize the kind filter
+        let kind = match &query.kind {
+            Some(kind) => Some(SearchKind::from_string(kind)?.as_str().to_string()),
+            None => None,
+        };
+
+        // 2. Search the index
+        let filter = SearchFilter { kind, category: query.category.clone(), tag: query.tag.clone() };
+        self.search_service.search(&query.q, &filter, query.paginate.page, query.paginate.page_size).await
+    }
+}
+// endregion =================================== SEARCH USE CASE ===================================
+
+// region =================================== INDEX SEARCH USE CASE ===================================
+#[async_trait]
+impl IndexSearchUseCase for SearchAppService {
+    async fn rebuild_search_index(&self) -> DomainResponse<usize> {
+        // 1. Keep the writes made while reading, then collect every published blog
+        self.search_service.begin_rebuild().await?;
+        let mut blogs = Vec::new();
+        let mut page = 1;
+        loop {
+            let (paginate, mut batch) =
+                self.blog_service.find_published_blogs(&QuerySpec::default(), page, REBUILD_PAGE_SIZE).await?;
+            blogs.append(&mut batch);
+            if page >= paginate.total_page {
+                break;
+            }
+            page += 1;
+        }
+
+        // 2. Collect projects and swap the index content
+        let projects = self.project_service.find_projects().await?;
+        self.search_service.rebuild(&blogs, &projects).await
+    }
+}
+// endregion =================================== INDEX SEARCH USE CASE ===================================
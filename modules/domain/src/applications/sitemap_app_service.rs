@@ -0,0 +1,92 @@
+use async_trait::async_trait;
+use std::sync::{Arc, Mutex};
+
+// shared modules
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::services::{blog_service::BlogService, category_service::CategoryService, project_service::ProjectService};
+use crate::usecases::sitemap_usecases::{SitemapEntry, SitemapEntryKind, SitemapUseCase};
+
+// Entries are reused as long as the scheduled publish interval, the blogs it publishes show up one tick late at most
+static ENTRIES_CACHE_SECONDS: i64 = 30;
+
+pub struct SitemapAppService {
+    blog_service: Arc<dyn BlogService>,
+    project_service: Arc<dyn ProjectService>,
+    category_service: Arc<dyn CategoryService>,
+    // Every sitemap file and robots request reads all entries, they are loaded once per cache window
+    cached_entries: Mutex<Option<(i64, Vec<SitemapEntry>)>>,
+}
+
+impl SitemapAppService {
+    pub fn new(
+        blog_service: Arc<dyn BlogService>,
+        project_service: Arc<dyn ProjectService>,
+        category_service: Arc<dyn CategoryService>,
+    ) -> Self {
+        Self { blog_service, project_service, category_service, cached_entries: Mutex::new(None) }
+    }
+
+    /// Every public page from the database, in a stable order.
+    async fn load_entries(&self) -> DomainResponse<Vec<SitemapEntry>> {
+        // 1. Published blogs, only ids and timestamps are loaded
+        let mut blogs: Vec<SitemapEntry> = self
+            .blog_service
+            .find_published_modification_times()
+            .await?
+            .into_iter()
+            .map(|(id, updated_at)| SitemapEntry { kind: SitemapEntryKind::Blog, id, updated_at })
+            .collect();
+
+        // 2. Projects and categories
+        let mut projects: Vec<SitemapEntry> = self
+            .project_service
+            .find_projects()
+            .await?
+            .into_iter()
+            .filter_map(|project| {
+                let id = project.base.id?;
+                Some(SitemapEntry { kind: SitemapEntryKind::Project, id, updated_at: project.base.updated_at })
+            })
+            .collect();
+        let mut categories: Vec<SitemapEntry> = self
+            .category_service
+            .find_categories()
+            .await?
+            .into_iter()
+            .filter_map(|category| {
+                let id = category.base.id?;
+                Some(SitemapEntry { kind: SitemapEntryKind::Category, id, updated_at: category.base.updated_at })
+            })
+            .collect();
+
+        // 3. Keep the order stable
+        for entries in [&mut blogs, &mut projects, &mut categories] {
+            entries.sort_by(|a, b| a.id.cmp(&b.id));
+        }
+
+        let mut entries = blogs;
+        entries.append(&mut projects);
+        entries.append(&mut categories);
+        Ok(entries)
+    }
+}
+
+// region =================================== SITEMAP USE CASE ===================================
+#[async_trait]
+impl SitemapUseCase for SitemapAppService {
+    async fn find_sitemap_entries(&self) -> DomainResponse<Vec<SitemapEntry>> {
+        let now = chrono::Utc::now().timestamp();
+        if let Some((loaded_at, entries)) = self.cached_entries.lock().unwrap().as_ref()
+            && now - loaded_at < ENTRIES_CACHE_SECONDS
+        {
+            return Ok(entries.clone());
+        }
+
+        let entries = self.load_entries().await?;
+        *self.cached_entries.lock().unwrap() = Some((now, entries.clone()));
+        Ok(entries)
+    }
+}
+// endregion =================================== SITEMAP USE CASE ===================================
@@ -0,0 +1,98 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+
+// shared modules
+use shared::configs::APP_CONFIG;
+use shared::models::failure::Failure;
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::entities::upload_entity::UploadEntity;
+use crate::services::{blog_service::BlogService, course_service::CourseService, upload_service::UploadService};
+use crate::usecases::upload_usecases::{CreateUploadParams, DirectUploadUseCase, PresignedUpload};
+use crate::values::upload_purpose::UploadPurpose;
+
+// Expired uploads collected per run, the rest waits for the next one
+static EXPIRED_BATCH_SIZE: u32 = 200;
+
+pub struct UploadAppService {
+    upload_service: Arc<dyn UploadService>,
+    blog_service: Arc<dyn BlogService>,
+    course_service: Arc<dyn CourseService>,
+}
+
+impl UploadAppService {
+    pub fn new(
+        upload_service: Arc<dyn UploadService>,
+        blog_service: Arc<dyn BlogService>,
+        course_service: Arc<dyn CourseService>,
+    ) -> Self {
+        Self { upload_service, blog_service, course_service }
+    }
+
+    /// Only the author of the blog or course can upload files to it.
+    async fn ensure_author(&self, account_id: &str, purpose: UploadPurpose, reference_id: &str) -> DomainResponse<()> {
+        let author_id = match purpose {
+            UploadPurpose::BlogAttachment => {
+                self.blog_service
+                    .find_blog_by_id(reference_id)
+                    .await?
+                    .ok_or_else(|| Failure::NotFound(format!("Blog with id {} not found", reference_id)))?
+                    .author_id
+            },
+            UploadPurpose::CourseVideo => self.course_service.find_course_by_id(reference_id).await?.author_id,
+        };
+
+        if author_id != account_id {
+            return Err(Failure::Forbidden("You can only upload files to your own content".to_string()));
+        }
+
+        Ok(())
+    }
+
+    async fn find_own_upload(&self, account_id: &str, upload_id: &str) -> DomainResponse<UploadEntity> {
+        let upload = self.upload_service.find_upload(upload_id).await?;
+        if upload.account_id != account_id {
+            return Err(Failure::NotFound(format!("Upload with id {} not found", upload_id)));
+        }
+
+        Ok(upload)
+    }
+}
+
+// region =================================== DIRECT UPLOAD USE CASE ===================================
+#[async_trait]
+impl DirectUploadUseCase for UploadAppService {
+    async fn create_upload(&self, account_id: &str, params: &CreateUploadParams) -> DomainResponse<PresignedUpload> {
+        // 1. Resolve the purpose and check the account may upload to the referenced content
+        let purpose = UploadPurpose::from_string(&params.purpose)?;
+        self.ensure_author(account_id, purpose, &params.reference_id).await?;
+
+        // 2. Record the pending upload and presign its URL
+        self.upload_service.create_upload(account_id, purpose, params).await
+    }
+
+    async fn find_upload(&self, account_id: &str, upload_id: &str) -> DomainResponse<UploadEntity> {
+        self.find_own_upload(account_id, upload_id).await
+    }
+
+    async fn complete_upload(&self, account_id: &str, upload_id: &str) -> DomainResponse<UploadEntity> {
+        // 1. Only the account that requested the upload can complete it
+        let upload = self.find_own_upload(account_id, upload_id).await?;
+
+        // 2. Past the grace period the upload is about to be cleaned up
+        let deadline = upload.expires_at + APP_CONFIG.media.pending_upload_grace_seconds;
+        if upload.is_pending() && chrono::Utc::now().timestamp() > deadline {
+            return Err(Failure::Conflict("The upload has expired".to_string()));
+        }
+
+        // 3. Verify the stored file
+        self.upload_service.complete_upload(&upload).await
+    }
+
+    async fn collect_expired_uploads(&self) -> DomainResponse<usize> {
+        let expires_before = chrono::Utc::now().timestamp() - APP_CONFIG.media.pending_upload_grace_seconds;
+        self.upload_service.collect_expired(expires_before, EXPIRED_BATCH_SIZE).await
+    }
+}
+// endregion =================================== DIRECT UPLOAD USE CASE ===================================
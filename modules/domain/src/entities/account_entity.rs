@@ -3,11 +3,32 @@ use serde::{Deserialize, Serialize};
 
 // shared modules
 use shared::models::failure::Failure;
+use shared::models::filters::{BOOLEAN_OPERATORS, FieldType, FilterField, Filterable, RANGE_OPERATORS, TEXT_OPERATORS};
 
 // internal modules
 use crate::entities::base_entity::BaseEntity;
 
+const MIN_USERNAME_LENGTH: usize = 3;
 const MAX_USERNAME_LENGTH: usize = 20;
+const MAX_BIO_LENGTH: usize = 500;
+const USERNAME_REGEX: &str = r"^[A-Za-z0-9_.-]+$";
+
+// Names that would shadow routes or pass for staff
+const RESERVED_USERNAMES: [&str; 13] = [
+    "admin",
+    "administrator",
+    "root",
+    "system",
+    "support",
+    "staff",
+    "moderator",
+    "me",
+    "api",
+    "settings",
+    "null",
+    "undefined",
+    "deleted-user",
+];
 const EMAIL_REGEX: &str = r"^[A-Za-z0-9._%+-]+@[A-Za-z0-9.-]+\.[A-Za-z]{2,6}$";
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -21,6 +42,14 @@ pub struct AccountEntity {
     pub email_verified: bool,
     pub bio: String,
     pub is_active: bool,
+    /// When the account was last deactivated, by its owner or by a scheduled deletion.
+    pub deactivated_at: Option<i64>,
+    /// When the account gets erased, reactivating it before then cancels the deletion.
+    pub deletion_due_at: Option<i64>,
+    /// When an admin suspended the account, signing in is refused until `suspended_until`.
+    pub suspended_at: Option<i64>,
+    pub suspended_until: Option<i64>,
+    pub suspension_reason: Option<String>,
 }
 
 crate::define_update_struct! {
@@ -47,9 +76,69 @@ impl AccountEntity {
             email_verified: false,
             bio: "Please write your bio.".to_string(),
             is_active: true,
+            deactivated_at: None,
+            deletion_due_at: None,
+            suspended_at: None,
+            suspended_until: None,
+            suspension_reason: None,
         })
     }
 
+    pub fn deactivate(&mut self) {
+        let now = chrono::Utc::now().timestamp();
+        self.is_active = false;
+        self.deactivated_at = Some(now);
+        self.base.updated_at = now;
+    }
+
+    /// Deactivate the account right away and erase it once `grace_seconds` passed.
+    pub fn schedule_deletion(&mut self, grace_seconds: i64) {
+        self.deactivate();
+        self.deletion_due_at = Some(self.deactivated_at.unwrap_or_default() + grace_seconds);
+    }
+
+    /// Reactivating also cancels a scheduled deletion.
+    pub fn reactivate(&mut self) {
+        self.is_active = true;
+        self.deactivated_at = None;
+        self.deletion_due_at = None;
+        self.base.updated_at = chrono::Utc::now().timestamp();
+    }
+
+    /// Suspend the account until `until`, a later suspension replaces the current one.
+    pub fn suspend(&mut self, reason: &str, until: i64) -> Result<(), Failure> {
+        let now = chrono::Utc::now().timestamp();
+        if until <= now {
+            return Err(Failure::ValidationError("Suspension must end in the future".to_string()));
+        }
+
+        self.suspended_at = Some(now);
+        self.suspended_until = Some(until);
+        self.suspension_reason = Some(reason.trim().to_string());
+        self.base.updated_at = now;
+        Ok(())
+    }
+
+    pub fn lift_suspension(&mut self) {
+        self.suspended_at = None;
+        self.suspended_until = None;
+        self.suspension_reason = None;
+        self.base.updated_at = chrono::Utc::now().timestamp();
+    }
+
+    /// The username followed by a number, shortened to stay within the length limit. Used when the username an
+    /// account signs up with is already taken.
+    pub fn numbered_username(username: &str, number: u32) -> String {
+        let suffix = number.to_string();
+        let kept = MAX_USERNAME_LENGTH.saturating_sub(suffix.len());
+        format!("{}{}", username.chars().take(kept).collect::<String>(), suffix)
+    }
+
+    /// A suspension ends by itself once `suspended_until` passed.
+    pub fn is_suspended(&self) -> bool {
+        self.suspended_until.is_some_and(|until| until > chrono::Utc::now().timestamp())
+    }
+
     fn validate_email(email: &str) -> Result<(), Failure> {
         let regex = Regex::new(EMAIL_REGEX)
             .map_err(|e| Failure::ValidationError(format!("Failed to compile email regex: {}", e)))?;
@@ -61,6 +150,49 @@ impl AccountEntity {
         Ok(())
     }
 
+    /// Apply the fields a user may edit on their own profile. Usernames chosen here follow stricter rules than
+    /// the ones derived from a sign-in provider.
+    pub fn apply_profile(&mut self, update: &UpdateAccountEntity) -> Result<(), Failure> {
+        if let Some(username) = &update.username {
+            Self::validate_profile_username(username)?;
+            self.username = username.clone();
+        }
+        if let Some(bio) = &update.bio {
+            if bio.chars().count() > MAX_BIO_LENGTH {
+                return Err(Failure::ValidationError(format!("Bio must not exceed {} characters", MAX_BIO_LENGTH)));
+            }
+            self.bio = bio.trim().to_string();
+        }
+
+        self.base.updated_at = chrono::Utc::now().timestamp();
+        Ok(())
+    }
+
+    fn validate_profile_username(username: &str) -> Result<(), Failure> {
+        Self::validate_username(username)?;
+
+        if username.len() < MIN_USERNAME_LENGTH {
+            return Err(Failure::ValidationError(format!(
+                "Username must be at least {} characters",
+                MIN_USERNAME_LENGTH
+            )));
+        }
+
+        let regex = Regex::new(USERNAME_REGEX)
+            .map_err(|e| Failure::ValidationError(format!("Failed to compile username regex: {}", e)))?;
+        if !regex.is_match(username) {
+            return Err(Failure::ValidationError(
+                "Username may only contain letters, digits, dots, dashes and underscores".to_string(),
+            ));
+        }
+
+        if RESERVED_USERNAMES.iter().any(|reserved| reserved.eq_ignore_ascii_case(username)) {
+            return Err(Failure::ValidationError(format!("Username {} is reserved", username)));
+        }
+
+        Ok(())
+    }
+
     fn validate_username(username: &str) -> Result<(), Failure> {
         if username.len() > MAX_USERNAME_LENGTH {
             return Err(Failure::ValidationError(format!(
@@ -72,3 +204,98 @@ impl AccountEntity {
         Ok(())
     }
 }
+
+impl Filterable for AccountEntity {
+    fn filter_fields() -> &'static [FilterField] {
+        static FIELDS: &[FilterField] = &[
+            FilterField {
+                name: "username",
+                column: "username",
+                field_type: FieldType::String,
+                operators: TEXT_OPERATORS,
+                sortable: true,
+            },
+            FilterField {
+                name: "email",
+                column: "email",
+                field_type: FieldType::String,
+                operators: TEXT_OPERATORS,
+                sortable: true,
+            },
+            FilterField {
+                name: "emailVerified",
+                column: "email_verified",
+                field_type: FieldType::Boolean,
+                operators: BOOLEAN_OPERATORS,
+                sortable: false,
+            },
+            FilterField {
+                name: "createdAt",
+                column: "created_at",
+                field_type: FieldType::Number,
+                operators: RANGE_OPERATORS,
+                sortable: true,
+            },
+            FilterField {
+                name: "updatedAt",
+                column: "updated_at",
+                field_type: FieldType::Number,
+                operators: RANGE_OPERATORS,
+                sortable: true,
+            },
+            FilterField {
+                name: "deactivatedAt",
+                column: "deactivated_at",
+                field_type: FieldType::Number,
+                operators: RANGE_OPERATORS,
+                sortable: true,
+            },
+            FilterField {
+                name: "suspendedUntil",
+                column: "suspended_until",
+                field_type: FieldType::Number,
+                operators: RANGE_OPERATORS,
+                sortable: true,
+            },
+            // Selectable only
+            FilterField {
+                name: "avatar",
+                column: "avatar",
+                field_type: FieldType::String,
+                operators: &[],
+                sortable: false,
+            },
+            FilterField { name: "bio", column: "bio", field_type: FieldType::String, operators: &[], sortable: false },
+            FilterField {
+                name: "isActive",
+                column: "is_active",
+                field_type: FieldType::Boolean,
+                operators: &[],
+                sortable: false,
+            },
+            FilterField {
+                name: "deletionDueAt",
+                column: "deletion_due_at",
+                field_type: FieldType::Number,
+                operators: &[],
+                sortable: false,
+            },
+            FilterField {
+                name: "suspendedAt",
+                column: "suspended_at",
+                field_type: FieldType::Number,
+                operators: &[],
+                sortable: false,
+            },
+            FilterField {
+                name: "suspensionReason",
+                column: "suspension_reason",
+                field_type: FieldType::String,
+                operators: &[],
+                sortable: false,
+            },
+        ];
+
+        FIELDS
+    }
+}
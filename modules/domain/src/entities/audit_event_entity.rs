@@ -0,0 +1,191 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeSet;
+
+// internal modules
+use crate::entities::base_entity::BaseEntity;
+use crate::values::audit_action::AuditAction;
+
+// Bumped on every write, it would show up in every diff
+const IGNORED_DIFF_FIELDS: [&str; 1] = ["updatedAt"];
+
+/// One field that changed, `None` on the side the field is missing from.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditChange {
+    pub field: String,
+    pub before: Option<Value>,
+    pub after: Option<Value>,
+}
+
+/// Something security relevant or administrative that happened, events are only ever appended.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditEventEntity {
+    #[serde(flatten)]
+    pub base: BaseEntity,
+    /// Account that performed the action, `None` for scheduled jobs.
+    pub actor_id: Option<String>,
+    /// Admin acting as `actor_id` through an impersonation.
+    pub impersonator_id: Option<String>,
+    pub action: String,
+    pub target_type: String,
+    pub target_id: Option<String>,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+    pub request_id: Option<String>,
+    pub changes: Vec<AuditChange>,
+}
+
+impl AuditEventEntity {
+    pub fn new(include_id: bool, action: AuditAction, target_id: Option<&str>) -> Self {
+        Self {
+            base: BaseEntity::new(include_id),
+            actor_id: None,
+            impersonator_id: None,
+            action: action.as_str().to_string(),
+            target_type: action.target_type().to_string(),
+            target_id: target_id.map(|id| id.to_string()),
+            ip_address: None,
+            user_agent: None,
+            request_id: None,
+            changes: vec![],
+        }
+    }
+
+    /// Record the actor when the request is not signed in, such as a sign-in.
+    pub fn with_actor(mut self, actor_id: &str) -> Self {
+        self.actor_id = Some(actor_id.to_string());
+        self
+    }
+
+    /// Record what changed between two states of the target, either side is `None` when the target was created
+    /// or deleted.
+    pub fn with_changes<T: Serialize>(mut self, before: Option<&T>, after: Option<&T>) -> Self {
+        let before = before.and_then(|before| serde_json::to_value(before).ok()).unwrap_or(Value::Null);
+        let after = after.and_then(|after| serde_json::to_value(after).ok()).unwrap_or(Value::Null);
+        self.changes = Self::diff(&before, &after);
+        self
+    }
+
+    /// Top level fields that differ, a state that is not an object is compared as a whole under an empty field.
+    pub fn diff(before: &Value, after: &Value) -> Vec<AuditChange> {
+        let empty = serde_json::Map::new();
+        let (before_fields, after_fields) = match (before, after) {
+            (Value::Object(before), Value::Object(after)) => (before, after),
+            (Value::Object(before), Value::Null) => (before, &empty),
+            (Value::Null, Value::Object(after)) => (&empty, after),
+            _ if before == after => return vec![],
+            _ => {
+                return vec![AuditChange {
+                    field: String::new(),
+                    before: Some(before.clone()).filter(|value| !value.is_null()),
+                    after: Some(after.clone()).filter(|value| !value.is_null()),
+                }];
+            },
+        };
+
+        let fields: BTreeSet<&String> = before_fields.keys().chain(after_fields.keys()).collect();
+        fields
+            .into_iter()
+            .filter(|field| !IGNORED_DIFF_FIELDS.contains(&field.as_str()))
+            .filter(|field| before_fields.get(*field) != after_fields.get(*field))
+            .map(|field| AuditChange {
+                field: field.clone(),
+                before: before_fields.get(field).cloned(),
+                after: after_fields.get(field).cloned(),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn event(before: Option<&Value>, after: Option<&Value>) -> AuditEventEntity {
+        AuditEventEntity::new(false, AuditAction::AccountSuspended, Some("account")).with_changes(before, after)
+    }
+
+    fn change(field: &str, before: Option<Value>, after: Option<Value>) -> AuditChange {
+        AuditChange { field: field.to_string(), before, after }
+    }
+
+    #[test]
+    fn only_changed_fields_are_recorded() {
+        let before = json!({ "username": "ada", "bio": "Hi", "avatar": "a.png" });
+        let after = json!({ "username": "lovelace", "bio": "Hi", "avatar": "b.png" });
+
+        assert_eq!(
+            event(Some(&before), Some(&after)).changes,
+            vec![
+                change("avatar", Some(json!("a.png")), Some(json!("b.png"))),
+                change("username", Some(json!("ada")), Some(json!("lovelace"))),
+            ]
+        );
+    }
+
+    #[test]
+    fn created_targets_record_every_field_without_a_before() {
+        let after = json!({ "name": "Rust", "parentId": null });
+
+        assert_eq!(
+            event(None, Some(&after)).changes,
+            vec![change("name", None, Some(json!("Rust"))), change("parentId", None, Some(Value::Null))]
+        );
+    }
+
+    #[test]
+    fn deleted_targets_record_every_field_without_an_after() {
+        let before = json!({ "name": "Rust" });
+
+        assert_eq!(event(Some(&before), None).changes, vec![change("name", Some(json!("Rust")), None)]);
+    }
+
+    #[test]
+    fn fields_present_on_one_side_only_are_recorded() {
+        let before = json!({ "suspendedUntil": 10 });
+        let after = json!({ "reason": "spam" });
+
+        assert_eq!(
+            event(Some(&before), Some(&after)).changes,
+            vec![change("reason", None, Some(json!("spam"))), change("suspendedUntil", Some(json!(10)), None)]
+        );
+    }
+
+    #[test]
+    fn the_update_timestamp_is_ignored() {
+        let before = json!({ "name": "Rust", "updatedAt": 1 });
+        let after = json!({ "name": "Rust", "updatedAt": 2 });
+
+        assert!(event(Some(&before), Some(&after)).changes.is_empty());
+    }
+
+    #[test]
+    fn nested_values_are_compared_as_a_whole() {
+        let before = json!({ "roles": ["USER"] });
+        let after = json!({ "roles": ["USER", "ADMIN"] });
+
+        assert_eq!(
+            event(Some(&before), Some(&after)).changes,
+            vec![change("roles", Some(json!(["USER"])), Some(json!(["USER", "ADMIN"])))]
+        );
+    }
+
+    #[test]
+    fn states_that_are_not_objects_are_compared_under_an_empty_field() {
+        assert_eq!(
+            AuditEventEntity::diff(&json!("draft"), &json!("published")),
+            vec![change("", Some(json!("draft")), Some(json!("published")))]
+        );
+        assert!(AuditEventEntity::diff(&json!(3), &json!(3)).is_empty());
+        assert!(AuditEventEntity::diff(&Value::Null, &Value::Null).is_empty());
+    }
+
+    #[test]
+    fn events_without_states_have_no_changes() {
+        assert!(event(None, None).changes.is_empty());
+    }
+}
@@ -1,15 +1,20 @@
 use serde::{Deserialize, Serialize};
 
 // shared modules
-use shared::{models::failure::Failure, types::DomainResponse};
+use shared::models::failure::Failure;
+use shared::models::filters::{FieldType, FilterField, FilterOperator, Filterable, RANGE_OPERATORS, TEXT_OPERATORS};
+use shared::types::DomainResponse;
 
 // internal modules
 use crate::entities::base_entity::BaseEntity;
+use crate::values::blog_status::BlogStatus;
 
 // Define rules for blog entity
 static MAX_BLOG_MARKDOWN_SIZE: usize = 10 * 1024 * 1024; // 10 MB
 static MAX_BLOG_NAME_SIZE: usize = 255;
 static MAX_BLOG_DESCRIPTION_SIZE: usize = 1024;
+pub static DEFAULT_REVISION_RETENTION: i32 = 20;
+static MAX_REVISION_RETENTION: i32 = 200;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -20,11 +25,25 @@ pub struct BlogEntity {
     pub categories: Vec<String>,
     pub name: String,
     pub description: String,
-    pub is_published: bool,
+    pub status: String,
+    pub publish_at: Option<i64>,
+    pub published_at: Option<i64>,
     pub markdown: String,
     pub stars: i32,
     pub views: i32,
     pub estimated_read_time: i32,
+    pub revision_retention: i32,
+}
+
+crate::define_update_struct! {
+    pub struct UpdateBlogEntity for BlogEntity {
+        pub categories: Vec<String>,
+        pub name: String,
+        pub description: String,
+        pub markdown: String,
+        pub estimated_read_time: i32,
+        pub revision_retention: i32,
+    }
 }
 
 impl BlogEntity {
@@ -35,7 +54,6 @@ impl BlogEntity {
         categories: &[String],
         name: &str,
         description: &str,
-        is_published: bool,
         markdown: &str,
         estimated_read_time: i32,
     ) -> BlogEntity {
@@ -45,11 +63,14 @@ impl BlogEntity {
             categories: categories.to_owned(),
             name: name.to_string(),
             description: description.to_string(),
-            is_published,
+            status: BlogStatus::Draft.as_str().to_string(),
+            publish_at: None,
+            published_at: None,
             markdown: markdown.to_string(),
             stars: 0,
             views: 0,
             estimated_read_time,
+            revision_retention: DEFAULT_REVISION_RETENTION,
         }
     }
 
@@ -85,4 +106,147 @@ impl BlogEntity {
 
         Ok(())
     }
+
+    pub fn validate_revision_retention(revision_retention: i32) -> DomainResponse<()> {
+        if !(1..=MAX_REVISION_RETENTION).contains(&revision_retention) {
+            return Err(Failure::ValidationError(format!(
+                "Revision retention must be between 1 and {}",
+                MAX_REVISION_RETENTION
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Move the blog to `next`, `publish_at` is required for and only kept on scheduled blogs.
+    pub fn transition(&mut self, next: BlogStatus, publish_at: Option<i64>) -> DomainResponse<()> {
+        let current = BlogStatus::from_string(&self.status)?;
+        if !current.can_transition_to(next) {
+            return Err(Failure::BadRequest(format!(
+                "Blog can not move from {} to {}",
+                current.as_str(),
+                next.as_str()
+            )));
+        }
+
+        let now = chrono::Utc::now().timestamp();
+        match next {
+            BlogStatus::Scheduled => {
+                let publish_at =
+                    publish_at.ok_or(Failure::ValidationError("Scheduled blogs require a publish time".to_string()))?;
+                if publish_at <= now {
+                    return Err(Failure::ValidationError("Publish time must be in the future".to_string()));
+                }
+
+                self.publish_at = Some(publish_at);
+            },
+            BlogStatus::Published => {
+                self.publish_at = None;
+                self.published_at = self.published_at.or(Some(now));
+            },
+            _ => self.publish_at = None,
+        }
+
+        self.status = next.as_str().to_string();
+        self.base.updated_at = now;
+        Ok(())
+    }
+
+    pub fn is_published(&self) -> bool {
+        self.status == BlogStatus::Published.as_str()
+    }
+}
+
+impl Filterable for BlogEntity {
+    fn filter_fields() -> &'static [FilterField] {
+        static FIELDS: &[FilterField] = &[
+            FilterField {
+                name: "name",
+                column: "name",
+                field_type: FieldType::String,
+                operators: TEXT_OPERATORS,
+                sortable: true,
+            },
+            FilterField {
+                name: "description",
+                column: "description",
+                field_type: FieldType::String,
+                operators: &[FilterOperator::Contains],
+                sortable: false,
+            },
+            FilterField {
+                name: "estimatedReadTime",
+                column: "estimated_read_time",
+                field_type: FieldType::Number,
+                operators: RANGE_OPERATORS,
+                sortable: true,
+            },
+            FilterField {
+                name: "stars",
+                column: "stars",
+                field_type: FieldType::Number,
+                operators: RANGE_OPERATORS,
+                sortable: true,
+            },
+            FilterField {
+                name: "views",
+                column: "views",
+                field_type: FieldType::Number,
+                operators: RANGE_OPERATORS,
+                sortable: true,
+            },
+            FilterField {
+                name: "publishedAt",
+                column: "published_at",
+                field_type: FieldType::Number,
+                operators: RANGE_OPERATORS,
+                sortable: true,
+            },
+            FilterField {
+                name: "createdAt",
+                column: "created_at",
+                field_type: FieldType::Number,
+                operators: RANGE_OPERATORS,
+                sortable: true,
+            },
+            FilterField {
+                name: "updatedAt",
+                column: "updated_at",
+                field_type: FieldType::Number,
+                operators: RANGE_OPERATORS,
+                sortable: true,
+            },
+            // Selectable only
+            FilterField {
+                name: "status",
+                column: "status",
+                field_type: FieldType::String,
+                operators: &[],
+                sortable: false,
+            },
+            FilterField {
+                name: "authorId",
+                column: "author_id",
+                field_type: FieldType::String,
+                operators: &[],
+                sortable: false,
+            },
+            FilterField {
+                name: "categories",
+                column: "categories",
+                field_type: FieldType::String,
+                operators: &[],
+                sortable: false,
+            },
+            FilterField {
+                name: "markdown",
+                column: "markdown",
+                field_type: FieldType::String,
+                operators: &[],
+                sortable: false,
+            },
+        ];
+
+        FIELDS
+    }
 }
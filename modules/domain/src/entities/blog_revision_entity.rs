@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+// internal modules
+use crate::entities::base_entity::BaseEntity;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlogRevisionEntity {
+    #[serde(flatten)]
+    pub base: BaseEntity,
+    pub blog_id: String,
+    pub author_id: String,
+    pub version: i32,
+    pub markdown: String,
+    pub content_hash: String,
+    pub restored_from: Option<i32>,
+}
+
+impl BlogRevisionEntity {
+    pub fn new(
+        include_id: bool,
+        blog_id: &str,
+        author_id: &str,
+        version: i32,
+        markdown: &str,
+        restored_from: Option<i32>,
+    ) -> Self {
+        Self {
+            base: BaseEntity::new(include_id),
+            blog_id: blog_id.to_string(),
+            author_id: author_id.to_string(),
+            version,
+            markdown: markdown.to_string(),
+            content_hash: Self::hash_content(markdown),
+            restored_from,
+        }
+    }
+
+    /// SHA-256 of the markdown, hex encoded. Used to skip revisions when content is unchanged.
+    pub fn hash_content(markdown: &str) -> String {
+        format!("{:x}", Sha256::digest(markdown.as_bytes()))
+    }
+}
@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+// internal modules
+use crate::entities::base_entity::BaseEntity;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlogStarEntity {
+    #[serde(flatten)]
+    pub base: BaseEntity,
+    pub blog_id: String,
+    pub account_id: String,
+}
+
+impl BlogStarEntity {
+    pub fn new(include_id: bool, blog_id: &str, account_id: &str) -> Self {
+        Self { base: BaseEntity::new(include_id), blog_id: blog_id.to_string(), account_id: account_id.to_string() }
+    }
+}
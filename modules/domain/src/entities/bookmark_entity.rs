@@ -0,0 +1,26 @@
+use serde::{Deserialize, Serialize};
+
+// internal modules
+use crate::entities::base_entity::BaseEntity;
+use crate::values::bookmark_target::BookmarkTarget;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BookmarkEntity {
+    #[serde(flatten)]
+    pub base: BaseEntity,
+    pub account_id: String,
+    pub target_type: String,
+    pub target_id: String,
+}
+
+impl BookmarkEntity {
+    pub fn new(include_id: bool, account_id: &str, target_type: BookmarkTarget, target_id: &str) -> Self {
+        Self {
+            base: BaseEntity::new(include_id),
+            account_id: account_id.to_string(),
+            target_type: target_type.as_str().to_string(),
+            target_id: target_id.to_string(),
+        }
+    }
+}
@@ -7,6 +7,8 @@ use shared::{models::failure::Failure, types::DomainResponse};
 use crate::entities::base_entity::BaseEntity;
 
 static MAX_CATEGORY_NAME_LENGTH: usize = 50;
+pub static MAX_CATEGORY_DEPTH: i32 = 5;
+pub static ROOT_CATEGORY_PATH: &str = "/";
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -14,12 +16,26 @@ pub struct CategoryEntity {
     #[serde(flatten)]
     pub base: BaseEntity,
     pub name: String,
+    pub parent_id: Option<String>,
+    /// Ids of every ancestor from the root, e.g. `/rootId/parentId/`, `/` for root categories.
+    pub path: String,
+    pub depth: i32,
 }
 
 impl CategoryEntity {
-    pub fn new(include_id: bool, name: &str) -> DomainResponse<Self> {
+    pub fn new(include_id: bool, name: &str, parent: Option<&CategoryEntity>) -> DomainResponse<Self> {
         Self::validate_name(name)?;
-        Ok(CategoryEntity { base: BaseEntity::new(include_id), name: name.to_string() })
+
+        let mut category = CategoryEntity {
+            base: BaseEntity::new(include_id),
+            name: name.to_string(),
+            parent_id: None,
+            path: ROOT_CATEGORY_PATH.to_string(),
+            depth: 0,
+        };
+        category.attach_to(parent)?;
+
+        Ok(category)
     }
 
     pub fn validate_name(name: &str) -> DomainResponse<()> {
@@ -32,4 +48,43 @@ impl CategoryEntity {
 
         Ok(())
     }
+
+    pub fn validate_depth(depth: i32) -> DomainResponse<()> {
+        if depth >= MAX_CATEGORY_DEPTH {
+            return Err(Failure::ValidationError(format!(
+                "Categories can not be nested deeper than {} levels",
+                MAX_CATEGORY_DEPTH
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Path shared by every descendant of this category.
+    pub fn descendant_path(&self) -> String {
+        format!("{}{}/", self.path, self.base.id.clone().unwrap_or_default())
+    }
+
+    pub fn is_ancestor_of(&self, other: &CategoryEntity) -> bool {
+        other.path.starts_with(&self.descendant_path())
+    }
+
+    /// Move under `parent`, or to the root when `None`. Descendants must be re-attached by the caller.
+    pub fn attach_to(&mut self, parent: Option<&CategoryEntity>) -> DomainResponse<()> {
+        match parent {
+            Some(parent) => {
+                Self::validate_depth(parent.depth + 1)?;
+                self.parent_id = parent.base.id.clone();
+                self.path = parent.descendant_path();
+                self.depth = parent.depth + 1;
+            },
+            None => {
+                self.parent_id = None;
+                self.path = ROOT_CATEGORY_PATH.to_string();
+                self.depth = 0;
+            },
+        }
+
+        Ok(())
+    }
 }
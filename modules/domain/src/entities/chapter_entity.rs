@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+
+// shared modules
+use shared::{models::failure::Failure, types::DomainResponse};
+
+// internal modules
+use crate::entities::base_entity::BaseEntity;
+
+// Define rules for chapter entity
+static MAX_CHAPTER_NAME_SIZE: usize = 255;
+static MAX_CHAPTER_DESCRIPTION_SIZE: usize = 2048;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChapterEntity {
+    #[serde(flatten)]
+    pub base: BaseEntity,
+    pub course_id: String,
+    pub order_index: i32,
+    pub name: String,
+    pub description: String,
+}
+
+impl ChapterEntity {
+    pub fn new(include_id: bool, course_id: &str, order_index: i32, name: &str, description: &str) -> Self {
+        Self {
+            base: BaseEntity::new(include_id),
+            course_id: course_id.to_string(),
+            order_index,
+            name: name.to_string(),
+            description: description.to_string(),
+        }
+    }
+
+    pub fn validate(&self) -> DomainResponse<()> {
+        if self.name.trim().is_empty() || self.name.len() > MAX_CHAPTER_NAME_SIZE {
+            return Err(Failure::ValidationError(format!(
+                "Chapter name must be between 1 and {} bytes",
+                MAX_CHAPTER_NAME_SIZE
+            )));
+        }
+
+        if self.description.len() > MAX_CHAPTER_DESCRIPTION_SIZE {
+            return Err(Failure::ValidationError(format!(
+                "Chapter description size exceeds the maximum limit of {} bytes",
+                MAX_CHAPTER_DESCRIPTION_SIZE
+            )));
+        }
+
+        if self.order_index < 0 {
+            return Err(Failure::ValidationError("Chapter order index must not be negative".to_string()));
+        }
+
+        Ok(())
+    }
+}
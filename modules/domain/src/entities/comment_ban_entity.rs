@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+
+// internal modules
+use crate::entities::base_entity::BaseEntity;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentBanEntity {
+    #[serde(flatten)]
+    pub base: BaseEntity,
+    pub account_id: String,
+    pub banned_by: String,
+    pub reason: String,
+}
+
+impl CommentBanEntity {
+    pub fn new(include_id: bool, account_id: &str, banned_by: &str, reason: &str) -> Self {
+        Self {
+            base: BaseEntity::new(include_id),
+            account_id: account_id.to_string(),
+            banned_by: banned_by.to_string(),
+            reason: reason.to_string(),
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+
+// shared modules
+use shared::{models::failure::Failure, types::DomainResponse};
+
+// internal modules
+use crate::entities::base_entity::BaseEntity;
+use crate::values::comment_status::CommentStatus;
+
+static MAX_COMMENT_CONTENT_SIZE: usize = 5 * 1024; // 5 KB
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CommentEntity {
+    #[serde(flatten)]
+    pub base: BaseEntity,
+    pub blog_id: String,
+    pub author_id: String,
+    pub parent_id: Option<String>,
+    pub content: String,
+    pub status: String,
+    pub edited_at: Option<i64>,
+}
+
+impl CommentEntity {
+    pub fn new(include_id: bool, blog_id: &str, author_id: &str, parent_id: Option<&str>, content: &str) -> Self {
+        Self {
+            base: BaseEntity::new(include_id),
+            blog_id: blog_id.to_string(),
+            author_id: author_id.to_string(),
+            parent_id: parent_id.map(|id| id.to_string()),
+            content: content.to_string(),
+            status: CommentStatus::Approved.as_str().to_string(),
+            edited_at: None,
+        }
+    }
+
+    pub fn validate_content(content: &str) -> DomainResponse<()> {
+        if content.trim().is_empty() {
+            return Err(Failure::ValidationError("Comment content must not be empty".to_string()));
+        }
+
+        if content.len() > MAX_COMMENT_CONTENT_SIZE {
+            return Err(Failure::ValidationError(format!(
+                "Comment content size exceeds the maximum limit of {} bytes",
+                MAX_COMMENT_CONTENT_SIZE
+            )));
+        }
+
+        Ok(())
+    }
+}
@@ -1 +1,252 @@
-pub struct CourseEntity {}
+use serde::{Deserialize, Serialize};
+
+// shared modules
+use shared::models::filters::{
+    ENUM_OPERATORS, FieldType, FilterField, FilterOperator, Filterable, RANGE_OPERATORS, TEXT_OPERATORS,
+};
+use shared::{models::failure::Failure, types::DomainResponse};
+
+// internal modules
+use crate::entities::base_entity::BaseEntity;
+use crate::values::{course_level::CourseLevel, course_status::CourseStatus};
+
+// Define rules for course entity
+static MAX_COURSE_NAME_SIZE: usize = 255;
+static MAX_COURSE_DESCRIPTION_SIZE: usize = 4096;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CourseEntity {
+    #[serde(flatten)]
+    pub base: BaseEntity,
+    pub author_id: String,
+    pub category_id: String,
+    pub name: String,
+    pub description: String,
+    pub level: String,
+    pub status: String,
+    /// Average of the approved review ratings, kept in sync with `review_count`.
+    pub rating: f32,
+    pub review_count: i32,
+    /// Sum of the lesson estimates in milliseconds.
+    pub approximate: i32,
+    pub enrollments: i32,
+}
+
+impl CourseEntity {
+    pub fn new(
+        include_id: bool,
+        author_id: &str,
+        category_id: &str,
+        name: &str,
+        description: &str,
+        level: CourseLevel,
+    ) -> Self {
+        Self {
+            base: BaseEntity::new(include_id),
+            author_id: author_id.to_string(),
+            category_id: category_id.to_string(),
+            name: name.to_string(),
+            description: description.to_string(),
+            level: level.as_str().to_string(),
+            status: CourseStatus::Draft.as_str().to_string(),
+            rating: 0.0,
+            review_count: 0,
+            approximate: 0,
+            enrollments: 0,
+        }
+    }
+
+    /// Average rating and review count once a review joins, changes or leaves the aggregate. `rating_delta` is
+    /// the rating added or taken away, `count_delta` the number of reviews, an empty aggregate rates 0.
+    pub fn reviewed_rating(rating: f32, review_count: i32, rating_delta: i32, count_delta: i32) -> (f32, i32) {
+        let next_count = review_count + count_delta;
+        let next_rating = match next_count > 0 {
+            true => (rating * review_count as f32 + rating_delta as f32) / next_count as f32,
+            false => 0.0,
+        };
+
+        (next_rating, next_count.max(0))
+    }
+
+    pub fn validate(&self) -> DomainResponse<()> {
+        if self.name.trim().is_empty() || self.name.len() > MAX_COURSE_NAME_SIZE {
+            return Err(Failure::ValidationError(format!(
+                "Course name must be between 1 and {} bytes",
+                MAX_COURSE_NAME_SIZE
+            )));
+        }
+
+        if self.description.len() > MAX_COURSE_DESCRIPTION_SIZE {
+            return Err(Failure::ValidationError(format!(
+                "Course description size exceeds the maximum limit of {} bytes",
+                MAX_COURSE_DESCRIPTION_SIZE
+            )));
+        }
+
+        CourseLevel::from_string(&self.level)?;
+        Ok(())
+    }
+
+    pub fn transition(&mut self, next: CourseStatus) -> DomainResponse<()> {
+        let current = CourseStatus::from_string(&self.status)?;
+        if !current.can_transition_to(next) {
+            return Err(Failure::BadRequest(format!(
+                "Course can not move from {} to {}",
+                current.as_str(),
+                next.as_str()
+            )));
+        }
+
+        self.status = next.as_str().to_string();
+        self.base.updated_at = chrono::Utc::now().timestamp();
+        Ok(())
+    }
+
+    pub fn is_published(&self) -> bool {
+        self.status == CourseStatus::Published.as_str()
+    }
+}
+
+impl Filterable for CourseEntity {
+    fn filter_fields() -> &'static [FilterField] {
+        static FIELDS: &[FilterField] = &[
+            FilterField {
+                name: "name",
+                column: "name",
+                field_type: FieldType::String,
+                operators: TEXT_OPERATORS,
+                sortable: true,
+            },
+            FilterField {
+                name: "description",
+                column: "description",
+                field_type: FieldType::String,
+                operators: &[FilterOperator::Contains],
+                sortable: false,
+            },
+            FilterField {
+                name: "level",
+                column: "level",
+                field_type: FieldType::String,
+                operators: ENUM_OPERATORS,
+                sortable: false,
+            },
+            FilterField {
+                name: "reviewCount",
+                column: "review_count",
+                field_type: FieldType::Number,
+                operators: RANGE_OPERATORS,
+                sortable: true,
+            },
+            FilterField {
+                name: "enrollments",
+                column: "enrollments",
+                field_type: FieldType::Number,
+                operators: RANGE_OPERATORS,
+                sortable: true,
+            },
+            FilterField {
+                name: "approximate",
+                column: "approximate",
+                field_type: FieldType::Number,
+                operators: RANGE_OPERATORS,
+                sortable: true,
+            },
+            FilterField {
+                name: "createdAt",
+                column: "created_at",
+                field_type: FieldType::Number,
+                operators: RANGE_OPERATORS,
+                sortable: true,
+            },
+            FilterField {
+                name: "updatedAt",
+                column: "updated_at",
+                field_type: FieldType::Number,
+                operators: RANGE_OPERATORS,
+                sortable: true,
+            },
+            // The average is fractional, it is sorted on but not filtered
+            FilterField {
+                name: "rating",
+                column: "rating",
+                field_type: FieldType::Number,
+                operators: &[],
+                sortable: true,
+            },
+            // Selectable only
+            FilterField {
+                name: "authorId",
+                column: "author_id",
+                field_type: FieldType::String,
+                operators: &[],
+                sortable: false,
+            },
+            FilterField {
+                name: "categoryId",
+                column: "category_id",
+                field_type: FieldType::String,
+                operators: &[],
+                sortable: false,
+            },
+            FilterField {
+                name: "status",
+                column: "status",
+                field_type: FieldType::String,
+                operators: &[],
+                sortable: false,
+            },
+        ];
+
+        FIELDS
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CourseEntity;
+
+    fn assert_rating(actual: (f32, i32), rating: f32, review_count: i32) {
+        assert!((actual.0 - rating).abs() < 1e-5, "rating {} is not {}", actual.0, rating);
+        assert_eq!(actual.1, review_count);
+    }
+
+    #[test]
+    fn the_first_review_sets_the_rating() {
+        assert_rating(CourseEntity::reviewed_rating(0.0, 0, 4, 1), 4.0, 1);
+    }
+
+    #[test]
+    fn a_new_review_joins_the_average() {
+        // 4 and 5 averaged, then a 3 joins
+        assert_rating(CourseEntity::reviewed_rating(4.5, 2, 3, 1), 4.0, 3);
+    }
+
+    #[test]
+    fn a_changed_rating_moves_the_average_without_counting_again() {
+        // 4, 5 and 3 where the 3 becomes a 5
+        assert_rating(CourseEntity::reviewed_rating(4.0, 3, 2, 0), 14.0 / 3.0, 3);
+    }
+
+    #[test]
+    fn a_removed_review_leaves_the_average() {
+        assert_rating(CourseEntity::reviewed_rating(4.0, 3, -3, -1), 4.5, 2);
+    }
+
+    #[test]
+    fn joining_then_leaving_restores_the_aggregate() {
+        let (rating, review_count) = CourseEntity::reviewed_rating(3.5, 4, 1, 1);
+        assert_rating(CourseEntity::reviewed_rating(rating, review_count, -1, -1), 3.5, 4);
+    }
+
+    #[test]
+    fn the_last_review_leaving_resets_the_rating() {
+        assert_rating(CourseEntity::reviewed_rating(5.0, 1, -5, -1), 0.0, 0);
+    }
+
+    #[test]
+    fn the_count_never_drops_below_zero() {
+        assert_rating(CourseEntity::reviewed_rating(0.0, 0, -4, -1), 0.0, 0);
+    }
+}
@@ -0,0 +1,64 @@
+use serde::{Deserialize, Serialize};
+
+// internal modules
+use crate::entities::base_entity::BaseEntity;
+use crate::values::data_export_status::DataExportStatus;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DataExportEntity {
+    #[serde(flatten)]
+    pub base: BaseEntity,
+    pub account_id: String,
+    pub status: String,
+    #[serde(skip_serializing)]
+    pub storage_key: Option<String>,
+    /// Size of the archive in bytes, once ready.
+    pub size: Option<i64>,
+    /// Why the export failed, if it did.
+    pub error: Option<String>,
+    pub completed_at: Option<i64>,
+    /// When the archive gets deleted, set once it is ready.
+    pub expires_at: Option<i64>,
+}
+
+impl DataExportEntity {
+    pub fn new(include_id: bool, account_id: &str) -> Self {
+        Self {
+            base: BaseEntity::new(include_id),
+            account_id: account_id.to_string(),
+            status: DataExportStatus::Pending.as_str().to_string(),
+            storage_key: None,
+            size: None,
+            error: None,
+            completed_at: None,
+            expires_at: None,
+        }
+    }
+
+    pub fn is_pending(&self) -> bool {
+        self.status == DataExportStatus::Pending.as_str()
+    }
+
+    pub fn is_ready(&self) -> bool {
+        self.status == DataExportStatus::Ready.as_str()
+    }
+
+    pub fn complete(&mut self, storage_key: &str, size: i64, retention_seconds: i64) {
+        let now = chrono::Utc::now().timestamp();
+        self.status = DataExportStatus::Ready.as_str().to_string();
+        self.storage_key = Some(storage_key.to_string());
+        self.size = Some(size);
+        self.completed_at = Some(now);
+        self.expires_at = Some(now + retention_seconds);
+        self.base.updated_at = now;
+    }
+
+    pub fn fail(&mut self, error: &str) {
+        let now = chrono::Utc::now().timestamp();
+        self.status = DataExportStatus::Failed.as_str().to_string();
+        self.error = Some(error.to_string());
+        self.completed_at = Some(now);
+        self.base.updated_at = now;
+    }
+}
@@ -1 +1,57 @@
-pub struct EnrollmentEntity {}
+use serde::{Deserialize, Serialize};
+
+// internal modules
+use crate::entities::base_entity::BaseEntity;
+use crate::values::enrollment_status::EnrollmentStatus;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EnrollmentEntity {
+    #[serde(flatten)]
+    pub base: BaseEntity,
+    pub account_id: String,
+    pub course_id: String,
+    pub status: String,
+    /// Completed lessons in percent, from 0 to 100.
+    pub progress: f32,
+    pub completed_at: Option<i64>,
+}
+
+impl EnrollmentEntity {
+    pub fn new(include_id: bool, account_id: &str, course_id: &str) -> Self {
+        Self {
+            base: BaseEntity::new(include_id),
+            account_id: account_id.to_string(),
+            course_id: course_id.to_string(),
+            status: EnrollmentStatus::Active.as_str().to_string(),
+            progress: 0.0,
+            completed_at: None,
+        }
+    }
+
+    /// Recompute the progress from the number of completed lessons, a course that gained new lessons
+    /// is no longer completed.
+    pub fn apply_progress(&mut self, completed_lessons: usize, total_lessons: usize) {
+        let now = chrono::Utc::now().timestamp();
+        self.progress = match total_lessons {
+            0 => 0.0,
+            _ => (completed_lessons.min(total_lessons) as f32 / total_lessons as f32) * 100.0,
+        };
+
+        if total_lessons > 0 && completed_lessons >= total_lessons {
+            if self.completed_at.is_none() {
+                self.completed_at = Some(now);
+            }
+            self.status = EnrollmentStatus::Completed.as_str().to_string();
+        } else {
+            self.completed_at = None;
+            self.status = EnrollmentStatus::Active.as_str().to_string();
+        }
+
+        self.base.updated_at = now;
+    }
+
+    pub fn is_completed(&self) -> bool {
+        self.status == EnrollmentStatus::Completed.as_str()
+    }
+}
@@ -1,2 +1,78 @@
-#[derive(Debug, Clone)]
-pub struct LessonEntity {}
+use serde::{Deserialize, Serialize};
+
+// shared modules
+use shared::{models::failure::Failure, types::DomainResponse};
+
+// internal modules
+use crate::entities::base_entity::BaseEntity;
+
+// Define rules for lesson entity
+static MAX_LESSON_NAME_SIZE: usize = 255;
+static MAX_LESSON_DESCRIPTION_SIZE: usize = 2048;
+static MAX_LESSON_MARKDOWN_SIZE: usize = 10 * 1024 * 1024; // 10 MB
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LessonEntity {
+    #[serde(flatten)]
+    pub base: BaseEntity,
+    pub chapter_id: String,
+    pub order_index: i32,
+    pub name: String,
+    pub description: String,
+    pub markdown: String,
+    pub estimated_time_ms: i32,
+}
+
+impl LessonEntity {
+    pub fn new(
+        include_id: bool,
+        chapter_id: &str,
+        order_index: i32,
+        name: &str,
+        description: &str,
+        markdown: &str,
+        estimated_time_ms: i32,
+    ) -> Self {
+        Self {
+            base: BaseEntity::new(include_id),
+            chapter_id: chapter_id.to_string(),
+            order_index,
+            name: name.to_string(),
+            description: description.to_string(),
+            markdown: markdown.to_string(),
+            estimated_time_ms,
+        }
+    }
+
+    pub fn validate(&self) -> DomainResponse<()> {
+        if self.name.trim().is_empty() || self.name.len() > MAX_LESSON_NAME_SIZE {
+            return Err(Failure::ValidationError(format!(
+                "Lesson name must be between 1 and {} bytes",
+                MAX_LESSON_NAME_SIZE
+            )));
+        }
+
+        if self.description.len() > MAX_LESSON_DESCRIPTION_SIZE {
+            return Err(Failure::ValidationError(format!(
+                "Lesson description size exceeds the maximum limit of {} bytes",
+                MAX_LESSON_DESCRIPTION_SIZE
+            )));
+        }
+
+        if self.markdown.len() > MAX_LESSON_MARKDOWN_SIZE {
+            return Err(Failure::ValidationError(format!(
+                "Lesson markdown size exceeds the maximum limit of {} bytes",
+                MAX_LESSON_MARKDOWN_SIZE
+            )));
+        }
+
+        if self.order_index < 0 || self.estimated_time_ms < 0 {
+            return Err(Failure::ValidationError(
+                "Lesson order index and estimated time must not be negative".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+}
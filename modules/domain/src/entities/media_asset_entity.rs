@@ -0,0 +1,82 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+// internal modules
+use crate::entities::base_entity::BaseEntity;
+use crate::values::media_purpose::MediaPurpose;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MediaAssetEntity {
+    #[serde(flatten)]
+    pub base: BaseEntity,
+    /// Account that uploaded the asset.
+    pub account_id: String,
+    pub purpose: String,
+    /// Id of the account, blog or project using the asset, depending on the purpose.
+    pub reference_id: Option<String>,
+    pub original_name: String,
+    pub content_type: String,
+    /// Size of the upload as received.
+    pub size: i64,
+    pub width: i32,
+    pub height: i32,
+    /// Public URL of each variant, keyed by variant name.
+    pub variants: HashMap<String, String>,
+    #[serde(skip_serializing)]
+    pub storage_keys: Vec<String>,
+    /// Set while nothing references the asset, it is collected once this is older than the grace period.
+    pub detached_at: Option<i64>,
+}
+
+impl MediaAssetEntity {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        include_id: bool,
+        account_id: &str,
+        purpose: MediaPurpose,
+        original_name: &str,
+        content_type: &str,
+        size: i64,
+        width: i32,
+        height: i32,
+    ) -> Self {
+        let base = BaseEntity::new(include_id);
+        let detached_at = Some(base.created_at);
+
+        Self {
+            base,
+            account_id: account_id.to_string(),
+            purpose: purpose.as_str().to_string(),
+            reference_id: None,
+            original_name: original_name.to_string(),
+            content_type: content_type.to_string(),
+            size,
+            width,
+            height,
+            variants: HashMap::new(),
+            storage_keys: vec![],
+            detached_at,
+        }
+    }
+
+    /// URL of the widest variant, what fields like `avatar` or `cover` point at.
+    pub fn primary_url(&self) -> Option<String> {
+        let purpose = MediaPurpose::from_string(&self.purpose).ok()?;
+        purpose.variants().iter().rev().find_map(|variant| self.variants.get(&variant.name).cloned())
+    }
+
+    pub fn attach(&mut self, reference_id: &str) {
+        let now = chrono::Utc::now().timestamp();
+        self.reference_id = Some(reference_id.to_string());
+        self.detached_at = None;
+        self.base.updated_at = now;
+    }
+
+    pub fn detach(&mut self) {
+        let now = chrono::Utc::now().timestamp();
+        self.reference_id = None;
+        self.detached_at = Some(now);
+        self.base.updated_at = now;
+    }
+}
@@ -1,13 +1,27 @@
 pub mod account_entity;
+pub mod audit_event_entity;
 pub mod base_entity;
 pub mod blog_entity;
+pub mod blog_revision_entity;
+pub mod blog_star_entity;
+pub mod bookmark_entity;
 pub mod category_entity;
+pub mod chapter_entity;
+pub mod comment_ban_entity;
+pub mod comment_entity;
 pub mod course_entity;
+pub mod data_export_entity;
 pub mod enrollment_entity;
 pub mod experience_entity;
 pub mod lesson_entity;
+pub mod media_asset_entity;
 pub mod notification_entity;
+pub mod notification_preference_entity;
+pub mod progress_entity;
 pub mod project_entity;
 pub mod provider_entity;
+pub mod review_entity;
 pub mod role_entity;
 pub mod session_entity;
+pub mod topic_subscription_entity;
+pub mod upload_entity;
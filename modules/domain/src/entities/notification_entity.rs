@@ -1,20 +1,79 @@
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 // internal modules
 use crate::entities::base_entity::BaseEntity;
+use crate::values::notification_kind::NotificationKind;
 
-#[derive(Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct NotificationEntity {
     #[serde(flatten)]
     pub base: BaseEntity,
     pub account_id: String,
+    pub kind: String,
     pub message: String,
+    /// Ids of the resources the notification is about, keyed by name, so clients can deep-link.
+    pub payload: HashMap<String, String>,
     pub is_read: bool,
+    pub read_at: Option<i64>,
 }
 
 impl NotificationEntity {
-    pub fn new(include_id: bool, account_id: String, message: String, is_read: bool) -> Self {
-        Self { base: BaseEntity::new(include_id), account_id, message, is_read }
+    pub fn new(
+        include_id: bool,
+        account_id: &str,
+        kind: NotificationKind,
+        message: &str,
+        payload: HashMap<String, String>,
+    ) -> Self {
+        Self {
+            base: BaseEntity::new(include_id),
+            account_id: account_id.to_string(),
+            kind: kind.as_str().to_string(),
+            message: message.to_string(),
+            payload,
+            is_read: false,
+            read_at: None,
+        }
+    }
+
+    pub fn blog_comment(account_id: &str, blog_id: &str, comment_id: &str, blog_name: &str) -> Self {
+        let payload = HashMap::from([
+            ("blogId".to_string(), blog_id.to_string()),
+            ("commentId".to_string(), comment_id.to_string()),
+        ]);
+        let message = format!("Your blog \"{}\" has a new comment", blog_name);
+        Self::new(true, account_id, NotificationKind::BlogComment, &message, payload)
+    }
+
+    pub fn comment_reply(account_id: &str, blog_id: &str, comment_id: &str, parent_id: &str, blog_name: &str) -> Self {
+        let payload = HashMap::from([
+            ("blogId".to_string(), blog_id.to_string()),
+            ("commentId".to_string(), comment_id.to_string()),
+            ("parentId".to_string(), parent_id.to_string()),
+        ]);
+        let message = format!("Someone replied to your comment on \"{}\"", blog_name);
+        Self::new(true, account_id, NotificationKind::CommentReply, &message, payload)
+    }
+
+    pub fn course_review(account_id: &str, course_id: &str, review_id: &str, course_name: &str, rating: i32) -> Self {
+        let payload = HashMap::from([
+            ("courseId".to_string(), course_id.to_string()),
+            ("reviewId".to_string(), review_id.to_string()),
+        ]);
+        let message = format!("Your course \"{}\" received a {} star review", course_name, rating);
+        Self::new(true, account_id, NotificationKind::CourseReview, &message, payload)
+    }
+
+    pub fn system(account_id: &str, message: &str) -> Self {
+        Self::new(true, account_id, NotificationKind::System, message, HashMap::new())
+    }
+
+    pub fn mark_read(&mut self) {
+        let now = chrono::Utc::now().timestamp();
+        self.is_read = true;
+        self.read_at.get_or_insert(now);
+        self.base.updated_at = now;
     }
 }
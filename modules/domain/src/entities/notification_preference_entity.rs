@@ -0,0 +1,120 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+// internal modules
+use crate::entities::base_entity::BaseEntity;
+use crate::values::{
+    digest_frequency::DigestFrequency, notification_channel::NotificationChannel, notification_kind::NotificationKind,
+};
+
+static MINUTES_PER_DAY: i64 = 24 * 60;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationPreferenceEntity {
+    #[serde(flatten)]
+    pub base: BaseEntity,
+    pub account_id: String,
+    /// Channels switched off, keyed by notification kind. Kinds without an entry use every channel.
+    pub muted_channels: HashMap<String, Vec<String>>,
+    /// Minute of the local day pushes and digests are held back from, wrapping past midnight when after the end.
+    pub quiet_hours_start: Option<i32>,
+    pub quiet_hours_end: Option<i32>,
+    pub utc_offset_minutes: i32,
+    pub digest_frequency: String,
+    pub last_digest_at: Option<i64>,
+    /// `None` while digests are off.
+    pub next_digest_at: Option<i64>,
+}
+
+impl NotificationPreferenceEntity {
+    /// Every channel on, no quiet hours and no digest, what accounts get until they change anything.
+    pub fn new(include_id: bool, account_id: &str) -> Self {
+        Self {
+            base: BaseEntity::new(include_id),
+            account_id: account_id.to_string(),
+            muted_channels: HashMap::new(),
+            quiet_hours_start: None,
+            quiet_hours_end: None,
+            utc_offset_minutes: 0,
+            digest_frequency: DigestFrequency::Off.as_str().to_string(),
+            last_digest_at: None,
+            next_digest_at: None,
+        }
+    }
+
+    pub fn allows(&self, kind: &str, channel: NotificationChannel) -> bool {
+        self.muted_channels.get(kind).is_none_or(|muted| !muted.iter().any(|muted| muted == channel.as_str()))
+    }
+
+    /// The kinds muted on `channel`.
+    pub fn muted_kinds(&self, channel: NotificationChannel) -> Vec<String> {
+        let muted = self.muted_channels.iter().filter(|(_, muted)| muted.iter().any(|muted| muted == channel.as_str()));
+        muted.map(|(kind, _)| kind.clone()).collect()
+    }
+
+    /// Whether a notification of `kind` may go out on `channel` at `timestamp`. Quiet hours only hold back
+    /// the channels that interrupt, in-app notifications are still stored.
+    pub fn delivers(&self, kind: &str, channel: NotificationChannel, timestamp: i64) -> bool {
+        self.allows(kind, channel) && (channel == NotificationChannel::InApp || !self.is_quiet_at(timestamp))
+    }
+
+    pub fn set_channel(&mut self, kind: NotificationKind, channel: NotificationChannel, enabled: bool) {
+        let muted = self.muted_channels.entry(kind.as_str().to_string()).or_default();
+        muted.retain(|muted| muted != channel.as_str());
+        if !enabled {
+            muted.push(channel.as_str().to_string());
+        }
+
+        if muted.is_empty() {
+            self.muted_channels.remove(kind.as_str());
+        }
+    }
+
+    pub fn is_quiet_at(&self, timestamp: i64) -> bool {
+        let (Some(start), Some(end)) = (self.quiet_hours_start, self.quiet_hours_end) else {
+            return false;
+        };
+
+        let minute = (timestamp / 60 + self.utc_offset_minutes as i64).rem_euclid(MINUTES_PER_DAY) as i32;
+        match start.cmp(&end) {
+            std::cmp::Ordering::Less => minute >= start && minute < end,
+            std::cmp::Ordering::Greater => minute >= start || minute < end,
+            std::cmp::Ordering::Equal => false,
+        }
+    }
+
+    /// When the quiet hours around `timestamp` end, `timestamp` itself outside of them.
+    pub fn quiet_hours_end_after(&self, timestamp: i64) -> i64 {
+        let Some(end) = self.quiet_hours_end.filter(|_| self.is_quiet_at(timestamp)) else {
+            return timestamp;
+        };
+
+        let minute = (timestamp / 60 + self.utc_offset_minutes as i64).rem_euclid(MINUTES_PER_DAY);
+        let minutes_left = (end as i64 - minute).rem_euclid(MINUTES_PER_DAY);
+        (timestamp / 60 + minutes_left) * 60
+    }
+
+    pub fn frequency(&self) -> DigestFrequency {
+        DigestFrequency::from_string(&self.digest_frequency).unwrap_or(DigestFrequency::Off)
+    }
+
+    /// Switch the digest frequency, the first digest of a new frequency goes out one period from now.
+    pub fn set_digest_frequency(&mut self, frequency: DigestFrequency, now: i64) {
+        if self.frequency() != frequency {
+            self.next_digest_at = frequency.period_seconds().map(|period| now + period);
+        }
+        self.digest_frequency = frequency.as_str().to_string();
+    }
+
+    /// Unread notifications created after this belong in the next digest.
+    pub fn digest_since(&self, now: i64) -> i64 {
+        self.last_digest_at.unwrap_or(now - self.frequency().period_seconds().unwrap_or_default())
+    }
+
+    pub fn mark_digest_sent(&mut self, now: i64) {
+        self.last_digest_at = Some(now);
+        self.next_digest_at = self.frequency().period_seconds().map(|period| now + period);
+        self.base.updated_at = now;
+    }
+}
@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+
+// internal modules
+use crate::entities::base_entity::BaseEntity;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProgressEntity {
+    #[serde(flatten)]
+    pub base: BaseEntity,
+    pub enrollment_id: String,
+    pub lesson_id: String,
+    pub is_completed: bool,
+    pub completed_at: Option<i64>,
+    pub time_spend_second: i32,
+    pub visits: i32,
+}
+
+impl ProgressEntity {
+    pub fn new(include_id: bool, enrollment_id: &str, lesson_id: &str) -> Self {
+        Self {
+            base: BaseEntity::new(include_id),
+            enrollment_id: enrollment_id.to_string(),
+            lesson_id: lesson_id.to_string(),
+            is_completed: false,
+            completed_at: None,
+            time_spend_second: 0,
+            visits: 0,
+        }
+    }
+
+    /// Count a visit of the lesson, completion is kept once reached.
+    pub fn record_visit(&mut self, time_spend_second: i32, completed: bool) {
+        let now = chrono::Utc::now().timestamp();
+        self.visits += 1;
+        self.time_spend_second = self.time_spend_second.saturating_add(time_spend_second);
+
+        if completed && !self.is_completed {
+            self.is_completed = true;
+            self.completed_at = Some(now);
+        }
+
+        self.base.updated_at = now;
+    }
+}
@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+
+// shared modules
+use shared::{models::failure::Failure, types::DomainResponse};
+
+// internal modules
+use crate::entities::base_entity::BaseEntity;
+use crate::values::review_status::ReviewStatus;
+
+static MIN_REVIEW_RATING: i32 = 1;
+static MAX_REVIEW_RATING: i32 = 5;
+static MAX_REVIEW_CONTENT_SIZE: usize = 5 * 1024; // 5 KB
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReviewEntity {
+    #[serde(flatten)]
+    pub base: BaseEntity,
+    pub course_id: String,
+    pub account_id: String,
+    pub rating: i32,
+    pub content: String,
+    pub status: String,
+}
+
+impl ReviewEntity {
+    pub fn new(include_id: bool, course_id: &str, account_id: &str, rating: i32, content: &str) -> Self {
+        Self {
+            base: BaseEntity::new(include_id),
+            course_id: course_id.to_string(),
+            account_id: account_id.to_string(),
+            rating,
+            content: content.to_string(),
+            status: ReviewStatus::Approved.as_str().to_string(),
+        }
+    }
+
+    pub fn validate(&self) -> DomainResponse<()> {
+        if !(MIN_REVIEW_RATING..=MAX_REVIEW_RATING).contains(&self.rating) {
+            return Err(Failure::ValidationError(format!(
+                "Review rating must be between {} and {}",
+                MIN_REVIEW_RATING, MAX_REVIEW_RATING
+            )));
+        }
+
+        if self.content.len() > MAX_REVIEW_CONTENT_SIZE {
+            return Err(Failure::ValidationError(format!(
+                "Review content size exceeds the maximum limit of {} bytes",
+                MAX_REVIEW_CONTENT_SIZE
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Only approved reviews count towards the course rating.
+    pub fn is_approved(&self) -> bool {
+        self.status == ReviewStatus::Approved.as_str()
+    }
+}
@@ -14,6 +14,9 @@ pub struct SessionEntity {
     pub ip_address: String,
     pub user_agent: String,
     pub device_type: String,
+    /// Push token of the device signed in with this session, never sent back to clients.
+    #[serde(skip_serializing, default)]
+    pub device_token: Option<String>,
 }
 
 impl SessionEntity {
@@ -26,6 +29,15 @@ impl SessionEntity {
         user_agent: String,
         device_type: String,
     ) -> Self {
-        Self { base: BaseEntity::new(include_id), account_id, jti, expires_at, ip_address, user_agent, device_type }
+        Self {
+            base: BaseEntity::new(include_id),
+            account_id,
+            jti,
+            expires_at,
+            ip_address,
+            user_agent,
+            device_type,
+            device_token: None,
+        }
     }
 }
@@ -0,0 +1,37 @@
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+// shared modules
+use shared::models::failure::Failure;
+
+// internal modules
+use crate::entities::base_entity::BaseEntity;
+
+// Topic names accepted by FCM
+const TOPIC_REGEX: &str = r"^[a-zA-Z0-9\-_.~%]{1,900}$";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TopicSubscriptionEntity {
+    #[serde(flatten)]
+    pub base: BaseEntity,
+    pub account_id: String,
+    pub topic: String,
+}
+
+impl TopicSubscriptionEntity {
+    pub fn new(include_id: bool, account_id: &str, topic: &str) -> Self {
+        Self { base: BaseEntity::new(include_id), account_id: account_id.to_string(), topic: topic.to_string() }
+    }
+
+    pub fn validate_topic(topic: &str) -> Result<(), Failure> {
+        let regex = Regex::new(TOPIC_REGEX)
+            .map_err(|e| Failure::ValidationError(format!("Failed to compile topic regex: {}", e)))?;
+
+        if !regex.is_match(topic) {
+            return Err(Failure::ValidationError(format!("Invalid topic name: {}", topic)));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,82 @@
+use serde::{Deserialize, Serialize};
+
+// internal modules
+use crate::entities::base_entity::BaseEntity;
+use crate::values::{upload_purpose::UploadPurpose, upload_status::UploadStatus};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UploadEntity {
+    #[serde(flatten)]
+    pub base: BaseEntity,
+    /// Account that requested the upload.
+    pub account_id: String,
+    pub purpose: String,
+    /// Id of the blog or course the file belongs to, depending on the purpose.
+    pub reference_id: String,
+    pub file_name: String,
+    pub content_type: String,
+    /// Declared size in bytes, the stored file has to match it.
+    pub size: i64,
+    /// Declared MD5 of the content as hex, the stored file has to match it.
+    pub checksum: String,
+    #[serde(skip_serializing)]
+    pub storage_key: String,
+    pub status: String,
+    /// Public URL of the file, once completed.
+    pub url: Option<String>,
+    /// When the presigned URL stops being accepted.
+    pub expires_at: i64,
+    pub completed_at: Option<i64>,
+}
+
+impl UploadEntity {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        include_id: bool,
+        account_id: &str,
+        purpose: UploadPurpose,
+        reference_id: &str,
+        file_name: &str,
+        content_type: &str,
+        size: i64,
+        checksum: &str,
+        expires_in: u32,
+    ) -> Self {
+        let base = BaseEntity::new(include_id);
+        let expires_at = base.created_at + expires_in as i64;
+
+        Self {
+            base,
+            account_id: account_id.to_string(),
+            purpose: purpose.as_str().to_string(),
+            reference_id: reference_id.to_string(),
+            file_name: file_name.to_string(),
+            content_type: content_type.to_string(),
+            size,
+            checksum: checksum.to_lowercase(),
+            storage_key: String::new(),
+            status: UploadStatus::Pending.as_str().to_string(),
+            url: None,
+            expires_at,
+            completed_at: None,
+        }
+    }
+
+    pub fn is_pending(&self) -> bool {
+        self.status == UploadStatus::Pending.as_str()
+    }
+
+    /// Whether `etag` proves the stored content is the declared one. Multipart ETags are not an MD5.
+    pub fn matches_checksum(&self, etag: &str) -> bool {
+        etag.trim_matches('"').eq_ignore_ascii_case(&self.checksum)
+    }
+
+    pub fn complete(&mut self, url: &str) {
+        let now = chrono::Utc::now().timestamp();
+        self.status = UploadStatus::Completed.as_str().to_string();
+        self.url = Some(url.to_string());
+        self.completed_at = Some(now);
+        self.base.updated_at = now;
+    }
+}
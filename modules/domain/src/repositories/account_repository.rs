@@ -1,12 +1,44 @@
 use async_trait::async_trait;
 
 // shared modules
+use shared::models::filters::QuerySpec;
+use shared::models::paginate::Paginate;
 use shared::types::DomainResponse;
 
 // internal modules
+use crate::values::account_status::AccountStatus;
 use crate::{entities::account_entity::AccountEntity, repositories::base_repository::BaseRepository};
 
+/// Criteria of an account search, every criterion set has to match.
+#[derive(Debug, Clone, Default)]
+pub struct AccountSearch {
+    /// Part of the email, case-insensitive.
+    pub email: Option<String>,
+    /// Part of the username, case-insensitive.
+    pub username: Option<String>,
+    pub email_verified: Option<bool>,
+    pub status: Option<AccountStatus>,
+    /// Only these accounts, resolved beforehand from criteria stored elsewhere such as the role.
+    pub account_ids: Option<Vec<String>>,
+}
+
 #[async_trait]
 pub trait AccountRepository: BaseRepository<AccountEntity> {
     async fn find_by_email(&self, email: &str) -> DomainResponse<Option<AccountEntity>>;
+
+    /// Usernames are matched case-insensitively.
+    async fn find_by_username(&self, username: &str) -> DomainResponse<Option<AccountEntity>>;
+
+    /// Accounts whose scheduled deletion is due at `now`, oldest due first.
+    async fn find_deletion_due(&self, now: i64, limit: u32) -> DomainResponse<Vec<AccountEntity>>;
+
+    /// Accounts matching both the search as of `now` and the spec, newest first unless the spec sorts.
+    async fn search(
+        &self,
+        search: &AccountSearch,
+        spec: &QuerySpec,
+        now: i64,
+        page: u32,
+        page_size: u32,
+    ) -> DomainResponse<(Paginate, Vec<AccountEntity>)>;
 }
@@ -0,0 +1,38 @@
+use async_trait::async_trait;
+
+// shared modules
+use shared::models::paginate::Paginate;
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::entities::audit_event_entity::AuditEventEntity;
+
+/// Criteria of an audit event search, every criterion set has to match.
+#[derive(Debug, Clone)]
+pub struct AuditEventFilter {
+    pub actor_id: Option<String>,
+    pub action: Option<String>,
+    pub target_type: Option<String>,
+    pub target_id: Option<String>,
+    /// Events recorded at or after, events are stored by day so the range is always bounded.
+    pub from: i64,
+    /// Events recorded at or before.
+    pub to: i64,
+}
+
+/// The audit log is append-only, events are never updated or removed once recorded.
+#[async_trait]
+pub trait AuditEventRepository: Send + Sync {
+    async fn create(&self, event: &AuditEventEntity) -> DomainResponse<AuditEventEntity>;
+
+    /// Events matching the filter, newest first.
+    async fn find_filtered_paginated(
+        &self,
+        filter: &AuditEventFilter,
+        page: u32,
+        page_size: u32,
+    ) -> DomainResponse<(Paginate, Vec<AuditEventEntity>)>;
+
+    /// At most `limit` events matching the filter, newest first.
+    async fn find_filtered(&self, filter: &AuditEventFilter, limit: u32) -> DomainResponse<Vec<AuditEventEntity>>;
+}
@@ -1,6 +1,7 @@
 use async_trait::async_trait;
 
 // shared modules
+use shared::models::filters::QuerySpec;
 use shared::models::paginate::Paginate;
 use shared::types::DomainResponse;
 
@@ -15,4 +16,8 @@ pub trait BaseRepository<E>: Send + Sync {
     async fn find_and_remove(&self, id: &str) -> DomainResponse<E>;
     async fn finds(&self) -> DomainResponse<Vec<E>>;
     async fn finds_paginated(&self, page: u32, page_size: u32) -> DomainResponse<(Paginate, Vec<E>)>;
+
+    /// Entities matching the spec, in its order or newest first when it does not sort. The spec is compiled for
+    /// the database behind the repository, which rejects what it can not answer efficiently.
+    async fn find_by(&self, spec: &QuerySpec, page: u32, page_size: u32) -> DomainResponse<(Paginate, Vec<E>)>;
 }
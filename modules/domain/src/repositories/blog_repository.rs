@@ -1,8 +1,56 @@
 use async_trait::async_trait;
+use std::collections::HashMap;
+
+// shared modules
+use shared::models::filters::QuerySpec;
+use shared::models::paginate::Paginate;
+use shared::types::DomainResponse;
 
 // internal modules
 use crate::entities::blog_entity::BlogEntity;
 use crate::repositories::base_repository::BaseRepository;
 
 #[async_trait]
-pub trait BlogRepository: BaseRepository<BlogEntity> {}
+pub trait BlogRepository: BaseRepository<BlogEntity> {
+    /// Published blogs of every author or only `author_id` matching the spec, newest first unless it sorts.
+    async fn find_published_paginated(
+        &self,
+        author_id: Option<&str>,
+        spec: &QuerySpec,
+        page: u32,
+        page_size: u32,
+    ) -> DomainResponse<(Paginate, Vec<BlogEntity>)>;
+    async fn find_latest_published(
+        &self,
+        category_id: Option<&str>,
+        author_id: Option<&str>,
+        limit: u32,
+    ) -> DomainResponse<Vec<BlogEntity>>;
+    /// `(id, updated_at)` of every published blog, without loading the content.
+    async fn find_published_modification_times(&self) -> DomainResponse<Vec<(String, i64)>>;
+    async fn find_scheduled_before(&self, timestamp: i64) -> DomainResponse<Vec<BlogEntity>>;
+
+    /// Write the published blog only while the stored one is still scheduled, `None` when another run published,
+    /// unscheduled or removed it first.
+    async fn publish_scheduled(&self, blog: &BlogEntity) -> DomainResponse<Option<BlogEntity>>;
+    async fn increment_counters(&self, blog_id: &str, stars: i32, views: i32) -> DomainResponse<()>;
+    async fn count_by_categories(&self, category_ids: &[String]) -> DomainResponse<u64>;
+    /// Blogs with no category outside `category_ids`, the ones dropping them would leave uncategorised.
+    async fn count_only_in_categories(&self, category_ids: &[String]) -> DomainResponse<u64>;
+    async fn count_published_by_category(&self) -> DomainResponse<HashMap<String, u32>>;
+
+    /// Replace `from` categories with `to` on every blog, or only drop them when `to` is `None`.
+    async fn replace_categories(&self, from: &[String], to: Option<&str>) -> DomainResponse<usize>;
+
+    /// Drop `from` categories from every blog, the blogs left without a category are filed under `fallback`.
+    async fn drop_categories(&self, from: &[String], fallback: &str) -> DomainResponse<usize>;
+
+    /// Every blog of an author whatever its status, newest first.
+    async fn find_by_author(&self, author_id: &str) -> DomainResponse<Vec<BlogEntity>>;
+
+    /// Hand every blog of `from` over to `to`, returns how many changed.
+    async fn replace_author(&self, from: &str, to: &str) -> DomainResponse<usize>;
+
+    /// Hard remove every blog of an author, soft deleted ones included, and return them.
+    async fn find_and_remove_by_author(&self, author_id: &str) -> DomainResponse<Vec<BlogEntity>>;
+}
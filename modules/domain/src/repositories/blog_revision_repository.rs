@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+
+// shared modules
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::entities::blog_revision_entity::BlogRevisionEntity;
+use crate::repositories::base_repository::BaseRepository;
+
+#[async_trait]
+pub trait BlogRevisionRepository: BaseRepository<BlogRevisionEntity> {
+    async fn find_by_blog_id(&self, blog_id: &str) -> DomainResponse<Vec<BlogRevisionEntity>>;
+    async fn find_by_version(&self, blog_id: &str, version: i32) -> DomainResponse<Option<BlogRevisionEntity>>;
+    async fn find_latest_by_blog_id(&self, blog_id: &str) -> DomainResponse<Option<BlogRevisionEntity>>;
+    async fn remove_older_than_version(&self, blog_id: &str, version: i32) -> DomainResponse<usize>;
+    async fn remove_by_blog_id(&self, blog_id: &str) -> DomainResponse<usize>;
+}
@@ -0,0 +1,22 @@
+use async_trait::async_trait;
+
+// shared modules
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::entities::blog_star_entity::BlogStarEntity;
+use crate::repositories::base_repository::BaseRepository;
+
+#[async_trait]
+pub trait BlogStarRepository: BaseRepository<BlogStarEntity> {
+    async fn find_by_blog_id_and_account_id(
+        &self,
+        blog_id: &str,
+        account_id: &str,
+    ) -> DomainResponse<Option<BlogStarEntity>>;
+
+    async fn find_by_account_id(&self, account_id: &str) -> DomainResponse<Vec<BlogStarEntity>>;
+
+    /// Stars the blog unless the account already did, returns whether a star was added.
+    async fn create_if_absent(&self, star: &BlogStarEntity) -> DomainResponse<bool>;
+}
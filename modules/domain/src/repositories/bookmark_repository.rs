@@ -0,0 +1,35 @@
+use async_trait::async_trait;
+
+// shared modules
+use shared::models::paginate::Paginate;
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::entities::bookmark_entity::BookmarkEntity;
+use crate::repositories::base_repository::BaseRepository;
+
+#[async_trait]
+pub trait BookmarkRepository: BaseRepository<BookmarkEntity> {
+    /// Store the bookmark unless the account already bookmarked the target, returns the stored bookmark.
+    async fn upsert(&self, bookmark: &BookmarkEntity) -> DomainResponse<BookmarkEntity>;
+
+    async fn remove_by_target(&self, account_id: &str, target_type: &str, target_id: &str) -> DomainResponse<usize>;
+    async fn remove_by_account_id(&self, account_id: &str) -> DomainResponse<usize>;
+
+    /// Bookmarks of an account, newest first.
+    async fn find_by_account_id(
+        &self,
+        account_id: &str,
+        target_type: Option<&str>,
+        page: u32,
+        page_size: u32,
+    ) -> DomainResponse<(Paginate, Vec<BookmarkEntity>)>;
+
+    /// The subset of `target_ids` the account bookmarked.
+    async fn find_bookmarked_ids(
+        &self,
+        account_id: &str,
+        target_type: &str,
+        target_ids: &[String],
+    ) -> DomainResponse<Vec<String>>;
+}
@@ -9,4 +9,6 @@ use crate::{entities::category_entity::CategoryEntity, repositories::base_reposi
 #[async_trait]
 pub trait CategoryRepository: BaseRepository<CategoryEntity> {
     async fn find_by_name(&self, name: &str) -> DomainResponse<Option<CategoryEntity>>;
+    async fn find_by_ids(&self, ids: &[String]) -> DomainResponse<Vec<CategoryEntity>>;
+    async fn find_by_path_prefix(&self, path_prefix: &str) -> DomainResponse<Vec<CategoryEntity>>;
 }
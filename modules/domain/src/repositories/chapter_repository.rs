@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+
+// shared modules
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::entities::chapter_entity::ChapterEntity;
+use crate::repositories::base_repository::BaseRepository;
+
+#[async_trait]
+pub trait ChapterRepository: BaseRepository<ChapterEntity> {
+    /// Chapters of a course ordered by `order_index`.
+    async fn find_by_course_id(&self, course_id: &str) -> DomainResponse<Vec<ChapterEntity>>;
+
+    /// Hard remove every chapter of a course, soft deleted ones included, and return them.
+    async fn find_and_remove_by_course_id(&self, course_id: &str) -> DomainResponse<Vec<ChapterEntity>>;
+}
@@ -0,0 +1,13 @@
+use async_trait::async_trait;
+
+// shared modules
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::entities::comment_ban_entity::CommentBanEntity;
+use crate::repositories::base_repository::BaseRepository;
+
+#[async_trait]
+pub trait CommentBanRepository: BaseRepository<CommentBanEntity> {
+    async fn find_by_account_id(&self, account_id: &str) -> DomainResponse<Option<CommentBanEntity>>;
+}
@@ -0,0 +1,26 @@
+use async_trait::async_trait;
+
+// shared modules
+use shared::models::paginate::Paginate;
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::entities::comment_entity::CommentEntity;
+use crate::repositories::base_repository::BaseRepository;
+
+#[async_trait]
+pub trait CommentRepository: BaseRepository<CommentEntity> {
+    async fn find_by_blog_id_paginated(
+        &self,
+        blog_id: &str,
+        status: &str,
+        page: u32,
+        page_size: u32,
+    ) -> DomainResponse<(Paginate, Vec<CommentEntity>)>;
+
+    async fn update_status_by_author_id(&self, author_id: &str, status: &str) -> DomainResponse<usize>;
+
+    /// Comments written by an account whatever their status, newest first.
+    async fn find_by_author_id(&self, author_id: &str) -> DomainResponse<Vec<CommentEntity>>;
+    async fn remove_by_author_id(&self, author_id: &str) -> DomainResponse<usize>;
+}
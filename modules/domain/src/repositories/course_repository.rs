@@ -0,0 +1,41 @@
+use async_trait::async_trait;
+
+// shared modules
+use shared::models::filters::QuerySpec;
+use shared::models::paginate::Paginate;
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::entities::course_entity::CourseEntity;
+use crate::repositories::base_repository::BaseRepository;
+
+#[async_trait]
+pub trait CourseRepository: BaseRepository<CourseEntity> {
+    /// Published courses matching the spec, newest first unless it sorts, optionally narrowed to some categories
+    /// and a level.
+    async fn find_published_paginated(
+        &self,
+        category_ids: Option<&[String]>,
+        level: Option<&str>,
+        spec: &QuerySpec,
+        page: u32,
+        page_size: u32,
+    ) -> DomainResponse<(Paginate, Vec<CourseEntity>)>;
+
+    async fn find_by_author_id(&self, author_id: &str) -> DomainResponse<Vec<CourseEntity>>;
+    async fn replace_author(&self, from: &str, to: &str) -> DomainResponse<usize>;
+
+    /// Hard remove every course of an author, soft deleted ones included, and return them.
+    async fn find_and_remove_by_author_id(&self, author_id: &str) -> DomainResponse<Vec<CourseEntity>>;
+
+    /// Write the editable fields of a course, the rating, review count and enrollments are left as stored
+    /// since they only move through `increment_enrollments` and `apply_review`.
+    async fn update_details(&self, course: &CourseEntity) -> DomainResponse<CourseEntity>;
+
+    /// Atomically add `delta` to the enrollment counter of a course.
+    async fn increment_enrollments(&self, course_id: &str, delta: i32) -> DomainResponse<()>;
+
+    /// Atomically fold a review change into the average rating, `rating_delta` is the change of the
+    /// rating sum and `count_delta` the change of the review count.
+    async fn apply_review(&self, course_id: &str, rating_delta: i32, count_delta: i32) -> DomainResponse<()>;
+}
@@ -0,0 +1,20 @@
+use async_trait::async_trait;
+
+// shared modules
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::entities::data_export_entity::DataExportEntity;
+use crate::repositories::base_repository::BaseRepository;
+
+#[async_trait]
+pub trait DataExportRepository: BaseRepository<DataExportEntity> {
+    /// Exports of an account, newest first.
+    async fn find_by_account_id(&self, account_id: &str) -> DomainResponse<Vec<DataExportEntity>>;
+
+    /// Exports waiting for the export job, oldest first.
+    async fn find_pending(&self, limit: u32) -> DomainResponse<Vec<DataExportEntity>>;
+
+    /// Ready exports whose archive expired before `expires_before`, oldest first.
+    async fn find_expired(&self, expires_before: i64, limit: u32) -> DomainResponse<Vec<DataExportEntity>>;
+}
@@ -0,0 +1,27 @@
+use async_trait::async_trait;
+
+// shared modules
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::entities::enrollment_entity::EnrollmentEntity;
+use crate::repositories::base_repository::BaseRepository;
+
+#[async_trait]
+pub trait EnrollmentRepository: BaseRepository<EnrollmentEntity> {
+    async fn find_by_account_and_course(
+        &self,
+        account_id: &str,
+        course_id: &str,
+    ) -> DomainResponse<Option<EnrollmentEntity>>;
+
+    /// Creates the enrollment unless the account is already enrolled in the course, which storage enforces so
+    /// concurrent requests can not both get through. Returns `None` when an enrollment was already there.
+    async fn create_if_absent(&self, enrollment: &EnrollmentEntity) -> DomainResponse<Option<EnrollmentEntity>>;
+
+    /// Enrollments of an account, most recently active first.
+    async fn find_by_account_id(&self, account_id: &str) -> DomainResponse<Vec<EnrollmentEntity>>;
+
+    async fn find_by_course_id(&self, course_id: &str) -> DomainResponse<Vec<EnrollmentEntity>>;
+    async fn remove_by_account_id(&self, account_id: &str) -> DomainResponse<usize>;
+}
@@ -8,6 +8,7 @@ use crate::entities::experience_entity::ExperienceEntity;
 use crate::repositories::base_repository::BaseRepository;
 
 #[async_trait]
+#[allow(clippy::too_many_arguments)]
 pub trait ExperienceRepository: BaseRepository<ExperienceEntity> {
     async fn find_by_account_id(&self, account_id: &str) -> DomainResponse<Vec<ExperienceEntity>>;
     async fn find_by_company(&self, company: &str) -> DomainResponse<Option<ExperienceEntity>>;
@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+
+// shared modules
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::entities::lesson_entity::LessonEntity;
+use crate::repositories::base_repository::BaseRepository;
+
+#[async_trait]
+pub trait LessonRepository: BaseRepository<LessonEntity> {
+    /// Lessons of the given chapters ordered by `order_index`.
+    async fn find_by_chapter_ids(&self, chapter_ids: &[String]) -> DomainResponse<Vec<LessonEntity>>;
+
+    /// Hard remove every lesson of a chapter, soft deleted ones included.
+    async fn remove_by_chapter_id(&self, chapter_id: &str) -> DomainResponse<usize>;
+}
@@ -0,0 +1,20 @@
+use async_trait::async_trait;
+
+// shared modules
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::entities::media_asset_entity::MediaAssetEntity;
+use crate::repositories::base_repository::BaseRepository;
+
+#[async_trait]
+pub trait MediaAssetRepository: BaseRepository<MediaAssetEntity> {
+    /// Assets of a purpose currently referenced by the entity.
+    async fn find_by_reference(&self, purpose: &str, reference_id: &str) -> DomainResponse<Vec<MediaAssetEntity>>;
+
+    /// Assets uploaded by an account, attached or not.
+    async fn find_by_account_id(&self, account_id: &str) -> DomainResponse<Vec<MediaAssetEntity>>;
+
+    /// Assets nothing referenced since before `detached_before`, oldest first.
+    async fn find_orphans(&self, detached_before: i64, limit: u32) -> DomainResponse<Vec<MediaAssetEntity>>;
+}
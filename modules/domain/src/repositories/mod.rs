@@ -1,10 +1,27 @@
 pub mod account_repository;
+pub mod audit_event_repository;
 pub mod base_repository;
 pub mod blog_repository;
+pub mod blog_revision_repository;
+pub mod blog_star_repository;
+pub mod bookmark_repository;
 pub mod category_repository;
+pub mod chapter_repository;
+pub mod comment_ban_repository;
+pub mod comment_repository;
+pub mod course_repository;
+pub mod data_export_repository;
+pub mod enrollment_repository;
 pub mod experience_repository;
+pub mod lesson_repository;
+pub mod media_asset_repository;
+pub mod notification_preference_repository;
 pub mod notification_repository;
+pub mod progress_repository;
 pub mod project_repository;
 pub mod provider_repository;
+pub mod review_repository;
 pub mod role_repository;
 pub mod session_repository;
+pub mod topic_subscription_repository;
+pub mod upload_repository;
@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+
+// shared modules
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::entities::notification_preference_entity::NotificationPreferenceEntity;
+use crate::repositories::base_repository::BaseRepository;
+
+#[async_trait]
+pub trait NotificationPreferenceRepository: BaseRepository<NotificationPreferenceEntity> {
+    async fn find_by_account_id(&self, account_id: &str) -> DomainResponse<Option<NotificationPreferenceEntity>>;
+
+    /// Preferences whose next digest is due at `now`, oldest due first.
+    async fn find_digest_due(&self, now: i64, limit: u32) -> DomainResponse<Vec<NotificationPreferenceEntity>>;
+
+    /// Hard delete everything of an account, returns how many were removed.
+    async fn remove_by_account_id(&self, account_id: &str) -> DomainResponse<usize>;
+}
@@ -1,8 +1,38 @@
 use async_trait::async_trait;
 
+// shared modules
+use shared::models::paginate::Paginate;
+use shared::types::DomainResponse;
+
 // internal modules
 use crate::entities::notification_entity::NotificationEntity;
 use crate::repositories::base_repository::BaseRepository;
 
 #[async_trait]
-pub trait NotificationRepository: BaseRepository<NotificationEntity> {}
+pub trait NotificationRepository: BaseRepository<NotificationEntity> {
+    /// Notifications of an account, newest first, leaving out the `muted_kinds`.
+    async fn find_by_account_id_paginated(
+        &self,
+        account_id: &str,
+        unread_only: bool,
+        muted_kinds: &[String],
+        page: u32,
+        page_size: u32,
+    ) -> DomainResponse<(Paginate, Vec<NotificationEntity>)>;
+
+    /// Unread notifications of an account created after `since`, newest first.
+    async fn find_unread_since(
+        &self,
+        account_id: &str,
+        since: i64,
+        limit: u32,
+    ) -> DomainResponse<Vec<NotificationEntity>>;
+
+    async fn count_unread(&self, account_id: &str, muted_kinds: &[String]) -> DomainResponse<u64>;
+
+    /// Mark every unread notification of an account but the `muted_kinds` as read, returns how many changed.
+    async fn mark_all_read(&self, account_id: &str, muted_kinds: &[String], read_at: i64) -> DomainResponse<usize>;
+
+    /// Hard delete everything of an account, returns how many were removed.
+    async fn remove_by_account_id(&self, account_id: &str) -> DomainResponse<usize>;
+}
@@ -0,0 +1,20 @@
+use async_trait::async_trait;
+
+// shared modules
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::entities::progress_entity::ProgressEntity;
+use crate::repositories::base_repository::BaseRepository;
+
+#[async_trait]
+pub trait ProgressRepository: BaseRepository<ProgressEntity> {
+    async fn find_by_enrollment_and_lesson(
+        &self,
+        enrollment_id: &str,
+        lesson_id: &str,
+    ) -> DomainResponse<Option<ProgressEntity>>;
+
+    async fn find_by_enrollment_ids(&self, enrollment_ids: &[String]) -> DomainResponse<Vec<ProgressEntity>>;
+    async fn remove_by_enrollment_ids(&self, enrollment_ids: &[String]) -> DomainResponse<usize>;
+}
@@ -7,6 +7,7 @@ use shared::types::DomainResponse;
 use crate::{entities::project_entity::ProjectEntity, repositories::base_repository::BaseRepository};
 
 #[async_trait]
+#[allow(clippy::too_many_arguments)]
 pub trait ProjectRepository: BaseRepository<ProjectEntity> {
     async fn find_by_name(&self, name: &str) -> DomainResponse<Option<ProjectEntity>>;
     async fn update_project_partial(
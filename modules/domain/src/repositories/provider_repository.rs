@@ -10,4 +10,7 @@ use crate::repositories::base_repository::BaseRepository;
 #[async_trait]
 pub trait ProviderRepository: BaseRepository<ProviderEntity> {
     async fn find_by_account_id(&self, account_id: &str) -> DomainResponse<Vec<ProviderEntity>>;
+
+    /// Hard delete everything of an account, returns how many were removed.
+    async fn remove_by_account_id(&self, account_id: &str) -> DomainResponse<usize>;
 }
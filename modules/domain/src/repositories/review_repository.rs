@@ -0,0 +1,43 @@
+use async_trait::async_trait;
+
+// shared modules
+use shared::models::paginate::Paginate;
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::entities::review_entity::ReviewEntity;
+use crate::repositories::base_repository::BaseRepository;
+
+#[async_trait]
+pub trait ReviewRepository: BaseRepository<ReviewEntity> {
+    async fn find_by_course_and_account(
+        &self,
+        course_id: &str,
+        account_id: &str,
+    ) -> DomainResponse<Option<ReviewEntity>>;
+
+    /// Creates the review unless the account already reviewed the course, which storage enforces so concurrent
+    /// requests can not both get through. Returns `None` when a review was already there.
+    async fn create_if_absent(&self, review: &ReviewEntity) -> DomainResponse<Option<ReviewEntity>>;
+
+    /// Reviews written by an account whatever their status, newest first.
+    async fn find_by_account_id(&self, account_id: &str) -> DomainResponse<Vec<ReviewEntity>>;
+    async fn remove_by_account_id(&self, account_id: &str) -> DomainResponse<usize>;
+
+    /// Reviews of a course with the given status, newest first.
+    async fn find_by_course_id_paginated(
+        &self,
+        course_id: &str,
+        status: &str,
+        page: u32,
+        page_size: u32,
+    ) -> DomainResponse<(Paginate, Vec<ReviewEntity>)>;
+
+    /// Reviews of every course with the given status, newest first.
+    async fn find_by_status_paginated(
+        &self,
+        status: &str,
+        page: u32,
+        page_size: u32,
+    ) -> DomainResponse<(Paginate, Vec<ReviewEntity>)>;
+}
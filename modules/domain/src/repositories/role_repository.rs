@@ -12,4 +12,10 @@ pub trait RoleRepository: BaseRepository<RoleEntity> {
     async fn find_by_account_id(&self, account_id: &str) -> DomainResponse<Option<RoleEntity>>;
     async fn find_and_update_role_by_account_id(&self, account_id: &str, role_name: &str)
     -> DomainResponse<RoleEntity>;
+
+    /// Ids of every account holding the role.
+    async fn find_account_ids_by_role(&self, role_name: &str) -> DomainResponse<Vec<String>>;
+
+    /// Hard delete everything of an account, returns how many were removed.
+    async fn remove_by_account_id(&self, account_id: &str) -> DomainResponse<usize>;
 }
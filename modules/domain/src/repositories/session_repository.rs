@@ -11,4 +11,13 @@ use crate::repositories::base_repository::BaseRepository;
 pub trait SessionRepository: BaseRepository<SessionEntity> {
     async fn remove_by_account_id(&self, account_id: &str) -> DomainResponse<()>;
     async fn find_by_jti(&self, jti: &str) -> DomainResponse<Option<SessionEntity>>;
+
+    /// Live sessions of an account that registered a device token.
+    async fn find_with_device_token(&self, account_id: &str) -> DomainResponse<Vec<SessionEntity>>;
+
+    /// Forget a device token on every session holding it, returns how many sessions changed.
+    async fn clear_device_token(&self, device_token: &str) -> DomainResponse<usize>;
+
+    /// Every session of an account, newest first.
+    async fn find_by_account_id(&self, account_id: &str) -> DomainResponse<Vec<SessionEntity>>;
 }
@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+
+// shared modules
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::entities::topic_subscription_entity::TopicSubscriptionEntity;
+use crate::repositories::base_repository::BaseRepository;
+
+#[async_trait]
+pub trait TopicSubscriptionRepository: BaseRepository<TopicSubscriptionEntity> {
+    async fn find_by_account_id(&self, account_id: &str) -> DomainResponse<Vec<TopicSubscriptionEntity>>;
+    async fn find_by_account_and_topic(
+        &self,
+        account_id: &str,
+        topic: &str,
+    ) -> DomainResponse<Option<TopicSubscriptionEntity>>;
+    async fn remove_by_account_id(&self, account_id: &str) -> DomainResponse<usize>;
+}
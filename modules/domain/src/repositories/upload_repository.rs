@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+
+// shared modules
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::entities::upload_entity::UploadEntity;
+use crate::repositories::base_repository::BaseRepository;
+
+#[async_trait]
+pub trait UploadRepository: BaseRepository<UploadEntity> {
+    /// Uploads of an account whatever their status.
+    async fn find_by_account_id(&self, account_id: &str) -> DomainResponse<Vec<UploadEntity>>;
+
+    /// Uploads still pending whose URL expired before `expires_before`, oldest first.
+    async fn find_expired(&self, expires_before: i64, limit: u32) -> DomainResponse<Vec<UploadEntity>>;
+}
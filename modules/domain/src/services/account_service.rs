@@ -2,12 +2,18 @@ use async_trait::async_trait;
 use std::sync::Arc;
 
 // shared modules
+use shared::configs::APP_CONFIG;
+use shared::models::failure::Failure;
+use shared::models::filters::QuerySpec;
 use shared::models::paginate::Paginate;
 use shared::types::DomainResponse;
 
 // internal modules
-use crate::entities::account_entity::AccountEntity;
-use crate::repositories::account_repository::AccountRepository;
+use crate::entities::account_entity::{AccountEntity, UpdateAccountEntity};
+use crate::repositories::account_repository::{AccountRepository, AccountSearch};
+
+// Numbered usernames tried after the one an account signs up with
+const MAX_USERNAME_ATTEMPTS: u32 = 20;
 
 #[async_trait]
 pub trait AccountService: Send + Sync {
@@ -15,11 +21,48 @@ pub trait AccountService: Send + Sync {
     async fn create_account(&self, username: &str, email: &str) -> DomainResponse<AccountEntity>;
     async fn find_by_email(&self, email: &str) -> DomainResponse<Option<AccountEntity>>;
     async fn find_account_by_id(&self, account_id: &str) -> DomainResponse<Option<AccountEntity>>;
+    async fn find_by_username(&self, username: &str) -> DomainResponse<Option<AccountEntity>>;
     async fn find_accounts_paginated(
         &self,
         page: u32,
         page_size: u32,
     ) -> DomainResponse<(Paginate, Vec<AccountEntity>)>;
+    async fn search_accounts(
+        &self,
+        search: &AccountSearch,
+        spec: &QuerySpec,
+        page: u32,
+        page_size: u32,
+    ) -> DomainResponse<(Paginate, Vec<AccountEntity>)>;
+    async fn update_avatar(&self, account: &AccountEntity, avatar: &str) -> DomainResponse<AccountEntity>;
+
+    /// Validate and save the profile fields, a username already taken by another account is a conflict.
+    async fn update_profile(
+        &self,
+        account: &AccountEntity,
+        update: &UpdateAccountEntity,
+    ) -> DomainResponse<AccountEntity>;
+
+    async fn deactivate_account(&self, account: &AccountEntity) -> DomainResponse<AccountEntity>;
+
+    /// Deactivate the account and erase it once the configured grace period passed.
+    async fn schedule_deletion(&self, account: &AccountEntity) -> DomainResponse<AccountEntity>;
+
+    /// Reactivating also cancels a scheduled deletion.
+    async fn reactivate_account(&self, account: &AccountEntity) -> DomainResponse<AccountEntity>;
+
+    /// Suspend the account until `until`, replacing a running suspension.
+    async fn suspend_account(&self, account: &AccountEntity, reason: &str, until: i64)
+    -> DomainResponse<AccountEntity>;
+    async fn lift_suspension(&self, account: &AccountEntity) -> DomainResponse<AccountEntity>;
+
+    /// Fails with forbidden when the account is deactivated or suspended.
+    async fn ensure_active(&self, account_id: &str) -> DomainResponse<AccountEntity>;
+    async fn find_deletion_due(&self, limit: u32) -> DomainResponse<Vec<AccountEntity>>;
+
+    /// The deactivated account anonymised content is handed over to, created on first use.
+    async fn find_or_create_ghost(&self) -> DomainResponse<AccountEntity>;
+    async fn remove_account(&self, account_id: &str) -> DomainResponse<()>;
 }
 
 pub struct AccountServiceImpl {
@@ -42,8 +85,21 @@ impl AccountService for AccountServiceImpl {
     }
 
     async fn create_account(&self, username: &str, email: &str) -> DomainResponse<AccountEntity> {
-        let account = AccountEntity::new(false, username.to_string(), email.to_string())?;
-        self.repository.create(&account).await
+        // Usernames are unique regardless of case, a taken one is numbered until a free one is found
+        let mut candidate = username.to_string();
+        for number in 2..=MAX_USERNAME_ATTEMPTS + 1 {
+            if self.repository.find_by_username(&candidate).await?.is_none() {
+                let account = AccountEntity::new(false, candidate.clone(), email.to_string())?;
+                match self.repository.create(&account).await {
+                    // Taken by a concurrent sign up since the lookup
+                    Err(Failure::Conflict(_)) => {},
+                    result => return result,
+                }
+            }
+            candidate = AccountEntity::numbered_username(username, number);
+        }
+
+        Err(Failure::Conflict(format!("Username {} is already taken", username)))
     }
 
     async fn find_by_email(&self, email: &str) -> DomainResponse<Option<AccountEntity>> {
@@ -54,6 +110,10 @@ impl AccountService for AccountServiceImpl {
         self.repository.find(account_id).await
     }
 
+    async fn find_by_username(&self, username: &str) -> DomainResponse<Option<AccountEntity>> {
+        self.repository.find_by_username(username).await
+    }
+
     async fn find_accounts_paginated(
         &self,
         page: u32,
@@ -61,4 +121,132 @@ impl AccountService for AccountServiceImpl {
     ) -> DomainResponse<(Paginate, Vec<AccountEntity>)> {
         self.repository.finds_paginated(page, page_size).await
     }
+
+    async fn search_accounts(
+        &self,
+        search: &AccountSearch,
+        spec: &QuerySpec,
+        page: u32,
+        page_size: u32,
+    ) -> DomainResponse<(Paginate, Vec<AccountEntity>)> {
+        self.repository.search(search, spec, chrono::Utc::now().timestamp(), page, page_size).await
+    }
+
+    async fn update_avatar(&self, account: &AccountEntity, avatar: &str) -> DomainResponse<AccountEntity> {
+        let mut account = account.clone();
+        account.avatar = avatar.to_string();
+        account.base.updated_at = chrono::Utc::now().timestamp();
+
+        let account_id = account.base.id.clone().unwrap_or_default();
+        self.repository.update(&account_id, &account).await
+    }
+
+    async fn update_profile(
+        &self,
+        account: &AccountEntity,
+        update: &UpdateAccountEntity,
+    ) -> DomainResponse<AccountEntity> {
+        let mut account = account.clone();
+        account.apply_profile(update)?;
+
+        let account_id = account.base.id.clone().unwrap_or_default();
+        if let Some(username) = &update.username {
+            let existing = self.repository.find_by_username(username).await?;
+            if existing.is_some_and(|existing| existing.base.id.as_deref() != Some(account_id.as_str())) {
+                return Err(Failure::Conflict(format!("Username {} is already taken", username)));
+            }
+        }
+
+        // The unique index still turns away a username taken since the check above
+        self.repository.update(&account_id, &account).await.map_err(|failure| match (failure, &update.username) {
+            (Failure::Conflict(_), Some(username)) => {
+                Failure::Conflict(format!("Username {} is already taken", username))
+            },
+            (failure, _) => failure,
+        })
+    }
+
+    async fn deactivate_account(&self, account: &AccountEntity) -> DomainResponse<AccountEntity> {
+        let mut account = account.clone();
+        account.deactivate();
+
+        let account_id = account.base.id.clone().unwrap_or_default();
+        self.repository.update(&account_id, &account).await
+    }
+
+    async fn schedule_deletion(&self, account: &AccountEntity) -> DomainResponse<AccountEntity> {
+        let mut account = account.clone();
+        account.schedule_deletion(APP_CONFIG.accounts.deletion_grace_seconds);
+
+        let account_id = account.base.id.clone().unwrap_or_default();
+        self.repository.update(&account_id, &account).await
+    }
+
+    async fn reactivate_account(&self, account: &AccountEntity) -> DomainResponse<AccountEntity> {
+        let mut account = account.clone();
+        account.reactivate();
+
+        let account_id = account.base.id.clone().unwrap_or_default();
+        self.repository.update(&account_id, &account).await
+    }
+
+    async fn suspend_account(
+        &self,
+        account: &AccountEntity,
+        reason: &str,
+        until: i64,
+    ) -> DomainResponse<AccountEntity> {
+        let mut account = account.clone();
+        account.suspend(reason, until)?;
+
+        let account_id = account.base.id.clone().unwrap_or_default();
+        self.repository.update(&account_id, &account).await
+    }
+
+    async fn lift_suspension(&self, account: &AccountEntity) -> DomainResponse<AccountEntity> {
+        let mut account = account.clone();
+        account.lift_suspension();
+
+        let account_id = account.base.id.clone().unwrap_or_default();
+        self.repository.update(&account_id, &account).await
+    }
+
+    async fn ensure_active(&self, account_id: &str) -> DomainResponse<AccountEntity> {
+        let account = self
+            .repository
+            .find(account_id)
+            .await?
+            .ok_or_else(|| Failure::Unauthorized("Account no longer exists".to_string()))?;
+
+        if !account.is_active {
+            return Err(Failure::Forbidden("This account is deactivated".to_string()));
+        }
+        if account.is_suspended() {
+            return Err(Failure::Forbidden("This account is suspended".to_string()));
+        }
+
+        Ok(account)
+    }
+
+    async fn find_deletion_due(&self, limit: u32) -> DomainResponse<Vec<AccountEntity>> {
+        self.repository.find_deletion_due(chrono::Utc::now().timestamp(), limit).await
+    }
+
+    async fn find_or_create_ghost(&self) -> DomainResponse<AccountEntity> {
+        let config = &APP_CONFIG.accounts;
+        if let Some(ghost) = self.repository.find_by_email(&config.ghost_email).await? {
+            return Ok(ghost);
+        }
+
+        // Deactivated so it has no public profile and can never sign in
+        let mut ghost = AccountEntity::new(false, config.ghost_username.clone(), config.ghost_email.clone())?;
+        ghost.bio = String::new();
+        ghost.deactivate();
+        self.repository.create(&ghost).await
+    }
+
+    async fn remove_account(&self, account_id: &str) -> DomainResponse<()> {
+        self.repository.remove(account_id).await?;
+        Ok(())
+    }
 }
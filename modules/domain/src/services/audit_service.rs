@@ -0,0 +1,90 @@
+use async_trait::async_trait;
+use std::future::Future;
+use std::sync::Arc;
+
+// shared modules
+use shared::models::paginate::Paginate;
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::entities::audit_event_entity::AuditEventEntity;
+use crate::repositories::audit_event_repository::{AuditEventFilter, AuditEventRepository};
+
+/// Who is behind the request being served, attached to every audit event emitted while serving it.
+#[derive(Debug, Clone, Default)]
+pub struct AuditContext {
+    pub actor_id: Option<String>,
+    pub impersonator_id: Option<String>,
+    pub ip_address: Option<String>,
+    pub user_agent: Option<String>,
+    pub request_id: Option<String>,
+}
+
+tokio::task_local! {
+    static AUDIT_CONTEXT: AuditContext;
+}
+
+impl AuditContext {
+    /// Run `future` with this context, nested scopes replace the outer one until they finish.
+    pub async fn scope<F: Future>(self, future: F) -> F::Output {
+        AUDIT_CONTEXT.scope(self, future).await
+    }
+
+    /// The context of the request being served, `None` outside of a request such as in scheduled jobs.
+    pub fn current() -> Option<AuditContext> {
+        AUDIT_CONTEXT.try_with(|context| context.clone()).ok()
+    }
+}
+
+#[async_trait]
+pub trait AuditService: Send + Sync {
+    /// Append the event along with the context of the current request. An actor set on the event is kept, the
+    /// one of the context is used otherwise.
+    async fn emit(&self, event: AuditEventEntity) -> DomainResponse<AuditEventEntity>;
+    async fn find_events(
+        &self,
+        filter: &AuditEventFilter,
+        page: u32,
+        page_size: u32,
+    ) -> DomainResponse<(Paginate, Vec<AuditEventEntity>)>;
+    async fn find_all_events(&self, filter: &AuditEventFilter, limit: u32) -> DomainResponse<Vec<AuditEventEntity>>;
+}
+
+pub struct AuditServiceImpl {
+    repository: Arc<dyn AuditEventRepository>,
+}
+
+impl AuditServiceImpl {
+    pub fn new(repository: Arc<dyn AuditEventRepository>) -> Self {
+        Self { repository }
+    }
+}
+
+#[async_trait]
+impl AuditService for AuditServiceImpl {
+    async fn emit(&self, event: AuditEventEntity) -> DomainResponse<AuditEventEntity> {
+        let mut event = event;
+        if let Some(context) = AuditContext::current() {
+            event.actor_id = event.actor_id.or(context.actor_id);
+            event.impersonator_id = context.impersonator_id;
+            event.ip_address = context.ip_address;
+            event.user_agent = context.user_agent;
+            event.request_id = context.request_id;
+        }
+
+        self.repository.create(&event).await
+    }
+
+    async fn find_events(
+        &self,
+        filter: &AuditEventFilter,
+        page: u32,
+        page_size: u32,
+    ) -> DomainResponse<(Paginate, Vec<AuditEventEntity>)> {
+        self.repository.find_filtered_paginated(filter, page, page_size).await
+    }
+
+    async fn find_all_events(&self, filter: &AuditEventFilter, limit: u32) -> DomainResponse<Vec<AuditEventEntity>> {
+        self.repository.find_filtered(filter, limit).await
+    }
+}
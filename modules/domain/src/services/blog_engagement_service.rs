@@ -0,0 +1,191 @@
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+// shared modules
+use shared::models::failure::Failure;
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::entities::blog_entity::BlogEntity;
+use crate::entities::blog_star_entity::BlogStarEntity;
+use crate::repositories::blog_repository::BlogRepository;
+use crate::repositories::blog_star_repository::BlogStarRepository;
+
+// Views from the same viewer on the same blog are only counted once per window
+static VIEW_DEDUP_WINDOW_SECONDS: i64 = 30 * 60;
+// Viewer and blog pairs remembered at most, the oldest half is forgotten beyond it
+static MAX_RECENT_VIEWS: usize = 100_000;
+
+#[derive(Debug, Default, Clone, Copy)]
+struct CounterDelta {
+    stars: i32,
+    views: i32,
+}
+
+#[async_trait]
+pub trait BlogEngagementService: Send + Sync {
+    /// Star or un-star the blog for the account, returns whether the blog is starred afterwards.
+    async fn toggle_star(&self, blog_id: &str, account_id: &str) -> DomainResponse<bool>;
+
+    /// Count a view unless `viewer_key` already viewed the blog inside the dedup window. The recent views are
+    /// kept in memory per replica, a viewer whose requests reach several replicas may be counted once on each.
+    async fn record_view(&self, blog_id: &str, viewer_key: &str) -> DomainResponse<bool>;
+
+    /// Add counter changes that are not flushed yet, so readers see their own star right away.
+    fn apply_pending_counters(&self, blog: &mut BlogEntity);
+
+    /// Write the aggregated counters to the database, returns the number of blogs written.
+    async fn flush_counters(&self) -> DomainResponse<usize>;
+
+    async fn find_stars_by_account(&self, account_id: &str) -> DomainResponse<Vec<BlogStarEntity>>;
+
+    /// Un-star every blog the account starred.
+    async fn remove_stars_by_account_id(&self, account_id: &str) -> DomainResponse<usize>;
+}
+
+pub struct BlogEngagementServiceImpl {
+    blog_repository: Arc<dyn BlogRepository>,
+    blog_star_repository: Arc<dyn BlogStarRepository>,
+    pending_counters: Mutex<HashMap<String, CounterDelta>>,
+    recent_views: Mutex<HashMap<(String, String), i64>>,
+}
+
+impl BlogEngagementServiceImpl {
+    pub fn new(blog_repository: Arc<dyn BlogRepository>, blog_star_repository: Arc<dyn BlogStarRepository>) -> Self {
+        BlogEngagementServiceImpl {
+            blog_repository,
+            blog_star_repository,
+            pending_counters: Mutex::new(HashMap::new()),
+            recent_views: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Make room for a new view once the map is full, expired views go first, then the oldest half.
+    fn evict_recent_views(recent_views: &mut HashMap<(String, String), i64>, now: i64) {
+        if recent_views.len() < MAX_RECENT_VIEWS {
+            return;
+        }
+
+        recent_views.retain(|_, seen_at| now - *seen_at < VIEW_DEDUP_WINDOW_SECONDS);
+        if recent_views.len() < MAX_RECENT_VIEWS {
+            return;
+        }
+
+        let mut seen_ats: Vec<i64> = recent_views.values().copied().collect();
+        let middle = seen_ats.len() / 2;
+        let (_, cutoff, _) = seen_ats.select_nth_unstable(middle);
+        let cutoff = *cutoff;
+        recent_views.retain(|_, seen_at| *seen_at > cutoff);
+    }
+
+    fn add_pending(&self, blog_id: &str, stars: i32, views: i32) {
+        let mut pending = self.pending_counters.lock().unwrap();
+        let delta = pending.entry(blog_id.to_string()).or_default();
+        delta.stars += stars;
+        delta.views += views;
+    }
+}
+
+#[async_trait]
+impl BlogEngagementService for BlogEngagementServiceImpl {
+    async fn toggle_star(&self, blog_id: &str, account_id: &str) -> DomainResponse<bool> {
+        let existing = self.blog_star_repository.find_by_blog_id_and_account_id(blog_id, account_id).await?;
+
+        match existing {
+            Some(star) => {
+                let star_id = star.base.id.ok_or(Failure::InternalError("Blog star id is missing".to_string()))?;
+                if self.blog_star_repository.remove(&star_id).await? > 0 {
+                    self.add_pending(blog_id, -1, 0);
+                }
+
+                Ok(false)
+            },
+            None => {
+                // A concurrent toggle may have starred it first, the blog is starred either way
+                let star = BlogStarEntity::new(false, blog_id, account_id);
+                if self.blog_star_repository.create_if_absent(&star).await? {
+                    self.add_pending(blog_id, 1, 0);
+                }
+
+                Ok(true)
+            },
+        }
+    }
+
+    async fn record_view(&self, blog_id: &str, viewer_key: &str) -> DomainResponse<bool> {
+        let now = chrono::Utc::now().timestamp();
+        let key = (blog_id.to_string(), viewer_key.to_string());
+
+        {
+            let mut recent_views = self.recent_views.lock().unwrap();
+            match recent_views.get(&key) {
+                Some(seen_at) if now - seen_at < VIEW_DEDUP_WINDOW_SECONDS => return Ok(false),
+                Some(_) => recent_views.insert(key, now),
+                None => {
+                    Self::evict_recent_views(&mut recent_views, now);
+                    recent_views.insert(key, now)
+                },
+            };
+        }
+
+        self.add_pending(blog_id, 0, 1);
+        Ok(true)
+    }
+
+    fn apply_pending_counters(&self, blog: &mut BlogEntity) {
+        let Some(blog_id) = blog.base.id.as_ref() else { return };
+
+        if let Some(delta) = self.pending_counters.lock().unwrap().get(blog_id) {
+            blog.stars += delta.stars;
+            blog.views += delta.views;
+        }
+    }
+
+    async fn flush_counters(&self) -> DomainResponse<usize> {
+        // 1. Forget views that left the dedup window
+        let now = chrono::Utc::now().timestamp();
+        self.recent_views.lock().unwrap().retain(|_, seen_at| now - *seen_at < VIEW_DEDUP_WINDOW_SECONDS);
+
+        // 2. Take the pending counters, new events keep aggregating while we write
+        let pending = std::mem::take(&mut *self.pending_counters.lock().unwrap());
+
+        // 3. One increment per blog, failed writes are queued again for the next flush
+        let mut flushed = 0;
+        let mut failure = None;
+        for (blog_id, delta) in pending {
+            if delta.stars == 0 && delta.views == 0 {
+                continue;
+            }
+
+            match self.blog_repository.increment_counters(&blog_id, delta.stars, delta.views).await {
+                Ok(_) => flushed += 1,
+                Err(e) => {
+                    self.add_pending(&blog_id, delta.stars, delta.views);
+                    failure = Some(e);
+                },
+            }
+        }
+
+        match failure {
+            Some(e) => Err(e),
+            None => Ok(flushed),
+        }
+    }
+
+    async fn find_stars_by_account(&self, account_id: &str) -> DomainResponse<Vec<BlogStarEntity>> {
+        self.blog_star_repository.find_by_account_id(account_id).await
+    }
+
+    async fn remove_stars_by_account_id(&self, account_id: &str) -> DomainResponse<usize> {
+        let mut removed_count = 0;
+        for star in self.blog_star_repository.find_by_account_id(account_id).await? {
+            if self.blog_star_repository.remove(&star.base.id.unwrap_or_default()).await? > 0 {
+                self.add_pending(&star.blog_id, -1, 0);
+                removed_count += 1;
+            }
+        }
+
+        Ok(removed_count)
+    }
+}
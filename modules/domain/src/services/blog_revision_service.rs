@@ -0,0 +1,94 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+
+// shared modules
+use shared::models::failure::Failure;
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::entities::blog_entity::BlogEntity;
+use crate::entities::blog_revision_entity::BlogRevisionEntity;
+use crate::repositories::blog_revision_repository::BlogRevisionRepository;
+use crate::values::text_diff::TextDiff;
+
+#[async_trait]
+pub trait BlogRevisionService: Send + Sync {
+    /// Snapshot the current blog content. Returns `None` when the content did not change since the latest revision.
+    async fn record_revision(
+        &self,
+        blog: &BlogEntity,
+        author_id: &str,
+        restored_from: Option<i32>,
+    ) -> DomainResponse<Option<BlogRevisionEntity>>;
+
+    async fn find_revisions(&self, blog_id: &str) -> DomainResponse<Vec<BlogRevisionEntity>>;
+    async fn find_revision(&self, blog_id: &str, version: i32) -> DomainResponse<BlogRevisionEntity>;
+    async fn diff_revisions(&self, blog_id: &str, from_version: i32, to_version: i32) -> DomainResponse<TextDiff>;
+    async fn remove_revisions(&self, blog_id: &str) -> DomainResponse<usize>;
+}
+
+pub struct BlogRevisionServiceImpl {
+    repository: Arc<dyn BlogRevisionRepository>,
+}
+
+impl BlogRevisionServiceImpl {
+    pub fn new(repository: Arc<dyn BlogRevisionRepository>) -> Self {
+        BlogRevisionServiceImpl { repository }
+    }
+}
+
+#[async_trait]
+impl BlogRevisionService for BlogRevisionServiceImpl {
+    async fn record_revision(
+        &self,
+        blog: &BlogEntity,
+        author_id: &str,
+        restored_from: Option<i32>,
+    ) -> DomainResponse<Option<BlogRevisionEntity>> {
+        let blog_id = blog.base.id.as_deref().ok_or(Failure::InternalError("Blog id is missing".to_string()))?;
+        let latest = self.repository.find_latest_by_blog_id(blog_id).await?;
+
+        // 1. Skip when nothing changed since the latest revision
+        let content_hash = BlogRevisionEntity::hash_content(&blog.markdown);
+        if let Some(ref latest) = latest
+            && latest.content_hash == content_hash
+        {
+            return Ok(None);
+        }
+
+        // 2. Append the new revision
+        let version = latest.map(|revision| revision.version + 1).unwrap_or(1);
+        let revision = BlogRevisionEntity::new(false, blog_id, author_id, version, &blog.markdown, restored_from);
+        let revision = self.repository.create(&revision).await?;
+
+        // 3. Drop revisions that fall outside the blog retention window
+        let oldest_kept = version - blog.revision_retention + 1;
+        if oldest_kept > 1 {
+            self.repository.remove_older_than_version(blog_id, oldest_kept).await?;
+        }
+
+        Ok(Some(revision))
+    }
+
+    async fn find_revisions(&self, blog_id: &str) -> DomainResponse<Vec<BlogRevisionEntity>> {
+        self.repository.find_by_blog_id(blog_id).await
+    }
+
+    async fn find_revision(&self, blog_id: &str, version: i32) -> DomainResponse<BlogRevisionEntity> {
+        self.repository
+            .find_by_version(blog_id, version)
+            .await?
+            .ok_or(Failure::NotFound(format!("Revision {} of blog {} not found", version, blog_id)))
+    }
+
+    async fn diff_revisions(&self, blog_id: &str, from_version: i32, to_version: i32) -> DomainResponse<TextDiff> {
+        let from = self.find_revision(blog_id, from_version).await?;
+        let to = self.find_revision(blog_id, to_version).await?;
+
+        Ok(TextDiff::from_lines(&from.markdown, &to.markdown))
+    }
+
+    async fn remove_revisions(&self, blog_id: &str) -> DomainResponse<usize> {
+        self.repository.remove_by_blog_id(blog_id).await
+    }
+}
@@ -1,11 +1,18 @@
 use async_trait::async_trait;
+use std::collections::HashMap;
 use std::sync::Arc;
 
 // shared modules
+use shared::models::failure::Failure;
+use shared::models::filters::QuerySpec;
+use shared::models::paginate::Paginate;
 use shared::types::DomainResponse;
 
 // internal modules
-use crate::{entities::blog_entity::BlogEntity, repositories::blog_repository::BlogRepository};
+use crate::entities::blog_entity::{BlogEntity, UpdateBlogEntity};
+use crate::repositories::blog_repository::BlogRepository;
+use crate::usecases::blog_usecases::ScheduledPublishResult;
+use crate::values::blog_status::BlogStatus;
 
 #[async_trait]
 pub trait BlogService: Send + Sync {
@@ -17,23 +24,68 @@ pub trait BlogService: Send + Sync {
         description: &str,
         markdown: &str,
         categories: &[String],
-        is_published: bool,
+        status: &str,
+        publish_at: Option<i64>,
         estimated_read_time: i32,
+        revision_retention: Option<i32>,
     ) -> DomainResponse<BlogEntity>;
 
-    #[allow(clippy::too_many_arguments)]
+    async fn find_blog_by_id(&self, blog_id: &str) -> DomainResponse<Option<BlogEntity>>;
+    async fn find_published_blogs(
+        &self,
+        spec: &QuerySpec,
+        page: u32,
+        page_size: u32,
+    ) -> DomainResponse<(Paginate, Vec<BlogEntity>)>;
+    async fn find_published_blogs_by_author(
+        &self,
+        author_id: &str,
+        page: u32,
+        page_size: u32,
+    ) -> DomainResponse<(Paginate, Vec<BlogEntity>)>;
+
+    async fn find_published_modification_times(&self) -> DomainResponse<Vec<(String, i64)>>;
+
+    /// Newest published blogs first, optionally narrowed to a category or an author.
+    async fn find_latest_published_blogs(
+        &self,
+        category_id: Option<&str>,
+        author_id: Option<&str>,
+        limit: u32,
+    ) -> DomainResponse<Vec<BlogEntity>>;
+
     async fn update_blog(
         &self,
         account_id: &str,
         blog_id: &str,
-        name: &str,
-        description: &str,
-        markdown: &str,
-        categories: &[String],
-        estimated_read_time: i32,
+        update: &UpdateBlogEntity,
     ) -> DomainResponse<BlogEntity>;
 
+    async fn change_blog_status(
+        &self,
+        account_id: &str,
+        blog_id: &str,
+        status: &str,
+        publish_at: Option<i64>,
+    ) -> DomainResponse<BlogEntity>;
+
+    /// One failing blog does not hold back the others, it is reported and left due.
+    async fn publish_scheduled_blogs(&self) -> DomainResponse<ScheduledPublishResult>;
+
     async fn delete_blog(&self, blog_id: &str) -> DomainResponse<bool>;
+
+    async fn count_blogs_in_categories(&self, category_ids: &[String]) -> DomainResponse<u64>;
+    async fn count_published_blogs_by_category(&self) -> DomainResponse<HashMap<String, u32>>;
+    async fn reassign_blog_categories(&self, from: &[String], to: Option<&str>) -> DomainResponse<usize>;
+    async fn count_blogs_only_in_categories(&self, category_ids: &[String]) -> DomainResponse<u64>;
+    async fn drop_blog_categories(&self, from: &[String], fallback: &str) -> DomainResponse<usize>;
+
+    /// Every blog of an author whatever its status, drafts included.
+    async fn find_blogs_by_author(&self, author_id: &str) -> DomainResponse<Vec<BlogEntity>>;
+    async fn reassign_blog_author(&self, from: &str, to: &str) -> DomainResponse<usize>;
+
+    /// Hard remove every blog of an author, soft deleted ones included, and return them.
+    async fn remove_blogs_by_author(&self, author_id: &str) -> DomainResponse<Vec<BlogEntity>>;
 }
 
 pub struct BlogServiceImpl {
@@ -56,42 +108,194 @@ impl BlogService for BlogServiceImpl {
         description: &str,
         markdown: &str,
         categories: &[String],
-        is_published: bool,
+        status: &str,
+        publish_at: Option<i64>,
         estimated_read_time: i32,
+        revision_retention: Option<i32>,
     ) -> DomainResponse<BlogEntity> {
         BlogEntity::validate_name_size(name)?;
         BlogEntity::validate_description_size(description)?;
         BlogEntity::validate_markdown_size(markdown)?;
 
-        let blog_entity = BlogEntity::new(
-            false,
-            author_id,
-            categories,
-            name,
-            description,
-            is_published,
-            markdown,
-            estimated_read_time,
-        );
+        let mut blog_entity =
+            BlogEntity::new(false, author_id, categories, name, description, markdown, estimated_read_time);
+
+        // Every blog starts as a draft, then moves to the requested status
+        let status = BlogStatus::from_string(status)?;
+        if status != BlogStatus::Draft {
+            blog_entity.transition(status, publish_at)?;
+        }
+
+        if let Some(revision_retention) = revision_retention {
+            BlogEntity::validate_revision_retention(revision_retention)?;
+            blog_entity.revision_retention = revision_retention;
+        }
 
         self.repository.create(&blog_entity).await
     }
 
-    #[allow(clippy::too_many_arguments)]
+    async fn find_blog_by_id(&self, blog_id: &str) -> DomainResponse<Option<BlogEntity>> {
+        self.repository.find(blog_id).await
+    }
+
+    async fn find_published_blogs(
+        &self,
+        spec: &QuerySpec,
+        page: u32,
+        page_size: u32,
+    ) -> DomainResponse<(Paginate, Vec<BlogEntity>)> {
+        self.repository.find_published_paginated(None, spec, page, page_size).await
+    }
+
+    async fn find_published_blogs_by_author(
+        &self,
+        author_id: &str,
+        page: u32,
+        page_size: u32,
+    ) -> DomainResponse<(Paginate, Vec<BlogEntity>)> {
+        self.repository.find_published_paginated(Some(author_id), &QuerySpec::default(), page, page_size).await
+    }
+
+    async fn find_published_modification_times(&self) -> DomainResponse<Vec<(String, i64)>> {
+        self.repository.find_published_modification_times().await
+    }
+
+    async fn find_latest_published_blogs(
+        &self,
+        category_id: Option<&str>,
+        author_id: Option<&str>,
+        limit: u32,
+    ) -> DomainResponse<Vec<BlogEntity>> {
+        self.repository.find_latest_published(category_id, author_id, limit).await
+    }
+
     async fn update_blog(
         &self,
-        _account_id: &str,
-        _blog_id: &str,
-        _name: &str,
-        _description: &str,
-        _markdown: &str,
-        _categories: &[String],
-        _estimated_read_time: i32,
+        account_id: &str,
+        blog_id: &str,
+        update: &UpdateBlogEntity,
     ) -> DomainResponse<BlogEntity> {
-        todo!()
+        let mut blog = self
+            .repository
+            .find(blog_id)
+            .await?
+            .ok_or(Failure::NotFound(format!("Blog with id {} not found", blog_id)))?;
+
+        if blog.author_id != account_id {
+            return Err(Failure::Forbidden("You are not the author of this blog".to_string()));
+        }
+
+        if let Some(ref name) = update.name {
+            BlogEntity::validate_name_size(name)?;
+            blog.name = name.clone();
+        }
+        if let Some(ref description) = update.description {
+            BlogEntity::validate_description_size(description)?;
+            blog.description = description.clone();
+        }
+        if let Some(ref markdown) = update.markdown {
+            BlogEntity::validate_markdown_size(markdown)?;
+            blog.markdown = markdown.clone();
+        }
+        if let Some(ref categories) = update.categories {
+            blog.categories = categories.clone();
+        }
+        if let Some(estimated_read_time) = update.estimated_read_time {
+            blog.estimated_read_time = estimated_read_time;
+        }
+        if let Some(revision_retention) = update.revision_retention {
+            BlogEntity::validate_revision_retention(revision_retention)?;
+            blog.revision_retention = revision_retention;
+        }
+
+        blog.base.updated_at = chrono::Utc::now().timestamp();
+        self.repository.update(blog_id, &blog).await
+    }
+
+    async fn change_blog_status(
+        &self,
+        account_id: &str,
+        blog_id: &str,
+        status: &str,
+        publish_at: Option<i64>,
+    ) -> DomainResponse<BlogEntity> {
+        let mut blog = self
+            .repository
+            .find(blog_id)
+            .await?
+            .ok_or(Failure::NotFound(format!("Blog with id {} not found", blog_id)))?;
+
+        if blog.author_id != account_id {
+            return Err(Failure::Forbidden("You are not the author of this blog".to_string()));
+        }
+
+        blog.transition(BlogStatus::from_string(status)?, publish_at)?;
+        self.repository.update(blog_id, &blog).await
+    }
+
+    async fn publish_scheduled_blogs(&self) -> DomainResponse<ScheduledPublishResult> {
+        let now = chrono::Utc::now().timestamp();
+        let due_blogs = self.repository.find_scheduled_before(now).await?;
+
+        let mut result = ScheduledPublishResult::default();
+        for mut blog in due_blogs {
+            let Some(blog_id) = blog.base.id.clone() else { continue };
+
+            // Keep the scheduled time as the publication date, not the time the job happened to run
+            let scheduled_at = blog.publish_at;
+            let published = match blog.transition(BlogStatus::Published, None) {
+                Ok(()) => {
+                    blog.published_at = scheduled_at.or(blog.published_at);
+                    self.repository.publish_scheduled(&blog).await
+                },
+                Err(failure) => Err(failure),
+            };
+
+            // Every replica runs the job, a blog another one published first is skipped
+            match published {
+                Ok(Some(blog)) => result.published.push(blog),
+                Ok(None) => {},
+                Err(failure) => result.failed.push((blog_id, failure.message().to_string())),
+            }
+        }
+
+        Ok(result)
+    }
+
+    async fn delete_blog(&self, blog_id: &str) -> DomainResponse<bool> {
+        let deleted_count = self.repository.delete(blog_id).await?;
+        Ok(deleted_count > 0)
+    }
+
+    async fn count_blogs_in_categories(&self, category_ids: &[String]) -> DomainResponse<u64> {
+        self.repository.count_by_categories(category_ids).await
+    }
+
+    async fn count_published_blogs_by_category(&self) -> DomainResponse<HashMap<String, u32>> {
+        self.repository.count_published_by_category().await
+    }
+
+    async fn reassign_blog_categories(&self, from: &[String], to: Option<&str>) -> DomainResponse<usize> {
+        self.repository.replace_categories(from, to).await
+    }
+
+    async fn count_blogs_only_in_categories(&self, category_ids: &[String]) -> DomainResponse<u64> {
+        self.repository.count_only_in_categories(category_ids).await
+    }
+
+    async fn drop_blog_categories(&self, from: &[String], fallback: &str) -> DomainResponse<usize> {
+        self.repository.drop_categories(from, fallback).await
+    }
+
+    async fn find_blogs_by_author(&self, author_id: &str) -> DomainResponse<Vec<BlogEntity>> {
+        self.repository.find_by_author(author_id).await
+    }
+
+    async fn reassign_blog_author(&self, from: &str, to: &str) -> DomainResponse<usize> {
+        self.repository.replace_author(from, to).await
     }
 
-    async fn delete_blog(&self, _blog_id: &str) -> DomainResponse<bool> {
-        todo!()
+    async fn remove_blogs_by_author(&self, author_id: &str) -> DomainResponse<Vec<BlogEntity>> {
+        self.repository.find_and_remove_by_author(author_id).await
     }
 }
@@ -0,0 +1,108 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+
+// shared modules
+use shared::models::paginate::Paginate;
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::entities::bookmark_entity::BookmarkEntity;
+use crate::repositories::bookmark_repository::BookmarkRepository;
+use crate::values::bookmark_target::BookmarkTarget;
+
+#[async_trait]
+pub trait BookmarkService: Send + Sync {
+    /// Adding the same target twice returns the existing bookmark.
+    async fn add_bookmark(
+        &self,
+        account_id: &str,
+        target_type: BookmarkTarget,
+        target_id: &str,
+    ) -> DomainResponse<BookmarkEntity>;
+
+    /// Removing a missing bookmark is not an error.
+    async fn remove_bookmark(
+        &self,
+        account_id: &str,
+        target_type: BookmarkTarget,
+        target_id: &str,
+    ) -> DomainResponse<()>;
+
+    async fn find_bookmarks(
+        &self,
+        account_id: &str,
+        target_type: Option<BookmarkTarget>,
+        page: u32,
+        page_size: u32,
+    ) -> DomainResponse<(Paginate, Vec<BookmarkEntity>)>;
+
+    async fn find_bookmarked_ids(
+        &self,
+        account_id: &str,
+        target_type: BookmarkTarget,
+        target_ids: &[String],
+    ) -> DomainResponse<Vec<String>>;
+
+    async fn remove_bookmarks_by_account_id(&self, account_id: &str) -> DomainResponse<usize>;
+}
+
+pub struct BookmarkServiceImpl {
+    bookmark_repository: Arc<dyn BookmarkRepository>,
+}
+
+impl BookmarkServiceImpl {
+    pub fn new(bookmark_repository: Arc<dyn BookmarkRepository>) -> Self {
+        Self { bookmark_repository }
+    }
+}
+
+#[async_trait]
+impl BookmarkService for BookmarkServiceImpl {
+    async fn add_bookmark(
+        &self,
+        account_id: &str,
+        target_type: BookmarkTarget,
+        target_id: &str,
+    ) -> DomainResponse<BookmarkEntity> {
+        let bookmark = BookmarkEntity::new(true, account_id, target_type, target_id);
+        self.bookmark_repository.upsert(&bookmark).await
+    }
+
+    async fn remove_bookmark(
+        &self,
+        account_id: &str,
+        target_type: BookmarkTarget,
+        target_id: &str,
+    ) -> DomainResponse<()> {
+        self.bookmark_repository.remove_by_target(account_id, target_type.as_str(), target_id).await?;
+        Ok(())
+    }
+
+    async fn find_bookmarks(
+        &self,
+        account_id: &str,
+        target_type: Option<BookmarkTarget>,
+        page: u32,
+        page_size: u32,
+    ) -> DomainResponse<(Paginate, Vec<BookmarkEntity>)> {
+        let target_type = target_type.as_ref().map(BookmarkTarget::as_str);
+        self.bookmark_repository.find_by_account_id(account_id, target_type, page, page_size).await
+    }
+
+    async fn find_bookmarked_ids(
+        &self,
+        account_id: &str,
+        target_type: BookmarkTarget,
+        target_ids: &[String],
+    ) -> DomainResponse<Vec<String>> {
+        if target_ids.is_empty() {
+            return Ok(vec![]);
+        }
+
+        self.bookmark_repository.find_bookmarked_ids(account_id, target_type.as_str(), target_ids).await
+    }
+
+    async fn remove_bookmarks_by_account_id(&self, account_id: &str) -> DomainResponse<usize> {
+        self.bookmark_repository.remove_by_account_id(account_id).await
+    }
+}
@@ -2,7 +2,7 @@ use async_trait::async_trait;
 use std::sync::Arc;
 
 // shared modules
-use shared::types::DomainResponse;
+use shared::{models::failure::Failure, types::DomainResponse};
 
 // internal modules
 use crate::{entities::category_entity::CategoryEntity, repositories::category_repository::CategoryRepository};
@@ -11,9 +11,22 @@ use crate::{entities::category_entity::CategoryEntity, repositories::category_re
 pub trait CategoryService: Send + Sync {
     async fn find_category_by_id(&self, category_id: &str) -> DomainResponse<Option<CategoryEntity>>;
     async fn find_categories(&self) -> DomainResponse<Vec<CategoryEntity>>;
+    async fn find_descendants(&self, category: &CategoryEntity) -> DomainResponse<Vec<CategoryEntity>>;
     async fn check_category_with_name_exists(&self, name: &str) -> DomainResponse<bool>;
-    async fn create_category(&self, name: &str) -> DomainResponse<CategoryEntity>;
+
+    /// Fails with a validation error naming every id that is not an existing category.
+    async fn ensure_categories_exist(&self, category_ids: &[String]) -> DomainResponse<()>;
+
+    async fn create_category(&self, name: &str, parent: Option<&CategoryEntity>) -> DomainResponse<CategoryEntity>;
     async fn update_category(&self, category_id: &str, entity: &CategoryEntity) -> DomainResponse<CategoryEntity>;
+
+    /// Move the category and its whole subtree under `parent`, or to the root when `None`.
+    async fn move_category(
+        &self,
+        category: &CategoryEntity,
+        parent: Option<&CategoryEntity>,
+    ) -> DomainResponse<CategoryEntity>;
+
     async fn find_and_delete_category(&self, category_id: &str) -> DomainResponse<CategoryEntity>;
 }
 
@@ -37,13 +50,36 @@ impl CategoryService for CategoryServiceImpl {
         self.repository.finds().await
     }
 
+    async fn find_descendants(&self, category: &CategoryEntity) -> DomainResponse<Vec<CategoryEntity>> {
+        self.repository.find_by_path_prefix(&category.descendant_path()).await
+    }
+
     async fn check_category_with_name_exists(&self, name: &str) -> DomainResponse<bool> {
         let category = self.repository.find_by_name(name).await?;
         Ok(category.is_some())
     }
 
-    async fn create_category(&self, name: &str) -> DomainResponse<CategoryEntity> {
-        let entity = CategoryEntity::new(false, name)?;
+    async fn ensure_categories_exist(&self, category_ids: &[String]) -> DomainResponse<()> {
+        if category_ids.is_empty() {
+            return Ok(());
+        }
+
+        let categories = self.repository.find_by_ids(category_ids).await?;
+        let missing: Vec<&str> = category_ids
+            .iter()
+            .filter(|id| !categories.iter().any(|category| category.base.id.as_ref() == Some(*id)))
+            .map(|id| id.as_str())
+            .collect();
+
+        if !missing.is_empty() {
+            return Err(Failure::ValidationError(format!("Unknown categories: {}", missing.join(", "))));
+        }
+
+        Ok(())
+    }
+
+    async fn create_category(&self, name: &str, parent: Option<&CategoryEntity>) -> DomainResponse<CategoryEntity> {
+        let entity = CategoryEntity::new(false, name, parent)?;
         self.repository.create(&entity).await
     }
 
@@ -51,6 +87,43 @@ impl CategoryService for CategoryServiceImpl {
         self.repository.update(category_id, entity).await
     }
 
+    async fn move_category(
+        &self,
+        category: &CategoryEntity,
+        parent: Option<&CategoryEntity>,
+    ) -> DomainResponse<CategoryEntity> {
+        let category_id = category.base.id.clone().unwrap_or_default();
+
+        // 1. A category can not become its own ancestor
+        if let Some(parent) = parent
+            && (parent.base.id == category.base.id || category.is_ancestor_of(parent))
+        {
+            return Err(Failure::ValidationError("A category can not be moved under itself".to_string()));
+        }
+
+        // 2. The deepest descendant must still fit under the new parent
+        let descendants = self.find_descendants(category).await?;
+        let mut moved = category.clone();
+        moved.attach_to(parent)?;
+        let depth_shift = moved.depth - category.depth;
+        for descendant in &descendants {
+            CategoryEntity::validate_depth(descendant.depth + depth_shift)?;
+        }
+
+        // 3. Rewrite the paths of the category and its subtree
+        let old_prefix = category.descendant_path();
+        let new_prefix = moved.descendant_path();
+        let moved = self.repository.update(&category_id, &moved).await?;
+        for mut descendant in descendants {
+            descendant.path = descendant.path.replacen(&old_prefix, &new_prefix, 1);
+            descendant.depth += depth_shift;
+            let descendant_id = descendant.base.id.clone().unwrap_or_default();
+            self.repository.update(&descendant_id, &descendant).await?;
+        }
+
+        Ok(moved)
+    }
+
     async fn find_and_delete_category(&self, category_id: &str) -> DomainResponse<CategoryEntity> {
         // Hard delete the category
         self.repository.find_and_remove(category_id).await
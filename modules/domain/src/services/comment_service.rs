@@ -0,0 +1,157 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+
+// shared modules
+use shared::models::failure::Failure;
+use shared::models::paginate::Paginate;
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::entities::comment_ban_entity::CommentBanEntity;
+use crate::entities::comment_entity::CommentEntity;
+use crate::repositories::comment_ban_repository::CommentBanRepository;
+use crate::repositories::comment_repository::CommentRepository;
+use crate::values::comment_status::CommentStatus;
+
+#[async_trait]
+pub trait CommentService: Send + Sync {
+    async fn create_comment(
+        &self,
+        blog_id: &str,
+        author_id: &str,
+        parent_id: Option<&str>,
+        content: &str,
+    ) -> DomainResponse<CommentEntity>;
+
+    async fn edit_comment(&self, comment_id: &str, author_id: &str, content: &str) -> DomainResponse<CommentEntity>;
+    async fn delete_comment(&self, comment_id: &str, author_id: &str) -> DomainResponse<()>;
+    async fn find_comment_by_id(&self, comment_id: &str) -> DomainResponse<CommentEntity>;
+    async fn find_visible_comments(
+        &self,
+        blog_id: &str,
+        page: u32,
+        page_size: u32,
+    ) -> DomainResponse<(Paginate, Vec<CommentEntity>)>;
+
+    async fn change_comment_status(&self, comment_id: &str, status: CommentStatus) -> DomainResponse<CommentEntity>;
+    async fn ban_author(&self, account_id: &str, banned_by: &str, reason: &str) -> DomainResponse<CommentBanEntity>;
+
+    async fn find_comments_by_author(&self, author_id: &str) -> DomainResponse<Vec<CommentEntity>>;
+    async fn remove_comments_by_author_id(&self, author_id: &str) -> DomainResponse<usize>;
+}
+
+pub struct CommentServiceImpl {
+    repository: Arc<dyn CommentRepository>,
+    ban_repository: Arc<dyn CommentBanRepository>,
+}
+
+impl CommentServiceImpl {
+    pub fn new(repository: Arc<dyn CommentRepository>, ban_repository: Arc<dyn CommentBanRepository>) -> Self {
+        Self { repository, ban_repository }
+    }
+
+    async fn ensure_not_banned(&self, account_id: &str) -> DomainResponse<()> {
+        if self.ban_repository.find_by_account_id(account_id).await?.is_some() {
+            return Err(Failure::Forbidden("You are banned from commenting".to_string()));
+        }
+
+        Ok(())
+    }
+
+    async fn find_own_comment(&self, comment_id: &str, author_id: &str) -> DomainResponse<CommentEntity> {
+        let comment = self.find_comment_by_id(comment_id).await?;
+        if comment.author_id != author_id {
+            return Err(Failure::Forbidden("You are not the author of this comment".to_string()));
+        }
+
+        Ok(comment)
+    }
+}
+
+#[async_trait]
+impl CommentService for CommentServiceImpl {
+    async fn create_comment(
+        &self,
+        blog_id: &str,
+        author_id: &str,
+        parent_id: Option<&str>,
+        content: &str,
+    ) -> DomainResponse<CommentEntity> {
+        CommentEntity::validate_content(content)?;
+        self.ensure_not_banned(author_id).await?;
+
+        // Replies must stay inside the thread of the same blog
+        if let Some(parent_id) = parent_id {
+            let parent = self.find_comment_by_id(parent_id).await?;
+            if parent.blog_id != blog_id {
+                return Err(Failure::BadRequest("Parent comment belongs to another blog".to_string()));
+            }
+        }
+
+        let comment = CommentEntity::new(false, blog_id, author_id, parent_id, content);
+        self.repository.create(&comment).await
+    }
+
+    async fn edit_comment(&self, comment_id: &str, author_id: &str, content: &str) -> DomainResponse<CommentEntity> {
+        CommentEntity::validate_content(content)?;
+        self.ensure_not_banned(author_id).await?;
+
+        let mut comment = self.find_own_comment(comment_id, author_id).await?;
+        let now = chrono::Utc::now().timestamp();
+        comment.content = content.to_string();
+        comment.edited_at = Some(now);
+        comment.base.updated_at = now;
+
+        self.repository.update(comment_id, &comment).await
+    }
+
+    async fn delete_comment(&self, comment_id: &str, author_id: &str) -> DomainResponse<()> {
+        self.find_own_comment(comment_id, author_id).await?;
+        self.repository.delete(comment_id).await?;
+        Ok(())
+    }
+
+    async fn find_comment_by_id(&self, comment_id: &str) -> DomainResponse<CommentEntity> {
+        self.repository
+            .find(comment_id)
+            .await?
+            .ok_or(Failure::NotFound(format!("Comment with id {} not found", comment_id)))
+    }
+
+    async fn find_visible_comments(
+        &self,
+        blog_id: &str,
+        page: u32,
+        page_size: u32,
+    ) -> DomainResponse<(Paginate, Vec<CommentEntity>)> {
+        self.repository.find_by_blog_id_paginated(blog_id, CommentStatus::Approved.as_str(), page, page_size).await
+    }
+
+    async fn change_comment_status(&self, comment_id: &str, status: CommentStatus) -> DomainResponse<CommentEntity> {
+        let mut comment = self.find_comment_by_id(comment_id).await?;
+        comment.status = status.as_str().to_string();
+        comment.base.updated_at = chrono::Utc::now().timestamp();
+
+        self.repository.update(comment_id, &comment).await
+    }
+
+    async fn ban_author(&self, account_id: &str, banned_by: &str, reason: &str) -> DomainResponse<CommentBanEntity> {
+        if let Some(ban) = self.ban_repository.find_by_account_id(account_id).await? {
+            return Ok(ban);
+        }
+
+        // Hide everything the author already wrote, then block new comments
+        self.repository.update_status_by_author_id(account_id, CommentStatus::Hidden.as_str()).await?;
+
+        let ban = CommentBanEntity::new(false, account_id, banned_by, reason);
+        self.ban_repository.create(&ban).await
+    }
+
+    async fn find_comments_by_author(&self, author_id: &str) -> DomainResponse<Vec<CommentEntity>> {
+        self.repository.find_by_author_id(author_id).await
+    }
+
+    async fn remove_comments_by_author_id(&self, author_id: &str) -> DomainResponse<usize> {
+        self.repository.remove_by_author_id(author_id).await
+    }
+}
@@ -0,0 +1,310 @@
+use async_trait::async_trait;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+// shared modules
+use shared::models::failure::Failure;
+use shared::models::filters::QuerySpec;
+use shared::models::paginate::Paginate;
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::entities::{chapter_entity::ChapterEntity, course_entity::CourseEntity, lesson_entity::LessonEntity};
+use crate::repositories::{
+    chapter_repository::ChapterRepository, course_repository::CourseRepository, lesson_repository::LessonRepository,
+};
+use crate::values::course_status::CourseStatus;
+
+#[async_trait]
+pub trait CourseService: Send + Sync {
+    async fn create_course(&self, course: &CourseEntity) -> DomainResponse<CourseEntity>;
+    async fn update_course(&self, course: &CourseEntity) -> DomainResponse<CourseEntity>;
+    async fn find_course_by_id(&self, course_id: &str) -> DomainResponse<CourseEntity>;
+    async fn find_published_courses(
+        &self,
+        category_ids: Option<&[String]>,
+        level: Option<&str>,
+        spec: &QuerySpec,
+        page: u32,
+        page_size: u32,
+    ) -> DomainResponse<(Paginate, Vec<CourseEntity>)>;
+
+    async fn find_courses_by_author(&self, author_id: &str) -> DomainResponse<Vec<CourseEntity>>;
+
+    /// Publishing requires at least one lesson.
+    async fn change_course_status(&self, course: &CourseEntity, status: CourseStatus) -> DomainResponse<CourseEntity>;
+
+    /// Soft delete the course together with its chapters and lessons.
+    async fn delete_course(&self, course_id: &str) -> DomainResponse<()>;
+
+    async fn find_chapters(&self, course_id: &str) -> DomainResponse<Vec<ChapterEntity>>;
+    async fn find_chapter_by_id(&self, chapter_id: &str) -> DomainResponse<ChapterEntity>;
+
+    /// Appends the chapter when `order_index` is `None`.
+    async fn add_chapter(&self, chapter: ChapterEntity, order_index: Option<i32>) -> DomainResponse<ChapterEntity>;
+    async fn update_chapter(&self, chapter: &ChapterEntity) -> DomainResponse<ChapterEntity>;
+    async fn delete_chapter(&self, chapter: &ChapterEntity) -> DomainResponse<()>;
+
+    /// `chapter_ids` must list every chapter of the course exactly once.
+    async fn reorder_chapters(&self, course_id: &str, chapter_ids: &[String]) -> DomainResponse<Vec<ChapterEntity>>;
+
+    async fn find_lessons(&self, chapter_ids: &[String]) -> DomainResponse<Vec<LessonEntity>>;
+    async fn find_lesson_by_id(&self, lesson_id: &str) -> DomainResponse<LessonEntity>;
+
+    /// Appends the lesson when `order_index` is `None`.
+    async fn add_lesson(&self, lesson: LessonEntity, order_index: Option<i32>) -> DomainResponse<LessonEntity>;
+    async fn update_lesson(&self, lesson: &LessonEntity) -> DomainResponse<LessonEntity>;
+    async fn delete_lesson(&self, lesson_id: &str) -> DomainResponse<()>;
+
+    /// `lesson_ids` must list every lesson of the chapter exactly once.
+    async fn reorder_lessons(&self, chapter_id: &str, lesson_ids: &[String]) -> DomainResponse<Vec<LessonEntity>>;
+
+    /// Recompute `approximate` from the lesson estimates.
+    async fn refresh_course_duration(&self, course_id: &str) -> DomainResponse<CourseEntity>;
+
+    async fn reassign_course_author(&self, from: &str, to: &str) -> DomainResponse<usize>;
+
+    /// Hard remove every course of an author with its chapters and lessons, soft deleted ones included.
+    async fn remove_courses_by_author(&self, author_id: &str) -> DomainResponse<usize>;
+}
+
+pub struct CourseServiceImpl {
+    course_repository: Arc<dyn CourseRepository>,
+    chapter_repository: Arc<dyn ChapterRepository>,
+    lesson_repository: Arc<dyn LessonRepository>,
+}
+
+impl CourseServiceImpl {
+    pub fn new(
+        course_repository: Arc<dyn CourseRepository>,
+        chapter_repository: Arc<dyn ChapterRepository>,
+        lesson_repository: Arc<dyn LessonRepository>,
+    ) -> Self {
+        Self { course_repository, chapter_repository, lesson_repository }
+    }
+
+    async fn find_course_lessons(&self, course_id: &str) -> DomainResponse<Vec<LessonEntity>> {
+        let chapter_ids: Vec<String> =
+            self.find_chapters(course_id).await?.into_iter().filter_map(|chapter| chapter.base.id).collect();
+        self.find_lessons(&chapter_ids).await
+    }
+
+    /// Fails unless `ids` and `existing` hold the same ids, each exactly once.
+    fn ensure_same_ids(ids: &[String], existing: &[Option<String>], kind: &str) -> DomainResponse<()> {
+        let given: HashSet<&str> = ids.iter().map(String::as_str).collect();
+        let current: HashSet<&str> = existing.iter().filter_map(|id| id.as_deref()).collect();
+
+        if given.len() != ids.len() || given != current {
+            return Err(Failure::ValidationError(format!("The new order must list every {} exactly once", kind)));
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl CourseService for CourseServiceImpl {
+    async fn create_course(&self, course: &CourseEntity) -> DomainResponse<CourseEntity> {
+        course.validate()?;
+        self.course_repository.create(course).await
+    }
+
+    async fn update_course(&self, course: &CourseEntity) -> DomainResponse<CourseEntity> {
+        course.validate()?;
+
+        let mut course = course.clone();
+        course.base.updated_at = chrono::Utc::now().timestamp();
+        self.course_repository.update_details(&course).await
+    }
+
+    async fn find_course_by_id(&self, course_id: &str) -> DomainResponse<CourseEntity> {
+        self.course_repository
+            .find(course_id)
+            .await?
+            .ok_or(Failure::NotFound(format!("Course with id {} not found", course_id)))
+    }
+
+    async fn find_published_courses(
+        &self,
+        category_ids: Option<&[String]>,
+        level: Option<&str>,
+        spec: &QuerySpec,
+        page: u32,
+        page_size: u32,
+    ) -> DomainResponse<(Paginate, Vec<CourseEntity>)> {
+        self.course_repository.find_published_paginated(category_ids, level, spec, page, page_size).await
+    }
+
+    async fn find_courses_by_author(&self, author_id: &str) -> DomainResponse<Vec<CourseEntity>> {
+        self.course_repository.find_by_author_id(author_id).await
+    }
+
+    async fn change_course_status(&self, course: &CourseEntity, status: CourseStatus) -> DomainResponse<CourseEntity> {
+        let course_id = course.base.id.clone().unwrap_or_default();
+        if status == CourseStatus::Published && self.find_course_lessons(&course_id).await?.is_empty() {
+            return Err(Failure::BadRequest("A course needs at least one lesson before it is published".to_string()));
+        }
+
+        let mut course = course.clone();
+        course.transition(status)?;
+        self.course_repository.update_details(&course).await
+    }
+
+    async fn delete_course(&self, course_id: &str) -> DomainResponse<()> {
+        for chapter in self.find_chapters(course_id).await? {
+            self.delete_chapter(&chapter).await?;
+        }
+
+        self.course_repository.delete(course_id).await?;
+        Ok(())
+    }
+
+    async fn find_chapters(&self, course_id: &str) -> DomainResponse<Vec<ChapterEntity>> {
+        self.chapter_repository.find_by_course_id(course_id).await
+    }
+
+    async fn find_chapter_by_id(&self, chapter_id: &str) -> DomainResponse<ChapterEntity> {
+        self.chapter_repository
+            .find(chapter_id)
+            .await?
+            .ok_or(Failure::NotFound(format!("Chapter with id {} not found", chapter_id)))
+    }
+
+    async fn add_chapter(&self, mut chapter: ChapterEntity, order_index: Option<i32>) -> DomainResponse<ChapterEntity> {
+        chapter.order_index = match order_index {
+            Some(order_index) => order_index,
+            None => self.find_chapters(&chapter.course_id).await?.last().map_or(0, |last| last.order_index + 1),
+        };
+
+        chapter.validate()?;
+        self.chapter_repository.create(&chapter).await
+    }
+
+    async fn update_chapter(&self, chapter: &ChapterEntity) -> DomainResponse<ChapterEntity> {
+        chapter.validate()?;
+
+        let mut chapter = chapter.clone();
+        chapter.base.updated_at = chrono::Utc::now().timestamp();
+        let chapter_id = chapter.base.id.clone().unwrap_or_default();
+        self.chapter_repository.update(&chapter_id, &chapter).await
+    }
+
+    async fn delete_chapter(&self, chapter: &ChapterEntity) -> DomainResponse<()> {
+        let chapter_id = chapter.base.id.clone().unwrap_or_default();
+        for lesson in self.find_lessons(std::slice::from_ref(&chapter_id)).await? {
+            self.lesson_repository.delete(&lesson.base.id.unwrap_or_default()).await?;
+        }
+
+        self.chapter_repository.delete(&chapter_id).await?;
+        Ok(())
+    }
+
+    async fn reorder_chapters(&self, course_id: &str, chapter_ids: &[String]) -> DomainResponse<Vec<ChapterEntity>> {
+        let chapters = self.find_chapters(course_id).await?;
+        let existing: Vec<Option<String>> = chapters.iter().map(|chapter| chapter.base.id.clone()).collect();
+        Self::ensure_same_ids(chapter_ids, &existing, "chapter")?;
+
+        let now = chrono::Utc::now().timestamp();
+        let mut reordered = Vec::with_capacity(chapters.len());
+        for (order_index, chapter_id) in chapter_ids.iter().enumerate() {
+            let mut chapter =
+                chapters.iter().find(|chapter| chapter.base.id.as_ref() == Some(chapter_id)).cloned().unwrap();
+            if chapter.order_index != order_index as i32 {
+                chapter.order_index = order_index as i32;
+                chapter.base.updated_at = now;
+                chapter = self.chapter_repository.update(chapter_id, &chapter).await?;
+            }
+            reordered.push(chapter);
+        }
+
+        Ok(reordered)
+    }
+
+    async fn find_lessons(&self, chapter_ids: &[String]) -> DomainResponse<Vec<LessonEntity>> {
+        if chapter_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        self.lesson_repository.find_by_chapter_ids(chapter_ids).await
+    }
+
+    async fn find_lesson_by_id(&self, lesson_id: &str) -> DomainResponse<LessonEntity> {
+        self.lesson_repository
+            .find(lesson_id)
+            .await?
+            .ok_or(Failure::NotFound(format!("Lesson with id {} not found", lesson_id)))
+    }
+
+    async fn add_lesson(&self, mut lesson: LessonEntity, order_index: Option<i32>) -> DomainResponse<LessonEntity> {
+        lesson.order_index = match order_index {
+            Some(order_index) => order_index,
+            None => {
+                let lessons = self.find_lessons(std::slice::from_ref(&lesson.chapter_id)).await?;
+                lessons.last().map_or(0, |last| last.order_index + 1)
+            },
+        };
+
+        lesson.validate()?;
+        self.lesson_repository.create(&lesson).await
+    }
+
+    async fn update_lesson(&self, lesson: &LessonEntity) -> DomainResponse<LessonEntity> {
+        lesson.validate()?;
+
+        let mut lesson = lesson.clone();
+        lesson.base.updated_at = chrono::Utc::now().timestamp();
+        let lesson_id = lesson.base.id.clone().unwrap_or_default();
+        self.lesson_repository.update(&lesson_id, &lesson).await
+    }
+
+    async fn delete_lesson(&self, lesson_id: &str) -> DomainResponse<()> {
+        self.lesson_repository.delete(lesson_id).await?;
+        Ok(())
+    }
+
+    async fn reorder_lessons(&self, chapter_id: &str, lesson_ids: &[String]) -> DomainResponse<Vec<LessonEntity>> {
+        let lessons = self.find_lessons(&[chapter_id.to_string()]).await?;
+        let existing: Vec<Option<String>> = lessons.iter().map(|lesson| lesson.base.id.clone()).collect();
+        Self::ensure_same_ids(lesson_ids, &existing, "lesson")?;
+
+        let now = chrono::Utc::now().timestamp();
+        let mut reordered = Vec::with_capacity(lessons.len());
+        for (order_index, lesson_id) in lesson_ids.iter().enumerate() {
+            let mut lesson = lessons.iter().find(|lesson| lesson.base.id.as_ref() == Some(lesson_id)).cloned().unwrap();
+            if lesson.order_index != order_index as i32 {
+                lesson.order_index = order_index as i32;
+                lesson.base.updated_at = now;
+                lesson = self.lesson_repository.update(lesson_id, &lesson).await?;
+            }
+            reordered.push(lesson);
+        }
+
+        Ok(reordered)
+    }
+
+    async fn refresh_course_duration(&self, course_id: &str) -> DomainResponse<CourseEntity> {
+        let mut course = self.find_course_by_id(course_id).await?;
+        let lessons = self.find_course_lessons(course_id).await?;
+        let approximate = lessons.iter().map(|lesson| lesson.estimated_time_ms as i64).sum::<i64>();
+
+        course.approximate = approximate.min(i32::MAX as i64) as i32;
+        course.base.updated_at = chrono::Utc::now().timestamp();
+        self.course_repository.update_details(&course).await
+    }
+
+    async fn reassign_course_author(&self, from: &str, to: &str) -> DomainResponse<usize> {
+        self.course_repository.replace_author(from, to).await
+    }
+
+    async fn remove_courses_by_author(&self, author_id: &str) -> DomainResponse<usize> {
+        let courses = self.course_repository.find_and_remove_by_author_id(author_id).await?;
+        for course in &courses {
+            let course_id = course.base.id.clone().unwrap_or_default();
+            for chapter in self.chapter_repository.find_and_remove_by_course_id(&course_id).await? {
+                self.lesson_repository.remove_by_chapter_id(&chapter.base.id.unwrap_or_default()).await?;
+            }
+        }
+
+        Ok(courses.len())
+    }
+}
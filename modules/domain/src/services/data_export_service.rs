@@ -0,0 +1,153 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+
+// shared modules
+use shared::configs::APP_CONFIG;
+use shared::models::failure::Failure;
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::apis::archive_api::{ArchiveApi, ArchiveEntry};
+use crate::apis::blob_storage::{Blob, BlobStorage};
+use crate::entities::data_export_entity::DataExportEntity;
+use crate::repositories::data_export_repository::DataExportRepository;
+
+// Archives live outside every public media folder, they are only handed out by the download endpoint
+static EXPORT_FOLDER: &str = "exports";
+static ARCHIVE_CONTENT_TYPE: &str = "application/zip";
+
+#[async_trait]
+pub trait DataExportService: Send + Sync {
+    /// Queue an export, an account has at most one export pending at a time.
+    async fn request_export(&self, account_id: &str) -> DomainResponse<DataExportEntity>;
+    async fn find_exports(&self, account_id: &str) -> DomainResponse<Vec<DataExportEntity>>;
+
+    /// Fails with not found unless the export belongs to the account.
+    async fn find_own_export(&self, account_id: &str, export_id: &str) -> DomainResponse<DataExportEntity>;
+    async fn find_pending_exports(&self, limit: u32) -> DomainResponse<Vec<DataExportEntity>>;
+
+    /// Zip the entries, store the archive and keep it downloadable for the configured retention.
+    async fn complete_export(
+        &self,
+        export: &DataExportEntity,
+        entries: Vec<ArchiveEntry>,
+    ) -> DomainResponse<DataExportEntity>;
+    async fn fail_export(&self, export: &DataExportEntity, reason: &str) -> DomainResponse<DataExportEntity>;
+
+    /// Fails with conflict until the archive is ready.
+    async fn download_export(&self, export: &DataExportEntity) -> DomainResponse<Blob>;
+    async fn collect_expired(&self, expires_before: i64, limit: u32) -> DomainResponse<usize>;
+
+    /// Delete every export of the account along with its archive.
+    async fn remove_exports_by_account_id(&self, account_id: &str) -> DomainResponse<usize>;
+}
+
+pub struct DataExportServiceImpl {
+    repository: Arc<dyn DataExportRepository>,
+    blob_storage: Arc<dyn BlobStorage>,
+    archive_api: Arc<dyn ArchiveApi>,
+}
+
+impl DataExportServiceImpl {
+    pub fn new(
+        repository: Arc<dyn DataExportRepository>,
+        blob_storage: Arc<dyn BlobStorage>,
+        archive_api: Arc<dyn ArchiveApi>,
+    ) -> Self {
+        Self { repository, blob_storage, archive_api }
+    }
+
+    async fn remove_export(&self, export: &DataExportEntity) -> DomainResponse<()> {
+        if let Some(storage_key) = &export.storage_key {
+            self.blob_storage.delete(storage_key).await?;
+        }
+
+        self.repository.remove(&export.base.id.clone().unwrap_or_default()).await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl DataExportService for DataExportServiceImpl {
+    async fn request_export(&self, account_id: &str) -> DomainResponse<DataExportEntity> {
+        let exports = self.repository.find_by_account_id(account_id).await?;
+        if exports.iter().any(|export| export.is_pending()) {
+            return Err(Failure::Conflict("An export of this account is already in progress".to_string()));
+        }
+
+        let export = DataExportEntity::new(false, account_id);
+        self.repository.create(&export).await
+    }
+
+    async fn find_exports(&self, account_id: &str) -> DomainResponse<Vec<DataExportEntity>> {
+        self.repository.find_by_account_id(account_id).await
+    }
+
+    async fn find_own_export(&self, account_id: &str, export_id: &str) -> DomainResponse<DataExportEntity> {
+        self.repository
+            .find(export_id)
+            .await?
+            .filter(|export| export.account_id == account_id)
+            .ok_or_else(|| Failure::NotFound(format!("Export with id {} not found", export_id)))
+    }
+
+    async fn find_pending_exports(&self, limit: u32) -> DomainResponse<Vec<DataExportEntity>> {
+        self.repository.find_pending(limit).await
+    }
+
+    async fn complete_export(
+        &self,
+        export: &DataExportEntity,
+        entries: Vec<ArchiveEntry>,
+    ) -> DomainResponse<DataExportEntity> {
+        let archive = self.archive_api.zip(entries).await?;
+        let size = archive.len() as i64;
+
+        let export_id = export.base.id.clone().unwrap_or_default();
+        let storage_key = format!("{}/{}/{}.zip", EXPORT_FOLDER, export.account_id, export_id);
+        self.blob_storage.put(&storage_key, archive, ARCHIVE_CONTENT_TYPE).await?;
+
+        let mut export = export.clone();
+        export.complete(&storage_key, size, APP_CONFIG.accounts.export_retention_seconds);
+        self.repository.update(&export_id, &export).await
+    }
+
+    async fn fail_export(&self, export: &DataExportEntity, reason: &str) -> DomainResponse<DataExportEntity> {
+        let mut export = export.clone();
+        export.fail(reason);
+
+        let export_id = export.base.id.clone().unwrap_or_default();
+        self.repository.update(&export_id, &export).await
+    }
+
+    async fn download_export(&self, export: &DataExportEntity) -> DomainResponse<Blob> {
+        let storage_key = export
+            .storage_key
+            .as_deref()
+            .filter(|_| export.is_ready())
+            .ok_or_else(|| Failure::Conflict("The export is not ready yet".to_string()))?;
+
+        self.blob_storage
+            .get(storage_key)
+            .await?
+            .ok_or_else(|| Failure::NotFound("The export archive no longer exists".to_string()))
+    }
+
+    async fn collect_expired(&self, expires_before: i64, limit: u32) -> DomainResponse<usize> {
+        let expired = self.repository.find_expired(expires_before, limit).await?;
+        for export in &expired {
+            self.remove_export(export).await?;
+        }
+
+        Ok(expired.len())
+    }
+
+    async fn remove_exports_by_account_id(&self, account_id: &str) -> DomainResponse<usize> {
+        let exports = self.repository.find_by_account_id(account_id).await?;
+        for export in &exports {
+            self.remove_export(export).await?;
+        }
+
+        Ok(exports.len())
+    }
+}
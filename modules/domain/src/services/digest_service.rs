@@ -0,0 +1,122 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+
+// shared modules
+use shared::configs::APP_CONFIG;
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::apis::mail_api::{Mail, MailApi};
+use crate::entities::{
+    notification_entity::NotificationEntity, notification_preference_entity::NotificationPreferenceEntity,
+};
+use crate::repositories::{
+    account_repository::AccountRepository, notification_preference_repository::NotificationPreferenceRepository,
+    notification_repository::NotificationRepository,
+};
+use crate::usecases::notification_usecases::DigestResult;
+use crate::values::{digest_frequency::DigestFrequency, notification_channel::NotificationChannel};
+
+// Digests handled per run, the rest stays due for the next one
+static DIGEST_BATCH_SIZE: u32 = 200;
+
+// Notifications listed in one digest, the mail links to the inbox for the rest
+static DIGEST_NOTIFICATION_LIMIT: u32 = 50;
+
+#[async_trait]
+pub trait DigestService: Send + Sync {
+    /// Mail every account whose digest is due the unread notifications it gets by email. Digests falling
+    /// into quiet hours are postponed until they end, failed mails stay due for the next run.
+    async fn send_due_digests(&self, now: i64) -> DomainResponse<DigestResult>;
+}
+
+pub struct DigestServiceImpl {
+    preference_repository: Arc<dyn NotificationPreferenceRepository>,
+    notification_repository: Arc<dyn NotificationRepository>,
+    account_repository: Arc<dyn AccountRepository>,
+    mail_api: Arc<dyn MailApi>,
+}
+
+impl DigestServiceImpl {
+    pub fn new(
+        preference_repository: Arc<dyn NotificationPreferenceRepository>,
+        notification_repository: Arc<dyn NotificationRepository>,
+        account_repository: Arc<dyn AccountRepository>,
+        mail_api: Arc<dyn MailApi>,
+    ) -> Self {
+        Self { preference_repository, notification_repository, account_repository, mail_api }
+    }
+
+    async fn save(&self, preferences: &NotificationPreferenceEntity) -> DomainResponse<()> {
+        let preference_id = preferences.base.id.clone().unwrap_or_default();
+        self.preference_repository.update(&preference_id, preferences).await?;
+        Ok(())
+    }
+
+    fn compose(email: &str, frequency: DigestFrequency, notifications: &[NotificationEntity]) -> Mail {
+        let period = match frequency {
+            DigestFrequency::Weekly => "weekly",
+            _ => "daily",
+        };
+        let subject = match notifications.len() {
+            1 => format!("Your {} digest: 1 unread notification", period),
+            count => format!("Your {} digest: {} unread notifications", period, count),
+        };
+
+        let lines: Vec<String> =
+            notifications.iter().map(|notification| format!("- {}", notification.message)).collect();
+        let text = format!(
+            "Here is what happened since your last digest:\n\n{}\n\nSee all your notifications at {}\n\nYou can change how often you get this email in your notification preferences.",
+            lines.join("\n"),
+            APP_CONFIG.site.public_url("/notifications"),
+        );
+
+        Mail { to: email.to_string(), subject, text, html: None }
+    }
+}
+
+#[async_trait]
+impl DigestService for DigestServiceImpl {
+    async fn send_due_digests(&self, now: i64) -> DomainResponse<DigestResult> {
+        let mut result = DigestResult::default();
+
+        for mut preferences in self.preference_repository.find_digest_due(now, DIGEST_BATCH_SIZE).await? {
+            if preferences.is_quiet_at(now) {
+                preferences.next_digest_at = Some(preferences.quiet_hours_end_after(now));
+                self.save(&preferences).await?;
+                result.postponed += 1;
+                continue;
+            }
+
+            let since = preferences.digest_since(now);
+            let notifications: Vec<NotificationEntity> = self
+                .notification_repository
+                .find_unread_since(&preferences.account_id, since, DIGEST_NOTIFICATION_LIMIT)
+                .await?
+                .into_iter()
+                .filter(|notification| preferences.allows(&notification.kind, NotificationChannel::Email))
+                .collect();
+            let account = self.account_repository.find(&preferences.account_id).await?;
+
+            // Nothing new or nobody to mail, the period still counts as covered
+            let Some(account) = account.filter(|account| account.is_active && !notifications.is_empty()) else {
+                preferences.mark_digest_sent(now);
+                self.save(&preferences).await?;
+                result.skipped += 1;
+                continue;
+            };
+
+            let mail = Self::compose(&account.email, preferences.frequency(), &notifications);
+            if self.mail_api.send_mail(&mail).await.is_err() {
+                result.failed += 1;
+                continue;
+            }
+
+            preferences.mark_digest_sent(now);
+            self.save(&preferences).await?;
+            result.sent += 1;
+        }
+
+        Ok(result)
+    }
+}
@@ -0,0 +1,170 @@
+use async_trait::async_trait;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+// shared modules
+use shared::models::failure::Failure;
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::entities::{enrollment_entity::EnrollmentEntity, progress_entity::ProgressEntity};
+use crate::repositories::{
+    course_repository::CourseRepository, enrollment_repository::EnrollmentRepository,
+    progress_repository::ProgressRepository,
+};
+
+#[async_trait]
+pub trait EnrollmentService: Send + Sync {
+    /// Fails with a conflict when the account is already enrolled.
+    async fn enroll(&self, account_id: &str, course_id: &str) -> DomainResponse<EnrollmentEntity>;
+
+    /// Remove the enrollment together with its progress, the account may enroll again.
+    async fn unenroll(&self, enrollment: &EnrollmentEntity) -> DomainResponse<()>;
+
+    async fn find_enrollment(&self, account_id: &str, course_id: &str) -> DomainResponse<Option<EnrollmentEntity>>;
+    async fn find_enrollments_by_account(&self, account_id: &str) -> DomainResponse<Vec<EnrollmentEntity>>;
+    async fn find_enrollments_by_course(&self, course_id: &str) -> DomainResponse<Vec<EnrollmentEntity>>;
+    async fn find_progresses(&self, enrollment_ids: &[String]) -> DomainResponse<Vec<ProgressEntity>>;
+
+    /// Count a lesson visit and mark it completed when asked.
+    async fn record_progress(
+        &self,
+        enrollment: &EnrollmentEntity,
+        lesson_id: &str,
+        time_spend_second: i32,
+        completed: bool,
+    ) -> DomainResponse<ProgressEntity>;
+
+    /// Recompute the progress percentage against the current lessons of the course.
+    async fn refresh_enrollment(
+        &self,
+        enrollment: &EnrollmentEntity,
+        lesson_ids: &[String],
+    ) -> DomainResponse<EnrollmentEntity>;
+
+    /// Remove every enrollment of the account together with its progress, the courses lose them from their count.
+    async fn remove_enrollments_by_account_id(&self, account_id: &str) -> DomainResponse<usize>;
+}
+
+pub struct EnrollmentServiceImpl {
+    enrollment_repository: Arc<dyn EnrollmentRepository>,
+    progress_repository: Arc<dyn ProgressRepository>,
+    course_repository: Arc<dyn CourseRepository>,
+}
+
+impl EnrollmentServiceImpl {
+    pub fn new(
+        enrollment_repository: Arc<dyn EnrollmentRepository>,
+        progress_repository: Arc<dyn ProgressRepository>,
+        course_repository: Arc<dyn CourseRepository>,
+    ) -> Self {
+        Self { enrollment_repository, progress_repository, course_repository }
+    }
+}
+
+#[async_trait]
+impl EnrollmentService for EnrollmentServiceImpl {
+    async fn enroll(&self, account_id: &str, course_id: &str) -> DomainResponse<EnrollmentEntity> {
+        if self.find_enrollment(account_id, course_id).await?.is_some() {
+            return Err(Failure::Conflict("You are already enrolled in this course".to_string()));
+        }
+
+        let enrollment = self
+            .enrollment_repository
+            .create_if_absent(&EnrollmentEntity::new(true, account_id, course_id))
+            .await?
+            .ok_or(Failure::Conflict("You are already enrolled in this course".to_string()))?;
+        self.course_repository.increment_enrollments(course_id, 1).await?;
+
+        Ok(enrollment)
+    }
+
+    async fn unenroll(&self, enrollment: &EnrollmentEntity) -> DomainResponse<()> {
+        let enrollment_id = enrollment.base.id.clone().unwrap_or_default();
+        for progress in self.find_progresses(std::slice::from_ref(&enrollment_id)).await? {
+            self.progress_repository.remove(&progress.base.id.unwrap_or_default()).await?;
+        }
+
+        self.enrollment_repository.remove(&enrollment_id).await?;
+        self.course_repository.increment_enrollments(&enrollment.course_id, -1).await
+    }
+
+    async fn find_enrollment(&self, account_id: &str, course_id: &str) -> DomainResponse<Option<EnrollmentEntity>> {
+        self.enrollment_repository.find_by_account_and_course(account_id, course_id).await
+    }
+
+    async fn find_enrollments_by_account(&self, account_id: &str) -> DomainResponse<Vec<EnrollmentEntity>> {
+        self.enrollment_repository.find_by_account_id(account_id).await
+    }
+
+    async fn find_enrollments_by_course(&self, course_id: &str) -> DomainResponse<Vec<EnrollmentEntity>> {
+        self.enrollment_repository.find_by_course_id(course_id).await
+    }
+
+    async fn find_progresses(&self, enrollment_ids: &[String]) -> DomainResponse<Vec<ProgressEntity>> {
+        if enrollment_ids.is_empty() {
+            return Ok(vec![]);
+        }
+
+        self.progress_repository.find_by_enrollment_ids(enrollment_ids).await
+    }
+
+    async fn record_progress(
+        &self,
+        enrollment: &EnrollmentEntity,
+        lesson_id: &str,
+        time_spend_second: i32,
+        completed: bool,
+    ) -> DomainResponse<ProgressEntity> {
+        let enrollment_id = enrollment.base.id.clone().unwrap_or_default();
+        match self.progress_repository.find_by_enrollment_and_lesson(&enrollment_id, lesson_id).await? {
+            Some(mut progress) => {
+                progress.record_visit(time_spend_second, completed);
+                let progress_id = progress.base.id.clone().unwrap_or_default();
+                self.progress_repository.update(&progress_id, &progress).await
+            },
+            None => {
+                let mut progress = ProgressEntity::new(true, &enrollment_id, lesson_id);
+                progress.record_visit(time_spend_second, completed);
+                self.progress_repository.create(&progress).await
+            },
+        }
+    }
+
+    async fn refresh_enrollment(
+        &self,
+        enrollment: &EnrollmentEntity,
+        lesson_ids: &[String],
+    ) -> DomainResponse<EnrollmentEntity> {
+        let enrollment_id = enrollment.base.id.clone().unwrap_or_default();
+        let lesson_ids: HashSet<&str> = lesson_ids.iter().map(String::as_str).collect();
+
+        // Progress on lessons removed from the course no longer counts
+        let completed_lessons = self
+            .find_progresses(std::slice::from_ref(&enrollment_id))
+            .await?
+            .iter()
+            .filter(|progress| progress.is_completed && lesson_ids.contains(progress.lesson_id.as_str()))
+            .count();
+
+        let mut enrollment = enrollment.clone();
+        enrollment.apply_progress(completed_lessons, lesson_ids.len());
+        self.enrollment_repository.update(&enrollment_id, &enrollment).await
+    }
+
+    async fn remove_enrollments_by_account_id(&self, account_id: &str) -> DomainResponse<usize> {
+        let enrollments = self.enrollment_repository.find_by_account_id(account_id).await?;
+        let enrollment_ids: Vec<String> =
+            enrollments.iter().map(|enrollment| enrollment.base.id.clone().unwrap_or_default()).collect();
+        if !enrollment_ids.is_empty() {
+            self.progress_repository.remove_by_enrollment_ids(&enrollment_ids).await?;
+        }
+
+        // Unenrolled ones already left the count
+        for enrollment in &enrollments {
+            self.course_repository.increment_enrollments(&enrollment.course_id, -1).await?;
+        }
+
+        self.enrollment_repository.remove_by_account_id(account_id).await
+    }
+}
@@ -0,0 +1,216 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+
+// shared modules
+use shared::configs::APP_CONFIG;
+use shared::models::failure::Failure;
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::apis::{
+    blob_storage::{Blob, BlobStorage},
+    image_processor::ImageProcessor,
+};
+use crate::entities::media_asset_entity::MediaAssetEntity;
+use crate::repositories::media_asset_repository::MediaAssetRepository;
+use crate::usecases::media_usecases::MediaUpload;
+use crate::values::{image_format::ImageFormat, media_purpose::MediaPurpose, upload_purpose::UploadPurpose};
+
+// Every variant is stored as WebP
+static OUTPUT_FORMAT: ImageFormat = ImageFormat::Webp;
+
+#[async_trait]
+pub trait MediaService: Send + Sync {
+    /// Validate the upload, store one WebP per configured variant and record the asset, detached until
+    /// something references it.
+    async fn upload(
+        &self,
+        account_id: &str,
+        purpose: MediaPurpose,
+        upload: &MediaUpload,
+    ) -> DomainResponse<MediaAssetEntity>;
+
+    async fn find_asset(&self, asset_id: &str) -> DomainResponse<MediaAssetEntity>;
+    async fn attach(&self, asset: &MediaAssetEntity, reference_id: &str) -> DomainResponse<MediaAssetEntity>;
+
+    /// Detach the other assets of the purpose the entity referenced, for fields holding a single asset.
+    async fn detach_others(&self, asset: &MediaAssetEntity) -> DomainResponse<usize>;
+    async fn detach(&self, asset: &MediaAssetEntity) -> DomainResponse<MediaAssetEntity>;
+    async fn find_blob(&self, key: &str) -> DomainResponse<Blob>;
+    async fn collect_orphans(&self, detached_before: i64, limit: u32) -> DomainResponse<usize>;
+
+    async fn find_assets_by_account(&self, account_id: &str) -> DomainResponse<Vec<MediaAssetEntity>>;
+
+    /// Remove every asset the account uploaded along with its stored variants.
+    async fn remove_assets_by_account_id(&self, account_id: &str) -> DomainResponse<usize>;
+}
+
+pub struct MediaServiceImpl {
+    repository: Arc<dyn MediaAssetRepository>,
+    blob_storage: Arc<dyn BlobStorage>,
+    image_processor: Arc<dyn ImageProcessor>,
+}
+
+impl MediaServiceImpl {
+    pub fn new(
+        repository: Arc<dyn MediaAssetRepository>,
+        blob_storage: Arc<dyn BlobStorage>,
+        image_processor: Arc<dyn ImageProcessor>,
+    ) -> Self {
+        Self { repository, blob_storage, image_processor }
+    }
+
+    /// The format of the upload, which has to fit the size limit and be what it claims to be.
+    fn validate_upload(upload: &MediaUpload) -> DomainResponse<ImageFormat> {
+        if upload.bytes.is_empty() {
+            return Err(Failure::ValidationError("The file is empty".to_string()));
+        }
+        if upload.bytes.len() > APP_CONFIG.media.max_upload_bytes {
+            return Err(Failure::ValidationError(format!(
+                "The file must not be larger than {} bytes",
+                APP_CONFIG.media.max_upload_bytes
+            )));
+        }
+
+        let declared = ImageFormat::from_mime_type(&upload.content_type)?;
+        let detected = ImageFormat::from_magic_bytes(&upload.bytes)?;
+        if declared != detected {
+            return Err(Failure::ValidationError(format!(
+                "The file is declared as {} but its content is {}",
+                declared.as_str(),
+                detected.as_str()
+            )));
+        }
+
+        Ok(detected)
+    }
+
+    async fn delete_blobs(&self, keys: &[String]) -> DomainResponse<()> {
+        for key in keys {
+            self.blob_storage.delete(key).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn save(&self, asset: &MediaAssetEntity) -> DomainResponse<MediaAssetEntity> {
+        let asset_id = asset.base.id.clone().unwrap_or_default();
+        self.repository.update(&asset_id, asset).await
+    }
+}
+
+#[async_trait]
+impl MediaService for MediaServiceImpl {
+    async fn upload(
+        &self,
+        account_id: &str,
+        purpose: MediaPurpose,
+        upload: &MediaUpload,
+    ) -> DomainResponse<MediaAssetEntity> {
+        let format = Self::validate_upload(upload)?;
+        let variants = purpose.variants();
+        let images = self.image_processor.process(&upload.bytes, format, &variants).await?;
+        let Some(widest) = images.iter().max_by_key(|image| image.width) else {
+            return Err(Failure::InternalServerError(format!("No image variants configured for {}", purpose.as_str())));
+        };
+
+        let mut asset = MediaAssetEntity::new(
+            true,
+            account_id,
+            purpose,
+            &upload.file_name,
+            OUTPUT_FORMAT.mime_type(),
+            upload.bytes.len() as i64,
+            widest.width as i32,
+            widest.height as i32,
+        );
+        let asset_id = asset.base.id.clone().unwrap_or_default();
+
+        for image in images {
+            let key = format!("{}/{}/{}.webp", purpose.folder(), asset_id, image.variant);
+            if let Err(failure) = self.blob_storage.put(&key, image.bytes, OUTPUT_FORMAT.mime_type()).await {
+                // Do not leave the variants stored so far behind without a record pointing at them
+                self.delete_blobs(&asset.storage_keys).await?;
+                return Err(failure);
+            }
+
+            asset.variants.insert(image.variant, self.blob_storage.public_url(&key));
+            asset.storage_keys.push(key);
+        }
+
+        self.repository.create(&asset).await
+    }
+
+    async fn find_asset(&self, asset_id: &str) -> DomainResponse<MediaAssetEntity> {
+        self.repository
+            .find(asset_id)
+            .await?
+            .ok_or_else(|| Failure::NotFound(format!("Media asset with id {} not found", asset_id)))
+    }
+
+    async fn attach(&self, asset: &MediaAssetEntity, reference_id: &str) -> DomainResponse<MediaAssetEntity> {
+        let mut asset = asset.clone();
+        asset.attach(reference_id);
+        self.save(&asset).await
+    }
+
+    async fn detach_others(&self, asset: &MediaAssetEntity) -> DomainResponse<usize> {
+        let Some(reference_id) = asset.reference_id.as_deref() else {
+            return Ok(0);
+        };
+
+        let mut detached = 0;
+        for mut other in self.repository.find_by_reference(&asset.purpose, reference_id).await? {
+            if other.base.id == asset.base.id {
+                continue;
+            }
+
+            other.detach();
+            self.save(&other).await?;
+            detached += 1;
+        }
+
+        Ok(detached)
+    }
+
+    async fn detach(&self, asset: &MediaAssetEntity) -> DomainResponse<MediaAssetEntity> {
+        let mut asset = asset.clone();
+        asset.detach();
+        self.save(&asset).await
+    }
+
+    async fn find_blob(&self, key: &str) -> DomainResponse<Blob> {
+        // Storage also holds private files such as data exports, only media and upload folders are public
+        let folders = MediaPurpose::ALL.iter().map(|purpose| purpose.folder());
+        let mut folders = folders.chain(UploadPurpose::ALL.iter().map(|purpose| purpose.folder()));
+        if !folders.any(|folder| key.strip_prefix(folder).is_some_and(|rest| rest.starts_with('/'))) {
+            return Err(Failure::NotFound(format!("File {} not found", key)));
+        }
+
+        self.blob_storage.get(key).await?.ok_or_else(|| Failure::NotFound(format!("File {} not found", key)))
+    }
+
+    async fn collect_orphans(&self, detached_before: i64, limit: u32) -> DomainResponse<usize> {
+        let orphans = self.repository.find_orphans(detached_before, limit).await?;
+        for orphan in &orphans {
+            self.delete_blobs(&orphan.storage_keys).await?;
+            self.repository.remove(&orphan.base.id.clone().unwrap_or_default()).await?;
+        }
+
+        Ok(orphans.len())
+    }
+
+    async fn find_assets_by_account(&self, account_id: &str) -> DomainResponse<Vec<MediaAssetEntity>> {
+        self.repository.find_by_account_id(account_id).await
+    }
+
+    async fn remove_assets_by_account_id(&self, account_id: &str) -> DomainResponse<usize> {
+        let assets = self.repository.find_by_account_id(account_id).await?;
+        for asset in &assets {
+            self.delete_blobs(&asset.storage_keys).await?;
+            self.repository.remove(&asset.base.id.clone().unwrap_or_default()).await?;
+        }
+
+        Ok(assets.len())
+    }
+}
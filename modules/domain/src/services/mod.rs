@@ -1,10 +1,25 @@
 pub mod account_service;
+pub mod audit_service;
 pub mod auth_service;
+pub mod blog_engagement_service;
+pub mod blog_revision_service;
 pub mod blog_service;
+pub mod bookmark_service;
 pub mod category_service;
+pub mod comment_service;
+pub mod course_service;
+pub mod data_export_service;
+pub mod digest_service;
+pub mod enrollment_service;
 pub mod experience_service;
+pub mod media_service;
+pub mod notification_preference_service;
 pub mod notification_service;
 pub mod project_service;
 pub mod provider_service;
+pub mod push_service;
+pub mod review_service;
 pub mod role_service;
+pub mod search_service;
 pub mod session_service;
+pub mod upload_service;
@@ -0,0 +1,58 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+
+// shared modules
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::entities::{base_entity::BaseEntity, notification_preference_entity::NotificationPreferenceEntity};
+use crate::repositories::notification_preference_repository::NotificationPreferenceRepository;
+
+#[async_trait]
+pub trait NotificationPreferenceService: Send + Sync {
+    /// Accounts that never saved preferences get the defaults, without storing them.
+    async fn find_preferences(&self, account_id: &str) -> DomainResponse<NotificationPreferenceEntity>;
+    async fn save_preferences(
+        &self,
+        preferences: &NotificationPreferenceEntity,
+    ) -> DomainResponse<NotificationPreferenceEntity>;
+    async fn remove_preferences_by_account_id(&self, account_id: &str) -> DomainResponse<usize>;
+}
+
+pub struct NotificationPreferenceServiceImpl {
+    preference_repository: Arc<dyn NotificationPreferenceRepository>,
+}
+
+impl NotificationPreferenceServiceImpl {
+    pub fn new(preference_repository: Arc<dyn NotificationPreferenceRepository>) -> Self {
+        Self { preference_repository }
+    }
+}
+
+#[async_trait]
+impl NotificationPreferenceService for NotificationPreferenceServiceImpl {
+    async fn find_preferences(&self, account_id: &str) -> DomainResponse<NotificationPreferenceEntity> {
+        let preferences = self.preference_repository.find_by_account_id(account_id).await?;
+        Ok(preferences.unwrap_or_else(|| NotificationPreferenceEntity::new(false, account_id)))
+    }
+
+    async fn save_preferences(
+        &self,
+        preferences: &NotificationPreferenceEntity,
+    ) -> DomainResponse<NotificationPreferenceEntity> {
+        let mut preferences = preferences.clone();
+        preferences.base.updated_at = chrono::Utc::now().timestamp();
+
+        match preferences.base.id.clone() {
+            Some(preference_id) => self.preference_repository.update(&preference_id, &preferences).await,
+            None => {
+                preferences.base.id = BaseEntity::new(true).id;
+                self.preference_repository.create(&preferences).await
+            },
+        }
+    }
+
+    async fn remove_preferences_by_account_id(&self, account_id: &str) -> DomainResponse<usize> {
+        self.preference_repository.remove_by_account_id(account_id).await
+    }
+}
@@ -1,22 +1,168 @@
 use async_trait::async_trait;
 use std::sync::Arc;
 
+// shared modules
+use shared::models::failure::Failure;
+use shared::models::paginate::Paginate;
+use shared::types::DomainResponse;
+
 // internal modules
-use crate::repositories::notification_repository::NotificationRepository;
+use crate::apis::event_hub::{EventHub, RealtimeEvent};
+use crate::entities::notification_entity::NotificationEntity;
+use crate::repositories::{
+    notification_preference_repository::NotificationPreferenceRepository,
+    notification_repository::NotificationRepository,
+};
+use crate::values::{notification_channel::NotificationChannel, realtime_event_kind::RealtimeEventKind};
 
 #[async_trait]
-pub trait NotificationService: Send + Sync {}
+pub trait NotificationService: Send + Sync {
+    /// Store the notification and publish it unless the account muted in-app notifications of its kind. Muted
+    /// ones are still stored, the other channels such as the email digest decide for themselves.
+    async fn create_notification(&self, notification: &NotificationEntity) -> DomainResponse<NotificationEntity>;
+    async fn find_notifications(
+        &self,
+        account_id: &str,
+        unread_only: bool,
+        page: u32,
+        page_size: u32,
+    ) -> DomainResponse<(Paginate, Vec<NotificationEntity>)>;
+
+    /// Fails with not found unless the notification belongs to the account.
+    async fn find_own_notification(
+        &self,
+        account_id: &str,
+        notification_id: &str,
+    ) -> DomainResponse<NotificationEntity>;
+
+    async fn count_unread(&self, account_id: &str) -> DomainResponse<u64>;
+    async fn mark_read(&self, notification: &NotificationEntity) -> DomainResponse<NotificationEntity>;
+    async fn mark_all_read(&self, account_id: &str) -> DomainResponse<usize>;
+    async fn delete_notification(&self, notification: &NotificationEntity) -> DomainResponse<()>;
+
+    /// Hard delete every notification of the account, without publishing an event per notification.
+    async fn remove_notifications_by_account_id(&self, account_id: &str) -> DomainResponse<usize>;
+}
 
-#[allow(dead_code)]
 pub struct NotificationServiceImpl {
     repository: Arc<dyn NotificationRepository>,
+    preference_repository: Arc<dyn NotificationPreferenceRepository>,
+    event_hub: Arc<dyn EventHub>,
 }
 
 impl NotificationServiceImpl {
-    pub fn new(repository: Arc<dyn NotificationRepository>) -> Self {
-        Self { repository }
+    pub fn new(
+        repository: Arc<dyn NotificationRepository>,
+        preference_repository: Arc<dyn NotificationPreferenceRepository>,
+        event_hub: Arc<dyn EventHub>,
+    ) -> Self {
+        Self { repository, preference_repository, event_hub }
+    }
+
+    /// Let the live sessions of the account know, the change itself is already stored.
+    async fn publish(&self, account_id: &str, kind: RealtimeEventKind, data: serde_json::Value) {
+        self.event_hub.publish(RealtimeEvent::new(account_id, kind, data)).await;
+    }
+
+    /// The kinds the account muted in-app, they stay out of its notification list.
+    async fn in_app_muted_kinds(&self, account_id: &str) -> DomainResponse<Vec<String>> {
+        let preferences = self.preference_repository.find_by_account_id(account_id).await?;
+        Ok(preferences.map(|preferences| preferences.muted_kinds(NotificationChannel::InApp)).unwrap_or_default())
+    }
+
+    fn to_value(notification: &NotificationEntity) -> serde_json::Value {
+        serde_json::to_value(notification).unwrap_or_default()
     }
 }
 
 #[async_trait]
-impl NotificationService for NotificationServiceImpl {}
+impl NotificationService for NotificationServiceImpl {
+    async fn create_notification(&self, notification: &NotificationEntity) -> DomainResponse<NotificationEntity> {
+        let notification = self.repository.create(notification).await?;
+
+        let preferences = self.preference_repository.find_by_account_id(&notification.account_id).await?;
+        if preferences.is_none_or(|preferences| {
+            preferences.delivers(&notification.kind, NotificationChannel::InApp, notification.base.created_at)
+        }) {
+            let value = Self::to_value(&notification);
+            self.publish(&notification.account_id, RealtimeEventKind::NotificationCreated, value).await;
+        }
+
+        Ok(notification)
+    }
+
+    async fn find_notifications(
+        &self,
+        account_id: &str,
+        unread_only: bool,
+        page: u32,
+        page_size: u32,
+    ) -> DomainResponse<(Paginate, Vec<NotificationEntity>)> {
+        let muted_kinds = self.in_app_muted_kinds(account_id).await?;
+        self.repository.find_by_account_id_paginated(account_id, unread_only, &muted_kinds, page, page_size).await
+    }
+
+    async fn find_own_notification(
+        &self,
+        account_id: &str,
+        notification_id: &str,
+    ) -> DomainResponse<NotificationEntity> {
+        self.repository
+            .find(notification_id)
+            .await?
+            .filter(|notification| notification.account_id == account_id)
+            .ok_or(Failure::NotFound(format!("Notification with id {} not found", notification_id)))
+    }
+
+    async fn count_unread(&self, account_id: &str) -> DomainResponse<u64> {
+        let muted_kinds = self.in_app_muted_kinds(account_id).await?;
+        self.repository.count_unread(account_id, &muted_kinds).await
+    }
+
+    async fn mark_read(&self, notification: &NotificationEntity) -> DomainResponse<NotificationEntity> {
+        if notification.is_read {
+            return Ok(notification.clone());
+        }
+
+        let mut notification = notification.clone();
+        notification.mark_read();
+        let notification_id = notification.base.id.clone().unwrap_or_default();
+        let notification = self.repository.update(&notification_id, &notification).await?;
+        self.publish(&notification.account_id, RealtimeEventKind::NotificationRead, Self::to_value(&notification))
+            .await;
+
+        Ok(notification)
+    }
+
+    async fn mark_all_read(&self, account_id: &str) -> DomainResponse<usize> {
+        let muted_kinds = self.in_app_muted_kinds(account_id).await?;
+        let updated = self.repository.mark_all_read(account_id, &muted_kinds, chrono::Utc::now().timestamp()).await?;
+        if updated > 0 {
+            self.publish(
+                account_id,
+                RealtimeEventKind::NotificationsReadAll,
+                serde_json::json!({ "updated": updated }),
+            )
+            .await;
+        }
+
+        Ok(updated)
+    }
+
+    async fn delete_notification(&self, notification: &NotificationEntity) -> DomainResponse<()> {
+        let notification_id = notification.base.id.clone().unwrap_or_default();
+        self.repository.delete(&notification_id).await?;
+        self.publish(
+            &notification.account_id,
+            RealtimeEventKind::NotificationDeleted,
+            serde_json::json!({ "id": notification_id }),
+        )
+        .await;
+
+        Ok(())
+    }
+
+    async fn remove_notifications_by_account_id(&self, account_id: &str) -> DomainResponse<usize> {
+        self.repository.remove_by_account_id(account_id).await
+    }
+}
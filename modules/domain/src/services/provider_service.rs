@@ -16,6 +16,7 @@ pub trait ProviderService: Send + Sync {
     -> DomainResponse<ProviderEntity>;
 
     async fn find_by_account_id(&self, account_id: &str) -> DomainResponse<Vec<ProviderEntity>>;
+    async fn remove_providers_by_account_id(&self, account_id: &str) -> DomainResponse<usize>;
 }
 
 pub struct ProviderServiceImpl {
@@ -45,4 +46,8 @@ impl ProviderService for ProviderServiceImpl {
     async fn find_by_account_id(&self, account_id: &str) -> DomainResponse<Vec<ProviderEntity>> {
         self.repository.find_by_account_id(account_id).await
     }
+
+    async fn remove_providers_by_account_id(&self, account_id: &str) -> DomainResponse<usize> {
+        self.repository.remove_by_account_id(account_id).await
+    }
 }
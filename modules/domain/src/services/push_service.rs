@@ -0,0 +1,225 @@
+use async_trait::async_trait;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+// shared modules
+use shared::models::failure::Failure;
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::apis::messaging_api::{MessagingApi, PushDelivery};
+use crate::entities::{
+    notification_preference_entity::NotificationPreferenceEntity, session_entity::SessionEntity,
+    topic_subscription_entity::TopicSubscriptionEntity,
+};
+use crate::repositories::{
+    notification_preference_repository::NotificationPreferenceRepository, session_repository::SessionRepository,
+    topic_subscription_repository::TopicSubscriptionRepository,
+};
+use crate::usecases::notification_usecases::{PushNotification, SendNotificationResponse};
+use crate::values::{notification_channel::NotificationChannel, notification_kind::NotificationKind};
+
+#[async_trait]
+pub trait PushService: Send + Sync {
+    /// Attach the device token to the session, taking it away from any other session first, and join
+    /// the topics the account subscribed to.
+    async fn register_device(&self, session: &SessionEntity, device_token: &str) -> DomainResponse<SessionEntity>;
+    async fn unregister_device(&self, session: &SessionEntity) -> DomainResponse<SessionEntity>;
+
+    /// Push to every device of the account, forgetting the tokens the transport reports as dead. Nothing is
+    /// sent when the account muted pushes of this kind or is in its quiet hours.
+    async fn push_to_account(
+        &self,
+        account_id: &str,
+        kind: NotificationKind,
+        notification: &PushNotification,
+    ) -> DomainResponse<SendNotificationResponse>;
+
+    async fn push_to_topic(
+        &self,
+        topic: &str,
+        notification: &PushNotification,
+    ) -> DomainResponse<SendNotificationResponse>;
+
+    async fn find_topics(&self, account_id: &str) -> DomainResponse<Vec<TopicSubscriptionEntity>>;
+    async fn subscribe_topic(&self, account_id: &str, topic: &str) -> DomainResponse<TopicSubscriptionEntity>;
+    async fn unsubscribe_topic(&self, account_id: &str, topic: &str) -> DomainResponse<()>;
+
+    /// Leave every topic of the account on its devices and forget the subscriptions, before its sessions go.
+    async fn remove_topics_by_account_id(&self, account_id: &str) -> DomainResponse<usize>;
+}
+
+pub struct PushServiceImpl {
+    session_repository: Arc<dyn SessionRepository>,
+    topic_subscription_repository: Arc<dyn TopicSubscriptionRepository>,
+    preference_repository: Arc<dyn NotificationPreferenceRepository>,
+    messaging_api: Arc<dyn MessagingApi>,
+}
+
+impl PushServiceImpl {
+    pub fn new(
+        session_repository: Arc<dyn SessionRepository>,
+        topic_subscription_repository: Arc<dyn TopicSubscriptionRepository>,
+        preference_repository: Arc<dyn NotificationPreferenceRepository>,
+        messaging_api: Arc<dyn MessagingApi>,
+    ) -> Self {
+        Self { session_repository, topic_subscription_repository, preference_repository, messaging_api }
+    }
+
+    async fn find_preferences(&self, account_id: &str) -> DomainResponse<NotificationPreferenceEntity> {
+        let preferences = self.preference_repository.find_by_account_id(account_id).await?;
+        Ok(preferences.unwrap_or_else(|| NotificationPreferenceEntity::new(false, account_id)))
+    }
+
+    async fn find_device_tokens(&self, account_id: &str) -> DomainResponse<Vec<String>> {
+        let sessions = self.session_repository.find_with_device_token(account_id).await?;
+        let tokens: HashSet<String> = sessions.into_iter().filter_map(|session| session.device_token).collect();
+
+        Ok(tokens.into_iter().collect())
+    }
+
+    async fn prune_tokens(&self, tokens: &[String]) -> DomainResponse<usize> {
+        for token in tokens {
+            self.session_repository.clear_device_token(token).await?;
+        }
+
+        Ok(tokens.len())
+    }
+
+    async fn save_session(&self, session: &SessionEntity) -> DomainResponse<SessionEntity> {
+        let session_id = session.base.id.clone().unwrap_or_default();
+        self.session_repository.update(&session_id, session).await
+    }
+}
+
+#[async_trait]
+impl PushService for PushServiceImpl {
+    async fn register_device(&self, session: &SessionEntity, device_token: &str) -> DomainResponse<SessionEntity> {
+        if session.device_token.as_deref() == Some(device_token) {
+            return Ok(session.clone());
+        }
+
+        // A device signing in again must not receive every push twice
+        self.session_repository.clear_device_token(device_token).await?;
+
+        let mut session = session.clone();
+        session.device_token = Some(device_token.to_string());
+        session.base.updated_at = chrono::Utc::now().timestamp();
+        let session = self.save_session(&session).await?;
+
+        let tokens = vec![device_token.to_string()];
+        for subscription in self.topic_subscription_repository.find_by_account_id(&session.account_id).await? {
+            let invalid = self.messaging_api.subscribe_to_topic(&tokens, &subscription.topic).await?;
+            if !invalid.is_empty() {
+                self.prune_tokens(&invalid).await?;
+                return Err(Failure::BadRequest("The device token is not valid".to_string()));
+            }
+        }
+
+        Ok(session)
+    }
+
+    async fn unregister_device(&self, session: &SessionEntity) -> DomainResponse<SessionEntity> {
+        let Some(device_token) = session.device_token.clone() else {
+            return Ok(session.clone());
+        };
+
+        let tokens = vec![device_token];
+        for subscription in self.topic_subscription_repository.find_by_account_id(&session.account_id).await? {
+            // The token is forgotten below either way, invalid ones need no extra pruning
+            self.messaging_api.unsubscribe_from_topic(&tokens, &subscription.topic).await?;
+        }
+
+        let mut session = session.clone();
+        session.device_token = None;
+        session.base.updated_at = chrono::Utc::now().timestamp();
+        self.save_session(&session).await
+    }
+
+    async fn push_to_account(
+        &self,
+        account_id: &str,
+        kind: NotificationKind,
+        notification: &PushNotification,
+    ) -> DomainResponse<SendNotificationResponse> {
+        let preferences = self.find_preferences(account_id).await?;
+        if !preferences.delivers(kind.as_str(), NotificationChannel::Push, chrono::Utc::now().timestamp()) {
+            return Ok(SendNotificationResponse { suppressed: true, ..Default::default() });
+        }
+
+        let mut response = SendNotificationResponse::default();
+        let mut invalid = vec![];
+
+        for token in self.find_device_tokens(account_id).await? {
+            // One unreachable device must not keep the others from receiving the push
+            match self.messaging_api.send_push_notification(&token, notification).await {
+                Ok(PushDelivery::Sent(message_id)) => response.message_ids.push(message_id),
+                Ok(PushDelivery::InvalidToken) => invalid.push(token),
+                Err(_) => response.failed_tokens += 1,
+            }
+        }
+
+        response.pruned_tokens = self.prune_tokens(&invalid).await?;
+        Ok(response)
+    }
+
+    async fn push_to_topic(
+        &self,
+        topic: &str,
+        notification: &PushNotification,
+    ) -> DomainResponse<SendNotificationResponse> {
+        TopicSubscriptionEntity::validate_topic(topic)?;
+        let message_id = self.messaging_api.send_push_to_topic(topic, notification).await?;
+
+        Ok(SendNotificationResponse { message_ids: vec![message_id], ..Default::default() })
+    }
+
+    async fn find_topics(&self, account_id: &str) -> DomainResponse<Vec<TopicSubscriptionEntity>> {
+        self.topic_subscription_repository.find_by_account_id(account_id).await
+    }
+
+    async fn subscribe_topic(&self, account_id: &str, topic: &str) -> DomainResponse<TopicSubscriptionEntity> {
+        TopicSubscriptionEntity::validate_topic(topic)?;
+        if let Some(subscription) =
+            self.topic_subscription_repository.find_by_account_and_topic(account_id, topic).await?
+        {
+            return Ok(subscription);
+        }
+
+        let tokens = self.find_device_tokens(account_id).await?;
+        if !tokens.is_empty() {
+            let invalid = self.messaging_api.subscribe_to_topic(&tokens, topic).await?;
+            self.prune_tokens(&invalid).await?;
+        }
+
+        self.topic_subscription_repository.create(&TopicSubscriptionEntity::new(true, account_id, topic)).await
+    }
+
+    async fn unsubscribe_topic(&self, account_id: &str, topic: &str) -> DomainResponse<()> {
+        let subscription = self
+            .topic_subscription_repository
+            .find_by_account_and_topic(account_id, topic)
+            .await?
+            .ok_or_else(|| Failure::NotFound(format!("You are not subscribed to topic {}", topic)))?;
+
+        let tokens = self.find_device_tokens(account_id).await?;
+        if !tokens.is_empty() {
+            let invalid = self.messaging_api.unsubscribe_from_topic(&tokens, topic).await?;
+            self.prune_tokens(&invalid).await?;
+        }
+
+        self.topic_subscription_repository.delete(&subscription.base.id.unwrap_or_default()).await?;
+        Ok(())
+    }
+
+    async fn remove_topics_by_account_id(&self, account_id: &str) -> DomainResponse<usize> {
+        let tokens = self.find_device_tokens(account_id).await?;
+        if !tokens.is_empty() {
+            for subscription in self.topic_subscription_repository.find_by_account_id(account_id).await? {
+                self.messaging_api.unsubscribe_from_topic(&tokens, &subscription.topic).await?;
+            }
+        }
+
+        self.topic_subscription_repository.remove_by_account_id(account_id).await
+    }
+}
@@ -0,0 +1,184 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+
+// shared modules
+use shared::models::failure::Failure;
+use shared::models::paginate::Paginate;
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::entities::review_entity::ReviewEntity;
+use crate::repositories::{course_repository::CourseRepository, review_repository::ReviewRepository};
+use crate::values::review_status::ReviewStatus;
+
+#[async_trait]
+pub trait ReviewService: Send + Sync {
+    /// Fails with a conflict when the account already reviewed the course.
+    async fn create_review(&self, review: &ReviewEntity) -> DomainResponse<ReviewEntity>;
+    async fn update_review(
+        &self,
+        review: &ReviewEntity,
+        rating: Option<i32>,
+        content: Option<&str>,
+    ) -> DomainResponse<ReviewEntity>;
+
+    async fn delete_review(&self, review: &ReviewEntity) -> DomainResponse<()>;
+    async fn change_review_status(&self, review: &ReviewEntity, status: ReviewStatus) -> DomainResponse<ReviewEntity>;
+    async fn find_review_by_id(&self, review_id: &str) -> DomainResponse<ReviewEntity>;
+    async fn find_review(&self, course_id: &str, account_id: &str) -> DomainResponse<Option<ReviewEntity>>;
+    async fn find_course_reviews(
+        &self,
+        course_id: &str,
+        page: u32,
+        page_size: u32,
+    ) -> DomainResponse<(Paginate, Vec<ReviewEntity>)>;
+
+    async fn find_reviews_by_status(
+        &self,
+        status: ReviewStatus,
+        page: u32,
+        page_size: u32,
+    ) -> DomainResponse<(Paginate, Vec<ReviewEntity>)>;
+
+    async fn find_reviews_by_account(&self, account_id: &str) -> DomainResponse<Vec<ReviewEntity>>;
+
+    /// Remove every review the account wrote, the approved ones leave the rating of their course.
+    async fn remove_reviews_by_account_id(&self, account_id: &str) -> DomainResponse<usize>;
+}
+
+pub struct ReviewServiceImpl {
+    review_repository: Arc<dyn ReviewRepository>,
+    course_repository: Arc<dyn CourseRepository>,
+}
+
+impl ReviewServiceImpl {
+    pub fn new(review_repository: Arc<dyn ReviewRepository>, course_repository: Arc<dyn CourseRepository>) -> Self {
+        Self { review_repository, course_repository }
+    }
+
+    async fn save(&self, review: &ReviewEntity) -> DomainResponse<ReviewEntity> {
+        let review_id = review.base.id.clone().unwrap_or_default();
+        self.review_repository.update(&review_id, review).await
+    }
+}
+
+#[async_trait]
+impl ReviewService for ReviewServiceImpl {
+    async fn create_review(&self, review: &ReviewEntity) -> DomainResponse<ReviewEntity> {
+        review.validate()?;
+        if self.find_review(&review.course_id, &review.account_id).await?.is_some() {
+            return Err(Failure::Conflict("You already reviewed this course".to_string()));
+        }
+
+        let review = self
+            .review_repository
+            .create_if_absent(review)
+            .await?
+            .ok_or(Failure::Conflict("You already reviewed this course".to_string()))?;
+        self.course_repository.apply_review(&review.course_id, review.rating, 1).await?;
+
+        Ok(review)
+    }
+
+    async fn update_review(
+        &self,
+        review: &ReviewEntity,
+        rating: Option<i32>,
+        content: Option<&str>,
+    ) -> DomainResponse<ReviewEntity> {
+        let mut updated = review.clone();
+        if let Some(rating) = rating {
+            updated.rating = rating;
+        }
+        if let Some(content) = content {
+            updated.content = content.to_string();
+        }
+        updated.validate()?;
+        updated.base.updated_at = chrono::Utc::now().timestamp();
+
+        let updated = self.save(&updated).await?;
+        if updated.is_approved() && updated.rating != review.rating {
+            self.course_repository.apply_review(&updated.course_id, updated.rating - review.rating, 0).await?;
+        }
+
+        Ok(updated)
+    }
+
+    async fn delete_review(&self, review: &ReviewEntity) -> DomainResponse<()> {
+        // Removed rather than soft deleted, the account may review the course again
+        self.review_repository.remove(&review.base.id.clone().unwrap_or_default()).await?;
+        if review.is_approved() {
+            self.course_repository.apply_review(&review.course_id, -review.rating, -1).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn change_review_status(&self, review: &ReviewEntity, status: ReviewStatus) -> DomainResponse<ReviewEntity> {
+        if review.status == status.as_str() {
+            return Ok(review.clone());
+        }
+
+        let mut updated = review.clone();
+        updated.status = status.as_str().to_string();
+        updated.base.updated_at = chrono::Utc::now().timestamp();
+        let updated = self.save(&updated).await?;
+
+        // Hidden reviews leave the course rating, approved ones join it again
+        match status {
+            ReviewStatus::Hidden => {
+                self.course_repository.apply_review(&updated.course_id, -updated.rating, -1).await?
+            },
+            ReviewStatus::Approved => {
+                self.course_repository.apply_review(&updated.course_id, updated.rating, 1).await?
+            },
+        }
+
+        Ok(updated)
+    }
+
+    async fn find_review_by_id(&self, review_id: &str) -> DomainResponse<ReviewEntity> {
+        self.review_repository
+            .find(review_id)
+            .await?
+            .ok_or(Failure::NotFound(format!("Review with id {} not found", review_id)))
+    }
+
+    async fn find_review(&self, course_id: &str, account_id: &str) -> DomainResponse<Option<ReviewEntity>> {
+        self.review_repository.find_by_course_and_account(course_id, account_id).await
+    }
+
+    async fn find_course_reviews(
+        &self,
+        course_id: &str,
+        page: u32,
+        page_size: u32,
+    ) -> DomainResponse<(Paginate, Vec<ReviewEntity>)> {
+        self.review_repository
+            .find_by_course_id_paginated(course_id, ReviewStatus::Approved.as_str(), page, page_size)
+            .await
+    }
+
+    async fn find_reviews_by_status(
+        &self,
+        status: ReviewStatus,
+        page: u32,
+        page_size: u32,
+    ) -> DomainResponse<(Paginate, Vec<ReviewEntity>)> {
+        self.review_repository.find_by_status_paginated(status.as_str(), page, page_size).await
+    }
+
+    async fn find_reviews_by_account(&self, account_id: &str) -> DomainResponse<Vec<ReviewEntity>> {
+        self.review_repository.find_by_account_id(account_id).await
+    }
+
+    async fn remove_reviews_by_account_id(&self, account_id: &str) -> DomainResponse<usize> {
+        for review in self.review_repository.find_by_account_id(account_id).await? {
+            if review.is_approved() {
+                self.course_repository.apply_review(&review.course_id, -review.rating, -1).await?;
+            }
+        }
+
+        self.review_repository.remove_by_account_id(account_id).await
+    }
+}
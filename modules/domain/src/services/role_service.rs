@@ -15,6 +15,8 @@ pub trait RoleService: Send + Sync {
     async fn create_role(&self, account_id: &str, role_name: &str) -> DomainResponse<RoleEntity>;
     async fn find_and_update_role_by_account_id(&self, account_id: &str, role_name: &str)
     -> DomainResponse<RoleEntity>;
+    async fn find_account_ids_by_role(&self, role_name: &str) -> DomainResponse<Vec<String>>;
+    async fn remove_role_by_account_id(&self, account_id: &str) -> DomainResponse<usize>;
 }
 
 pub struct RoleServiceImpl {
@@ -47,4 +49,12 @@ impl RoleService for RoleServiceImpl {
         let role = Role::from_string(role_name)?;
         self.repository.find_and_update_role_by_account_id(account_id, role.as_str()).await
     }
+
+    async fn find_account_ids_by_role(&self, role_name: &str) -> DomainResponse<Vec<String>> {
+        self.repository.find_account_ids_by_role(role_name).await
+    }
+
+    async fn remove_role_by_account_id(&self, account_id: &str) -> DomainResponse<usize> {
+        self.repository.remove_by_account_id(account_id).await
+    }
 }
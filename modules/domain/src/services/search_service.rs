@@ -0,0 +1,113 @@
+use async_trait::async_trait;
+use std::sync::Arc;
+
+// shared modules
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::apis::search_index::{SearchDocument, SearchFilter, SearchIndex};
+use crate::entities::{blog_entity::BlogEntity, project_entity::ProjectEntity};
+use crate::usecases::search_usecases::SearchResult;
+use crate::values::search_kind::SearchKind;
+
+#[async_trait]
+pub trait SearchService: Send + Sync {
+    /// Index a blog while it is readable, otherwise take it out of the index.
+    async fn index_blog(&self, blog: &BlogEntity) -> DomainResponse<()>;
+    async fn index_project(&self, project: &ProjectEntity) -> DomainResponse<()>;
+    async fn remove_document(&self, id: &str) -> DomainResponse<()>;
+    /// Keep the writes made while the documents of a rebuild are read, `rebuild` applies them.
+    async fn begin_rebuild(&self) -> DomainResponse<()>;
+    async fn rebuild(&self, blogs: &[BlogEntity], projects: &[ProjectEntity]) -> DomainResponse<usize>;
+    async fn search(
+        &self,
+        text: &str,
+        filter: &SearchFilter,
+        page: u32,
+        page_size: u32,
+    ) -> DomainResponse<SearchResult>;
+}
+
+pub struct SearchServiceImpl {
+    search_index: Arc<dyn SearchIndex>,
+}
+
+impl SearchServiceImpl {
+    pub fn new(search_index: Arc<dyn SearchIndex>) -> Self {
+        Self { search_index }
+    }
+
+    fn blog_document(blog: &BlogEntity) -> Option<SearchDocument> {
+        Some(SearchDocument {
+            id: blog.base.id.clone()?,
+            kind: SearchKind::Blog.as_str().to_string(),
+            title: blog.name.clone(),
+            description: blog.description.clone(),
+            content: blog.markdown.clone(),
+            categories: blog.categories.clone(),
+            tags: vec![],
+        })
+    }
+
+    fn project_document(project: &ProjectEntity) -> Option<SearchDocument> {
+        Some(SearchDocument {
+            id: project.base.id.clone()?,
+            kind: SearchKind::Project.as_str().to_string(),
+            title: project.name.clone(),
+            description: project.description.clone(),
+            content: project.markdown.clone(),
+            categories: vec![],
+            tags: project.tags.clone(),
+        })
+    }
+}
+
+#[async_trait]
+impl SearchService for SearchServiceImpl {
+    async fn index_blog(&self, blog: &BlogEntity) -> DomainResponse<()> {
+        let Some(document) = Self::blog_document(blog) else {
+            return Ok(());
+        };
+
+        match blog.is_published() && blog.base.deleted_at.is_none() {
+            true => self.search_index.upsert(document).await,
+            false => self.search_index.remove(&document.id).await,
+        }
+    }
+
+    async fn index_project(&self, project: &ProjectEntity) -> DomainResponse<()> {
+        match Self::project_document(project) {
+            Some(document) => self.search_index.upsert(document).await,
+            None => Ok(()),
+        }
+    }
+
+    async fn remove_document(&self, id: &str) -> DomainResponse<()> {
+        self.search_index.remove(id).await
+    }
+
+    async fn begin_rebuild(&self) -> DomainResponse<()> {
+        self.search_index.begin_rebuild().await
+    }
+
+    async fn rebuild(&self, blogs: &[BlogEntity], projects: &[ProjectEntity]) -> DomainResponse<usize> {
+        let documents = blogs
+            .iter()
+            .filter(|blog| blog.is_published())
+            .filter_map(Self::blog_document)
+            .chain(projects.iter().filter_map(Self::project_document))
+            .collect();
+
+        self.search_index.replace_all(documents).await
+    }
+
+    async fn search(
+        &self,
+        text: &str,
+        filter: &SearchFilter,
+        page: u32,
+        page_size: u32,
+    ) -> DomainResponse<SearchResult> {
+        self.search_index.search(text, filter, page, page_size).await
+    }
+}
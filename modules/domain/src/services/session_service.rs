@@ -25,6 +25,7 @@ pub trait SessionService: Send + Sync {
     async fn invalidate_session(&self, session_id: &str) -> DomainResponse<()>;
     async fn clean_session_by_account_id(&self, account_id: &str) -> DomainResponse<()>;
     async fn find_sessions(&self, query: &FindSessionsQuery) -> DomainResponse<Vec<SessionEntity>>;
+    async fn find_sessions_by_account_id(&self, account_id: &str) -> DomainResponse<Vec<SessionEntity>>;
 }
 
 pub struct SessionServiceImpl {
@@ -77,4 +78,8 @@ impl SessionService for SessionServiceImpl {
     async fn find_sessions(&self, _query: &FindSessionsQuery) -> DomainResponse<Vec<SessionEntity>> {
         self.repository.finds().await
     }
+
+    async fn find_sessions_by_account_id(&self, account_id: &str) -> DomainResponse<Vec<SessionEntity>> {
+        self.repository.find_by_account_id(account_id).await
+    }
 }
@@ -0,0 +1,200 @@
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+// shared modules
+use shared::configs::APP_CONFIG;
+use shared::models::failure::Failure;
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::apis::blob_storage::{BlobMetadata, BlobStorage};
+use crate::entities::upload_entity::UploadEntity;
+use crate::repositories::upload_repository::UploadRepository;
+use crate::usecases::upload_usecases::{CreateUploadParams, PresignedUpload};
+use crate::values::upload_purpose::UploadPurpose;
+
+// Presigned PUTs land under this prefix, only the verified copies outside it are served
+static STAGING_FOLDER: &str = "staging";
+
+#[async_trait]
+pub trait UploadService: Send + Sync {
+    /// Validate the declared file, record it as pending and presign the URL it is PUT to.
+    async fn create_upload(
+        &self,
+        account_id: &str,
+        purpose: UploadPurpose,
+        params: &CreateUploadParams,
+    ) -> DomainResponse<PresignedUpload>;
+
+    async fn find_upload(&self, upload_id: &str) -> DomainResponse<UploadEntity>;
+
+    /// Compare the stored file with what was declared and move it out of the staging folder, where the presigned
+    /// URL can no longer replace it. A file that does not match is deleted so the client can upload it again while
+    /// the URL is valid.
+    async fn complete_upload(&self, upload: &UploadEntity) -> DomainResponse<UploadEntity>;
+    async fn collect_expired(&self, expires_before: i64, limit: u32) -> DomainResponse<usize>;
+
+    async fn find_uploads_by_account(&self, account_id: &str) -> DomainResponse<Vec<UploadEntity>>;
+
+    /// Remove every upload of the account along with its stored file.
+    async fn remove_uploads_by_account_id(&self, account_id: &str) -> DomainResponse<usize>;
+}
+
+pub struct UploadServiceImpl {
+    repository: Arc<dyn UploadRepository>,
+    blob_storage: Arc<dyn BlobStorage>,
+}
+
+impl UploadServiceImpl {
+    pub fn new(repository: Arc<dyn UploadRepository>, blob_storage: Arc<dyn BlobStorage>) -> Self {
+        Self { repository, blob_storage }
+    }
+
+    fn validate_params(purpose: UploadPurpose, params: &CreateUploadParams) -> DomainResponse<()> {
+        if !purpose.accepts(&params.content_type) {
+            return Err(Failure::ValidationError(format!(
+                "{} is not accepted for {}",
+                params.content_type,
+                purpose.as_str()
+            )));
+        }
+        if params.size > purpose.max_size() {
+            return Err(Failure::ValidationError(format!(
+                "The file must not be larger than {} bytes",
+                purpose.max_size()
+            )));
+        }
+        if !params.checksum.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(Failure::ValidationError("Checksum must be an MD5 hex digest".to_string()));
+        }
+
+        Ok(())
+    }
+
+    /// Keep keys to characters every storage and URL handles as-is.
+    fn storage_name(file_name: &str) -> String {
+        file_name
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() || matches!(c, '.' | '-' | '_') { c } else { '-' })
+            .collect()
+    }
+
+    /// Why the stored file is not the declared one, `None` when it matches.
+    fn mismatch(upload: &UploadEntity, metadata: &BlobMetadata) -> Option<String> {
+        if metadata.size != upload.size {
+            return Some(format!("The uploaded file is {} bytes, {} were declared", metadata.size, upload.size));
+        }
+        if !metadata.etag.as_deref().is_some_and(|etag| upload.matches_checksum(etag)) {
+            return Some("The uploaded file does not match the declared checksum".to_string());
+        }
+
+        None
+    }
+
+    async fn verify(&self, upload: &UploadEntity, key: &str) -> DomainResponse<()> {
+        let Some(metadata) = self.blob_storage.head(key).await? else {
+            return Err(Failure::ValidationError("The file has not been uploaded yet".to_string()));
+        };
+        if let Some(reason) = Self::mismatch(upload, &metadata) {
+            self.blob_storage.delete(key).await?;
+            return Err(Failure::ValidationError(reason));
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl UploadService for UploadServiceImpl {
+    async fn create_upload(
+        &self,
+        account_id: &str,
+        purpose: UploadPurpose,
+        params: &CreateUploadParams,
+    ) -> DomainResponse<PresignedUpload> {
+        Self::validate_params(purpose, params)?;
+
+        let expires_in = APP_CONFIG.media.presign_expiry_seconds;
+        let mut upload = UploadEntity::new(
+            true,
+            account_id,
+            purpose,
+            &params.reference_id,
+            &params.file_name,
+            &params.content_type,
+            params.size,
+            &params.checksum,
+            expires_in,
+        );
+        let upload_id = upload.base.id.clone().unwrap_or_default();
+        upload.storage_key =
+            format!("{}/{}/{}/{}", STAGING_FOLDER, purpose.folder(), upload_id, Self::storage_name(&params.file_name));
+
+        // Presign first, a backend that cannot should not leave a pending record behind
+        let url = self.blob_storage.presign_put(&upload.storage_key, &upload.content_type, expires_in).await?;
+        let upload = self.repository.create(&upload).await?;
+
+        Ok(PresignedUpload {
+            url,
+            method: "PUT".to_string(),
+            headers: HashMap::from([("Content-Type".to_string(), upload.content_type.clone())]),
+            expires_at: upload.expires_at,
+            upload,
+        })
+    }
+
+    async fn find_upload(&self, upload_id: &str) -> DomainResponse<UploadEntity> {
+        self.repository
+            .find(upload_id)
+            .await?
+            .ok_or_else(|| Failure::NotFound(format!("Upload with id {} not found", upload_id)))
+    }
+
+    async fn complete_upload(&self, upload: &UploadEntity) -> DomainResponse<UploadEntity> {
+        if !upload.is_pending() {
+            return Ok(upload.clone());
+        }
+
+        let staging_key = upload.storage_key.clone();
+        self.verify(upload, &staging_key).await?;
+
+        // The copy is checked again, the file could have been replaced through the URL between the two steps
+        let mut upload = upload.clone();
+        if let Some(storage_key) = staging_key.strip_prefix(&format!("{}/", STAGING_FOLDER)) {
+            self.blob_storage.copy(&staging_key, storage_key).await?;
+            self.blob_storage.delete(&staging_key).await?;
+            self.verify(&upload, storage_key).await?;
+            upload.storage_key = storage_key.to_string();
+        }
+
+        upload.complete(&self.blob_storage.public_url(&upload.storage_key));
+        let upload_id = upload.base.id.clone().unwrap_or_default();
+        self.repository.update(&upload_id, &upload).await
+    }
+
+    async fn collect_expired(&self, expires_before: i64, limit: u32) -> DomainResponse<usize> {
+        let expired = self.repository.find_expired(expires_before, limit).await?;
+        for upload in &expired {
+            // The client may have stored the file without ever completing the upload
+            self.blob_storage.delete(&upload.storage_key).await?;
+            self.repository.remove(&upload.base.id.clone().unwrap_or_default()).await?;
+        }
+
+        Ok(expired.len())
+    }
+
+    async fn find_uploads_by_account(&self, account_id: &str) -> DomainResponse<Vec<UploadEntity>> {
+        self.repository.find_by_account_id(account_id).await
+    }
+
+    async fn remove_uploads_by_account_id(&self, account_id: &str) -> DomainResponse<usize> {
+        let uploads = self.repository.find_by_account_id(account_id).await?;
+        for upload in &uploads {
+            self.blob_storage.delete(&upload.storage_key).await?;
+            self.repository.remove(&upload.base.id.clone().unwrap_or_default()).await?;
+        }
+
+        Ok(uploads.len())
+    }
+}
@@ -1,23 +1,42 @@
 use async_trait::async_trait;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
 use validator::Validate;
 
 // shared modules
 use shared::{
+    functions::deserialize_functions::deserialize_optional_bool_from_string,
     models::paginate::{BasePaginateQuery, Paginate},
     types::DomainResponse,
 };
 
 // internal modules
 use crate::entities::role_entity::RoleEntity;
-use crate::entities::{account_entity::AccountEntity, blog_entity::BlogEntity};
+use crate::entities::{
+    account_entity::AccountEntity, blog_entity::BlogEntity, blog_revision_entity::BlogRevisionEntity,
+};
+use crate::values::text_diff::TextDiff;
 
 // region =================================== MANAGE ACCOUNT USE CASE ===================================
 #[derive(Debug, Clone, Deserialize, Validate)]
 #[serde(rename_all = "camelCase")]
 pub struct FindAccountsQuery {
     #[serde(flatten)]
+    #[validate(nested)]
     pub paginate: BasePaginateQuery,
+    /// Part of the email, case-insensitive.
+    pub email: Option<String>,
+    /// Part of the username, case-insensitive.
+    pub username: Option<String>,
+    pub role: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_optional_bool_from_string")]
+    pub email_verified: Option<bool>,
+    /// `ACTIVE`, `DEACTIVATED`, `SUSPENDED` or `PENDING_DELETION`.
+    pub status: Option<String>,
+    /// `filter[..]`, `sort` and `fields` parameters, see `QuerySpec::parse`.
+    #[serde(flatten)]
+    pub params: HashMap<String, String>,
 }
 
 #[derive(Debug, Clone, Deserialize, Validate)]
@@ -29,7 +48,8 @@ pub struct FindAccountWithEmailQuery {
 
 #[async_trait]
 pub trait ManageAccountsUseCase: Send + Sync {
-    async fn find_accounts(&self, query: &FindAccountsQuery) -> DomainResponse<(Paginate, Vec<AccountEntity>)>;
+    /// Accounts matching every filter of the query, newest first unless it sorts, with only the fields it selects.
+    async fn find_accounts(&self, query: &FindAccountsQuery) -> DomainResponse<(Paginate, Vec<Value>)>;
     async fn find_account_with_id(&self, account_id: &str) -> DomainResponse<Option<AccountEntity>>;
     async fn find_account_with_email(&self, query: &FindAccountWithEmailQuery)
     -> DomainResponse<Option<AccountEntity>>;
@@ -86,11 +106,42 @@ pub struct PublishBlogParams {
 
     #[validate(range(min = 1, message = "Estimated read time must be greater than zero"))]
     pub estimated_read_time: i32,
-    pub is_published: bool,
+
+    #[validate(length(min = 1, message = "Status must not be empty"))]
+    pub status: String,
+    pub publish_at: Option<i64>,
+
+    #[validate(range(min = 1, max = 200, message = "Revision retention must be between 1 and 200"))]
+    pub revision_retention: Option<i32>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct UpdateBlogParams {
+    #[validate(length(min = 1, message = "Name must not be empty"))]
+    pub name: Option<String>,
+
+    #[validate(length(min = 1, message = "Description must not be empty"))]
+    pub description: Option<String>,
+
+    #[validate(length(min = 1, message = "Markdown content must not be empty"))]
+    pub markdown: Option<String>,
+
+    #[validate(length(min = 1, message = "At least one category must be provided"))]
+    pub categories: Option<Vec<String>>,
+
+    #[validate(range(min = 1, message = "Estimated read time must be greater than zero"))]
+    pub estimated_read_time: Option<i32>,
+
+    #[validate(range(min = 1, max = 200, message = "Revision retention must be between 1 and 200"))]
+    pub revision_retention: Option<i32>,
 }
 
 #[derive(Debug, Deserialize, Validate)]
-pub struct UpdateBlogParams {}
+pub struct ChangeBlogStatusParams {
+    #[validate(length(min = 1, message = "Status must not be empty"))]
+    pub status: String,
+    pub publish_at: Option<i64>,
+}
 
 #[async_trait]
 pub trait MangeBlogAccountUseCase: Send + Sync {
@@ -103,6 +154,96 @@ pub trait MangeBlogAccountUseCase: Send + Sync {
         params: &UpdateBlogParams,
     ) -> DomainResponse<BlogEntity>;
 
+    async fn change_account_blog_status(
+        &self,
+        account_id: &str,
+        blog_id: &str,
+        params: &ChangeBlogStatusParams,
+    ) -> DomainResponse<BlogEntity>;
+
     async fn delete_account_blog(&self, account_id: &str, blog_id: &str) -> DomainResponse<()>;
 }
 // endregion =================================== MANAGE BLOG ACCOUNT USE CASE ===================================
+
+// region =================================== MANAGE BLOG REVISION USE CASE ===================================
+#[derive(Debug, Deserialize, Validate)]
+pub struct DiffBlogRevisionsQuery {
+    #[validate(range(min = 1, message = "From version must be greater than zero"))]
+    pub from: i32,
+
+    #[validate(range(min = 1, message = "To version must be greater than zero"))]
+    pub to: i32,
+}
+
+#[async_trait]
+pub trait ManageBlogRevisionUseCase: Send + Sync {
+    async fn find_blog_revisions(&self, account_id: &str, blog_id: &str) -> DomainResponse<Vec<BlogRevisionEntity>>;
+
+    async fn diff_blog_revisions(
+        &self,
+        account_id: &str,
+        blog_id: &str,
+        query: &DiffBlogRevisionsQuery,
+    ) -> DomainResponse<TextDiff>;
+
+    async fn restore_blog_revision(&self, account_id: &str, blog_id: &str, version: i32) -> DomainResponse<BlogEntity>;
+}
+// endregion =================================== MANAGE BLOG REVISION USE CASE ===================================
+
+// region =================================== MANAGE PROFILE USE CASE ===================================
+#[derive(Debug, Clone, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateProfileParams {
+    #[validate(length(min = 3, max = 20, message = "Username must be between 3 and 20 characters"))]
+    pub username: Option<String>,
+    #[validate(length(max = 500, message = "Bio must not exceed 500 characters"))]
+    pub bio: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct FindPublicProfileQuery {
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub paginate: BasePaginateQuery,
+}
+
+/// What anyone may see of an account, without email or account state.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PublicProfile {
+    pub id: String,
+    pub username: String,
+    pub avatar: String,
+    pub bio: String,
+    pub joined_at: i64,
+    pub blogs: Vec<BlogEntity>,
+    pub paginate: Paginate,
+}
+
+impl PublicProfile {
+    pub fn new(account: &AccountEntity, paginate: Paginate, blogs: Vec<BlogEntity>) -> Self {
+        Self {
+            id: account.base.id.clone().unwrap_or_default(),
+            username: account.username.clone(),
+            avatar: account.avatar.clone(),
+            bio: account.bio.clone(),
+            joined_at: account.base.created_at,
+            blogs,
+            paginate,
+        }
+    }
+}
+
+#[async_trait]
+pub trait ManageProfileUseCase: Send + Sync {
+    async fn update_profile(&self, account_id: &str, params: &UpdateProfileParams) -> DomainResponse<AccountEntity>;
+
+    /// The profile of an active account with a page of its published blogs.
+    async fn find_public_profile(
+        &self,
+        username: &str,
+        query: &FindPublicProfileQuery,
+    ) -> DomainResponse<PublicProfile>;
+}
+// endregion =================================== MANAGE PROFILE USE CASE ===================================
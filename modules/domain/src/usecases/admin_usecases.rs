@@ -0,0 +1,62 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+// shared modules
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::entities::{account_entity::AccountEntity, provider_entity::ProviderEntity, session_entity::SessionEntity};
+use crate::usecases::auth_usecases::SessionMetadata;
+
+// region =================================== MODERATE ACCOUNT USE CASE ===================================
+#[derive(Debug, Clone, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct SuspendAccountParams {
+    #[validate(length(min = 1, max = 500, message = "Reason must be between 1 and 500 characters"))]
+    pub reason: String,
+    /// Unix timestamp the suspension ends at, it has to be in the future.
+    pub suspended_until: i64,
+}
+
+#[async_trait]
+pub trait ModerateAccountUseCase: Send + Sync {
+    /// Suspend the account and sign it out everywhere, admins can not be suspended.
+    async fn suspend_account(
+        &self,
+        admin_id: &str,
+        account_id: &str,
+        params: &SuspendAccountParams,
+    ) -> DomainResponse<AccountEntity>;
+    async fn lift_suspension(&self, account_id: &str) -> DomainResponse<AccountEntity>;
+
+    /// Remove every session of the account, its access tokens stop working right away.
+    async fn force_sign_out(&self, account_id: &str) -> DomainResponse<()>;
+
+    /// Linked sign-in providers, without password hashes or provider ids.
+    async fn find_account_providers(&self, account_id: &str) -> DomainResponse<Vec<ProviderEntity>>;
+    async fn find_account_sessions(&self, account_id: &str) -> DomainResponse<Vec<SessionEntity>>;
+}
+// endregion =================================== MODERATE ACCOUNT USE CASE ===================================
+
+// region =================================== IMPERSONATE ACCOUNT USE CASE ===================================
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImpersonationResponse {
+    pub access_token: String,
+    pub account_id: String,
+    pub expires_at: i64,
+}
+
+#[async_trait]
+pub trait ImpersonateAccountUseCase: Send + Sync {
+    /// Issue a short-lived access token of the account carrying the admin as its `act` claim. No refresh token
+    /// is issued and the account keeps its own sessions, admins can not be impersonated.
+    async fn impersonate_account(
+        &self,
+        admin_id: &str,
+        account_id: &str,
+        metadata: &SessionMetadata,
+    ) -> DomainResponse<ImpersonationResponse>;
+}
+// endregion =================================== IMPERSONATE ACCOUNT USE CASE ===================================
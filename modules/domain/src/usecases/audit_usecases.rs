@@ -0,0 +1,73 @@
+use async_trait::async_trait;
+use serde::Deserialize;
+use validator::Validate;
+
+// shared modules
+use shared::{
+    functions::deserialize_functions::deserialize_optional_i64_from_string,
+    models::paginate::{BasePaginateQuery, Paginate},
+    types::DomainResponse,
+};
+
+// internal modules
+use crate::entities::audit_event_entity::AuditEventEntity;
+
+// region =================================== QUERY AUDIT LOG USE CASE ===================================
+/// Filters shared by the audit log search and export, `from` and `to` are unix timestamps.
+#[derive(Debug, Clone, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditEventsFilterQuery {
+    pub actor_id: Option<String>,
+    pub action: Option<String>,
+    pub target_type: Option<String>,
+    pub target_id: Option<String>,
+    #[serde(default, deserialize_with = "deserialize_optional_i64_from_string")]
+    pub from: Option<i64>,
+    #[serde(default, deserialize_with = "deserialize_optional_i64_from_string")]
+    pub to: Option<i64>,
+}
+
+#[derive(Debug, Clone, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct FindAuditEventsQuery {
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub paginate: BasePaginateQuery,
+    #[serde(flatten)]
+    pub filter: AuditEventsFilterQuery,
+}
+
+#[derive(Debug, Clone, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportAuditEventsQuery {
+    #[serde(flatten)]
+    pub filter: AuditEventsFilterQuery,
+    /// `csv` or `json`, defaults to `csv`.
+    pub format: Option<String>,
+}
+
+#[async_trait]
+pub trait QueryAuditLogUseCase: Send + Sync {
+    /// Events matching the filters, newest first. Without `from` the last 30 days before `to` are searched.
+    async fn find_audit_events(
+        &self,
+        query: &FindAuditEventsQuery,
+    ) -> DomainResponse<(Paginate, Vec<AuditEventEntity>)>;
+
+    /// Every event matching the filters up to the export limit, newest first.
+    async fn export_audit_events(&self, query: &ExportAuditEventsQuery) -> DomainResponse<Vec<AuditEventEntity>>;
+}
+// endregion =================================== QUERY AUDIT LOG USE CASE ===================================
+
+// region =================================== RECORD IMPERSONATION USE CASE ===================================
+#[async_trait]
+pub trait RecordImpersonationUseCase: Send + Sync {
+    /// Record an impersonation token turned away from a route only the account itself may use, the actor and
+    /// impersonator come from the audit context of the request.
+    async fn record_denied_impersonation(&self, account_id: &str) -> DomainResponse<()>;
+
+    /// Record a request made with an impersonation token, with its method and path. The actor and impersonator
+    /// come from the audit context of the request.
+    async fn record_impersonated_request(&self, account_id: &str, method: &str, path: &str) -> DomainResponse<()>;
+}
+// endregion =================================== RECORD IMPERSONATION USE CASE ===================================
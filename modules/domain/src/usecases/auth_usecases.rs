@@ -44,11 +44,18 @@ pub struct RefreshTokenParams {
 pub trait ManageSessionAuthUseCase: Send + Sync {
     async fn sign_up(&self, params: &AuthParams) -> Result<AccountEntity, Failure>;
     async fn sign_in(&self, params: &AuthParams, metadata: &SessionMetadata) -> Result<AuthResponse, Failure>;
+
+    /// Sign in a deactivated account with its password and reactivate it, cancelling a scheduled deletion.
+    async fn reactivate(&self, params: &AuthParams, metadata: &SessionMetadata) -> Result<AuthResponse, Failure>;
     async fn refresh_token(
         &self,
         params: &RefreshTokenParams,
         metadata: &SessionMetadata,
     ) -> Result<AuthResponse, Failure>;
+
+    /// Fails with unauthorized once the session of the token ended, and with forbidden when the account was
+    /// deactivated or suspended after the token was issued.
+    async fn ensure_session_active(&self, account_id: &str, jti: &str) -> DomainResponse<()>;
     async fn sign_out(&self) -> DomainResponse<()>;
 }
 // endregion ================================= MANAGE SESSION AUTH USE CASES =================================
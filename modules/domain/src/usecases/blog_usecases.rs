@@ -0,0 +1,60 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use validator::Validate;
+
+// shared modules
+use shared::{
+    models::paginate::{BasePaginateQuery, Paginate},
+    types::DomainResponse,
+};
+
+// internal modules
+use crate::entities::blog_entity::BlogEntity;
+
+// region =================================== MANAGE BLOG USE CASE ===================================
+#[derive(Debug, Clone, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct FindBlogsQuery {
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub paginate: BasePaginateQuery,
+    /// `filter[..]`, `sort` and `fields` parameters, see `QuerySpec::parse`.
+    #[serde(flatten)]
+    pub params: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ToggleBlogStarResponse {
+    pub starred: bool,
+    pub stars: i32,
+}
+
+#[async_trait]
+pub trait ManageBlogUseCase: Send + Sync {
+    /// Published blogs matching the query, with only the fields it selects.
+    async fn find_blogs(&self, query: &FindBlogsQuery) -> DomainResponse<(Paginate, Vec<Value>)>;
+
+    /// `viewer_key` identifies the reader (account or IP) for view deduplication.
+    async fn find_blog(&self, blog_id: &str, viewer_key: &str) -> DomainResponse<BlogEntity>;
+
+    async fn toggle_blog_star(&self, account_id: &str, blog_id: &str) -> DomainResponse<ToggleBlogStarResponse>;
+}
+// endregion =================================== MANAGE BLOG USE CASE ===================================
+
+// region =================================== SCHEDULE BLOG USE CASE ===================================
+#[derive(Debug, Clone, Default)]
+pub struct ScheduledPublishResult {
+    pub published: Vec<BlogEntity>,
+    /// Blog ids with the reason they failed, a blog that could not be published stays due for the next run.
+    pub failed: Vec<(String, String)>,
+}
+
+#[async_trait]
+pub trait ScheduleBlogUseCase: Send + Sync {
+    async fn publish_scheduled_blogs(&self) -> DomainResponse<ScheduledPublishResult>;
+    async fn flush_blog_counters(&self) -> DomainResponse<usize>;
+}
+// endregion =================================== SCHEDULE BLOG USE CASE ===================================
@@ -0,0 +1,59 @@
+use async_trait::async_trait;
+use serde::Deserialize;
+use std::collections::HashMap;
+use validator::Validate;
+
+// shared modules
+use shared::{
+    models::paginate::{BasePaginateQuery, Paginate},
+    types::DomainResponse,
+};
+
+// internal modules
+use crate::entities::bookmark_entity::BookmarkEntity;
+
+// region =================================== BOOKMARK USE CASE ===================================
+#[derive(Debug, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct AddBookmarkParams {
+    /// One of `COURSE`, `LESSON`, `BLOG` or `PROJECT`.
+    pub target_type: String,
+    #[validate(length(min = 1, message = "Target id must not be empty"))]
+    pub target_id: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct FindBookmarksQuery {
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub paginate: BasePaginateQuery,
+    pub target_type: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckBookmarksParams {
+    pub target_type: String,
+    #[validate(length(min = 1, max = 100, message = "Between 1 and 100 target ids can be checked at once"))]
+    pub target_ids: Vec<String>,
+}
+
+#[async_trait]
+pub trait BookmarkUseCase: Send + Sync {
+    async fn add_bookmark(&self, account_id: &str, params: &AddBookmarkParams) -> DomainResponse<BookmarkEntity>;
+    async fn remove_bookmark(&self, account_id: &str, target_type: &str, target_id: &str) -> DomainResponse<()>;
+    async fn find_bookmarks(
+        &self,
+        account_id: &str,
+        query: &FindBookmarksQuery,
+    ) -> DomainResponse<(Paginate, Vec<BookmarkEntity>)>;
+
+    /// Whether each of the targets is bookmarked by the account.
+    async fn check_bookmarks(
+        &self,
+        account_id: &str,
+        params: &CheckBookmarksParams,
+    ) -> DomainResponse<HashMap<String, bool>>;
+}
+// endregion =================================== BOOKMARK USE CASE ===================================
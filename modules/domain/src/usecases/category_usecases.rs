@@ -1,5 +1,5 @@
 use async_trait::async_trait;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use validator::Validate;
 
 // shared modules
@@ -9,9 +9,11 @@ use shared::types::DomainResponse;
 use crate::entities::category_entity::CategoryEntity;
 
 #[derive(Debug, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
 pub struct CreateCategoryParams {
     #[validate(length(min = 1, max = 50, message = "Category name must be between 1 and 50 characters"))]
     pub name: String,
+    pub parent_id: Option<String>,
 }
 
 #[derive(Debug, Deserialize, Validate)]
@@ -20,12 +22,42 @@ pub struct UpdateCategoryParams {
     pub name: String,
 }
 
+#[derive(Debug, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct MoveCategoryParams {
+    /// New parent, `null` moves the category to the root.
+    pub parent_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteCategoryQuery {
+    /// `REFUSE` (default), `REASSIGN` or `CASCADE`.
+    pub strategy: Option<String>,
+    /// Target category, required by `REASSIGN`.
+    pub reassign_to: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CategoryTreeNode {
+    #[serde(flatten)]
+    pub category: CategoryEntity,
+    /// Published blogs tagged with this category.
+    pub blog_count: u32,
+    /// Published blogs tagged with this category or any descendant.
+    pub total_blog_count: u32,
+    pub children: Vec<CategoryTreeNode>,
+}
+
 #[async_trait]
 pub trait ManageCategoryUseCase: Send + Sync {
     async fn create_category(&self, params: &CreateCategoryParams) -> DomainResponse<CategoryEntity>;
     async fn update_category(&self, category_id: &str, params: &UpdateCategoryParams)
     -> DomainResponse<CategoryEntity>;
-    async fn delete_category(&self, category_id: &str) -> DomainResponse<CategoryEntity>;
+    async fn move_category(&self, category_id: &str, params: &MoveCategoryParams) -> DomainResponse<CategoryEntity>;
+    async fn delete_category(&self, category_id: &str, query: &DeleteCategoryQuery) -> DomainResponse<CategoryEntity>;
     async fn find_category_by_id(&self, category_id: &str) -> DomainResponse<CategoryEntity>;
     async fn find_categories(&self) -> DomainResponse<Vec<CategoryEntity>>;
+    async fn find_category_tree(&self) -> DomainResponse<Vec<CategoryTreeNode>>;
 }
@@ -0,0 +1,83 @@
+use async_trait::async_trait;
+use serde::Deserialize;
+use validator::Validate;
+
+// shared modules
+use shared::{
+    models::paginate::{BasePaginateQuery, Paginate},
+    types::DomainResponse,
+};
+
+// internal modules
+use crate::entities::{comment_ban_entity::CommentBanEntity, comment_entity::CommentEntity};
+
+// region =================================== MANAGE COMMENT USE CASE ===================================
+#[derive(Debug, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateCommentParams {
+    #[validate(length(min = 1, message = "Content must not be empty"))]
+    pub content: String,
+    pub parent_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct EditCommentParams {
+    #[validate(length(min = 1, message = "Content must not be empty"))]
+    pub content: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct FindCommentsQuery {
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub paginate: BasePaginateQuery,
+}
+
+#[async_trait]
+pub trait ManageCommentUseCase: Send + Sync {
+    async fn create_comment(
+        &self,
+        account_id: &str,
+        blog_id: &str,
+        params: &CreateCommentParams,
+    ) -> DomainResponse<CommentEntity>;
+
+    async fn edit_comment(
+        &self,
+        account_id: &str,
+        comment_id: &str,
+        params: &EditCommentParams,
+    ) -> DomainResponse<CommentEntity>;
+
+    async fn delete_comment(&self, account_id: &str, comment_id: &str) -> DomainResponse<()>;
+
+    async fn find_blog_comments(
+        &self,
+        blog_id: &str,
+        query: &FindCommentsQuery,
+    ) -> DomainResponse<(Paginate, Vec<CommentEntity>)>;
+}
+// endregion =================================== MANAGE COMMENT USE CASE ===================================
+
+// region =================================== MODERATE COMMENT USE CASE ===================================
+#[derive(Debug, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct BanCommentAuthorParams {
+    #[validate(length(min = 1, max = 500, message = "Reason must be between 1 and 500 characters"))]
+    pub reason: String,
+}
+
+#[async_trait]
+pub trait ModerateCommentUseCase: Send + Sync {
+    async fn hide_comment(&self, comment_id: &str) -> DomainResponse<CommentEntity>;
+    async fn approve_comment(&self, comment_id: &str) -> DomainResponse<CommentEntity>;
+    async fn ban_comment_author(
+        &self,
+        admin_id: &str,
+        comment_id: &str,
+        params: &BanCommentAuthorParams,
+    ) -> DomainResponse<CommentBanEntity>;
+}
+// endregion =================================== MODERATE COMMENT USE CASE ===================================
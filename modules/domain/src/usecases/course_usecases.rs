@@ -0,0 +1,226 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use validator::Validate;
+
+// shared modules
+use shared::{
+    models::paginate::{BasePaginateQuery, Paginate},
+    types::DomainResponse,
+};
+
+// internal modules
+use crate::entities::{chapter_entity::ChapterEntity, course_entity::CourseEntity, lesson_entity::LessonEntity};
+
+// region =================================== MANAGE COURSE USE CASE ===================================
+#[derive(Debug, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateCourseParams {
+    #[validate(length(min = 1, max = 255, message = "Name must be between 1 and 255 characters"))]
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    pub category_id: String,
+    pub level: String,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateCourseParams {
+    #[validate(length(min = 1, max = 255, message = "Name must be between 1 and 255 characters"))]
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub category_id: Option<String>,
+    pub level: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateChapterParams {
+    #[validate(length(min = 1, max = 255, message = "Name must be between 1 and 255 characters"))]
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[validate(range(min = 0, message = "Order index must not be negative"))]
+    pub order_index: Option<i32>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateChapterParams {
+    #[validate(length(min = 1, max = 255, message = "Name must be between 1 and 255 characters"))]
+    pub name: Option<String>,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateLessonParams {
+    #[validate(length(min = 1, max = 255, message = "Name must be between 1 and 255 characters"))]
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    #[serde(default)]
+    pub markdown: String,
+    #[validate(range(min = 0, message = "Estimated time must not be negative"))]
+    pub estimated_time_ms: i32,
+    #[validate(range(min = 0, message = "Order index must not be negative"))]
+    pub order_index: Option<i32>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateLessonParams {
+    #[validate(length(min = 1, max = 255, message = "Name must be between 1 and 255 characters"))]
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub markdown: Option<String>,
+    #[validate(range(min = 0, message = "Estimated time must not be negative"))]
+    pub estimated_time_ms: Option<i32>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct ReorderParams {
+    /// Every child id in its new order.
+    #[validate(length(min = 1, message = "Ids must not be empty"))]
+    pub ids: Vec<String>,
+}
+
+#[async_trait]
+pub trait ManageCourseUseCase: Send + Sync {
+    async fn create_course(&self, account_id: &str, params: &CreateCourseParams) -> DomainResponse<CourseEntity>;
+    async fn update_course(
+        &self,
+        account_id: &str,
+        course_id: &str,
+        params: &UpdateCourseParams,
+    ) -> DomainResponse<CourseEntity>;
+
+    async fn publish_course(&self, account_id: &str, course_id: &str) -> DomainResponse<CourseEntity>;
+    async fn unpublish_course(&self, account_id: &str, course_id: &str) -> DomainResponse<CourseEntity>;
+    async fn archive_course(&self, account_id: &str, course_id: &str) -> DomainResponse<CourseEntity>;
+    async fn delete_course(&self, account_id: &str, course_id: &str) -> DomainResponse<()>;
+    async fn find_own_courses(&self, account_id: &str) -> DomainResponse<Vec<CourseEntity>>;
+
+    async fn add_chapter(
+        &self,
+        account_id: &str,
+        course_id: &str,
+        params: &CreateChapterParams,
+    ) -> DomainResponse<ChapterEntity>;
+
+    async fn update_chapter(
+        &self,
+        account_id: &str,
+        course_id: &str,
+        chapter_id: &str,
+        params: &UpdateChapterParams,
+    ) -> DomainResponse<ChapterEntity>;
+
+    async fn delete_chapter(&self, account_id: &str, course_id: &str, chapter_id: &str) -> DomainResponse<()>;
+    async fn reorder_chapters(
+        &self,
+        account_id: &str,
+        course_id: &str,
+        params: &ReorderParams,
+    ) -> DomainResponse<Vec<ChapterEntity>>;
+
+    async fn add_lesson(
+        &self,
+        account_id: &str,
+        course_id: &str,
+        chapter_id: &str,
+        params: &CreateLessonParams,
+    ) -> DomainResponse<LessonEntity>;
+
+    async fn update_lesson(
+        &self,
+        account_id: &str,
+        course_id: &str,
+        lesson_id: &str,
+        params: &UpdateLessonParams,
+    ) -> DomainResponse<LessonEntity>;
+
+    async fn delete_lesson(&self, account_id: &str, course_id: &str, lesson_id: &str) -> DomainResponse<()>;
+    async fn reorder_lessons(
+        &self,
+        account_id: &str,
+        course_id: &str,
+        chapter_id: &str,
+        params: &ReorderParams,
+    ) -> DomainResponse<Vec<LessonEntity>>;
+}
+// endregion =================================== MANAGE COURSE USE CASE ===================================
+
+// region =================================== BROWSE COURSE USE CASE ===================================
+#[derive(Debug, Clone, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct FindCoursesQuery {
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub paginate: BasePaginateQuery,
+    /// Includes the courses of every subcategory.
+    pub category_id: Option<String>,
+    pub level: Option<String>,
+    /// `filter[..]`, `sort` and `fields` parameters, see `QuerySpec::parse`.
+    #[serde(flatten)]
+    pub params: HashMap<String, String>,
+}
+
+/// Lesson without its content, for course outlines.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LessonOutline {
+    pub id: Option<String>,
+    pub order_index: i32,
+    pub name: String,
+    pub description: String,
+    pub estimated_time_ms: i32,
+}
+
+impl From<LessonEntity> for LessonOutline {
+    fn from(lesson: LessonEntity) -> Self {
+        Self {
+            id: lesson.base.id,
+            order_index: lesson.order_index,
+            name: lesson.name,
+            description: lesson.description,
+            estimated_time_ms: lesson.estimated_time_ms,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChapterOutline {
+    #[serde(flatten)]
+    pub chapter: ChapterEntity,
+    pub lessons: Vec<LessonOutline>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CourseDetail {
+    #[serde(flatten)]
+    pub course: CourseEntity,
+    pub chapters: Vec<ChapterOutline>,
+}
+
+#[async_trait]
+pub trait BrowseCourseUseCase: Send + Sync {
+    /// Published courses matching the query, with only the fields it selects.
+    async fn find_courses(&self, query: &FindCoursesQuery) -> DomainResponse<(Paginate, Vec<Value>)>;
+
+    /// Unpublished courses are only visible to their author.
+    async fn find_course(&self, viewer_id: Option<&str>, course_id: &str) -> DomainResponse<CourseDetail>;
+
+    async fn find_lesson(
+        &self,
+        viewer_id: Option<&str>,
+        course_id: &str,
+        lesson_id: &str,
+    ) -> DomainResponse<LessonEntity>;
+}
+// endregion =================================== BROWSE COURSE USE CASE ===================================
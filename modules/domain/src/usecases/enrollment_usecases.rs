@@ -0,0 +1,89 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+// shared modules
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::entities::{
+    course_entity::CourseEntity, enrollment_entity::EnrollmentEntity, progress_entity::ProgressEntity,
+};
+use crate::usecases::course_usecases::LessonOutline;
+
+// region =================================== LEARN COURSE USE CASE ===================================
+#[derive(Debug, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordProgressParams {
+    #[validate(range(min = 0, max = 86400, message = "Time spent must be between 0 and 86400 seconds"))]
+    pub time_spend_second: i32,
+    #[serde(default)]
+    pub completed: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CourseProgress {
+    pub enrollment: EnrollmentEntity,
+    pub lessons: Vec<ProgressEntity>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ContinueLearning {
+    pub course: CourseEntity,
+    pub enrollment: EnrollmentEntity,
+    /// First lesson of the course, in outline order, that is not completed yet.
+    pub lesson: LessonOutline,
+}
+
+#[async_trait]
+pub trait LearnCourseUseCase: Send + Sync {
+    async fn enroll(&self, account_id: &str, course_id: &str) -> DomainResponse<EnrollmentEntity>;
+    async fn unenroll(&self, account_id: &str, course_id: &str) -> DomainResponse<()>;
+    async fn find_enrollments(&self, account_id: &str) -> DomainResponse<Vec<EnrollmentEntity>>;
+    async fn find_course_progress(&self, account_id: &str, course_id: &str) -> DomainResponse<CourseProgress>;
+    async fn record_progress(
+        &self,
+        account_id: &str,
+        course_id: &str,
+        lesson_id: &str,
+        params: &RecordProgressParams,
+    ) -> DomainResponse<CourseProgress>;
+
+    /// The next lesson of the most recently active, unfinished enrollment.
+    async fn continue_learning(&self, account_id: &str) -> DomainResponse<ContinueLearning>;
+}
+// endregion =================================== LEARN COURSE USE CASE ===================================
+
+// region =================================== COURSE ANALYTICS USE CASE ===================================
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LessonFunnelStep {
+    pub lesson_id: String,
+    pub chapter_id: String,
+    pub name: String,
+    /// Learners that opened the lesson at least once.
+    pub started: u32,
+    pub completed: u32,
+    /// Completed learners in percent of all enrollments.
+    pub completion_rate: f32,
+    pub average_time_spend_second: f32,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CourseAnalytics {
+    pub course_id: String,
+    pub enrollments: u32,
+    pub completed_enrollments: u32,
+    pub average_progress: f32,
+    /// One step per lesson, in outline order.
+    pub funnel: Vec<LessonFunnelStep>,
+}
+
+#[async_trait]
+pub trait CourseAnalyticsUseCase: Send + Sync {
+    async fn find_course_analytics(&self, account_id: &str, course_id: &str) -> DomainResponse<CourseAnalytics>;
+}
+// endregion =================================== COURSE ANALYTICS USE CASE ===================================
@@ -0,0 +1,36 @@
+use async_trait::async_trait;
+
+// shared modules
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::entities::blog_entity::BlogEntity;
+
+// region =================================== BLOG FEED USE CASE ===================================
+#[derive(Debug, Clone)]
+pub enum FeedScope {
+    All,
+    Category(String),
+    Author(String),
+}
+
+#[derive(Debug, Clone)]
+pub struct BlogFeedItem {
+    pub blog: BlogEntity,
+    pub author_name: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct BlogFeed {
+    pub title: String,
+    pub description: String,
+    /// Latest `updated_at` of the items, 0 for an empty feed.
+    pub updated_at: i64,
+    pub items: Vec<BlogFeedItem>,
+}
+
+#[async_trait]
+pub trait BlogFeedUseCase: Send + Sync {
+    async fn find_blog_feed(&self, scope: &FeedScope) -> DomainResponse<BlogFeed>;
+}
+// endregion =================================== BLOG FEED USE CASE ===================================
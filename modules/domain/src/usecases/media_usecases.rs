@@ -0,0 +1,54 @@
+use async_trait::async_trait;
+
+// shared modules
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::apis::blob_storage::Blob;
+use crate::entities::media_asset_entity::MediaAssetEntity;
+
+// ================================ UPLOAD MEDIA ================================
+/// A file received from a multipart form, validated by the media service.
+#[derive(Debug, Clone)]
+pub struct MediaUpload {
+    pub file_name: String,
+    pub content_type: String,
+    pub bytes: Vec<u8>,
+}
+
+#[async_trait]
+pub trait UploadMediaUseCase: Send + Sync {
+    /// Replaces the avatar of the account, the previous one is left for collection.
+    async fn upload_avatar(&self, account_id: &str, upload: &MediaUpload) -> DomainResponse<MediaAssetEntity>;
+
+    /// Only the author can add images to a blog.
+    async fn upload_blog_image(
+        &self,
+        account_id: &str,
+        blog_id: &str,
+        upload: &MediaUpload,
+    ) -> DomainResponse<MediaAssetEntity>;
+
+    /// Replaces the cover of the project, the previous one is left for collection.
+    async fn upload_project_cover(
+        &self,
+        account_id: &str,
+        project_id: &str,
+        upload: &MediaUpload,
+    ) -> DomainResponse<MediaAssetEntity>;
+}
+// ================================ UPLOAD MEDIA ================================
+
+// ================================ MANAGE MEDIA ================================
+#[async_trait]
+pub trait ManageMediaUseCase: Send + Sync {
+    async fn find_asset(&self, asset_id: &str) -> DomainResponse<MediaAssetEntity>;
+
+    /// Only the uploader can delete an asset, avatars and covers in use have to be replaced instead.
+    async fn delete_asset(&self, account_id: &str, asset_id: &str) -> DomainResponse<()>;
+    async fn find_file(&self, key: &str) -> DomainResponse<Blob>;
+
+    /// Delete the files and records of assets nothing referenced for longer than the grace period.
+    async fn collect_orphans(&self) -> DomainResponse<usize>;
+}
+// ================================ MANAGE MEDIA ================================
@@ -1,6 +1,20 @@
 pub mod account_usecases;
+pub mod admin_usecases;
+pub mod audit_usecases;
 pub mod auth_usecases;
+pub mod blog_usecases;
+pub mod bookmark_usecases;
 pub mod category_usecases;
+pub mod comment_usecases;
+pub mod course_usecases;
+pub mod enrollment_usecases;
+pub mod feed_usecases;
 pub mod global_usecases;
+pub mod media_usecases;
 pub mod notification_usecases;
+pub mod privacy_usecases;
+pub mod review_usecases;
+pub mod search_usecases;
 pub mod session_usecases;
+pub mod sitemap_usecases;
+pub mod upload_usecases;
@@ -1,23 +1,41 @@
 use async_trait::async_trait;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use validator::Validate;
 
 // shared modules
-use shared::types::DomainResponse;
+use shared::{
+    models::paginate::{BasePaginateQuery, Paginate},
+    types::DomainResponse,
+};
+
+// internal modules
+use crate::entities::{
+    notification_entity::NotificationEntity, notification_preference_entity::NotificationPreferenceEntity,
+    topic_subscription_entity::TopicSubscriptionEntity,
+};
+use crate::values::{notification_channel::NotificationChannel, notification_kind::NotificationKind};
 
 // ================================ PUSH NOTIFICATION ================================
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Validate)]
 #[serde(rename_all = "camelCase")]
 pub struct PushNotification {
+    #[validate(length(min = 1, max = 200, message = "Title must be between 1 and 200 characters"))]
     pub title: String,
+    #[validate(length(max = 4000, message = "Body must not exceed 4000 characters"))]
     pub body: String,
     pub data: Option<HashMap<String, String>>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SendNotificationResponse {
-    pub message_id: String,
+    pub message_ids: Vec<String>,
+    /// Dead device tokens forgotten while sending.
+    pub pruned_tokens: usize,
+    pub failed_tokens: usize,
+    /// The notification preferences or quiet hours of the account held the push back.
+    pub suppressed: bool,
 }
 
 #[async_trait]
@@ -32,7 +50,157 @@ pub trait PushNotificationUseCase: Send + Sync {
 }
 // ================================ PUSH NOTIFICATION ================================
 
+// ================================ PUSH SUBSCRIPTION ================================
+#[derive(Debug, Clone, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct RegisterDeviceParams {
+    #[validate(length(min = 1, max = 4096, message = "Device token must be between 1 and 4096 characters"))]
+    pub device_token: String,
+}
+
+#[async_trait]
+pub trait ManagePushSubscriptionUseCase: Send + Sync {
+    /// Device tokens belong to the session identified by `jti`, signing out forgets them.
+    async fn register_device(&self, account_id: &str, jti: &str, params: &RegisterDeviceParams) -> DomainResponse<()>;
+    async fn unregister_device(&self, account_id: &str, jti: &str) -> DomainResponse<()>;
+    async fn find_topics(&self, account_id: &str) -> DomainResponse<Vec<TopicSubscriptionEntity>>;
+    async fn subscribe_topic(&self, account_id: &str, topic: &str) -> DomainResponse<TopicSubscriptionEntity>;
+    async fn unsubscribe_topic(&self, account_id: &str, topic: &str) -> DomainResponse<()>;
+}
+// ================================ PUSH SUBSCRIPTION ================================
+
 // ================================ MANAGE NOTIFICATION ================================
+#[derive(Debug, Clone, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct FindNotificationsQuery {
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub paginate: BasePaginateQuery,
+    #[serde(default)]
+    pub unread: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnreadCount {
+    pub unread: u64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarkAllReadResult {
+    pub updated: usize,
+}
+
 #[async_trait]
-pub trait MangeNotificationUseCase: Send + Sync {}
+pub trait ManageNotificationUseCase: Send + Sync {
+    async fn find_notifications(
+        &self,
+        account_id: &str,
+        query: &FindNotificationsQuery,
+    ) -> DomainResponse<(Paginate, Vec<NotificationEntity>)>;
+
+    async fn count_unread(&self, account_id: &str) -> DomainResponse<UnreadCount>;
+    async fn mark_read(&self, account_id: &str, notification_id: &str) -> DomainResponse<NotificationEntity>;
+    async fn mark_all_read(&self, account_id: &str) -> DomainResponse<MarkAllReadResult>;
+    async fn delete_notification(&self, account_id: &str, notification_id: &str) -> DomainResponse<()>;
+}
 // ================================ MANAGE NOTIFICATION ================================
+
+// ================================ NOTIFICATION PREFERENCE ================================
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChannelPreference {
+    pub kind: String,
+    pub in_app: bool,
+    pub push: bool,
+    pub email: bool,
+}
+
+/// Replaces the preferences as a whole, kinds left out of `channels` get every channel back.
+#[derive(Debug, Clone, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateNotificationPreferencesParams {
+    #[serde(default)]
+    pub channels: Vec<ChannelPreference>,
+    /// Minute of the local day, set together with the end or not at all.
+    #[validate(range(min = 0, max = 1439, message = "Quiet hours must start between minute 0 and 1439 of the day"))]
+    pub quiet_hours_start: Option<i32>,
+    #[validate(range(min = 0, max = 1439, message = "Quiet hours must end between minute 0 and 1439 of the day"))]
+    pub quiet_hours_end: Option<i32>,
+    #[serde(default)]
+    #[validate(range(min = -720, max = 840, message = "UTC offset must be between -720 and 840 minutes"))]
+    pub utc_offset_minutes: i32,
+    #[serde(default = "UpdateNotificationPreferencesParams::default_digest_frequency")]
+    pub digest_frequency: String,
+}
+
+impl UpdateNotificationPreferencesParams {
+    fn default_digest_frequency() -> String {
+        "OFF".to_string()
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationPreferences {
+    /// One entry per notification kind.
+    pub channels: Vec<ChannelPreference>,
+    pub quiet_hours_start: Option<i32>,
+    pub quiet_hours_end: Option<i32>,
+    pub utc_offset_minutes: i32,
+    pub digest_frequency: String,
+    pub next_digest_at: Option<i64>,
+}
+
+impl NotificationPreferences {
+    pub fn from_entity(preferences: &NotificationPreferenceEntity) -> Self {
+        let channels = NotificationKind::ALL
+            .iter()
+            .map(|kind| ChannelPreference {
+                kind: kind.as_str().to_string(),
+                in_app: preferences.allows(kind.as_str(), NotificationChannel::InApp),
+                push: preferences.allows(kind.as_str(), NotificationChannel::Push),
+                email: preferences.allows(kind.as_str(), NotificationChannel::Email),
+            })
+            .collect();
+
+        Self {
+            channels,
+            quiet_hours_start: preferences.quiet_hours_start,
+            quiet_hours_end: preferences.quiet_hours_end,
+            utc_offset_minutes: preferences.utc_offset_minutes,
+            digest_frequency: preferences.digest_frequency.clone(),
+            next_digest_at: preferences.next_digest_at,
+        }
+    }
+}
+
+#[async_trait]
+pub trait ManageNotificationPreferenceUseCase: Send + Sync {
+    async fn find_preferences(&self, account_id: &str) -> DomainResponse<NotificationPreferences>;
+    async fn update_preferences(
+        &self,
+        account_id: &str,
+        params: &UpdateNotificationPreferencesParams,
+    ) -> DomainResponse<NotificationPreferences>;
+}
+// ================================ NOTIFICATION PREFERENCE ================================
+
+// ================================ NOTIFICATION DIGEST ================================
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DigestResult {
+    pub sent: usize,
+    /// Nothing unread to mail, the period counts as covered.
+    pub skipped: usize,
+    /// Due during quiet hours, moved to when they end.
+    pub postponed: usize,
+    pub failed: usize,
+}
+
+#[async_trait]
+pub trait NotificationDigestUseCase: Send + Sync {
+    async fn send_digests(&self) -> DomainResponse<DigestResult>;
+}
+// ================================ NOTIFICATION DIGEST ================================
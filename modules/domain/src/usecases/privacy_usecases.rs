@@ -0,0 +1,40 @@
+use async_trait::async_trait;
+
+// shared modules
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::apis::blob_storage::Blob;
+use crate::entities::{account_entity::AccountEntity, data_export_entity::DataExportEntity};
+
+// region =================================== MANAGE ACCOUNT STATUS USE CASE ===================================
+#[async_trait]
+pub trait ManageAccountStatusUseCase: Send + Sync {
+    /// Deactivate the account and sign it out everywhere, signing in is refused until it is reactivated.
+    async fn deactivate_account(&self, account_id: &str) -> DomainResponse<AccountEntity>;
+
+    /// Deactivate the account now and erase it once the grace period passed, reactivating cancels it.
+    async fn schedule_account_deletion(&self, account_id: &str) -> DomainResponse<AccountEntity>;
+
+    /// Erase the accounts whose deletion is due along with everything they own, returns how many were erased.
+    /// An account that fails is logged and retried on the next run.
+    async fn erase_due_accounts(&self) -> DomainResponse<usize>;
+}
+// endregion =================================== MANAGE ACCOUNT STATUS USE CASE ===================================
+
+// region =================================== DATA EXPORT USE CASE ===================================
+#[async_trait]
+pub trait DataExportUseCase: Send + Sync {
+    /// Queue a data export, the archive is produced by the export job.
+    async fn request_export(&self, account_id: &str) -> DomainResponse<DataExportEntity>;
+    async fn find_exports(&self, account_id: &str) -> DomainResponse<Vec<DataExportEntity>>;
+    async fn find_export(&self, account_id: &str, export_id: &str) -> DomainResponse<DataExportEntity>;
+    async fn download_export(&self, account_id: &str, export_id: &str) -> DomainResponse<Blob>;
+
+    /// Bundle everything the accounts of pending exports own into a JSON per entity, zipped.
+    async fn process_pending_exports(&self) -> DomainResponse<usize>;
+
+    /// Delete the archives past their retention.
+    async fn collect_expired_exports(&self) -> DomainResponse<usize>;
+}
+// endregion =================================== DATA EXPORT USE CASE ===================================
@@ -0,0 +1,85 @@
+use async_trait::async_trait;
+use serde::Deserialize;
+use validator::Validate;
+
+// shared modules
+use shared::{
+    models::paginate::{BasePaginateQuery, Paginate},
+    types::DomainResponse,
+};
+
+// internal modules
+use crate::entities::review_entity::ReviewEntity;
+
+// region =================================== MANAGE REVIEW USE CASE ===================================
+#[derive(Debug, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateReviewParams {
+    #[validate(range(min = 1, max = 5, message = "Rating must be between 1 and 5"))]
+    pub rating: i32,
+    #[serde(default)]
+    pub content: String,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateReviewParams {
+    #[validate(range(min = 1, max = 5, message = "Rating must be between 1 and 5"))]
+    pub rating: Option<i32>,
+    pub content: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct FindReviewsQuery {
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub paginate: BasePaginateQuery,
+}
+
+#[async_trait]
+pub trait ManageReviewUseCase: Send + Sync {
+    /// Only enrolled learners can review a course, once.
+    async fn create_review(
+        &self,
+        account_id: &str,
+        course_id: &str,
+        params: &CreateReviewParams,
+    ) -> DomainResponse<ReviewEntity>;
+
+    async fn update_review(
+        &self,
+        account_id: &str,
+        course_id: &str,
+        params: &UpdateReviewParams,
+    ) -> DomainResponse<ReviewEntity>;
+
+    async fn delete_review(&self, account_id: &str, course_id: &str) -> DomainResponse<()>;
+    async fn find_own_review(&self, account_id: &str, course_id: &str) -> DomainResponse<ReviewEntity>;
+    async fn find_course_reviews(
+        &self,
+        course_id: &str,
+        query: &FindReviewsQuery,
+    ) -> DomainResponse<(Paginate, Vec<ReviewEntity>)>;
+}
+// endregion =================================== MANAGE REVIEW USE CASE ===================================
+
+// region =================================== MODERATE REVIEW USE CASE ===================================
+#[derive(Debug, Clone, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct FindModeratedReviewsQuery {
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub paginate: BasePaginateQuery,
+    /// Defaults to approved reviews.
+    pub status: Option<String>,
+}
+
+#[async_trait]
+pub trait ModerateReviewUseCase: Send + Sync {
+    async fn find_reviews(&self, query: &FindModeratedReviewsQuery) -> DomainResponse<(Paginate, Vec<ReviewEntity>)>;
+    async fn hide_review(&self, review_id: &str) -> DomainResponse<ReviewEntity>;
+    async fn approve_review(&self, review_id: &str) -> DomainResponse<ReviewEntity>;
+    async fn remove_review(&self, review_id: &str) -> DomainResponse<()>;
+}
+// endregion =================================== MODERATE REVIEW USE CASE ===================================
@@ -0,0 +1,71 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+// shared modules
+use shared::{
+    models::paginate::{BasePaginateQuery, Paginate},
+    types::DomainResponse,
+};
+
+// region =================================== SEARCH USE CASE ===================================
+#[derive(Debug, Clone, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchQuery {
+    #[validate(length(min = 1, max = 200, message = "Search query must be between 1 and 200 characters"))]
+    pub q: String,
+    pub kind: Option<String>,
+    pub category: Option<String>,
+    pub tag: Option<String>,
+
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub paginate: BasePaginateQuery,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchHit {
+    pub id: String,
+    pub kind: String,
+    pub title: String,
+    pub description: String,
+    pub score: f32,
+    pub snippet: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchFacet {
+    pub value: String,
+    pub count: u32,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchFacets {
+    pub categories: Vec<SearchFacet>,
+    pub tags: Vec<SearchFacet>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchResult {
+    pub paginate: Paginate,
+    pub hits: Vec<SearchHit>,
+    pub facets: SearchFacets,
+}
+
+#[async_trait]
+pub trait SearchUseCase: Send + Sync {
+    async fn search(&self, query: &SearchQuery) -> DomainResponse<SearchResult>;
+}
+// endregion =================================== SEARCH USE CASE ===================================
+
+// region =================================== INDEX SEARCH USE CASE ===================================
+#[async_trait]
+pub trait IndexSearchUseCase: Send + Sync {
+    /// Rebuild the whole index from the database, returns the number of indexed documents.
+    async fn rebuild_search_index(&self) -> DomainResponse<usize>;
+}
+// endregion =================================== INDEX SEARCH USE CASE ===================================
@@ -0,0 +1,26 @@
+use async_trait::async_trait;
+
+// shared modules
+use shared::types::DomainResponse;
+
+// region =================================== SITEMAP USE CASE ===================================
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SitemapEntryKind {
+    Blog,
+    Project,
+    Category,
+}
+
+#[derive(Debug, Clone)]
+pub struct SitemapEntry {
+    pub kind: SitemapEntryKind,
+    pub id: String,
+    pub updated_at: i64,
+}
+
+#[async_trait]
+pub trait SitemapUseCase: Send + Sync {
+    /// Every public page, in a stable order so sitemap files keep their URLs between requests.
+    async fn find_sitemap_entries(&self) -> DomainResponse<Vec<SitemapEntry>>;
+}
+// endregion =================================== SITEMAP USE CASE ===================================
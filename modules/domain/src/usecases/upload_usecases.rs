@@ -0,0 +1,57 @@
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use validator::Validate;
+
+// shared modules
+use shared::types::DomainResponse;
+
+// internal modules
+use crate::entities::upload_entity::UploadEntity;
+
+// region =================================== DIRECT UPLOAD USE CASE ===================================
+#[derive(Debug, Clone, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateUploadParams {
+    #[validate(length(min = 1, message = "Purpose must not be empty"))]
+    pub purpose: String,
+    /// Id of the blog or course the file belongs to.
+    #[validate(length(min = 1, message = "Reference id must not be empty"))]
+    pub reference_id: String,
+    #[validate(length(min = 1, max = 255, message = "File name must be between 1 and 255 characters"))]
+    pub file_name: String,
+    #[validate(length(min = 1, message = "Content type must not be empty"))]
+    pub content_type: String,
+    #[validate(range(min = 1, message = "Size must be positive"))]
+    pub size: i64,
+    /// MD5 of the content as hex.
+    #[validate(length(equal = 32, message = "Checksum must be an MD5 hex digest"))]
+    pub checksum: String,
+}
+
+/// Where and how the client sends the file.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PresignedUpload {
+    pub upload: UploadEntity,
+    pub url: String,
+    pub method: String,
+    /// Headers the PUT request has to carry.
+    pub headers: HashMap<String, String>,
+    pub expires_at: i64,
+}
+
+#[async_trait]
+pub trait DirectUploadUseCase: Send + Sync {
+    /// Record a pending upload and issue the URL the file is PUT to. Only the author of the blog or
+    /// course can upload to it.
+    async fn create_upload(&self, account_id: &str, params: &CreateUploadParams) -> DomainResponse<PresignedUpload>;
+    async fn find_upload(&self, account_id: &str, upload_id: &str) -> DomainResponse<UploadEntity>;
+
+    /// Check the stored file against the declared size and checksum and mark the upload completed.
+    async fn complete_upload(&self, account_id: &str, upload_id: &str) -> DomainResponse<UploadEntity>;
+
+    /// Delete the files and records of uploads that were never completed.
+    async fn collect_expired_uploads(&self) -> DomainResponse<usize>;
+}
+// endregion =================================== DIRECT UPLOAD USE CASE ===================================
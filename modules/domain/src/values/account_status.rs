@@ -0,0 +1,34 @@
+use shared::models::failure::Failure;
+
+/// Where an account stands, derived from its activation, suspension and deletion fields.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccountStatus {
+    Active,
+    /// Deactivated by its owner, reactivating signs it back in.
+    Deactivated,
+    /// Suspended by an admin until the suspension ends or is lifted.
+    Suspended,
+    /// Deactivated with a deletion scheduled after the grace period.
+    PendingDeletion,
+}
+
+impl AccountStatus {
+    pub fn as_str(&self) -> &str {
+        match self {
+            AccountStatus::Active => "ACTIVE",
+            AccountStatus::Deactivated => "DEACTIVATED",
+            AccountStatus::Suspended => "SUSPENDED",
+            AccountStatus::PendingDeletion => "PENDING_DELETION",
+        }
+    }
+
+    pub fn from_string(status: &str) -> Result<Self, Failure> {
+        match status.to_uppercase().as_str() {
+            "ACTIVE" => Ok(AccountStatus::Active),
+            "DEACTIVATED" => Ok(AccountStatus::Deactivated),
+            "SUSPENDED" => Ok(AccountStatus::Suspended),
+            "PENDING_DELETION" => Ok(AccountStatus::PendingDeletion),
+            _ => Err(Failure::ValidationError(format!("Unknown account status: {}", status))),
+        }
+    }
+}
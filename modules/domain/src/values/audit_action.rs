@@ -0,0 +1,115 @@
+use shared::models::failure::Failure;
+
+/// What an audit event records, each action has a single kind of target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuditAction {
+    SignUp,
+    SignIn,
+    /// Wrong password for a registered email.
+    SignInFailed,
+    AccountReactivated,
+    AccountDeactivated,
+    AccountDeletionScheduled,
+    /// The account and everything it owned were erased once its deletion was due.
+    AccountErased,
+    AccountSuspended,
+    AccountSuspensionLifted,
+    AccountSignedOut,
+    AccountImpersonated,
+    /// An impersonation token was turned away from a route only the account itself may use.
+    ImpersonationDenied,
+    /// A request changing something was made with an impersonation token.
+    ImpersonatedRequest,
+    RoleGranted,
+    RoleChanged,
+    CategoryCreated,
+    CategoryUpdated,
+    CategoryMoved,
+    CategoryDeleted,
+    ProjectRemoved,
+    DataExportRequested,
+    DataExportDownloaded,
+}
+
+impl AuditAction {
+    pub fn as_str(&self) -> &str {
+        match self {
+            AuditAction::SignUp => "SIGN_UP",
+            AuditAction::SignIn => "SIGN_IN",
+            AuditAction::SignInFailed => "SIGN_IN_FAILED",
+            AuditAction::AccountReactivated => "ACCOUNT_REACTIVATED",
+            AuditAction::AccountDeactivated => "ACCOUNT_DEACTIVATED",
+            AuditAction::AccountDeletionScheduled => "ACCOUNT_DELETION_SCHEDULED",
+            AuditAction::AccountErased => "ACCOUNT_ERASED",
+            AuditAction::AccountSuspended => "ACCOUNT_SUSPENDED",
+            AuditAction::AccountSuspensionLifted => "ACCOUNT_SUSPENSION_LIFTED",
+            AuditAction::AccountSignedOut => "ACCOUNT_SIGNED_OUT",
+            AuditAction::AccountImpersonated => "ACCOUNT_IMPERSONATED",
+            AuditAction::ImpersonationDenied => "IMPERSONATION_DENIED",
+            AuditAction::ImpersonatedRequest => "IMPERSONATED_REQUEST",
+            AuditAction::RoleGranted => "ROLE_GRANTED",
+            AuditAction::RoleChanged => "ROLE_CHANGED",
+            AuditAction::CategoryCreated => "CATEGORY_CREATED",
+            AuditAction::CategoryUpdated => "CATEGORY_UPDATED",
+            AuditAction::CategoryMoved => "CATEGORY_MOVED",
+            AuditAction::CategoryDeleted => "CATEGORY_DELETED",
+            AuditAction::ProjectRemoved => "PROJECT_REMOVED",
+            AuditAction::DataExportRequested => "DATA_EXPORT_REQUESTED",
+            AuditAction::DataExportDownloaded => "DATA_EXPORT_DOWNLOADED",
+        }
+    }
+
+    pub fn from_string(action: &str) -> Result<Self, Failure> {
+        match action.to_uppercase().as_str() {
+            "SIGN_UP" => Ok(AuditAction::SignUp),
+            "SIGN_IN" => Ok(AuditAction::SignIn),
+            "SIGN_IN_FAILED" => Ok(AuditAction::SignInFailed),
+            "ACCOUNT_REACTIVATED" => Ok(AuditAction::AccountReactivated),
+            "ACCOUNT_DEACTIVATED" => Ok(AuditAction::AccountDeactivated),
+            "ACCOUNT_DELETION_SCHEDULED" => Ok(AuditAction::AccountDeletionScheduled),
+            "ACCOUNT_ERASED" => Ok(AuditAction::AccountErased),
+            "ACCOUNT_SUSPENDED" => Ok(AuditAction::AccountSuspended),
+            "ACCOUNT_SUSPENSION_LIFTED" => Ok(AuditAction::AccountSuspensionLifted),
+            "ACCOUNT_SIGNED_OUT" => Ok(AuditAction::AccountSignedOut),
+            "ACCOUNT_IMPERSONATED" => Ok(AuditAction::AccountImpersonated),
+            "IMPERSONATION_DENIED" => Ok(AuditAction::ImpersonationDenied),
+            "IMPERSONATED_REQUEST" => Ok(AuditAction::ImpersonatedRequest),
+            "ROLE_GRANTED" => Ok(AuditAction::RoleGranted),
+            "ROLE_CHANGED" => Ok(AuditAction::RoleChanged),
+            "CATEGORY_CREATED" => Ok(AuditAction::CategoryCreated),
+            "CATEGORY_UPDATED" => Ok(AuditAction::CategoryUpdated),
+            "CATEGORY_MOVED" => Ok(AuditAction::CategoryMoved),
+            "CATEGORY_DELETED" => Ok(AuditAction::CategoryDeleted),
+            "PROJECT_REMOVED" => Ok(AuditAction::ProjectRemoved),
+            "DATA_EXPORT_REQUESTED" => Ok(AuditAction::DataExportRequested),
+            "DATA_EXPORT_DOWNLOADED" => Ok(AuditAction::DataExportDownloaded),
+            _ => Err(Failure::ValidationError(format!("Unknown audit action: {}", action))),
+        }
+    }
+
+    /// Kind of entity the action targets.
+    pub fn target_type(&self) -> &str {
+        match self {
+            AuditAction::SignUp
+            | AuditAction::SignIn
+            | AuditAction::SignInFailed
+            | AuditAction::AccountReactivated
+            | AuditAction::AccountDeactivated
+            | AuditAction::AccountDeletionScheduled
+            | AuditAction::AccountErased
+            | AuditAction::AccountSuspended
+            | AuditAction::AccountSuspensionLifted
+            | AuditAction::AccountSignedOut
+            | AuditAction::AccountImpersonated
+            | AuditAction::ImpersonationDenied
+            | AuditAction::ImpersonatedRequest => "ACCOUNT",
+            AuditAction::RoleGranted | AuditAction::RoleChanged => "ROLE",
+            AuditAction::CategoryCreated
+            | AuditAction::CategoryUpdated
+            | AuditAction::CategoryMoved
+            | AuditAction::CategoryDeleted => "CATEGORY",
+            AuditAction::ProjectRemoved => "PROJECT",
+            AuditAction::DataExportRequested | AuditAction::DataExportDownloaded => "DATA_EXPORT",
+        }
+    }
+}
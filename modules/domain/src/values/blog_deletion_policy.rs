@@ -0,0 +1,26 @@
+use shared::models::failure::Failure;
+
+/// What happens to the blogs of an account once it is erased.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlogDeletionPolicy {
+    /// Keep the blogs online under the ghost account.
+    Anonymize,
+    Delete,
+}
+
+impl BlogDeletionPolicy {
+    pub fn as_str(&self) -> &str {
+        match self {
+            BlogDeletionPolicy::Anonymize => "ANONYMIZE",
+            BlogDeletionPolicy::Delete => "DELETE",
+        }
+    }
+
+    pub fn from_string(policy: &str) -> Result<Self, Failure> {
+        match policy.to_uppercase().as_str() {
+            "ANONYMIZE" => Ok(BlogDeletionPolicy::Anonymize),
+            "DELETE" => Ok(BlogDeletionPolicy::Delete),
+            _ => Err(Failure::ValidationError(format!("Unknown blog deletion policy: {}", policy))),
+        }
+    }
+}
@@ -0,0 +1,104 @@
+use shared::models::failure::Failure;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlogStatus {
+    Draft,
+    Scheduled,
+    Published,
+    Unlisted,
+    Archived,
+}
+
+impl BlogStatus {
+    pub fn as_str(&self) -> &str {
+        match self {
+            BlogStatus::Draft => "DRAFT",
+            BlogStatus::Scheduled => "SCHEDULED",
+            BlogStatus::Published => "PUBLISHED",
+            BlogStatus::Unlisted => "UNLISTED",
+            BlogStatus::Archived => "ARCHIVED",
+        }
+    }
+
+    pub fn from_string(status: &str) -> Result<Self, Failure> {
+        match status.to_uppercase().as_str() {
+            "DRAFT" => Ok(BlogStatus::Draft),
+            "SCHEDULED" => Ok(BlogStatus::Scheduled),
+            "PUBLISHED" => Ok(BlogStatus::Published),
+            "UNLISTED" => Ok(BlogStatus::Unlisted),
+            "ARCHIVED" => Ok(BlogStatus::Archived),
+            _ => Err(Failure::ValidationError(format!("Unknown blog status: {}", status))),
+        }
+    }
+
+    /// Allowed moves between states. Archived posts must go back to draft before they can be published again.
+    pub fn can_transition_to(&self, next: BlogStatus) -> bool {
+        use BlogStatus::*;
+
+        matches!(
+            (self, next),
+            (Draft, Scheduled | Published | Unlisted | Archived)
+                | (Scheduled, Draft | Scheduled | Published | Archived)
+                | (Published, Draft | Unlisted | Archived)
+                | (Unlisted, Draft | Published | Archived)
+                | (Archived, Draft)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BlogStatus::{self, *};
+
+    const ALL: [BlogStatus; 5] = [Draft, Scheduled, Published, Unlisted, Archived];
+
+    #[test]
+    fn drafts_move_anywhere_but_to_draft() {
+        assert!(!Draft.can_transition_to(Draft));
+        for next in [Scheduled, Published, Unlisted, Archived] {
+            assert!(Draft.can_transition_to(next), "Draft -> {:?}", next);
+        }
+    }
+
+    #[test]
+    fn scheduled_blogs_can_be_rescheduled_but_not_unlisted() {
+        assert!(Scheduled.can_transition_to(Scheduled));
+        assert!(Scheduled.can_transition_to(Published));
+        assert!(Scheduled.can_transition_to(Draft));
+        assert!(Scheduled.can_transition_to(Archived));
+        assert!(!Scheduled.can_transition_to(Unlisted));
+    }
+
+    #[test]
+    fn published_blogs_can_not_be_scheduled_again() {
+        assert!(!Published.can_transition_to(Scheduled));
+        assert!(!Published.can_transition_to(Published));
+        assert!(Published.can_transition_to(Unlisted));
+        assert!(Unlisted.can_transition_to(Published));
+        assert!(!Unlisted.can_transition_to(Scheduled));
+    }
+
+    #[test]
+    fn archived_blogs_only_return_to_draft() {
+        for next in ALL {
+            assert_eq!(Archived.can_transition_to(next), next == Draft, "Archived -> {:?}", next);
+        }
+    }
+
+    #[test]
+    fn every_status_can_be_archived_or_returned_to_draft_except_itself() {
+        for status in ALL {
+            assert_eq!(status.can_transition_to(Archived), status != Archived, "{:?} -> Archived", status);
+            assert_eq!(status.can_transition_to(Draft), status != Draft, "{:?} -> Draft", status);
+        }
+    }
+
+    #[test]
+    fn statuses_round_trip_through_their_names() {
+        for status in ALL {
+            assert_eq!(BlogStatus::from_string(status.as_str()).unwrap(), status);
+            assert_eq!(BlogStatus::from_string(&status.as_str().to_lowercase()).unwrap(), status);
+        }
+        assert!(BlogStatus::from_string("DELETED").is_err());
+    }
+}
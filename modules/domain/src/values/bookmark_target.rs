@@ -0,0 +1,30 @@
+use shared::models::failure::Failure;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BookmarkTarget {
+    Course,
+    Lesson,
+    Blog,
+    Project,
+}
+
+impl BookmarkTarget {
+    pub fn as_str(&self) -> &str {
+        match self {
+            BookmarkTarget::Course => "COURSE",
+            BookmarkTarget::Lesson => "LESSON",
+            BookmarkTarget::Blog => "BLOG",
+            BookmarkTarget::Project => "PROJECT",
+        }
+    }
+
+    pub fn from_string(target: &str) -> Result<Self, Failure> {
+        match target.to_uppercase().as_str() {
+            "COURSE" => Ok(BookmarkTarget::Course),
+            "LESSON" => Ok(BookmarkTarget::Lesson),
+            "BLOG" => Ok(BookmarkTarget::Blog),
+            "PROJECT" => Ok(BookmarkTarget::Project),
+            _ => Err(Failure::ValidationError(format!("Unknown bookmark target: {}", target))),
+        }
+    }
+}
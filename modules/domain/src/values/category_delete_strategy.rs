@@ -0,0 +1,32 @@
+use shared::models::failure::Failure;
+
+/// What happens to subcategories and blogs when a category is deleted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CategoryDeleteStrategy {
+    /// Refuse while subcategories or blogs still reference the category.
+    Refuse,
+    /// Move subcategories and blogs to another category.
+    Reassign,
+    /// Delete subcategories too and detach every blog from the deleted categories, the blogs left without one
+    /// move to the parent. Refused for a root category while blogs would be left without one.
+    Cascade,
+}
+
+impl CategoryDeleteStrategy {
+    pub fn as_str(&self) -> &str {
+        match self {
+            CategoryDeleteStrategy::Refuse => "REFUSE",
+            CategoryDeleteStrategy::Reassign => "REASSIGN",
+            CategoryDeleteStrategy::Cascade => "CASCADE",
+        }
+    }
+
+    pub fn from_string(strategy_str: &str) -> Result<Self, Failure> {
+        match strategy_str.to_uppercase().as_str() {
+            "REFUSE" => Ok(CategoryDeleteStrategy::Refuse),
+            "REASSIGN" => Ok(CategoryDeleteStrategy::Reassign),
+            "CASCADE" => Ok(CategoryDeleteStrategy::Cascade),
+            _ => Err(Failure::ValidationError(format!("Unknown category delete strategy: {}", strategy_str))),
+        }
+    }
+}
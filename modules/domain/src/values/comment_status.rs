@@ -0,0 +1,24 @@
+use shared::models::failure::Failure;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommentStatus {
+    Approved,
+    Hidden,
+}
+
+impl CommentStatus {
+    pub fn as_str(&self) -> &str {
+        match self {
+            CommentStatus::Approved => "APPROVED",
+            CommentStatus::Hidden => "HIDDEN",
+        }
+    }
+
+    pub fn from_string(status: &str) -> Result<Self, Failure> {
+        match status.to_uppercase().as_str() {
+            "APPROVED" => Ok(CommentStatus::Approved),
+            "HIDDEN" => Ok(CommentStatus::Hidden),
+            _ => Err(Failure::ValidationError(format!("Unknown comment status: {}", status))),
+        }
+    }
+}
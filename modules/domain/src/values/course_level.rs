@@ -0,0 +1,27 @@
+use shared::models::failure::Failure;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CourseLevel {
+    Beginner,
+    Intermediate,
+    Advanced,
+}
+
+impl CourseLevel {
+    pub fn as_str(&self) -> &str {
+        match self {
+            CourseLevel::Beginner => "BEGINNER",
+            CourseLevel::Intermediate => "INTERMEDIATE",
+            CourseLevel::Advanced => "ADVANCED",
+        }
+    }
+
+    pub fn from_string(level: &str) -> Result<Self, Failure> {
+        match level.to_uppercase().as_str() {
+            "BEGINNER" => Ok(CourseLevel::Beginner),
+            "INTERMEDIATE" => Ok(CourseLevel::Intermediate),
+            "ADVANCED" => Ok(CourseLevel::Advanced),
+            _ => Err(Failure::ValidationError(format!("Unknown course level: {}", level))),
+        }
+    }
+}
@@ -0,0 +1,34 @@
+use shared::models::failure::Failure;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CourseStatus {
+    Draft,
+    Published,
+    Archived,
+}
+
+impl CourseStatus {
+    pub fn as_str(&self) -> &str {
+        match self {
+            CourseStatus::Draft => "DRAFT",
+            CourseStatus::Published => "PUBLISHED",
+            CourseStatus::Archived => "ARCHIVED",
+        }
+    }
+
+    pub fn from_string(status: &str) -> Result<Self, Failure> {
+        match status.to_uppercase().as_str() {
+            "DRAFT" => Ok(CourseStatus::Draft),
+            "PUBLISHED" => Ok(CourseStatus::Published),
+            "ARCHIVED" => Ok(CourseStatus::Archived),
+            _ => Err(Failure::ValidationError(format!("Unknown course status: {}", status))),
+        }
+    }
+
+    /// Archived courses go back to draft before they can be published again.
+    pub fn can_transition_to(&self, next: CourseStatus) -> bool {
+        use CourseStatus::*;
+
+        matches!((self, next), (Draft, Published | Archived) | (Published, Draft | Archived) | (Archived, Draft))
+    }
+}
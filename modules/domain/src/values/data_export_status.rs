@@ -0,0 +1,29 @@
+use shared::models::failure::Failure;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DataExportStatus {
+    /// Requested, waiting for the export job.
+    Pending,
+    /// The archive is stored and can be downloaded until it expires.
+    Ready,
+    Failed,
+}
+
+impl DataExportStatus {
+    pub fn as_str(&self) -> &str {
+        match self {
+            DataExportStatus::Pending => "PENDING",
+            DataExportStatus::Ready => "READY",
+            DataExportStatus::Failed => "FAILED",
+        }
+    }
+
+    pub fn from_string(status: &str) -> Result<Self, Failure> {
+        match status.to_uppercase().as_str() {
+            "PENDING" => Ok(DataExportStatus::Pending),
+            "READY" => Ok(DataExportStatus::Ready),
+            "FAILED" => Ok(DataExportStatus::Failed),
+            _ => Err(Failure::ValidationError(format!("Unknown data export status: {}", status))),
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use shared::models::failure::Failure;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DigestFrequency {
+    Off,
+    Daily,
+    Weekly,
+}
+
+impl DigestFrequency {
+    pub fn as_str(&self) -> &str {
+        match self {
+            DigestFrequency::Off => "OFF",
+            DigestFrequency::Daily => "DAILY",
+            DigestFrequency::Weekly => "WEEKLY",
+        }
+    }
+
+    pub fn from_string(frequency: &str) -> Result<Self, Failure> {
+        match frequency.to_uppercase().as_str() {
+            "OFF" => Ok(DigestFrequency::Off),
+            "DAILY" => Ok(DigestFrequency::Daily),
+            "WEEKLY" => Ok(DigestFrequency::Weekly),
+            _ => Err(Failure::ValidationError(format!("Unknown digest frequency: {}", frequency))),
+        }
+    }
+
+    /// Seconds between two digests, `None` when digests are off.
+    pub fn period_seconds(&self) -> Option<i64> {
+        match self {
+            DigestFrequency::Off => None,
+            DigestFrequency::Daily => Some(24 * 60 * 60),
+            DigestFrequency::Weekly => Some(7 * 24 * 60 * 60),
+        }
+    }
+}
@@ -0,0 +1,24 @@
+use shared::models::failure::Failure;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EnrollmentStatus {
+    Active,
+    Completed,
+}
+
+impl EnrollmentStatus {
+    pub fn as_str(&self) -> &str {
+        match self {
+            EnrollmentStatus::Active => "ACTIVE",
+            EnrollmentStatus::Completed => "COMPLETED",
+        }
+    }
+
+    pub fn from_string(status: &str) -> Result<Self, Failure> {
+        match status.to_uppercase().as_str() {
+            "ACTIVE" => Ok(EnrollmentStatus::Active),
+            "COMPLETED" => Ok(EnrollmentStatus::Completed),
+            _ => Err(Failure::ValidationError(format!("Unknown enrollment status: {}", status))),
+        }
+    }
+}
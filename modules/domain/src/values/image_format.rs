@@ -0,0 +1,50 @@
+use shared::models::failure::Failure;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    Jpeg,
+    Png,
+    Gif,
+    Webp,
+}
+
+impl ImageFormat {
+    pub fn as_str(&self) -> &str {
+        match self {
+            ImageFormat::Jpeg => "JPEG",
+            ImageFormat::Png => "PNG",
+            ImageFormat::Gif => "GIF",
+            ImageFormat::Webp => "WEBP",
+        }
+    }
+
+    pub fn mime_type(&self) -> &str {
+        match self {
+            ImageFormat::Jpeg => "image/jpeg",
+            ImageFormat::Png => "image/png",
+            ImageFormat::Gif => "image/gif",
+            ImageFormat::Webp => "image/webp",
+        }
+    }
+
+    pub fn from_mime_type(mime_type: &str) -> Result<Self, Failure> {
+        match mime_type.to_lowercase().as_str() {
+            "image/jpeg" | "image/jpg" => Ok(ImageFormat::Jpeg),
+            "image/png" => Ok(ImageFormat::Png),
+            "image/gif" => Ok(ImageFormat::Gif),
+            "image/webp" => Ok(ImageFormat::Webp),
+            _ => Err(Failure::ValidationError(format!("Unsupported file type: {}", mime_type))),
+        }
+    }
+
+    /// The format the file content actually is, whatever it was declared as.
+    pub fn from_magic_bytes(bytes: &[u8]) -> Result<Self, Failure> {
+        match bytes {
+            [0xFF, 0xD8, 0xFF, ..] => Ok(ImageFormat::Jpeg),
+            [0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A, ..] => Ok(ImageFormat::Png),
+            [b'G', b'I', b'F', b'8', b'7' | b'9', b'a', ..] => Ok(ImageFormat::Gif),
+            [b'R', b'I', b'F', b'F', _, _, _, _, b'W', b'E', b'B', b'P', ..] => Ok(ImageFormat::Webp),
+            _ => Err(Failure::ValidationError("The file is not a supported image".to_string())),
+        }
+    }
+}
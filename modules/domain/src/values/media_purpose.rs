@@ -0,0 +1,57 @@
+use shared::configs::APP_CONFIG;
+use shared::models::failure::Failure;
+
+// internal modules
+use crate::apis::image_processor::ImageVariant;
+
+/// What an asset is uploaded for, which also tells what kind of entity references it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MediaPurpose {
+    Avatar,
+    BlogImage,
+    ProjectCover,
+}
+
+impl MediaPurpose {
+    pub const ALL: [MediaPurpose; 3] = [MediaPurpose::Avatar, MediaPurpose::BlogImage, MediaPurpose::ProjectCover];
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            MediaPurpose::Avatar => "AVATAR",
+            MediaPurpose::BlogImage => "BLOG_IMAGE",
+            MediaPurpose::ProjectCover => "PROJECT_COVER",
+        }
+    }
+
+    pub fn from_string(purpose: &str) -> Result<Self, Failure> {
+        match purpose.to_uppercase().as_str() {
+            "AVATAR" => Ok(MediaPurpose::Avatar),
+            "BLOG_IMAGE" => Ok(MediaPurpose::BlogImage),
+            "PROJECT_COVER" => Ok(MediaPurpose::ProjectCover),
+            _ => Err(Failure::ValidationError(format!("Unknown media purpose: {}", purpose))),
+        }
+    }
+
+    /// Storage folder of the purpose.
+    pub fn folder(&self) -> &str {
+        match self {
+            MediaPurpose::Avatar => "avatars",
+            MediaPurpose::BlogImage => "blog-images",
+            MediaPurpose::ProjectCover => "project-covers",
+        }
+    }
+
+    /// Configured variants, narrowest first.
+    pub fn variants(&self) -> Vec<ImageVariant> {
+        let variants = match self {
+            MediaPurpose::Avatar => &APP_CONFIG.media.avatar_variants,
+            MediaPurpose::BlogImage => &APP_CONFIG.media.blog_image_variants,
+            MediaPurpose::ProjectCover => &APP_CONFIG.media.project_cover_variants,
+        };
+
+        let mut variants: Vec<ImageVariant> =
+            variants.iter().filter_map(|variant| ImageVariant::parse(variant)).collect();
+        variants.sort_by_key(|variant| variant.max_width);
+        variants
+    }
+}
@@ -1,2 +1,24 @@
+pub mod account_status;
+pub mod audit_action;
 pub mod auth_provider;
+pub mod blog_deletion_policy;
+pub mod blog_status;
+pub mod bookmark_target;
+pub mod category_delete_strategy;
+pub mod comment_status;
+pub mod course_level;
+pub mod course_status;
+pub mod data_export_status;
+pub mod digest_frequency;
+pub mod enrollment_status;
+pub mod image_format;
+pub mod media_purpose;
+pub mod notification_channel;
+pub mod notification_kind;
+pub mod realtime_event_kind;
+pub mod review_status;
 pub mod roles;
+pub mod search_kind;
+pub mod text_diff;
+pub mod upload_purpose;
+pub mod upload_status;
@@ -0,0 +1,27 @@
+use shared::models::failure::Failure;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotificationChannel {
+    InApp,
+    Push,
+    Email,
+}
+
+impl NotificationChannel {
+    pub fn as_str(&self) -> &str {
+        match self {
+            NotificationChannel::InApp => "IN_APP",
+            NotificationChannel::Push => "PUSH",
+            NotificationChannel::Email => "EMAIL",
+        }
+    }
+
+    pub fn from_string(channel: &str) -> Result<Self, Failure> {
+        match channel.to_uppercase().as_str() {
+            "IN_APP" => Ok(NotificationChannel::InApp),
+            "PUSH" => Ok(NotificationChannel::Push),
+            "EMAIL" => Ok(NotificationChannel::Email),
+            _ => Err(Failure::ValidationError(format!("Unknown notification channel: {}", channel))),
+        }
+    }
+}
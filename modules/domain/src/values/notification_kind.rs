@@ -0,0 +1,37 @@
+use shared::models::failure::Failure;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotificationKind {
+    BlogComment,
+    CommentReply,
+    CourseReview,
+    System,
+}
+
+impl NotificationKind {
+    pub const ALL: [NotificationKind; 4] = [
+        NotificationKind::BlogComment,
+        NotificationKind::CommentReply,
+        NotificationKind::CourseReview,
+        NotificationKind::System,
+    ];
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            NotificationKind::BlogComment => "BLOG_COMMENT",
+            NotificationKind::CommentReply => "COMMENT_REPLY",
+            NotificationKind::CourseReview => "COURSE_REVIEW",
+            NotificationKind::System => "SYSTEM",
+        }
+    }
+
+    pub fn from_string(kind: &str) -> Result<Self, Failure> {
+        match kind.to_uppercase().as_str() {
+            "BLOG_COMMENT" => Ok(NotificationKind::BlogComment),
+            "COMMENT_REPLY" => Ok(NotificationKind::CommentReply),
+            "COURSE_REVIEW" => Ok(NotificationKind::CourseReview),
+            "SYSTEM" => Ok(NotificationKind::System),
+            _ => Err(Failure::ValidationError(format!("Unknown notification kind: {}", kind))),
+        }
+    }
+}
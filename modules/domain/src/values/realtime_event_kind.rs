@@ -0,0 +1,34 @@
+use shared::models::failure::Failure;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RealtimeEventKind {
+    NotificationCreated,
+    NotificationRead,
+    NotificationsReadAll,
+    NotificationDeleted,
+    /// Events were missed beyond what can be replayed, clients should refetch their state.
+    SyncRequired,
+}
+
+impl RealtimeEventKind {
+    pub fn as_str(&self) -> &str {
+        match self {
+            RealtimeEventKind::NotificationCreated => "notification.created",
+            RealtimeEventKind::NotificationRead => "notification.read",
+            RealtimeEventKind::NotificationsReadAll => "notification.read_all",
+            RealtimeEventKind::NotificationDeleted => "notification.deleted",
+            RealtimeEventKind::SyncRequired => "sync.required",
+        }
+    }
+
+    pub fn from_string(kind: &str) -> Result<Self, Failure> {
+        match kind.to_lowercase().as_str() {
+            "notification.created" => Ok(RealtimeEventKind::NotificationCreated),
+            "notification.read" => Ok(RealtimeEventKind::NotificationRead),
+            "notification.read_all" => Ok(RealtimeEventKind::NotificationsReadAll),
+            "notification.deleted" => Ok(RealtimeEventKind::NotificationDeleted),
+            "sync.required" => Ok(RealtimeEventKind::SyncRequired),
+            _ => Err(Failure::ValidationError(format!("Unknown realtime event kind: {}", kind))),
+        }
+    }
+}
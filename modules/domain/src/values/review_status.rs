@@ -0,0 +1,24 @@
+use shared::models::failure::Failure;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReviewStatus {
+    Approved,
+    Hidden,
+}
+
+impl ReviewStatus {
+    pub fn as_str(&self) -> &str {
+        match self {
+            ReviewStatus::Approved => "APPROVED",
+            ReviewStatus::Hidden => "HIDDEN",
+        }
+    }
+
+    pub fn from_string(status: &str) -> Result<Self, Failure> {
+        match status.to_uppercase().as_str() {
+            "APPROVED" => Ok(ReviewStatus::Approved),
+            "HIDDEN" => Ok(ReviewStatus::Hidden),
+            _ => Err(Failure::ValidationError(format!("Unknown review status: {}", status))),
+        }
+    }
+}
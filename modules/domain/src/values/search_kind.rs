@@ -0,0 +1,24 @@
+use shared::models::failure::Failure;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchKind {
+    Blog,
+    Project,
+}
+
+impl SearchKind {
+    pub fn as_str(&self) -> &str {
+        match self {
+            SearchKind::Blog => "BLOG",
+            SearchKind::Project => "PROJECT",
+        }
+    }
+
+    pub fn from_string(kind_str: &str) -> Result<Self, Failure> {
+        match kind_str.to_uppercase().as_str() {
+            "BLOG" => Ok(SearchKind::Blog),
+            "PROJECT" => Ok(SearchKind::Project),
+            _ => Err(Failure::ValidationError(format!("Unknown search kind: {}", kind_str))),
+        }
+    }
+}
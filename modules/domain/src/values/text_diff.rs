@@ -0,0 +1,62 @@
+use serde::Serialize;
+use similar::{ChangeTag, TextDiff as SimilarTextDiff};
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum DiffTag {
+    Equal,
+    Insert,
+    Delete,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiffLine {
+    pub tag: DiffTag,
+    pub old_line: Option<usize>,
+    pub new_line: Option<usize>,
+    pub content: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TextDiff {
+    pub insertions: usize,
+    pub deletions: usize,
+    pub lines: Vec<DiffLine>,
+}
+
+impl TextDiff {
+    /// Line based diff between two texts, line numbers are 1-based.
+    pub fn from_lines(old: &str, new: &str) -> Self {
+        let diff = SimilarTextDiff::from_lines(old, new);
+        let mut insertions = 0;
+        let mut deletions = 0;
+
+        let lines = diff
+            .iter_all_changes()
+            .map(|change| {
+                let tag = match change.tag() {
+                    ChangeTag::Equal => DiffTag::Equal,
+                    ChangeTag::Insert => {
+                        insertions += 1;
+                        DiffTag::Insert
+                    },
+                    ChangeTag::Delete => {
+                        deletions += 1;
+                        DiffTag::Delete
+                    },
+                };
+
+                DiffLine {
+                    tag,
+                    old_line: change.old_index().map(|index| index + 1),
+                    new_line: change.new_index().map(|index| index + 1),
+                    content: change.value().trim_end_matches(['\r', '\n']).to_string(),
+                }
+            })
+            .collect();
+
+        TextDiff { insertions, deletions, lines }
+    }
+}
@@ -0,0 +1,52 @@
+use shared::configs::APP_CONFIG;
+use shared::models::failure::Failure;
+
+/// What a direct upload is for, which also tells what kind of entity it belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UploadPurpose {
+    BlogAttachment,
+    CourseVideo,
+}
+
+impl UploadPurpose {
+    pub const ALL: [UploadPurpose; 2] = [UploadPurpose::BlogAttachment, UploadPurpose::CourseVideo];
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            UploadPurpose::BlogAttachment => "BLOG_ATTACHMENT",
+            UploadPurpose::CourseVideo => "COURSE_VIDEO",
+        }
+    }
+
+    pub fn from_string(purpose: &str) -> Result<Self, Failure> {
+        match purpose.to_uppercase().as_str() {
+            "BLOG_ATTACHMENT" => Ok(UploadPurpose::BlogAttachment),
+            "COURSE_VIDEO" => Ok(UploadPurpose::CourseVideo),
+            _ => Err(Failure::ValidationError(format!("Unknown upload purpose: {}", purpose))),
+        }
+    }
+
+    /// Storage folder of the purpose.
+    pub fn folder(&self) -> &str {
+        match self {
+            UploadPurpose::BlogAttachment => "blog-attachments",
+            UploadPurpose::CourseVideo => "course-videos",
+        }
+    }
+
+    pub fn max_size(&self) -> i64 {
+        match self {
+            UploadPurpose::BlogAttachment => APP_CONFIG.media.max_attachment_bytes,
+            UploadPurpose::CourseVideo => APP_CONFIG.media.max_video_bytes,
+        }
+    }
+
+    pub fn accepts(&self, content_type: &str) -> bool {
+        let types = match self {
+            UploadPurpose::BlogAttachment => &APP_CONFIG.media.attachment_types,
+            UploadPurpose::CourseVideo => &APP_CONFIG.media.video_types,
+        };
+
+        types.iter().any(|accepted| accepted.eq_ignore_ascii_case(content_type))
+    }
+}
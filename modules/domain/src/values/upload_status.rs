@@ -0,0 +1,26 @@
+use shared::models::failure::Failure;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UploadStatus {
+    /// The URL was issued, the file may or may not be in storage yet.
+    Pending,
+    /// The stored file matched the declared size and checksum.
+    Completed,
+}
+
+impl UploadStatus {
+    pub fn as_str(&self) -> &str {
+        match self {
+            UploadStatus::Pending => "PENDING",
+            UploadStatus::Completed => "COMPLETED",
+        }
+    }
+
+    pub fn from_string(status: &str) -> Result<Self, Failure> {
+        match status.to_uppercase().as_str() {
+            "PENDING" => Ok(UploadStatus::Pending),
+            "COMPLETED" => Ok(UploadStatus::Completed),
+            _ => Err(Failure::ValidationError(format!("Unknown upload status: {}", status))),
+        }
+    }
+}
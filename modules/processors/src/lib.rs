@@ -55,6 +55,15 @@ pub fn mongo_repository_derive(input: TokenStream) -> TokenStream {
             ) -> shared::types::DomainResponse<(shared::models::paginate::Paginate, Vec<#entity_type>)> {
                 self.base.finds_paginated(page, page_size).await
             }
+
+            async fn find_by(
+                &self,
+                spec: &shared::models::filters::QuerySpec,
+                page: u32,
+                page_size: u32,
+            ) -> shared::types::DomainResponse<(shared::models::paginate::Paginate, Vec<#entity_type>)> {
+                self.base.find_by(spec, page, page_size).await
+            }
         }
     };
 
@@ -12,6 +12,9 @@ use crate::functions::path_functions::PathFunctions;
 pub struct ServerConfig {
     pub host: String,
     pub port: u16,
+    /// Proxy addresses whose `X-Forwarded-For`/`X-Real-IP` headers are believed, none by default.
+    #[serde(default, deserialize_with = "deserialize_comma_separated")]
+    pub trusted_proxies: Vec<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -34,6 +37,16 @@ pub struct RedisConfig {
     pub password: String,
 }
 
+impl RedisConfig {
+    pub fn url(&self) -> String {
+        if self.password.is_empty() {
+            format!("redis://{}:{}/{}", self.host, self.port, self.db)
+        } else {
+            format!("redis://:{}@{}:{}/{}", self.password, self.host, self.port, self.db)
+        }
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct DatabaseConfig {
     pub database_type: String,
@@ -69,6 +82,354 @@ pub struct OAuth2Config {
     pub github_redirect_url: String,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct SiteConfig {
+    /// Public origin used in absolute links, without a trailing slash.
+    pub base_url: String,
+    #[serde(default = "SiteConfig::default_allow_indexing")]
+    pub robots_allow_indexing: bool,
+    #[serde(default = "SiteConfig::default_robots_disallow", deserialize_with = "deserialize_comma_separated")]
+    pub robots_disallow: Vec<String>,
+}
+
+impl SiteConfig {
+    fn default_allow_indexing() -> bool {
+        true
+    }
+
+    fn default_robots_disallow() -> Vec<String> {
+        vec!["/api/".to_string()]
+    }
+
+    pub fn public_url(&self, path: &str) -> String {
+        format!("{}{}", self.base_url.trim_end_matches('/'), path)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RealtimeConfig {
+    /// Fan events out through Redis pub/sub so sessions connected to other replicas receive them too.
+    #[serde(default)]
+    pub redis_fanout: bool,
+    #[serde(default = "RealtimeConfig::default_heartbeat_seconds")]
+    pub heartbeat_seconds: u64,
+    /// Events kept per account for sessions that reconnect with a last event id.
+    #[serde(default = "RealtimeConfig::default_replay_size")]
+    pub replay_size: usize,
+    #[serde(default = "RealtimeConfig::default_replay_seconds")]
+    pub replay_seconds: i64,
+    /// Events buffered for a session before it counts as lagging behind.
+    #[serde(default = "RealtimeConfig::default_session_buffer")]
+    pub session_buffer: usize,
+}
+
+impl RealtimeConfig {
+    fn default_heartbeat_seconds() -> u64 {
+        25
+    }
+
+    fn default_replay_size() -> usize {
+        100
+    }
+
+    fn default_replay_seconds() -> i64 {
+        600
+    }
+
+    fn default_session_buffer() -> usize {
+        64
+    }
+}
+
+impl Default for RealtimeConfig {
+    fn default() -> Self {
+        Self {
+            redis_fanout: false,
+            heartbeat_seconds: Self::default_heartbeat_seconds(),
+            replay_size: Self::default_replay_size(),
+            replay_seconds: Self::default_replay_seconds(),
+            session_buffer: Self::default_session_buffer(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MessagingConfig {
+    /// `FCM` delivers through Firebase Cloud Messaging, `MOCK` keeps pushes in the process for local runs.
+    #[serde(default = "MessagingConfig::default_transport")]
+    pub transport: String,
+    /// Falls back to the project of the service account when empty.
+    #[serde(default)]
+    pub fcm_project_id: String,
+    /// Service account key file, relative to the project root.
+    #[serde(default)]
+    pub fcm_credentials_path: String,
+}
+
+impl MessagingConfig {
+    fn default_transport() -> String {
+        "MOCK".to_string()
+    }
+}
+
+impl Default for MessagingConfig {
+    fn default() -> Self {
+        Self {
+            transport: Self::default_transport(),
+            fcm_project_id: String::new(),
+            fcm_credentials_path: String::new(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MailConfig {
+    /// `SMTP` sends through the configured relay, `MOCK` only logs the mails for local runs.
+    #[serde(default = "MailConfig::default_transport")]
+    pub transport: String,
+    #[serde(default)]
+    pub smtp_host: String,
+    #[serde(default = "MailConfig::default_smtp_port")]
+    pub smtp_port: u16,
+    #[serde(default)]
+    pub smtp_username: String,
+    #[serde(default)]
+    pub smtp_password: String,
+    /// Sender of outgoing mails, e.g. `Visionary <no-reply@example.com>`.
+    #[serde(default)]
+    pub from: String,
+}
+
+impl MailConfig {
+    fn default_transport() -> String {
+        "MOCK".to_string()
+    }
+
+    fn default_smtp_port() -> u16 {
+        587
+    }
+}
+
+impl Default for MailConfig {
+    fn default() -> Self {
+        Self {
+            transport: Self::default_transport(),
+            smtp_host: String::new(),
+            smtp_port: Self::default_smtp_port(),
+            smtp_username: String::new(),
+            smtp_password: String::new(),
+            from: String::new(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MediaConfig {
+    /// `LOCAL` keeps files under `local_path`, `S3` stores them in an S3-compatible bucket.
+    #[serde(default = "MediaConfig::default_storage")]
+    pub storage: String,
+    /// Relative to the project root.
+    #[serde(default = "MediaConfig::default_local_path")]
+    pub local_path: String,
+    /// Origin files are served from, e.g. a CDN. Falls back to the API for local files and to the bucket for S3.
+    #[serde(default)]
+    pub public_base_url: String,
+    #[serde(default)]
+    pub s3_endpoint: String,
+    #[serde(default = "MediaConfig::default_s3_region")]
+    pub s3_region: String,
+    #[serde(default)]
+    pub s3_bucket: String,
+    #[serde(default)]
+    pub s3_access_key: String,
+    #[serde(default)]
+    pub s3_secret_key: String,
+    /// MinIO and most self-hosted stand-ins only understand path-style bucket addressing.
+    #[serde(default = "MediaConfig::default_s3_path_style")]
+    pub s3_path_style: bool,
+    #[serde(default = "MediaConfig::default_max_upload_bytes")]
+    pub max_upload_bytes: usize,
+    /// Larger images are rejected before decoding.
+    #[serde(default = "MediaConfig::default_max_dimension")]
+    pub max_dimension: u32,
+    /// Variants as `name:width`, images are only ever scaled down.
+    #[serde(default = "MediaConfig::default_avatar_variants", deserialize_with = "deserialize_comma_separated")]
+    pub avatar_variants: Vec<String>,
+    #[serde(default = "MediaConfig::default_image_variants", deserialize_with = "deserialize_comma_separated")]
+    pub blog_image_variants: Vec<String>,
+    #[serde(default = "MediaConfig::default_image_variants", deserialize_with = "deserialize_comma_separated")]
+    pub project_cover_variants: Vec<String>,
+    /// How long an asset nothing references is kept before it is collected.
+    #[serde(default = "MediaConfig::default_orphan_grace_seconds")]
+    pub orphan_grace_seconds: i64,
+    /// Lifetime of a presigned direct upload URL.
+    #[serde(default = "MediaConfig::default_presign_expiry_seconds")]
+    pub presign_expiry_seconds: u32,
+    /// How long after its URL expired a direct upload can still be completed before it is cleaned up.
+    #[serde(default = "MediaConfig::default_pending_upload_grace_seconds")]
+    pub pending_upload_grace_seconds: i64,
+    #[serde(default = "MediaConfig::default_max_attachment_bytes")]
+    pub max_attachment_bytes: i64,
+    /// A single presigned PUT cannot exceed 5 GiB.
+    #[serde(default = "MediaConfig::default_max_video_bytes")]
+    pub max_video_bytes: i64,
+    #[serde(default = "MediaConfig::default_attachment_types", deserialize_with = "deserialize_comma_separated")]
+    pub attachment_types: Vec<String>,
+    #[serde(default = "MediaConfig::default_video_types", deserialize_with = "deserialize_comma_separated")]
+    pub video_types: Vec<String>,
+}
+
+impl MediaConfig {
+    fn default_storage() -> String {
+        "LOCAL".to_string()
+    }
+
+    fn default_local_path() -> String {
+        "storage/media".to_string()
+    }
+
+    fn default_s3_region() -> String {
+        "us-east-1".to_string()
+    }
+
+    fn default_s3_path_style() -> bool {
+        true
+    }
+
+    fn default_max_upload_bytes() -> usize {
+        10 * 1024 * 1024
+    }
+
+    fn default_max_dimension() -> u32 {
+        8192
+    }
+
+    fn default_avatar_variants() -> Vec<String> {
+        vec!["small:64".to_string(), "medium:256".to_string()]
+    }
+
+    fn default_image_variants() -> Vec<String> {
+        vec!["medium:960".to_string(), "large:1920".to_string()]
+    }
+
+    fn default_orphan_grace_seconds() -> i64 {
+        24 * 60 * 60
+    }
+
+    fn default_presign_expiry_seconds() -> u32 {
+        15 * 60
+    }
+
+    fn default_pending_upload_grace_seconds() -> i64 {
+        60 * 60
+    }
+
+    fn default_max_attachment_bytes() -> i64 {
+        100 * 1024 * 1024
+    }
+
+    fn default_max_video_bytes() -> i64 {
+        5 * 1024 * 1024 * 1024
+    }
+
+    fn default_attachment_types() -> Vec<String> {
+        ["application/pdf", "application/zip", "text/plain", "image/png", "image/jpeg", "image/gif", "image/webp"]
+            .map(String::from)
+            .to_vec()
+    }
+
+    fn default_video_types() -> Vec<String> {
+        ["video/mp4", "video/webm", "video/quicktime"].map(String::from).to_vec()
+    }
+}
+
+impl Default for MediaConfig {
+    fn default() -> Self {
+        Self {
+            storage: Self::default_storage(),
+            local_path: Self::default_local_path(),
+            public_base_url: String::new(),
+            s3_endpoint: String::new(),
+            s3_region: Self::default_s3_region(),
+            s3_bucket: String::new(),
+            s3_access_key: String::new(),
+            s3_secret_key: String::new(),
+            s3_path_style: Self::default_s3_path_style(),
+            max_upload_bytes: Self::default_max_upload_bytes(),
+            max_dimension: Self::default_max_dimension(),
+            avatar_variants: Self::default_avatar_variants(),
+            blog_image_variants: Self::default_image_variants(),
+            project_cover_variants: Self::default_image_variants(),
+            orphan_grace_seconds: Self::default_orphan_grace_seconds(),
+            presign_expiry_seconds: Self::default_presign_expiry_seconds(),
+            pending_upload_grace_seconds: Self::default_pending_upload_grace_seconds(),
+            max_attachment_bytes: Self::default_max_attachment_bytes(),
+            max_video_bytes: Self::default_max_video_bytes(),
+            attachment_types: Self::default_attachment_types(),
+            video_types: Self::default_video_types(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AccountConfig {
+    /// How long a scheduled deletion can still be cancelled by reactivating the account.
+    #[serde(default = "AccountConfig::default_deletion_grace_seconds")]
+    pub deletion_grace_seconds: i64,
+    /// `ANONYMIZE` hands the blogs of an erased account over to the ghost account, `DELETE` deletes them.
+    #[serde(default = "AccountConfig::default_blog_deletion_policy")]
+    pub blog_deletion_policy: String,
+    #[serde(default = "AccountConfig::default_ghost_username")]
+    pub ghost_username: String,
+    #[serde(default = "AccountConfig::default_ghost_email")]
+    pub ghost_email: String,
+    /// How long a finished data export can be downloaded before it is deleted.
+    #[serde(default = "AccountConfig::default_export_retention_seconds")]
+    pub export_retention_seconds: i64,
+    /// Lifetime of the access token an admin gets when impersonating an account, it can not be refreshed.
+    #[serde(default = "AccountConfig::default_impersonation_token_seconds")]
+    pub impersonation_token_seconds: i64,
+}
+
+impl AccountConfig {
+    fn default_deletion_grace_seconds() -> i64 {
+        30 * 24 * 60 * 60
+    }
+
+    fn default_blog_deletion_policy() -> String {
+        "ANONYMIZE".to_string()
+    }
+
+    fn default_ghost_username() -> String {
+        "deleted-user".to_string()
+    }
+
+    fn default_ghost_email() -> String {
+        "deleted-user@visionary.invalid".to_string()
+    }
+
+    fn default_export_retention_seconds() -> i64 {
+        7 * 24 * 60 * 60
+    }
+
+    fn default_impersonation_token_seconds() -> i64 {
+        15 * 60
+    }
+}
+
+impl Default for AccountConfig {
+    fn default() -> Self {
+        Self {
+            deletion_grace_seconds: Self::default_deletion_grace_seconds(),
+            blog_deletion_policy: Self::default_blog_deletion_policy(),
+            ghost_username: Self::default_ghost_username(),
+            ghost_email: Self::default_ghost_email(),
+            export_retention_seconds: Self::default_export_retention_seconds(),
+            impersonation_token_seconds: Self::default_impersonation_token_seconds(),
+        }
+    }
+}
+
 #[derive(Deserialize, Debug)]
 pub struct AppConfig {
     pub mode: String,
@@ -79,6 +440,17 @@ pub struct AppConfig {
     pub crypto: CryptoConfig,
     pub jwt: JwtConfig,
     pub oauth2: OAuth2Config,
+    pub site: SiteConfig,
+    #[serde(default)]
+    pub realtime: RealtimeConfig,
+    #[serde(default)]
+    pub messaging: MessagingConfig,
+    #[serde(default)]
+    pub mail: MailConfig,
+    #[serde(default)]
+    pub media: MediaConfig,
+    #[serde(default)]
+    pub accounts: AccountConfig,
 }
 
 impl AppConfig {
@@ -112,6 +484,12 @@ impl AppConfig {
             crypto: deserialized.crypto,
             jwt: deserialized.jwt,
             oauth2: deserialized.oauth2,
+            site: deserialized.site,
+            realtime: deserialized.realtime,
+            messaging: deserialized.messaging,
+            mail: deserialized.mail,
+            media: deserialized.media,
+            accounts: deserialized.accounts,
         }
     }
 }
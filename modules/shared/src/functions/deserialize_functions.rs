@@ -15,3 +15,20 @@ where
     let s: String = Deserialize::deserialize(deserializer)?;
     s.parse::<u32>().map_err(serde::de::Error::custom)
 }
+
+/// Query strings carry booleans as text, `true`/`false` are accepted in any case.
+pub fn deserialize_optional_bool_from_string<'de, D>(deserializer: D) -> Result<Option<bool>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s: Option<String> = Deserialize::deserialize(deserializer)?;
+    s.map(|s| s.to_lowercase().parse::<bool>().map_err(serde::de::Error::custom)).transpose()
+}
+
+pub fn deserialize_optional_i64_from_string<'de, D>(deserializer: D) -> Result<Option<i64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s: Option<String> = Deserialize::deserialize(deserializer)?;
+    s.map(|s| s.parse::<i64>().map_err(serde::de::Error::custom)).transpose()
+}
@@ -0,0 +1,41 @@
+use markdown::mdast::Node;
+use markdown::{CompileOptions, Options, ParseOptions};
+
+pub struct MarkdownFunctions;
+
+impl MarkdownFunctions {
+    /// Render blog post markdown (CommonMark with GFM) to HTML, raw HTML and dangerous link protocols are escaped.
+    pub fn to_html(markdown: &str) -> String {
+        let options = Options {
+            parse: ParseOptions::gfm(),
+            compile: CompileOptions {
+                allow_dangerous_html: false,
+                allow_dangerous_protocol: false,
+                ..CompileOptions::gfm()
+            },
+        };
+
+        // Only MDX constructs can fail to parse, plain markdown always renders
+        markdown::to_html_with_options(markdown, &options)
+            .unwrap_or_else(|_| format!("<p>{}</p>", Self::escape_html(markdown)))
+    }
+
+    /// Plain text of the first paragraph, for summaries.
+    pub fn excerpt(markdown: &str, max_chars: usize) -> String {
+        let text = markdown::to_mdast(markdown, &ParseOptions::gfm())
+            .ok()
+            .and_then(|root| {
+                root.children()?.iter().find(|node| matches!(node, Node::Paragraph(_))).map(|node| node.to_string())
+            })
+            .unwrap_or_default();
+
+        match text.char_indices().nth(max_chars) {
+            Some((index, _)) => format!("{}…", text[..index].trim_end()),
+            None => text,
+        }
+    }
+
+    pub fn escape_html(text: &str) -> String {
+        text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+    }
+}
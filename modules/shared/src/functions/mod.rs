@@ -1,2 +1,3 @@
 pub mod deserialize_functions;
+pub mod markdown_functions;
 pub mod path_functions;
@@ -7,4 +7,12 @@ pub struct Claims {
     pub exp: i64,
     pub iat: i64,
     pub role: Option<String>,
+    /// Set when the token was issued to someone acting as the subject, see RFC 8693.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub act: Option<ActorClaim>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ActorClaim {
+    pub sub: String, // Account ID of the actor
 }
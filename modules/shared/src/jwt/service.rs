@@ -3,7 +3,7 @@ use jsonwebtoken::{Header, TokenData, Validation, decode, encode};
 
 // internal modules
 use crate::configs::APP_CONFIG;
-use crate::jwt::claims::Claims;
+use crate::jwt::claims::{ActorClaim, Claims};
 use crate::jwt::keys::KEY_MANAGER;
 use crate::models::failure::Failure;
 
@@ -19,6 +19,7 @@ impl JwtService {
             exp: expiry.timestamp(),
             iat: now.timestamp(),
             role,
+            act: None,
         };
 
         let header = Header::new(KEY_MANAGER.access_keys.algorithm);
@@ -27,6 +28,29 @@ impl JwtService {
             .map_err(|e| Failure::InternalServerError(format!("Failed to generate access token: {}", e)))
     }
 
+    /// Access token of `account_id` carrying `actor_id` as the `act` claim, no refresh token goes with it.
+    pub fn generate_impersonation_token(
+        account_id: &str,
+        actor_id: &str,
+        jti: &str,
+        role: Option<String>,
+    ) -> Result<String, Failure> {
+        let now = chrono::Utc::now();
+        let expiry = now + Duration::seconds(APP_CONFIG.accounts.impersonation_token_seconds);
+        let claims = Claims {
+            sub: account_id.to_string(),
+            jti: jti.to_string(),
+            exp: expiry.timestamp(),
+            iat: now.timestamp(),
+            role,
+            act: Some(ActorClaim { sub: actor_id.to_string() }),
+        };
+
+        let header = Header::new(KEY_MANAGER.access_keys.algorithm);
+        encode(&header, &claims, &KEY_MANAGER.access_keys.encoding_key)
+            .map_err(|e| Failure::InternalServerError(format!("Failed to generate impersonation token: {}", e)))
+    }
+
     pub fn generate_refresh_token(account_id: &str, jti: &str, role: Option<String>) -> Result<String, Failure> {
         let now = chrono::Utc::now();
         let expiry = now + Duration::seconds(APP_CONFIG.jwt.refresh_token_expiry);
@@ -36,6 +60,7 @@ impl JwtService {
             exp: expiry.timestamp(),
             iat: now.timestamp(),
             role,
+            act: None,
         };
 
         let header = Header::new(KEY_MANAGER.refresh_keys.algorithm);
@@ -1,21 +1,18 @@
-use mongodb::bson::{Document, doc};
-use std::fmt::Debug;
+use mongodb::bson::{Bson, Document, doc};
+use serde_json::Value;
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap};
 
-pub trait DatabaseFilter: Debug + Clone + Send + Sync {}
+// internal modules
+use crate::models::failure::Failure;
 
-#[derive(Debug, Clone)]
-pub struct MongoFilter {
-    pub criteria: Vec<FilterCriteria>,
-}
+// Bounds on what a single query string can ask for
+static MAX_CRITERIA: usize = 10;
+static MAX_SORT_FIELDS: usize = 3;
+static MAX_VALUE_LENGTH: usize = 256;
+static MAX_LIST_VALUES: usize = 50;
 
-#[derive(Debug, Clone)]
-pub struct FilterCriteria {
-    pub field: String,
-    pub operator: FilterOperator,
-    pub value: FilterValue,
-}
-
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum FilterOperator {
     Eq,
     Ne,
@@ -30,7 +27,91 @@ pub enum FilterOperator {
     EndsWith,
 }
 
-#[derive(Debug, Clone)]
+impl FilterOperator {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FilterOperator::Eq => "eq",
+            FilterOperator::Ne => "ne",
+            FilterOperator::Gt => "gt",
+            FilterOperator::Gte => "gte",
+            FilterOperator::Lt => "lt",
+            FilterOperator::Lte => "lte",
+            FilterOperator::In => "in",
+            FilterOperator::NotIn => "nin",
+            FilterOperator::Contains => "contains",
+            FilterOperator::StartsWith => "startsWith",
+            FilterOperator::EndsWith => "endsWith",
+        }
+    }
+
+    pub fn from_string(operator: &str) -> Result<Self, Failure> {
+        match operator {
+            "eq" => Ok(FilterOperator::Eq),
+            "ne" => Ok(FilterOperator::Ne),
+            "gt" => Ok(FilterOperator::Gt),
+            "gte" => Ok(FilterOperator::Gte),
+            "lt" => Ok(FilterOperator::Lt),
+            "lte" => Ok(FilterOperator::Lte),
+            "in" => Ok(FilterOperator::In),
+            "nin" => Ok(FilterOperator::NotIn),
+            "contains" => Ok(FilterOperator::Contains),
+            "startsWith" => Ok(FilterOperator::StartsWith),
+            "endsWith" => Ok(FilterOperator::EndsWith),
+            _ => Err(Failure::BadRequest(format!("Unknown filter operator: {}", operator))),
+        }
+    }
+
+    fn is_list(&self) -> bool {
+        matches!(self, FilterOperator::In | FilterOperator::NotIn)
+    }
+}
+
+/// Operator sets the allow-lists are built from.
+pub static TEXT_OPERATORS: &[FilterOperator] = &[
+    FilterOperator::Eq,
+    FilterOperator::Ne,
+    FilterOperator::In,
+    FilterOperator::NotIn,
+    FilterOperator::Contains,
+    FilterOperator::StartsWith,
+    FilterOperator::EndsWith,
+];
+pub static ENUM_OPERATORS: &[FilterOperator] =
+    &[FilterOperator::Eq, FilterOperator::Ne, FilterOperator::In, FilterOperator::NotIn];
+pub static RANGE_OPERATORS: &[FilterOperator] = &[
+    FilterOperator::Eq,
+    FilterOperator::Ne,
+    FilterOperator::Gt,
+    FilterOperator::Gte,
+    FilterOperator::Lt,
+    FilterOperator::Lte,
+];
+pub static BOOLEAN_OPERATORS: &[FilterOperator] = &[FilterOperator::Eq];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldType {
+    String,
+    Number,
+    Boolean,
+}
+
+/// A field clients may filter, sort or select, `name` as the API spells it and `column` as it is stored.
+#[derive(Debug, Clone, Copy)]
+pub struct FilterField {
+    pub name: &'static str,
+    pub column: &'static str,
+    pub field_type: FieldType,
+    /// Empty when the field can not be filtered on.
+    pub operators: &'static [FilterOperator],
+    pub sortable: bool,
+}
+
+/// Entities that can be listed with a `QuerySpec`, fields missing from the allow-list are rejected.
+pub trait Filterable {
+    fn filter_fields() -> &'static [FilterField];
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub enum FilterValue {
     String(String),
     Number(i64),
@@ -39,15 +120,284 @@ pub enum FilterValue {
     NumberArray(Vec<i64>),
 }
 
-impl DatabaseFilter for MongoFilter {}
+impl FilterValue {
+    fn parse(field: &FilterField, operator: FilterOperator, raw: &str) -> Result<Self, Failure> {
+        if raw.len() > MAX_VALUE_LENGTH {
+            return Err(Failure::BadRequest(format!("Filter value of {} is too long", field.name)));
+        }
+
+        let invalid = || Failure::BadRequest(format!("Invalid value for {}: {}", field.name, raw));
+        let number = |value: &str| value.trim().parse::<i64>().map_err(|_| invalid());
+        match (field.field_type, operator.is_list()) {
+            (FieldType::String, false) => Ok(FilterValue::String(raw.to_string())),
+            (FieldType::Number, false) => Ok(FilterValue::Number(number(raw)?)),
+            (FieldType::Boolean, false) => raw.parse::<bool>().map(FilterValue::Boolean).map_err(|_| invalid()),
+            (field_type, true) => {
+                let values: Vec<&str> = raw.split(',').filter(|value| !value.is_empty()).collect();
+                if values.is_empty() || values.len() > MAX_LIST_VALUES {
+                    return Err(Failure::BadRequest(format!(
+                        "Filter {} of {} takes between 1 and {} values",
+                        operator.as_str(),
+                        field.name,
+                        MAX_LIST_VALUES
+                    )));
+                }
 
+                match field_type {
+                    FieldType::Number => Ok(FilterValue::NumberArray(
+                        values.into_iter().map(number).collect::<Result<Vec<i64>, Failure>>()?,
+                    )),
+                    _ => Ok(FilterValue::StringArray(values.into_iter().map(|value| value.to_string()).collect())),
+                }
+            },
+        }
+    }
+}
+
+/// One condition of a spec, `field` is the stored column.
 #[derive(Debug, Clone)]
-pub struct ScyllaFilter {
-    pub partition_keys: Vec<(String, ScyllaValue)>,
-    pub clustering_conditions: Vec<ClusteringCondition>,
+pub struct FilterCriteria {
+    pub field: String,
+    pub operator: FilterOperator,
+    pub value: FilterValue,
+}
+
+impl FilterCriteria {
+    pub fn new(field: &str, operator: FilterOperator, value: FilterValue) -> Self {
+        Self { field: field.to_string(), operator, value }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortDirection {
+    Ascending,
+    Descending,
+}
+
+/// `field` is the stored column.
+#[derive(Debug, Clone)]
+pub struct SortCriteria {
+    pub field: String,
+    pub direction: SortDirection,
+}
+
+/// What to list, parsed from query strings such as `?filter[name][contains]=rust&sort=-createdAt&fields=name`.
+/// Every field was checked against the entity allow-list, so repositories can use the spec as is.
+#[derive(Debug, Clone, Default)]
+pub struct QuerySpec {
+    pub criteria: Vec<FilterCriteria>,
+    pub sort: Vec<SortCriteria>,
+    /// API names of the fields to keep in the response, `None` keeps every field.
+    pub fields: Option<Vec<String>>,
+}
+
+impl QuerySpec {
+    /// Build the spec from the query string, parameters other than `filter[..]`, `sort` and `fields` are left to
+    /// the caller. `filter[field]=value` is a shorthand for `filter[field][eq]=value`.
+    pub fn parse(params: &HashMap<String, String>, allow_list: &[FilterField]) -> Result<Self, Failure> {
+        let find_field = |name: &str| {
+            allow_list
+                .iter()
+                .find(|field| field.name == name)
+                .ok_or_else(|| Failure::BadRequest(format!("Unknown field: {}", name)))
+        };
+
+        // Sorted so the same query string always yields the same spec
+        let filters: BTreeMap<&String, &String> = params.iter().collect();
+        let mut spec = QuerySpec::default();
+        for (key, raw) in filters {
+            let Some(path) = key.strip_prefix("filter[").and_then(|path| path.strip_suffix(']')) else {
+                continue;
+            };
+            let (name, operator) = match path.split_once("][") {
+                Some((name, operator)) => (name, FilterOperator::from_string(operator)?),
+                None => (path, FilterOperator::Eq),
+            };
+
+            let field = find_field(name)?;
+            if !field.operators.contains(&operator) {
+                return Err(Failure::BadRequest(format!(
+                    "Field {} can not be filtered with {}",
+                    field.name,
+                    operator.as_str()
+                )));
+            }
+
+            let value = FilterValue::parse(field, operator, raw)?;
+            spec.criteria.push(FilterCriteria::new(field.column, operator, value));
+        }
+        if spec.criteria.len() > MAX_CRITERIA {
+            return Err(Failure::BadRequest(format!("At most {} filters can be combined", MAX_CRITERIA)));
+        }
+
+        if let Some(sort) = params.get("sort") {
+            for token in sort.split(',').filter(|token| !token.is_empty()) {
+                let (name, direction) = match token.strip_prefix('-') {
+                    Some(name) => (name, SortDirection::Descending),
+                    None => (token.strip_prefix('+').unwrap_or(token), SortDirection::Ascending),
+                };
+
+                let field = find_field(name)?;
+                if !field.sortable {
+                    return Err(Failure::BadRequest(format!("Field {} can not be sorted on", field.name)));
+                }
+                spec.sort.push(SortCriteria { field: field.column.to_string(), direction });
+            }
+            if spec.sort.len() > MAX_SORT_FIELDS {
+                return Err(Failure::BadRequest(format!("At most {} sort fields can be combined", MAX_SORT_FIELDS)));
+            }
+        }
+
+        if let Some(fields) = params.get("fields") {
+            let fields = fields
+                .split(',')
+                .filter(|name| !name.is_empty())
+                .map(|name| find_field(name).map(|field| field.name.to_string()))
+                .collect::<Result<Vec<String>, Failure>>()?;
+            spec.fields = Some(fields);
+        }
+
+        Ok(spec)
+    }
+
+    /// Add a condition the caller enforces regardless of the query string.
+    pub fn with_criteria(mut self, criteria: FilterCriteria) -> Self {
+        self.criteria.push(criteria);
+        self
+    }
+
+    /// Keep only the selected fields of a serialized entity, `id` is always kept.
+    pub fn project(&self, value: Value) -> Value {
+        let (Some(fields), Value::Object(object)) = (&self.fields, &value) else {
+            return value;
+        };
+
+        let projected = object
+            .iter()
+            .filter(|(key, _)| key.as_str() == "id" || fields.contains(key))
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect();
+        Value::Object(projected)
+    }
 }
 
-impl DatabaseFilter for ScyllaFilter {}
+// region =================================== MONGO ===================================
+pub struct MongoFilterConverter;
+
+impl MongoFilterConverter {
+    /// The conditions of the spec, conditions on the same field are merged. Soft deletion is left to the caller.
+    pub fn convert_to_mongo_filter(spec: &QuerySpec) -> Document {
+        let mut filter = Document::new();
+        for criteria in &spec.criteria {
+            let condition = Self::convert_criteria(criteria);
+            match filter.get_mut(&criteria.field) {
+                Some(Bson::Document(existing)) => existing.extend(condition),
+                _ => {
+                    filter.insert(&criteria.field, condition);
+                },
+            }
+        }
+
+        filter
+    }
+
+    /// `None` when the spec does not sort, the repository default applies then.
+    pub fn convert_to_mongo_sort(spec: &QuerySpec) -> Option<Document> {
+        if spec.sort.is_empty() {
+            return None;
+        }
+
+        let mut sort = Document::new();
+        for criteria in &spec.sort {
+            let direction = match criteria.direction {
+                SortDirection::Ascending => 1,
+                SortDirection::Descending => -1,
+            };
+            sort.insert(&criteria.field, direction);
+        }
+
+        Some(Self::with_tiebreaker(sort))
+    }
+
+    /// Order documents with equal sort keys by `_id`, in the direction of the last key, so pages never overlap.
+    pub fn with_tiebreaker(mut sort: Document) -> Document {
+        if !sort.contains_key("_id") {
+            let direction = sort.iter().last().map(|(_, direction)| direction.clone()).unwrap_or(Bson::Int32(1));
+            sort.insert("_id", direction);
+        }
+
+        sort
+    }
+
+    fn to_bson(value: &FilterValue) -> Bson {
+        match value {
+            FilterValue::String(v) => Bson::String(v.clone()),
+            FilterValue::Number(v) => Bson::Int64(*v),
+            FilterValue::Boolean(v) => Bson::Boolean(*v),
+            FilterValue::StringArray(v) => Bson::Array(v.iter().cloned().map(Bson::String).collect()),
+            FilterValue::NumberArray(v) => Bson::Array(v.iter().copied().map(Bson::Int64).collect()),
+        }
+    }
+
+    fn convert_criteria(criteria: &FilterCriteria) -> Document {
+        let value = Self::to_bson(&criteria.value);
+        match criteria.operator {
+            FilterOperator::Eq => doc! { "$eq": value },
+            FilterOperator::Ne => doc! { "$ne": value },
+            FilterOperator::Gt => doc! { "$gt": value },
+            FilterOperator::Gte => doc! { "$gte": value },
+            FilterOperator::Lt => doc! { "$lt": value },
+            FilterOperator::Lte => doc! { "$lte": value },
+            FilterOperator::In => doc! { "$in": value },
+            FilterOperator::NotIn => doc! { "$nin": value },
+
+            // User input is matched literally, never as a pattern
+            FilterOperator::Contains => Self::regex(&criteria.value, regex::escape),
+            FilterOperator::StartsWith => Self::regex(&criteria.value, |v| format!("^{}", regex::escape(v))),
+            FilterOperator::EndsWith => Self::regex(&criteria.value, |v| format!("{}$", regex::escape(v))),
+        }
+    }
+
+    fn regex(value: &FilterValue, pattern: impl Fn(&str) -> String) -> Document {
+        match value {
+            FilterValue::String(v) => doc! { "$regex": pattern(v), "$options": "i" },
+            _ => doc! {},
+        }
+    }
+}
+// endregion =================================== MONGO ===================================
+
+// region =================================== SCYLLA ===================================
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CqlKeyKind {
+    Partition,
+    Clustering,
+    /// Secondary index, equality only.
+    Indexed,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CqlColumnType {
+    Text,
+    Int,
+    BigInt,
+    Boolean,
+    Uuid,
+}
+
+/// A column a CQL query can restrict without `ALLOW FILTERING`.
+#[derive(Debug, Clone, Copy)]
+pub struct CqlKey {
+    pub column: &'static str,
+    pub kind: CqlKeyKind,
+    pub column_type: CqlColumnType,
+}
+
+impl CqlKey {
+    pub const fn new(column: &'static str, kind: CqlKeyKind, column_type: CqlColumnType) -> Self {
+        Self { column, kind, column_type }
+    }
+}
 
 #[derive(Debug, Clone)]
 pub enum ScyllaValue {
@@ -56,71 +406,618 @@ pub enum ScyllaValue {
     BigInt(i64),
     Boolean(bool),
     Uuid(uuid::Uuid),
+    List(Vec<ScyllaValue>),
 }
 
-#[derive(Debug, Clone)]
-pub struct ClusteringCondition {
-    pub column: String,
-    pub operator: ScyllaOperator,
-    pub value: ScyllaValue,
-}
-
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ScyllaOperator {
     Eq,
+    In,
     Gt,
     Gte,
     Lt,
     Lte,
 }
 
-pub struct MongoFilterConverter;
+impl ScyllaOperator {
+    fn as_cql(&self) -> &'static str {
+        match self {
+            ScyllaOperator::Eq => "=",
+            ScyllaOperator::In => "IN",
+            ScyllaOperator::Gt => ">",
+            ScyllaOperator::Gte => ">=",
+            ScyllaOperator::Lt => "<",
+            ScyllaOperator::Lte => "<=",
+        }
+    }
+}
 
-impl MongoFilterConverter {
-    pub fn convert_to_mongo_filter(query: &MongoFilter) -> Document {
-        let mut filter = doc! {
-            "deleted_at": { "$exists": false }
-        };
+#[derive(Debug, Clone)]
+pub struct ScyllaCondition {
+    pub column: String,
+    pub operator: ScyllaOperator,
+    pub value: ScyllaValue,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ScyllaFilter {
+    pub conditions: Vec<ScyllaCondition>,
+    pub order_by: Option<SortCriteria>,
+}
 
-        for criteria in &query.criteria {
-            let mongo_criteria = Self::convert_criteria(criteria);
-            filter.insert(&criteria.field, mongo_criteria);
+impl ScyllaFilter {
+    /// ` WHERE ...` with a bind marker per condition, empty without conditions.
+    pub fn where_clause(&self) -> String {
+        if self.conditions.is_empty() {
+            return String::new();
         }
 
-        filter
+        let conditions: Vec<String> = self
+            .conditions
+            .iter()
+            .map(|condition| format!("{} {} ?", condition.column, condition.operator.as_cql()))
+            .collect();
+        format!(" WHERE {}", conditions.join(" AND "))
     }
 
-    fn convert_criteria(criteria: &FilterCriteria) -> mongodb::bson::Bson {
-        match (&criteria.operator, &criteria.value) {
-            (FilterOperator::Eq, FilterValue::String(v)) => doc! { "$eq": v }.into(),
-            (FilterOperator::Eq, FilterValue::Number(v)) => doc! { "$eq": v }.into(),
-            (FilterOperator::Eq, FilterValue::Boolean(v)) => doc! { "$eq": v }.into(),
+    /// ` ORDER BY ...`, empty when the clustering order applies.
+    pub fn order_clause(&self) -> String {
+        match &self.order_by {
+            Some(SortCriteria { field, direction: SortDirection::Ascending }) => format!(" ORDER BY {} ASC", field),
+            Some(SortCriteria { field, direction: SortDirection::Descending }) => format!(" ORDER BY {} DESC", field),
+            None => String::new(),
+        }
+    }
 
-            (FilterOperator::Ne, FilterValue::String(v)) => doc! { "$ne": v }.into(),
-            (FilterOperator::Ne, FilterValue::Number(v)) => doc! { "$ne": v }.into(),
+    /// Values of the bind markers, in order.
+    pub fn values(&self) -> Vec<ScyllaValue> {
+        self.conditions.iter().map(|condition| condition.value.clone()).collect()
+    }
+}
 
-            (FilterOperator::Gt, FilterValue::Number(v)) => doc! { "$gt": v }.into(),
-            (FilterOperator::Gte, FilterValue::Number(v)) => doc! { "$gte": v }.into(),
-            (FilterOperator::Lt, FilterValue::Number(v)) => doc! { "$lt": v }.into(),
-            (FilterOperator::Lte, FilterValue::Number(v)) => doc! { "$lte": v }.into(),
+pub struct ScyllaFilterConverter;
 
-            (FilterOperator::In, FilterValue::StringArray(v)) => doc! { "$in": v }.into(),
-            (FilterOperator::In, FilterValue::NumberArray(v)) => doc! { "$in": v }.into(),
+impl ScyllaFilterConverter {
+    /// Compile the spec against the keys of the table. Only queries Scylla answers without `ALLOW FILTERING` are
+    /// accepted: the whole partition key restricted by equality, clustering columns under a restricted partition
+    /// and a single secondary index.
+    pub fn convert_to_scylla_filter(spec: &QuerySpec, keys: &[CqlKey]) -> Result<ScyllaFilter, Failure> {
+        let unsupported = |message: String| Err(Failure::BadRequest(message));
 
-            (FilterOperator::NotIn, FilterValue::StringArray(v)) => doc! { "$nin": v }.into(),
-            (FilterOperator::NotIn, FilterValue::NumberArray(v)) => doc! { "$nin": v }.into(),
+        let mut filter = ScyllaFilter::default();
+        for criteria in &spec.criteria {
+            let Some(key) = keys.iter().find(|key| key.column == criteria.field) else {
+                return unsupported(format!("Filtering on {} is not supported by this database", criteria.field));
+            };
 
-            (FilterOperator::Contains, FilterValue::String(v)) => doc! { "$regex": v, "$options": "i" }.into(),
+            let operator = match (key.kind, criteria.operator) {
+                (_, FilterOperator::Eq) => ScyllaOperator::Eq,
+                (CqlKeyKind::Partition | CqlKeyKind::Clustering, FilterOperator::In) => ScyllaOperator::In,
+                (CqlKeyKind::Clustering, FilterOperator::Gt) => ScyllaOperator::Gt,
+                (CqlKeyKind::Clustering, FilterOperator::Gte) => ScyllaOperator::Gte,
+                (CqlKeyKind::Clustering, FilterOperator::Lt) => ScyllaOperator::Lt,
+                (CqlKeyKind::Clustering, FilterOperator::Lte) => ScyllaOperator::Lte,
+                (_, operator) => {
+                    return unsupported(format!(
+                        "Filter {} on {} is not supported by this database",
+                        operator.as_str(),
+                        criteria.field
+                    ));
+                },
+            };
+
+            let value = Self::to_scylla_value(key, &criteria.value)?;
+            filter.conditions.push(ScyllaCondition { column: key.column.to_string(), operator, value });
+        }
 
-            (FilterOperator::StartsWith, FilterValue::String(v)) => {
-                doc! { "$regex": format!("^{}", v), "$options": "i" }.into()
+        // The partition is either fully restricted or not at all
+        let restricted = |kind: CqlKeyKind| {
+            keys.iter()
+                .filter(|key| key.kind == kind)
+                .filter(|key| filter.conditions.iter().any(|condition| condition.column == key.column))
+                .count()
+        };
+        let partition_keys = keys.iter().filter(|key| key.kind == CqlKeyKind::Partition).count();
+        let partition_restricted = restricted(CqlKeyKind::Partition);
+        if partition_restricted > 0 && partition_restricted < partition_keys {
+            return unsupported("Every partition key has to be filtered on together".to_string());
+        }
+        let has_partition = partition_keys > 0 && partition_restricted == partition_keys;
+        if !has_partition && restricted(CqlKeyKind::Clustering) > 0 {
+            return unsupported("Clustering columns can only be filtered on within a partition".to_string());
+        }
+        if restricted(CqlKeyKind::Indexed) > 1 || (has_partition && restricted(CqlKeyKind::Indexed) > 0) {
+            return unsupported("Only a single indexed field can be filtered on by this database".to_string());
+        }
+
+        // Rows come in clustering order, only the first clustering column of a single partition can be reversed
+        let first_clustering = keys.iter().find(|key| key.kind == CqlKeyKind::Clustering);
+        match spec.sort.as_slice() {
+            [] => {},
+            [sort] if has_partition && first_clustering.is_some_and(|key| key.column == sort.field) => {
+                let single_partition = filter
+                    .conditions
+                    .iter()
+                    .filter(|condition| {
+                        keys.iter().any(|key| key.kind == CqlKeyKind::Partition && key.column == condition.column)
+                    })
+                    .all(|condition| condition.operator != ScyllaOperator::In);
+                if !single_partition {
+                    return unsupported("Sorting is only supported within a single partition".to_string());
+                }
+                filter.order_by = Some(sort.clone());
             },
+            _ => return unsupported("This sort is not supported by this database".to_string()),
+        }
+
+        Ok(filter)
+    }
 
-            (FilterOperator::EndsWith, FilterValue::String(v)) => {
-                doc! { "$regex": format!("{}$", v), "$options": "i" }.into()
+    fn to_scylla_value(key: &CqlKey, value: &FilterValue) -> Result<ScyllaValue, Failure> {
+        let invalid = || Failure::BadRequest(format!("Invalid value for {}", key.column));
+        let uuid = |v: &String| uuid::Uuid::parse_str(v).map(ScyllaValue::Uuid).map_err(|_| invalid());
+        let number = |n: i64| match key.column_type {
+            CqlColumnType::Int => i32::try_from(n).map(ScyllaValue::Int).map_err(|_| invalid()),
+            CqlColumnType::BigInt => Ok(ScyllaValue::BigInt(n)),
+            _ => Err(invalid()),
+        };
+
+        match (key.column_type, value) {
+            (CqlColumnType::Text, FilterValue::String(v)) => Ok(ScyllaValue::String(v.clone())),
+            (CqlColumnType::Uuid, FilterValue::String(v)) => uuid(v),
+            (CqlColumnType::Boolean, FilterValue::Boolean(v)) => Ok(ScyllaValue::Boolean(*v)),
+            (_, FilterValue::Number(v)) => number(*v),
+            (CqlColumnType::Text, FilterValue::StringArray(v)) => {
+                Ok(ScyllaValue::List(v.iter().cloned().map(ScyllaValue::String).collect()))
+            },
+            (CqlColumnType::Uuid, FilterValue::StringArray(v)) => {
+                Ok(ScyllaValue::List(v.iter().map(uuid).collect::<Result<Vec<_>, Failure>>()?))
+            },
+            (_, FilterValue::NumberArray(v)) => {
+                Ok(ScyllaValue::List(v.iter().map(|n| number(*n)).collect::<Result<Vec<_>, Failure>>()?))
             },
+            _ => Err(invalid()),
+        }
+    }
+}
+// endregion =================================== SCYLLA ===================================
+
+// region =================================== MEMORY ===================================
+/// Runs a spec over entities already loaded, for listings a backend can only serve by scanning.
+/// Entities are matched as serialized, the allow-list that produced the spec maps columns back to fields.
+pub struct MemoryFilter;
+
+impl MemoryFilter {
+    pub fn matches(spec: &QuerySpec, allow_list: &[FilterField], entity: &Value) -> bool {
+        spec.criteria.iter().all(|criteria| {
+            let value = Self::field_value(allow_list, &criteria.field, entity);
+            Self::matches_criteria(criteria, value)
+        })
+    }
+
+    /// Order of two entities under the spec sort, `Equal` when the spec does not sort.
+    pub fn compare(spec: &QuerySpec, allow_list: &[FilterField], a: &Value, b: &Value) -> Ordering {
+        for sort in &spec.sort {
+            let ordering = Self::compare_values(
+                Self::field_value(allow_list, &sort.field, a),
+                Self::field_value(allow_list, &sort.field, b),
+            );
+            let ordering = match sort.direction {
+                SortDirection::Ascending => ordering,
+                SortDirection::Descending => ordering.reverse(),
+            };
+            if ordering != Ordering::Equal {
+                return ordering;
+            }
+        }
+
+        Ordering::Equal
+    }
+
+    fn field_value<'a>(allow_list: &[FilterField], column: &str, entity: &'a Value) -> &'a Value {
+        allow_list
+            .iter()
+            .find(|field| field.column == column)
+            .and_then(|field| entity.get(field.name))
+            .unwrap_or(&Value::Null)
+    }
+
+    fn matches_criteria(criteria: &FilterCriteria, value: &Value) -> bool {
+        // Like Mongo, a condition on an array field holds when it holds for any of its elements
+        if let Value::Array(elements) = value {
+            return match criteria.operator {
+                FilterOperator::Ne | FilterOperator::NotIn => {
+                    elements.iter().all(|element| Self::matches_criteria(criteria, element))
+                },
+                _ => elements.iter().any(|element| Self::matches_criteria(criteria, element)),
+            };
+        }
+
+        let ordering = |expected: &FilterValue| match expected {
+            FilterValue::String(expected) => value.as_str().map(|value| value.cmp(expected.as_str())),
+            FilterValue::Number(expected) => value.as_i64().map(|value| value.cmp(expected)),
+            FilterValue::Boolean(expected) => value.as_bool().map(|value| value.cmp(expected)),
+            _ => None,
+        };
+        let in_list = || match &criteria.value {
+            FilterValue::StringArray(list) => value.as_str().is_some_and(|value| list.iter().any(|item| item == value)),
+            FilterValue::NumberArray(list) => value.as_i64().is_some_and(|value| list.contains(&value)),
+            _ => false,
+        };
+        let text = |matches: fn(&str, &str) -> bool| match (&criteria.value, value.as_str()) {
+            (FilterValue::String(expected), Some(value)) => matches(&value.to_lowercase(), &expected.to_lowercase()),
+            _ => false,
+        };
+
+        match criteria.operator {
+            FilterOperator::Eq => ordering(&criteria.value) == Some(Ordering::Equal),
+            FilterOperator::Ne => ordering(&criteria.value) != Some(Ordering::Equal),
+            FilterOperator::Gt => ordering(&criteria.value) == Some(Ordering::Greater),
+            FilterOperator::Gte => ordering(&criteria.value).is_some_and(|ordering| ordering != Ordering::Less),
+            FilterOperator::Lt => ordering(&criteria.value) == Some(Ordering::Less),
+            FilterOperator::Lte => ordering(&criteria.value).is_some_and(|ordering| ordering != Ordering::Greater),
+            FilterOperator::In => in_list(),
+            FilterOperator::NotIn => !in_list(),
+            FilterOperator::Contains => text(|value, expected| value.contains(expected)),
+            FilterOperator::StartsWith => text(|value, expected| value.starts_with(expected)),
+            FilterOperator::EndsWith => text(|value, expected| value.ends_with(expected)),
+        }
+    }
+
+    /// Missing values sort first, as they do in Mongo.
+    fn compare_values(a: &Value, b: &Value) -> Ordering {
+        match (a, b) {
+            (Value::Number(a), Value::Number(b)) => {
+                a.as_f64().unwrap_or_default().partial_cmp(&b.as_f64().unwrap_or_default()).unwrap_or(Ordering::Equal)
+            },
+            (Value::String(a), Value::String(b)) => a.cmp(b),
+            (Value::Bool(a), Value::Bool(b)) => a.cmp(b),
+            (Value::Null, Value::Null) => Ordering::Equal,
+            (Value::Null, _) => Ordering::Less,
+            (_, Value::Null) => Ordering::Greater,
+            _ => Ordering::Equal,
+        }
+    }
+}
+// endregion =================================== MEMORY ===================================
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    static FIELDS: &[FilterField] = &[
+        FilterField {
+            name: "name",
+            column: "name",
+            field_type: FieldType::String,
+            operators: TEXT_OPERATORS,
+            sortable: true,
+        },
+        FilterField {
+            name: "status",
+            column: "status",
+            field_type: FieldType::String,
+            operators: ENUM_OPERATORS,
+            sortable: false,
+        },
+        FilterField {
+            name: "createdAt",
+            column: "created_at",
+            field_type: FieldType::Number,
+            operators: RANGE_OPERATORS,
+            sortable: true,
+        },
+        FilterField {
+            name: "isActive",
+            column: "is_active",
+            field_type: FieldType::Boolean,
+            operators: BOOLEAN_OPERATORS,
+            sortable: false,
+        },
+        FilterField { name: "bio", column: "bio", field_type: FieldType::String, operators: &[], sortable: false },
+    ];
+
+    fn parse(pairs: &[(&str, &str)]) -> Result<QuerySpec, Failure> {
+        let params = pairs.iter().map(|(key, value)| (key.to_string(), value.to_string())).collect();
+        QuerySpec::parse(&params, FIELDS)
+    }
+
+    fn spec(pairs: &[(&str, &str)]) -> QuerySpec {
+        parse(pairs).unwrap()
+    }
+
+    fn assert_bad_request(result: Result<impl std::fmt::Debug, Failure>) {
+        assert!(matches!(result, Err(Failure::BadRequest(_))), "{:?} is not a bad request", result);
+    }
+
+    // region =================================== PARSE ===================================
+    #[test]
+    fn filters_map_api_names_to_columns() {
+        let spec = spec(&[("filter[createdAt][gte]", "10"), ("filter[name][contains]", "rust")]);
+
+        assert_eq!(spec.criteria.len(), 2);
+        assert_eq!(spec.criteria[0].field, "created_at");
+        assert_eq!(spec.criteria[0].operator, FilterOperator::Gte);
+        assert_eq!(spec.criteria[0].value, FilterValue::Number(10));
+        assert_eq!(spec.criteria[1].field, "name");
+        assert_eq!(spec.criteria[1].value, FilterValue::String("rust".to_string()));
+    }
+
+    #[test]
+    fn a_filter_without_operator_is_an_equality() {
+        let spec = spec(&[("filter[isActive]", "true")]);
+
+        assert_eq!(spec.criteria[0].operator, FilterOperator::Eq);
+        assert_eq!(spec.criteria[0].value, FilterValue::Boolean(true));
+    }
+
+    #[test]
+    fn list_operators_split_their_values() {
+        let spec = spec(&[("filter[status][in]", "DRAFT,PUBLISHED,"), ("filter[createdAt][eq]", "5")]);
+
+        let status = spec.criteria.iter().find(|criteria| criteria.field == "status").unwrap();
+        assert_eq!(status.value, FilterValue::StringArray(vec!["DRAFT".to_string(), "PUBLISHED".to_string()]));
+    }
 
-            _ => doc! {}.into(),
+    #[test]
+    fn other_parameters_are_left_alone() {
+        let spec = spec(&[("page", "2"), ("pageSize", "10"), ("filter[name]", "rust")]);
+
+        assert_eq!(spec.criteria.len(), 1);
+        assert!(spec.sort.is_empty());
+        assert!(spec.fields.is_none());
+    }
+
+    #[test]
+    fn unknown_fields_and_operators_are_rejected() {
+        assert_bad_request(parse(&[("filter[password]", "x")]));
+        assert_bad_request(parse(&[("filter[name][regex]", "x")]));
+        assert_bad_request(parse(&[("filter[status][contains]", "x")]));
+        assert_bad_request(parse(&[("filter[bio]", "x")]));
+    }
+
+    #[test]
+    fn values_must_match_the_field_type() {
+        assert_bad_request(parse(&[("filter[createdAt][gt]", "yesterday")]));
+        assert_bad_request(parse(&[("filter[isActive]", "yes")]));
+        assert_bad_request(parse(&[("filter[createdAt][in]", "1,x")]));
+    }
+
+    #[test]
+    fn sizes_are_bounded() {
+        let long = "x".repeat(MAX_VALUE_LENGTH + 1);
+        assert_bad_request(parse(&[("filter[name]", long.as_str())]));
+
+        let many = (0..=MAX_LIST_VALUES).map(|index| index.to_string()).collect::<Vec<_>>().join(",");
+        assert_bad_request(parse(&[("filter[status][in]", many.as_str())]));
+        assert_bad_request(parse(&[("filter[status][in]", ",")]));
+
+        let text =
+            ["eq", "ne", "in", "nin", "contains", "startsWith", "endsWith"].map(|op| format!("filter[name][{}]", op));
+        let range = ["gt", "gte", "lt", "lte"].map(|op| format!("filter[createdAt][{}]", op));
+        let params: HashMap<String, String> = text.into_iter().chain(range).map(|key| (key, "1".to_string())).collect();
+        assert!(params.len() > MAX_CRITERIA);
+        assert_bad_request(QuerySpec::parse(&params, FIELDS));
+    }
+
+    #[test]
+    fn sort_reads_directions_in_order() {
+        let spec = spec(&[("sort", "-createdAt,+name")]);
+
+        assert_eq!(spec.sort.len(), 2);
+        assert_eq!(spec.sort[0].field, "created_at");
+        assert_eq!(spec.sort[0].direction, SortDirection::Descending);
+        assert_eq!(spec.sort[1].field, "name");
+        assert_eq!(spec.sort[1].direction, SortDirection::Ascending);
+    }
+
+    #[test]
+    fn sort_only_takes_sortable_fields() {
+        assert_bad_request(parse(&[("sort", "status")]));
+        assert_bad_request(parse(&[("sort", "-unknown")]));
+        assert_bad_request(parse(&[("sort", "name,-name,createdAt,-createdAt")]));
+    }
+
+    #[test]
+    fn fields_keep_api_names_and_projection_keeps_the_id() {
+        let spec = spec(&[("fields", "name,createdAt")]);
+        assert_eq!(spec.fields, Some(vec!["name".to_string(), "createdAt".to_string()]));
+
+        let projected = spec.project(json!({ "id": "1", "name": "Rust", "createdAt": 1, "bio": "secret" }));
+        assert_eq!(projected, json!({ "id": "1", "name": "Rust", "createdAt": 1 }));
+        assert_bad_request(parse(&[("fields", "name,password")]));
+    }
+
+    #[test]
+    fn the_same_query_string_yields_the_same_spec() {
+        let pairs = [("filter[name][contains]", "a"), ("filter[createdAt][gt]", "1"), ("filter[status]", "DRAFT")];
+        let fields = |spec: QuerySpec| spec.criteria.into_iter().map(|criteria| criteria.field).collect::<Vec<_>>();
+
+        for _ in 0..10 {
+            assert_eq!(fields(spec(&pairs)), vec!["created_at", "name", "status"]);
         }
     }
+    // endregion =================================== PARSE ===================================
+
+    // region =================================== MONGO ===================================
+    #[test]
+    fn mongo_merges_conditions_on_the_same_field() {
+        let spec =
+            spec(&[("filter[createdAt][gte]", "10"), ("filter[createdAt][lt]", "20"), ("filter[isActive]", "true")]);
+
+        assert_eq!(
+            MongoFilterConverter::convert_to_mongo_filter(&spec),
+            doc! { "created_at": { "$gte": 10_i64, "$lt": 20_i64 }, "is_active": { "$eq": true } }
+        );
+    }
+
+    #[test]
+    fn mongo_matches_text_literally_regardless_of_case() {
+        let spec = spec(&[("filter[name][startsWith]", "c++ (")]);
+
+        assert_eq!(
+            MongoFilterConverter::convert_to_mongo_filter(&spec),
+            doc! { "name": { "$regex": r"^c\+\+ \(", "$options": "i" } }
+        );
+    }
+
+    #[test]
+    fn mongo_lists_become_arrays() {
+        let spec = spec(&[("filter[status][nin]", "DRAFT,ARCHIVED")]);
+
+        assert_eq!(
+            MongoFilterConverter::convert_to_mongo_filter(&spec),
+            doc! { "status": { "$nin": ["DRAFT", "ARCHIVED"] } }
+        );
+    }
+
+    #[test]
+    fn mongo_sort_ends_on_the_id_in_the_last_direction() {
+        assert_eq!(MongoFilterConverter::convert_to_mongo_sort(&spec(&[])), None);
+        assert_eq!(
+            MongoFilterConverter::convert_to_mongo_sort(&spec(&[("sort", "name,-createdAt")])),
+            Some(doc! { "name": 1, "created_at": -1, "_id": -1 })
+        );
+        assert_eq!(MongoFilterConverter::with_tiebreaker(doc! { "_id": 1 }), doc! { "_id": 1 });
+    }
+    // endregion =================================== MONGO ===================================
+
+    // region =================================== SCYLLA ===================================
+    static KEYS: &[CqlKey] = &[
+        CqlKey::new("status", CqlKeyKind::Partition, CqlColumnType::Text),
+        CqlKey::new("created_at", CqlKeyKind::Clustering, CqlColumnType::BigInt),
+        CqlKey::new("name", CqlKeyKind::Indexed, CqlColumnType::Text),
+        CqlKey::new("is_active", CqlKeyKind::Indexed, CqlColumnType::Boolean),
+    ];
+
+    fn scylla(pairs: &[(&str, &str)]) -> Result<ScyllaFilter, Failure> {
+        ScyllaFilterConverter::convert_to_scylla_filter(&spec(pairs), KEYS)
+    }
+
+    #[test]
+    fn scylla_restricts_the_partition_and_its_clustering_range() {
+        let filter =
+            scylla(&[("filter[status]", "DRAFT"), ("filter[createdAt][gte]", "10"), ("sort", "-createdAt")]).unwrap();
+
+        assert_eq!(filter.where_clause(), " WHERE created_at >= ? AND status = ?");
+        assert_eq!(filter.order_clause(), " ORDER BY created_at DESC");
+        assert!(
+            matches!(filter.values().as_slice(), [ScyllaValue::BigInt(10), ScyllaValue::String(status)] if status == "DRAFT")
+        );
+    }
+
+    #[test]
+    fn scylla_takes_a_single_index_on_its_own() {
+        let filter = scylla(&[("filter[name]", "Rust")]).unwrap();
+
+        assert_eq!(filter.where_clause(), " WHERE name = ?");
+        assert_eq!(filter.order_clause(), "");
+        assert_bad_request(scylla(&[("filter[name]", "Rust"), ("filter[isActive]", "true")]));
+        assert_bad_request(scylla(&[("filter[name]", "Rust"), ("filter[status]", "DRAFT")]));
+    }
+
+    #[test]
+    fn scylla_rejects_what_would_need_filtering() {
+        assert_bad_request(scylla(&[("filter[createdAt][gt]", "1")]));
+        assert_bad_request(scylla(&[("filter[name][contains]", "rust")]));
+        assert_bad_request(scylla(&[("filter[status][ne]", "DRAFT")]));
+        assert_bad_request(scylla(&[("sort", "-createdAt")]));
+        assert_bad_request(scylla(&[("filter[status]", "DRAFT"), ("sort", "name")]));
+        assert_bad_request(scylla(&[("filter[status][in]", "DRAFT,PUBLISHED"), ("sort", "-createdAt")]));
+    }
+
+    #[test]
+    fn scylla_rejects_columns_it_has_no_key_for() {
+        let keys = &KEYS[..1];
+        let spec = spec(&[("filter[name]", "Rust")]);
+
+        assert_bad_request(ScyllaFilterConverter::convert_to_scylla_filter(&spec, keys));
+    }
+
+    #[test]
+    fn scylla_values_follow_the_column_type() {
+        static TYPED: &[CqlKey] = &[
+            CqlKey::new("status", CqlKeyKind::Partition, CqlColumnType::Uuid),
+            CqlKey::new("created_at", CqlKeyKind::Clustering, CqlColumnType::Int),
+        ];
+        let convert = |pairs: &[(&str, &str)]| ScyllaFilterConverter::convert_to_scylla_filter(&spec(pairs), TYPED);
+
+        let id = "0191f1a4-7c1e-7b3c-9a43-6f1b2d3c4e5f";
+        let filter = convert(&[("filter[status]", id), ("filter[createdAt][lt]", "7")]).unwrap();
+        assert!(matches!(filter.values().as_slice(), [ScyllaValue::Int(7), ScyllaValue::Uuid(_)]));
+        assert_bad_request(convert(&[("filter[status]", "not-a-uuid")]));
+        assert_bad_request(convert(&[("filter[status]", id), ("filter[createdAt][lt]", "9999999999")]));
+    }
+    // endregion =================================== SCYLLA ===================================
+
+    // region =================================== MEMORY ===================================
+    fn matches(pairs: &[(&str, &str)], entity: &Value) -> bool {
+        MemoryFilter::matches(&spec(pairs), FIELDS, entity)
+    }
+
+    #[test]
+    fn memory_reads_fields_by_api_name() {
+        let entity = json!({ "name": "Rust in Action", "createdAt": 15, "isActive": true, "status": "DRAFT" });
+
+        assert!(matches(&[("filter[createdAt][gte]", "10"), ("filter[createdAt][lt]", "20")], &entity));
+        assert!(matches(&[("filter[isActive]", "true")], &entity));
+        assert!(!matches(&[("filter[createdAt][gt]", "15")], &entity));
+        assert!(matches(&[("filter[status][in]", "DRAFT,PUBLISHED")], &entity));
+        assert!(!matches(&[("filter[status][nin]", "DRAFT")], &entity));
+    }
+
+    #[test]
+    fn memory_matches_text_regardless_of_case() {
+        let entity = json!({ "name": "Rust in Action" });
+
+        assert!(matches(&[("filter[name][contains]", "IN ACT")], &entity));
+        assert!(matches(&[("filter[name][startsWith]", "rust")], &entity));
+        assert!(matches(&[("filter[name][endsWith]", "action")], &entity));
+        assert!(!matches(&[("filter[name][startsWith]", "action")], &entity));
+    }
+
+    #[test]
+    fn memory_treats_missing_fields_like_mongo() {
+        let entity = json!({ "name": "Rust" });
+
+        assert!(!matches(&[("filter[createdAt][gte]", "0")], &entity));
+        assert!(matches(&[("filter[createdAt][ne]", "0")], &entity));
+        assert!(matches(&[("filter[status][nin]", "DRAFT")], &entity));
+    }
+
+    #[test]
+    fn memory_matches_arrays_on_any_element() {
+        let entity = json!({ "status": ["DRAFT", "PUBLISHED"] });
+
+        assert!(matches(&[("filter[status]", "DRAFT")], &entity));
+        assert!(!matches(&[("filter[status][ne]", "DRAFT")], &entity));
+        assert!(matches(&[("filter[status][ne]", "ARCHIVED")], &entity));
+        assert!(!matches(&[("filter[status][nin]", "PUBLISHED")], &entity));
+    }
+
+    #[test]
+    fn memory_sorts_by_each_key_with_missing_values_first() {
+        let spec = spec(&[("sort", "name,-createdAt")]);
+        let mut entities = vec![
+            json!({ "name": "b", "createdAt": 1 }),
+            json!({ "name": "a", "createdAt": 1 }),
+            json!({ "name": "a", "createdAt": 2 }),
+            json!({ "createdAt": 3 }),
+        ];
+        entities.sort_by(|a, b| MemoryFilter::compare(&spec, FIELDS, a, b));
+
+        assert_eq!(
+            entities,
+            vec![
+                json!({ "createdAt": 3 }),
+                json!({ "name": "a", "createdAt": 2 }),
+                json!({ "name": "a", "createdAt": 1 }),
+                json!({ "name": "b", "createdAt": 1 }),
+            ]
+        );
+        assert_eq!(MemoryFilter::compare(&QuerySpec::default(), FIELDS, &entities[0], &entities[1]), Ordering::Equal);
+    }
+    // endregion =================================== MEMORY ===================================
 }